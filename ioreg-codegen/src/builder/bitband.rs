@@ -0,0 +1,98 @@
+use crate::{Register, RegisterPropertyValue};
+use super::{RegisterExt, RegisterFieldExt};
+use quote::quote;
+
+/// A Cortex-M bit-band region and the alias region mapping each of its bits
+/// to a word.
+#[derive(Clone, Copy)]
+pub(crate) struct BitBandRegion {
+    base: u64,
+    alias: u64,
+}
+
+const REGION_SIZE: u64 = 0x10_0000;
+
+const REGIONS: [BitBandRegion; 2] = [
+    // SRAM
+    BitBandRegion { base: 0x2000_0000, alias: 0x2200_0000 },
+    // Peripherals
+    BitBandRegion { base: 0x4000_0000, alias: 0x4200_0000 },
+];
+
+/// Finds the bit-band region covering `len` bytes starting at `base`, if any.
+pub(crate) fn region_for(base: u64, len: u64) -> Option<BitBandRegion> {
+    REGIONS.iter()
+        .find(|r| base >= r.base && base + len <= r.base + REGION_SIZE)
+        .cloned()
+}
+
+/// Builds bit-band accessors for the single-bit fields of `register`.
+///
+/// Each access is a single word load or store to the alias of the bit, so it
+/// can't race with interrupts touching other bits of the register. Writes
+/// are only generated for registers without `set_to_clear` fields, since the
+/// bus still performs a read-modify-write of the whole register.
+pub(crate) fn build_bitband_fns(register: &Register, region: BitBandRegion) -> proc_macro2::TokenStream {
+    use RegisterPropertyValue::*;
+    let base = region.base as usize;
+    let alias = region.alias as usize;
+    let writable_register = !register.has_set_to_clear();
+    let accessors = register.fields.iter()
+        .filter(|field| field.bit_size_single() == 1 && field.variants.is_none())
+        .map(|field| {
+            let bit = field.shift_expr(0);
+            // Unlike the bits of a value, an alias out of the field is another
+            // field's, or another register's, so the index is always checked
+            let (index_arg, index_check, bit_expr) = if field.count_value() > 1 {
+                let count = syn::LitInt::new(field.count_value(), syn::IntSuffix::None, field.offset.span());
+                (quote!(index: usize), quote!(assert!(index < #count);), quote!(#bit + index))
+            } else {
+                (quote!(), quote!(), quote!(#bit))
+            };
+            let read_ident = field.prefixed_ident("bitband_read");
+            let set_ident = field.prefixed_ident("bitband_set");
+            let clear_ident = field.prefixed_ident("bitband_clear");
+            let read = if field.has_property(WriteOnly) {
+                quote!()
+            } else {
+                quote! {
+                    #[inline(always)]
+                    pub fn #read_ident(&self, #index_arg) -> bool {
+                        #index_check
+                        unsafe { core::ptr::read_volatile(self.bitband_alias(#bit_expr)) != 0 }
+                    }
+                }
+            };
+            let write = if !writable_register || field.has_property(WriteOnly) || field.has_property(ReadOnly) {
+                quote!()
+            } else {
+                quote! {
+                    #[inline(always)]
+                    pub fn #set_ident(&self, #index_arg) {
+                        #index_check
+                        unsafe { core::ptr::write_volatile(self.bitband_alias(#bit_expr), 1) }
+                    }
+
+                    #[inline(always)]
+                    pub fn #clear_ident(&self, #index_arg) {
+                        #index_check
+                        unsafe { core::ptr::write_volatile(self.bitband_alias(#bit_expr), 0) }
+                    }
+                }
+            };
+            quote! {
+                #read
+                #write
+            }
+        });
+    quote! {
+        #[doc = "Address of the bit-band alias word for `bit` of this register"]
+        #[inline(always)]
+        pub fn bitband_alias(&self, bit: usize) -> *mut u32 {
+            let address = self as *const Self as usize;
+            #alias.wrapping_add(address.wrapping_sub(#base) << 5).wrapping_add(bit << 2) as *mut u32
+        }
+
+        #( #accessors )*
+    }
+}
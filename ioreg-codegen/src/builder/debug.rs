@@ -0,0 +1,104 @@
+use crate::Register;
+use super::RegisterFieldExt;
+use quote::quote;
+use std::collections::HashMap;
+
+/// Builds a `core::fmt::Debug` implementation for a register getter, printing
+/// every readable field by name. Enum fields print their variant name, or the
+/// raw value in hex if it doesn't match a variant.
+pub(crate) fn build_debug_impl(register: &Register, get_ident: &syn::Ident, enum_register_idents: &HashMap<syn::Ident, syn::Path>) -> proc_macro2::TokenStream {
+    use crate::RegisterPropertyValue::WriteOnly;
+    let register_ty = &register.ty;
+    let get_name = get_ident.to_string();
+    let fields = register.fields.iter()
+        .filter(|field| !field.has_property(WriteOnly))
+        .map(|field| {
+            let name = field.ident.to_string();
+            let getter_ident = field.getter_ident();
+            let value_expr = |index: Option<u64>| {
+                match enum_register_idents.get(&field.ident) {
+                    Some(enum_path) => {
+                        let index = index.map(|i| {
+                            let i = syn::LitInt::new(i, syn::IntSuffix::None, field.ident.span());
+                            quote!(#i)
+                        });
+                        let primitive_expr = field.primitive_extract_expr(index, &quote!(self.value), register.ty);
+                        quote! {
+                            ::volatile_cell::field::Decoded(<#enum_path as core::convert::TryFrom<#register_ty>>::try_from(#primitive_expr))
+                        }
+                    },
+                    None => match index {
+                        Some(i) => {
+                            let i = syn::LitInt::new(i, syn::IntSuffix::None, field.ident.span());
+                            quote!(self.#getter_ident(#i))
+                        },
+                        None => quote!(self.#getter_ident()),
+                    },
+                }
+            };
+            let value = if field.count_value() > 1 {
+                let values = (0..field.count_value()).map(|i| value_expr(Some(i)));
+                quote!([ #( #values ),* ])
+            } else {
+                value_expr(None)
+            };
+            quote!(.field(#name, &#value))
+        });
+    quote! {
+        impl core::fmt::Debug for #get_ident {
+            fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                f.debug_struct(#get_name)
+                    #( #fields )*
+                    .finish()
+            }
+        }
+    }
+}
+
+fn fnv1a(s: &str) -> u32 {
+    s.bytes().fold(0x811c9dc5, |hash, b| (hash ^ b as u32).wrapping_mul(0x01000193))
+}
+
+/// Describes the layout of a register for host-side decoding of log records,
+/// e.g. `pmc::mckr css@0+2{0=SlowClk,1=MainClk} pres@4+3`.
+fn log_layout(register: &Register, path: &str) -> String {
+    let mut layout = String::from(path);
+    for field in register.fields.iter() {
+        layout.push_str(&format!(" {}@{}+{}", &field.ident, field.shift_expr(0).value(), field.bit_size_single()));
+        if field.count_value() > 1 {
+            layout.push_str(&format!("x{}", field.count_value()));
+        }
+        if let Some(ref variants) = field.variants {
+            let variants: Vec<String> = variants.variants.iter()
+                .map(|v| format!("{}={}", v.value.value(), &v.ident))
+                .collect();
+            layout.push_str(&format!("{{{}}}", variants.join(",")));
+        }
+    }
+    layout
+}
+
+/// Builds the compact binary log encoding for a register getter: a 32-bit
+/// identifier hashed from the register path followed by the raw value, both
+/// little endian. `LOG_LAYOUT` carries what a host tool needs to decode it.
+pub(crate) fn build_log_impl(register: &Register, get_ident: &syn::Ident, path: &str) -> proc_macro2::TokenStream {
+    let log_id = fnv1a(path);
+    let layout = log_layout(register, path);
+    let value_len = register.ty.byte_length() as usize;
+    let record_len = 4 + value_len;
+    quote! {
+        impl #get_ident {
+            pub const LOG_ID: u32 = #log_id;
+            pub const LOG_LAYOUT: &'static str = #layout;
+
+            #[doc = "Encode the register value as a binary log record"]
+            #[inline(always)]
+            pub fn to_log_bytes(&self) -> [u8; #record_len] {
+                let mut record = [0u8; #record_len];
+                record[..4].copy_from_slice(&Self::LOG_ID.to_le_bytes());
+                record[4..].copy_from_slice(&self.value.to_le_bytes()[..#value_len]);
+                record
+            }
+        }
+    }
+}
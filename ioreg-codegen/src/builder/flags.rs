@@ -0,0 +1,206 @@
+use crate::{Register, RegisterField, RegisterPropertyValue, RegisterVariant};
+use super::{RegisterFieldExt, register_field_primitive};
+use quote::{ToTokens, quote};
+
+/// Whether `field` gets a flags type: it's cleared by writing ones and spans
+/// several bits, so handlers need to say which of them to acknowledge.
+pub(crate) fn is_flags_field(field: &RegisterField) -> bool {
+    field.bit_size_single() > 1 && field.has_property(RegisterPropertyValue::SetToClear)
+}
+
+fn flags_ident(field: &RegisterField) -> syn::Ident {
+    use heck::CamelCase;
+    let s = format!("{}Flags", field.ident.to_string().to_camel_case());
+    syn::Ident::new(&s, field.ident.span())
+}
+
+/// Path of the flags type of `field` from the register's scope, if it has one.
+pub(crate) fn flags_path(register: &Register, field: &RegisterField) -> Option<syn::Path> {
+    use heck::SnakeCase;
+    if !is_flags_field(field) {
+        return None;
+    }
+    let mod_ident = syn::Ident::new(&register.ident.to_string().to_snake_case(), register.ident.span());
+    let flags_ident = flags_ident(field);
+    Some(syn::parse_quote!(#mod_ident::#flags_ident))
+}
+
+/// Builds the bitflags-style set type of a flags field. Variants of the field
+/// name its bits, by their index within the field, and become associated
+/// constants.
+pub(crate) fn build_flags_type(field: &RegisterField) -> syn::Result<proc_macro2::TokenStream> {
+    let primitive = register_field_primitive(field)?;
+    let variants: Vec<&RegisterVariant> = field.variants.iter().flat_map(|v| v.variants.iter()).collect();
+    if let Some(variant) = variants.iter().find(|v| v.value.value() >= field.bit_size_single()) {
+        return Err(syn::Error::new(variant.value.span(), format!("flag bit {} is outside of field {}", variant.value.value(), field.ident)));
+    }
+    Ok(build_flags_set(&flags_ident(field), &primitive, &field.mask_expr_single(), &variants))
+}
+
+/// Builds a set type named `flags_ident` over the bits of `mask`, with a
+/// constant for each of `names`, whose values are bit indices.
+pub(crate) fn build_flags_set<T: ToTokens>(flags_ident: &syn::Ident, primitive: &T, mask: &syn::LitInt, names: &[&RegisterVariant]) -> proc_macro2::TokenStream {
+    let consts = names.iter().map(|variant| {
+        let ident = &variant.ident;
+        let bit = &variant.value;
+        quote! {
+            pub const #ident: #flags_ident = #flags_ident(1 << #bit);
+        }
+    });
+    quote! {
+        #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+        pub struct #flags_ident(#primitive);
+
+        #[allow(non_upper_case_globals)]
+        impl #flags_ident {
+            #( #consts )*
+
+            #[inline(always)]
+            pub const fn empty() -> #flags_ident {
+                #flags_ident(0)
+            }
+
+            #[inline(always)]
+            pub const fn all() -> #flags_ident {
+                #flags_ident(#mask as #primitive)
+            }
+
+            #[doc = "Create a set from raw bits, dropping those outside the field"]
+            #[inline(always)]
+            pub const fn from_bits_truncate(bits: #primitive) -> #flags_ident {
+                #flags_ident(bits & (#mask as #primitive))
+            }
+
+            #[inline(always)]
+            pub const fn bits(&self) -> #primitive {
+                self.0
+            }
+
+            #[inline(always)]
+            pub const fn is_empty(&self) -> bool {
+                self.0 == 0
+            }
+
+            #[inline(always)]
+            pub const fn contains(&self, other: #flags_ident) -> bool {
+                (self.0 & other.0) == other.0
+            }
+
+            #[inline(always)]
+            pub const fn intersects(&self, other: #flags_ident) -> bool {
+                (self.0 & other.0) != 0
+            }
+        }
+
+        impl From<#primitive> for #flags_ident {
+            #[inline(always)]
+            fn from(bits: #primitive) -> #flags_ident {
+                #flags_ident::from_bits_truncate(bits)
+            }
+        }
+
+        impl core::ops::BitOr for #flags_ident {
+            type Output = #flags_ident;
+
+            #[inline(always)]
+            fn bitor(self, other: #flags_ident) -> #flags_ident {
+                #flags_ident(self.0 | other.0)
+            }
+        }
+
+        impl core::ops::BitOrAssign for #flags_ident {
+            #[inline(always)]
+            fn bitor_assign(&mut self, other: #flags_ident) {
+                self.0 |= other.0;
+            }
+        }
+
+        impl core::ops::BitAnd for #flags_ident {
+            type Output = #flags_ident;
+
+            #[inline(always)]
+            fn bitand(self, other: #flags_ident) -> #flags_ident {
+                #flags_ident(self.0 & other.0)
+            }
+        }
+
+        impl core::ops::BitAndAssign for #flags_ident {
+            #[inline(always)]
+            fn bitand_assign(&mut self, other: #flags_ident) {
+                self.0 &= other.0;
+            }
+        }
+
+        impl core::ops::Sub for #flags_ident {
+            type Output = #flags_ident;
+
+            #[inline(always)]
+            fn sub(self, other: #flags_ident) -> #flags_ident {
+                #flags_ident(self.0 & !other.0)
+            }
+        }
+
+        impl core::ops::Not for #flags_ident {
+            type Output = #flags_ident;
+
+            #[inline(always)]
+            fn not(self) -> #flags_ident {
+                #flags_ident(!self.0 & (#mask as #primitive))
+            }
+        }
+    }
+}
+
+/// Builds the getter of a flags field, returning the set of pending flags.
+pub(crate) fn build_flags_getter_fn(register: &Register, field: &RegisterField, flags_path: &syn::Path) -> proc_macro2::TokenStream {
+    let getter_ident = field.getter_ident();
+    if field.count_value() > 1 {
+        let primitive_expr = field.primitive_extract_expr(Some(quote!(index)), &quote!(self.value), register.ty);
+        quote! {
+            #[inline(always)]
+            pub fn #getter_ident(&self, index: usize) -> #flags_path {
+                #flags_path::from_bits_truncate((#primitive_expr) as _)
+            }
+        }
+    } else {
+        let primitive_expr = field.primitive_extract_expr(None, &quote!(self.value), register.ty);
+        quote! {
+            #[inline(always)]
+            pub fn #getter_ident(&self) -> #flags_path {
+                #flags_path::from_bits_truncate((#primitive_expr) as _)
+            }
+        }
+    }
+}
+
+/// Builds the updater function clearing the given flags of a flags field,
+/// leaving its other pending flags set.
+pub(crate) fn build_flags_clear_fn(register: &Register, field: &RegisterField, flags_path: &syn::Path) -> proc_macro2::TokenStream {
+    let clear_ident = field.prefixed_ident("clear");
+    let register_ty = &register.ty;
+    let shift = field.shift_expr(0);
+    if field.count_value() > 1 {
+        let len = syn::LitInt::new(field.bit_size_single(), syn::IntSuffix::None, field.offset.span());
+        quote! {
+            #[doc = "Acknowledge `flags`, writing ones to just those bits"]
+            #[inline(always)]
+            pub fn #clear_ident<'b, F: Into<#flags_path>>(&'b mut self, index: usize, flags: F) -> &'b mut Self {
+                let bits = (flags.into().bits() as #register_ty) << Self::update_offset(#shift, #len, index);
+                self.value |= bits;
+                self.mask |= bits;
+                self
+            }
+        }
+    } else {
+        quote! {
+            #[doc = "Acknowledge `flags`, writing ones to just those bits"]
+            #[inline(always)]
+            pub fn #clear_ident<'b, F: Into<#flags_path>>(&'b mut self, flags: F) -> &'b mut Self {
+                let bits = (flags.into().bits() as #register_ty) << #shift;
+                self.value |= bits;
+                self.mask |= bits;
+                self
+            }
+        }
+    }
+}
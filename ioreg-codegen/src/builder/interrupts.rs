@@ -0,0 +1,48 @@
+use crate::{InterruptFamily, RegisterGroup, RegisterVariant};
+use super::flags::build_flags_set;
+use quote::quote;
+
+/// Builds the flags type of an interrupt register family, along with the
+/// functions of its group operating on all four registers.
+pub(crate) fn build_interrupt_fns(group: &RegisterGroup, family: &InterruptFamily, group_ident: &syn::Ident, backend: bool) -> proc_macro2::TokenStream {
+    use heck::CamelCase;
+    let flags_ident = syn::Ident::new(&format!("{}Flags", group.ident.to_string().to_camel_case()), group.ident.span());
+    let primitive = &family.ty;
+    let mask = syn::LitInt::new(family.mask(), syn::IntSuffix::None, group.ident.span());
+    let names: Vec<&RegisterVariant> = family.flags.iter().collect();
+    let flags_definition = build_flags_set(&flags_ident, primitive, &mask, &names);
+    let (impl_generics, group_ty) = if backend {
+        (quote!(<B: ::volatile_cell::backend::RegisterBackend>), quote!(#group_ident<B>))
+    } else {
+        (quote!(), quote!(#group_ident))
+    };
+    quote! {
+        #flags_definition
+
+        impl #impl_generics #group_ty {
+            #[doc = "Enable the interrupts in `flags`, leaving the others as they are"]
+            #[inline(always)]
+            pub fn enable<F: Into<#flags_ident>>(&self, flags: F) {
+                self.ier.update().set_bits(flags.into().bits());
+            }
+
+            #[doc = "Disable the interrupts in `flags`, leaving the others as they are"]
+            #[inline(always)]
+            pub fn disable<F: Into<#flags_ident>>(&self, flags: F) {
+                self.idr.update().set_bits(flags.into().bits());
+            }
+
+            #[doc = "The enabled interrupts"]
+            #[inline(always)]
+            pub fn mask(&self) -> #flags_ident {
+                #flags_ident::from_bits_truncate(self.imr.get().bits())
+            }
+
+            #[doc = "The pending interrupts. On many devices reading them acknowledges them"]
+            #[inline(always)]
+            pub fn status(&self) -> #flags_ident {
+                #flags_ident::from_bits_truncate(self.isr.get().bits())
+            }
+        }
+    }
+}
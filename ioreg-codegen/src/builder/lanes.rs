@@ -0,0 +1,112 @@
+use crate::{Endian, Register, RegisterOptionValue, RegisterPropertyValue, RegisterType};
+use super::{RegisterFieldExt, from_device_order, to_device_order};
+use quote::quote;
+
+/// Width of the lanes of a register accessible with narrower loads and
+/// stores, as given by its `bytes` or `halfwords` option.
+fn lane_bits(register: &Register) -> Option<u64> {
+    if register.has_option(RegisterOptionValue::Bytes) {
+        Some(8)
+    } else if register.has_option(RegisterOptionValue::Halfwords) {
+        Some(16)
+    } else {
+        None
+    }
+}
+
+/// Builds accessors for the byte or halfword lanes of `register`.
+///
+/// Each lane is exposed as its own `VolatileCell`, and fields that exactly
+/// fill a lane get `read_*`/`write_*` accessors using a single narrow access,
+/// so writing them can't race with writes to the rest of the register.
+pub(crate) fn build_lane_fns(register: &Register, endian: Endian) -> syn::Result<proc_macro2::TokenStream> {
+    let bits = match lane_bits(register) {
+        Some(bits) => bits,
+        None => return Ok(quote!()),
+    };
+    if register.ty.cells().is_some() {
+        return Err(syn::Error::new(register.ident.span(), "lanes aren't supported for registers made up of multiple cells"));
+    }
+    let register_bits = register.ty.byte_length() * 8;
+    if bits >= register_bits {
+        return Err(syn::Error::new(register.ident.span(), format!("{} lanes must be narrower than the register", bits)));
+    }
+    let lane_ty = if bits == 8 {
+        RegisterType::Reg8
+    } else {
+        RegisterType::Reg16
+    };
+    let lane_count = (register_bits / bits) as usize;
+    let last_lane = lane_count - 1;
+    let lane_index = match endian {
+        Endian::Native => quote! {
+            #[cfg(target_endian = "little")]
+            let index = index;
+            #[cfg(target_endian = "big")]
+            let index = #last_lane - index;
+        },
+        Endian::Little => quote!(),
+        Endian::Big => quote!(let index = #last_lane - index;),
+    };
+    let accessors = register.fields.iter()
+        .filter(|field| {
+            field.bit_size_single() == bits
+                && field.shift_expr(0).value() % bits == 0
+                && field.variants.is_none()
+                && !field.has_property(RegisterPropertyValue::SetToClear)
+        })
+        .map(|field| {
+            let first_lane = (field.shift_expr(0).value() / bits) as usize;
+            let (index_arg, lane_expr) = if field.count_value() > 1 {
+                (quote!(index: usize,), quote!(#first_lane + index))
+            } else {
+                (quote!(), quote!(#first_lane))
+            };
+            let lane_load = from_device_order(endian, &lane_ty, quote!(self.lane(#lane_expr).get()));
+            let lane_store = to_device_order(endian, quote!(new_value));
+            let read_ident = field.prefixed_ident("read");
+            let write_ident = field.prefixed_ident("write");
+            let read = if field.has_property(RegisterPropertyValue::WriteOnly) {
+                quote!()
+            } else {
+                quote! {
+                    #[doc = "Read the field with a single narrow load of its lane"]
+                    #[inline(always)]
+                    pub fn #read_ident(&self, #index_arg) -> #lane_ty {
+                        #lane_load
+                    }
+                }
+            };
+            let write = if field.has_property(RegisterPropertyValue::ReadOnly) {
+                quote!()
+            } else {
+                quote! {
+                    #[doc = "Write the field with a single narrow store to its lane, leaving the rest of the register untouched"]
+                    #[inline(always)]
+                    pub fn #write_ident(&self, #index_arg new_value: #lane_ty) {
+                        self.lane(#lane_expr).set(#lane_store);
+                    }
+                }
+            };
+            quote! {
+                #read
+                #write
+            }
+        });
+    Ok(quote! {
+        #[doc = "The lanes of the register, in address order"]
+        #[inline(always)]
+        pub fn lanes(&self) -> &[::volatile_cell::VolatileCell<#lane_ty>; #lane_count] {
+            unsafe { &*(self.value.as_ptr() as *const [::volatile_cell::VolatileCell<#lane_ty>; #lane_count]) }
+        }
+
+        #[doc = "Lane `index` of the register, counting from the least significant. Its value is in the byte order of the device."]
+        #[inline(always)]
+        pub fn lane(&self, index: usize) -> &::volatile_cell::VolatileCell<#lane_ty> {
+            #lane_index
+            &self.lanes()[index]
+        }
+
+        #( #accessors )*
+    })
+}
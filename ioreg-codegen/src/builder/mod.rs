@@ -0,0 +1,960 @@
+use super::{Endian, IoRegs, Register, RegisterOptionValue, RegisterType, RegisterField, RegisterFieldOffset, RegisterPropertyValue};
+use quote::{ ToTokens, quote };
+use std::borrow::Cow;
+use std::collections::{LinkedList, HashMap};
+use std::iter;
+
+pub mod alignment;
+pub mod bitband;
+pub mod union;
+pub mod casing;
+pub mod debug;
+pub mod flags;
+pub mod interrupts;
+pub mod lanes;
+pub mod peripheral;
+pub mod typestate;
+pub mod snapshot;
+pub mod value;
+pub mod wait;
+
+pub(crate) trait RegisterExt {
+    fn is_write_only(&self) -> bool;
+    fn is_read_only(&self) -> bool;
+    fn has_set_to_clear(&self) -> bool;
+    fn byte_start(&self) -> u64;
+}
+
+impl RegisterExt for Register {
+    fn is_write_only(&self) -> bool {
+        self.fields.iter().all(|f| {
+            f.properties
+                .as_ref()
+                .and_then(|p| p.properties.iter().map(|p| p.value).find(|&v| v == RegisterPropertyValue::WriteOnly))
+                .is_some()
+        })
+    }
+
+    fn is_read_only(&self) -> bool {
+        self.fields.iter().all(|f| f.has_property(RegisterPropertyValue::ReadOnly))
+    }
+
+    fn has_set_to_clear(&self) -> bool {
+        self.fields.iter().any(|f| f.has_property(RegisterPropertyValue::SetToClear))
+    }
+
+    fn byte_start(&self) -> u64 {
+        self.offset.value()
+    }
+}
+
+trait RegisterFieldExt {
+    fn bit_size_full(&self) -> u64;
+    fn bit_size_single(&self) -> u64;
+    fn shift_expr(&self, index: u64) -> syn::LitInt;
+    fn mask_expr_full(&self) -> syn::LitInt;
+    fn mask_expr_single(&self) -> syn::LitInt;
+    fn primitive_extract_expr<T: ToTokens>(&self, index: Option<proc_macro2::TokenStream>, value_expr: &T, ty: RegisterType) -> proc_macro2::TokenStream;
+    fn max_value(&self) -> u64;
+    fn variants_exhaustive(&self) -> bool;
+    fn build_clear_fn(&self) -> proc_macro2::TokenStream;
+    fn has_property(&self, value: RegisterPropertyValue) -> bool;
+    fn getter_ident(&self) -> syn::Ident;
+    fn prefixed_ident(&self, prefix: &str) -> syn::Ident;
+}
+
+impl RegisterFieldExt for RegisterField {
+    #[inline]
+    fn bit_size_full(&self) -> u64 {
+        self.offset.bit_size()
+    }
+
+    fn bit_size_single(&self) -> u64 {
+        let count = self.count_value();
+        self.bit_size_full() / count
+    }
+
+    fn shift_expr(&self, index: u64) -> syn::LitInt {
+        use syn::IntSuffix;
+
+        let size = self.bit_size_single();
+        let base = match &self.offset {
+            &RegisterFieldOffset::Bit(ref low) => low,
+            &RegisterFieldOffset::BitRange(ref range) => &range.start,
+        };
+        let value = base.value() + (size * index);
+        syn::LitInt::new(value, IntSuffix::None, base.span())
+    }
+
+    fn mask_expr_full(&self) -> syn::LitInt {
+        use syn::IntSuffix;
+        let span = self.offset.span();
+        syn::LitInt::new(ones(self.bit_size_full()), IntSuffix::None, span)
+    }
+
+    fn mask_expr_single(&self) -> syn::LitInt {
+        use syn::IntSuffix;
+        let span = self.offset.span();
+        let value = self.max_value();
+        syn::LitInt::new(value, IntSuffix::None, span)
+    }
+
+    fn max_value(&self) -> u64 {
+        let bits = self.bit_size_single();
+        if bits > 64 {
+            panic!("too many bits for {}: {}", &self.ident, bits);
+        }
+        ones(bits)
+    }
+
+    /// Whether the variants of the field cover every value of its bits, so
+    /// that decoding it can't fail.
+    fn variants_exhaustive(&self) -> bool {
+        self.variants.as_ref()
+            .map_or(false, |v| self.max_value().checked_add(1) == Some(v.variants.len() as u64))
+    }
+
+    /// Extracts the field from `value_expr` with a constant shift and mask,
+    /// which folds into a single bit-field extract where the target has one
+    /// (`ubfx` on thumbv7m). The shift or mask is left out when it has no
+    /// effect, keeping the expression simple before optimization too.
+    fn primitive_extract_expr<T: ToTokens>(&self, index: Option<proc_macro2::TokenStream>, value_expr: &T, ty: RegisterType) -> proc_macro2::TokenStream {
+        let mask = self.mask_expr_single();
+        if let Some(index) = index {
+            let base = self.shift_expr(0);
+            let size_expr = syn::LitInt::new(self.bit_size_single(), syn::IntSuffix::None, self.offset.span());
+            return quote!((#value_expr >> (#base + (#size_expr * #index))) & #mask);
+        }
+        let shift = self.shift_expr(0);
+        let shifted = if shift.value() == 0 {
+            quote!(#value_expr)
+        } else {
+            quote!((#value_expr >> #shift))
+        };
+        let storage_bits = match ty {
+            RegisterType::Reg8 => 8,
+            RegisterType::Reg16 => 16,
+            RegisterType::Reg24 | RegisterType::Reg32 => 32,
+            _ => 64,
+        };
+        if shift.value() + self.bit_size_single() >= storage_bits {
+            // Nothing above the field is left after the shift
+            shifted
+        } else {
+            quote!((#shifted & #mask))
+        }
+    }
+
+    fn has_property(&self, value: RegisterPropertyValue) -> bool {
+        self.properties
+            .as_ref()
+            .and_then(|props| props.properties.iter().find(|&p| p.value == value))
+            .is_some()
+    }
+
+    fn getter_ident(&self) -> syn::Ident {
+        use heck::SnakeCase;
+        let s = <str as SnakeCase>::to_snake_case(self.ident.to_string().as_ref());
+        syn::Ident::new(&s, self.ident.span())
+    }
+
+    fn prefixed_ident(&self, prefix: &str) -> syn::Ident {
+        use heck::SnakeCase;
+        let s = <str as SnakeCase>::to_snake_case(self.ident.to_string().as_ref());
+        syn::Ident::new(&format!("{}_{}", prefix, s), self.ident.span())
+    }
+
+    fn build_clear_fn(&self) -> proc_macro2::TokenStream {
+        let clear_ident = self.prefixed_ident("clear");
+        let mask = self.mask_expr_single();
+        let shift = self.shift_expr(0);
+        if self.count_value() > 1 {
+            let len = syn::LitInt::new(self.bit_size_single(), syn::IntSuffix::None, self.offset.span());
+            quote! {
+                #[inline(always)]
+                pub fn #clear_ident<'b>(&'b mut self, index: usize) -> &'b mut Self {
+                    let shift = (#shift + (#len * index));
+                    self.value |= #mask << shift;
+                    self.mask |= #mask << shift;
+                    self
+                }
+            }
+        } else {
+            quote! {
+                #[inline(always)]
+                pub fn #clear_ident<'b>(&'b mut self) -> &'b mut Self {
+                    self.value |= #mask << #shift;
+                    self.mask |= #mask << #shift;
+                    self
+                }
+            }
+        }
+    }
+}
+
+/// A mask of the `bits` lowest bits.
+fn ones(bits: u64) -> u64 {
+    if bits >= 64 {
+        u64::max_value()
+    } else {
+        (1 << bits) - 1
+    }
+}
+
+fn register_type(ty: RegisterType) -> impl ToTokens {
+    match ty {
+        RegisterType::Reg8 => quote!(u8),
+        RegisterType::Reg16 => quote!(u16),
+        RegisterType::Reg24 | RegisterType::Reg32 => quote!(u32),
+        _ => quote!(u64),
+    }
+}
+
+fn register_field_primitive(field: &RegisterField) -> syn::Result<syn::export::TokenStream2> {
+    match field.bit_size_single() {
+        1 => Ok(quote!(bool)),
+        2...8 => Ok(quote!(u8)),
+        9...16 => Ok(quote!(u16)),
+        17...32 => Ok(quote!(u32)),
+        33...64 => Ok(quote!(u64)),
+        size => Err(syn::Error::new(field.offset.span(), format!("Invalid register field size: {}", size))),
+    }
+}
+
+fn camel_case_cow<'a, T: ?Sized>(input: Cow<'a, T>) -> Cow<'a, T> where
+    T: heck::CamelCase + ToOwned,
+{
+    use heck::CamelCase;
+    Cow::Owned(input.to_camel_case())
+}
+
+fn build_register_field_enum(field: &RegisterField, register_ty: Option<RegisterType>) -> syn::Result<Option<(syn::Ident, syn::export::TokenStream2)>> {
+    if let Some(variants) = field.variants.as_ref() {
+        let enum_ident = {
+            let mut enum_ident = field.ident.to_string().into();
+            enum_ident = camel_case_cow(enum_ident);
+            syn::Ident::new(enum_ident.as_ref(), field.ident.span())
+        };
+        let default_primitive = register_field_primitive(field)?;
+        let primitive = register_ty
+            .map(ToTokens::into_token_stream)
+            .unwrap_or(default_primitive);
+        let get_variant_idents = || variants.variants.iter().map(|v| &v.ident);
+        let variant_idents = get_variant_idents();
+        let variant_idents2 = get_variant_idents();
+        let get_variant_values = || variants.variants.iter().map(|v| &v.value);
+        let variant_values = get_variant_values();
+        let variant_values2 = get_variant_values();
+        let enum_ident_ref = &enum_ident;
+        let enum_ident_rep = iter::repeat(enum_ident_ref);
+        #[cfg(test)]
+        let derive_expr = quote!(#[derive(Debug, Clone, Copy, PartialEq, Eq)]);
+        #[cfg(not(test))]
+        let derive_expr = quote!(#[derive(Clone, Copy, PartialEq, Eq)]);
+        let definition = quote! {
+            #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+            #[repr(#primitive)]
+            pub enum #enum_ident_ref {
+                #( #variant_idents = #variant_values ),*
+            }
+
+            impl core::convert::TryFrom<#primitive> for #enum_ident_ref {
+                type Error = #primitive;
+
+                #[inline(always)]
+                fn try_from(primitive: #primitive) -> Result<Self, Self::Error> {
+                    match primitive {
+                        #( #variant_values2 => Ok(#enum_ident_rep::#variant_idents2), )*
+                        v => Err(v),
+                    }
+                }
+            }
+        };
+        Ok(Some((enum_ident, definition)))
+    } else {
+        Ok(None)
+    }
+}
+
+fn build_getter_fn(register: &Register, field: &RegisterField, enum_register_idents: &HashMap<syn::Ident, syn::Path>) -> proc_macro2::TokenStream {
+    if let Some(flags_path) = flags::flags_path(register, field) {
+        return flags::build_flags_getter_fn(register, field, &flags_path);
+    }
+    let register_ty = &register.ty;
+    let getter_ident = field.getter_ident();
+    let mut is_enum = true;
+    let field_ty: Cow<syn::Path> = enum_register_idents.get(&field.ident)
+        .map(Cow::Borrowed)
+        .map(Ok)
+        .unwrap_or_else(|| {
+            is_enum = false;
+            // Only override for booleans
+            if field.bit_size_single() == 1 {
+                register_field_primitive(&field)
+                    .and_then(syn::parse2)
+                    .map(Cow::Owned)
+            } else {
+                syn::parse2(register_ty.into_token_stream())
+                    .map(Cow::Owned)
+            }
+        })
+        .unwrap(); // TODO: get rid of this unwrap
+    let field_ty = field_ty.as_ref();
+    let idx_expr = if field.count_value() > 1 {
+        Some(quote!(index))
+    } else {
+        None
+    };
+    let primitive_expr = field.primitive_extract_expr(idx_expr, &quote!(self.value), register.ty);
+    let value = if field.bit_size_single() == 1 && field.variants.is_none() {
+        quote! {
+            let val = #primitive_expr;
+            val != 0x0
+        }
+    } else if enum_register_idents.get(&field.ident).is_none() {
+        primitive_expr
+    } else {
+        if field.variants_exhaustive() {
+            // All paths are covered, so we're good to transmute
+            quote!(unsafe { core::mem::transmute::<_, #field_ty>(#primitive_expr) })
+        } else {
+            #[cfg(feature = "unsafe_variant_unchecked")]
+            {
+                quote!(unsafe { core::mem::transmute::<_, #field_ty>(#primitive_expr) })
+            }
+            #[cfg(not(feature = "unsafe_variant_unchecked"))]
+            {
+                quote! {
+                    use core::convert::TryFrom;
+                    let primitive_value: #register_ty = #primitive_expr;
+                    #field_ty::try_from(primitive_value).unwrap()
+                }
+            }
+        }
+    };
+    if field.count_value() > 1 {
+        quote! {
+            #[inline(always)]
+            pub fn #getter_ident(&self, index: usize) -> #field_ty {
+                #value
+            }
+        }
+    } else {
+        quote! {
+            #[inline(always)]
+            pub fn #getter_ident(&self) -> #field_ty {
+                #value
+            }
+        }
+    }
+}
+
+fn setter_field_ty<'a>(field: &RegisterField, enum_register_idents: &'a HashMap<syn::Ident, syn::Path>) -> Cow<'a, syn::Path> {
+    enum_register_idents.get(&field.ident)
+        .map(Cow::Borrowed)
+        .map(Ok)
+        .unwrap_or_else(|| {
+            register_field_primitive(&field)
+                .and_then(syn::parse2)
+                .map(Cow::Owned)
+        })
+        .unwrap() // TODO: get rid of this unwrap
+}
+
+fn build_changed_fields_fn(register: &Register, get_ident: &syn::Ident) -> proc_macro2::TokenStream {
+    let descriptors = register.fields.iter()
+        .filter(|field| !field.has_property(RegisterPropertyValue::WriteOnly))
+        .map(|field| {
+            let name = field.ident.to_string();
+            let offset = field.shift_expr(0).value() as u32;
+            let width = field.bit_size_single() as u32;
+            let count = field.count_value() as u32;
+            quote! {
+                ::volatile_cell::field::FieldDescriptor {
+                    name: #name,
+                    offset: #offset,
+                    width: #width,
+                    count: #count,
+                }
+            }
+        });
+    quote! {
+        impl #get_ident {
+            pub const FIELDS: &'static [::volatile_cell::field::FieldDescriptor] = &[
+                #( #descriptors ),*
+            ];
+
+            #[doc = "Iterate over the readable fields that differ from those of `other`"]
+            #[inline]
+            pub fn changed_fields(&self, other: &#get_ident) -> ::volatile_cell::field::FieldChanges {
+                ::volatile_cell::field::FieldChanges::new(Self::FIELDS, self.value as u64, other.value as u64)
+            }
+        }
+    }
+}
+
+pub struct RegisterStructIdents {
+    pub base: syn::Ident,
+    pub update: syn::Ident,
+    pub get: syn::Ident,
+    pub value: syn::Ident,
+}
+
+pub(crate) fn build_register_enums(register: &Register) -> syn::Result<(syn::export::TokenStream2, HashMap<syn::Ident, syn::Path>)> {
+    let mod_ident = {
+        use heck::SnakeCase;
+        let s = <str as SnakeCase>::to_snake_case(register.ident.to_string().as_ref());
+        syn::Ident::new(&s, register.ident.span())
+    };
+    let mut enum_register_definitions = LinkedList::new();
+    let mut enum_register_idents = HashMap::new();
+    for field in register.fields.iter() {
+        if flags::is_flags_field(field) {
+            enum_register_definitions.push_back(flags::build_flags_type(field)?);
+            continue;
+        }
+        if let Some((enum_ident, ts)) = build_register_field_enum(field, Some(register.ty))? {
+            let mod_ident = &mod_ident;
+            let enum_path: syn::Path = syn::parse2(quote!(#mod_ident::#enum_ident))?;
+            enum_register_idents.insert(field.ident.clone(), enum_path);
+            enum_register_definitions.push_back(ts);
+            if typestate::is_typestate_field(register, field) {
+                enum_register_definitions.push_back(typestate::build_state_mod(field, &enum_ident)?);
+            }
+        }
+    }
+    let mod_definition = quote! {
+        pub mod #mod_ident {
+            #( #enum_register_definitions )*
+        }
+    };
+    Ok((mod_definition, enum_register_idents))
+}
+
+/// Builds the call reporting an access of `register` to the trace hook, for
+/// use in its `load` and `store` helpers where `value` is in scope.
+#[cfg(feature = "tracing")]
+fn build_trace_call(register: &Register, context: &RegisterContext, access: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+    let peripheral = match context.path.rfind("::") {
+        Some(i) => &context.path[..i],
+        None => &context.path[..],
+    };
+    let name = register.ident.to_string();
+    let address = if context.backend {
+        quote!(self.offset)
+    } else {
+        quote!(self as *const Self as usize)
+    };
+    quote! {
+        ::volatile_cell::trace::record(&::volatile_cell::trace::Event {
+            peripheral: #peripheral,
+            register: #name,
+            address: #address,
+            value: value as u64,
+            access: ::volatile_cell::trace::Access::#access,
+        });
+    }
+}
+
+/// Converts `expr`, a `ty` as stored by the device, to the target's byte
+/// order.
+pub(crate) fn from_device_order<T: ToTokens, E: ToTokens>(endian: Endian, ty: &T, expr: E) -> proc_macro2::TokenStream {
+    match endian {
+        Endian::Native => quote!(#expr),
+        Endian::Little => quote!(#ty::from_le(#expr)),
+        Endian::Big => quote!(#ty::from_be(#expr)),
+    }
+}
+
+/// Converts `expr` from the target's byte order to that of the device.
+pub(crate) fn to_device_order<E: ToTokens>(endian: Endian, expr: E) -> proc_macro2::TokenStream {
+    match endian {
+        Endian::Native => quote!(#expr),
+        Endian::Little => quote!((#expr).to_le()),
+        Endian::Big => quote!((#expr).to_be()),
+    }
+}
+
+/// Builds the storage type of `register` along with expressions loading and
+/// storing its whole value (`value` in the latter), swapping bytes if the
+/// device order differs from the target.
+///
+/// Registers without a native width are made up of narrower cells, in
+/// address order from the least significant (most significant for big
+/// endian devices). Those are accessed starting with the least significant
+/// cell unless the register is `high_first`.
+fn build_cell_access(register: &Register, endian: Endian) -> syn::Result<(proc_macro2::TokenStream, proc_macro2::TokenStream, proc_macro2::TokenStream)> {
+    let register_ty = &register.ty;
+    let high_first = register.has_option(RegisterOptionValue::HighFirst);
+    let (cell, count) = match register.ty.cells() {
+        Some(cells) => cells,
+        None => {
+            if high_first {
+                return Err(syn::Error::new(register.ident.span(), "high_first only applies to registers made up of multiple cells"));
+            }
+            let load = from_device_order(endian, register_ty, quote!(self.value.get()));
+            let store = to_device_order(endian, quote!(value));
+            return Ok((
+                quote!(::volatile_cell::VolatileCell<#register_ty>),
+                load,
+                quote!(self.value.set(#store);),
+            ));
+        },
+    };
+    let cell_bits = cell.byte_length() as usize * 8;
+    let cell_mask = syn::LitInt::new((1u64 << cell_bits) - 1, syn::IntSuffix::None, register.ident.span());
+    // Cells by significance, as (address index, shift)
+    let mut order: Vec<(usize, usize)> = (0..count)
+        .map(|i| {
+            let index = if endian == Endian::Big {
+                count - 1 - i
+            } else {
+                i
+            };
+            (index, i * cell_bits)
+        })
+        .collect();
+    if high_first {
+        order.reverse();
+    }
+    let loads = order.iter().map(|&(i, shift)| {
+        let cell_value = from_device_order(endian, &cell, quote!(self.value[#i].get()));
+        quote!(value |= (#cell_value as #register_ty) << #shift;)
+    });
+    let stores = order.iter().map(|&(i, shift)| {
+        let cell_value = to_device_order(endian, quote!(((value >> #shift) & #cell_mask) as #cell));
+        quote!(self.value[#i].set(#cell_value);)
+    });
+    Ok((
+        quote!([::volatile_cell::VolatileCell<#cell>; #count]),
+        quote! {
+            {
+                let mut value: #register_ty = 0;
+                #( #loads )*
+                value
+            }
+        },
+        quote!(#( #stores )*),
+    ))
+}
+
+/// Peripheral-wide information needed to build each of its registers.
+pub(crate) struct RegisterContext {
+    /// Module path of the register, e.g. `pmc::mckr`
+    pub(crate) path: String,
+    pub(crate) bitband: Option<bitband::BitBandRegion>,
+    pub(crate) endian: Endian,
+    /// Whether registers are accessed through a `RegisterBackend` instead of
+    /// memory mapped cells
+    pub(crate) backend: bool,
+}
+
+/// Builds the register struct along with its getter, updater and value types.
+pub(crate) fn build_register_struct(register: &Register, context: &RegisterContext) -> syn::Result<(RegisterStructIdents, syn::export::TokenStream2)> {
+    let struct_idents = {
+        let struct_ident = {
+            use heck::CamelCase;
+            let mut struct_ident = register.ident.to_string().into();
+            struct_ident = camel_case_cow(struct_ident);
+            syn::Ident::new(&struct_ident, register.ident.span())
+        };
+        let struct_ident_str = struct_ident.to_string();
+        let update_ident = syn::Ident::new(&format!("{}{}", &struct_ident_str, "Update"), register.ident.span());
+        let get_ident = syn::Ident::new(&format!("{}{}", &struct_ident_str, "Get"), register.ident.span());
+        let value_ident = syn::Ident::new(&format!("{}{}", &struct_ident_str, "Value"), register.ident.span());
+        RegisterStructIdents {
+            base: struct_ident,
+            update: update_ident,
+            get: get_ident,
+            value: value_ident,
+        }
+    };
+    let (mod_definition, enum_register_idents) = build_register_enums(register)?;
+    let register_ty = &register.ty;
+    let struct_ident = &struct_idents.base;
+    let update_ident = &struct_idents.update;
+    let get_ident = &struct_idents.get;
+    let value_ident = &struct_idents.value;
+    let backend_bound = quote!(::volatile_cell::backend::RegisterBackend);
+    let (struct_generics, struct_ty, update_generics, update_ty) = if context.backend {
+        (
+            quote!(<B: #backend_bound>),
+            quote!(#struct_ident<B>),
+            quote!(<'a, B: #backend_bound>),
+            quote!(#update_ident<'a, B>),
+        )
+    } else {
+        (quote!(), quote!(#struct_ident), quote!(<'a>), quote!(#update_ident<'a>))
+    };
+    // Write-only registers can't be read back, so there's nothing to retry on
+    let current = from_device_order(context.endian, register_ty, quote!(current));
+    let merged = to_device_order(context.endian, quote!(update.merge(#current)));
+    let exclusive_definition = match register.ty.atomic_ty() {
+        Some((ref atomic_ty, atomic_width)) if !register.is_write_only() && !context.backend => quote! {
+            #[doc="Update the register with exclusive loads and stores (LDREX/STREX), retrying `f` until no other access intervened"]
+            #[doc=""]
+            #[doc="The exclusive accesses go to the register's memory directly rather than through its cell, so they aren't seen by the replayer or the tracing hook."]
+            #[cfg(target_has_atomic = #atomic_width)]
+            #[inline]
+            pub fn update_exclusive<F: FnMut(&mut #update_ident)>(&self, mut f: F) {
+                use ::core::sync::atomic::Ordering;
+                let cell = unsafe { &*(self.value.as_ptr() as *const #atomic_ty) };
+                let mut current = cell.load(Ordering::Relaxed);
+                loop {
+                    // Detached, so that if `f` panics the update isn't stored
+                    // with a plain read-modify-write on drop
+                    let mut update = #update_ident::detached(self);
+                    f(&mut update);
+                    let merged = #merged;
+                    if update.mask == 0 {
+                        return;
+                    }
+                    match cell.compare_exchange_weak(current, merged, Ordering::SeqCst, Ordering::Relaxed) {
+                        Ok(_) => return,
+                        Err(actual) => current = actual,
+                    }
+                }
+            }
+        },
+        _ => quote!(),
+    };
+    let wait_definitions = wait::build_wait_fns(register);
+    let lane_definitions = if context.backend {
+        if register.has_option(RegisterOptionValue::Bytes) || register.has_option(RegisterOptionValue::Halfwords) {
+            return Err(syn::Error::new(register.ident.span(), "byte and halfword lanes aren't supported with a register backend"));
+        }
+        quote!()
+    } else {
+        lanes::build_lane_fns(register, context.endian)?
+    };
+    let bitband_definitions = context.bitband
+        .filter(|_| !context.backend)
+        .map(|region| bitband::build_bitband_fns(register, region))
+        .unwrap_or_else(|| quote!());
+    #[cfg(feature = "tracing")]
+    let (trace_load, trace_store) = (
+        build_trace_call(register, context, quote!(Read)),
+        build_trace_call(register, context, quote!(Write)),
+    );
+    #[cfg(not(feature = "tracing"))]
+    let (trace_load, trace_store) = (quote!(), quote!());
+    let (struct_body, load_expr, store_stmt) = if context.backend {
+        let bits = register.ty.byte_length() as u32 * 8;
+        // Backends access whole registers, so there are no cells to reorder
+        if context.endian != Endian::Native && register.ty.cells().is_some() {
+            return Err(syn::Error::new(register.ident.span(), "endian is only supported for 8, 16, 32 and 64 bit registers with a register backend"));
+        }
+        let backend_load = from_device_order(context.endian, register_ty, quote!((self.backend.read(self.offset, #bits) as #register_ty)));
+        let backend_store = to_device_order(context.endian, quote!(value));
+        (
+            quote! {
+                pub struct #struct_ident<B> {
+                    backend: B,
+                    offset: usize,
+                }
+
+                impl #struct_generics #struct_ty {
+                    #[doc = "Access the register at `offset` bytes from the base of `backend`"]
+                    #[inline(always)]
+                    pub fn at(backend: B, offset: usize) -> #struct_ty {
+                        #struct_ident {
+                            backend: backend,
+                            offset: offset,
+                        }
+                    }
+                }
+            },
+            backend_load,
+            quote!(self.backend.write(self.offset, #bits, #backend_store as u64);),
+        )
+    } else {
+        let (cell_ty, load_expr, store_stmt) = build_cell_access(register, context.endian)?;
+        (
+            quote! {
+                #[repr(C)]
+                pub struct #struct_ident {
+                    value: #cell_ty,
+                }
+            },
+            load_expr,
+            store_stmt,
+        )
+    };
+    let struct_definition = quote! {
+        #struct_body
+
+        impl #struct_generics #struct_ty {
+            #[inline(always)]
+            fn load(&self) -> #register_ty {
+                let value = #load_expr;
+                #trace_load
+                value
+            }
+
+            #[inline(always)]
+            fn store(&self, value: #register_ty) {
+                #trace_store
+                #store_stmt
+            }
+
+            #[doc="Create a new updater"]
+            #[inline(always)]
+            pub fn update<'a>(&'a self) -> #update_ty {
+                #update_ident::new(self)
+            }
+
+            #[doc="Create an updater whose read-modify-write happens inside the critical section `cs`"]
+            #[inline(always)]
+            pub fn update_in<'a, C: ::volatile_cell::sync::CriticalSection>(&'a self, _cs: &'a C) -> #update_ty {
+                #update_ident::new(self)
+            }
+
+            #exclusive_definition
+
+            #[doc="Create a getter representing the current state of the register"]
+            #[inline(always)]
+            pub fn get(&self) -> #get_ident {
+                #get_ident::new(self)
+            }
+
+            #wait_definitions
+
+            #lane_definitions
+
+            #bitband_definitions
+        }
+    };
+    let get_function_definitions = register.fields.iter().filter_map(|field| {
+        if field.has_property(RegisterPropertyValue::WriteOnly) {
+            return None;
+        }
+        Some(build_getter_fn(register, field, &enum_register_idents))
+    });
+    let update_function_definitions = register.fields.iter().filter_map(|field| {
+        if field.has_property(RegisterPropertyValue::ReadOnly) {
+            return None;
+        }
+        if let Some(flags_path) = flags::flags_path(register, field) {
+            return Some(flags::build_flags_clear_fn(register, field, &flags_path));
+        }
+        if field.has_property(RegisterPropertyValue::SetToClear) {
+            let clear_fn = field.build_clear_fn();
+            return Some(clear_fn);
+        }
+        let setter_ident = field.prefixed_ident("set");
+        let field_ty = setter_field_ty(field, &enum_register_idents);
+        let field_ty = field_ty.as_ref();
+        let mask = field.mask_expr_single();
+        let register_ty = &register.ty;
+        let shift = field.shift_expr(0);
+        let ret = if field.count_value() > 1 {
+            use syn::IntSuffix;
+            use syn::spanned::Spanned;
+            let last_index_expr = syn::LitInt::new(field.count_value(), IntSuffix::None, field.count.as_ref().map(|c| c.span()).unwrap());
+            let single_size = syn::LitInt::new(field.bit_size_single(), IntSuffix::None, field.offset.span());
+            #[cfg(feature = "field_count_checks")]
+            let count_check = quote! {
+                if index > #last_index_expr {
+                    panic!();
+                }
+            };
+            #[cfg(not(feature = "field_count_checks"))]
+            let count_check = quote!();
+            quote! {
+                #[inline(always)]
+                pub fn #setter_ident<'b>(&'b mut self, index: usize, new_value: #field_ty) -> &'b mut Self {
+                    #count_check
+                    let update_offset = Self::update_offset(#shift, #single_size, index);
+                    let context_mask: #register_ty = #mask << update_offset;
+                    self.value = (self.value & !context_mask) | (((new_value as #register_ty) & #mask) << update_offset);
+                    self.mask |= context_mask;
+                    self
+                }
+            }
+        } else {
+            quote! {
+                #[inline(always)]
+                pub fn #setter_ident<'b>(&'b mut self, new_value: #field_ty) -> &'b mut Self {
+                    let context_mask: #register_ty = #mask << #shift;
+                    self.value = (self.value & !context_mask) | (((new_value as #register_ty) & #mask) << #shift);
+                    self.mask |= context_mask;
+                    self
+                }
+            }
+        };
+        Some(ret)
+    });
+    let get_definition = {
+        quote! {
+            #[derive(Clone, PartialEq, Eq)]
+            pub struct #get_ident {
+                value: #register_ty,
+            }
+
+            impl #get_ident {
+                #[doc = "Create a getter reflecting the current value of the register"]
+                #[inline(always)]
+                pub fn new #struct_generics (reg: &#struct_ty) -> #get_ident {
+                    #get_ident {
+                        value: reg.load(),
+                    }
+                }
+
+                #( #get_function_definitions )*
+            }
+        }
+    };
+    let update_definition = {
+        let mut clear: u64 = 0;
+        for field in register.fields.iter() {
+            if field.properties.as_ref().and_then(|p| p.properties.iter().map(|p| p.value).find(|&value| value == RegisterPropertyValue::SetToClear)).is_some() {
+                let mask = field.mask_expr_full().value();
+                clear |= mask << field.shift_expr(0).value();
+            }
+        }
+        let initial_value = if register.is_write_only() {
+            quote!(0)
+        } else {
+            quote! {
+                if self.write_only {
+                    0
+                } else {
+                    self.reg.load()
+                }
+            }
+        };
+        quote! {
+            pub struct #update_ident #update_generics {
+                value: #register_ty,
+                mask: #register_ty,
+                write_only: bool,
+                store_on_drop: bool,
+                reg: &'a #struct_ty,
+            }
+
+            impl #update_generics #update_ty {
+                #[inline(always)]
+                pub fn new(reg: &'a #struct_ty) -> #update_ty {
+                    #update_ident {
+                        value: 0,
+                        mask: 0,
+                        write_only: false,
+                        store_on_drop: true,
+                        reg: reg,
+                    }
+                }
+
+                #[inline(always)]
+                pub fn new_ignoring_state(reg: &'a #struct_ty) -> #update_ty {
+                    #update_ident {
+                        value: 0,
+                        mask: 0,
+                        write_only: true,
+                        store_on_drop: true,
+                        reg: reg,
+                    }
+                }
+
+                #[doc = "An update that is never stored, for the caller to store itself"]
+                #[inline(always)]
+                fn detached(reg: &'a #struct_ty) -> #update_ty {
+                    #update_ident {
+                        value: 0,
+                        mask: 0,
+                        write_only: false,
+                        store_on_drop: false,
+                        reg: reg,
+                    }
+                }
+
+                const fn clear_mask() -> #register_ty {
+                    #clear as #register_ty
+                }
+
+                const fn update_offset(base: usize, size: usize, index: usize) -> usize {
+                    base + (size * index)
+                }
+
+                #[inline(always)]
+                fn merge(&self, current: #register_ty) -> #register_ty {
+                    let v: #register_ty = current & (!Self::clear_mask()) & (!self.mask);
+                    self.value | v
+                }
+
+                #( #update_function_definitions )*
+            }
+
+            impl #update_generics Drop for #update_ty {
+                #[inline(always)]
+                fn drop(&mut self) {
+                    if self.store_on_drop && self.mask != 0 {
+                        let current: #register_ty = #initial_value;
+                        self.reg.store(self.merge(current));
+                    }
+                }
+            }
+        }
+    };
+    let value_definition = value::build_value_struct(register, value_ident, &enum_register_idents);
+    let conversions = quote! {
+        impl From<#get_ident> for #value_ident {
+            #[inline(always)]
+            fn from(get: #get_ident) -> #value_ident {
+                #value_ident::from_bits(get.value)
+            }
+        }
+
+        impl From<#value_ident> for #get_ident {
+            #[inline(always)]
+            fn from(value: #value_ident) -> #get_ident {
+                #get_ident {
+                    value: value.to_bits(),
+                }
+            }
+        }
+
+        impl #struct_generics #struct_ty {
+            #[doc = "Write `value` to the register in a single store, without reading it first"]
+            #[inline(always)]
+            pub fn write(&self, value: #value_ident) {
+                self.store(value.to_bits());
+            }
+        }
+
+        impl #update_generics #update_ty {
+            #[doc = "Replace every field of the register with those of `value`"]
+            #[inline(always)]
+            pub fn assign<'b>(&'b mut self, value: #value_ident) -> &'b mut Self {
+                self.value = value.to_bits();
+                self.mask = !0;
+                self
+            }
+        }
+    };
+    let changes_definition = build_changed_fields_fn(register, get_ident);
+    if context.backend && register.has_option(RegisterOptionValue::Typestate) {
+        return Err(syn::Error::new(register.ident.span(), "typestate isn't supported with a register backend"));
+    }
+    let typed_definition = typestate::build_typed_struct(register, &struct_idents, &enum_register_idents);
+    #[cfg(feature = "debug_impls")]
+    let debug_definition = debug::build_debug_impl(register, get_ident, &enum_register_idents);
+    #[cfg(not(feature = "debug_impls"))]
+    let debug_definition = quote!();
+    #[cfg(feature = "binary_log")]
+    let log_definition = debug::build_log_impl(register, get_ident, &context.path);
+    #[cfg(not(feature = "binary_log"))]
+    let log_definition = quote!();
+    let ret = quote! {
+        #mod_definition
+        #struct_definition
+        #update_definition
+        #get_definition
+        #value_definition
+        #conversions
+        #changes_definition
+        #typed_definition
+        #debug_definition
+        #log_definition
+    };
+    Ok((struct_idents, ret))
+}
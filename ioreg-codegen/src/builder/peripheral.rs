@@ -0,0 +1,148 @@
+use crate::{Peripherals, PeripheralInstance};
+use quote::{quote, ToTokens};
+
+/// Owned, non-`Copy` handle to a single instance of a peripheral, emitted
+/// alongside the peripheral struct as e.g. `pmc::PmcPeripheral`.
+///
+/// Tokens dereference to the peripheral registers, so drivers can take one by
+/// value to prove they have exclusive access, or borrow one to share it.
+/// Being unique, they also dereference mutably, as `typed` registers need. If the peripheral was declared
+/// with a location (`@ 0x...`) the token can also be conjured from it with
+/// `steal`. Tokens of peripherals with a register backend are generic over
+/// it, like the peripheral.
+pub(crate) struct PeripheralToken {
+    union_ident: syn::Ident,
+    location: Option<syn::LitInt>,
+    backend: bool,
+}
+
+impl PeripheralToken {
+    pub(crate) fn new(union_ident: &syn::Ident, location: Option<&syn::LitInt>, backend: bool) -> Self {
+        PeripheralToken {
+            union_ident: union_ident.clone(),
+            location: location.cloned(),
+            backend: backend,
+        }
+    }
+
+    pub(crate) fn ident(union_ident: &syn::Ident) -> syn::Ident {
+        syn::Ident::new(&format!("{}Peripheral", union_ident), union_ident.span())
+    }
+}
+
+impl ToTokens for PeripheralToken {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        let union_ident = &self.union_ident;
+        let token_ident = Self::ident(union_ident);
+        let (generics, union_ty, token_ty) = if self.backend {
+            (
+                quote!(<B: ::volatile_cell::backend::RegisterBackend + 'static>),
+                quote!(#union_ident<B>),
+                quote!(#token_ident<B>),
+            )
+        } else {
+            (quote!(), quote!(#union_ident), quote!(#token_ident))
+        };
+        let steal = match self.location {
+            Some(ref location) => quote! {
+                #[doc = "Address of the peripheral registers"]
+                pub const ADDRESS: usize = #location;
+
+                #[doc = "Create a token for the peripheral at `ADDRESS`."]
+                #[doc = ""]
+                #[doc = "Unsafe because it bypasses `Peripherals::take`, so other tokens for the same peripheral may exist."]
+                #[inline(always)]
+                pub unsafe fn steal() -> #token_ident {
+                    #token_ident::new(&mut *(Self::ADDRESS as *mut #union_ident))
+                }
+            },
+            None => quote!(),
+        };
+        tokens.extend(quote! {
+            #[doc = "Owned handle granting exclusive access to the peripheral"]
+            pub struct #token_ident #generics {
+                regs: &'static mut #union_ty,
+            }
+
+            impl #generics #token_ty {
+                #[doc = "Create a token for the peripheral registers at `regs`."]
+                #[doc = ""]
+                #[doc = "Unsafe because the caller must ensure it's the only token for `regs`."]
+                #[inline(always)]
+                pub const unsafe fn new(regs: &'static mut #union_ty) -> #token_ty {
+                    #token_ident {
+                        regs: regs,
+                    }
+                }
+
+                #steal
+            }
+
+            impl #generics ::core::ops::Deref for #token_ty {
+                type Target = #union_ty;
+
+                #[inline(always)]
+                fn deref(&self) -> &#union_ty {
+                    self.regs
+                }
+            }
+
+            impl #generics ::core::ops::DerefMut for #token_ty {
+                #[inline(always)]
+                fn deref_mut(&mut self) -> &mut #union_ty {
+                    self.regs
+                }
+            }
+        });
+    }
+}
+
+fn build_instance(instance: &PeripheralInstance) -> proc_macro2::TokenStream {
+    let ident = &instance.ident;
+    let ty = &instance.ty;
+    match instance.init {
+        Some((_, ref regs)) => quote!(#ident: <#ty>::new(#regs)),
+        None => quote!(#ident: <#ty>::steal()),
+    }
+}
+
+/// Builds a device-level struct holding one token per peripheral instance,
+/// along with `take` that hands it out at most once.
+pub(crate) fn build_peripherals(peripherals: &Peripherals) -> syn::Result<proc_macro2::TokenStream> {
+    let ident = &peripherals.ident;
+    let field_definitions = peripherals.instances.iter().map(|instance| {
+        let field_ident = &instance.ident;
+        let ty = &instance.ty;
+        quote!(pub #field_ident: #ty)
+    });
+    let field_initializers = peripherals.instances.iter().map(build_instance);
+    Ok(quote! {
+        #[allow(non_snake_case)]
+        pub struct #ident {
+            #( #field_definitions ),*
+        }
+
+        impl #ident {
+            #[doc = "Take the peripheral tokens, returning `None` if they've already been taken"]
+            #[inline]
+            pub fn take() -> Option<#ident> {
+                static TAKEN: ::core::sync::atomic::AtomicBool = ::core::sync::atomic::AtomicBool::new(false);
+                if TAKEN.swap(true, ::core::sync::atomic::Ordering::SeqCst) {
+                    None
+                } else {
+                    Some(unsafe { #ident::steal() })
+                }
+            }
+
+            #[doc = "Create the peripheral tokens regardless of whether they've been taken."]
+            #[doc = ""]
+            #[doc = "Unsafe because it may hand out a second token for the same peripheral."]
+            #[allow(unused_unsafe)]
+            pub unsafe fn steal() -> #ident {
+                #ident {
+                    #( #field_initializers ),*
+                }
+            }
+        }
+    })
+}
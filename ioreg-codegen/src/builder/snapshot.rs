@@ -0,0 +1,161 @@
+use crate::{Register, RegisterGroup, RegisterOptionValue};
+use super::{RegisterExt, RegisterStructIdents};
+use super::casing::ToCasing;
+use quote::{quote, ToTokens};
+use std::collections::LinkedList;
+
+struct RestoreStatement {
+    order: u64,
+    statement: proc_macro2::TokenStream,
+}
+
+/// Collects the registers of a peripheral (or group) that can be saved and
+/// later written back, producing a `Snapshot` struct with `capture` and
+/// `restore` functions alongside the peripheral struct.
+///
+/// Registers are included if they are readable, have no `set_to_clear` fields
+/// and aren't marked `no_snapshot`. Registers are restored in ascending
+/// `restore_order` (default 0), otherwise in declaration order. A group is
+/// restored as a whole, in the position given by its own `restore_order`, so
+/// ordering only applies among the direct members of a peripheral or group.
+/// Read-only registers are captured but never written back.
+pub(crate) struct SnapshotBuilder {
+    union_ident: syn::Ident,
+    backend: bool,
+    fields: LinkedList<proc_macro2::TokenStream>,
+    captures: LinkedList<proc_macro2::TokenStream>,
+    restores: Vec<RestoreStatement>,
+}
+
+impl SnapshotBuilder {
+    pub(crate) fn new(union_ident: &syn::Ident, backend: bool) -> Self {
+        SnapshotBuilder {
+            union_ident: union_ident.clone(),
+            backend: backend,
+            fields: LinkedList::new(),
+            captures: LinkedList::new(),
+            restores: Vec::new(),
+        }
+    }
+
+    pub(crate) fn add_register(&mut self, reg: &Register, idents: &RegisterStructIdents) {
+        if reg.is_write_only() || reg.has_set_to_clear() || reg.has_option(RegisterOptionValue::NoSnapshot) {
+            return;
+        }
+        let reg_ident = &reg.ident;
+        let value_ident = &idents.value;
+        let update_ident = &idents.update;
+        let (field_ty, capture, restore) = match reg.count {
+            Some(ref count) => {
+                let len = &count.count;
+                let capture = quote! {
+                    {
+                        let mut values = [#value_ident::new(); #len];
+                        for i in 0..#len {
+                            values[i] = #value_ident::from(p.#reg_ident[i].get());
+                        }
+                        values
+                    }
+                };
+                let restore = quote! {
+                    for i in 0..#len {
+                        #update_ident::new_ignoring_state(&p.#reg_ident[i])
+                            .assign(snapshot.#reg_ident[i]);
+                    }
+                };
+                (quote!([#value_ident; #len]), capture, restore)
+            },
+            None => {
+                let capture = quote!(#value_ident::from(p.#reg_ident.get()));
+                let restore = quote! {
+                    #update_ident::new_ignoring_state(&p.#reg_ident)
+                        .assign(snapshot.#reg_ident);
+                };
+                (value_ident.into_token_stream(), capture, restore)
+            },
+        };
+        self.fields.push_back(quote!(pub #reg_ident: #field_ty));
+        self.captures.push_back(quote!(#reg_ident: #capture));
+        if !reg.is_read_only() {
+            self.restores.push(RestoreStatement {
+                order: reg.restore_order(),
+                statement: restore,
+            });
+        }
+    }
+
+    pub(crate) fn add_group(&mut self, group: &RegisterGroup) {
+        let group_ident = group.ident.to_snake_case();
+        let (field_ty, capture, restore) = match group.count {
+            Some(ref count) => {
+                let len = &count.count;
+                let capture = quote! {
+                    {
+                        let mut snapshots = [#group_ident::capture(&p.#group_ident[0]); #len];
+                        for i in 1..#len {
+                            snapshots[i] = #group_ident::capture(&p.#group_ident[i]);
+                        }
+                        snapshots
+                    }
+                };
+                let restore = quote! {
+                    for i in 0..#len {
+                        #group_ident::restore(&p.#group_ident[i], &snapshot.#group_ident[i]);
+                    }
+                };
+                (quote!([#group_ident::Snapshot; #len]), capture, restore)
+            },
+            None => {
+                let capture = quote!(#group_ident::capture(&p.#group_ident));
+                let restore = quote! {
+                    #group_ident::restore(&p.#group_ident, &snapshot.#group_ident);
+                };
+                (quote!(#group_ident::Snapshot), capture, restore)
+            },
+        };
+        self.fields.push_back(quote!(pub #group_ident: #field_ty));
+        self.captures.push_back(quote!(#group_ident: #capture));
+        self.restores.push(RestoreStatement {
+            order: group.restore_order(),
+            statement: restore,
+        });
+    }
+}
+
+impl ToTokens for SnapshotBuilder {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        let union_ident = &self.union_ident;
+        let fields = self.fields.iter();
+        let captures = self.captures.iter();
+        let mut restores: Vec<&RestoreStatement> = self.restores.iter().collect();
+        // sort_by_key is stable, so equal orders keep declaration order
+        restores.sort_by_key(|r| r.order);
+        let restores = restores.into_iter().map(|r| &r.statement);
+        let (generics, union_ty) = if self.backend {
+            (quote!(<B: ::volatile_cell::backend::RegisterBackend>), quote!(#union_ident<B>))
+        } else {
+            (quote!(), quote!(#union_ident))
+        };
+        tokens.extend(quote! {
+            #[doc = "Saved state of every readable register without side effects"]
+            #[derive(Clone, Copy)]
+            pub struct Snapshot {
+                #( #fields ),*
+            }
+
+            #[doc = "Read the current state of the peripheral"]
+            #[allow(unused_variables)]
+            pub fn capture #generics (p: &#union_ty) -> Snapshot {
+                Snapshot {
+                    #( #captures ),*
+                }
+            }
+
+            #[doc = "Write a previously captured state back to the peripheral"]
+            #[allow(unused_variables)]
+            pub fn restore #generics (p: &#union_ty, snapshot: &Snapshot) {
+                #( #restores )*
+            }
+        });
+    }
+}
@@ -0,0 +1,193 @@
+use crate::{Register, RegisterField, RegisterOptionValue, RegisterPropertyValue};
+use super::{RegisterFieldExt, RegisterStructIdents};
+use quote::quote;
+use std::collections::HashMap;
+
+/// Whether `field` is tracked by the type-state layer of `register`: only
+/// single, writable enum fields of registers marked `typestate` are.
+pub(crate) fn is_typestate_field(register: &Register, field: &RegisterField) -> bool {
+    register.has_option(RegisterOptionValue::Typestate)
+        && field.variants.is_some()
+        && field.count_value() == 1
+        && !field.has_property(RegisterPropertyValue::ReadOnly)
+        && !field.has_property(RegisterPropertyValue::SetToClear)
+}
+
+fn state_param(field: &RegisterField) -> syn::Ident {
+    use heck::CamelCase;
+    syn::Ident::new(&format!("{}State", field.ident.to_string().to_camel_case()), field.ident.span())
+}
+
+/// Builds the state module of a type-state field, e.g. `mckr::css`, holding a
+/// marker type per variant plus `Unknown` for when the value isn't tracked.
+pub(crate) fn build_state_mod(field: &RegisterField, enum_ident: &syn::Ident) -> syn::Result<proc_macro2::TokenStream> {
+    let mod_ident = field.getter_ident();
+    let variants = &field.variants.as_ref().unwrap().variants;
+    if let Some(v) = variants.iter().find(|v| v.ident == "Unknown") {
+        return Err(syn::Error::new(v.ident.span(), "variant name `Unknown` is reserved for typestate registers"));
+    }
+    let markers = variants.iter().map(|v| {
+        let ident = &v.ident;
+        quote! {
+            pub struct #ident;
+
+            impl State for #ident {}
+
+            impl Known for #ident {
+                const VALUE: super::#enum_ident = super::#enum_ident::#ident;
+            }
+        }
+    });
+    Ok(quote! {
+        pub mod #mod_ident {
+            #[doc = "Type-level value of the field"]
+            pub trait State {}
+
+            #[doc = "A state whose field value is known at compile time"]
+            pub trait Known: State {
+                const VALUE: super::#enum_ident;
+            }
+
+            #[doc = "The field value isn't known"]
+            pub struct Unknown;
+
+            impl State for Unknown {}
+
+            #( #markers )*
+        }
+    })
+}
+
+/// Builds the `XxxTyped` wrapper of a `typestate` register, which carries the
+/// value of each type-state field as a type parameter.
+///
+/// Setters consume the wrapper and return it with the new state, so drivers
+/// can encode ordering constraints (e.g. only switch the clock source once a
+/// prescaler is set) in the states their functions accept. The wrapper
+/// borrows the register mutably, so while it's alive nothing else can write
+/// the register or start tracking it again.
+pub(crate) fn build_typed_struct(register: &Register, idents: &RegisterStructIdents, enum_register_idents: &HashMap<syn::Ident, syn::Path>) -> proc_macro2::TokenStream {
+    let fields: Vec<&RegisterField> = register.fields.iter()
+        .filter(|field| is_typestate_field(register, field))
+        .collect();
+    if fields.is_empty() {
+        return quote!();
+    }
+    let struct_ident = &idents.base;
+    let get_ident = &idents.get;
+    let value_ident = &idents.value;
+    let typed_ident = syn::Ident::new(&format!("{}Typed", struct_ident), struct_ident.span());
+    let mod_ident = {
+        use heck::SnakeCase;
+        syn::Ident::new(&register.ident.to_string().to_snake_case(), register.ident.span())
+    };
+    let params: Vec<syn::Ident> = fields.iter().map(|f| state_param(f)).collect();
+    let state_paths: Vec<proc_macro2::TokenStream> = fields.iter()
+        .map(|f| {
+            let field_mod = f.getter_ident();
+            quote!(#mod_ident::#field_mod)
+        })
+        .collect();
+    let bounds = params.iter().zip(state_paths.iter()).map(|(p, s)| quote!(#p: #s::State));
+    let bounds2 = params.iter().zip(state_paths.iter()).map(|(p, s)| quote!(#p: #s::State));
+    let known_bounds = params.iter().zip(state_paths.iter()).map(|(p, s)| quote!(#p: #s::Known));
+    let unknowns = state_paths.iter().map(|s| quote!(#s::Unknown));
+    let transitions = fields.iter().enumerate().map(|(i, field)| {
+        let setter = field.prefixed_ident("set");
+        let getter = field.getter_ident();
+        let enum_path = &enum_register_idents[&field.ident];
+        let state_path = &state_paths[i];
+        let param = &params[i];
+        let result_params = params.iter().enumerate().map(|(j, p)| if i == j {
+            quote!(S)
+        } else {
+            quote!(#p)
+        });
+        quote! {
+            #[doc = "Write the field, moving it to state `S`"]
+            #[inline(always)]
+            pub fn #setter<S: #state_path::Known>(self, _state: S) -> #typed_ident<'a, #( #result_params ),*> {
+                self.reg.update().#setter(S::VALUE);
+                #typed_ident {
+                    reg: self.reg,
+                    _state: ::core::marker::PhantomData,
+                }
+            }
+
+            #[doc = "Value of the field, known without reading the register"]
+            #[inline(always)]
+            pub fn #getter(&self) -> #enum_path where #param: #state_path::Known {
+                #param::VALUE
+            }
+        }
+    });
+    let checks = fields.iter().zip(params.iter()).map(|(field, param)| {
+        let with = field.prefixed_ident("with");
+        quote!(.#with(#param::VALUE))
+    });
+    let check_mask = fields.iter().fold(0u64, |mask, field| {
+        mask | (field.mask_expr_single().value() << field.shift_expr(0).value())
+    });
+    let register_ty = &register.ty;
+    let try_typed = if fields.iter().any(|f| f.has_property(RegisterPropertyValue::WriteOnly)) {
+        quote!()
+    } else {
+        let params = params.iter();
+        quote! {
+            #[doc = "Track the type-state fields, if the register currently holds the given states"]
+            #[inline]
+            pub fn try_typed<'a, #( #known_bounds ),*>(&'a mut self) -> Option<#typed_ident<'a, #( #params ),*>> {
+                let expected = #value_ident::new() #( #checks )* .to_bits();
+                let mask = #check_mask as #register_ty;
+                if self.load() & mask == expected & mask {
+                    Some(#typed_ident {
+                        reg: self,
+                        _state: ::core::marker::PhantomData,
+                    })
+                } else {
+                    None
+                }
+            }
+        }
+    };
+    let params2 = params.iter();
+    let params3 = params.iter();
+    quote! {
+        #[doc = "View of the register tracking the value of its enum fields in its type"]
+        pub struct #typed_ident<'a, #( #bounds ),*> {
+            reg: &'a mut #struct_ident,
+            _state: ::core::marker::PhantomData<( #( #params2 ),* ,)>,
+        }
+
+        impl<'a, #( #bounds2 ),*> #typed_ident<'a, #( #params3 ),*> {
+            #[doc = "Read the register, e.g. for fields not tracked by type"]
+            #[inline(always)]
+            pub fn get(&self) -> #get_ident {
+                self.reg.get()
+            }
+
+            #[doc = "The underlying register, e.g. to write fields not tracked by type."]
+            #[doc = ""]
+            #[doc = "Unsafe because writing the tracked fields through it leaves their states wrong."]
+            #[inline(always)]
+            pub unsafe fn register(&self) -> &#struct_ident {
+                self.reg
+            }
+
+            #( #transitions )*
+        }
+
+        impl #struct_ident {
+            #[doc = "Start tracking the type-state fields, whose values are unknown until written"]
+            #[inline(always)]
+            pub fn typed<'a>(&'a mut self) -> #typed_ident<'a, #( #unknowns ),*> {
+                #typed_ident {
+                    reg: self,
+                    _state: ::core::marker::PhantomData,
+                }
+            }
+
+            #try_typed
+        }
+    }
+}
@@ -1,17 +1,29 @@
-use crate::{IoRegs, RegisterOrGroup, Register, RegisterGroup, LitVecSize};
+use crate::{Endian, IoRegs, RegisterOrGroup, Register, RegisterGroup, LitVecSize};
 use std::collections::LinkedList;
 use quote::{quote, ToTokens};
-use super::RegisterExt;
+use super::{RegisterContext, RegisterExt};
+use super::bitband::{self, BitBandRegion};
+use super::interrupts;
 use super::casing::ToCasing;
 
 use super::alignment::AlignmentTest;
+use super::snapshot::SnapshotBuilder;
+use super::peripheral::PeripheralToken;
 
 struct UnionBuilder {
     field_definitions: LinkedList<proc_macro2::TokenStream>,
     register_definitions: LinkedList<proc_macro2::TokenStream>,
+    interrupts: Option<proc_macro2::TokenStream>,
+    initializers: LinkedList<proc_macro2::TokenStream>,
     alignment_tests: LinkedList<AlignmentTest>,
+    snapshot: SnapshotBuilder,
+    token: Option<PeripheralToken>,
     union_ident: syn::Ident,
     mod_ident: syn::Ident,
+    path: String,
+    bitband: Option<BitBandRegion>,
+    endian: Endian,
+    backend: bool,
     offset: usize,
     padding_count: usize,
 }
@@ -21,21 +33,41 @@ impl UnionBuilder {
         UnionBuilder {
             field_definitions: LinkedList::new(),
             register_definitions: LinkedList::new(),
+            interrupts: None,
+            initializers: LinkedList::new(),
             alignment_tests: LinkedList::new(),
+            snapshot: SnapshotBuilder::new(&union.name.to_camel_case(), union.has_backend()),
+            token: Some(PeripheralToken::new(&union.name.to_camel_case(), union.location.as_ref().map(|l| &l.location), union.has_backend())),
             union_ident: union.name.to_camel_case(),
             mod_ident: union.name.to_snake_case(),
+            path: union.name.to_snake_case().to_string(),
+            // Bit-banding is only defined for the little endian layout of Cortex-M
+            bitband: union.location
+                .as_ref()
+                .filter(|_| union.endian() != Endian::Big)
+                .and_then(|l| bitband::region_for(l.location.value(), union.byte_extent())),
+            endian: union.endian(),
+            backend: union.has_backend(),
             offset: 0,
             padding_count: 0,
         }
     }
 
-    fn from_group(group: &RegisterGroup) -> Self {
+    fn from_group(group: &RegisterGroup, parent: &UnionBuilder) -> Self {
         UnionBuilder {
             field_definitions: LinkedList::new(),
             register_definitions: LinkedList::new(),
+            interrupts: None,
+            initializers: LinkedList::new(),
             alignment_tests: LinkedList::new(),
+            snapshot: SnapshotBuilder::new(&group.ident.to_camel_case(), parent.backend),
+            token: None,
             union_ident: group.ident.to_camel_case(),
             mod_ident: group.ident.to_snake_case(),
+            path: format!("{}::{}", &parent.path, group.ident.to_snake_case()),
+            bitband: parent.bitband,
+            endian: parent.endian,
+            backend: parent.backend,
             offset: 0,
             padding_count: 0,
         }
@@ -50,23 +82,55 @@ impl UnionBuilder {
             .unwrap()
     }
 
+    /// Type of a member, which takes the backend as a parameter if any.
+    fn generic_ty<T: ToTokens>(&self, ty: &T) -> proc_macro2::TokenStream {
+        if self.backend {
+            quote!(#ty<B>)
+        } else {
+            ty.into_token_stream()
+        }
+    }
+
+    /// Records how to create a member when using a backend, given its offset
+    /// and, for arrays, the distance between elements.
+    fn add_initializer<T: ToTokens>(&mut self, ident: &syn::Ident, ty: &T, offset: u64, stride: u64, count: u64) {
+        if !self.backend {
+            return;
+        }
+        let elements = (0..count).map(|i| {
+            let element_offset = (offset + i * stride) as usize;
+            quote!(#ty::at(backend, offset + #element_offset))
+        });
+        let initializer = if count > 1 {
+            quote!(#ident: [ #( #elements ),* ])
+        } else {
+            quote!(#ident: #( #elements )*)
+        };
+        self.initializers.push_back(initializer);
+    }
+
     fn add_register_group(&mut self, group: &RegisterGroup) -> syn::Result<&mut Self> {
         let group_ident = group.ident.to_snake_case();
-        let mut builder = UnionBuilder::from_group(group);
+        let mut builder = UnionBuilder::from_group(group, self);
         for member in &group.members {
             match member {
                 &RegisterOrGroup::Single(ref reg) => builder.add_register(reg).map(|_| ())?,
                 &RegisterOrGroup::Group(ref group) => builder.add_register_group(group).map(|_| ())?,
             }
         }
+        if let Some(ref family) = group.interrupts {
+            builder.interrupts = Some(interrupts::build_interrupt_fns(group, family, &builder.union_ident, builder.backend));
+        }
         self.advance_to_offset(group.offset.value() as usize, || group.offset.span());
         let group_ty = builder.ty_path();
         let group_ty = &group_ty;
         let group_ident = &group_ident;
-        let field_ty = repeated_type(group_ty.clone().into_token_stream(), group.count.clone());
+        let field_ty = repeated_type(self.generic_ty(group_ty), group.count.clone());
         let field_definition = quote! {
             pub #group_ident: #field_ty
         };
+        let stride = group.byte_length() / group.count_value();
+        self.add_initializer(group_ident, group_ty, group.offset.value(), stride, group.count_value());
         self.offset += group.byte_length() as usize;
         #[cfg(feature = "alignment_tests")]
         {
@@ -81,6 +145,7 @@ impl UnionBuilder {
         }
         self.field_definitions.push_back(field_definition);
         self.register_definitions.push_back(builder.into_token_stream());
+        self.snapshot.add_group(group);
         Ok(self)
     }
 
@@ -92,6 +157,11 @@ impl UnionBuilder {
         if offset == self.offset {
             return;
         }
+        if self.backend {
+            // Nothing to lay out, registers get their offsets when created
+            self.offset = offset;
+            return;
+        }
 
         let padding_ident_s = format!("_padding{}", self.padding_count);
         self.padding_count += 1;
@@ -107,13 +177,20 @@ impl UnionBuilder {
 
     fn add_register(&mut self, reg: &Register) -> syn::Result<&mut Self> {
         self.advance_to_offset(reg.byte_start() as usize, || reg.offset.span());
-        let (idents, struct_definition) = super::build_register_struct(reg)?;
+        let context = RegisterContext {
+            path: format!("{}::{}", &self.path, &reg.ident),
+            bitband: self.bitband,
+            endian: self.endian,
+            backend: self.backend,
+        };
+        let (idents, struct_definition) = super::build_register_struct(reg, &context)?;
         let reg_ident = &reg.ident;
         let reg_ty = &idents.base;
-        let field_ty = repeated_type(reg_ty.clone().into_token_stream(), reg.count.clone());
+        let field_ty = repeated_type(self.generic_ty(reg_ty), reg.count.clone());
         let field_definition = quote! {
             pub #reg_ident: #field_ty
         };
+        self.add_initializer(reg_ident, reg_ty, reg.byte_start(), reg.ty.byte_length(), reg.count_value());
         self.offset += reg.byte_length() as usize;
         #[cfg(feature = "alignment_tests")]
         {
@@ -129,6 +206,7 @@ impl UnionBuilder {
         }
         self.field_definitions.push_back(field_definition);
         self.register_definitions.push_back(struct_definition);
+        self.snapshot.add_register(reg, &idents);
         Ok(self)
     }
 }
@@ -140,14 +218,53 @@ impl ToTokens for UnionBuilder {
         let register_definitions = self.register_definitions.iter();
         let field_definitions = self.field_definitions.iter();
         let tests = self.alignment_tests.iter();
+        let snapshot = &self.snapshot;
+        let token = &self.token;
+        let interrupts = &self.interrupts;
+
+        if self.backend {
+            // Members are created at their offsets rather than laid out in a
+            // repr(C) struct, so there's no layout for alignment tests to check
+            let initializers = self.initializers.iter();
+            tokens.extend(quote! {
+                pub mod #mod_ident {
+                    #( #register_definitions )*
+                    #interrupts
+                    pub struct #union_ident<B> {
+                        #( #field_definitions ),*
+                    }
+                    #snapshot
+                    #token
+
+                    impl<B: ::volatile_cell::backend::RegisterBackend + Copy> #union_ident<B> {
+                        #[doc = "Access the registers through `backend`"]
+                        #[inline]
+                        pub fn new(backend: B) -> #union_ident<B> {
+                            Self::at(backend, 0)
+                        }
 
+                        #[doc = "Access the registers at `offset` bytes from the base of `backend`"]
+                        #[inline]
+                        pub fn at(backend: B, offset: usize) -> #union_ident<B> {
+                            #union_ident {
+                                #( #initializers ),*
+                            }
+                        }
+                    }
+                }
+            });
+            return;
+        }
         tokens.extend(quote! {
             pub mod #mod_ident {
                 #( #register_definitions )*
+                #interrupts
                 #[repr(C)]
                 pub struct #union_ident {
                     #( #field_definitions ),*
                 }
+                #snapshot
+                #token
                 #( #tests )*
             }
         });
@@ -165,6 +282,9 @@ fn repeated_type(ty: proc_macro2::TokenStream, count: Option<LitVecSize>) -> pro
 }
 
 pub(crate) fn build_union(union: &IoRegs) -> syn::Result<proc_macro2::TokenStream> {
+    if let (true, Some(location)) = (union.has_backend(), union.location.as_ref()) {
+        return Err(syn::Error::new(location.location.span(), "a peripheral with a register backend has no location, its base is given by the backend"));
+    }
     let mut builder = UnionBuilder::new(union);
     for reg_or_group in union.registers.iter() {
         match reg_or_group {
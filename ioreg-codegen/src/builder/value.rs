@@ -0,0 +1,134 @@
+use crate::{Register, RegisterField};
+use super::{RegisterFieldExt, build_getter_fn, build_register_enums, setter_field_ty};
+use super::casing::ToCasing;
+use quote::quote;
+use std::collections::HashMap;
+
+fn build_with_fn(register: &Register, field: &RegisterField, enum_register_idents: &HashMap<syn::Ident, syn::Path>) -> proc_macro2::TokenStream {
+    let with_ident = field.prefixed_ident("with");
+    let field_ty = setter_field_ty(field, enum_register_idents);
+    let field_ty = field_ty.as_ref();
+    let register_ty = &register.ty;
+    let mask = field.mask_expr_single();
+    let shift = field.shift_expr(0);
+    if field.count_value() > 1 {
+        use syn::IntSuffix;
+        let count = syn::LitInt::new(field.count_value(), IntSuffix::None, field.offset.span());
+        let single_size = syn::LitInt::new(field.bit_size_single(), IntSuffix::None, field.offset.span());
+        #[cfg(feature = "field_count_checks")]
+        let count_check = quote! {
+            if index >= #count {
+                panic!();
+            }
+        };
+        #[cfg(not(feature = "field_count_checks"))]
+        let count_check = quote!();
+        quote! {
+            #[inline(always)]
+            pub const fn #with_ident(self, index: usize, new_value: #field_ty) -> Self {
+                #count_check
+                let shift = #shift + (#single_size * index);
+                let context_mask: #register_ty = #mask << shift;
+                Self {
+                    value: (self.value & !context_mask) | (((new_value as #register_ty) & #mask) << shift),
+                }
+            }
+        }
+    } else {
+        quote! {
+            #[inline(always)]
+            pub const fn #with_ident(self, new_value: #field_ty) -> Self {
+                let context_mask: #register_ty = #mask << #shift;
+                Self {
+                    value: (self.value & !context_mask) | (((new_value as #register_ty) & #mask) << #shift),
+                }
+            }
+        }
+    }
+}
+
+/// Builds the getter of `field` for a value type.
+///
+/// Values may be decoded from arbitrary bits, e.g. DMA descriptors or
+/// protocol headers, so enum fields whose variants don't cover every value
+/// return the raw value as an error instead of panicking.
+fn build_value_getter_fn(register: &Register, field: &RegisterField, enum_register_idents: &HashMap<syn::Ident, syn::Path>) -> proc_macro2::TokenStream {
+    let enum_path = match enum_register_idents.get(&field.ident) {
+        Some(path) if !field.variants_exhaustive() => path,
+        _ => return build_getter_fn(register, field, enum_register_idents),
+    };
+    let register_ty = &register.ty;
+    let getter_ident = field.getter_ident();
+    let (index_arg, index_expr) = if field.count_value() > 1 {
+        (Some(quote!(index: usize)), Some(quote!(index)))
+    } else {
+        (None, None)
+    };
+    let primitive_expr = field.primitive_extract_expr(index_expr, &quote!(self.value), register.ty);
+    quote! {
+        #[inline(always)]
+        pub fn #getter_ident(&self, #index_arg) -> Result<#enum_path, #register_ty> {
+            use core::convert::TryFrom;
+            let primitive_value: #register_ty = #primitive_expr;
+            #enum_path::try_from(primitive_value)
+        }
+    }
+}
+
+/// Builds a plain `Copy` value type sharing the bit layout of `register`.
+///
+/// Unlike the `Get` and `Update` types, every field gets both a getter and a
+/// `with_*` setter, since access restrictions only apply to the hardware.
+/// Getters of enum fields not covering every value return a `Result`. The
+/// constructors and setters are `const fn`, so values can be computed at
+/// compile time and kept in flash.
+pub(crate) fn build_value_struct(register: &Register, value_ident: &syn::Ident, enum_register_idents: &HashMap<syn::Ident, syn::Path>) -> proc_macro2::TokenStream {
+    let register_ty = &register.ty;
+    let getters = register.fields.iter()
+        .map(|field| build_value_getter_fn(register, field, enum_register_idents));
+    let setters = register.fields.iter()
+        .map(|field| build_with_fn(register, field, enum_register_idents));
+    quote! {
+        #[derive(Clone, Copy, PartialEq, Eq, Default)]
+        #[repr(transparent)]
+        pub struct #value_ident {
+            value: #register_ty,
+        }
+
+        impl #value_ident {
+            #[doc = "Create a value with every field cleared"]
+            #[inline(always)]
+            pub const fn new() -> #value_ident {
+                #value_ident {
+                    value: 0,
+                }
+            }
+
+            #[inline(always)]
+            pub const fn from_bits(bits: #register_ty) -> #value_ident {
+                #value_ident {
+                    value: bits,
+                }
+            }
+
+            #[inline(always)]
+            pub const fn to_bits(&self) -> #register_ty {
+                self.value
+            }
+
+            #( #getters )*
+
+            #( #setters )*
+        }
+    }
+}
+
+pub(crate) fn build_bitfield(register: &Register) -> syn::Result<proc_macro2::TokenStream> {
+    let (mod_definition, enum_register_idents) = build_register_enums(register)?;
+    let value_ident = register.ident.to_camel_case();
+    let value_definition = build_value_struct(register, &value_ident, &enum_register_idents);
+    Ok(quote! {
+        #mod_definition
+        #value_definition
+    })
+}
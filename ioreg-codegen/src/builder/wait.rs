@@ -0,0 +1,53 @@
+use crate::{Register, RegisterPropertyValue};
+use super::RegisterFieldExt;
+use quote::quote;
+
+/// Builds `wait_*` helpers for the readable single-bit fields of `register`,
+/// waiting for the bit to be set:
+///
+/// * `wait_x()` spins until it is,
+/// * `wait_x_timeout(max_polls)` gives up with `Err(Timeout)` after
+///   `max_polls` reads,
+/// * `wait_x_async()` returns a `Future`, which can be woken by an interrupt
+///   with `woken_by`.
+pub(crate) fn build_wait_fns(register: &Register) -> proc_macro2::TokenStream {
+    let waits = register.fields.iter()
+        .filter(|field| {
+            field.bit_size_single() == 1
+                && field.variants.is_none()
+                && !field.has_property(RegisterPropertyValue::WriteOnly)
+        })
+        .map(|field| {
+            let getter = field.getter_ident();
+            let wait_ident = field.prefixed_ident("wait");
+            let timeout_ident = syn::Ident::new(&format!("{}_timeout", wait_ident), wait_ident.span());
+            let async_ident = syn::Ident::new(&format!("{}_async", wait_ident), wait_ident.span());
+            let (index_arg, index) = if field.count_value() > 1 {
+                (quote!(index: usize,), quote!(index))
+            } else {
+                (quote!(), quote!())
+            };
+            quote! {
+                #[doc = "Spin until the bit is set"]
+                #[inline]
+                pub fn #wait_ident(&self, #index_arg) {
+                    while !self.get().#getter(#index) {}
+                }
+
+                #[doc = "Poll until the bit is set, at most `max_polls` times"]
+                #[inline]
+                pub fn #timeout_ident(&self, #index_arg max_polls: u32) -> Result<(), ::volatile_cell::wait::Timeout> {
+                    ::volatile_cell::wait::poll_until(|| self.get().#getter(#index), max_polls)
+                }
+
+                #[doc = "Future resolving once the bit is set"]
+                #[inline]
+                pub fn #async_ident<'a>(&'a self, #index_arg) -> ::volatile_cell::wait::WaitUntil<'a, impl FnMut() -> bool + Unpin + 'a> {
+                    ::volatile_cell::wait::WaitUntil::new(move || self.get().#getter(#index))
+                }
+            }
+        });
+    quote! {
+        #( #waits )*
+    }
+}
@@ -0,0 +1,153 @@
+//! Ahead-of-time generation of `ioregs!` definitions from a build script.
+//!
+//! Definitions are read from `.ioregs` files containing one or more inputs in
+//! the same syntax accepted by the `ioregs!` macro, and written out as Rust
+//! source to be pulled in with `include!`:
+//!
+//! ```ignore
+//! // build.rs
+//! ioreg_codegen::generate::Generator::new()
+//!     .file("src/hal/sam3x/pmc.ioregs")
+//!     .generate()
+//!     .expect("failed to generate register definitions");
+//!
+//! // src/hal/sam3x/pmc.rs
+//! include!(concat!(env!("OUT_DIR"), "/pmc.rs"));
+//! ```
+
+use crate::IoRegsFile;
+use proc_macro2::TokenStream;
+use std::env;
+use std::error;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+#[derive(Debug)]
+pub enum Error {
+    Io(PathBuf, io::Error),
+    Parse(PathBuf, syn::Error),
+    NoOutDir,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            &Error::Io(ref path, ref e) => write!(f, "{}: {}", path.display(), e),
+            &Error::Parse(ref path, ref e) => write!(f, "{}: {}", path.display(), e),
+            &Error::NoOutDir => write!(f, "no output directory given and OUT_DIR is not set"),
+        }
+    }
+}
+
+impl error::Error for Error {}
+
+/// Expands the contents of an `.ioregs` file into the same tokens the
+/// `ioregs!` macro would produce for each definition.
+pub fn expand_str(source: &str) -> syn::Result<TokenStream> {
+    let file: IoRegsFile = syn::parse_str(source)?;
+    let mut output = TokenStream::new();
+    for definition in file.definitions.iter() {
+        output.extend(crate::expand(definition)?);
+    }
+    Ok(output)
+}
+
+pub struct Generator {
+    files: Vec<PathBuf>,
+    out_dir: Option<PathBuf>,
+    rustfmt: bool,
+    rerun_if_changed: bool,
+}
+
+impl Generator {
+    pub fn new() -> Generator {
+        Generator {
+            files: Vec::new(),
+            out_dir: None,
+            rustfmt: true,
+            rerun_if_changed: true,
+        }
+    }
+
+    /// Adds an `.ioregs` file to generate. The output is named after the file
+    /// stem, so `pmc.ioregs` is written to `pmc.rs`.
+    pub fn file<P: AsRef<Path>>(&mut self, path: P) -> &mut Self {
+        self.files.push(path.as_ref().to_path_buf());
+        self
+    }
+
+    /// Sets the output directory. Defaults to `OUT_DIR`.
+    pub fn out_dir<P: AsRef<Path>>(&mut self, path: P) -> &mut Self {
+        self.out_dir = Some(path.as_ref().to_path_buf());
+        self
+    }
+
+    /// Sets whether the output is formatted with `rustfmt` (the default).
+    /// If `rustfmt` can't be run or fails, the output is left unformatted
+    /// and a `cargo:warning` says why.
+    pub fn rustfmt(&mut self, enabled: bool) -> &mut Self {
+        self.rustfmt = enabled;
+        self
+    }
+
+    /// Sets whether `cargo:rerun-if-changed` is printed for each input (the default).
+    pub fn rerun_if_changed(&mut self, enabled: bool) -> &mut Self {
+        self.rerun_if_changed = enabled;
+        self
+    }
+
+    /// Generates every input file, returning the paths written.
+    pub fn generate(&self) -> Result<Vec<PathBuf>, Error> {
+        let out_dir = match self.out_dir {
+            Some(ref dir) => dir.clone(),
+            None => env::var_os("OUT_DIR")
+                .map(PathBuf::from)
+                .ok_or(Error::NoOutDir)?,
+        };
+        let mut written = Vec::with_capacity(self.files.len());
+        for path in self.files.iter() {
+            if self.rerun_if_changed {
+                println!("cargo:rerun-if-changed={}", path.display());
+            }
+            let source = fs::read_to_string(path)
+                .map_err(|e| Error::Io(path.clone(), e))?;
+            let output = expand_str(&source)
+                .map_err(|e| Error::Parse(path.clone(), e))?;
+            let stem = path.file_stem().unwrap_or(path.as_os_str());
+            let out_path = out_dir.join(stem).with_extension("rs");
+            fs::write(&out_path, output.to_string())
+                .map_err(|e| Error::Io(out_path.clone(), e))?;
+            if self.rustfmt {
+                format_file(&out_path);
+            }
+            written.push(out_path);
+        }
+        Ok(written)
+    }
+}
+
+impl Default for Generator {
+    fn default() -> Generator {
+        Generator::new()
+    }
+}
+
+fn format_file(path: &Path) {
+    let rustfmt = env::var_os("RUSTFMT").unwrap_or("rustfmt".into());
+    // Formatting is only a convenience for reading the output, so a missing
+    // or failing rustfmt leaves the unformatted (but identical) tokens behind,
+    // with a warning in the build output.
+    let status = Command::new(&rustfmt)
+        .arg("--edition")
+        .arg("2018")
+        .arg(path)
+        .status();
+    match status {
+        Ok(ref status) if status.success() => {},
+        Ok(status) => println!("cargo:warning=rustfmt failed on {} ({}), leaving it unformatted", path.display(), status),
+        Err(e) => println!("cargo:warning=can't run {:?} to format {}: {}", rustfmt, path.display(), e),
+    }
+}
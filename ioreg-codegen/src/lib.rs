@@ -0,0 +1,1070 @@
+#![recursion_limit="128"]
+#![allow(dead_code)]
+
+extern crate syn;
+extern crate quote;
+extern crate proc_macro2;
+extern crate heck;
+
+mod builder;
+pub mod generate;
+mod register_map;
+pub(crate) mod util;
+
+use syn::{braced, parenthesized, token, Token};
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use std::iter;
+use quote::{ToTokens, quote};
+use util::{ ParseOptional, ParseStreamExt };
+pub(crate) use util::LitVecSize;
+
+fn parse_exact_ident<S: AsRef<str>>(input: ParseStream, value: S) -> syn::Result<syn::Ident> {
+    let value = value.as_ref();
+    input.parse()
+        .and_then(|ident: syn::Ident| if &ident.to_string() == value {
+            Ok(ident)
+        } else {
+            Err(syn::Error::new(ident.span(), format!("expected {}", value)))
+        })
+}
+
+struct IoRegsLocation {
+    location_token: Token![@],
+    location: syn::LitInt,
+}
+
+impl ParseOptional for IoRegsLocation {
+    fn parse_optional(input: ParseStream) -> syn::Result<Option<Self>> {
+        let has_at = input.peek(Token![@]);
+        if !has_at {
+            return Ok(None);
+        }
+        Ok(Some(IoRegsLocation {
+            location_token: input.parse()?,
+            location: input.parse()?,
+        }))
+    }
+}
+
+pub struct IoRegs {
+    pub(crate) name: syn::Ident,
+    pub(crate) location: Option<IoRegsLocation>,
+    pub(crate) equals_token: Token![=],
+    pub(crate) brace_token: token::Brace,
+    pub(crate) registers: Punctuated<RegisterOrGroup, Token![,]>,
+    options: Option<Options<PeripheralOption>>,
+}
+
+impl IoRegs {
+    fn options<'a>(&'a self) -> impl Iterator<Item=&'a PeripheralOptionValue> + 'a {
+        self.options
+            .iter()
+            .flat_map(|options| options.options.iter())
+            .map(|o| &o.value)
+    }
+
+    pub(crate) fn endian(&self) -> Endian {
+        self.options()
+            .filter_map(|o| match o {
+                &PeripheralOptionValue::Endian(endian) => Some(endian),
+                _ => None,
+            })
+            .last()
+            .unwrap_or(Endian::Native)
+    }
+
+    /// Whether the registers are accessed through a `RegisterBackend` given
+    /// when creating the peripheral, rather than mapped in memory.
+    pub(crate) fn has_backend(&self) -> bool {
+        self.options().any(|o| *o == PeripheralOptionValue::Backend)
+    }
+
+    /// Number of bytes spanned by the registers, from the peripheral base to
+    /// the end of the last register.
+    pub(crate) fn byte_extent(&self) -> u64 {
+        self.registers
+            .iter()
+            .map(|r| r.byte_offset() + r.byte_length())
+            .max()
+            .unwrap_or(0)
+    }
+}
+
+impl Parse for IoRegs {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let content;
+        Ok(IoRegs {
+            name: input.parse()?,
+            location: input.parse_optional()?,
+            equals_token: input.parse()?,
+            brace_token: braced!(content in input),
+            registers: content.parse_terminated(RegisterOrGroup::parse)?,
+            options: input.parse_optional()?,
+        })
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub(crate) enum RegisterType {
+    Reg8,
+    Reg16,
+    Reg24,
+    Reg32,
+    Reg40,
+    Reg48,
+    Reg56,
+    Reg64,
+}
+
+impl RegisterType {
+    pub(crate) fn byte_length(self) -> u64 {
+        use RegisterType::*;
+        match self {
+            Reg8 => 1,
+            Reg16 => 2,
+            Reg24 => 3,
+            Reg32 => 4,
+            Reg40 => 5,
+            Reg48 => 6,
+            Reg56 => 7,
+            Reg64 => 8,
+        }
+    }
+
+    /// Type and number of the cells making up a register without a native
+    /// width, e.g. three `reg16` cells for a `reg48`.
+    pub(crate) fn cells(self) -> Option<(RegisterType, usize)> {
+        use RegisterType::*;
+        match self {
+            Reg24 => Some((Reg8, 3)),
+            Reg40 => Some((Reg8, 5)),
+            Reg48 => Some((Reg16, 3)),
+            Reg56 => Some((Reg8, 7)),
+            _ => None,
+        }
+    }
+
+    /// Atomic type with the same width as the register, used for exclusive
+    /// (LDREX/STREX) updates, along with its `target_has_atomic` width.
+    pub(crate) fn atomic_ty(self) -> Option<(proc_macro2::TokenStream, &'static str)> {
+        use RegisterType::*;
+        match self {
+            Reg8 => Some((quote!(::core::sync::atomic::AtomicU8), "8")),
+            Reg16 => Some((quote!(::core::sync::atomic::AtomicU16), "16")),
+            Reg32 => Some((quote!(::core::sync::atomic::AtomicU32), "32")),
+            Reg64 => Some((quote!(::core::sync::atomic::AtomicU64), "64")),
+            _ => None,
+        }
+    }
+}
+
+impl ToTokens for RegisterType {
+    fn to_tokens(&self, output: &mut proc_macro2::TokenStream) {
+        use RegisterType::*;
+        let tokens = match *self {
+            Reg8 => quote!(u8),
+            Reg16 => quote!(u16),
+            Reg24 | Reg32 => quote!(u32),
+            Reg40 | Reg48 | Reg56 | Reg64 => quote!(u64),
+        };
+        output.extend(tokens);
+    }
+}
+
+impl Parse for RegisterType {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let ty: syn::Ident = input.parse()?;
+        match ty.to_string().as_ref() {
+            "reg8" => Ok(RegisterType::Reg8),
+            "reg16" => Ok(RegisterType::Reg16),
+            "reg24" => Ok(RegisterType::Reg24),
+            "reg32" => Ok(RegisterType::Reg32),
+            "reg40" => Ok(RegisterType::Reg40),
+            "reg48" => Ok(RegisterType::Reg48),
+            "reg56" => Ok(RegisterType::Reg56),
+            "reg64" => Ok(RegisterType::Reg64),
+            _ => Err(syn::Error::new(ty.span(), format!("Invalid ioregs register type: {}", &ty))),
+        }
+    }
+}
+
+pub(crate) enum RegisterOrGroup {
+    Single(Register),
+    Group(RegisterGroup),
+}
+
+impl RegisterOrGroup {
+    #[inline]
+    pub(crate) fn byte_offset(&self) -> u64 {
+        match self {
+            &RegisterOrGroup::Single(ref reg) => reg.offset.value(),
+            &RegisterOrGroup::Group(ref group) => group.offset.value(),
+        }
+    }
+
+    #[inline]
+    pub(crate) fn byte_length(&self) -> u64 {
+        match self {
+            &RegisterOrGroup::Single(ref reg) => reg.byte_length(),
+            &RegisterOrGroup::Group(ref group) => group.byte_length(),
+        }
+    }
+}
+
+impl Parse for RegisterOrGroup {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        // TODO: improve the error messages that this would generate to indicate all options
+        if InterruptFamily::peek(input) {
+            Ok(RegisterOrGroup::Group(input.call(RegisterGroup::parse_interrupts)?))
+        } else if RegisterGroup::peek(input) {
+            Ok(RegisterOrGroup::Group(input.parse()?))
+        } else {
+            Ok(RegisterOrGroup::Single(input.parse()?))
+        }
+    }
+}
+
+pub(crate) struct RegisterGroup {
+    pub(crate) offset: syn::LitInt,
+    pub(crate) arrow_token: Token![=>],
+    pub(crate) group_ident: syn::Ident,
+    pub(crate) ident: syn::Ident,
+    pub(crate) count: Option<LitVecSize>,
+    pub(crate) brace_token: token::Brace,
+    pub(crate) members: Punctuated<RegisterOrGroup, Token![,]>,
+    pub(crate) interrupts: Option<InterruptFamily>,
+    pub(crate) options: Option<RegisterOptions>,
+}
+
+impl RegisterGroup {
+    fn peek(input: ParseStream) -> bool {
+        let fork = input.fork();
+        fork.parse::<syn::LitInt>().is_ok()
+            && fork.parse::<Token![=>]>().is_ok()
+            && fork.call(|s| parse_exact_ident(s, "group")).is_ok()
+    }
+
+    pub(crate) fn count_value(&self) -> u64 {
+        self.count
+            .as_ref()
+            .map(|c| c.value())
+            .unwrap_or(1)
+    }
+
+    pub(crate) fn byte_length(&self) -> u64 {
+        // Up to the end of the last member, gaps between members included
+        let single_size: u64 = self.members
+            .iter()
+            .map(|m| m.byte_offset() + m.byte_length())
+            .max()
+            .unwrap_or(0);
+        single_size * self.count_value()
+    }
+
+    pub(crate) fn restore_order(&self) -> u64 {
+        self.options.iter()
+            .flat_map(|o| o.options.iter())
+            .filter_map(|o| match o.value {
+                RegisterOptionValue::RestoreOrder(order) => Some(order),
+                _ => None,
+            })
+            .next()
+            .unwrap_or(0)
+    }
+}
+
+/// Groups only take `restore_order`, ordering the restore of the whole group
+/// in the `Snapshot` of the peripheral containing it.
+pub(crate) fn check_group_options(options: &Option<RegisterOptions>) -> syn::Result<()> {
+    for option in options.iter().flat_map(|o| o.options.iter()) {
+        match option.value {
+            RegisterOptionValue::RestoreOrder(..) => {},
+            _ => return Err(syn::Error::new(option.span, "only restore_order can be set on a group")),
+        }
+    }
+    Ok(())
+}
+
+impl Parse for RegisterGroup {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let brace_content;
+        let ret = RegisterGroup {
+            offset: input.parse()?,
+            arrow_token: input.parse()?,
+            group_ident: input.call(|s| parse_exact_ident(s, "group"))?,
+            ident: input.parse()?,
+            count: input.call(ParseOptional::parse_optional)?,
+            brace_token: braced!(brace_content in input),
+            members: brace_content.parse_terminated(RegisterOrGroup::parse)?,
+            interrupts: None,
+            options: input.parse_optional()?,
+        };
+        check_group_options(&ret.options)?;
+        Ok(ret)
+    }
+}
+
+/// An interrupt register family, e.g.
+/// `0x40 => interrupts reg32 irq { 0 => RxReady, 1 => TxEmpty }`, naming
+/// bits of the enable, disable, mask and status registers laid out one after
+/// the other. Without any named bits, the flags cover the whole register.
+///
+/// Registers laid out otherwise take the offset of each from the start of
+/// the family, e.g.
+/// `0x60 => interrupts reg32 irq { ... }: (enable: 0x0, disable: 0x4, status: 0x8, mask: 0xC)`.
+pub(crate) struct InterruptFamily {
+    pub(crate) ty: RegisterType,
+    pub(crate) flags: Punctuated<RegisterVariant, Token![,]>,
+}
+
+impl InterruptFamily {
+    fn peek(input: ParseStream) -> bool {
+        let fork = input.fork();
+        fork.parse::<syn::LitInt>().is_ok()
+            && fork.parse::<Token![=>]>().is_ok()
+            && fork.call(|s| parse_exact_ident(s, "interrupts")).is_ok()
+    }
+
+    /// Bits covered by the flags.
+    pub(crate) fn mask(&self) -> u64 {
+        if self.flags.is_empty() {
+            return u64::max_value() >> (64 - self.ty.byte_length() * 8);
+        }
+        self.flags.iter().fold(0, |mask, flag| mask | (1 << flag.value.value()))
+    }
+}
+
+/// Offset of one of the registers of an interrupt register family, as in
+/// `enable: 0x0`.
+struct InterruptOffset {
+    ident: syn::Ident,
+    offset: syn::LitInt,
+}
+
+impl Parse for InterruptOffset {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let ident: syn::Ident = input.parse()?;
+        match ident.to_string().as_ref() {
+            "enable" | "disable" | "mask" | "status" => {},
+            _ => return Err(syn::Error::new(ident.span(), format!("Invalid interrupt register: {}, expected enable, disable, mask or status", ident))),
+        }
+        let _: Token![:] = input.parse()?;
+        Ok(InterruptOffset {
+            ident: ident,
+            offset: input.parse()?,
+        })
+    }
+}
+
+/// Byte offsets of the enable, disable, mask and status registers of a
+/// family, one after the other unless `layout` gives each of them.
+fn interrupt_offsets(ty: RegisterType, ident: &syn::Ident, layout: &Option<Options<InterruptOffset>>) -> syn::Result<[u64; 4]> {
+    let names = ["enable", "disable", "mask", "status"];
+    let layout = match *layout {
+        Some(ref layout) => layout,
+        None => {
+            let width = ty.byte_length();
+            return Ok([0, width, 2 * width, 3 * width]);
+        },
+    };
+    let mut offsets = [None; 4];
+    for entry in layout.options.iter() {
+        let index = names.iter().position(|n| entry.ident == n).unwrap();
+        if offsets[index].is_some() {
+            return Err(syn::Error::new(entry.ident.span(), format!("the offset of {} is given more than once", entry.ident)));
+        }
+        offsets[index] = Some(entry.offset.value());
+    }
+    let mut ret = [0; 4];
+    for (i, offset) in offsets.iter().enumerate() {
+        match *offset {
+            Some(offset) => ret[i] = offset,
+            None => return Err(syn::Error::new(ident.span(), format!("the offset of {} is missing from the layout of {}", names[i], ident))),
+        }
+    }
+    let mut sorted: Vec<(u64, &str)> = ret.iter().cloned().zip(names.iter().cloned()).collect();
+    sorted.sort();
+    for pair in sorted.windows(2) {
+        if pair[1].0 < pair[0].0 + ty.byte_length() {
+            return Err(syn::Error::new(ident.span(), format!("the {} and {} registers of {} overlap", pair[0].1, pair[1].1, ident)));
+        }
+    }
+    Ok(ret)
+}
+
+impl RegisterGroup {
+    /// Parses an interrupt register family into a group of its four
+    /// registers, each with a single `bits` field spanning the register.
+    fn parse_interrupts(input: ParseStream) -> syn::Result<Self> {
+        use syn::IntSuffix;
+        let offset: syn::LitInt = input.parse()?;
+        let arrow_token: Token![=>] = input.parse()?;
+        let group_ident = input.call(|s| parse_exact_ident(s, "interrupts"))?;
+        let ty: RegisterType = input.parse()?;
+        let ident: syn::Ident = input.parse()?;
+        let content;
+        let brace_token = braced!(content in input);
+        let flags: Punctuated<RegisterVariant, Token![,]> = content.parse_terminated(RegisterVariant::parse)?;
+        let bit_size = ty.byte_length() * 8;
+        if ty.cells().is_some() {
+            return Err(syn::Error::new(ident.span(), "interrupt registers must have a native width"));
+        }
+        if let Some(flag) = flags.iter().find(|f| f.value.value() >= bit_size) {
+            return Err(syn::Error::new(flag.value.span(), format!("interrupt bit {} is outside of a {}-bit register", flag.value.value(), bit_size)));
+        }
+        let span = ident.span();
+        let register = |name: &str, offset: u64, access: RegisterPropertyValue, snapshot: bool| {
+            let mut options = Punctuated::new();
+            if !snapshot {
+                options.push(RegisterOption {
+                    value: RegisterOptionValue::NoSnapshot,
+                    span: span,
+                });
+            }
+            let mut fields = Punctuated::new();
+            fields.push(RegisterField {
+                offset: RegisterFieldOffset::BitRange(LitIntRange {
+                    start: syn::LitInt::new(0, IntSuffix::None, span),
+                    range_sep: Token![..](span),
+                    end: syn::LitInt::new(bit_size - 1, IntSuffix::None, span),
+                    inverted: false,
+                }),
+                arrow_token: Token![=>](span),
+                ident: syn::Ident::new("bits", span),
+                count: None,
+                variants: None,
+                properties: Some(RegisterProperties {
+                    colon_token: Token![:](span),
+                    properties: RegisterPropertyList::Single(RegisterProperty {
+                        value: access,
+                        span: span,
+                    }),
+                }),
+            });
+            RegisterOrGroup::Single(Register {
+                offset: syn::LitInt::new(offset, IntSuffix::None, span),
+                arrow_token: Token![=>](span),
+                ty: ty,
+                ident: syn::Ident::new(name, span),
+                count: None,
+                brace_token: brace_token,
+                fields: fields,
+                options: if snapshot {
+                    None
+                } else {
+                    Some(Options {
+                        colon_token: Token![:](span),
+                        paren_token: None,
+                        options: options,
+                    })
+                },
+            })
+        };
+        let layout: Option<Options<InterruptOffset>> = input.parse_optional()?;
+        let offsets = interrupt_offsets(ty, &ident, &layout)?;
+        let mut registers = vec![
+            (offsets[0], register("ier", offsets[0], RegisterPropertyValue::WriteOnly, true)),
+            (offsets[1], register("idr", offsets[1], RegisterPropertyValue::WriteOnly, true)),
+            (offsets[2], register("imr", offsets[2], RegisterPropertyValue::ReadOnly, true)),
+            // Reading the status often acknowledges it, so leave it to the driver
+            (offsets[3], register("isr", offsets[3], RegisterPropertyValue::ReadOnly, false)),
+        ];
+        // Members are laid out in order of their offsets
+        registers.sort_by_key(|&(offset, _)| offset);
+        let mut members = Punctuated::new();
+        for (_, member) in registers {
+            members.push(member);
+        }
+        Ok(RegisterGroup {
+            offset: offset,
+            arrow_token: arrow_token,
+            group_ident: group_ident,
+            ident: ident,
+            count: None,
+            brace_token: brace_token,
+            members: members,
+            interrupts: Some(InterruptFamily {
+                ty: ty,
+                flags: flags,
+            }),
+            options: None,
+        })
+    }
+}
+
+struct Register {
+    offset: syn::LitInt,
+    arrow_token: Token![=>],
+    ty: RegisterType,
+    ident: syn::Ident,
+    count: Option<LitVecSize>,
+    brace_token: token::Brace,
+    fields: Punctuated<RegisterField, Token![,]>,
+    options: Option<RegisterOptions>,
+}
+
+impl Register {
+    pub(crate) fn count_value(&self) -> u64 {
+        self.count
+            .as_ref()
+            .map(|c| c.value())
+            .unwrap_or(1)
+    }
+
+    pub(crate) fn byte_length(&self) -> u64 {
+        self.ty.byte_length() * self.count_value()
+    }
+
+    pub(crate) fn options<'a>(&'a self) -> Box<dyn Iterator<Item=&'a RegisterOptionValue> + 'a> {
+        match self.options {
+            Some(ref options) => Box::new(options.options.iter().map(|o| &o.value)),
+            None => Box::new(iter::empty()),
+        }
+    }
+
+    pub(crate) fn has_option(&self, value: RegisterOptionValue) -> bool {
+        self.options().any(|o| *o == value)
+    }
+
+    pub(crate) fn restore_order(&self) -> u64 {
+        self.options()
+            .filter_map(|o| match o {
+                &RegisterOptionValue::RestoreOrder(order) => Some(order),
+                _ => None,
+            })
+            .next()
+            .unwrap_or(0)
+    }
+}
+
+#[derive(Clone, PartialEq, Eq)]
+pub(crate) enum RegisterOptionValue {
+    NoSnapshot,
+    RestoreOrder(u64),
+    Typestate,
+    Bytes,
+    Halfwords,
+    HighFirst,
+}
+
+struct RegisterOption {
+    value: RegisterOptionValue,
+    span: proc_macro2::Span,
+}
+
+impl Parse for RegisterOption {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let ident: syn::Ident = input.parse()?;
+        use RegisterOptionValue::*;
+        let value = match ident.to_string().as_ref() {
+            "no_snapshot" => NoSnapshot,
+            "typestate" => Typestate,
+            "bytes" => Bytes,
+            "halfwords" => Halfwords,
+            "high_first" => HighFirst,
+            "restore_order" => {
+                let _: Token![=] = input.parse()?;
+                let order: syn::LitInt = input.parse()?;
+                RestoreOrder(order.value())
+            },
+            _ => return Err(syn::Error::new(ident.span(), format!("Invalid ioregs register option: {}", ident))),
+        };
+        Ok(RegisterOption {
+            value: value,
+            span: ident.span(),
+        })
+    }
+}
+
+/// Options following a register or peripheral body, either a single
+/// `: option` or a list `: (option, ...)`.
+struct Options<T> {
+    colon_token: Token![:],
+    paren_token: Option<token::Paren>,
+    options: Punctuated<T, Token![,]>,
+}
+
+type RegisterOptions = Options<RegisterOption>;
+
+impl<T: Parse> ParseOptional for Options<T> {
+    fn parse_optional(input: ParseStream) -> syn::Result<Option<Self>> {
+        let has_colon = input.peek(Token![:]);
+        if !has_colon {
+            return Ok(None);
+        }
+        let colon_token = input.parse()?;
+        if input.peek(token::Paren) {
+            let content;
+            Ok(Some(Options {
+                colon_token: colon_token,
+                paren_token: Some(parenthesized!(content in input)),
+                options: content.parse_terminated(T::parse)?,
+            }))
+        } else {
+            let mut options = Punctuated::new();
+            options.push(input.parse()?);
+            Ok(Some(Options {
+                colon_token: colon_token,
+                paren_token: None,
+                options: options,
+            }))
+        }
+    }
+}
+
+/// Byte order of the registers of a peripheral. Registers without an
+/// explicit order use that of the target.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Endian {
+    Native,
+    Little,
+    Big,
+}
+
+#[derive(Clone, PartialEq, Eq)]
+pub(crate) enum PeripheralOptionValue {
+    Endian(Endian),
+    Backend,
+}
+
+struct PeripheralOption {
+    value: PeripheralOptionValue,
+    span: proc_macro2::Span,
+}
+
+impl Parse for PeripheralOption {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let ident: syn::Ident = input.parse()?;
+        use PeripheralOptionValue::*;
+        let value = match ident.to_string().as_ref() {
+            "endian" => {
+                let _: Token![=] = input.parse()?;
+                let order: syn::Ident = input.parse()?;
+                match order.to_string().as_ref() {
+                    "big" => Endian(self::Endian::Big),
+                    "little" => Endian(self::Endian::Little),
+                    _ => return Err(syn::Error::new(order.span(), format!("Invalid ioregs endianness: {}", order))),
+                }
+            },
+            "backend" => Backend,
+            _ => return Err(syn::Error::new(ident.span(), format!("Invalid ioregs peripheral option: {}", ident))),
+        };
+        Ok(PeripheralOption {
+            value: value,
+            span: ident.span(),
+        })
+    }
+}
+
+impl Parse for Register {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let content;
+        Ok(Register {
+            offset: input.parse()?,
+            arrow_token: input.parse()?,
+            ty: input.parse()?,
+            ident: input.parse()?,
+            count: input.call(ParseOptional::parse_optional)?,
+            brace_token: braced!(content in input),
+            fields: content.parse_terminated(RegisterField::parse)?,
+            options: input.parse_optional()?,
+        })
+    }
+}
+
+#[derive(Clone)]
+pub(crate) struct LitIntRange {
+    pub(crate) start: syn::LitInt,
+    pub(crate) range_sep: Token![..],
+    pub(crate) end: syn::LitInt,
+    pub(crate) inverted: bool,
+}
+
+impl ToTokens for LitIntRange {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        self.start.to_tokens(tokens);
+        self.range_sep.to_tokens(tokens);
+        self.end.to_tokens(tokens);
+    }
+}
+
+impl LitIntRange {
+    pub(crate) fn bit_size(&self) -> u64 {
+        self.end.value() - self.start.value() + 1
+    }
+
+    pub(crate) fn span(&self) -> proc_macro2::Span {
+        // TODO: nightly could provide better support here
+        self.start.span()
+    }
+}
+
+impl Parse for LitIntRange {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        use std::mem;
+        let mut ret = LitIntRange {
+            start: input.parse()?,
+            range_sep: input.parse()?,
+            end: input.parse()?,
+            inverted: false,
+        };
+        if ret.start.value() > ret.end.value() {
+            mem::swap(&mut ret.start, &mut ret.end);
+            ret.inverted = true;
+        }
+        Ok(ret)
+    }
+}
+
+#[derive(Clone)]
+enum RegisterFieldOffset {
+    Bit(syn::LitInt),
+    BitRange(LitIntRange),
+}
+
+impl ToTokens for RegisterFieldOffset {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        use RegisterFieldOffset::*;
+        match self {
+            Bit(ref v) => v.to_tokens(tokens),
+            BitRange(ref range) => range.to_tokens(tokens),
+        }
+    }
+}
+
+impl RegisterFieldOffset {
+    pub(crate) fn bit_size(&self) -> u64 {
+        match self {
+            &RegisterFieldOffset::Bit(..) => 1,
+            &RegisterFieldOffset::BitRange(ref range) => range.bit_size(),
+        }
+    }
+
+    pub(crate) fn span(&self) -> proc_macro2::Span {
+        match self {
+            &RegisterFieldOffset::Bit(ref v) => v.span(),
+            &RegisterFieldOffset::BitRange(ref range) => range.span(),
+        }
+    }
+}
+
+impl Parse for RegisterFieldOffset {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        // TODO: improve the error messages that this would generate to indicate all options
+        if input.fork().parse::<LitIntRange>().is_ok() {
+            Ok(RegisterFieldOffset::BitRange(input.parse()?))
+        } else {
+            Ok(RegisterFieldOffset::Bit(input.parse()?))
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RegisterPropertyValue {
+    SetToClear,
+    WriteOnly,
+    ReadOnly,
+    ReadWrite
+}
+
+impl RegisterPropertyValue {
+    fn is_access_modifier(self) -> bool {
+        use RegisterPropertyValue::*;
+        match self {
+            WriteOnly => true,
+            ReadOnly => true,
+            ReadWrite => true,
+            _ => false,
+        }
+    }
+}
+
+struct RegisterProperty {
+    value: RegisterPropertyValue,
+    span: proc_macro2::Span,
+}
+
+impl RegisterProperty {
+    #[inline(always)]
+    fn is_access_modifier(&self) -> bool {
+        self.value.is_access_modifier()
+    }
+}
+
+impl Parse for RegisterProperty {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let ident: syn::Ident = input.parse()?;
+        use RegisterPropertyValue::*;
+        let value = match ident.to_string().as_ref() {
+            "set_to_clear" => Ok(SetToClear),
+            "wo" => Ok(WriteOnly),
+            "ro" => Ok(ReadOnly),
+            "rw" => Ok(ReadWrite),
+            _ => Err(syn::Error::new(ident.span(), format!("Invalid ioregs register property: {}", ident))),
+        };
+        value.map(|v| RegisterProperty {
+            value: v,
+            span: ident.span(),
+        })
+    }
+}
+
+enum RegisterPropertyList {
+    Single(RegisterProperty),
+    Multiple {
+        paren_token: token::Paren,
+        properties: Punctuated<RegisterProperty, Token![,]>,
+    }
+}
+
+impl RegisterPropertyList {
+    fn parse_multiple(input: ParseStream) -> syn::Result<RegisterPropertyList> {
+        let content: syn::parse::ParseBuffer<'_>;
+        let paren_token: token::Paren = parenthesized!(content in input);
+        let properties: Punctuated<RegisterProperty, Token![,]> =
+            content.parse_terminated(RegisterProperty::parse)?;
+        let ret = RegisterPropertyList::Multiple {
+            paren_token: paren_token,
+            properties: properties,
+        };
+        ret.validate()?;
+        Ok(ret)
+    }
+
+    fn span(&self) -> proc_macro2::Span {
+        // TODO: improve span handling for Multiple case
+        match self {
+            &RegisterPropertyList::Single(ref prop) => prop.span,
+            &RegisterPropertyList::Multiple { ref paren_token, .. } => paren_token.span,
+        }
+    }
+
+    fn validate(&self) -> syn::Result<()> {
+        let access_modifiers = self.iter()
+            .filter(|&prop| prop.is_access_modifier())
+            .count();
+        if access_modifiers > 1 {
+            return Err(syn::Error::new(self.span(), format!("more than one access modifier found for register field")));
+        }
+        let set_to_clear_conflicts = self.iter()
+            .filter(|&prop| prop.value == RegisterPropertyValue::SetToClear || prop.value == RegisterPropertyValue::ReadOnly)
+            .count();
+        if set_to_clear_conflicts >= 2 {
+            return Err(syn::Error::new(self.span(), format!("set_to_clear and ro cannot be set on the same register field")));
+        }
+        Ok(())
+    }
+
+    pub(crate) fn iter<'a>(&'a self) -> Box<Iterator<Item=&'a RegisterProperty> + 'a> {
+        match self {
+            &RegisterPropertyList::Single(ref prop) => Box::new(iter::once(prop)),
+            &RegisterPropertyList::Multiple { ref properties, .. } => Box::new(properties.iter()),
+        }
+    }
+}
+
+impl Parse for RegisterPropertyList {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let has_paren = input.peek(token::Paren);
+        if has_paren {
+            RegisterPropertyList::parse_multiple(input)
+        } else {
+            Ok(RegisterPropertyList::Single(input.parse()?))
+        }
+    }
+}
+
+struct RegisterProperties {
+    colon_token: Token![:],
+    properties: RegisterPropertyList,
+}
+
+fn parse_optional_register_properties(input: ParseStream) -> syn::Result<Option<RegisterProperties>> {
+    let has_colon = input.peek(Token![:]);
+    if !has_colon {
+        return Ok(None);
+    }
+    Ok(Some(RegisterProperties {
+        colon_token: input.parse()?,
+        properties: input.parse()?,
+    }))
+}
+
+pub(crate) struct RegisterVariant {
+    pub(crate) value: syn::LitInt,
+    arrow_token: Token![=>],
+    pub(crate) ident: syn::Ident,
+}
+
+impl Parse for RegisterVariant {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        Ok(RegisterVariant {
+            value: input.parse()?,
+            arrow_token: input.parse()?,
+            ident: input.parse()?,
+        })
+    }
+}
+
+struct RegisterVariants {
+    brace_token: token::Brace,
+    variants: Punctuated<RegisterVariant, Token![,]>,
+}
+
+fn parse_optional_register_variants(input: ParseStream) -> syn::Result<Option<RegisterVariants>> {
+    let has_brace = input.peek(token::Brace);
+    if !has_brace {
+        return Ok(None)
+    }
+    let content;
+    Ok(Some(RegisterVariants {
+        brace_token: braced!(content in input),
+        variants: content.parse_terminated(RegisterVariant::parse)?,
+    }))
+}
+
+struct RegisterField {
+    offset: RegisterFieldOffset,
+    arrow_token: Token![=>],
+    ident: syn::Ident,
+    count: Option<LitVecSize>,
+    variants: Option<RegisterVariants>,
+    properties: Option<RegisterProperties>,
+}
+
+impl RegisterField {
+    pub(crate) fn count_value(&self) -> u64 {
+        self.count
+            .as_ref()
+            .map(|c| c.value())
+            .unwrap_or(1)
+    }
+}
+
+impl Parse for RegisterField {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        Ok(RegisterField {
+            offset: input.parse()?,
+            arrow_token: input.parse()?,
+            ident: input.parse()?,
+            count: input.parse_optional()?,
+            variants: input.call(parse_optional_register_variants)?,
+            properties: input.call(parse_optional_register_properties)?,
+        })
+    }
+}
+
+/// A sequence of `ioregs!` definitions, as found in an `.ioregs` file.
+pub struct IoRegsFile {
+    pub definitions: Vec<IoRegs>,
+}
+
+impl Parse for IoRegsFile {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut definitions = Vec::new();
+        while !input.is_empty() {
+            definitions.push(input.parse()?);
+            if input.peek(Token![;]) {
+                let _: Token![;] = input.parse()?;
+            }
+        }
+        Ok(IoRegsFile {
+            definitions: definitions,
+        })
+    }
+}
+
+/// A standalone bit layout, e.g. `reg32 dma_ctrl { 0..15 => btsize, ... }`,
+/// which is built like a register without any of the MMIO access types.
+pub struct BitField {
+    register: Register,
+}
+
+impl Parse for BitField {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let content;
+        let ty: RegisterType = input.parse()?;
+        let ident: syn::Ident = input.parse()?;
+        let register = Register {
+            offset: syn::LitInt::new(0, syn::IntSuffix::None, ident.span()),
+            arrow_token: Token![=>](ident.span()),
+            ty: ty,
+            ident: ident,
+            count: None,
+            brace_token: braced!(content in input),
+            fields: content.parse_terminated(RegisterField::parse)?,
+            options: None,
+        };
+        Ok(BitField {
+            register: register,
+        })
+    }
+}
+
+/// A single peripheral instance in a `peripherals!` definition, e.g.
+/// `PIOA: pio::PioPeripheral = unsafe { &PIO_A }`. Without an initializer
+/// the instance is created with the token's `steal`.
+pub(crate) struct PeripheralInstance {
+    pub(crate) ident: syn::Ident,
+    pub(crate) colon_token: Token![:],
+    pub(crate) ty: syn::Type,
+    pub(crate) init: Option<(Token![=], syn::Expr)>,
+}
+
+impl Parse for PeripheralInstance {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        Ok(PeripheralInstance {
+            ident: input.parse()?,
+            colon_token: input.parse()?,
+            ty: input.parse()?,
+            init: if input.peek(Token![=]) {
+                Some((input.parse()?, input.parse()?))
+            } else {
+                None
+            },
+        })
+    }
+}
+
+/// Device-level set of peripheral tokens, e.g.
+/// `Peripherals { PMC: pmc::PmcPeripheral, ... }`.
+pub struct Peripherals {
+    pub(crate) ident: syn::Ident,
+    pub(crate) brace_token: token::Brace,
+    pub(crate) instances: Punctuated<PeripheralInstance, Token![,]>,
+}
+
+impl Parse for Peripherals {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let content;
+        Ok(Peripherals {
+            ident: input.parse()?,
+            brace_token: braced!(content in input),
+            instances: content.parse_terminated(PeripheralInstance::parse)?,
+        })
+    }
+}
+
+/// Expands a parsed `ioregs!` definition into the generated register types.
+pub fn expand(input: &IoRegs) -> syn::Result<proc_macro2::TokenStream> {
+    builder::union::build_union(input)
+}
+
+/// Expands a `#[register_map]` module into the same register types as the
+/// equivalent `ioregs!` definition, in a module with the visibility and
+/// attributes of `module`.
+pub fn expand_register_map(args: proc_macro2::TokenStream, module: &syn::ItemMod) -> syn::Result<proc_macro2::TokenStream> {
+    register_map::expand(args, module)
+}
+
+/// Expands a parsed `bitfield!` definition into its value type.
+pub fn expand_bitfield(input: &BitField) -> syn::Result<proc_macro2::TokenStream> {
+    builder::value::build_bitfield(&input.register)
+}
+
+/// Expands a parsed `peripherals!` definition into the device-level
+/// `Peripherals` struct.
+pub fn expand_peripherals(input: &Peripherals) -> syn::Result<proc_macro2::TokenStream> {
+    builder::peripheral::build_peripherals(input)
+}
@@ -0,0 +1,373 @@
+//! Lowering of `#[register_map]` modules, which describe registers with
+//! ordinary Rust items, into the same definitions `ioregs!` parses:
+//!
+//! ```ignore
+//! #[register_map(at = 0x400E_0600)]
+//! mod pmc {
+//!     struct Pmc {
+//!         #[offset(0x20)] mor: Reg32<Mor>,
+//!         #[offset(0x68)] st: Reg32<St>,
+//!     }
+//!
+//!     struct Mor {
+//!         #[bits(0)] moscxten: bool,
+//!         #[bits(4..6)] moscrcf: Moscrcf,
+//!     }
+//!
+//!     enum Moscrcf { MHz_4 = 0, MHz_8 = 1, MHz_12 = 2 }
+//!
+//!     struct St {
+//!         #[bits(0, ro)] moscxts: bool,
+//!     }
+//! }
+//! ```
+//!
+//! The struct named after the module is the peripheral. Its fields, marked
+//! with `#[offset(..)]`, are either registers (`Reg8<T>` .. `Reg64<T>`, where
+//! `T` is a struct of `#[bits(..)]` fields) or groups (a struct of more
+//! `#[offset(..)]` fields), optionally in arrays. Register options go in
+//! `#[options(..)]` (only `restore_order` on groups), and field properties
+//! after the bits. Fields with an enum type get its variants, others are
+//! `bool` for single bits or the smallest integer type holding theirs, and
+//! arrays of fields split their bits evenly. The module keeps its visibility
+//! and attributes.
+
+use crate::*;
+use heck::CamelCase;
+use std::collections::HashMap;
+use syn::parse::Parser;
+use syn::spanned::Spanned;
+
+enum Item<'a> {
+    Struct(&'a syn::ItemStruct),
+    Enum(&'a syn::ItemEnum),
+}
+
+struct Items<'a> {
+    items: HashMap<String, Item<'a>>,
+}
+
+impl<'a> Items<'a> {
+    fn new(items: &'a [syn::Item]) -> syn::Result<Self> {
+        let mut ret = HashMap::new();
+        for item in items {
+            let (ident, item) = match item {
+                &syn::Item::Struct(ref s) => (&s.ident, Item::Struct(s)),
+                &syn::Item::Enum(ref e) => (&e.ident, Item::Enum(e)),
+                _ => return Err(syn::Error::new(item.span(), "only structs and enums are supported in a register map")),
+            };
+            if ret.insert(ident.to_string(), item).is_some() {
+                return Err(syn::Error::new(ident.span(), format!("{} is defined more than once in the register map", ident)));
+            }
+        }
+        Ok(Items {
+            items: ret,
+        })
+    }
+
+    fn get(&self, ident: &syn::Ident) -> Option<&Item<'a>> {
+        self.items.get(&ident.to_string())
+    }
+
+    fn get_struct(&self, ident: &syn::Ident) -> syn::Result<&'a syn::ItemStruct> {
+        match self.get(ident) {
+            Some(&Item::Struct(s)) => Ok(s),
+            _ => Err(syn::Error::new(ident.span(), format!("no struct {} in register map", ident))),
+        }
+    }
+}
+
+/// `#[register_map(..)]` arguments: an `at = address` location followed by
+/// peripheral options.
+struct MapArgs {
+    location: Option<IoRegsLocation>,
+    options: Punctuated<PeripheralOption, Token![,]>,
+}
+
+impl Parse for MapArgs {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut location = None;
+        if input.fork().call(|s| parse_exact_ident(s, "at")).is_ok() {
+            let at = input.call(|s| parse_exact_ident(s, "at"))?;
+            let _: Token![=] = input.parse()?;
+            location = Some(IoRegsLocation {
+                location_token: Token![@](at.span()),
+                location: input.parse()?,
+            });
+            if !input.is_empty() {
+                let _: Token![,] = input.parse()?;
+            }
+        }
+        Ok(MapArgs {
+            location: location,
+            options: input.parse_terminated(PeripheralOption::parse)?,
+        })
+    }
+}
+
+fn attr_named<'a>(attrs: &'a [syn::Attribute], name: &str) -> Option<&'a syn::Attribute> {
+    attrs.iter().find(|a| a.path.segments.len() == 1 && a.path.segments[0].ident == name)
+}
+
+fn check_attrs(attrs: &[syn::Attribute], allowed: &[&str]) -> syn::Result<()> {
+    let unknown = attrs.iter()
+        .find(|a| !allowed.iter().chain(&["doc"]).any(|name| a.path.segments.len() == 1 && a.path.segments[0].ident == name));
+    match unknown {
+        Some(attr) => Err(syn::Error::new(attr.span(), "unsupported attribute in register map")),
+        None => Ok(()),
+    }
+}
+
+/// Parses the parenthesized arguments of `attr`.
+fn parse_attr_args<T, F>(attr: &syn::Attribute, parser: F) -> syn::Result<T> where
+    F: FnOnce(ParseStream) -> syn::Result<T>,
+{
+    let parse = |input: ParseStream| {
+        let content;
+        parenthesized!(content in input);
+        parser(&content)
+    };
+    parse.parse2(attr.tts.clone())
+}
+
+/// Splits an array type into its element type and length.
+fn array_parts(ty: &syn::Type) -> syn::Result<(&syn::Type, Option<LitVecSize>)> {
+    match ty {
+        &syn::Type::Array(ref array) => match array.len {
+            syn::Expr::Lit(syn::ExprLit { lit: syn::Lit::Int(ref count), .. }) => Ok((&array.elem, Some(LitVecSize {
+                bracket_token: array.bracket_token,
+                count: count.clone(),
+            }))),
+            ref len => Err(syn::Error::new(len.span(), "register map array lengths must be integer literals")),
+        },
+        ty => Ok((ty, None)),
+    }
+}
+
+fn type_ident(ty: &syn::Type) -> Option<&syn::PathSegment> {
+    match ty {
+        &syn::Type::Path(syn::TypePath { qself: None, ref path }) if path.segments.len() == 1 => Some(&path.segments[0]),
+        _ => None,
+    }
+}
+
+/// For a `RegN<T>` type, the register type and the ident of `T`.
+fn register_type(segment: &syn::PathSegment) -> Option<(RegisterType, &syn::Ident)> {
+    let ty = match segment.ident.to_string().as_ref() {
+        "Reg8" => RegisterType::Reg8,
+        "Reg16" => RegisterType::Reg16,
+        "Reg24" => RegisterType::Reg24,
+        "Reg32" => RegisterType::Reg32,
+        "Reg40" => RegisterType::Reg40,
+        "Reg48" => RegisterType::Reg48,
+        "Reg56" => RegisterType::Reg56,
+        "Reg64" => RegisterType::Reg64,
+        _ => return None,
+    };
+    let args = match segment.arguments {
+        syn::PathArguments::AngleBracketed(ref args) if args.args.len() == 1 => &args.args[0],
+        _ => return None,
+    };
+    match args {
+        &syn::GenericArgument::Type(ref layout) => type_ident(layout).map(|s| (ty, &s.ident)),
+        _ => None,
+    }
+}
+
+fn lower_members(fields: &syn::Fields, items: &Items) -> syn::Result<Punctuated<RegisterOrGroup, Token![,]>> {
+    fields.iter()
+        .map(|field| lower_member(field, items))
+        .collect()
+}
+
+fn lower_member(field: &syn::Field, items: &Items) -> syn::Result<RegisterOrGroup> {
+    check_attrs(&field.attrs, &["offset", "options"])?;
+    let ident = field.ident.clone()
+        .ok_or_else(|| syn::Error::new(field.span(), "register map fields must be named"))?;
+    let offset = attr_named(&field.attrs, "offset")
+        .ok_or_else(|| syn::Error::new(ident.span(), format!("missing #[offset(..)] on {}", ident)))
+        .and_then(|attr| parse_attr_args(attr, |input| input.parse::<syn::LitInt>()))?;
+    let options = attr_named(&field.attrs, "options")
+        .map(|attr| parse_attr_args(attr, |input| Ok(Options {
+            colon_token: Token![:](attr.span()),
+            paren_token: Some(token::Paren(attr.span())),
+            options: input.parse_terminated(RegisterOption::parse)?,
+        })))
+        .map_or(Ok(None), |r| r.map(Some))?;
+    let (ty, count) = array_parts(&field.ty)?;
+    let segment = type_ident(ty)
+        .ok_or_else(|| syn::Error::new(ty.span(), "expected a register or group type"))?;
+    match register_type(segment) {
+        Some((register_ty, layout)) => {
+            let layout = items.get_struct(layout)?;
+            Ok(RegisterOrGroup::Single(Register {
+                offset: offset,
+                arrow_token: Token![=>](ident.span()),
+                ty: register_ty,
+                ident: ident,
+                count: count,
+                brace_token: token::Brace(layout.span()),
+                fields: lower_fields(layout, items)?,
+                options: options,
+            }))
+        },
+        None => {
+            check_group_options(&options)?;
+            let group = items.get_struct(&segment.ident)?;
+            Ok(RegisterOrGroup::Group(RegisterGroup {
+                offset: offset,
+                arrow_token: Token![=>](ident.span()),
+                group_ident: syn::Ident::new("group", ident.span()),
+                ident: ident,
+                count: count,
+                brace_token: token::Brace(group.span()),
+                members: lower_members(&group.fields, items)?,
+                interrupts: None,
+                options: options,
+            }))
+        },
+    }
+}
+
+fn lower_fields(layout: &syn::ItemStruct, items: &Items) -> syn::Result<Punctuated<RegisterField, Token![,]>> {
+    layout.fields.iter()
+        .map(|field| lower_field(field, items))
+        .collect()
+}
+
+fn lower_field(field: &syn::Field, items: &Items) -> syn::Result<RegisterField> {
+    check_attrs(&field.attrs, &["bits"])?;
+    let ident = field.ident.clone()
+        .ok_or_else(|| syn::Error::new(field.span(), "register map fields must be named"))?;
+    let bits = attr_named(&field.attrs, "bits")
+        .ok_or_else(|| syn::Error::new(ident.span(), format!("missing #[bits(..)] on {}", ident)))?;
+    let (offset, properties) = parse_attr_args(bits, |input| {
+        let offset: RegisterFieldOffset = input.parse()?;
+        let mut properties: Punctuated<RegisterProperty, Token![,]> = Punctuated::new();
+        while !input.is_empty() {
+            let _: Token![,] = input.parse()?;
+            if input.is_empty() {
+                break;
+            }
+            properties.push(input.parse()?);
+        }
+        Ok((offset, properties))
+    })?;
+    let properties = if properties.is_empty() {
+        None
+    } else {
+        let list = RegisterPropertyList::Multiple {
+            paren_token: token::Paren(bits.span()),
+            properties: properties,
+        };
+        list.validate()?;
+        Some(RegisterProperties {
+            colon_token: Token![:](bits.span()),
+            properties: list,
+        })
+    };
+    let (ty, count) = array_parts(&field.ty)?;
+    let variants = match type_ident(ty).and_then(|s| items.get(&s.ident)) {
+        Some(&Item::Enum(e)) => Some(lower_variants(e)?),
+        _ => None,
+    };
+    check_field_type(ty, &offset, count.as_ref(), variants.is_some())?;
+    Ok(RegisterField {
+        offset: offset,
+        arrow_token: Token![=>](ident.span()),
+        ident: ident,
+        count: count,
+        variants: variants,
+        properties: properties,
+    })
+}
+
+/// Checks the declared type of a field, with `ty` the element type of arrays,
+/// against its bits.
+fn check_field_type(ty: &syn::Type, offset: &RegisterFieldOffset, count: Option<&LitVecSize>, is_enum: bool) -> syn::Result<()> {
+    let bits = offset.bit_size();
+    if let Some(count) = count {
+        if count.value() == 0 || bits % count.value() != 0 {
+            return Err(syn::Error::new(count.count.span(), format!("{} bits can't be split evenly into {} fields", bits, count.value())));
+        }
+    }
+    if is_enum {
+        return Ok(());
+    }
+    let size = bits / count.map_or(1, |c| c.value());
+    let expected = match size {
+        1 => "bool",
+        2..=8 => "u8",
+        9..=16 => "u16",
+        17..=32 => "u32",
+        33..=64 => "u64",
+        size => return Err(syn::Error::new(offset.span(), format!("Invalid register field size: {}", size))),
+    };
+    match type_ident(ty) {
+        Some(segment) if segment.arguments.is_empty() && segment.ident == expected => Ok(()),
+        _ => Err(syn::Error::new(ty.span(), format!("expected {} or an enum for a field of size {}", expected, size))),
+    }
+}
+
+fn lower_variants(e: &syn::ItemEnum) -> syn::Result<RegisterVariants> {
+    let mut next = 0;
+    let variants = e.variants.iter()
+        .map(|variant| {
+            let value = match variant.discriminant {
+                Some((_, syn::Expr::Lit(syn::ExprLit { lit: syn::Lit::Int(ref value), .. }))) => value.clone(),
+                Some((_, ref expr)) => return Err(syn::Error::new(expr.span(), "register map discriminants must be integer literals")),
+                None => syn::LitInt::new(next, syn::IntSuffix::None, variant.ident.span()),
+            };
+            next = value.value() + 1;
+            Ok(RegisterVariant {
+                value: value,
+                arrow_token: Token![=>](variant.ident.span()),
+                ident: variant.ident.clone(),
+            })
+        })
+        .collect::<syn::Result<_>>()?;
+    Ok(RegisterVariants {
+        brace_token: e.brace_token,
+        variants: variants,
+    })
+}
+
+/// Lowers a `#[register_map(args)]` module into the `ioregs!` definition it
+/// describes.
+pub(crate) fn lower(args: proc_macro2::TokenStream, module: &syn::ItemMod) -> syn::Result<IoRegs> {
+    let args: MapArgs = syn::parse2(args)?;
+    let (brace_token, items) = module.content.as_ref()
+        .ok_or_else(|| syn::Error::new(module.span(), "register maps must be inline modules"))?;
+    let items = Items::new(items)?;
+    let peripheral_ident = syn::Ident::new(&module.ident.to_string().to_camel_case(), module.ident.span());
+    let peripheral = items.get_struct(&peripheral_ident)?;
+    let options = if args.options.is_empty() {
+        None
+    } else {
+        Some(Options {
+            colon_token: Token![:](module.ident.span()),
+            paren_token: Some(token::Paren(module.ident.span())),
+            options: args.options,
+        })
+    };
+    Ok(IoRegs {
+        name: module.ident.clone(),
+        location: args.location,
+        equals_token: Token![=](module.ident.span()),
+        brace_token: *brace_token,
+        registers: lower_members(&peripheral.fields, &items)?,
+        options: options,
+    })
+}
+
+/// Expands a `#[register_map(args)]` module, giving the generated module the
+/// visibility and outer attributes of `module`.
+pub(crate) fn expand(args: proc_macro2::TokenStream, module: &syn::ItemMod) -> syn::Result<proc_macro2::TokenStream> {
+    let mut generated: syn::ItemMod = syn::parse2(crate::expand(&lower(args, module)?)?)?;
+    generated.vis = module.vis.clone();
+    generated.attrs = module.attrs.iter()
+        .cloned()
+        .chain(generated.attrs)
+        .collect();
+    Ok(generated.into_token_stream())
+}
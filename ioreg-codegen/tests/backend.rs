@@ -0,0 +1,38 @@
+use ioreg_codegen::generate::expand_str;
+
+fn error(source: &str) -> String {
+    expand_str(source).err().expect("expected an error").to_string()
+}
+
+#[test]
+fn rejects_location() {
+    assert_eq!(
+        error("DEV @ 0x4000_0000 = { 0x0 => reg32 ctrl { 0 => on, }, }: backend"),
+        "a peripheral with a register backend has no location, its base is given by the backend",
+    );
+}
+
+#[test]
+fn rejects_lanes() {
+    assert_eq!(
+        error("DEV = { 0x0 => reg32 prio { 0..31 => level[4], }: bytes, }: backend"),
+        "byte and halfword lanes aren't supported with a register backend",
+    );
+}
+
+#[test]
+fn rejects_endian_of_composite_registers() {
+    assert_eq!(
+        error("DEV = { 0x0 => reg24 addr { 0..23 => value, }, }: (backend, endian = big)"),
+        "endian is only supported for 8, 16, 32 and 64 bit registers with a register backend",
+    );
+}
+
+#[test]
+fn converts_byte_order() {
+    let output = expand_str("DEV = { 0x0 => reg32 ctrl { 0..31 => value, }, }: (backend, endian = big)")
+        .unwrap()
+        .to_string();
+    assert!(output.contains("u32 :: from_be ( ( self . backend . read ( self . offset , 32u32 ) as u32 ) )"));
+    assert!(output.contains("( value ) . to_be ( ) as u64"));
+}
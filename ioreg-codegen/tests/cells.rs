@@ -0,0 +1,31 @@
+use ioreg_codegen::generate::expand_str;
+
+fn cell_access_order(options: &str, access: &str) -> Vec<usize> {
+    let source = format!("CELLS = {{ 0x0 => reg48 stamp {{ 0..47 => ticks, }}{}, }}", options);
+    let output = expand_str(&source).unwrap().to_string();
+    let mut positions: Vec<(usize, usize)> = (0..3)
+        .map(|i| {
+            let pattern = format!("self . value [ {}usize ] . {}", i, access);
+            (output.find(&pattern).expect(&pattern), i)
+        })
+        .collect();
+    positions.sort();
+    positions.into_iter().map(|(_, i)| i).collect()
+}
+
+#[test]
+fn cells_accessed_low_first_by_default() {
+    assert_eq!(cell_access_order("", "get"), vec![0, 1, 2]);
+    assert_eq!(cell_access_order("", "set"), vec![0, 1, 2]);
+}
+
+#[test]
+fn cells_accessed_high_first() {
+    assert_eq!(cell_access_order(": high_first", "get"), vec![2, 1, 0]);
+    assert_eq!(cell_access_order(": high_first", "set"), vec![2, 1, 0]);
+}
+
+#[test]
+fn high_first_requires_cells() {
+    assert!(expand_str("CELLS = { 0x0 => reg32 ctrl { 0 => enable, }: high_first, }").is_err());
+}
@@ -0,0 +1,30 @@
+use ioreg_codegen::generate::expand_str;
+
+fn getter(output: &str, name: &str) -> String {
+    let start = output.find(&format!("pub fn {} (", name)).unwrap();
+    let body = &output[start..];
+    body[..body.find('}').unwrap()].to_string()
+}
+
+#[test]
+fn extracts_use_constant_shift_and_mask() {
+    let output = expand_str("EXTRACT = { 0x0 => reg32 ctrl { 0..3 => low, 4..7 => mid, 24..31 => top, 8..15 => lane[2], }, }")
+        .unwrap()
+        .to_string();
+    assert!(!output.contains("bextr"));
+    assert!(getter(&output, "low").contains("self . value & 15"));
+    assert!(getter(&output, "mid").contains("( self . value >> 4 ) & 15"));
+    // Nothing above the topmost field, so no mask
+    let top = getter(&output, "top");
+    assert!(top.contains("self . value >> 24"));
+    assert!(!top.contains("& 255"));
+    assert!(getter(&output, "lane").contains("( self . value >> ( 8 + ( 4 * index ) ) ) & 15"));
+}
+
+#[test]
+fn full_width_masks_do_not_overflow() {
+    let output = expand_str("WIDE = { 0x0 => reg64 counter { 0..63 => value, }, }")
+        .unwrap()
+        .to_string();
+    assert!(output.contains("18446744073709551615"));
+}
@@ -0,0 +1,78 @@
+use ioreg_codegen::generate::{expand_str, Generator};
+use ioreg_codegen::IoRegs;
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+const SOURCE: &'static str = r#"
+// PMC subset
+PMC @ 0x400E0600 = {
+    0x30 => reg32 mckr {
+        0..1 => css {
+            0 => SlowClk,
+            1 => MainClk,
+            2 => PllaClk,
+            3 => UpplClk,
+        },
+        4..6 => pres,
+    },
+    0x68 => reg32 st {
+        3 => mckrdy: ro,
+    },
+};
+
+EEFC = {
+    0x0 => reg32 fmr {
+        0..11 => fws
+    },
+}
+"#;
+
+fn scratch_dir(name: &str) -> PathBuf {
+    let dir = env::temp_dir().join(format!("ioreg-codegen-{}-{}", name, std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+#[test]
+fn expands_every_definition() {
+    let macro_input: IoRegs = syn::parse_str(&SOURCE[..SOURCE.find("};").unwrap() + 1]).unwrap();
+    let expected = ioreg_codegen::expand(&macro_input).unwrap().to_string();
+    let output = expand_str(SOURCE).unwrap().to_string();
+    assert!(output.starts_with(&expected));
+    assert!(output.contains("pub mod eefc"));
+}
+
+#[test]
+fn generated_file_matches_macro_output() {
+    let dir = scratch_dir("generate");
+    let input = dir.join("sam3x.ioregs");
+    fs::write(&input, SOURCE).unwrap();
+    let written = Generator::new()
+        .file(&input)
+        .out_dir(&dir)
+        .rustfmt(false)
+        .rerun_if_changed(false)
+        .generate()
+        .unwrap();
+    assert_eq!(written, vec![dir.join("sam3x.rs")]);
+    let output = fs::read_to_string(&written[0]).unwrap();
+    assert_eq!(output, expand_str(SOURCE).unwrap().to_string());
+    syn::parse_file(&output).unwrap();
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn reports_parse_errors_with_path() {
+    let dir = scratch_dir("errors");
+    let input = dir.join("broken.ioregs");
+    fs::write(&input, "BROKEN = { 0x0 => reg12 r { 0 => f } }").unwrap();
+    let err = Generator::new()
+        .file(&input)
+        .out_dir(&dir)
+        .rerun_if_changed(false)
+        .generate()
+        .unwrap_err();
+    assert!(err.to_string().contains("broken.ioregs"));
+    fs::remove_dir_all(&dir).unwrap();
+}
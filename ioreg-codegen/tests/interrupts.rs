@@ -0,0 +1,30 @@
+use ioreg_codegen::generate::expand_str;
+
+fn layout_error(layout: &str) -> String {
+    let source = format!("IRQ = {{ 0x0 => interrupts reg32 irq {{}}: ({}), }}", layout);
+    expand_str(&source).unwrap_err().to_string()
+}
+
+#[test]
+fn layout_needs_every_register() {
+    let error = layout_error("enable: 0x0, disable: 0x4, status: 0x8");
+    assert!(error.contains("mask is missing"), "{}", error);
+}
+
+#[test]
+fn layout_rejects_repeated_register() {
+    let error = layout_error("enable: 0x0, enable: 0x4, mask: 0x8, status: 0xC");
+    assert!(error.contains("enable is given more than once"), "{}", error);
+}
+
+#[test]
+fn layout_rejects_overlapping_registers() {
+    let error = layout_error("enable: 0x0, disable: 0x2, mask: 0x8, status: 0xC");
+    assert!(error.contains("enable and disable registers of irq overlap"), "{}", error);
+}
+
+#[test]
+fn layout_rejects_unknown_register() {
+    let error = layout_error("enable: 0x0, disable: 0x4, mask: 0x8, pending: 0xC");
+    assert!(error.contains("Invalid interrupt register: pending"), "{}", error);
+}
@@ -0,0 +1,80 @@
+use ioreg_codegen::expand_register_map;
+
+fn expand(args: &str, module: &str) -> syn::Result<String> {
+    let module: syn::ItemMod = syn::parse_str(module).unwrap();
+    expand_register_map(args.parse().unwrap(), &module).map(|output| output.to_string())
+}
+
+fn field_error(ty: &str, bits: &str) -> String {
+    let module = format!("mod dev {{
+        struct Dev {{ #[offset(0x0)] ctrl: Reg32<Ctrl>, }}
+        struct Ctrl {{ #[bits({})] value: {}, }}
+        enum Mode {{ Off, On }}
+    }}", bits, ty);
+    expand("", &module).err().expect("expected an error").to_string()
+}
+
+#[test]
+fn accepts_matching_field_types() {
+    for &(ty, bits) in &[("bool", "0"), ("u8", "0..1"), ("u8", "0..7"), ("u16", "0..8"), ("u32", "0..31"),
+                         ("[bool; 4]", "4..7"), ("[u8; 2]", "0..15"), ("Mode", "0..1"), ("[Mode; 2]", "0..3")] {
+        let module = format!("mod dev {{
+            struct Dev {{ #[offset(0x0)] ctrl: Reg32<Ctrl>, }}
+            struct Ctrl {{ #[bits({})] value: {}, }}
+            enum Mode {{ Off, On }}
+        }}", bits, ty);
+        assert!(expand("", &module).is_ok(), "{} on bits {}", ty, bits);
+    }
+}
+
+#[test]
+fn rejects_integer_too_wide() {
+    assert_eq!(field_error("u16", "8..15"), "expected u8 or an enum for a field of size 8");
+}
+
+#[test]
+fn rejects_integer_for_single_bit() {
+    assert_eq!(field_error("u8", "1"), "expected bool or an enum for a field of size 1");
+}
+
+#[test]
+fn rejects_bool_for_several_bits() {
+    assert_eq!(field_error("bool", "0..3"), "expected u8 or an enum for a field of size 4");
+}
+
+#[test]
+fn rejects_array_with_wrong_count() {
+    assert_eq!(field_error("[bool; 4]", "0..7"), "expected u8 or an enum for a field of size 2");
+    assert_eq!(field_error("[bool; 3]", "0..7"), "8 bits can't be split evenly into 3 fields");
+}
+
+#[test]
+fn rejects_unknown_type() {
+    assert_eq!(field_error("Other", "0..3"), "expected u8 or an enum for a field of size 4");
+}
+
+#[test]
+fn rejects_duplicate_items() {
+    let module = "mod dev {
+        struct Dev { #[offset(0x0)] ctrl: Reg32<Ctrl>, }
+        struct Ctrl { #[bits(0)] on: bool, }
+        enum Ctrl { Off, On }
+    }";
+    assert_eq!(expand("", module).err().expect("expected an error").to_string(), "Ctrl is defined more than once in the register map");
+}
+
+#[test]
+fn keeps_visibility_and_attributes() {
+    let module = "
+        /// The device
+        #[allow(dead_code)]
+        pub(crate) mod dev {
+            struct Dev { #[offset(0x0)] ctrl: Reg32<Ctrl>, }
+            struct Ctrl { #[bits(0)] on: bool, }
+        }";
+    let output = expand("", module).unwrap();
+    assert!(output.starts_with("# [ doc = \" The device\" ] # [ allow ( dead_code ) ] pub ( crate ) mod dev {"), "{}", output);
+
+    let output = expand("", "mod dev { struct Dev { #[offset(0x0)] ctrl: Reg32<Ctrl>, } struct Ctrl { #[bits(0)] on: bool, } }").unwrap();
+    assert!(output.starts_with("mod dev {"), "{}", output);
+}
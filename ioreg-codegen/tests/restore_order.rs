@@ -0,0 +1,41 @@
+use ioreg_codegen::generate::expand_str;
+
+/// The `restore` function of the peripheral, which follows those of its
+/// groups.
+fn restore_body(output: &str) -> &str {
+    let start = output.rfind("pub fn restore (").unwrap();
+    &output[start..]
+}
+
+#[test]
+fn groups_restore_in_their_order() {
+    let output = expand_str("
+        ORDER = {
+            0x0 => group chan[2] {
+                0x0 => reg32 period { 0..15 => ticks, },
+            }: restore_order = 2,
+            0x8 => reg32 enable { 0 => on, }: restore_order = 1,
+            0xc => reg32 mode { 0..3 => div, },
+        }")
+        .unwrap()
+        .to_string();
+    let body = restore_body(&output);
+    let mode = body.find("p . mode").unwrap();
+    let enable = body.find("p . enable").unwrap();
+    let chan = body.find("chan :: restore").unwrap();
+    assert!(mode < enable);
+    assert!(enable < chan);
+}
+
+#[test]
+fn groups_reject_register_options() {
+    let error = expand_str("
+        ORDER = {
+            0x0 => group chan[2] {
+                0x0 => reg32 period { 0..15 => ticks, },
+            }: no_snapshot,
+        }")
+        .err()
+        .unwrap();
+    assert_eq!(error.to_string(), "only restore_order can be set on a group");
+}
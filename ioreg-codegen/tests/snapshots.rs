@@ -0,0 +1,173 @@
+//! Golden-file tests of what `ioregs!` expands to, so that changes to the
+//! generated API show up in review.
+//!
+//! Every definition in `tests/snapshots/corpus` and every `ioregs!`
+//! invocation under `zinc/src/hal` is expanded, formatted with `rustfmt` and
+//! compared to its snapshot in `tests/snapshots`. Run with
+//! `UPDATE_SNAPSHOTS=1` to write the current output instead. `rustfmt`, or
+//! the one `RUSTFMT` names, must be available: without it the test fails.
+//!
+//! The snapshots are of the default features, which the test is limited to.
+#![cfg(all(feature = "field_count_checks", not(any(feature = "alignment_tests", feature = "debug_impls", feature = "binary_log", feature = "tracing"))))]
+
+use ioreg_codegen::IoRegs;
+use ioreg_codegen::generate::expand_str;
+use proc_macro2::{TokenStream, TokenTree};
+use std::env;
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+struct Case {
+    name: String,
+    output: TokenStream,
+}
+
+fn snapshot_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/snapshots")
+}
+
+fn files_with_extension(dir: &Path, extension: &str, files: &mut Vec<PathBuf>) {
+    for entry in fs::read_dir(dir).unwrap() {
+        let path = entry.unwrap().path();
+        if path.is_dir() {
+            files_with_extension(&path, extension, files);
+        } else if path.extension().map_or(false, |e| e == extension) {
+            files.push(path);
+        }
+    }
+}
+
+fn corpus_cases(cases: &mut Vec<Case>) {
+    let mut files = Vec::new();
+    files_with_extension(&snapshot_dir().join("corpus"), "ioregs", &mut files);
+    for path in files {
+        let source = fs::read_to_string(&path).unwrap();
+        let output = expand_str(&source)
+            .unwrap_or_else(|e| panic!("{}: {}", path.display(), e));
+        cases.push(Case {
+            name: path.file_stem().unwrap().to_string_lossy().into_owned(),
+            output: output,
+        });
+    }
+}
+
+/// Finds `ioregs!(...)` invocations in `tokens`, at any depth.
+fn find_invocations(tokens: TokenStream, found: &mut Vec<TokenStream>) {
+    let trees: Vec<TokenTree> = tokens.into_iter().collect();
+    for (i, tree) in trees.iter().enumerate() {
+        match tree {
+            TokenTree::Ident(ident) if ident == "ioregs" => {
+                match (trees.get(i + 1), trees.get(i + 2)) {
+                    (Some(TokenTree::Punct(bang)), Some(TokenTree::Group(body))) if bang.as_char() == '!' => {
+                        found.push(body.stream());
+                    },
+                    _ => {},
+                }
+            },
+            TokenTree::Group(group) => find_invocations(group.stream(), found),
+            _ => {},
+        }
+    }
+}
+
+fn zinc_cases(cases: &mut Vec<Case>) {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("../zinc/src");
+    let mut files = Vec::new();
+    files_with_extension(&root.join("hal"), "rs", &mut files);
+    for path in files {
+        let source = fs::read_to_string(&path).unwrap();
+        let tokens: TokenStream = source.parse()
+            .unwrap_or_else(|e| panic!("{}: {:?}", path.display(), e));
+        let mut invocations = Vec::new();
+        find_invocations(tokens, &mut invocations);
+        let module = path.strip_prefix(&root).unwrap().with_extension("");
+        let module = module.to_string_lossy().replace('/', "-");
+        for invocation in invocations {
+            let definition: IoRegs = syn::parse2(invocation)
+                .unwrap_or_else(|e| panic!("{}: {}", path.display(), e));
+            let output = ioreg_codegen::expand(&definition)
+                .unwrap_or_else(|e| panic!("{}: {}", path.display(), e));
+            let name = output.clone().into_iter()
+                .find_map(|t| match t {
+                    TokenTree::Ident(ref ident) if ident != "pub" && ident != "mod" => Some(ident.to_string()),
+                    _ => None,
+                })
+                .unwrap();
+            cases.push(Case {
+                name: format!("zinc-{}-{}", module, name),
+                output: output,
+            });
+        }
+    }
+}
+
+fn rustfmt(source: &str) -> String {
+    let rustfmt = env::var_os("RUSTFMT").unwrap_or("rustfmt".into());
+    let mut child = Command::new(&rustfmt)
+        .args(&["--edition", "2018"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap_or_else(|e| panic!("can't run {:?} to format the expansions: {}", rustfmt, e));
+    child.stdin.take().unwrap().write_all(source.as_bytes()).unwrap();
+    let output = child.wait_with_output().unwrap();
+    assert!(output.status.success(), "rustfmt failed on the expansion");
+    String::from_utf8(output.stdout).unwrap()
+}
+
+fn first_difference(expected: &str, actual: &str) -> String {
+    let line = expected.lines()
+        .zip(actual.lines())
+        .position(|(e, a)| e != a)
+        .unwrap_or_else(|| expected.lines().count().min(actual.lines().count()));
+    format!(
+        "line {}:\n  expected: {}\n  actual:   {}",
+        line + 1,
+        expected.lines().nth(line).unwrap_or("<end>"),
+        actual.lines().nth(line).unwrap_or("<end>"),
+    )
+}
+
+#[test]
+fn expansions_match_snapshots() {
+    let mut cases = Vec::new();
+    corpus_cases(&mut cases);
+    zinc_cases(&mut cases);
+    assert!(!cases.is_empty());
+
+    let update = env::var_os("UPDATE_SNAPSHOTS").is_some();
+    let mut failures = Vec::new();
+    for case in cases.iter() {
+        let actual = rustfmt(&case.output.to_string());
+        let path = snapshot_dir().join(&case.name).with_extension("rs");
+        if update {
+            fs::write(&path, &actual).unwrap();
+            continue;
+        }
+        match fs::read_to_string(&path) {
+            Ok(ref expected) if *expected == actual => {},
+            Ok(ref expected) => failures.push(format!("{} changed, {}", case.name, first_difference(expected, &actual))),
+            Err(_) => failures.push(format!("{} has no snapshot", case.name)),
+        }
+    }
+
+    // Snapshots of definitions that are gone
+    for entry in fs::read_dir(snapshot_dir()).unwrap() {
+        let path = entry.unwrap().path();
+        if path.extension().map_or(true, |e| e != "rs") {
+            continue;
+        }
+        let name = path.file_stem().unwrap().to_string_lossy().into_owned();
+        if !cases.iter().any(|c| c.name == name) {
+            if update {
+                fs::remove_file(&path).unwrap();
+            } else {
+                failures.push(format!("{} has a snapshot but no definition", name));
+            }
+        }
+    }
+
+    assert!(failures.is_empty(), "{}\n\nrun with UPDATE_SNAPSHOTS=1 to update the snapshots", failures.join("\n"));
+}
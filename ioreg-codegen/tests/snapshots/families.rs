@@ -0,0 +1,2189 @@
+pub mod timer {
+    pub mod mode {}
+    #[repr(C)]
+    pub struct Mode {
+        value: ::volatile_cell::VolatileCell<u32>,
+    }
+    impl Mode {
+        #[inline(always)]
+        fn load(&self) -> u32 {
+            let value = self.value.get();
+            value
+        }
+        #[inline(always)]
+        fn store(&self, value: u32) {
+            self.value.set(value);
+        }
+        #[doc = "Create a new updater"]
+        #[inline(always)]
+        pub fn update<'a>(&'a self) -> ModeUpdate<'a> {
+            ModeUpdate::new(self)
+        }
+        #[doc = "Create an updater whose read-modify-write happens inside the critical section `cs`"]
+        #[inline(always)]
+        pub fn update_in<'a, C: ::volatile_cell::sync::CriticalSection>(
+            &'a self,
+            _cs: &'a C,
+        ) -> ModeUpdate<'a> {
+            ModeUpdate::new(self)
+        }
+        #[doc = "Update the register with exclusive loads and stores (LDREX/STREX), retrying `f` until no other access intervened"]
+        #[doc = ""]
+        #[doc = "The exclusive accesses go to the register's memory directly rather than through its cell, so they aren't seen by the replayer or the tracing hook."]
+        #[cfg(target_has_atomic = "32")]
+        #[inline]
+        pub fn update_exclusive<F: FnMut(&mut ModeUpdate)>(&self, mut f: F) {
+            use ::core::sync::atomic::Ordering;
+            let cell = unsafe { &*(self.value.as_ptr() as *const ::core::sync::atomic::AtomicU32) };
+            let mut current = cell.load(Ordering::Relaxed);
+            loop {
+                let mut update = ModeUpdate::detached(self);
+                f(&mut update);
+                let merged = update.merge(current);
+                if update.mask == 0 {
+                    return;
+                }
+                match cell.compare_exchange_weak(
+                    current,
+                    merged,
+                    Ordering::SeqCst,
+                    Ordering::Relaxed,
+                ) {
+                    Ok(_) => return,
+                    Err(actual) => current = actual,
+                }
+            }
+        }
+        #[doc = "Create a getter representing the current state of the register"]
+        #[inline(always)]
+        pub fn get(&self) -> ModeGet {
+            ModeGet::new(self)
+        }
+        #[doc = "Spin until the bit is set"]
+        #[inline]
+        pub fn wait_enable(&self) {
+            while !self.get().enable() {}
+        }
+        #[doc = "Poll until the bit is set, at most `max_polls` times"]
+        #[inline]
+        pub fn wait_enable_timeout(
+            &self,
+            max_polls: u32,
+        ) -> Result<(), ::volatile_cell::wait::Timeout> {
+            ::volatile_cell::wait::poll_until(|| self.get().enable(), max_polls)
+        }
+        #[doc = "Future resolving once the bit is set"]
+        #[inline]
+        pub fn wait_enable_async<'a>(
+            &'a self,
+        ) -> ::volatile_cell::wait::WaitUntil<'a, impl FnMut() -> bool + Unpin + 'a> {
+            ::volatile_cell::wait::WaitUntil::new(move || self.get().enable())
+        }
+    }
+    pub struct ModeUpdate<'a> {
+        value: u32,
+        mask: u32,
+        write_only: bool,
+        store_on_drop: bool,
+        reg: &'a Mode,
+    }
+    impl<'a> ModeUpdate<'a> {
+        #[inline(always)]
+        pub fn new(reg: &'a Mode) -> ModeUpdate<'a> {
+            ModeUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[inline(always)]
+        pub fn new_ignoring_state(reg: &'a Mode) -> ModeUpdate<'a> {
+            ModeUpdate {
+                value: 0,
+                mask: 0,
+                write_only: true,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[doc = "An update that is never stored, for the caller to store itself"]
+        #[inline(always)]
+        fn detached(reg: &'a Mode) -> ModeUpdate<'a> {
+            ModeUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: false,
+                reg: reg,
+            }
+        }
+        const fn clear_mask() -> u32 {
+            0u64 as u32
+        }
+        const fn update_offset(base: usize, size: usize, index: usize) -> usize {
+            base + (size * index)
+        }
+        #[inline(always)]
+        fn merge(&self, current: u32) -> u32 {
+            let v: u32 = current & (!Self::clear_mask()) & (!self.mask);
+            self.value | v
+        }
+        #[inline(always)]
+        pub fn set_div<'b>(&'b mut self, new_value: u8) -> &'b mut Self {
+            let context_mask: u32 = 15 << 0;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 15) << 0);
+            self.mask |= context_mask;
+            self
+        }
+        #[inline(always)]
+        pub fn set_enable<'b>(&'b mut self, new_value: bool) -> &'b mut Self {
+            let context_mask: u32 = 1 << 4;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 1) << 4);
+            self.mask |= context_mask;
+            self
+        }
+    }
+    impl<'a> Drop for ModeUpdate<'a> {
+        #[inline(always)]
+        fn drop(&mut self) {
+            if self.store_on_drop && self.mask != 0 {
+                let current: u32 = if self.write_only { 0 } else { self.reg.load() };
+                self.reg.store(self.merge(current));
+            }
+        }
+    }
+    #[derive(Clone, PartialEq, Eq)]
+    pub struct ModeGet {
+        value: u32,
+    }
+    impl ModeGet {
+        #[doc = "Create a getter reflecting the current value of the register"]
+        #[inline(always)]
+        pub fn new(reg: &Mode) -> ModeGet {
+            ModeGet { value: reg.load() }
+        }
+        #[inline(always)]
+        pub fn div(&self) -> u32 {
+            (self.value & 15)
+        }
+        #[inline(always)]
+        pub fn enable(&self) -> bool {
+            let val = ((self.value >> 4) & 1);
+            val != 0x0
+        }
+    }
+    #[derive(Clone, Copy, PartialEq, Eq, Default)]
+    #[repr(transparent)]
+    pub struct ModeValue {
+        value: u32,
+    }
+    impl ModeValue {
+        #[doc = "Create a value with every field cleared"]
+        #[inline(always)]
+        pub const fn new() -> ModeValue {
+            ModeValue { value: 0 }
+        }
+        #[inline(always)]
+        pub const fn from_bits(bits: u32) -> ModeValue {
+            ModeValue { value: bits }
+        }
+        #[inline(always)]
+        pub const fn to_bits(&self) -> u32 {
+            self.value
+        }
+        #[inline(always)]
+        pub fn div(&self) -> u32 {
+            (self.value & 15)
+        }
+        #[inline(always)]
+        pub fn enable(&self) -> bool {
+            let val = ((self.value >> 4) & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub const fn with_div(self, new_value: u8) -> Self {
+            let context_mask: u32 = 15 << 0;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 15) << 0),
+            }
+        }
+        #[inline(always)]
+        pub const fn with_enable(self, new_value: bool) -> Self {
+            let context_mask: u32 = 1 << 4;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 1) << 4),
+            }
+        }
+    }
+    impl From<ModeGet> for ModeValue {
+        #[inline(always)]
+        fn from(get: ModeGet) -> ModeValue {
+            ModeValue::from_bits(get.value)
+        }
+    }
+    impl From<ModeValue> for ModeGet {
+        #[inline(always)]
+        fn from(value: ModeValue) -> ModeGet {
+            ModeGet {
+                value: value.to_bits(),
+            }
+        }
+    }
+    impl Mode {
+        #[doc = "Write `value` to the register in a single store, without reading it first"]
+        #[inline(always)]
+        pub fn write(&self, value: ModeValue) {
+            self.store(value.to_bits());
+        }
+    }
+    impl<'a> ModeUpdate<'a> {
+        #[doc = "Replace every field of the register with those of `value`"]
+        #[inline(always)]
+        pub fn assign<'b>(&'b mut self, value: ModeValue) -> &'b mut Self {
+            self.value = value.to_bits();
+            self.mask = !0;
+            self
+        }
+    }
+    impl ModeGet {
+        pub const FIELDS: &'static [::volatile_cell::field::FieldDescriptor] = &[
+            ::volatile_cell::field::FieldDescriptor {
+                name: "div",
+                offset: 0u32,
+                width: 4u32,
+                count: 1u32,
+            },
+            ::volatile_cell::field::FieldDescriptor {
+                name: "enable",
+                offset: 4u32,
+                width: 1u32,
+                count: 1u32,
+            },
+        ];
+        #[doc = "Iterate over the readable fields that differ from those of `other`"]
+        #[inline]
+        pub fn changed_fields(&self, other: &ModeGet) -> ::volatile_cell::field::FieldChanges {
+            ::volatile_cell::field::FieldChanges::new(
+                Self::FIELDS,
+                self.value as u64,
+                other.value as u64,
+            )
+        }
+    }
+    pub mod isr {
+        #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+        pub struct IrqFlags(u8);
+        #[allow(non_upper_case_globals)]
+        impl IrqFlags {
+            pub const Overflow: IrqFlags = IrqFlags(1 << 0);
+            pub const Compare: IrqFlags = IrqFlags(1 << 1);
+            pub const Capture: IrqFlags = IrqFlags(1 << 3);
+            #[inline(always)]
+            pub const fn empty() -> IrqFlags {
+                IrqFlags(0)
+            }
+            #[inline(always)]
+            pub const fn all() -> IrqFlags {
+                IrqFlags(15 as u8)
+            }
+            #[doc = "Create a set from raw bits, dropping those outside the field"]
+            #[inline(always)]
+            pub const fn from_bits_truncate(bits: u8) -> IrqFlags {
+                IrqFlags(bits & (15 as u8))
+            }
+            #[inline(always)]
+            pub const fn bits(&self) -> u8 {
+                self.0
+            }
+            #[inline(always)]
+            pub const fn is_empty(&self) -> bool {
+                self.0 == 0
+            }
+            #[inline(always)]
+            pub const fn contains(&self, other: IrqFlags) -> bool {
+                (self.0 & other.0) == other.0
+            }
+            #[inline(always)]
+            pub const fn intersects(&self, other: IrqFlags) -> bool {
+                (self.0 & other.0) != 0
+            }
+        }
+        impl From<u8> for IrqFlags {
+            #[inline(always)]
+            fn from(bits: u8) -> IrqFlags {
+                IrqFlags::from_bits_truncate(bits)
+            }
+        }
+        impl core::ops::BitOr for IrqFlags {
+            type Output = IrqFlags;
+            #[inline(always)]
+            fn bitor(self, other: IrqFlags) -> IrqFlags {
+                IrqFlags(self.0 | other.0)
+            }
+        }
+        impl core::ops::BitOrAssign for IrqFlags {
+            #[inline(always)]
+            fn bitor_assign(&mut self, other: IrqFlags) {
+                self.0 |= other.0;
+            }
+        }
+        impl core::ops::BitAnd for IrqFlags {
+            type Output = IrqFlags;
+            #[inline(always)]
+            fn bitand(self, other: IrqFlags) -> IrqFlags {
+                IrqFlags(self.0 & other.0)
+            }
+        }
+        impl core::ops::BitAndAssign for IrqFlags {
+            #[inline(always)]
+            fn bitand_assign(&mut self, other: IrqFlags) {
+                self.0 &= other.0;
+            }
+        }
+        impl core::ops::Sub for IrqFlags {
+            type Output = IrqFlags;
+            #[inline(always)]
+            fn sub(self, other: IrqFlags) -> IrqFlags {
+                IrqFlags(self.0 & !other.0)
+            }
+        }
+        impl core::ops::Not for IrqFlags {
+            type Output = IrqFlags;
+            #[inline(always)]
+            fn not(self) -> IrqFlags {
+                IrqFlags(!self.0 & (15 as u8))
+            }
+        }
+        #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+        pub struct ChanFlags(u8);
+        #[allow(non_upper_case_globals)]
+        impl ChanFlags {
+            #[inline(always)]
+            pub const fn empty() -> ChanFlags {
+                ChanFlags(0)
+            }
+            #[inline(always)]
+            pub const fn all() -> ChanFlags {
+                ChanFlags(15 as u8)
+            }
+            #[doc = "Create a set from raw bits, dropping those outside the field"]
+            #[inline(always)]
+            pub const fn from_bits_truncate(bits: u8) -> ChanFlags {
+                ChanFlags(bits & (15 as u8))
+            }
+            #[inline(always)]
+            pub const fn bits(&self) -> u8 {
+                self.0
+            }
+            #[inline(always)]
+            pub const fn is_empty(&self) -> bool {
+                self.0 == 0
+            }
+            #[inline(always)]
+            pub const fn contains(&self, other: ChanFlags) -> bool {
+                (self.0 & other.0) == other.0
+            }
+            #[inline(always)]
+            pub const fn intersects(&self, other: ChanFlags) -> bool {
+                (self.0 & other.0) != 0
+            }
+        }
+        impl From<u8> for ChanFlags {
+            #[inline(always)]
+            fn from(bits: u8) -> ChanFlags {
+                ChanFlags::from_bits_truncate(bits)
+            }
+        }
+        impl core::ops::BitOr for ChanFlags {
+            type Output = ChanFlags;
+            #[inline(always)]
+            fn bitor(self, other: ChanFlags) -> ChanFlags {
+                ChanFlags(self.0 | other.0)
+            }
+        }
+        impl core::ops::BitOrAssign for ChanFlags {
+            #[inline(always)]
+            fn bitor_assign(&mut self, other: ChanFlags) {
+                self.0 |= other.0;
+            }
+        }
+        impl core::ops::BitAnd for ChanFlags {
+            type Output = ChanFlags;
+            #[inline(always)]
+            fn bitand(self, other: ChanFlags) -> ChanFlags {
+                ChanFlags(self.0 & other.0)
+            }
+        }
+        impl core::ops::BitAndAssign for ChanFlags {
+            #[inline(always)]
+            fn bitand_assign(&mut self, other: ChanFlags) {
+                self.0 &= other.0;
+            }
+        }
+        impl core::ops::Sub for ChanFlags {
+            type Output = ChanFlags;
+            #[inline(always)]
+            fn sub(self, other: ChanFlags) -> ChanFlags {
+                ChanFlags(self.0 & !other.0)
+            }
+        }
+        impl core::ops::Not for ChanFlags {
+            type Output = ChanFlags;
+            #[inline(always)]
+            fn not(self) -> ChanFlags {
+                ChanFlags(!self.0 & (15 as u8))
+            }
+        }
+    }
+    #[repr(C)]
+    pub struct Isr {
+        value: ::volatile_cell::VolatileCell<u32>,
+    }
+    impl Isr {
+        #[inline(always)]
+        fn load(&self) -> u32 {
+            let value = self.value.get();
+            value
+        }
+        #[inline(always)]
+        fn store(&self, value: u32) {
+            self.value.set(value);
+        }
+        #[doc = "Create a new updater"]
+        #[inline(always)]
+        pub fn update<'a>(&'a self) -> IsrUpdate<'a> {
+            IsrUpdate::new(self)
+        }
+        #[doc = "Create an updater whose read-modify-write happens inside the critical section `cs`"]
+        #[inline(always)]
+        pub fn update_in<'a, C: ::volatile_cell::sync::CriticalSection>(
+            &'a self,
+            _cs: &'a C,
+        ) -> IsrUpdate<'a> {
+            IsrUpdate::new(self)
+        }
+        #[doc = "Update the register with exclusive loads and stores (LDREX/STREX), retrying `f` until no other access intervened"]
+        #[doc = ""]
+        #[doc = "The exclusive accesses go to the register's memory directly rather than through its cell, so they aren't seen by the replayer or the tracing hook."]
+        #[cfg(target_has_atomic = "32")]
+        #[inline]
+        pub fn update_exclusive<F: FnMut(&mut IsrUpdate)>(&self, mut f: F) {
+            use ::core::sync::atomic::Ordering;
+            let cell = unsafe { &*(self.value.as_ptr() as *const ::core::sync::atomic::AtomicU32) };
+            let mut current = cell.load(Ordering::Relaxed);
+            loop {
+                let mut update = IsrUpdate::detached(self);
+                f(&mut update);
+                let merged = update.merge(current);
+                if update.mask == 0 {
+                    return;
+                }
+                match cell.compare_exchange_weak(
+                    current,
+                    merged,
+                    Ordering::SeqCst,
+                    Ordering::Relaxed,
+                ) {
+                    Ok(_) => return,
+                    Err(actual) => current = actual,
+                }
+            }
+        }
+        #[doc = "Create a getter representing the current state of the register"]
+        #[inline(always)]
+        pub fn get(&self) -> IsrGet {
+            IsrGet::new(self)
+        }
+    }
+    pub struct IsrUpdate<'a> {
+        value: u32,
+        mask: u32,
+        write_only: bool,
+        store_on_drop: bool,
+        reg: &'a Isr,
+    }
+    impl<'a> IsrUpdate<'a> {
+        #[inline(always)]
+        pub fn new(reg: &'a Isr) -> IsrUpdate<'a> {
+            IsrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[inline(always)]
+        pub fn new_ignoring_state(reg: &'a Isr) -> IsrUpdate<'a> {
+            IsrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: true,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[doc = "An update that is never stored, for the caller to store itself"]
+        #[inline(always)]
+        fn detached(reg: &'a Isr) -> IsrUpdate<'a> {
+            IsrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: false,
+                reg: reg,
+            }
+        }
+        const fn clear_mask() -> u32 {
+            65295u64 as u32
+        }
+        const fn update_offset(base: usize, size: usize, index: usize) -> usize {
+            base + (size * index)
+        }
+        #[inline(always)]
+        fn merge(&self, current: u32) -> u32 {
+            let v: u32 = current & (!Self::clear_mask()) & (!self.mask);
+            self.value | v
+        }
+        #[doc = "Acknowledge `flags`, writing ones to just those bits"]
+        #[inline(always)]
+        pub fn clear_irq<'b, F: Into<isr::IrqFlags>>(&'b mut self, flags: F) -> &'b mut Self {
+            let bits = (flags.into().bits() as u32) << 0;
+            self.value |= bits;
+            self.mask |= bits;
+            self
+        }
+        #[doc = "Acknowledge `flags`, writing ones to just those bits"]
+        #[inline(always)]
+        pub fn clear_chan<'b, F: Into<isr::ChanFlags>>(
+            &'b mut self,
+            index: usize,
+            flags: F,
+        ) -> &'b mut Self {
+            let bits = (flags.into().bits() as u32) << Self::update_offset(8, 4, index);
+            self.value |= bits;
+            self.mask |= bits;
+            self
+        }
+    }
+    impl<'a> Drop for IsrUpdate<'a> {
+        #[inline(always)]
+        fn drop(&mut self) {
+            if self.store_on_drop && self.mask != 0 {
+                let current: u32 = if self.write_only { 0 } else { self.reg.load() };
+                self.reg.store(self.merge(current));
+            }
+        }
+    }
+    #[derive(Clone, PartialEq, Eq)]
+    pub struct IsrGet {
+        value: u32,
+    }
+    impl IsrGet {
+        #[doc = "Create a getter reflecting the current value of the register"]
+        #[inline(always)]
+        pub fn new(reg: &Isr) -> IsrGet {
+            IsrGet { value: reg.load() }
+        }
+        #[inline(always)]
+        pub fn irq(&self) -> isr::IrqFlags {
+            isr::IrqFlags::from_bits_truncate((self.value & 15) as _)
+        }
+        #[inline(always)]
+        pub fn chan(&self, index: usize) -> isr::ChanFlags {
+            isr::ChanFlags::from_bits_truncate(((self.value >> (8 + (4 * index))) & 15) as _)
+        }
+    }
+    #[derive(Clone, Copy, PartialEq, Eq, Default)]
+    #[repr(transparent)]
+    pub struct IsrValue {
+        value: u32,
+    }
+    impl IsrValue {
+        #[doc = "Create a value with every field cleared"]
+        #[inline(always)]
+        pub const fn new() -> IsrValue {
+            IsrValue { value: 0 }
+        }
+        #[inline(always)]
+        pub const fn from_bits(bits: u32) -> IsrValue {
+            IsrValue { value: bits }
+        }
+        #[inline(always)]
+        pub const fn to_bits(&self) -> u32 {
+            self.value
+        }
+        #[inline(always)]
+        pub fn irq(&self) -> isr::IrqFlags {
+            isr::IrqFlags::from_bits_truncate((self.value & 15) as _)
+        }
+        #[inline(always)]
+        pub fn chan(&self, index: usize) -> isr::ChanFlags {
+            isr::ChanFlags::from_bits_truncate(((self.value >> (8 + (4 * index))) & 15) as _)
+        }
+        #[inline(always)]
+        pub const fn with_irq(self, new_value: u8) -> Self {
+            let context_mask: u32 = 15 << 0;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 15) << 0),
+            }
+        }
+        #[inline(always)]
+        pub const fn with_chan(self, index: usize, new_value: u8) -> Self {
+            if index >= 2 {
+                panic!();
+            }
+            let shift = 8 + (4 * index);
+            let context_mask: u32 = 15 << shift;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 15) << shift),
+            }
+        }
+    }
+    impl From<IsrGet> for IsrValue {
+        #[inline(always)]
+        fn from(get: IsrGet) -> IsrValue {
+            IsrValue::from_bits(get.value)
+        }
+    }
+    impl From<IsrValue> for IsrGet {
+        #[inline(always)]
+        fn from(value: IsrValue) -> IsrGet {
+            IsrGet {
+                value: value.to_bits(),
+            }
+        }
+    }
+    impl Isr {
+        #[doc = "Write `value` to the register in a single store, without reading it first"]
+        #[inline(always)]
+        pub fn write(&self, value: IsrValue) {
+            self.store(value.to_bits());
+        }
+    }
+    impl<'a> IsrUpdate<'a> {
+        #[doc = "Replace every field of the register with those of `value`"]
+        #[inline(always)]
+        pub fn assign<'b>(&'b mut self, value: IsrValue) -> &'b mut Self {
+            self.value = value.to_bits();
+            self.mask = !0;
+            self
+        }
+    }
+    impl IsrGet {
+        pub const FIELDS: &'static [::volatile_cell::field::FieldDescriptor] = &[
+            ::volatile_cell::field::FieldDescriptor {
+                name: "irq",
+                offset: 0u32,
+                width: 4u32,
+                count: 1u32,
+            },
+            ::volatile_cell::field::FieldDescriptor {
+                name: "chan",
+                offset: 8u32,
+                width: 4u32,
+                count: 2u32,
+            },
+        ];
+        #[doc = "Iterate over the readable fields that differ from those of `other`"]
+        #[inline]
+        pub fn changed_fields(&self, other: &IsrGet) -> ::volatile_cell::field::FieldChanges {
+            ::volatile_cell::field::FieldChanges::new(
+                Self::FIELDS,
+                self.value as u64,
+                other.value as u64,
+            )
+        }
+    }
+    pub mod chan {
+        pub mod period {}
+        #[repr(C)]
+        pub struct Period {
+            value: ::volatile_cell::VolatileCell<u32>,
+        }
+        impl Period {
+            #[inline(always)]
+            fn load(&self) -> u32 {
+                let value = self.value.get();
+                value
+            }
+            #[inline(always)]
+            fn store(&self, value: u32) {
+                self.value.set(value);
+            }
+            #[doc = "Create a new updater"]
+            #[inline(always)]
+            pub fn update<'a>(&'a self) -> PeriodUpdate<'a> {
+                PeriodUpdate::new(self)
+            }
+            #[doc = "Create an updater whose read-modify-write happens inside the critical section `cs`"]
+            #[inline(always)]
+            pub fn update_in<'a, C: ::volatile_cell::sync::CriticalSection>(
+                &'a self,
+                _cs: &'a C,
+            ) -> PeriodUpdate<'a> {
+                PeriodUpdate::new(self)
+            }
+            #[doc = "Update the register with exclusive loads and stores (LDREX/STREX), retrying `f` until no other access intervened"]
+            #[doc = ""]
+            #[doc = "The exclusive accesses go to the register's memory directly rather than through its cell, so they aren't seen by the replayer or the tracing hook."]
+            #[cfg(target_has_atomic = "32")]
+            #[inline]
+            pub fn update_exclusive<F: FnMut(&mut PeriodUpdate)>(&self, mut f: F) {
+                use ::core::sync::atomic::Ordering;
+                let cell =
+                    unsafe { &*(self.value.as_ptr() as *const ::core::sync::atomic::AtomicU32) };
+                let mut current = cell.load(Ordering::Relaxed);
+                loop {
+                    let mut update = PeriodUpdate::detached(self);
+                    f(&mut update);
+                    let merged = update.merge(current);
+                    if update.mask == 0 {
+                        return;
+                    }
+                    match cell.compare_exchange_weak(
+                        current,
+                        merged,
+                        Ordering::SeqCst,
+                        Ordering::Relaxed,
+                    ) {
+                        Ok(_) => return,
+                        Err(actual) => current = actual,
+                    }
+                }
+            }
+            #[doc = "Create a getter representing the current state of the register"]
+            #[inline(always)]
+            pub fn get(&self) -> PeriodGet {
+                PeriodGet::new(self)
+            }
+        }
+        pub struct PeriodUpdate<'a> {
+            value: u32,
+            mask: u32,
+            write_only: bool,
+            store_on_drop: bool,
+            reg: &'a Period,
+        }
+        impl<'a> PeriodUpdate<'a> {
+            #[inline(always)]
+            pub fn new(reg: &'a Period) -> PeriodUpdate<'a> {
+                PeriodUpdate {
+                    value: 0,
+                    mask: 0,
+                    write_only: false,
+                    store_on_drop: true,
+                    reg: reg,
+                }
+            }
+            #[inline(always)]
+            pub fn new_ignoring_state(reg: &'a Period) -> PeriodUpdate<'a> {
+                PeriodUpdate {
+                    value: 0,
+                    mask: 0,
+                    write_only: true,
+                    store_on_drop: true,
+                    reg: reg,
+                }
+            }
+            #[doc = "An update that is never stored, for the caller to store itself"]
+            #[inline(always)]
+            fn detached(reg: &'a Period) -> PeriodUpdate<'a> {
+                PeriodUpdate {
+                    value: 0,
+                    mask: 0,
+                    write_only: false,
+                    store_on_drop: false,
+                    reg: reg,
+                }
+            }
+            const fn clear_mask() -> u32 {
+                0u64 as u32
+            }
+            const fn update_offset(base: usize, size: usize, index: usize) -> usize {
+                base + (size * index)
+            }
+            #[inline(always)]
+            fn merge(&self, current: u32) -> u32 {
+                let v: u32 = current & (!Self::clear_mask()) & (!self.mask);
+                self.value | v
+            }
+            #[inline(always)]
+            pub fn set_ticks<'b>(&'b mut self, new_value: u16) -> &'b mut Self {
+                let context_mask: u32 = 65535 << 0;
+                self.value = (self.value & !context_mask) | (((new_value as u32) & 65535) << 0);
+                self.mask |= context_mask;
+                self
+            }
+        }
+        impl<'a> Drop for PeriodUpdate<'a> {
+            #[inline(always)]
+            fn drop(&mut self) {
+                if self.store_on_drop && self.mask != 0 {
+                    let current: u32 = if self.write_only { 0 } else { self.reg.load() };
+                    self.reg.store(self.merge(current));
+                }
+            }
+        }
+        #[derive(Clone, PartialEq, Eq)]
+        pub struct PeriodGet {
+            value: u32,
+        }
+        impl PeriodGet {
+            #[doc = "Create a getter reflecting the current value of the register"]
+            #[inline(always)]
+            pub fn new(reg: &Period) -> PeriodGet {
+                PeriodGet { value: reg.load() }
+            }
+            #[inline(always)]
+            pub fn ticks(&self) -> u32 {
+                (self.value & 65535)
+            }
+        }
+        #[derive(Clone, Copy, PartialEq, Eq, Default)]
+        #[repr(transparent)]
+        pub struct PeriodValue {
+            value: u32,
+        }
+        impl PeriodValue {
+            #[doc = "Create a value with every field cleared"]
+            #[inline(always)]
+            pub const fn new() -> PeriodValue {
+                PeriodValue { value: 0 }
+            }
+            #[inline(always)]
+            pub const fn from_bits(bits: u32) -> PeriodValue {
+                PeriodValue { value: bits }
+            }
+            #[inline(always)]
+            pub const fn to_bits(&self) -> u32 {
+                self.value
+            }
+            #[inline(always)]
+            pub fn ticks(&self) -> u32 {
+                (self.value & 65535)
+            }
+            #[inline(always)]
+            pub const fn with_ticks(self, new_value: u16) -> Self {
+                let context_mask: u32 = 65535 << 0;
+                Self {
+                    value: (self.value & !context_mask) | (((new_value as u32) & 65535) << 0),
+                }
+            }
+        }
+        impl From<PeriodGet> for PeriodValue {
+            #[inline(always)]
+            fn from(get: PeriodGet) -> PeriodValue {
+                PeriodValue::from_bits(get.value)
+            }
+        }
+        impl From<PeriodValue> for PeriodGet {
+            #[inline(always)]
+            fn from(value: PeriodValue) -> PeriodGet {
+                PeriodGet {
+                    value: value.to_bits(),
+                }
+            }
+        }
+        impl Period {
+            #[doc = "Write `value` to the register in a single store, without reading it first"]
+            #[inline(always)]
+            pub fn write(&self, value: PeriodValue) {
+                self.store(value.to_bits());
+            }
+        }
+        impl<'a> PeriodUpdate<'a> {
+            #[doc = "Replace every field of the register with those of `value`"]
+            #[inline(always)]
+            pub fn assign<'b>(&'b mut self, value: PeriodValue) -> &'b mut Self {
+                self.value = value.to_bits();
+                self.mask = !0;
+                self
+            }
+        }
+        impl PeriodGet {
+            pub const FIELDS: &'static [::volatile_cell::field::FieldDescriptor] =
+                &[::volatile_cell::field::FieldDescriptor {
+                    name: "ticks",
+                    offset: 0u32,
+                    width: 16u32,
+                    count: 1u32,
+                }];
+            #[doc = "Iterate over the readable fields that differ from those of `other`"]
+            #[inline]
+            pub fn changed_fields(
+                &self,
+                other: &PeriodGet,
+            ) -> ::volatile_cell::field::FieldChanges {
+                ::volatile_cell::field::FieldChanges::new(
+                    Self::FIELDS,
+                    self.value as u64,
+                    other.value as u64,
+                )
+            }
+        }
+        pub mod status {}
+        #[repr(C)]
+        pub struct Status {
+            value: ::volatile_cell::VolatileCell<u32>,
+        }
+        impl Status {
+            #[inline(always)]
+            fn load(&self) -> u32 {
+                let value = self.value.get();
+                value
+            }
+            #[inline(always)]
+            fn store(&self, value: u32) {
+                self.value.set(value);
+            }
+            #[doc = "Create a new updater"]
+            #[inline(always)]
+            pub fn update<'a>(&'a self) -> StatusUpdate<'a> {
+                StatusUpdate::new(self)
+            }
+            #[doc = "Create an updater whose read-modify-write happens inside the critical section `cs`"]
+            #[inline(always)]
+            pub fn update_in<'a, C: ::volatile_cell::sync::CriticalSection>(
+                &'a self,
+                _cs: &'a C,
+            ) -> StatusUpdate<'a> {
+                StatusUpdate::new(self)
+            }
+            #[doc = "Update the register with exclusive loads and stores (LDREX/STREX), retrying `f` until no other access intervened"]
+            #[doc = ""]
+            #[doc = "The exclusive accesses go to the register's memory directly rather than through its cell, so they aren't seen by the replayer or the tracing hook."]
+            #[cfg(target_has_atomic = "32")]
+            #[inline]
+            pub fn update_exclusive<F: FnMut(&mut StatusUpdate)>(&self, mut f: F) {
+                use ::core::sync::atomic::Ordering;
+                let cell =
+                    unsafe { &*(self.value.as_ptr() as *const ::core::sync::atomic::AtomicU32) };
+                let mut current = cell.load(Ordering::Relaxed);
+                loop {
+                    let mut update = StatusUpdate::detached(self);
+                    f(&mut update);
+                    let merged = update.merge(current);
+                    if update.mask == 0 {
+                        return;
+                    }
+                    match cell.compare_exchange_weak(
+                        current,
+                        merged,
+                        Ordering::SeqCst,
+                        Ordering::Relaxed,
+                    ) {
+                        Ok(_) => return,
+                        Err(actual) => current = actual,
+                    }
+                }
+            }
+            #[doc = "Create a getter representing the current state of the register"]
+            #[inline(always)]
+            pub fn get(&self) -> StatusGet {
+                StatusGet::new(self)
+            }
+            #[doc = "Spin until the bit is set"]
+            #[inline]
+            pub fn wait_busy(&self) {
+                while !self.get().busy() {}
+            }
+            #[doc = "Poll until the bit is set, at most `max_polls` times"]
+            #[inline]
+            pub fn wait_busy_timeout(
+                &self,
+                max_polls: u32,
+            ) -> Result<(), ::volatile_cell::wait::Timeout> {
+                ::volatile_cell::wait::poll_until(|| self.get().busy(), max_polls)
+            }
+            #[doc = "Future resolving once the bit is set"]
+            #[inline]
+            pub fn wait_busy_async<'a>(
+                &'a self,
+            ) -> ::volatile_cell::wait::WaitUntil<'a, impl FnMut() -> bool + Unpin + 'a>
+            {
+                ::volatile_cell::wait::WaitUntil::new(move || self.get().busy())
+            }
+        }
+        pub struct StatusUpdate<'a> {
+            value: u32,
+            mask: u32,
+            write_only: bool,
+            store_on_drop: bool,
+            reg: &'a Status,
+        }
+        impl<'a> StatusUpdate<'a> {
+            #[inline(always)]
+            pub fn new(reg: &'a Status) -> StatusUpdate<'a> {
+                StatusUpdate {
+                    value: 0,
+                    mask: 0,
+                    write_only: false,
+                    store_on_drop: true,
+                    reg: reg,
+                }
+            }
+            #[inline(always)]
+            pub fn new_ignoring_state(reg: &'a Status) -> StatusUpdate<'a> {
+                StatusUpdate {
+                    value: 0,
+                    mask: 0,
+                    write_only: true,
+                    store_on_drop: true,
+                    reg: reg,
+                }
+            }
+            #[doc = "An update that is never stored, for the caller to store itself"]
+            #[inline(always)]
+            fn detached(reg: &'a Status) -> StatusUpdate<'a> {
+                StatusUpdate {
+                    value: 0,
+                    mask: 0,
+                    write_only: false,
+                    store_on_drop: false,
+                    reg: reg,
+                }
+            }
+            const fn clear_mask() -> u32 {
+                0u64 as u32
+            }
+            const fn update_offset(base: usize, size: usize, index: usize) -> usize {
+                base + (size * index)
+            }
+            #[inline(always)]
+            fn merge(&self, current: u32) -> u32 {
+                let v: u32 = current & (!Self::clear_mask()) & (!self.mask);
+                self.value | v
+            }
+        }
+        impl<'a> Drop for StatusUpdate<'a> {
+            #[inline(always)]
+            fn drop(&mut self) {
+                if self.store_on_drop && self.mask != 0 {
+                    let current: u32 = if self.write_only { 0 } else { self.reg.load() };
+                    self.reg.store(self.merge(current));
+                }
+            }
+        }
+        #[derive(Clone, PartialEq, Eq)]
+        pub struct StatusGet {
+            value: u32,
+        }
+        impl StatusGet {
+            #[doc = "Create a getter reflecting the current value of the register"]
+            #[inline(always)]
+            pub fn new(reg: &Status) -> StatusGet {
+                StatusGet { value: reg.load() }
+            }
+            #[inline(always)]
+            pub fn busy(&self) -> bool {
+                let val = (self.value & 1);
+                val != 0x0
+            }
+        }
+        #[derive(Clone, Copy, PartialEq, Eq, Default)]
+        #[repr(transparent)]
+        pub struct StatusValue {
+            value: u32,
+        }
+        impl StatusValue {
+            #[doc = "Create a value with every field cleared"]
+            #[inline(always)]
+            pub const fn new() -> StatusValue {
+                StatusValue { value: 0 }
+            }
+            #[inline(always)]
+            pub const fn from_bits(bits: u32) -> StatusValue {
+                StatusValue { value: bits }
+            }
+            #[inline(always)]
+            pub const fn to_bits(&self) -> u32 {
+                self.value
+            }
+            #[inline(always)]
+            pub fn busy(&self) -> bool {
+                let val = (self.value & 1);
+                val != 0x0
+            }
+            #[inline(always)]
+            pub const fn with_busy(self, new_value: bool) -> Self {
+                let context_mask: u32 = 1 << 0;
+                Self {
+                    value: (self.value & !context_mask) | (((new_value as u32) & 1) << 0),
+                }
+            }
+        }
+        impl From<StatusGet> for StatusValue {
+            #[inline(always)]
+            fn from(get: StatusGet) -> StatusValue {
+                StatusValue::from_bits(get.value)
+            }
+        }
+        impl From<StatusValue> for StatusGet {
+            #[inline(always)]
+            fn from(value: StatusValue) -> StatusGet {
+                StatusGet {
+                    value: value.to_bits(),
+                }
+            }
+        }
+        impl Status {
+            #[doc = "Write `value` to the register in a single store, without reading it first"]
+            #[inline(always)]
+            pub fn write(&self, value: StatusValue) {
+                self.store(value.to_bits());
+            }
+        }
+        impl<'a> StatusUpdate<'a> {
+            #[doc = "Replace every field of the register with those of `value`"]
+            #[inline(always)]
+            pub fn assign<'b>(&'b mut self, value: StatusValue) -> &'b mut Self {
+                self.value = value.to_bits();
+                self.mask = !0;
+                self
+            }
+        }
+        impl StatusGet {
+            pub const FIELDS: &'static [::volatile_cell::field::FieldDescriptor] =
+                &[::volatile_cell::field::FieldDescriptor {
+                    name: "busy",
+                    offset: 0u32,
+                    width: 1u32,
+                    count: 1u32,
+                }];
+            #[doc = "Iterate over the readable fields that differ from those of `other`"]
+            #[inline]
+            pub fn changed_fields(
+                &self,
+                other: &StatusGet,
+            ) -> ::volatile_cell::field::FieldChanges {
+                ::volatile_cell::field::FieldChanges::new(
+                    Self::FIELDS,
+                    self.value as u64,
+                    other.value as u64,
+                )
+            }
+        }
+        #[repr(C)]
+        pub struct Chan {
+            pub period: Period,
+            pub status: Status,
+        }
+        #[doc = "Saved state of every readable register without side effects"]
+        #[derive(Clone, Copy)]
+        pub struct Snapshot {
+            pub period: PeriodValue,
+        }
+        #[doc = "Read the current state of the peripheral"]
+        #[allow(unused_variables)]
+        pub fn capture(p: &Chan) -> Snapshot {
+            Snapshot {
+                period: PeriodValue::from(p.period.get()),
+            }
+        }
+        #[doc = "Write a previously captured state back to the peripheral"]
+        #[allow(unused_variables)]
+        pub fn restore(p: &Chan, snapshot: &Snapshot) {
+            PeriodUpdate::new_ignoring_state(&p.period).assign(snapshot.period);
+        }
+    }
+    pub mod irq {
+        pub mod ier {}
+        #[repr(C)]
+        pub struct Ier {
+            value: ::volatile_cell::VolatileCell<u32>,
+        }
+        impl Ier {
+            #[inline(always)]
+            fn load(&self) -> u32 {
+                let value = self.value.get();
+                value
+            }
+            #[inline(always)]
+            fn store(&self, value: u32) {
+                self.value.set(value);
+            }
+            #[doc = "Create a new updater"]
+            #[inline(always)]
+            pub fn update<'a>(&'a self) -> IerUpdate<'a> {
+                IerUpdate::new(self)
+            }
+            #[doc = "Create an updater whose read-modify-write happens inside the critical section `cs`"]
+            #[inline(always)]
+            pub fn update_in<'a, C: ::volatile_cell::sync::CriticalSection>(
+                &'a self,
+                _cs: &'a C,
+            ) -> IerUpdate<'a> {
+                IerUpdate::new(self)
+            }
+            #[doc = "Create a getter representing the current state of the register"]
+            #[inline(always)]
+            pub fn get(&self) -> IerGet {
+                IerGet::new(self)
+            }
+        }
+        pub struct IerUpdate<'a> {
+            value: u32,
+            mask: u32,
+            write_only: bool,
+            store_on_drop: bool,
+            reg: &'a Ier,
+        }
+        impl<'a> IerUpdate<'a> {
+            #[inline(always)]
+            pub fn new(reg: &'a Ier) -> IerUpdate<'a> {
+                IerUpdate {
+                    value: 0,
+                    mask: 0,
+                    write_only: false,
+                    store_on_drop: true,
+                    reg: reg,
+                }
+            }
+            #[inline(always)]
+            pub fn new_ignoring_state(reg: &'a Ier) -> IerUpdate<'a> {
+                IerUpdate {
+                    value: 0,
+                    mask: 0,
+                    write_only: true,
+                    store_on_drop: true,
+                    reg: reg,
+                }
+            }
+            #[doc = "An update that is never stored, for the caller to store itself"]
+            #[inline(always)]
+            fn detached(reg: &'a Ier) -> IerUpdate<'a> {
+                IerUpdate {
+                    value: 0,
+                    mask: 0,
+                    write_only: false,
+                    store_on_drop: false,
+                    reg: reg,
+                }
+            }
+            const fn clear_mask() -> u32 {
+                0u64 as u32
+            }
+            const fn update_offset(base: usize, size: usize, index: usize) -> usize {
+                base + (size * index)
+            }
+            #[inline(always)]
+            fn merge(&self, current: u32) -> u32 {
+                let v: u32 = current & (!Self::clear_mask()) & (!self.mask);
+                self.value | v
+            }
+            #[inline(always)]
+            pub fn set_bits<'b>(&'b mut self, new_value: u32) -> &'b mut Self {
+                let context_mask: u32 = 4294967295 << 0;
+                self.value =
+                    (self.value & !context_mask) | (((new_value as u32) & 4294967295) << 0);
+                self.mask |= context_mask;
+                self
+            }
+        }
+        impl<'a> Drop for IerUpdate<'a> {
+            #[inline(always)]
+            fn drop(&mut self) {
+                if self.store_on_drop && self.mask != 0 {
+                    let current: u32 = 0;
+                    self.reg.store(self.merge(current));
+                }
+            }
+        }
+        #[derive(Clone, PartialEq, Eq)]
+        pub struct IerGet {
+            value: u32,
+        }
+        impl IerGet {
+            #[doc = "Create a getter reflecting the current value of the register"]
+            #[inline(always)]
+            pub fn new(reg: &Ier) -> IerGet {
+                IerGet { value: reg.load() }
+            }
+        }
+        #[derive(Clone, Copy, PartialEq, Eq, Default)]
+        #[repr(transparent)]
+        pub struct IerValue {
+            value: u32,
+        }
+        impl IerValue {
+            #[doc = "Create a value with every field cleared"]
+            #[inline(always)]
+            pub const fn new() -> IerValue {
+                IerValue { value: 0 }
+            }
+            #[inline(always)]
+            pub const fn from_bits(bits: u32) -> IerValue {
+                IerValue { value: bits }
+            }
+            #[inline(always)]
+            pub const fn to_bits(&self) -> u32 {
+                self.value
+            }
+            #[inline(always)]
+            pub fn bits(&self) -> u32 {
+                self.value
+            }
+            #[inline(always)]
+            pub const fn with_bits(self, new_value: u32) -> Self {
+                let context_mask: u32 = 4294967295 << 0;
+                Self {
+                    value: (self.value & !context_mask) | (((new_value as u32) & 4294967295) << 0),
+                }
+            }
+        }
+        impl From<IerGet> for IerValue {
+            #[inline(always)]
+            fn from(get: IerGet) -> IerValue {
+                IerValue::from_bits(get.value)
+            }
+        }
+        impl From<IerValue> for IerGet {
+            #[inline(always)]
+            fn from(value: IerValue) -> IerGet {
+                IerGet {
+                    value: value.to_bits(),
+                }
+            }
+        }
+        impl Ier {
+            #[doc = "Write `value` to the register in a single store, without reading it first"]
+            #[inline(always)]
+            pub fn write(&self, value: IerValue) {
+                self.store(value.to_bits());
+            }
+        }
+        impl<'a> IerUpdate<'a> {
+            #[doc = "Replace every field of the register with those of `value`"]
+            #[inline(always)]
+            pub fn assign<'b>(&'b mut self, value: IerValue) -> &'b mut Self {
+                self.value = value.to_bits();
+                self.mask = !0;
+                self
+            }
+        }
+        impl IerGet {
+            pub const FIELDS: &'static [::volatile_cell::field::FieldDescriptor] = &[];
+            #[doc = "Iterate over the readable fields that differ from those of `other`"]
+            #[inline]
+            pub fn changed_fields(&self, other: &IerGet) -> ::volatile_cell::field::FieldChanges {
+                ::volatile_cell::field::FieldChanges::new(
+                    Self::FIELDS,
+                    self.value as u64,
+                    other.value as u64,
+                )
+            }
+        }
+        pub mod idr {}
+        #[repr(C)]
+        pub struct Idr {
+            value: ::volatile_cell::VolatileCell<u32>,
+        }
+        impl Idr {
+            #[inline(always)]
+            fn load(&self) -> u32 {
+                let value = self.value.get();
+                value
+            }
+            #[inline(always)]
+            fn store(&self, value: u32) {
+                self.value.set(value);
+            }
+            #[doc = "Create a new updater"]
+            #[inline(always)]
+            pub fn update<'a>(&'a self) -> IdrUpdate<'a> {
+                IdrUpdate::new(self)
+            }
+            #[doc = "Create an updater whose read-modify-write happens inside the critical section `cs`"]
+            #[inline(always)]
+            pub fn update_in<'a, C: ::volatile_cell::sync::CriticalSection>(
+                &'a self,
+                _cs: &'a C,
+            ) -> IdrUpdate<'a> {
+                IdrUpdate::new(self)
+            }
+            #[doc = "Create a getter representing the current state of the register"]
+            #[inline(always)]
+            pub fn get(&self) -> IdrGet {
+                IdrGet::new(self)
+            }
+        }
+        pub struct IdrUpdate<'a> {
+            value: u32,
+            mask: u32,
+            write_only: bool,
+            store_on_drop: bool,
+            reg: &'a Idr,
+        }
+        impl<'a> IdrUpdate<'a> {
+            #[inline(always)]
+            pub fn new(reg: &'a Idr) -> IdrUpdate<'a> {
+                IdrUpdate {
+                    value: 0,
+                    mask: 0,
+                    write_only: false,
+                    store_on_drop: true,
+                    reg: reg,
+                }
+            }
+            #[inline(always)]
+            pub fn new_ignoring_state(reg: &'a Idr) -> IdrUpdate<'a> {
+                IdrUpdate {
+                    value: 0,
+                    mask: 0,
+                    write_only: true,
+                    store_on_drop: true,
+                    reg: reg,
+                }
+            }
+            #[doc = "An update that is never stored, for the caller to store itself"]
+            #[inline(always)]
+            fn detached(reg: &'a Idr) -> IdrUpdate<'a> {
+                IdrUpdate {
+                    value: 0,
+                    mask: 0,
+                    write_only: false,
+                    store_on_drop: false,
+                    reg: reg,
+                }
+            }
+            const fn clear_mask() -> u32 {
+                0u64 as u32
+            }
+            const fn update_offset(base: usize, size: usize, index: usize) -> usize {
+                base + (size * index)
+            }
+            #[inline(always)]
+            fn merge(&self, current: u32) -> u32 {
+                let v: u32 = current & (!Self::clear_mask()) & (!self.mask);
+                self.value | v
+            }
+            #[inline(always)]
+            pub fn set_bits<'b>(&'b mut self, new_value: u32) -> &'b mut Self {
+                let context_mask: u32 = 4294967295 << 0;
+                self.value =
+                    (self.value & !context_mask) | (((new_value as u32) & 4294967295) << 0);
+                self.mask |= context_mask;
+                self
+            }
+        }
+        impl<'a> Drop for IdrUpdate<'a> {
+            #[inline(always)]
+            fn drop(&mut self) {
+                if self.store_on_drop && self.mask != 0 {
+                    let current: u32 = 0;
+                    self.reg.store(self.merge(current));
+                }
+            }
+        }
+        #[derive(Clone, PartialEq, Eq)]
+        pub struct IdrGet {
+            value: u32,
+        }
+        impl IdrGet {
+            #[doc = "Create a getter reflecting the current value of the register"]
+            #[inline(always)]
+            pub fn new(reg: &Idr) -> IdrGet {
+                IdrGet { value: reg.load() }
+            }
+        }
+        #[derive(Clone, Copy, PartialEq, Eq, Default)]
+        #[repr(transparent)]
+        pub struct IdrValue {
+            value: u32,
+        }
+        impl IdrValue {
+            #[doc = "Create a value with every field cleared"]
+            #[inline(always)]
+            pub const fn new() -> IdrValue {
+                IdrValue { value: 0 }
+            }
+            #[inline(always)]
+            pub const fn from_bits(bits: u32) -> IdrValue {
+                IdrValue { value: bits }
+            }
+            #[inline(always)]
+            pub const fn to_bits(&self) -> u32 {
+                self.value
+            }
+            #[inline(always)]
+            pub fn bits(&self) -> u32 {
+                self.value
+            }
+            #[inline(always)]
+            pub const fn with_bits(self, new_value: u32) -> Self {
+                let context_mask: u32 = 4294967295 << 0;
+                Self {
+                    value: (self.value & !context_mask) | (((new_value as u32) & 4294967295) << 0),
+                }
+            }
+        }
+        impl From<IdrGet> for IdrValue {
+            #[inline(always)]
+            fn from(get: IdrGet) -> IdrValue {
+                IdrValue::from_bits(get.value)
+            }
+        }
+        impl From<IdrValue> for IdrGet {
+            #[inline(always)]
+            fn from(value: IdrValue) -> IdrGet {
+                IdrGet {
+                    value: value.to_bits(),
+                }
+            }
+        }
+        impl Idr {
+            #[doc = "Write `value` to the register in a single store, without reading it first"]
+            #[inline(always)]
+            pub fn write(&self, value: IdrValue) {
+                self.store(value.to_bits());
+            }
+        }
+        impl<'a> IdrUpdate<'a> {
+            #[doc = "Replace every field of the register with those of `value`"]
+            #[inline(always)]
+            pub fn assign<'b>(&'b mut self, value: IdrValue) -> &'b mut Self {
+                self.value = value.to_bits();
+                self.mask = !0;
+                self
+            }
+        }
+        impl IdrGet {
+            pub const FIELDS: &'static [::volatile_cell::field::FieldDescriptor] = &[];
+            #[doc = "Iterate over the readable fields that differ from those of `other`"]
+            #[inline]
+            pub fn changed_fields(&self, other: &IdrGet) -> ::volatile_cell::field::FieldChanges {
+                ::volatile_cell::field::FieldChanges::new(
+                    Self::FIELDS,
+                    self.value as u64,
+                    other.value as u64,
+                )
+            }
+        }
+        pub mod imr {}
+        #[repr(C)]
+        pub struct Imr {
+            value: ::volatile_cell::VolatileCell<u32>,
+        }
+        impl Imr {
+            #[inline(always)]
+            fn load(&self) -> u32 {
+                let value = self.value.get();
+                value
+            }
+            #[inline(always)]
+            fn store(&self, value: u32) {
+                self.value.set(value);
+            }
+            #[doc = "Create a new updater"]
+            #[inline(always)]
+            pub fn update<'a>(&'a self) -> ImrUpdate<'a> {
+                ImrUpdate::new(self)
+            }
+            #[doc = "Create an updater whose read-modify-write happens inside the critical section `cs`"]
+            #[inline(always)]
+            pub fn update_in<'a, C: ::volatile_cell::sync::CriticalSection>(
+                &'a self,
+                _cs: &'a C,
+            ) -> ImrUpdate<'a> {
+                ImrUpdate::new(self)
+            }
+            #[doc = "Update the register with exclusive loads and stores (LDREX/STREX), retrying `f` until no other access intervened"]
+            #[doc = ""]
+            #[doc = "The exclusive accesses go to the register's memory directly rather than through its cell, so they aren't seen by the replayer or the tracing hook."]
+            #[cfg(target_has_atomic = "32")]
+            #[inline]
+            pub fn update_exclusive<F: FnMut(&mut ImrUpdate)>(&self, mut f: F) {
+                use ::core::sync::atomic::Ordering;
+                let cell =
+                    unsafe { &*(self.value.as_ptr() as *const ::core::sync::atomic::AtomicU32) };
+                let mut current = cell.load(Ordering::Relaxed);
+                loop {
+                    let mut update = ImrUpdate::detached(self);
+                    f(&mut update);
+                    let merged = update.merge(current);
+                    if update.mask == 0 {
+                        return;
+                    }
+                    match cell.compare_exchange_weak(
+                        current,
+                        merged,
+                        Ordering::SeqCst,
+                        Ordering::Relaxed,
+                    ) {
+                        Ok(_) => return,
+                        Err(actual) => current = actual,
+                    }
+                }
+            }
+            #[doc = "Create a getter representing the current state of the register"]
+            #[inline(always)]
+            pub fn get(&self) -> ImrGet {
+                ImrGet::new(self)
+            }
+        }
+        pub struct ImrUpdate<'a> {
+            value: u32,
+            mask: u32,
+            write_only: bool,
+            store_on_drop: bool,
+            reg: &'a Imr,
+        }
+        impl<'a> ImrUpdate<'a> {
+            #[inline(always)]
+            pub fn new(reg: &'a Imr) -> ImrUpdate<'a> {
+                ImrUpdate {
+                    value: 0,
+                    mask: 0,
+                    write_only: false,
+                    store_on_drop: true,
+                    reg: reg,
+                }
+            }
+            #[inline(always)]
+            pub fn new_ignoring_state(reg: &'a Imr) -> ImrUpdate<'a> {
+                ImrUpdate {
+                    value: 0,
+                    mask: 0,
+                    write_only: true,
+                    store_on_drop: true,
+                    reg: reg,
+                }
+            }
+            #[doc = "An update that is never stored, for the caller to store itself"]
+            #[inline(always)]
+            fn detached(reg: &'a Imr) -> ImrUpdate<'a> {
+                ImrUpdate {
+                    value: 0,
+                    mask: 0,
+                    write_only: false,
+                    store_on_drop: false,
+                    reg: reg,
+                }
+            }
+            const fn clear_mask() -> u32 {
+                0u64 as u32
+            }
+            const fn update_offset(base: usize, size: usize, index: usize) -> usize {
+                base + (size * index)
+            }
+            #[inline(always)]
+            fn merge(&self, current: u32) -> u32 {
+                let v: u32 = current & (!Self::clear_mask()) & (!self.mask);
+                self.value | v
+            }
+        }
+        impl<'a> Drop for ImrUpdate<'a> {
+            #[inline(always)]
+            fn drop(&mut self) {
+                if self.store_on_drop && self.mask != 0 {
+                    let current: u32 = if self.write_only { 0 } else { self.reg.load() };
+                    self.reg.store(self.merge(current));
+                }
+            }
+        }
+        #[derive(Clone, PartialEq, Eq)]
+        pub struct ImrGet {
+            value: u32,
+        }
+        impl ImrGet {
+            #[doc = "Create a getter reflecting the current value of the register"]
+            #[inline(always)]
+            pub fn new(reg: &Imr) -> ImrGet {
+                ImrGet { value: reg.load() }
+            }
+            #[inline(always)]
+            pub fn bits(&self) -> u32 {
+                self.value
+            }
+        }
+        #[derive(Clone, Copy, PartialEq, Eq, Default)]
+        #[repr(transparent)]
+        pub struct ImrValue {
+            value: u32,
+        }
+        impl ImrValue {
+            #[doc = "Create a value with every field cleared"]
+            #[inline(always)]
+            pub const fn new() -> ImrValue {
+                ImrValue { value: 0 }
+            }
+            #[inline(always)]
+            pub const fn from_bits(bits: u32) -> ImrValue {
+                ImrValue { value: bits }
+            }
+            #[inline(always)]
+            pub const fn to_bits(&self) -> u32 {
+                self.value
+            }
+            #[inline(always)]
+            pub fn bits(&self) -> u32 {
+                self.value
+            }
+            #[inline(always)]
+            pub const fn with_bits(self, new_value: u32) -> Self {
+                let context_mask: u32 = 4294967295 << 0;
+                Self {
+                    value: (self.value & !context_mask) | (((new_value as u32) & 4294967295) << 0),
+                }
+            }
+        }
+        impl From<ImrGet> for ImrValue {
+            #[inline(always)]
+            fn from(get: ImrGet) -> ImrValue {
+                ImrValue::from_bits(get.value)
+            }
+        }
+        impl From<ImrValue> for ImrGet {
+            #[inline(always)]
+            fn from(value: ImrValue) -> ImrGet {
+                ImrGet {
+                    value: value.to_bits(),
+                }
+            }
+        }
+        impl Imr {
+            #[doc = "Write `value` to the register in a single store, without reading it first"]
+            #[inline(always)]
+            pub fn write(&self, value: ImrValue) {
+                self.store(value.to_bits());
+            }
+        }
+        impl<'a> ImrUpdate<'a> {
+            #[doc = "Replace every field of the register with those of `value`"]
+            #[inline(always)]
+            pub fn assign<'b>(&'b mut self, value: ImrValue) -> &'b mut Self {
+                self.value = value.to_bits();
+                self.mask = !0;
+                self
+            }
+        }
+        impl ImrGet {
+            pub const FIELDS: &'static [::volatile_cell::field::FieldDescriptor] =
+                &[::volatile_cell::field::FieldDescriptor {
+                    name: "bits",
+                    offset: 0u32,
+                    width: 32u32,
+                    count: 1u32,
+                }];
+            #[doc = "Iterate over the readable fields that differ from those of `other`"]
+            #[inline]
+            pub fn changed_fields(&self, other: &ImrGet) -> ::volatile_cell::field::FieldChanges {
+                ::volatile_cell::field::FieldChanges::new(
+                    Self::FIELDS,
+                    self.value as u64,
+                    other.value as u64,
+                )
+            }
+        }
+        pub mod isr {}
+        #[repr(C)]
+        pub struct Isr {
+            value: ::volatile_cell::VolatileCell<u32>,
+        }
+        impl Isr {
+            #[inline(always)]
+            fn load(&self) -> u32 {
+                let value = self.value.get();
+                value
+            }
+            #[inline(always)]
+            fn store(&self, value: u32) {
+                self.value.set(value);
+            }
+            #[doc = "Create a new updater"]
+            #[inline(always)]
+            pub fn update<'a>(&'a self) -> IsrUpdate<'a> {
+                IsrUpdate::new(self)
+            }
+            #[doc = "Create an updater whose read-modify-write happens inside the critical section `cs`"]
+            #[inline(always)]
+            pub fn update_in<'a, C: ::volatile_cell::sync::CriticalSection>(
+                &'a self,
+                _cs: &'a C,
+            ) -> IsrUpdate<'a> {
+                IsrUpdate::new(self)
+            }
+            #[doc = "Update the register with exclusive loads and stores (LDREX/STREX), retrying `f` until no other access intervened"]
+            #[doc = ""]
+            #[doc = "The exclusive accesses go to the register's memory directly rather than through its cell, so they aren't seen by the replayer or the tracing hook."]
+            #[cfg(target_has_atomic = "32")]
+            #[inline]
+            pub fn update_exclusive<F: FnMut(&mut IsrUpdate)>(&self, mut f: F) {
+                use ::core::sync::atomic::Ordering;
+                let cell =
+                    unsafe { &*(self.value.as_ptr() as *const ::core::sync::atomic::AtomicU32) };
+                let mut current = cell.load(Ordering::Relaxed);
+                loop {
+                    let mut update = IsrUpdate::detached(self);
+                    f(&mut update);
+                    let merged = update.merge(current);
+                    if update.mask == 0 {
+                        return;
+                    }
+                    match cell.compare_exchange_weak(
+                        current,
+                        merged,
+                        Ordering::SeqCst,
+                        Ordering::Relaxed,
+                    ) {
+                        Ok(_) => return,
+                        Err(actual) => current = actual,
+                    }
+                }
+            }
+            #[doc = "Create a getter representing the current state of the register"]
+            #[inline(always)]
+            pub fn get(&self) -> IsrGet {
+                IsrGet::new(self)
+            }
+        }
+        pub struct IsrUpdate<'a> {
+            value: u32,
+            mask: u32,
+            write_only: bool,
+            store_on_drop: bool,
+            reg: &'a Isr,
+        }
+        impl<'a> IsrUpdate<'a> {
+            #[inline(always)]
+            pub fn new(reg: &'a Isr) -> IsrUpdate<'a> {
+                IsrUpdate {
+                    value: 0,
+                    mask: 0,
+                    write_only: false,
+                    store_on_drop: true,
+                    reg: reg,
+                }
+            }
+            #[inline(always)]
+            pub fn new_ignoring_state(reg: &'a Isr) -> IsrUpdate<'a> {
+                IsrUpdate {
+                    value: 0,
+                    mask: 0,
+                    write_only: true,
+                    store_on_drop: true,
+                    reg: reg,
+                }
+            }
+            #[doc = "An update that is never stored, for the caller to store itself"]
+            #[inline(always)]
+            fn detached(reg: &'a Isr) -> IsrUpdate<'a> {
+                IsrUpdate {
+                    value: 0,
+                    mask: 0,
+                    write_only: false,
+                    store_on_drop: false,
+                    reg: reg,
+                }
+            }
+            const fn clear_mask() -> u32 {
+                0u64 as u32
+            }
+            const fn update_offset(base: usize, size: usize, index: usize) -> usize {
+                base + (size * index)
+            }
+            #[inline(always)]
+            fn merge(&self, current: u32) -> u32 {
+                let v: u32 = current & (!Self::clear_mask()) & (!self.mask);
+                self.value | v
+            }
+        }
+        impl<'a> Drop for IsrUpdate<'a> {
+            #[inline(always)]
+            fn drop(&mut self) {
+                if self.store_on_drop && self.mask != 0 {
+                    let current: u32 = if self.write_only { 0 } else { self.reg.load() };
+                    self.reg.store(self.merge(current));
+                }
+            }
+        }
+        #[derive(Clone, PartialEq, Eq)]
+        pub struct IsrGet {
+            value: u32,
+        }
+        impl IsrGet {
+            #[doc = "Create a getter reflecting the current value of the register"]
+            #[inline(always)]
+            pub fn new(reg: &Isr) -> IsrGet {
+                IsrGet { value: reg.load() }
+            }
+            #[inline(always)]
+            pub fn bits(&self) -> u32 {
+                self.value
+            }
+        }
+        #[derive(Clone, Copy, PartialEq, Eq, Default)]
+        #[repr(transparent)]
+        pub struct IsrValue {
+            value: u32,
+        }
+        impl IsrValue {
+            #[doc = "Create a value with every field cleared"]
+            #[inline(always)]
+            pub const fn new() -> IsrValue {
+                IsrValue { value: 0 }
+            }
+            #[inline(always)]
+            pub const fn from_bits(bits: u32) -> IsrValue {
+                IsrValue { value: bits }
+            }
+            #[inline(always)]
+            pub const fn to_bits(&self) -> u32 {
+                self.value
+            }
+            #[inline(always)]
+            pub fn bits(&self) -> u32 {
+                self.value
+            }
+            #[inline(always)]
+            pub const fn with_bits(self, new_value: u32) -> Self {
+                let context_mask: u32 = 4294967295 << 0;
+                Self {
+                    value: (self.value & !context_mask) | (((new_value as u32) & 4294967295) << 0),
+                }
+            }
+        }
+        impl From<IsrGet> for IsrValue {
+            #[inline(always)]
+            fn from(get: IsrGet) -> IsrValue {
+                IsrValue::from_bits(get.value)
+            }
+        }
+        impl From<IsrValue> for IsrGet {
+            #[inline(always)]
+            fn from(value: IsrValue) -> IsrGet {
+                IsrGet {
+                    value: value.to_bits(),
+                }
+            }
+        }
+        impl Isr {
+            #[doc = "Write `value` to the register in a single store, without reading it first"]
+            #[inline(always)]
+            pub fn write(&self, value: IsrValue) {
+                self.store(value.to_bits());
+            }
+        }
+        impl<'a> IsrUpdate<'a> {
+            #[doc = "Replace every field of the register with those of `value`"]
+            #[inline(always)]
+            pub fn assign<'b>(&'b mut self, value: IsrValue) -> &'b mut Self {
+                self.value = value.to_bits();
+                self.mask = !0;
+                self
+            }
+        }
+        impl IsrGet {
+            pub const FIELDS: &'static [::volatile_cell::field::FieldDescriptor] =
+                &[::volatile_cell::field::FieldDescriptor {
+                    name: "bits",
+                    offset: 0u32,
+                    width: 32u32,
+                    count: 1u32,
+                }];
+            #[doc = "Iterate over the readable fields that differ from those of `other`"]
+            #[inline]
+            pub fn changed_fields(&self, other: &IsrGet) -> ::volatile_cell::field::FieldChanges {
+                ::volatile_cell::field::FieldChanges::new(
+                    Self::FIELDS,
+                    self.value as u64,
+                    other.value as u64,
+                )
+            }
+        }
+        #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+        pub struct IrqFlags(u32);
+        #[allow(non_upper_case_globals)]
+        impl IrqFlags {
+            pub const Overflow: IrqFlags = IrqFlags(1 << 0);
+            pub const Compare: IrqFlags = IrqFlags(1 << 1);
+            #[inline(always)]
+            pub const fn empty() -> IrqFlags {
+                IrqFlags(0)
+            }
+            #[inline(always)]
+            pub const fn all() -> IrqFlags {
+                IrqFlags(3 as u32)
+            }
+            #[doc = "Create a set from raw bits, dropping those outside the field"]
+            #[inline(always)]
+            pub const fn from_bits_truncate(bits: u32) -> IrqFlags {
+                IrqFlags(bits & (3 as u32))
+            }
+            #[inline(always)]
+            pub const fn bits(&self) -> u32 {
+                self.0
+            }
+            #[inline(always)]
+            pub const fn is_empty(&self) -> bool {
+                self.0 == 0
+            }
+            #[inline(always)]
+            pub const fn contains(&self, other: IrqFlags) -> bool {
+                (self.0 & other.0) == other.0
+            }
+            #[inline(always)]
+            pub const fn intersects(&self, other: IrqFlags) -> bool {
+                (self.0 & other.0) != 0
+            }
+        }
+        impl From<u32> for IrqFlags {
+            #[inline(always)]
+            fn from(bits: u32) -> IrqFlags {
+                IrqFlags::from_bits_truncate(bits)
+            }
+        }
+        impl core::ops::BitOr for IrqFlags {
+            type Output = IrqFlags;
+            #[inline(always)]
+            fn bitor(self, other: IrqFlags) -> IrqFlags {
+                IrqFlags(self.0 | other.0)
+            }
+        }
+        impl core::ops::BitOrAssign for IrqFlags {
+            #[inline(always)]
+            fn bitor_assign(&mut self, other: IrqFlags) {
+                self.0 |= other.0;
+            }
+        }
+        impl core::ops::BitAnd for IrqFlags {
+            type Output = IrqFlags;
+            #[inline(always)]
+            fn bitand(self, other: IrqFlags) -> IrqFlags {
+                IrqFlags(self.0 & other.0)
+            }
+        }
+        impl core::ops::BitAndAssign for IrqFlags {
+            #[inline(always)]
+            fn bitand_assign(&mut self, other: IrqFlags) {
+                self.0 &= other.0;
+            }
+        }
+        impl core::ops::Sub for IrqFlags {
+            type Output = IrqFlags;
+            #[inline(always)]
+            fn sub(self, other: IrqFlags) -> IrqFlags {
+                IrqFlags(self.0 & !other.0)
+            }
+        }
+        impl core::ops::Not for IrqFlags {
+            type Output = IrqFlags;
+            #[inline(always)]
+            fn not(self) -> IrqFlags {
+                IrqFlags(!self.0 & (3 as u32))
+            }
+        }
+        impl Irq {
+            #[doc = "Enable the interrupts in `flags`, leaving the others as they are"]
+            #[inline(always)]
+            pub fn enable<F: Into<IrqFlags>>(&self, flags: F) {
+                self.ier.update().set_bits(flags.into().bits());
+            }
+            #[doc = "Disable the interrupts in `flags`, leaving the others as they are"]
+            #[inline(always)]
+            pub fn disable<F: Into<IrqFlags>>(&self, flags: F) {
+                self.idr.update().set_bits(flags.into().bits());
+            }
+            #[doc = "The enabled interrupts"]
+            #[inline(always)]
+            pub fn mask(&self) -> IrqFlags {
+                IrqFlags::from_bits_truncate(self.imr.get().bits())
+            }
+            #[doc = "The pending interrupts. On many devices reading them acknowledges them"]
+            #[inline(always)]
+            pub fn status(&self) -> IrqFlags {
+                IrqFlags::from_bits_truncate(self.isr.get().bits())
+            }
+        }
+        #[repr(C)]
+        pub struct Irq {
+            pub ier: Ier,
+            pub idr: Idr,
+            pub imr: Imr,
+            pub isr: Isr,
+        }
+        #[doc = "Saved state of every readable register without side effects"]
+        #[derive(Clone, Copy)]
+        pub struct Snapshot {
+            pub imr: ImrValue,
+        }
+        #[doc = "Read the current state of the peripheral"]
+        #[allow(unused_variables)]
+        pub fn capture(p: &Irq) -> Snapshot {
+            Snapshot {
+                imr: ImrValue::from(p.imr.get()),
+            }
+        }
+        #[doc = "Write a previously captured state back to the peripheral"]
+        #[allow(unused_variables)]
+        pub fn restore(p: &Irq, snapshot: &Snapshot) {}
+    }
+    #[repr(C)]
+    pub struct Timer {
+        pub mode: Mode,
+        pub isr: Isr,
+        _padding0: [u8; 8],
+        pub chan: [chan::Chan; 2],
+        pub irq: irq::Irq,
+    }
+    #[doc = "Saved state of every readable register without side effects"]
+    #[derive(Clone, Copy)]
+    pub struct Snapshot {
+        pub mode: ModeValue,
+        pub chan: [chan::Snapshot; 2],
+        pub irq: irq::Snapshot,
+    }
+    #[doc = "Read the current state of the peripheral"]
+    #[allow(unused_variables)]
+    pub fn capture(p: &Timer) -> Snapshot {
+        Snapshot {
+            mode: ModeValue::from(p.mode.get()),
+            chan: {
+                let mut snapshots = [chan::capture(&p.chan[0]); 2];
+                for i in 1..2 {
+                    snapshots[i] = chan::capture(&p.chan[i]);
+                }
+                snapshots
+            },
+            irq: irq::capture(&p.irq),
+        }
+    }
+    #[doc = "Write a previously captured state back to the peripheral"]
+    #[allow(unused_variables)]
+    pub fn restore(p: &Timer, snapshot: &Snapshot) {
+        for i in 0..2 {
+            chan::restore(&p.chan[i], &snapshot.chan[i]);
+        }
+        irq::restore(&p.irq, &snapshot.irq);
+        ModeUpdate::new_ignoring_state(&p.mode).assign(snapshot.mode);
+    }
+    #[doc = "Owned handle granting exclusive access to the peripheral"]
+    pub struct TimerPeripheral {
+        regs: &'static mut Timer,
+    }
+    impl TimerPeripheral {
+        #[doc = "Create a token for the peripheral registers at `regs`."]
+        #[doc = ""]
+        #[doc = "Unsafe because the caller must ensure it's the only token for `regs`."]
+        #[inline(always)]
+        pub const unsafe fn new(regs: &'static mut Timer) -> TimerPeripheral {
+            TimerPeripheral { regs: regs }
+        }
+    }
+    impl ::core::ops::Deref for TimerPeripheral {
+        type Target = Timer;
+        #[inline(always)]
+        fn deref(&self) -> &Timer {
+            self.regs
+        }
+    }
+    impl ::core::ops::DerefMut for TimerPeripheral {
+        #[inline(always)]
+        fn deref_mut(&mut self) -> &mut Timer {
+            self.regs
+        }
+    }
+}
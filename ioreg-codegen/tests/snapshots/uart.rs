@@ -0,0 +1,1374 @@
+pub mod uart {
+    pub mod cr {}
+    #[repr(C)]
+    pub struct Cr {
+        value: ::volatile_cell::VolatileCell<u32>,
+    }
+    impl Cr {
+        #[inline(always)]
+        fn load(&self) -> u32 {
+            let value = self.value.get();
+            value
+        }
+        #[inline(always)]
+        fn store(&self, value: u32) {
+            self.value.set(value);
+        }
+        #[doc = "Create a new updater"]
+        #[inline(always)]
+        pub fn update<'a>(&'a self) -> CrUpdate<'a> {
+            CrUpdate::new(self)
+        }
+        #[doc = "Create an updater whose read-modify-write happens inside the critical section `cs`"]
+        #[inline(always)]
+        pub fn update_in<'a, C: ::volatile_cell::sync::CriticalSection>(
+            &'a self,
+            _cs: &'a C,
+        ) -> CrUpdate<'a> {
+            CrUpdate::new(self)
+        }
+        #[doc = "Create a getter representing the current state of the register"]
+        #[inline(always)]
+        pub fn get(&self) -> CrGet {
+            CrGet::new(self)
+        }
+        #[doc = "Address of the bit-band alias word for `bit` of this register"]
+        #[inline(always)]
+        pub fn bitband_alias(&self, bit: usize) -> *mut u32 {
+            let address = self as *const Self as usize;
+            1107296256usize
+                .wrapping_add(address.wrapping_sub(1073741824usize) << 5)
+                .wrapping_add(bit << 2) as *mut u32
+        }
+    }
+    pub struct CrUpdate<'a> {
+        value: u32,
+        mask: u32,
+        write_only: bool,
+        store_on_drop: bool,
+        reg: &'a Cr,
+    }
+    impl<'a> CrUpdate<'a> {
+        #[inline(always)]
+        pub fn new(reg: &'a Cr) -> CrUpdate<'a> {
+            CrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[inline(always)]
+        pub fn new_ignoring_state(reg: &'a Cr) -> CrUpdate<'a> {
+            CrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: true,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[doc = "An update that is never stored, for the caller to store itself"]
+        #[inline(always)]
+        fn detached(reg: &'a Cr) -> CrUpdate<'a> {
+            CrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: false,
+                reg: reg,
+            }
+        }
+        const fn clear_mask() -> u32 {
+            0u64 as u32
+        }
+        const fn update_offset(base: usize, size: usize, index: usize) -> usize {
+            base + (size * index)
+        }
+        #[inline(always)]
+        fn merge(&self, current: u32) -> u32 {
+            let v: u32 = current & (!Self::clear_mask()) & (!self.mask);
+            self.value | v
+        }
+        #[inline(always)]
+        pub fn set_rstrx<'b>(&'b mut self, new_value: bool) -> &'b mut Self {
+            let context_mask: u32 = 1 << 2;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 1) << 2);
+            self.mask |= context_mask;
+            self
+        }
+        #[inline(always)]
+        pub fn set_rsttx<'b>(&'b mut self, new_value: bool) -> &'b mut Self {
+            let context_mask: u32 = 1 << 3;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 1) << 3);
+            self.mask |= context_mask;
+            self
+        }
+        #[inline(always)]
+        pub fn set_rxen<'b>(&'b mut self, new_value: bool) -> &'b mut Self {
+            let context_mask: u32 = 1 << 4;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 1) << 4);
+            self.mask |= context_mask;
+            self
+        }
+        #[inline(always)]
+        pub fn set_txen<'b>(&'b mut self, new_value: bool) -> &'b mut Self {
+            let context_mask: u32 = 1 << 6;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 1) << 6);
+            self.mask |= context_mask;
+            self
+        }
+    }
+    impl<'a> Drop for CrUpdate<'a> {
+        #[inline(always)]
+        fn drop(&mut self) {
+            if self.store_on_drop && self.mask != 0 {
+                let current: u32 = 0;
+                self.reg.store(self.merge(current));
+            }
+        }
+    }
+    #[derive(Clone, PartialEq, Eq)]
+    pub struct CrGet {
+        value: u32,
+    }
+    impl CrGet {
+        #[doc = "Create a getter reflecting the current value of the register"]
+        #[inline(always)]
+        pub fn new(reg: &Cr) -> CrGet {
+            CrGet { value: reg.load() }
+        }
+    }
+    #[derive(Clone, Copy, PartialEq, Eq, Default)]
+    #[repr(transparent)]
+    pub struct CrValue {
+        value: u32,
+    }
+    impl CrValue {
+        #[doc = "Create a value with every field cleared"]
+        #[inline(always)]
+        pub const fn new() -> CrValue {
+            CrValue { value: 0 }
+        }
+        #[inline(always)]
+        pub const fn from_bits(bits: u32) -> CrValue {
+            CrValue { value: bits }
+        }
+        #[inline(always)]
+        pub const fn to_bits(&self) -> u32 {
+            self.value
+        }
+        #[inline(always)]
+        pub fn rstrx(&self) -> bool {
+            let val = ((self.value >> 2) & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub fn rsttx(&self) -> bool {
+            let val = ((self.value >> 3) & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub fn rxen(&self) -> bool {
+            let val = ((self.value >> 4) & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub fn txen(&self) -> bool {
+            let val = ((self.value >> 6) & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub const fn with_rstrx(self, new_value: bool) -> Self {
+            let context_mask: u32 = 1 << 2;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 1) << 2),
+            }
+        }
+        #[inline(always)]
+        pub const fn with_rsttx(self, new_value: bool) -> Self {
+            let context_mask: u32 = 1 << 3;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 1) << 3),
+            }
+        }
+        #[inline(always)]
+        pub const fn with_rxen(self, new_value: bool) -> Self {
+            let context_mask: u32 = 1 << 4;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 1) << 4),
+            }
+        }
+        #[inline(always)]
+        pub const fn with_txen(self, new_value: bool) -> Self {
+            let context_mask: u32 = 1 << 6;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 1) << 6),
+            }
+        }
+    }
+    impl From<CrGet> for CrValue {
+        #[inline(always)]
+        fn from(get: CrGet) -> CrValue {
+            CrValue::from_bits(get.value)
+        }
+    }
+    impl From<CrValue> for CrGet {
+        #[inline(always)]
+        fn from(value: CrValue) -> CrGet {
+            CrGet {
+                value: value.to_bits(),
+            }
+        }
+    }
+    impl Cr {
+        #[doc = "Write `value` to the register in a single store, without reading it first"]
+        #[inline(always)]
+        pub fn write(&self, value: CrValue) {
+            self.store(value.to_bits());
+        }
+    }
+    impl<'a> CrUpdate<'a> {
+        #[doc = "Replace every field of the register with those of `value`"]
+        #[inline(always)]
+        pub fn assign<'b>(&'b mut self, value: CrValue) -> &'b mut Self {
+            self.value = value.to_bits();
+            self.mask = !0;
+            self
+        }
+    }
+    impl CrGet {
+        pub const FIELDS: &'static [::volatile_cell::field::FieldDescriptor] = &[];
+        #[doc = "Iterate over the readable fields that differ from those of `other`"]
+        #[inline]
+        pub fn changed_fields(&self, other: &CrGet) -> ::volatile_cell::field::FieldChanges {
+            ::volatile_cell::field::FieldChanges::new(
+                Self::FIELDS,
+                self.value as u64,
+                other.value as u64,
+            )
+        }
+    }
+    pub mod mr {
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        #[repr(u32)]
+        pub enum Par {
+            Even = 0x0,
+            Odd = 0x1,
+            No = 0x4,
+        }
+        impl core::convert::TryFrom<u32> for Par {
+            type Error = u32;
+            #[inline(always)]
+            fn try_from(primitive: u32) -> Result<Self, Self::Error> {
+                match primitive {
+                    0x0 => Ok(Par::Even),
+                    0x1 => Ok(Par::Odd),
+                    0x4 => Ok(Par::No),
+                    v => Err(v),
+                }
+            }
+        }
+    }
+    #[repr(C)]
+    pub struct Mr {
+        value: ::volatile_cell::VolatileCell<u32>,
+    }
+    impl Mr {
+        #[inline(always)]
+        fn load(&self) -> u32 {
+            let value = self.value.get();
+            value
+        }
+        #[inline(always)]
+        fn store(&self, value: u32) {
+            self.value.set(value);
+        }
+        #[doc = "Create a new updater"]
+        #[inline(always)]
+        pub fn update<'a>(&'a self) -> MrUpdate<'a> {
+            MrUpdate::new(self)
+        }
+        #[doc = "Create an updater whose read-modify-write happens inside the critical section `cs`"]
+        #[inline(always)]
+        pub fn update_in<'a, C: ::volatile_cell::sync::CriticalSection>(
+            &'a self,
+            _cs: &'a C,
+        ) -> MrUpdate<'a> {
+            MrUpdate::new(self)
+        }
+        #[doc = "Update the register with exclusive loads and stores (LDREX/STREX), retrying `f` until no other access intervened"]
+        #[doc = ""]
+        #[doc = "The exclusive accesses go to the register's memory directly rather than through its cell, so they aren't seen by the replayer or the tracing hook."]
+        #[cfg(target_has_atomic = "32")]
+        #[inline]
+        pub fn update_exclusive<F: FnMut(&mut MrUpdate)>(&self, mut f: F) {
+            use ::core::sync::atomic::Ordering;
+            let cell = unsafe { &*(self.value.as_ptr() as *const ::core::sync::atomic::AtomicU32) };
+            let mut current = cell.load(Ordering::Relaxed);
+            loop {
+                let mut update = MrUpdate::detached(self);
+                f(&mut update);
+                let merged = update.merge(current);
+                if update.mask == 0 {
+                    return;
+                }
+                match cell.compare_exchange_weak(
+                    current,
+                    merged,
+                    Ordering::SeqCst,
+                    Ordering::Relaxed,
+                ) {
+                    Ok(_) => return,
+                    Err(actual) => current = actual,
+                }
+            }
+        }
+        #[doc = "Create a getter representing the current state of the register"]
+        #[inline(always)]
+        pub fn get(&self) -> MrGet {
+            MrGet::new(self)
+        }
+        #[doc = "Address of the bit-band alias word for `bit` of this register"]
+        #[inline(always)]
+        pub fn bitband_alias(&self, bit: usize) -> *mut u32 {
+            let address = self as *const Self as usize;
+            1107296256usize
+                .wrapping_add(address.wrapping_sub(1073741824usize) << 5)
+                .wrapping_add(bit << 2) as *mut u32
+        }
+    }
+    pub struct MrUpdate<'a> {
+        value: u32,
+        mask: u32,
+        write_only: bool,
+        store_on_drop: bool,
+        reg: &'a Mr,
+    }
+    impl<'a> MrUpdate<'a> {
+        #[inline(always)]
+        pub fn new(reg: &'a Mr) -> MrUpdate<'a> {
+            MrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[inline(always)]
+        pub fn new_ignoring_state(reg: &'a Mr) -> MrUpdate<'a> {
+            MrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: true,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[doc = "An update that is never stored, for the caller to store itself"]
+        #[inline(always)]
+        fn detached(reg: &'a Mr) -> MrUpdate<'a> {
+            MrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: false,
+                reg: reg,
+            }
+        }
+        const fn clear_mask() -> u32 {
+            0u64 as u32
+        }
+        const fn update_offset(base: usize, size: usize, index: usize) -> usize {
+            base + (size * index)
+        }
+        #[inline(always)]
+        fn merge(&self, current: u32) -> u32 {
+            let v: u32 = current & (!Self::clear_mask()) & (!self.mask);
+            self.value | v
+        }
+        #[inline(always)]
+        pub fn set_par<'b>(&'b mut self, new_value: mr::Par) -> &'b mut Self {
+            let context_mask: u32 = 7 << 9;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 7) << 9);
+            self.mask |= context_mask;
+            self
+        }
+        #[inline(always)]
+        pub fn set_chmode<'b>(&'b mut self, new_value: u8) -> &'b mut Self {
+            let context_mask: u32 = 3 << 14;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 3) << 14);
+            self.mask |= context_mask;
+            self
+        }
+    }
+    impl<'a> Drop for MrUpdate<'a> {
+        #[inline(always)]
+        fn drop(&mut self) {
+            if self.store_on_drop && self.mask != 0 {
+                let current: u32 = if self.write_only { 0 } else { self.reg.load() };
+                self.reg.store(self.merge(current));
+            }
+        }
+    }
+    #[derive(Clone, PartialEq, Eq)]
+    pub struct MrGet {
+        value: u32,
+    }
+    impl MrGet {
+        #[doc = "Create a getter reflecting the current value of the register"]
+        #[inline(always)]
+        pub fn new(reg: &Mr) -> MrGet {
+            MrGet { value: reg.load() }
+        }
+        #[inline(always)]
+        pub fn par(&self) -> mr::Par {
+            use core::convert::TryFrom;
+            let primitive_value: u32 = ((self.value >> 9) & 7);
+            mr::Par::try_from(primitive_value).unwrap()
+        }
+        #[inline(always)]
+        pub fn chmode(&self) -> u32 {
+            ((self.value >> 14) & 3)
+        }
+    }
+    #[derive(Clone, Copy, PartialEq, Eq, Default)]
+    #[repr(transparent)]
+    pub struct MrValue {
+        value: u32,
+    }
+    impl MrValue {
+        #[doc = "Create a value with every field cleared"]
+        #[inline(always)]
+        pub const fn new() -> MrValue {
+            MrValue { value: 0 }
+        }
+        #[inline(always)]
+        pub const fn from_bits(bits: u32) -> MrValue {
+            MrValue { value: bits }
+        }
+        #[inline(always)]
+        pub const fn to_bits(&self) -> u32 {
+            self.value
+        }
+        #[inline(always)]
+        pub fn par(&self) -> Result<mr::Par, u32> {
+            use core::convert::TryFrom;
+            let primitive_value: u32 = ((self.value >> 9) & 7);
+            mr::Par::try_from(primitive_value)
+        }
+        #[inline(always)]
+        pub fn chmode(&self) -> u32 {
+            ((self.value >> 14) & 3)
+        }
+        #[inline(always)]
+        pub const fn with_par(self, new_value: mr::Par) -> Self {
+            let context_mask: u32 = 7 << 9;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 7) << 9),
+            }
+        }
+        #[inline(always)]
+        pub const fn with_chmode(self, new_value: u8) -> Self {
+            let context_mask: u32 = 3 << 14;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 3) << 14),
+            }
+        }
+    }
+    impl From<MrGet> for MrValue {
+        #[inline(always)]
+        fn from(get: MrGet) -> MrValue {
+            MrValue::from_bits(get.value)
+        }
+    }
+    impl From<MrValue> for MrGet {
+        #[inline(always)]
+        fn from(value: MrValue) -> MrGet {
+            MrGet {
+                value: value.to_bits(),
+            }
+        }
+    }
+    impl Mr {
+        #[doc = "Write `value` to the register in a single store, without reading it first"]
+        #[inline(always)]
+        pub fn write(&self, value: MrValue) {
+            self.store(value.to_bits());
+        }
+    }
+    impl<'a> MrUpdate<'a> {
+        #[doc = "Replace every field of the register with those of `value`"]
+        #[inline(always)]
+        pub fn assign<'b>(&'b mut self, value: MrValue) -> &'b mut Self {
+            self.value = value.to_bits();
+            self.mask = !0;
+            self
+        }
+    }
+    impl MrGet {
+        pub const FIELDS: &'static [::volatile_cell::field::FieldDescriptor] = &[
+            ::volatile_cell::field::FieldDescriptor {
+                name: "par",
+                offset: 9u32,
+                width: 3u32,
+                count: 1u32,
+            },
+            ::volatile_cell::field::FieldDescriptor {
+                name: "chmode",
+                offset: 14u32,
+                width: 2u32,
+                count: 1u32,
+            },
+        ];
+        #[doc = "Iterate over the readable fields that differ from those of `other`"]
+        #[inline]
+        pub fn changed_fields(&self, other: &MrGet) -> ::volatile_cell::field::FieldChanges {
+            ::volatile_cell::field::FieldChanges::new(
+                Self::FIELDS,
+                self.value as u64,
+                other.value as u64,
+            )
+        }
+    }
+    pub mod sr {}
+    #[repr(C)]
+    pub struct Sr {
+        value: ::volatile_cell::VolatileCell<u32>,
+    }
+    impl Sr {
+        #[inline(always)]
+        fn load(&self) -> u32 {
+            let value = self.value.get();
+            value
+        }
+        #[inline(always)]
+        fn store(&self, value: u32) {
+            self.value.set(value);
+        }
+        #[doc = "Create a new updater"]
+        #[inline(always)]
+        pub fn update<'a>(&'a self) -> SrUpdate<'a> {
+            SrUpdate::new(self)
+        }
+        #[doc = "Create an updater whose read-modify-write happens inside the critical section `cs`"]
+        #[inline(always)]
+        pub fn update_in<'a, C: ::volatile_cell::sync::CriticalSection>(
+            &'a self,
+            _cs: &'a C,
+        ) -> SrUpdate<'a> {
+            SrUpdate::new(self)
+        }
+        #[doc = "Update the register with exclusive loads and stores (LDREX/STREX), retrying `f` until no other access intervened"]
+        #[doc = ""]
+        #[doc = "The exclusive accesses go to the register's memory directly rather than through its cell, so they aren't seen by the replayer or the tracing hook."]
+        #[cfg(target_has_atomic = "32")]
+        #[inline]
+        pub fn update_exclusive<F: FnMut(&mut SrUpdate)>(&self, mut f: F) {
+            use ::core::sync::atomic::Ordering;
+            let cell = unsafe { &*(self.value.as_ptr() as *const ::core::sync::atomic::AtomicU32) };
+            let mut current = cell.load(Ordering::Relaxed);
+            loop {
+                let mut update = SrUpdate::detached(self);
+                f(&mut update);
+                let merged = update.merge(current);
+                if update.mask == 0 {
+                    return;
+                }
+                match cell.compare_exchange_weak(
+                    current,
+                    merged,
+                    Ordering::SeqCst,
+                    Ordering::Relaxed,
+                ) {
+                    Ok(_) => return,
+                    Err(actual) => current = actual,
+                }
+            }
+        }
+        #[doc = "Create a getter representing the current state of the register"]
+        #[inline(always)]
+        pub fn get(&self) -> SrGet {
+            SrGet::new(self)
+        }
+        #[doc = "Spin until the bit is set"]
+        #[inline]
+        pub fn wait_rxrdy(&self) {
+            while !self.get().rxrdy() {}
+        }
+        #[doc = "Poll until the bit is set, at most `max_polls` times"]
+        #[inline]
+        pub fn wait_rxrdy_timeout(
+            &self,
+            max_polls: u32,
+        ) -> Result<(), ::volatile_cell::wait::Timeout> {
+            ::volatile_cell::wait::poll_until(|| self.get().rxrdy(), max_polls)
+        }
+        #[doc = "Future resolving once the bit is set"]
+        #[inline]
+        pub fn wait_rxrdy_async<'a>(
+            &'a self,
+        ) -> ::volatile_cell::wait::WaitUntil<'a, impl FnMut() -> bool + Unpin + 'a> {
+            ::volatile_cell::wait::WaitUntil::new(move || self.get().rxrdy())
+        }
+        #[doc = "Spin until the bit is set"]
+        #[inline]
+        pub fn wait_txrdy(&self) {
+            while !self.get().txrdy() {}
+        }
+        #[doc = "Poll until the bit is set, at most `max_polls` times"]
+        #[inline]
+        pub fn wait_txrdy_timeout(
+            &self,
+            max_polls: u32,
+        ) -> Result<(), ::volatile_cell::wait::Timeout> {
+            ::volatile_cell::wait::poll_until(|| self.get().txrdy(), max_polls)
+        }
+        #[doc = "Future resolving once the bit is set"]
+        #[inline]
+        pub fn wait_txrdy_async<'a>(
+            &'a self,
+        ) -> ::volatile_cell::wait::WaitUntil<'a, impl FnMut() -> bool + Unpin + 'a> {
+            ::volatile_cell::wait::WaitUntil::new(move || self.get().txrdy())
+        }
+        #[doc = "Spin until the bit is set"]
+        #[inline]
+        pub fn wait_ovre(&self) {
+            while !self.get().ovre() {}
+        }
+        #[doc = "Poll until the bit is set, at most `max_polls` times"]
+        #[inline]
+        pub fn wait_ovre_timeout(
+            &self,
+            max_polls: u32,
+        ) -> Result<(), ::volatile_cell::wait::Timeout> {
+            ::volatile_cell::wait::poll_until(|| self.get().ovre(), max_polls)
+        }
+        #[doc = "Future resolving once the bit is set"]
+        #[inline]
+        pub fn wait_ovre_async<'a>(
+            &'a self,
+        ) -> ::volatile_cell::wait::WaitUntil<'a, impl FnMut() -> bool + Unpin + 'a> {
+            ::volatile_cell::wait::WaitUntil::new(move || self.get().ovre())
+        }
+        #[doc = "Address of the bit-band alias word for `bit` of this register"]
+        #[inline(always)]
+        pub fn bitband_alias(&self, bit: usize) -> *mut u32 {
+            let address = self as *const Self as usize;
+            1107296256usize
+                .wrapping_add(address.wrapping_sub(1073741824usize) << 5)
+                .wrapping_add(bit << 2) as *mut u32
+        }
+        #[inline(always)]
+        pub fn bitband_read_rxrdy(&self) -> bool {
+            unsafe { core::ptr::read_volatile(self.bitband_alias(0)) != 0 }
+        }
+        #[inline(always)]
+        pub fn bitband_read_txrdy(&self) -> bool {
+            unsafe { core::ptr::read_volatile(self.bitband_alias(1)) != 0 }
+        }
+        #[inline(always)]
+        pub fn bitband_read_ovre(&self) -> bool {
+            unsafe { core::ptr::read_volatile(self.bitband_alias(5)) != 0 }
+        }
+    }
+    pub struct SrUpdate<'a> {
+        value: u32,
+        mask: u32,
+        write_only: bool,
+        store_on_drop: bool,
+        reg: &'a Sr,
+    }
+    impl<'a> SrUpdate<'a> {
+        #[inline(always)]
+        pub fn new(reg: &'a Sr) -> SrUpdate<'a> {
+            SrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[inline(always)]
+        pub fn new_ignoring_state(reg: &'a Sr) -> SrUpdate<'a> {
+            SrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: true,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[doc = "An update that is never stored, for the caller to store itself"]
+        #[inline(always)]
+        fn detached(reg: &'a Sr) -> SrUpdate<'a> {
+            SrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: false,
+                reg: reg,
+            }
+        }
+        const fn clear_mask() -> u32 {
+            32u64 as u32
+        }
+        const fn update_offset(base: usize, size: usize, index: usize) -> usize {
+            base + (size * index)
+        }
+        #[inline(always)]
+        fn merge(&self, current: u32) -> u32 {
+            let v: u32 = current & (!Self::clear_mask()) & (!self.mask);
+            self.value | v
+        }
+        #[inline(always)]
+        pub fn clear_ovre<'b>(&'b mut self) -> &'b mut Self {
+            self.value |= 1 << 5;
+            self.mask |= 1 << 5;
+            self
+        }
+    }
+    impl<'a> Drop for SrUpdate<'a> {
+        #[inline(always)]
+        fn drop(&mut self) {
+            if self.store_on_drop && self.mask != 0 {
+                let current: u32 = if self.write_only { 0 } else { self.reg.load() };
+                self.reg.store(self.merge(current));
+            }
+        }
+    }
+    #[derive(Clone, PartialEq, Eq)]
+    pub struct SrGet {
+        value: u32,
+    }
+    impl SrGet {
+        #[doc = "Create a getter reflecting the current value of the register"]
+        #[inline(always)]
+        pub fn new(reg: &Sr) -> SrGet {
+            SrGet { value: reg.load() }
+        }
+        #[inline(always)]
+        pub fn rxrdy(&self) -> bool {
+            let val = (self.value & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub fn txrdy(&self) -> bool {
+            let val = ((self.value >> 1) & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub fn ovre(&self) -> bool {
+            let val = ((self.value >> 5) & 1);
+            val != 0x0
+        }
+    }
+    #[derive(Clone, Copy, PartialEq, Eq, Default)]
+    #[repr(transparent)]
+    pub struct SrValue {
+        value: u32,
+    }
+    impl SrValue {
+        #[doc = "Create a value with every field cleared"]
+        #[inline(always)]
+        pub const fn new() -> SrValue {
+            SrValue { value: 0 }
+        }
+        #[inline(always)]
+        pub const fn from_bits(bits: u32) -> SrValue {
+            SrValue { value: bits }
+        }
+        #[inline(always)]
+        pub const fn to_bits(&self) -> u32 {
+            self.value
+        }
+        #[inline(always)]
+        pub fn rxrdy(&self) -> bool {
+            let val = (self.value & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub fn txrdy(&self) -> bool {
+            let val = ((self.value >> 1) & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub fn ovre(&self) -> bool {
+            let val = ((self.value >> 5) & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub const fn with_rxrdy(self, new_value: bool) -> Self {
+            let context_mask: u32 = 1 << 0;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 1) << 0),
+            }
+        }
+        #[inline(always)]
+        pub const fn with_txrdy(self, new_value: bool) -> Self {
+            let context_mask: u32 = 1 << 1;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 1) << 1),
+            }
+        }
+        #[inline(always)]
+        pub const fn with_ovre(self, new_value: bool) -> Self {
+            let context_mask: u32 = 1 << 5;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 1) << 5),
+            }
+        }
+    }
+    impl From<SrGet> for SrValue {
+        #[inline(always)]
+        fn from(get: SrGet) -> SrValue {
+            SrValue::from_bits(get.value)
+        }
+    }
+    impl From<SrValue> for SrGet {
+        #[inline(always)]
+        fn from(value: SrValue) -> SrGet {
+            SrGet {
+                value: value.to_bits(),
+            }
+        }
+    }
+    impl Sr {
+        #[doc = "Write `value` to the register in a single store, without reading it first"]
+        #[inline(always)]
+        pub fn write(&self, value: SrValue) {
+            self.store(value.to_bits());
+        }
+    }
+    impl<'a> SrUpdate<'a> {
+        #[doc = "Replace every field of the register with those of `value`"]
+        #[inline(always)]
+        pub fn assign<'b>(&'b mut self, value: SrValue) -> &'b mut Self {
+            self.value = value.to_bits();
+            self.mask = !0;
+            self
+        }
+    }
+    impl SrGet {
+        pub const FIELDS: &'static [::volatile_cell::field::FieldDescriptor] = &[
+            ::volatile_cell::field::FieldDescriptor {
+                name: "rxrdy",
+                offset: 0u32,
+                width: 1u32,
+                count: 1u32,
+            },
+            ::volatile_cell::field::FieldDescriptor {
+                name: "txrdy",
+                offset: 1u32,
+                width: 1u32,
+                count: 1u32,
+            },
+            ::volatile_cell::field::FieldDescriptor {
+                name: "ovre",
+                offset: 5u32,
+                width: 1u32,
+                count: 1u32,
+            },
+        ];
+        #[doc = "Iterate over the readable fields that differ from those of `other`"]
+        #[inline]
+        pub fn changed_fields(&self, other: &SrGet) -> ::volatile_cell::field::FieldChanges {
+            ::volatile_cell::field::FieldChanges::new(
+                Self::FIELDS,
+                self.value as u64,
+                other.value as u64,
+            )
+        }
+    }
+    pub mod thr {}
+    #[repr(C)]
+    pub struct Thr {
+        value: ::volatile_cell::VolatileCell<u32>,
+    }
+    impl Thr {
+        #[inline(always)]
+        fn load(&self) -> u32 {
+            let value = self.value.get();
+            value
+        }
+        #[inline(always)]
+        fn store(&self, value: u32) {
+            self.value.set(value);
+        }
+        #[doc = "Create a new updater"]
+        #[inline(always)]
+        pub fn update<'a>(&'a self) -> ThrUpdate<'a> {
+            ThrUpdate::new(self)
+        }
+        #[doc = "Create an updater whose read-modify-write happens inside the critical section `cs`"]
+        #[inline(always)]
+        pub fn update_in<'a, C: ::volatile_cell::sync::CriticalSection>(
+            &'a self,
+            _cs: &'a C,
+        ) -> ThrUpdate<'a> {
+            ThrUpdate::new(self)
+        }
+        #[doc = "Create a getter representing the current state of the register"]
+        #[inline(always)]
+        pub fn get(&self) -> ThrGet {
+            ThrGet::new(self)
+        }
+        #[doc = "Address of the bit-band alias word for `bit` of this register"]
+        #[inline(always)]
+        pub fn bitband_alias(&self, bit: usize) -> *mut u32 {
+            let address = self as *const Self as usize;
+            1107296256usize
+                .wrapping_add(address.wrapping_sub(1073741824usize) << 5)
+                .wrapping_add(bit << 2) as *mut u32
+        }
+    }
+    pub struct ThrUpdate<'a> {
+        value: u32,
+        mask: u32,
+        write_only: bool,
+        store_on_drop: bool,
+        reg: &'a Thr,
+    }
+    impl<'a> ThrUpdate<'a> {
+        #[inline(always)]
+        pub fn new(reg: &'a Thr) -> ThrUpdate<'a> {
+            ThrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[inline(always)]
+        pub fn new_ignoring_state(reg: &'a Thr) -> ThrUpdate<'a> {
+            ThrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: true,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[doc = "An update that is never stored, for the caller to store itself"]
+        #[inline(always)]
+        fn detached(reg: &'a Thr) -> ThrUpdate<'a> {
+            ThrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: false,
+                reg: reg,
+            }
+        }
+        const fn clear_mask() -> u32 {
+            0u64 as u32
+        }
+        const fn update_offset(base: usize, size: usize, index: usize) -> usize {
+            base + (size * index)
+        }
+        #[inline(always)]
+        fn merge(&self, current: u32) -> u32 {
+            let v: u32 = current & (!Self::clear_mask()) & (!self.mask);
+            self.value | v
+        }
+        #[inline(always)]
+        pub fn set_txchr<'b>(&'b mut self, new_value: u8) -> &'b mut Self {
+            let context_mask: u32 = 255 << 0;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 255) << 0);
+            self.mask |= context_mask;
+            self
+        }
+    }
+    impl<'a> Drop for ThrUpdate<'a> {
+        #[inline(always)]
+        fn drop(&mut self) {
+            if self.store_on_drop && self.mask != 0 {
+                let current: u32 = 0;
+                self.reg.store(self.merge(current));
+            }
+        }
+    }
+    #[derive(Clone, PartialEq, Eq)]
+    pub struct ThrGet {
+        value: u32,
+    }
+    impl ThrGet {
+        #[doc = "Create a getter reflecting the current value of the register"]
+        #[inline(always)]
+        pub fn new(reg: &Thr) -> ThrGet {
+            ThrGet { value: reg.load() }
+        }
+    }
+    #[derive(Clone, Copy, PartialEq, Eq, Default)]
+    #[repr(transparent)]
+    pub struct ThrValue {
+        value: u32,
+    }
+    impl ThrValue {
+        #[doc = "Create a value with every field cleared"]
+        #[inline(always)]
+        pub const fn new() -> ThrValue {
+            ThrValue { value: 0 }
+        }
+        #[inline(always)]
+        pub const fn from_bits(bits: u32) -> ThrValue {
+            ThrValue { value: bits }
+        }
+        #[inline(always)]
+        pub const fn to_bits(&self) -> u32 {
+            self.value
+        }
+        #[inline(always)]
+        pub fn txchr(&self) -> u32 {
+            (self.value & 255)
+        }
+        #[inline(always)]
+        pub const fn with_txchr(self, new_value: u8) -> Self {
+            let context_mask: u32 = 255 << 0;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 255) << 0),
+            }
+        }
+    }
+    impl From<ThrGet> for ThrValue {
+        #[inline(always)]
+        fn from(get: ThrGet) -> ThrValue {
+            ThrValue::from_bits(get.value)
+        }
+    }
+    impl From<ThrValue> for ThrGet {
+        #[inline(always)]
+        fn from(value: ThrValue) -> ThrGet {
+            ThrGet {
+                value: value.to_bits(),
+            }
+        }
+    }
+    impl Thr {
+        #[doc = "Write `value` to the register in a single store, without reading it first"]
+        #[inline(always)]
+        pub fn write(&self, value: ThrValue) {
+            self.store(value.to_bits());
+        }
+    }
+    impl<'a> ThrUpdate<'a> {
+        #[doc = "Replace every field of the register with those of `value`"]
+        #[inline(always)]
+        pub fn assign<'b>(&'b mut self, value: ThrValue) -> &'b mut Self {
+            self.value = value.to_bits();
+            self.mask = !0;
+            self
+        }
+    }
+    impl ThrGet {
+        pub const FIELDS: &'static [::volatile_cell::field::FieldDescriptor] = &[];
+        #[doc = "Iterate over the readable fields that differ from those of `other`"]
+        #[inline]
+        pub fn changed_fields(&self, other: &ThrGet) -> ::volatile_cell::field::FieldChanges {
+            ::volatile_cell::field::FieldChanges::new(
+                Self::FIELDS,
+                self.value as u64,
+                other.value as u64,
+            )
+        }
+    }
+    pub mod brgr {}
+    #[repr(C)]
+    pub struct Brgr {
+        value: ::volatile_cell::VolatileCell<u32>,
+    }
+    impl Brgr {
+        #[inline(always)]
+        fn load(&self) -> u32 {
+            let value = self.value.get();
+            value
+        }
+        #[inline(always)]
+        fn store(&self, value: u32) {
+            self.value.set(value);
+        }
+        #[doc = "Create a new updater"]
+        #[inline(always)]
+        pub fn update<'a>(&'a self) -> BrgrUpdate<'a> {
+            BrgrUpdate::new(self)
+        }
+        #[doc = "Create an updater whose read-modify-write happens inside the critical section `cs`"]
+        #[inline(always)]
+        pub fn update_in<'a, C: ::volatile_cell::sync::CriticalSection>(
+            &'a self,
+            _cs: &'a C,
+        ) -> BrgrUpdate<'a> {
+            BrgrUpdate::new(self)
+        }
+        #[doc = "Update the register with exclusive loads and stores (LDREX/STREX), retrying `f` until no other access intervened"]
+        #[doc = ""]
+        #[doc = "The exclusive accesses go to the register's memory directly rather than through its cell, so they aren't seen by the replayer or the tracing hook."]
+        #[cfg(target_has_atomic = "32")]
+        #[inline]
+        pub fn update_exclusive<F: FnMut(&mut BrgrUpdate)>(&self, mut f: F) {
+            use ::core::sync::atomic::Ordering;
+            let cell = unsafe { &*(self.value.as_ptr() as *const ::core::sync::atomic::AtomicU32) };
+            let mut current = cell.load(Ordering::Relaxed);
+            loop {
+                let mut update = BrgrUpdate::detached(self);
+                f(&mut update);
+                let merged = update.merge(current);
+                if update.mask == 0 {
+                    return;
+                }
+                match cell.compare_exchange_weak(
+                    current,
+                    merged,
+                    Ordering::SeqCst,
+                    Ordering::Relaxed,
+                ) {
+                    Ok(_) => return,
+                    Err(actual) => current = actual,
+                }
+            }
+        }
+        #[doc = "Create a getter representing the current state of the register"]
+        #[inline(always)]
+        pub fn get(&self) -> BrgrGet {
+            BrgrGet::new(self)
+        }
+        #[doc = "Address of the bit-band alias word for `bit` of this register"]
+        #[inline(always)]
+        pub fn bitband_alias(&self, bit: usize) -> *mut u32 {
+            let address = self as *const Self as usize;
+            1107296256usize
+                .wrapping_add(address.wrapping_sub(1073741824usize) << 5)
+                .wrapping_add(bit << 2) as *mut u32
+        }
+    }
+    pub struct BrgrUpdate<'a> {
+        value: u32,
+        mask: u32,
+        write_only: bool,
+        store_on_drop: bool,
+        reg: &'a Brgr,
+    }
+    impl<'a> BrgrUpdate<'a> {
+        #[inline(always)]
+        pub fn new(reg: &'a Brgr) -> BrgrUpdate<'a> {
+            BrgrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[inline(always)]
+        pub fn new_ignoring_state(reg: &'a Brgr) -> BrgrUpdate<'a> {
+            BrgrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: true,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[doc = "An update that is never stored, for the caller to store itself"]
+        #[inline(always)]
+        fn detached(reg: &'a Brgr) -> BrgrUpdate<'a> {
+            BrgrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: false,
+                reg: reg,
+            }
+        }
+        const fn clear_mask() -> u32 {
+            0u64 as u32
+        }
+        const fn update_offset(base: usize, size: usize, index: usize) -> usize {
+            base + (size * index)
+        }
+        #[inline(always)]
+        fn merge(&self, current: u32) -> u32 {
+            let v: u32 = current & (!Self::clear_mask()) & (!self.mask);
+            self.value | v
+        }
+        #[inline(always)]
+        pub fn set_cd<'b>(&'b mut self, new_value: u16) -> &'b mut Self {
+            let context_mask: u32 = 65535 << 0;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 65535) << 0);
+            self.mask |= context_mask;
+            self
+        }
+    }
+    impl<'a> Drop for BrgrUpdate<'a> {
+        #[inline(always)]
+        fn drop(&mut self) {
+            if self.store_on_drop && self.mask != 0 {
+                let current: u32 = if self.write_only { 0 } else { self.reg.load() };
+                self.reg.store(self.merge(current));
+            }
+        }
+    }
+    #[derive(Clone, PartialEq, Eq)]
+    pub struct BrgrGet {
+        value: u32,
+    }
+    impl BrgrGet {
+        #[doc = "Create a getter reflecting the current value of the register"]
+        #[inline(always)]
+        pub fn new(reg: &Brgr) -> BrgrGet {
+            BrgrGet { value: reg.load() }
+        }
+        #[inline(always)]
+        pub fn cd(&self) -> u32 {
+            (self.value & 65535)
+        }
+    }
+    #[derive(Clone, Copy, PartialEq, Eq, Default)]
+    #[repr(transparent)]
+    pub struct BrgrValue {
+        value: u32,
+    }
+    impl BrgrValue {
+        #[doc = "Create a value with every field cleared"]
+        #[inline(always)]
+        pub const fn new() -> BrgrValue {
+            BrgrValue { value: 0 }
+        }
+        #[inline(always)]
+        pub const fn from_bits(bits: u32) -> BrgrValue {
+            BrgrValue { value: bits }
+        }
+        #[inline(always)]
+        pub const fn to_bits(&self) -> u32 {
+            self.value
+        }
+        #[inline(always)]
+        pub fn cd(&self) -> u32 {
+            (self.value & 65535)
+        }
+        #[inline(always)]
+        pub const fn with_cd(self, new_value: u16) -> Self {
+            let context_mask: u32 = 65535 << 0;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 65535) << 0),
+            }
+        }
+    }
+    impl From<BrgrGet> for BrgrValue {
+        #[inline(always)]
+        fn from(get: BrgrGet) -> BrgrValue {
+            BrgrValue::from_bits(get.value)
+        }
+    }
+    impl From<BrgrValue> for BrgrGet {
+        #[inline(always)]
+        fn from(value: BrgrValue) -> BrgrGet {
+            BrgrGet {
+                value: value.to_bits(),
+            }
+        }
+    }
+    impl Brgr {
+        #[doc = "Write `value` to the register in a single store, without reading it first"]
+        #[inline(always)]
+        pub fn write(&self, value: BrgrValue) {
+            self.store(value.to_bits());
+        }
+    }
+    impl<'a> BrgrUpdate<'a> {
+        #[doc = "Replace every field of the register with those of `value`"]
+        #[inline(always)]
+        pub fn assign<'b>(&'b mut self, value: BrgrValue) -> &'b mut Self {
+            self.value = value.to_bits();
+            self.mask = !0;
+            self
+        }
+    }
+    impl BrgrGet {
+        pub const FIELDS: &'static [::volatile_cell::field::FieldDescriptor] =
+            &[::volatile_cell::field::FieldDescriptor {
+                name: "cd",
+                offset: 0u32,
+                width: 16u32,
+                count: 1u32,
+            }];
+        #[doc = "Iterate over the readable fields that differ from those of `other`"]
+        #[inline]
+        pub fn changed_fields(&self, other: &BrgrGet) -> ::volatile_cell::field::FieldChanges {
+            ::volatile_cell::field::FieldChanges::new(
+                Self::FIELDS,
+                self.value as u64,
+                other.value as u64,
+            )
+        }
+    }
+    #[repr(C)]
+    pub struct Uart {
+        pub cr: Cr,
+        pub mr: Mr,
+        _padding0: [u8; 12],
+        pub sr: Sr,
+        _padding1: [u8; 4],
+        pub thr: Thr,
+        pub brgr: Brgr,
+    }
+    #[doc = "Saved state of every readable register without side effects"]
+    #[derive(Clone, Copy)]
+    pub struct Snapshot {
+        pub mr: MrValue,
+        pub brgr: BrgrValue,
+    }
+    #[doc = "Read the current state of the peripheral"]
+    #[allow(unused_variables)]
+    pub fn capture(p: &Uart) -> Snapshot {
+        Snapshot {
+            mr: MrValue::from(p.mr.get()),
+            brgr: BrgrValue::from(p.brgr.get()),
+        }
+    }
+    #[doc = "Write a previously captured state back to the peripheral"]
+    #[allow(unused_variables)]
+    pub fn restore(p: &Uart, snapshot: &Snapshot) {
+        MrUpdate::new_ignoring_state(&p.mr).assign(snapshot.mr);
+        BrgrUpdate::new_ignoring_state(&p.brgr).assign(snapshot.brgr);
+    }
+    #[doc = "Owned handle granting exclusive access to the peripheral"]
+    pub struct UartPeripheral {
+        regs: &'static mut Uart,
+    }
+    impl UartPeripheral {
+        #[doc = "Create a token for the peripheral registers at `regs`."]
+        #[doc = ""]
+        #[doc = "Unsafe because the caller must ensure it's the only token for `regs`."]
+        #[inline(always)]
+        pub const unsafe fn new(regs: &'static mut Uart) -> UartPeripheral {
+            UartPeripheral { regs: regs }
+        }
+        #[doc = "Address of the peripheral registers"]
+        pub const ADDRESS: usize = 0x400E0800;
+        #[doc = "Create a token for the peripheral at `ADDRESS`."]
+        #[doc = ""]
+        #[doc = "Unsafe because it bypasses `Peripherals::take`, so other tokens for the same peripheral may exist."]
+        #[inline(always)]
+        pub unsafe fn steal() -> UartPeripheral {
+            UartPeripheral::new(&mut *(Self::ADDRESS as *mut Uart))
+        }
+    }
+    impl ::core::ops::Deref for UartPeripheral {
+        type Target = Uart;
+        #[inline(always)]
+        fn deref(&self) -> &Uart {
+            self.regs
+        }
+    }
+    impl ::core::ops::DerefMut for UartPeripheral {
+        #[inline(always)]
+        fn deref_mut(&mut self) -> &mut Uart {
+            self.regs
+        }
+    }
+}
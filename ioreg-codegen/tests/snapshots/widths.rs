@@ -0,0 +1,1515 @@
+pub mod widths {
+    pub mod ctrl {}
+    #[repr(C)]
+    pub struct Ctrl {
+        value: ::volatile_cell::VolatileCell<u8>,
+    }
+    impl Ctrl {
+        #[inline(always)]
+        fn load(&self) -> u8 {
+            let value = self.value.get();
+            value
+        }
+        #[inline(always)]
+        fn store(&self, value: u8) {
+            self.value.set(value);
+        }
+        #[doc = "Create a new updater"]
+        #[inline(always)]
+        pub fn update<'a>(&'a self) -> CtrlUpdate<'a> {
+            CtrlUpdate::new(self)
+        }
+        #[doc = "Create an updater whose read-modify-write happens inside the critical section `cs`"]
+        #[inline(always)]
+        pub fn update_in<'a, C: ::volatile_cell::sync::CriticalSection>(
+            &'a self,
+            _cs: &'a C,
+        ) -> CtrlUpdate<'a> {
+            CtrlUpdate::new(self)
+        }
+        #[doc = "Update the register with exclusive loads and stores (LDREX/STREX), retrying `f` until no other access intervened"]
+        #[doc = ""]
+        #[doc = "The exclusive accesses go to the register's memory directly rather than through its cell, so they aren't seen by the replayer or the tracing hook."]
+        #[cfg(target_has_atomic = "8")]
+        #[inline]
+        pub fn update_exclusive<F: FnMut(&mut CtrlUpdate)>(&self, mut f: F) {
+            use ::core::sync::atomic::Ordering;
+            let cell = unsafe { &*(self.value.as_ptr() as *const ::core::sync::atomic::AtomicU8) };
+            let mut current = cell.load(Ordering::Relaxed);
+            loop {
+                let mut update = CtrlUpdate::detached(self);
+                f(&mut update);
+                let merged = update.merge(current);
+                if update.mask == 0 {
+                    return;
+                }
+                match cell.compare_exchange_weak(
+                    current,
+                    merged,
+                    Ordering::SeqCst,
+                    Ordering::Relaxed,
+                ) {
+                    Ok(_) => return,
+                    Err(actual) => current = actual,
+                }
+            }
+        }
+        #[doc = "Create a getter representing the current state of the register"]
+        #[inline(always)]
+        pub fn get(&self) -> CtrlGet {
+            CtrlGet::new(self)
+        }
+        #[doc = "Spin until the bit is set"]
+        #[inline]
+        pub fn wait_enable(&self) {
+            while !self.get().enable() {}
+        }
+        #[doc = "Poll until the bit is set, at most `max_polls` times"]
+        #[inline]
+        pub fn wait_enable_timeout(
+            &self,
+            max_polls: u32,
+        ) -> Result<(), ::volatile_cell::wait::Timeout> {
+            ::volatile_cell::wait::poll_until(|| self.get().enable(), max_polls)
+        }
+        #[doc = "Future resolving once the bit is set"]
+        #[inline]
+        pub fn wait_enable_async<'a>(
+            &'a self,
+        ) -> ::volatile_cell::wait::WaitUntil<'a, impl FnMut() -> bool + Unpin + 'a> {
+            ::volatile_cell::wait::WaitUntil::new(move || self.get().enable())
+        }
+    }
+    pub struct CtrlUpdate<'a> {
+        value: u8,
+        mask: u8,
+        write_only: bool,
+        store_on_drop: bool,
+        reg: &'a Ctrl,
+    }
+    impl<'a> CtrlUpdate<'a> {
+        #[inline(always)]
+        pub fn new(reg: &'a Ctrl) -> CtrlUpdate<'a> {
+            CtrlUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[inline(always)]
+        pub fn new_ignoring_state(reg: &'a Ctrl) -> CtrlUpdate<'a> {
+            CtrlUpdate {
+                value: 0,
+                mask: 0,
+                write_only: true,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[doc = "An update that is never stored, for the caller to store itself"]
+        #[inline(always)]
+        fn detached(reg: &'a Ctrl) -> CtrlUpdate<'a> {
+            CtrlUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: false,
+                reg: reg,
+            }
+        }
+        const fn clear_mask() -> u8 {
+            0u64 as u8
+        }
+        const fn update_offset(base: usize, size: usize, index: usize) -> usize {
+            base + (size * index)
+        }
+        #[inline(always)]
+        fn merge(&self, current: u8) -> u8 {
+            let v: u8 = current & (!Self::clear_mask()) & (!self.mask);
+            self.value | v
+        }
+        #[inline(always)]
+        pub fn set_enable<'b>(&'b mut self, new_value: bool) -> &'b mut Self {
+            let context_mask: u8 = 1 << 0;
+            self.value = (self.value & !context_mask) | (((new_value as u8) & 1) << 0);
+            self.mask |= context_mask;
+            self
+        }
+        #[inline(always)]
+        pub fn set_mode<'b>(&'b mut self, new_value: u8) -> &'b mut Self {
+            let context_mask: u8 = 15 << 4;
+            self.value = (self.value & !context_mask) | (((new_value as u8) & 15) << 4);
+            self.mask |= context_mask;
+            self
+        }
+    }
+    impl<'a> Drop for CtrlUpdate<'a> {
+        #[inline(always)]
+        fn drop(&mut self) {
+            if self.store_on_drop && self.mask != 0 {
+                let current: u8 = if self.write_only { 0 } else { self.reg.load() };
+                self.reg.store(self.merge(current));
+            }
+        }
+    }
+    #[derive(Clone, PartialEq, Eq)]
+    pub struct CtrlGet {
+        value: u8,
+    }
+    impl CtrlGet {
+        #[doc = "Create a getter reflecting the current value of the register"]
+        #[inline(always)]
+        pub fn new(reg: &Ctrl) -> CtrlGet {
+            CtrlGet { value: reg.load() }
+        }
+        #[inline(always)]
+        pub fn enable(&self) -> bool {
+            let val = (self.value & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub fn mode(&self) -> u8 {
+            (self.value >> 4)
+        }
+    }
+    #[derive(Clone, Copy, PartialEq, Eq, Default)]
+    #[repr(transparent)]
+    pub struct CtrlValue {
+        value: u8,
+    }
+    impl CtrlValue {
+        #[doc = "Create a value with every field cleared"]
+        #[inline(always)]
+        pub const fn new() -> CtrlValue {
+            CtrlValue { value: 0 }
+        }
+        #[inline(always)]
+        pub const fn from_bits(bits: u8) -> CtrlValue {
+            CtrlValue { value: bits }
+        }
+        #[inline(always)]
+        pub const fn to_bits(&self) -> u8 {
+            self.value
+        }
+        #[inline(always)]
+        pub fn enable(&self) -> bool {
+            let val = (self.value & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub fn mode(&self) -> u8 {
+            (self.value >> 4)
+        }
+        #[inline(always)]
+        pub const fn with_enable(self, new_value: bool) -> Self {
+            let context_mask: u8 = 1 << 0;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u8) & 1) << 0),
+            }
+        }
+        #[inline(always)]
+        pub const fn with_mode(self, new_value: u8) -> Self {
+            let context_mask: u8 = 15 << 4;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u8) & 15) << 4),
+            }
+        }
+    }
+    impl From<CtrlGet> for CtrlValue {
+        #[inline(always)]
+        fn from(get: CtrlGet) -> CtrlValue {
+            CtrlValue::from_bits(get.value)
+        }
+    }
+    impl From<CtrlValue> for CtrlGet {
+        #[inline(always)]
+        fn from(value: CtrlValue) -> CtrlGet {
+            CtrlGet {
+                value: value.to_bits(),
+            }
+        }
+    }
+    impl Ctrl {
+        #[doc = "Write `value` to the register in a single store, without reading it first"]
+        #[inline(always)]
+        pub fn write(&self, value: CtrlValue) {
+            self.store(value.to_bits());
+        }
+    }
+    impl<'a> CtrlUpdate<'a> {
+        #[doc = "Replace every field of the register with those of `value`"]
+        #[inline(always)]
+        pub fn assign<'b>(&'b mut self, value: CtrlValue) -> &'b mut Self {
+            self.value = value.to_bits();
+            self.mask = !0;
+            self
+        }
+    }
+    impl CtrlGet {
+        pub const FIELDS: &'static [::volatile_cell::field::FieldDescriptor] = &[
+            ::volatile_cell::field::FieldDescriptor {
+                name: "enable",
+                offset: 0u32,
+                width: 1u32,
+                count: 1u32,
+            },
+            ::volatile_cell::field::FieldDescriptor {
+                name: "mode",
+                offset: 4u32,
+                width: 4u32,
+                count: 1u32,
+            },
+        ];
+        #[doc = "Iterate over the readable fields that differ from those of `other`"]
+        #[inline]
+        pub fn changed_fields(&self, other: &CtrlGet) -> ::volatile_cell::field::FieldChanges {
+            ::volatile_cell::field::FieldChanges::new(
+                Self::FIELDS,
+                self.value as u64,
+                other.value as u64,
+            )
+        }
+    }
+    pub mod addr {}
+    #[repr(C)]
+    pub struct Addr {
+        value: [::volatile_cell::VolatileCell<u8>; 3usize],
+    }
+    impl Addr {
+        #[inline(always)]
+        fn load(&self) -> u32 {
+            let value = {
+                let mut value: u32 = 0;
+                value |= (self.value[0usize].get() as u32) << 0usize;
+                value |= (self.value[1usize].get() as u32) << 8usize;
+                value |= (self.value[2usize].get() as u32) << 16usize;
+                value
+            };
+            value
+        }
+        #[inline(always)]
+        fn store(&self, value: u32) {
+            self.value[0usize].set(((value >> 0usize) & 255) as u8);
+            self.value[1usize].set(((value >> 8usize) & 255) as u8);
+            self.value[2usize].set(((value >> 16usize) & 255) as u8);
+        }
+        #[doc = "Create a new updater"]
+        #[inline(always)]
+        pub fn update<'a>(&'a self) -> AddrUpdate<'a> {
+            AddrUpdate::new(self)
+        }
+        #[doc = "Create an updater whose read-modify-write happens inside the critical section `cs`"]
+        #[inline(always)]
+        pub fn update_in<'a, C: ::volatile_cell::sync::CriticalSection>(
+            &'a self,
+            _cs: &'a C,
+        ) -> AddrUpdate<'a> {
+            AddrUpdate::new(self)
+        }
+        #[doc = "Create a getter representing the current state of the register"]
+        #[inline(always)]
+        pub fn get(&self) -> AddrGet {
+            AddrGet::new(self)
+        }
+    }
+    pub struct AddrUpdate<'a> {
+        value: u32,
+        mask: u32,
+        write_only: bool,
+        store_on_drop: bool,
+        reg: &'a Addr,
+    }
+    impl<'a> AddrUpdate<'a> {
+        #[inline(always)]
+        pub fn new(reg: &'a Addr) -> AddrUpdate<'a> {
+            AddrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[inline(always)]
+        pub fn new_ignoring_state(reg: &'a Addr) -> AddrUpdate<'a> {
+            AddrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: true,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[doc = "An update that is never stored, for the caller to store itself"]
+        #[inline(always)]
+        fn detached(reg: &'a Addr) -> AddrUpdate<'a> {
+            AddrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: false,
+                reg: reg,
+            }
+        }
+        const fn clear_mask() -> u32 {
+            0u64 as u32
+        }
+        const fn update_offset(base: usize, size: usize, index: usize) -> usize {
+            base + (size * index)
+        }
+        #[inline(always)]
+        fn merge(&self, current: u32) -> u32 {
+            let v: u32 = current & (!Self::clear_mask()) & (!self.mask);
+            self.value | v
+        }
+        #[inline(always)]
+        pub fn set_low<'b>(&'b mut self, new_value: u8) -> &'b mut Self {
+            let context_mask: u32 = 255 << 0;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 255) << 0);
+            self.mask |= context_mask;
+            self
+        }
+        #[inline(always)]
+        pub fn set_high<'b>(&'b mut self, new_value: u16) -> &'b mut Self {
+            let context_mask: u32 = 65535 << 8;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 65535) << 8);
+            self.mask |= context_mask;
+            self
+        }
+    }
+    impl<'a> Drop for AddrUpdate<'a> {
+        #[inline(always)]
+        fn drop(&mut self) {
+            if self.store_on_drop && self.mask != 0 {
+                let current: u32 = if self.write_only { 0 } else { self.reg.load() };
+                self.reg.store(self.merge(current));
+            }
+        }
+    }
+    #[derive(Clone, PartialEq, Eq)]
+    pub struct AddrGet {
+        value: u32,
+    }
+    impl AddrGet {
+        #[doc = "Create a getter reflecting the current value of the register"]
+        #[inline(always)]
+        pub fn new(reg: &Addr) -> AddrGet {
+            AddrGet { value: reg.load() }
+        }
+        #[inline(always)]
+        pub fn low(&self) -> u32 {
+            (self.value & 255)
+        }
+        #[inline(always)]
+        pub fn high(&self) -> u32 {
+            ((self.value >> 8) & 65535)
+        }
+    }
+    #[derive(Clone, Copy, PartialEq, Eq, Default)]
+    #[repr(transparent)]
+    pub struct AddrValue {
+        value: u32,
+    }
+    impl AddrValue {
+        #[doc = "Create a value with every field cleared"]
+        #[inline(always)]
+        pub const fn new() -> AddrValue {
+            AddrValue { value: 0 }
+        }
+        #[inline(always)]
+        pub const fn from_bits(bits: u32) -> AddrValue {
+            AddrValue { value: bits }
+        }
+        #[inline(always)]
+        pub const fn to_bits(&self) -> u32 {
+            self.value
+        }
+        #[inline(always)]
+        pub fn low(&self) -> u32 {
+            (self.value & 255)
+        }
+        #[inline(always)]
+        pub fn high(&self) -> u32 {
+            ((self.value >> 8) & 65535)
+        }
+        #[inline(always)]
+        pub const fn with_low(self, new_value: u8) -> Self {
+            let context_mask: u32 = 255 << 0;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 255) << 0),
+            }
+        }
+        #[inline(always)]
+        pub const fn with_high(self, new_value: u16) -> Self {
+            let context_mask: u32 = 65535 << 8;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 65535) << 8),
+            }
+        }
+    }
+    impl From<AddrGet> for AddrValue {
+        #[inline(always)]
+        fn from(get: AddrGet) -> AddrValue {
+            AddrValue::from_bits(get.value)
+        }
+    }
+    impl From<AddrValue> for AddrGet {
+        #[inline(always)]
+        fn from(value: AddrValue) -> AddrGet {
+            AddrGet {
+                value: value.to_bits(),
+            }
+        }
+    }
+    impl Addr {
+        #[doc = "Write `value` to the register in a single store, without reading it first"]
+        #[inline(always)]
+        pub fn write(&self, value: AddrValue) {
+            self.store(value.to_bits());
+        }
+    }
+    impl<'a> AddrUpdate<'a> {
+        #[doc = "Replace every field of the register with those of `value`"]
+        #[inline(always)]
+        pub fn assign<'b>(&'b mut self, value: AddrValue) -> &'b mut Self {
+            self.value = value.to_bits();
+            self.mask = !0;
+            self
+        }
+    }
+    impl AddrGet {
+        pub const FIELDS: &'static [::volatile_cell::field::FieldDescriptor] = &[
+            ::volatile_cell::field::FieldDescriptor {
+                name: "low",
+                offset: 0u32,
+                width: 8u32,
+                count: 1u32,
+            },
+            ::volatile_cell::field::FieldDescriptor {
+                name: "high",
+                offset: 8u32,
+                width: 16u32,
+                count: 1u32,
+            },
+        ];
+        #[doc = "Iterate over the readable fields that differ from those of `other`"]
+        #[inline]
+        pub fn changed_fields(&self, other: &AddrGet) -> ::volatile_cell::field::FieldChanges {
+            ::volatile_cell::field::FieldChanges::new(
+                Self::FIELDS,
+                self.value as u64,
+                other.value as u64,
+            )
+        }
+    }
+    pub mod count {}
+    #[repr(C)]
+    pub struct Count {
+        value: ::volatile_cell::VolatileCell<u16>,
+    }
+    impl Count {
+        #[inline(always)]
+        fn load(&self) -> u16 {
+            let value = self.value.get();
+            value
+        }
+        #[inline(always)]
+        fn store(&self, value: u16) {
+            self.value.set(value);
+        }
+        #[doc = "Create a new updater"]
+        #[inline(always)]
+        pub fn update<'a>(&'a self) -> CountUpdate<'a> {
+            CountUpdate::new(self)
+        }
+        #[doc = "Create an updater whose read-modify-write happens inside the critical section `cs`"]
+        #[inline(always)]
+        pub fn update_in<'a, C: ::volatile_cell::sync::CriticalSection>(
+            &'a self,
+            _cs: &'a C,
+        ) -> CountUpdate<'a> {
+            CountUpdate::new(self)
+        }
+        #[doc = "Update the register with exclusive loads and stores (LDREX/STREX), retrying `f` until no other access intervened"]
+        #[doc = ""]
+        #[doc = "The exclusive accesses go to the register's memory directly rather than through its cell, so they aren't seen by the replayer or the tracing hook."]
+        #[cfg(target_has_atomic = "16")]
+        #[inline]
+        pub fn update_exclusive<F: FnMut(&mut CountUpdate)>(&self, mut f: F) {
+            use ::core::sync::atomic::Ordering;
+            let cell = unsafe { &*(self.value.as_ptr() as *const ::core::sync::atomic::AtomicU16) };
+            let mut current = cell.load(Ordering::Relaxed);
+            loop {
+                let mut update = CountUpdate::detached(self);
+                f(&mut update);
+                let merged = update.merge(current);
+                if update.mask == 0 {
+                    return;
+                }
+                match cell.compare_exchange_weak(
+                    current,
+                    merged,
+                    Ordering::SeqCst,
+                    Ordering::Relaxed,
+                ) {
+                    Ok(_) => return,
+                    Err(actual) => current = actual,
+                }
+            }
+        }
+        #[doc = "Create a getter representing the current state of the register"]
+        #[inline(always)]
+        pub fn get(&self) -> CountGet {
+            CountGet::new(self)
+        }
+    }
+    pub struct CountUpdate<'a> {
+        value: u16,
+        mask: u16,
+        write_only: bool,
+        store_on_drop: bool,
+        reg: &'a Count,
+    }
+    impl<'a> CountUpdate<'a> {
+        #[inline(always)]
+        pub fn new(reg: &'a Count) -> CountUpdate<'a> {
+            CountUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[inline(always)]
+        pub fn new_ignoring_state(reg: &'a Count) -> CountUpdate<'a> {
+            CountUpdate {
+                value: 0,
+                mask: 0,
+                write_only: true,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[doc = "An update that is never stored, for the caller to store itself"]
+        #[inline(always)]
+        fn detached(reg: &'a Count) -> CountUpdate<'a> {
+            CountUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: false,
+                reg: reg,
+            }
+        }
+        const fn clear_mask() -> u16 {
+            0u64 as u16
+        }
+        const fn update_offset(base: usize, size: usize, index: usize) -> usize {
+            base + (size * index)
+        }
+        #[inline(always)]
+        fn merge(&self, current: u16) -> u16 {
+            let v: u16 = current & (!Self::clear_mask()) & (!self.mask);
+            self.value | v
+        }
+        #[inline(always)]
+        pub fn set_value<'b>(&'b mut self, new_value: u16) -> &'b mut Self {
+            let context_mask: u16 = 65535 << 0;
+            self.value = (self.value & !context_mask) | (((new_value as u16) & 65535) << 0);
+            self.mask |= context_mask;
+            self
+        }
+    }
+    impl<'a> Drop for CountUpdate<'a> {
+        #[inline(always)]
+        fn drop(&mut self) {
+            if self.store_on_drop && self.mask != 0 {
+                let current: u16 = if self.write_only { 0 } else { self.reg.load() };
+                self.reg.store(self.merge(current));
+            }
+        }
+    }
+    #[derive(Clone, PartialEq, Eq)]
+    pub struct CountGet {
+        value: u16,
+    }
+    impl CountGet {
+        #[doc = "Create a getter reflecting the current value of the register"]
+        #[inline(always)]
+        pub fn new(reg: &Count) -> CountGet {
+            CountGet { value: reg.load() }
+        }
+        #[inline(always)]
+        pub fn value(&self) -> u16 {
+            self.value
+        }
+    }
+    #[derive(Clone, Copy, PartialEq, Eq, Default)]
+    #[repr(transparent)]
+    pub struct CountValue {
+        value: u16,
+    }
+    impl CountValue {
+        #[doc = "Create a value with every field cleared"]
+        #[inline(always)]
+        pub const fn new() -> CountValue {
+            CountValue { value: 0 }
+        }
+        #[inline(always)]
+        pub const fn from_bits(bits: u16) -> CountValue {
+            CountValue { value: bits }
+        }
+        #[inline(always)]
+        pub const fn to_bits(&self) -> u16 {
+            self.value
+        }
+        #[inline(always)]
+        pub fn value(&self) -> u16 {
+            self.value
+        }
+        #[inline(always)]
+        pub const fn with_value(self, new_value: u16) -> Self {
+            let context_mask: u16 = 65535 << 0;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u16) & 65535) << 0),
+            }
+        }
+    }
+    impl From<CountGet> for CountValue {
+        #[inline(always)]
+        fn from(get: CountGet) -> CountValue {
+            CountValue::from_bits(get.value)
+        }
+    }
+    impl From<CountValue> for CountGet {
+        #[inline(always)]
+        fn from(value: CountValue) -> CountGet {
+            CountGet {
+                value: value.to_bits(),
+            }
+        }
+    }
+    impl Count {
+        #[doc = "Write `value` to the register in a single store, without reading it first"]
+        #[inline(always)]
+        pub fn write(&self, value: CountValue) {
+            self.store(value.to_bits());
+        }
+    }
+    impl<'a> CountUpdate<'a> {
+        #[doc = "Replace every field of the register with those of `value`"]
+        #[inline(always)]
+        pub fn assign<'b>(&'b mut self, value: CountValue) -> &'b mut Self {
+            self.value = value.to_bits();
+            self.mask = !0;
+            self
+        }
+    }
+    impl CountGet {
+        pub const FIELDS: &'static [::volatile_cell::field::FieldDescriptor] =
+            &[::volatile_cell::field::FieldDescriptor {
+                name: "value",
+                offset: 0u32,
+                width: 16u32,
+                count: 1u32,
+            }];
+        #[doc = "Iterate over the readable fields that differ from those of `other`"]
+        #[inline]
+        pub fn changed_fields(&self, other: &CountGet) -> ::volatile_cell::field::FieldChanges {
+            ::volatile_cell::field::FieldChanges::new(
+                Self::FIELDS,
+                self.value as u64,
+                other.value as u64,
+            )
+        }
+    }
+    pub mod stamp {}
+    #[repr(C)]
+    pub struct Stamp {
+        value: [::volatile_cell::VolatileCell<u16>; 3usize],
+    }
+    impl Stamp {
+        #[inline(always)]
+        fn load(&self) -> u64 {
+            let value = {
+                let mut value: u64 = 0;
+                value |= (self.value[2usize].get() as u64) << 32usize;
+                value |= (self.value[1usize].get() as u64) << 16usize;
+                value |= (self.value[0usize].get() as u64) << 0usize;
+                value
+            };
+            value
+        }
+        #[inline(always)]
+        fn store(&self, value: u64) {
+            self.value[2usize].set(((value >> 32usize) & 65535) as u16);
+            self.value[1usize].set(((value >> 16usize) & 65535) as u16);
+            self.value[0usize].set(((value >> 0usize) & 65535) as u16);
+        }
+        #[doc = "Create a new updater"]
+        #[inline(always)]
+        pub fn update<'a>(&'a self) -> StampUpdate<'a> {
+            StampUpdate::new(self)
+        }
+        #[doc = "Create an updater whose read-modify-write happens inside the critical section `cs`"]
+        #[inline(always)]
+        pub fn update_in<'a, C: ::volatile_cell::sync::CriticalSection>(
+            &'a self,
+            _cs: &'a C,
+        ) -> StampUpdate<'a> {
+            StampUpdate::new(self)
+        }
+        #[doc = "Create a getter representing the current state of the register"]
+        #[inline(always)]
+        pub fn get(&self) -> StampGet {
+            StampGet::new(self)
+        }
+    }
+    pub struct StampUpdate<'a> {
+        value: u64,
+        mask: u64,
+        write_only: bool,
+        store_on_drop: bool,
+        reg: &'a Stamp,
+    }
+    impl<'a> StampUpdate<'a> {
+        #[inline(always)]
+        pub fn new(reg: &'a Stamp) -> StampUpdate<'a> {
+            StampUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[inline(always)]
+        pub fn new_ignoring_state(reg: &'a Stamp) -> StampUpdate<'a> {
+            StampUpdate {
+                value: 0,
+                mask: 0,
+                write_only: true,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[doc = "An update that is never stored, for the caller to store itself"]
+        #[inline(always)]
+        fn detached(reg: &'a Stamp) -> StampUpdate<'a> {
+            StampUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: false,
+                reg: reg,
+            }
+        }
+        const fn clear_mask() -> u64 {
+            0u64 as u64
+        }
+        const fn update_offset(base: usize, size: usize, index: usize) -> usize {
+            base + (size * index)
+        }
+        #[inline(always)]
+        fn merge(&self, current: u64) -> u64 {
+            let v: u64 = current & (!Self::clear_mask()) & (!self.mask);
+            self.value | v
+        }
+        #[inline(always)]
+        pub fn set_ticks<'b>(&'b mut self, new_value: u64) -> &'b mut Self {
+            let context_mask: u64 = 281474976710655 << 0;
+            self.value =
+                (self.value & !context_mask) | (((new_value as u64) & 281474976710655) << 0);
+            self.mask |= context_mask;
+            self
+        }
+    }
+    impl<'a> Drop for StampUpdate<'a> {
+        #[inline(always)]
+        fn drop(&mut self) {
+            if self.store_on_drop && self.mask != 0 {
+                let current: u64 = if self.write_only { 0 } else { self.reg.load() };
+                self.reg.store(self.merge(current));
+            }
+        }
+    }
+    #[derive(Clone, PartialEq, Eq)]
+    pub struct StampGet {
+        value: u64,
+    }
+    impl StampGet {
+        #[doc = "Create a getter reflecting the current value of the register"]
+        #[inline(always)]
+        pub fn new(reg: &Stamp) -> StampGet {
+            StampGet { value: reg.load() }
+        }
+        #[inline(always)]
+        pub fn ticks(&self) -> u64 {
+            (self.value & 281474976710655)
+        }
+    }
+    #[derive(Clone, Copy, PartialEq, Eq, Default)]
+    #[repr(transparent)]
+    pub struct StampValue {
+        value: u64,
+    }
+    impl StampValue {
+        #[doc = "Create a value with every field cleared"]
+        #[inline(always)]
+        pub const fn new() -> StampValue {
+            StampValue { value: 0 }
+        }
+        #[inline(always)]
+        pub const fn from_bits(bits: u64) -> StampValue {
+            StampValue { value: bits }
+        }
+        #[inline(always)]
+        pub const fn to_bits(&self) -> u64 {
+            self.value
+        }
+        #[inline(always)]
+        pub fn ticks(&self) -> u64 {
+            (self.value & 281474976710655)
+        }
+        #[inline(always)]
+        pub const fn with_ticks(self, new_value: u64) -> Self {
+            let context_mask: u64 = 281474976710655 << 0;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u64) & 281474976710655) << 0),
+            }
+        }
+    }
+    impl From<StampGet> for StampValue {
+        #[inline(always)]
+        fn from(get: StampGet) -> StampValue {
+            StampValue::from_bits(get.value)
+        }
+    }
+    impl From<StampValue> for StampGet {
+        #[inline(always)]
+        fn from(value: StampValue) -> StampGet {
+            StampGet {
+                value: value.to_bits(),
+            }
+        }
+    }
+    impl Stamp {
+        #[doc = "Write `value` to the register in a single store, without reading it first"]
+        #[inline(always)]
+        pub fn write(&self, value: StampValue) {
+            self.store(value.to_bits());
+        }
+    }
+    impl<'a> StampUpdate<'a> {
+        #[doc = "Replace every field of the register with those of `value`"]
+        #[inline(always)]
+        pub fn assign<'b>(&'b mut self, value: StampValue) -> &'b mut Self {
+            self.value = value.to_bits();
+            self.mask = !0;
+            self
+        }
+    }
+    impl StampGet {
+        pub const FIELDS: &'static [::volatile_cell::field::FieldDescriptor] =
+            &[::volatile_cell::field::FieldDescriptor {
+                name: "ticks",
+                offset: 0u32,
+                width: 48u32,
+                count: 1u32,
+            }];
+        #[doc = "Iterate over the readable fields that differ from those of `other`"]
+        #[inline]
+        pub fn changed_fields(&self, other: &StampGet) -> ::volatile_cell::field::FieldChanges {
+            ::volatile_cell::field::FieldChanges::new(
+                Self::FIELDS,
+                self.value as u64,
+                other.value as u64,
+            )
+        }
+    }
+    pub mod wide {}
+    #[repr(C)]
+    pub struct Wide {
+        value: ::volatile_cell::VolatileCell<u64>,
+    }
+    impl Wide {
+        #[inline(always)]
+        fn load(&self) -> u64 {
+            let value = self.value.get();
+            value
+        }
+        #[inline(always)]
+        fn store(&self, value: u64) {
+            self.value.set(value);
+        }
+        #[doc = "Create a new updater"]
+        #[inline(always)]
+        pub fn update<'a>(&'a self) -> WideUpdate<'a> {
+            WideUpdate::new(self)
+        }
+        #[doc = "Create an updater whose read-modify-write happens inside the critical section `cs`"]
+        #[inline(always)]
+        pub fn update_in<'a, C: ::volatile_cell::sync::CriticalSection>(
+            &'a self,
+            _cs: &'a C,
+        ) -> WideUpdate<'a> {
+            WideUpdate::new(self)
+        }
+        #[doc = "Update the register with exclusive loads and stores (LDREX/STREX), retrying `f` until no other access intervened"]
+        #[doc = ""]
+        #[doc = "The exclusive accesses go to the register's memory directly rather than through its cell, so they aren't seen by the replayer or the tracing hook."]
+        #[cfg(target_has_atomic = "64")]
+        #[inline]
+        pub fn update_exclusive<F: FnMut(&mut WideUpdate)>(&self, mut f: F) {
+            use ::core::sync::atomic::Ordering;
+            let cell = unsafe { &*(self.value.as_ptr() as *const ::core::sync::atomic::AtomicU64) };
+            let mut current = cell.load(Ordering::Relaxed);
+            loop {
+                let mut update = WideUpdate::detached(self);
+                f(&mut update);
+                let merged = update.merge(current);
+                if update.mask == 0 {
+                    return;
+                }
+                match cell.compare_exchange_weak(
+                    current,
+                    merged,
+                    Ordering::SeqCst,
+                    Ordering::Relaxed,
+                ) {
+                    Ok(_) => return,
+                    Err(actual) => current = actual,
+                }
+            }
+        }
+        #[doc = "Create a getter representing the current state of the register"]
+        #[inline(always)]
+        pub fn get(&self) -> WideGet {
+            WideGet::new(self)
+        }
+    }
+    pub struct WideUpdate<'a> {
+        value: u64,
+        mask: u64,
+        write_only: bool,
+        store_on_drop: bool,
+        reg: &'a Wide,
+    }
+    impl<'a> WideUpdate<'a> {
+        #[inline(always)]
+        pub fn new(reg: &'a Wide) -> WideUpdate<'a> {
+            WideUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[inline(always)]
+        pub fn new_ignoring_state(reg: &'a Wide) -> WideUpdate<'a> {
+            WideUpdate {
+                value: 0,
+                mask: 0,
+                write_only: true,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[doc = "An update that is never stored, for the caller to store itself"]
+        #[inline(always)]
+        fn detached(reg: &'a Wide) -> WideUpdate<'a> {
+            WideUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: false,
+                reg: reg,
+            }
+        }
+        const fn clear_mask() -> u64 {
+            0u64 as u64
+        }
+        const fn update_offset(base: usize, size: usize, index: usize) -> usize {
+            base + (size * index)
+        }
+        #[inline(always)]
+        fn merge(&self, current: u64) -> u64 {
+            let v: u64 = current & (!Self::clear_mask()) & (!self.mask);
+            self.value | v
+        }
+        #[inline(always)]
+        pub fn set_low<'b>(&'b mut self, new_value: u64) -> &'b mut Self {
+            let context_mask: u64 = 1099511627775 << 0;
+            self.value = (self.value & !context_mask) | (((new_value as u64) & 1099511627775) << 0);
+            self.mask |= context_mask;
+            self
+        }
+        #[inline(always)]
+        pub fn set_high<'b>(&'b mut self, new_value: u32) -> &'b mut Self {
+            let context_mask: u64 = 16777215 << 40;
+            self.value = (self.value & !context_mask) | (((new_value as u64) & 16777215) << 40);
+            self.mask |= context_mask;
+            self
+        }
+    }
+    impl<'a> Drop for WideUpdate<'a> {
+        #[inline(always)]
+        fn drop(&mut self) {
+            if self.store_on_drop && self.mask != 0 {
+                let current: u64 = if self.write_only { 0 } else { self.reg.load() };
+                self.reg.store(self.merge(current));
+            }
+        }
+    }
+    #[derive(Clone, PartialEq, Eq)]
+    pub struct WideGet {
+        value: u64,
+    }
+    impl WideGet {
+        #[doc = "Create a getter reflecting the current value of the register"]
+        #[inline(always)]
+        pub fn new(reg: &Wide) -> WideGet {
+            WideGet { value: reg.load() }
+        }
+        #[inline(always)]
+        pub fn low(&self) -> u64 {
+            (self.value & 1099511627775)
+        }
+        #[inline(always)]
+        pub fn high(&self) -> u64 {
+            (self.value >> 40)
+        }
+    }
+    #[derive(Clone, Copy, PartialEq, Eq, Default)]
+    #[repr(transparent)]
+    pub struct WideValue {
+        value: u64,
+    }
+    impl WideValue {
+        #[doc = "Create a value with every field cleared"]
+        #[inline(always)]
+        pub const fn new() -> WideValue {
+            WideValue { value: 0 }
+        }
+        #[inline(always)]
+        pub const fn from_bits(bits: u64) -> WideValue {
+            WideValue { value: bits }
+        }
+        #[inline(always)]
+        pub const fn to_bits(&self) -> u64 {
+            self.value
+        }
+        #[inline(always)]
+        pub fn low(&self) -> u64 {
+            (self.value & 1099511627775)
+        }
+        #[inline(always)]
+        pub fn high(&self) -> u64 {
+            (self.value >> 40)
+        }
+        #[inline(always)]
+        pub const fn with_low(self, new_value: u64) -> Self {
+            let context_mask: u64 = 1099511627775 << 0;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u64) & 1099511627775) << 0),
+            }
+        }
+        #[inline(always)]
+        pub const fn with_high(self, new_value: u32) -> Self {
+            let context_mask: u64 = 16777215 << 40;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u64) & 16777215) << 40),
+            }
+        }
+    }
+    impl From<WideGet> for WideValue {
+        #[inline(always)]
+        fn from(get: WideGet) -> WideValue {
+            WideValue::from_bits(get.value)
+        }
+    }
+    impl From<WideValue> for WideGet {
+        #[inline(always)]
+        fn from(value: WideValue) -> WideGet {
+            WideGet {
+                value: value.to_bits(),
+            }
+        }
+    }
+    impl Wide {
+        #[doc = "Write `value` to the register in a single store, without reading it first"]
+        #[inline(always)]
+        pub fn write(&self, value: WideValue) {
+            self.store(value.to_bits());
+        }
+    }
+    impl<'a> WideUpdate<'a> {
+        #[doc = "Replace every field of the register with those of `value`"]
+        #[inline(always)]
+        pub fn assign<'b>(&'b mut self, value: WideValue) -> &'b mut Self {
+            self.value = value.to_bits();
+            self.mask = !0;
+            self
+        }
+    }
+    impl WideGet {
+        pub const FIELDS: &'static [::volatile_cell::field::FieldDescriptor] = &[
+            ::volatile_cell::field::FieldDescriptor {
+                name: "low",
+                offset: 0u32,
+                width: 40u32,
+                count: 1u32,
+            },
+            ::volatile_cell::field::FieldDescriptor {
+                name: "high",
+                offset: 40u32,
+                width: 24u32,
+                count: 1u32,
+            },
+        ];
+        #[doc = "Iterate over the readable fields that differ from those of `other`"]
+        #[inline]
+        pub fn changed_fields(&self, other: &WideGet) -> ::volatile_cell::field::FieldChanges {
+            ::volatile_cell::field::FieldChanges::new(
+                Self::FIELDS,
+                self.value as u64,
+                other.value as u64,
+            )
+        }
+    }
+    pub mod ipr {}
+    #[repr(C)]
+    pub struct Ipr {
+        value: ::volatile_cell::VolatileCell<u32>,
+    }
+    impl Ipr {
+        #[inline(always)]
+        fn load(&self) -> u32 {
+            let value = self.value.get();
+            value
+        }
+        #[inline(always)]
+        fn store(&self, value: u32) {
+            self.value.set(value);
+        }
+        #[doc = "Create a new updater"]
+        #[inline(always)]
+        pub fn update<'a>(&'a self) -> IprUpdate<'a> {
+            IprUpdate::new(self)
+        }
+        #[doc = "Create an updater whose read-modify-write happens inside the critical section `cs`"]
+        #[inline(always)]
+        pub fn update_in<'a, C: ::volatile_cell::sync::CriticalSection>(
+            &'a self,
+            _cs: &'a C,
+        ) -> IprUpdate<'a> {
+            IprUpdate::new(self)
+        }
+        #[doc = "Update the register with exclusive loads and stores (LDREX/STREX), retrying `f` until no other access intervened"]
+        #[doc = ""]
+        #[doc = "The exclusive accesses go to the register's memory directly rather than through its cell, so they aren't seen by the replayer or the tracing hook."]
+        #[cfg(target_has_atomic = "32")]
+        #[inline]
+        pub fn update_exclusive<F: FnMut(&mut IprUpdate)>(&self, mut f: F) {
+            use ::core::sync::atomic::Ordering;
+            let cell = unsafe { &*(self.value.as_ptr() as *const ::core::sync::atomic::AtomicU32) };
+            let mut current = cell.load(Ordering::Relaxed);
+            loop {
+                let mut update = IprUpdate::detached(self);
+                f(&mut update);
+                let merged = update.merge(current);
+                if update.mask == 0 {
+                    return;
+                }
+                match cell.compare_exchange_weak(
+                    current,
+                    merged,
+                    Ordering::SeqCst,
+                    Ordering::Relaxed,
+                ) {
+                    Ok(_) => return,
+                    Err(actual) => current = actual,
+                }
+            }
+        }
+        #[doc = "Create a getter representing the current state of the register"]
+        #[inline(always)]
+        pub fn get(&self) -> IprGet {
+            IprGet::new(self)
+        }
+        #[doc = "The lanes of the register, in address order"]
+        #[inline(always)]
+        pub fn lanes(&self) -> &[::volatile_cell::VolatileCell<u8>; 4usize] {
+            unsafe { &*(self.value.as_ptr() as *const [::volatile_cell::VolatileCell<u8>; 4usize]) }
+        }
+        #[doc = "Lane `index` of the register, counting from the least significant. Its value is in the byte order of the device."]
+        #[inline(always)]
+        pub fn lane(&self, index: usize) -> &::volatile_cell::VolatileCell<u8> {
+            #[cfg(target_endian = "little")]
+            let index = index;
+            #[cfg(target_endian = "big")]
+            let index = 3usize - index;
+            &self.lanes()[index]
+        }
+        #[doc = "Read the field with a single narrow load of its lane"]
+        #[inline(always)]
+        pub fn read_ipr(&self, index: usize) -> u8 {
+            self.lane(0usize + index).get()
+        }
+        #[doc = "Write the field with a single narrow store to its lane, leaving the rest of the register untouched"]
+        #[inline(always)]
+        pub fn write_ipr(&self, index: usize, new_value: u8) {
+            self.lane(0usize + index).set(new_value);
+        }
+    }
+    pub struct IprUpdate<'a> {
+        value: u32,
+        mask: u32,
+        write_only: bool,
+        store_on_drop: bool,
+        reg: &'a Ipr,
+    }
+    impl<'a> IprUpdate<'a> {
+        #[inline(always)]
+        pub fn new(reg: &'a Ipr) -> IprUpdate<'a> {
+            IprUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[inline(always)]
+        pub fn new_ignoring_state(reg: &'a Ipr) -> IprUpdate<'a> {
+            IprUpdate {
+                value: 0,
+                mask: 0,
+                write_only: true,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[doc = "An update that is never stored, for the caller to store itself"]
+        #[inline(always)]
+        fn detached(reg: &'a Ipr) -> IprUpdate<'a> {
+            IprUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: false,
+                reg: reg,
+            }
+        }
+        const fn clear_mask() -> u32 {
+            0u64 as u32
+        }
+        const fn update_offset(base: usize, size: usize, index: usize) -> usize {
+            base + (size * index)
+        }
+        #[inline(always)]
+        fn merge(&self, current: u32) -> u32 {
+            let v: u32 = current & (!Self::clear_mask()) & (!self.mask);
+            self.value | v
+        }
+        #[inline(always)]
+        pub fn set_ipr<'b>(&'b mut self, index: usize, new_value: u8) -> &'b mut Self {
+            if index > 4 {
+                panic!();
+            }
+            let update_offset = Self::update_offset(0, 8, index);
+            let context_mask: u32 = 255 << update_offset;
+            self.value =
+                (self.value & !context_mask) | (((new_value as u32) & 255) << update_offset);
+            self.mask |= context_mask;
+            self
+        }
+    }
+    impl<'a> Drop for IprUpdate<'a> {
+        #[inline(always)]
+        fn drop(&mut self) {
+            if self.store_on_drop && self.mask != 0 {
+                let current: u32 = if self.write_only { 0 } else { self.reg.load() };
+                self.reg.store(self.merge(current));
+            }
+        }
+    }
+    #[derive(Clone, PartialEq, Eq)]
+    pub struct IprGet {
+        value: u32,
+    }
+    impl IprGet {
+        #[doc = "Create a getter reflecting the current value of the register"]
+        #[inline(always)]
+        pub fn new(reg: &Ipr) -> IprGet {
+            IprGet { value: reg.load() }
+        }
+        #[inline(always)]
+        pub fn ipr(&self, index: usize) -> u32 {
+            (self.value >> (0 + (8 * index))) & 255
+        }
+    }
+    #[derive(Clone, Copy, PartialEq, Eq, Default)]
+    #[repr(transparent)]
+    pub struct IprValue {
+        value: u32,
+    }
+    impl IprValue {
+        #[doc = "Create a value with every field cleared"]
+        #[inline(always)]
+        pub const fn new() -> IprValue {
+            IprValue { value: 0 }
+        }
+        #[inline(always)]
+        pub const fn from_bits(bits: u32) -> IprValue {
+            IprValue { value: bits }
+        }
+        #[inline(always)]
+        pub const fn to_bits(&self) -> u32 {
+            self.value
+        }
+        #[inline(always)]
+        pub fn ipr(&self, index: usize) -> u32 {
+            (self.value >> (0 + (8 * index))) & 255
+        }
+        #[inline(always)]
+        pub const fn with_ipr(self, index: usize, new_value: u8) -> Self {
+            if index >= 4 {
+                panic!();
+            }
+            let shift = 0 + (8 * index);
+            let context_mask: u32 = 255 << shift;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 255) << shift),
+            }
+        }
+    }
+    impl From<IprGet> for IprValue {
+        #[inline(always)]
+        fn from(get: IprGet) -> IprValue {
+            IprValue::from_bits(get.value)
+        }
+    }
+    impl From<IprValue> for IprGet {
+        #[inline(always)]
+        fn from(value: IprValue) -> IprGet {
+            IprGet {
+                value: value.to_bits(),
+            }
+        }
+    }
+    impl Ipr {
+        #[doc = "Write `value` to the register in a single store, without reading it first"]
+        #[inline(always)]
+        pub fn write(&self, value: IprValue) {
+            self.store(value.to_bits());
+        }
+    }
+    impl<'a> IprUpdate<'a> {
+        #[doc = "Replace every field of the register with those of `value`"]
+        #[inline(always)]
+        pub fn assign<'b>(&'b mut self, value: IprValue) -> &'b mut Self {
+            self.value = value.to_bits();
+            self.mask = !0;
+            self
+        }
+    }
+    impl IprGet {
+        pub const FIELDS: &'static [::volatile_cell::field::FieldDescriptor] =
+            &[::volatile_cell::field::FieldDescriptor {
+                name: "ipr",
+                offset: 0u32,
+                width: 8u32,
+                count: 4u32,
+            }];
+        #[doc = "Iterate over the readable fields that differ from those of `other`"]
+        #[inline]
+        pub fn changed_fields(&self, other: &IprGet) -> ::volatile_cell::field::FieldChanges {
+            ::volatile_cell::field::FieldChanges::new(
+                Self::FIELDS,
+                self.value as u64,
+                other.value as u64,
+            )
+        }
+    }
+    #[repr(C)]
+    pub struct Widths {
+        pub ctrl: Ctrl,
+        pub addr: Addr,
+        pub count: Count,
+        pub stamp: Stamp,
+        _padding0: [u8; 4],
+        pub wide: Wide,
+        pub ipr: [Ipr; 2],
+    }
+    #[doc = "Saved state of every readable register without side effects"]
+    #[derive(Clone, Copy)]
+    pub struct Snapshot {
+        pub ctrl: CtrlValue,
+        pub addr: AddrValue,
+        pub count: CountValue,
+        pub stamp: StampValue,
+        pub wide: WideValue,
+        pub ipr: [IprValue; 2],
+    }
+    #[doc = "Read the current state of the peripheral"]
+    #[allow(unused_variables)]
+    pub fn capture(p: &Widths) -> Snapshot {
+        Snapshot {
+            ctrl: CtrlValue::from(p.ctrl.get()),
+            addr: AddrValue::from(p.addr.get()),
+            count: CountValue::from(p.count.get()),
+            stamp: StampValue::from(p.stamp.get()),
+            wide: WideValue::from(p.wide.get()),
+            ipr: {
+                let mut values = [IprValue::new(); 2];
+                for i in 0..2 {
+                    values[i] = IprValue::from(p.ipr[i].get());
+                }
+                values
+            },
+        }
+    }
+    #[doc = "Write a previously captured state back to the peripheral"]
+    #[allow(unused_variables)]
+    pub fn restore(p: &Widths, snapshot: &Snapshot) {
+        CtrlUpdate::new_ignoring_state(&p.ctrl).assign(snapshot.ctrl);
+        AddrUpdate::new_ignoring_state(&p.addr).assign(snapshot.addr);
+        CountUpdate::new_ignoring_state(&p.count).assign(snapshot.count);
+        StampUpdate::new_ignoring_state(&p.stamp).assign(snapshot.stamp);
+        WideUpdate::new_ignoring_state(&p.wide).assign(snapshot.wide);
+        for i in 0..2 {
+            IprUpdate::new_ignoring_state(&p.ipr[i]).assign(snapshot.ipr[i]);
+        }
+    }
+    #[doc = "Owned handle granting exclusive access to the peripheral"]
+    pub struct WidthsPeripheral {
+        regs: &'static mut Widths,
+    }
+    impl WidthsPeripheral {
+        #[doc = "Create a token for the peripheral registers at `regs`."]
+        #[doc = ""]
+        #[doc = "Unsafe because the caller must ensure it's the only token for `regs`."]
+        #[inline(always)]
+        pub const unsafe fn new(regs: &'static mut Widths) -> WidthsPeripheral {
+            WidthsPeripheral { regs: regs }
+        }
+    }
+    impl ::core::ops::Deref for WidthsPeripheral {
+        type Target = Widths;
+        #[inline(always)]
+        fn deref(&self) -> &Widths {
+            self.regs
+        }
+    }
+    impl ::core::ops::DerefMut for WidthsPeripheral {
+        #[inline(always)]
+        fn deref_mut(&mut self) -> &mut Widths {
+            self.regs
+        }
+    }
+}
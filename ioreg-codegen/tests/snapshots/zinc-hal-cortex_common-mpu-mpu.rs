@@ -0,0 +1,1760 @@
+pub mod mpu {
+    pub mod mpu_type {}
+    #[repr(C)]
+    pub struct MpuType {
+        value: ::volatile_cell::VolatileCell<u32>,
+    }
+    impl MpuType {
+        #[inline(always)]
+        fn load(&self) -> u32 {
+            let value = self.value.get();
+            value
+        }
+        #[inline(always)]
+        fn store(&self, value: u32) {
+            self.value.set(value);
+        }
+        #[doc = "Create a new updater"]
+        #[inline(always)]
+        pub fn update<'a>(&'a self) -> MpuTypeUpdate<'a> {
+            MpuTypeUpdate::new(self)
+        }
+        #[doc = "Create an updater whose read-modify-write happens inside the critical section `cs`"]
+        #[inline(always)]
+        pub fn update_in<'a, C: ::volatile_cell::sync::CriticalSection>(
+            &'a self,
+            _cs: &'a C,
+        ) -> MpuTypeUpdate<'a> {
+            MpuTypeUpdate::new(self)
+        }
+        #[doc = "Update the register with exclusive loads and stores (LDREX/STREX), retrying `f` until no other access intervened"]
+        #[doc = ""]
+        #[doc = "The exclusive accesses go to the register's memory directly rather than through its cell, so they aren't seen by the replayer or the tracing hook."]
+        #[cfg(target_has_atomic = "32")]
+        #[inline]
+        pub fn update_exclusive<F: FnMut(&mut MpuTypeUpdate)>(&self, mut f: F) {
+            use ::core::sync::atomic::Ordering;
+            let cell = unsafe { &*(self.value.as_ptr() as *const ::core::sync::atomic::AtomicU32) };
+            let mut current = cell.load(Ordering::Relaxed);
+            loop {
+                let mut update = MpuTypeUpdate::detached(self);
+                f(&mut update);
+                let merged = update.merge(current);
+                if update.mask == 0 {
+                    return;
+                }
+                match cell.compare_exchange_weak(
+                    current,
+                    merged,
+                    Ordering::SeqCst,
+                    Ordering::Relaxed,
+                ) {
+                    Ok(_) => return,
+                    Err(actual) => current = actual,
+                }
+            }
+        }
+        #[doc = "Create a getter representing the current state of the register"]
+        #[inline(always)]
+        pub fn get(&self) -> MpuTypeGet {
+            MpuTypeGet::new(self)
+        }
+        #[doc = "Spin until the bit is set"]
+        #[inline]
+        pub fn wait_separate(&self) {
+            while !self.get().separate() {}
+        }
+        #[doc = "Poll until the bit is set, at most `max_polls` times"]
+        #[inline]
+        pub fn wait_separate_timeout(
+            &self,
+            max_polls: u32,
+        ) -> Result<(), ::volatile_cell::wait::Timeout> {
+            ::volatile_cell::wait::poll_until(|| self.get().separate(), max_polls)
+        }
+        #[doc = "Future resolving once the bit is set"]
+        #[inline]
+        pub fn wait_separate_async<'a>(
+            &'a self,
+        ) -> ::volatile_cell::wait::WaitUntil<'a, impl FnMut() -> bool + Unpin + 'a> {
+            ::volatile_cell::wait::WaitUntil::new(move || self.get().separate())
+        }
+    }
+    pub struct MpuTypeUpdate<'a> {
+        value: u32,
+        mask: u32,
+        write_only: bool,
+        store_on_drop: bool,
+        reg: &'a MpuType,
+    }
+    impl<'a> MpuTypeUpdate<'a> {
+        #[inline(always)]
+        pub fn new(reg: &'a MpuType) -> MpuTypeUpdate<'a> {
+            MpuTypeUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[inline(always)]
+        pub fn new_ignoring_state(reg: &'a MpuType) -> MpuTypeUpdate<'a> {
+            MpuTypeUpdate {
+                value: 0,
+                mask: 0,
+                write_only: true,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[doc = "An update that is never stored, for the caller to store itself"]
+        #[inline(always)]
+        fn detached(reg: &'a MpuType) -> MpuTypeUpdate<'a> {
+            MpuTypeUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: false,
+                reg: reg,
+            }
+        }
+        const fn clear_mask() -> u32 {
+            0u64 as u32
+        }
+        const fn update_offset(base: usize, size: usize, index: usize) -> usize {
+            base + (size * index)
+        }
+        #[inline(always)]
+        fn merge(&self, current: u32) -> u32 {
+            let v: u32 = current & (!Self::clear_mask()) & (!self.mask);
+            self.value | v
+        }
+    }
+    impl<'a> Drop for MpuTypeUpdate<'a> {
+        #[inline(always)]
+        fn drop(&mut self) {
+            if self.store_on_drop && self.mask != 0 {
+                let current: u32 = if self.write_only { 0 } else { self.reg.load() };
+                self.reg.store(self.merge(current));
+            }
+        }
+    }
+    #[derive(Clone, PartialEq, Eq)]
+    pub struct MpuTypeGet {
+        value: u32,
+    }
+    impl MpuTypeGet {
+        #[doc = "Create a getter reflecting the current value of the register"]
+        #[inline(always)]
+        pub fn new(reg: &MpuType) -> MpuTypeGet {
+            MpuTypeGet { value: reg.load() }
+        }
+        #[inline(always)]
+        pub fn separate(&self) -> bool {
+            let val = (self.value & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub fn dregion(&self) -> u32 {
+            ((self.value >> 8) & 255)
+        }
+        #[inline(always)]
+        pub fn iregion(&self) -> u32 {
+            ((self.value >> 16) & 255)
+        }
+    }
+    #[derive(Clone, Copy, PartialEq, Eq, Default)]
+    #[repr(transparent)]
+    pub struct MpuTypeValue {
+        value: u32,
+    }
+    impl MpuTypeValue {
+        #[doc = "Create a value with every field cleared"]
+        #[inline(always)]
+        pub const fn new() -> MpuTypeValue {
+            MpuTypeValue { value: 0 }
+        }
+        #[inline(always)]
+        pub const fn from_bits(bits: u32) -> MpuTypeValue {
+            MpuTypeValue { value: bits }
+        }
+        #[inline(always)]
+        pub const fn to_bits(&self) -> u32 {
+            self.value
+        }
+        #[inline(always)]
+        pub fn separate(&self) -> bool {
+            let val = (self.value & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub fn dregion(&self) -> u32 {
+            ((self.value >> 8) & 255)
+        }
+        #[inline(always)]
+        pub fn iregion(&self) -> u32 {
+            ((self.value >> 16) & 255)
+        }
+        #[inline(always)]
+        pub const fn with_separate(self, new_value: bool) -> Self {
+            let context_mask: u32 = 1 << 0;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 1) << 0),
+            }
+        }
+        #[inline(always)]
+        pub const fn with_dregion(self, new_value: u8) -> Self {
+            let context_mask: u32 = 255 << 8;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 255) << 8),
+            }
+        }
+        #[inline(always)]
+        pub const fn with_iregion(self, new_value: u8) -> Self {
+            let context_mask: u32 = 255 << 16;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 255) << 16),
+            }
+        }
+    }
+    impl From<MpuTypeGet> for MpuTypeValue {
+        #[inline(always)]
+        fn from(get: MpuTypeGet) -> MpuTypeValue {
+            MpuTypeValue::from_bits(get.value)
+        }
+    }
+    impl From<MpuTypeValue> for MpuTypeGet {
+        #[inline(always)]
+        fn from(value: MpuTypeValue) -> MpuTypeGet {
+            MpuTypeGet {
+                value: value.to_bits(),
+            }
+        }
+    }
+    impl MpuType {
+        #[doc = "Write `value` to the register in a single store, without reading it first"]
+        #[inline(always)]
+        pub fn write(&self, value: MpuTypeValue) {
+            self.store(value.to_bits());
+        }
+    }
+    impl<'a> MpuTypeUpdate<'a> {
+        #[doc = "Replace every field of the register with those of `value`"]
+        #[inline(always)]
+        pub fn assign<'b>(&'b mut self, value: MpuTypeValue) -> &'b mut Self {
+            self.value = value.to_bits();
+            self.mask = !0;
+            self
+        }
+    }
+    impl MpuTypeGet {
+        pub const FIELDS: &'static [::volatile_cell::field::FieldDescriptor] = &[
+            ::volatile_cell::field::FieldDescriptor {
+                name: "separate",
+                offset: 0u32,
+                width: 1u32,
+                count: 1u32,
+            },
+            ::volatile_cell::field::FieldDescriptor {
+                name: "dregion",
+                offset: 8u32,
+                width: 8u32,
+                count: 1u32,
+            },
+            ::volatile_cell::field::FieldDescriptor {
+                name: "iregion",
+                offset: 16u32,
+                width: 8u32,
+                count: 1u32,
+            },
+        ];
+        #[doc = "Iterate over the readable fields that differ from those of `other`"]
+        #[inline]
+        pub fn changed_fields(&self, other: &MpuTypeGet) -> ::volatile_cell::field::FieldChanges {
+            ::volatile_cell::field::FieldChanges::new(
+                Self::FIELDS,
+                self.value as u64,
+                other.value as u64,
+            )
+        }
+    }
+    pub mod ctrl {}
+    #[repr(C)]
+    pub struct Ctrl {
+        value: ::volatile_cell::VolatileCell<u32>,
+    }
+    impl Ctrl {
+        #[inline(always)]
+        fn load(&self) -> u32 {
+            let value = self.value.get();
+            value
+        }
+        #[inline(always)]
+        fn store(&self, value: u32) {
+            self.value.set(value);
+        }
+        #[doc = "Create a new updater"]
+        #[inline(always)]
+        pub fn update<'a>(&'a self) -> CtrlUpdate<'a> {
+            CtrlUpdate::new(self)
+        }
+        #[doc = "Create an updater whose read-modify-write happens inside the critical section `cs`"]
+        #[inline(always)]
+        pub fn update_in<'a, C: ::volatile_cell::sync::CriticalSection>(
+            &'a self,
+            _cs: &'a C,
+        ) -> CtrlUpdate<'a> {
+            CtrlUpdate::new(self)
+        }
+        #[doc = "Update the register with exclusive loads and stores (LDREX/STREX), retrying `f` until no other access intervened"]
+        #[doc = ""]
+        #[doc = "The exclusive accesses go to the register's memory directly rather than through its cell, so they aren't seen by the replayer or the tracing hook."]
+        #[cfg(target_has_atomic = "32")]
+        #[inline]
+        pub fn update_exclusive<F: FnMut(&mut CtrlUpdate)>(&self, mut f: F) {
+            use ::core::sync::atomic::Ordering;
+            let cell = unsafe { &*(self.value.as_ptr() as *const ::core::sync::atomic::AtomicU32) };
+            let mut current = cell.load(Ordering::Relaxed);
+            loop {
+                let mut update = CtrlUpdate::detached(self);
+                f(&mut update);
+                let merged = update.merge(current);
+                if update.mask == 0 {
+                    return;
+                }
+                match cell.compare_exchange_weak(
+                    current,
+                    merged,
+                    Ordering::SeqCst,
+                    Ordering::Relaxed,
+                ) {
+                    Ok(_) => return,
+                    Err(actual) => current = actual,
+                }
+            }
+        }
+        #[doc = "Create a getter representing the current state of the register"]
+        #[inline(always)]
+        pub fn get(&self) -> CtrlGet {
+            CtrlGet::new(self)
+        }
+        #[doc = "Spin until the bit is set"]
+        #[inline]
+        pub fn wait_enable(&self) {
+            while !self.get().enable() {}
+        }
+        #[doc = "Poll until the bit is set, at most `max_polls` times"]
+        #[inline]
+        pub fn wait_enable_timeout(
+            &self,
+            max_polls: u32,
+        ) -> Result<(), ::volatile_cell::wait::Timeout> {
+            ::volatile_cell::wait::poll_until(|| self.get().enable(), max_polls)
+        }
+        #[doc = "Future resolving once the bit is set"]
+        #[inline]
+        pub fn wait_enable_async<'a>(
+            &'a self,
+        ) -> ::volatile_cell::wait::WaitUntil<'a, impl FnMut() -> bool + Unpin + 'a> {
+            ::volatile_cell::wait::WaitUntil::new(move || self.get().enable())
+        }
+        #[doc = "Spin until the bit is set"]
+        #[inline]
+        pub fn wait_hfnmiena(&self) {
+            while !self.get().hfnmiena() {}
+        }
+        #[doc = "Poll until the bit is set, at most `max_polls` times"]
+        #[inline]
+        pub fn wait_hfnmiena_timeout(
+            &self,
+            max_polls: u32,
+        ) -> Result<(), ::volatile_cell::wait::Timeout> {
+            ::volatile_cell::wait::poll_until(|| self.get().hfnmiena(), max_polls)
+        }
+        #[doc = "Future resolving once the bit is set"]
+        #[inline]
+        pub fn wait_hfnmiena_async<'a>(
+            &'a self,
+        ) -> ::volatile_cell::wait::WaitUntil<'a, impl FnMut() -> bool + Unpin + 'a> {
+            ::volatile_cell::wait::WaitUntil::new(move || self.get().hfnmiena())
+        }
+        #[doc = "Spin until the bit is set"]
+        #[inline]
+        pub fn wait_privdefena(&self) {
+            while !self.get().privdefena() {}
+        }
+        #[doc = "Poll until the bit is set, at most `max_polls` times"]
+        #[inline]
+        pub fn wait_privdefena_timeout(
+            &self,
+            max_polls: u32,
+        ) -> Result<(), ::volatile_cell::wait::Timeout> {
+            ::volatile_cell::wait::poll_until(|| self.get().privdefena(), max_polls)
+        }
+        #[doc = "Future resolving once the bit is set"]
+        #[inline]
+        pub fn wait_privdefena_async<'a>(
+            &'a self,
+        ) -> ::volatile_cell::wait::WaitUntil<'a, impl FnMut() -> bool + Unpin + 'a> {
+            ::volatile_cell::wait::WaitUntil::new(move || self.get().privdefena())
+        }
+    }
+    pub struct CtrlUpdate<'a> {
+        value: u32,
+        mask: u32,
+        write_only: bool,
+        store_on_drop: bool,
+        reg: &'a Ctrl,
+    }
+    impl<'a> CtrlUpdate<'a> {
+        #[inline(always)]
+        pub fn new(reg: &'a Ctrl) -> CtrlUpdate<'a> {
+            CtrlUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[inline(always)]
+        pub fn new_ignoring_state(reg: &'a Ctrl) -> CtrlUpdate<'a> {
+            CtrlUpdate {
+                value: 0,
+                mask: 0,
+                write_only: true,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[doc = "An update that is never stored, for the caller to store itself"]
+        #[inline(always)]
+        fn detached(reg: &'a Ctrl) -> CtrlUpdate<'a> {
+            CtrlUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: false,
+                reg: reg,
+            }
+        }
+        const fn clear_mask() -> u32 {
+            0u64 as u32
+        }
+        const fn update_offset(base: usize, size: usize, index: usize) -> usize {
+            base + (size * index)
+        }
+        #[inline(always)]
+        fn merge(&self, current: u32) -> u32 {
+            let v: u32 = current & (!Self::clear_mask()) & (!self.mask);
+            self.value | v
+        }
+        #[inline(always)]
+        pub fn set_enable<'b>(&'b mut self, new_value: bool) -> &'b mut Self {
+            let context_mask: u32 = 1 << 0;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 1) << 0);
+            self.mask |= context_mask;
+            self
+        }
+        #[inline(always)]
+        pub fn set_hfnmiena<'b>(&'b mut self, new_value: bool) -> &'b mut Self {
+            let context_mask: u32 = 1 << 1;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 1) << 1);
+            self.mask |= context_mask;
+            self
+        }
+        #[inline(always)]
+        pub fn set_privdefena<'b>(&'b mut self, new_value: bool) -> &'b mut Self {
+            let context_mask: u32 = 1 << 2;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 1) << 2);
+            self.mask |= context_mask;
+            self
+        }
+    }
+    impl<'a> Drop for CtrlUpdate<'a> {
+        #[inline(always)]
+        fn drop(&mut self) {
+            if self.store_on_drop && self.mask != 0 {
+                let current: u32 = if self.write_only { 0 } else { self.reg.load() };
+                self.reg.store(self.merge(current));
+            }
+        }
+    }
+    #[derive(Clone, PartialEq, Eq)]
+    pub struct CtrlGet {
+        value: u32,
+    }
+    impl CtrlGet {
+        #[doc = "Create a getter reflecting the current value of the register"]
+        #[inline(always)]
+        pub fn new(reg: &Ctrl) -> CtrlGet {
+            CtrlGet { value: reg.load() }
+        }
+        #[inline(always)]
+        pub fn enable(&self) -> bool {
+            let val = (self.value & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub fn hfnmiena(&self) -> bool {
+            let val = ((self.value >> 1) & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub fn privdefena(&self) -> bool {
+            let val = ((self.value >> 2) & 1);
+            val != 0x0
+        }
+    }
+    #[derive(Clone, Copy, PartialEq, Eq, Default)]
+    #[repr(transparent)]
+    pub struct CtrlValue {
+        value: u32,
+    }
+    impl CtrlValue {
+        #[doc = "Create a value with every field cleared"]
+        #[inline(always)]
+        pub const fn new() -> CtrlValue {
+            CtrlValue { value: 0 }
+        }
+        #[inline(always)]
+        pub const fn from_bits(bits: u32) -> CtrlValue {
+            CtrlValue { value: bits }
+        }
+        #[inline(always)]
+        pub const fn to_bits(&self) -> u32 {
+            self.value
+        }
+        #[inline(always)]
+        pub fn enable(&self) -> bool {
+            let val = (self.value & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub fn hfnmiena(&self) -> bool {
+            let val = ((self.value >> 1) & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub fn privdefena(&self) -> bool {
+            let val = ((self.value >> 2) & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub const fn with_enable(self, new_value: bool) -> Self {
+            let context_mask: u32 = 1 << 0;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 1) << 0),
+            }
+        }
+        #[inline(always)]
+        pub const fn with_hfnmiena(self, new_value: bool) -> Self {
+            let context_mask: u32 = 1 << 1;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 1) << 1),
+            }
+        }
+        #[inline(always)]
+        pub const fn with_privdefena(self, new_value: bool) -> Self {
+            let context_mask: u32 = 1 << 2;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 1) << 2),
+            }
+        }
+    }
+    impl From<CtrlGet> for CtrlValue {
+        #[inline(always)]
+        fn from(get: CtrlGet) -> CtrlValue {
+            CtrlValue::from_bits(get.value)
+        }
+    }
+    impl From<CtrlValue> for CtrlGet {
+        #[inline(always)]
+        fn from(value: CtrlValue) -> CtrlGet {
+            CtrlGet {
+                value: value.to_bits(),
+            }
+        }
+    }
+    impl Ctrl {
+        #[doc = "Write `value` to the register in a single store, without reading it first"]
+        #[inline(always)]
+        pub fn write(&self, value: CtrlValue) {
+            self.store(value.to_bits());
+        }
+    }
+    impl<'a> CtrlUpdate<'a> {
+        #[doc = "Replace every field of the register with those of `value`"]
+        #[inline(always)]
+        pub fn assign<'b>(&'b mut self, value: CtrlValue) -> &'b mut Self {
+            self.value = value.to_bits();
+            self.mask = !0;
+            self
+        }
+    }
+    impl CtrlGet {
+        pub const FIELDS: &'static [::volatile_cell::field::FieldDescriptor] = &[
+            ::volatile_cell::field::FieldDescriptor {
+                name: "enable",
+                offset: 0u32,
+                width: 1u32,
+                count: 1u32,
+            },
+            ::volatile_cell::field::FieldDescriptor {
+                name: "hfnmiena",
+                offset: 1u32,
+                width: 1u32,
+                count: 1u32,
+            },
+            ::volatile_cell::field::FieldDescriptor {
+                name: "privdefena",
+                offset: 2u32,
+                width: 1u32,
+                count: 1u32,
+            },
+        ];
+        #[doc = "Iterate over the readable fields that differ from those of `other`"]
+        #[inline]
+        pub fn changed_fields(&self, other: &CtrlGet) -> ::volatile_cell::field::FieldChanges {
+            ::volatile_cell::field::FieldChanges::new(
+                Self::FIELDS,
+                self.value as u64,
+                other.value as u64,
+            )
+        }
+    }
+    pub mod rnr {}
+    #[repr(C)]
+    pub struct Rnr {
+        value: ::volatile_cell::VolatileCell<u32>,
+    }
+    impl Rnr {
+        #[inline(always)]
+        fn load(&self) -> u32 {
+            let value = self.value.get();
+            value
+        }
+        #[inline(always)]
+        fn store(&self, value: u32) {
+            self.value.set(value);
+        }
+        #[doc = "Create a new updater"]
+        #[inline(always)]
+        pub fn update<'a>(&'a self) -> RnrUpdate<'a> {
+            RnrUpdate::new(self)
+        }
+        #[doc = "Create an updater whose read-modify-write happens inside the critical section `cs`"]
+        #[inline(always)]
+        pub fn update_in<'a, C: ::volatile_cell::sync::CriticalSection>(
+            &'a self,
+            _cs: &'a C,
+        ) -> RnrUpdate<'a> {
+            RnrUpdate::new(self)
+        }
+        #[doc = "Update the register with exclusive loads and stores (LDREX/STREX), retrying `f` until no other access intervened"]
+        #[doc = ""]
+        #[doc = "The exclusive accesses go to the register's memory directly rather than through its cell, so they aren't seen by the replayer or the tracing hook."]
+        #[cfg(target_has_atomic = "32")]
+        #[inline]
+        pub fn update_exclusive<F: FnMut(&mut RnrUpdate)>(&self, mut f: F) {
+            use ::core::sync::atomic::Ordering;
+            let cell = unsafe { &*(self.value.as_ptr() as *const ::core::sync::atomic::AtomicU32) };
+            let mut current = cell.load(Ordering::Relaxed);
+            loop {
+                let mut update = RnrUpdate::detached(self);
+                f(&mut update);
+                let merged = update.merge(current);
+                if update.mask == 0 {
+                    return;
+                }
+                match cell.compare_exchange_weak(
+                    current,
+                    merged,
+                    Ordering::SeqCst,
+                    Ordering::Relaxed,
+                ) {
+                    Ok(_) => return,
+                    Err(actual) => current = actual,
+                }
+            }
+        }
+        #[doc = "Create a getter representing the current state of the register"]
+        #[inline(always)]
+        pub fn get(&self) -> RnrGet {
+            RnrGet::new(self)
+        }
+    }
+    pub struct RnrUpdate<'a> {
+        value: u32,
+        mask: u32,
+        write_only: bool,
+        store_on_drop: bool,
+        reg: &'a Rnr,
+    }
+    impl<'a> RnrUpdate<'a> {
+        #[inline(always)]
+        pub fn new(reg: &'a Rnr) -> RnrUpdate<'a> {
+            RnrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[inline(always)]
+        pub fn new_ignoring_state(reg: &'a Rnr) -> RnrUpdate<'a> {
+            RnrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: true,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[doc = "An update that is never stored, for the caller to store itself"]
+        #[inline(always)]
+        fn detached(reg: &'a Rnr) -> RnrUpdate<'a> {
+            RnrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: false,
+                reg: reg,
+            }
+        }
+        const fn clear_mask() -> u32 {
+            0u64 as u32
+        }
+        const fn update_offset(base: usize, size: usize, index: usize) -> usize {
+            base + (size * index)
+        }
+        #[inline(always)]
+        fn merge(&self, current: u32) -> u32 {
+            let v: u32 = current & (!Self::clear_mask()) & (!self.mask);
+            self.value | v
+        }
+        #[inline(always)]
+        pub fn set_region<'b>(&'b mut self, new_value: u8) -> &'b mut Self {
+            let context_mask: u32 = 255 << 0;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 255) << 0);
+            self.mask |= context_mask;
+            self
+        }
+    }
+    impl<'a> Drop for RnrUpdate<'a> {
+        #[inline(always)]
+        fn drop(&mut self) {
+            if self.store_on_drop && self.mask != 0 {
+                let current: u32 = if self.write_only { 0 } else { self.reg.load() };
+                self.reg.store(self.merge(current));
+            }
+        }
+    }
+    #[derive(Clone, PartialEq, Eq)]
+    pub struct RnrGet {
+        value: u32,
+    }
+    impl RnrGet {
+        #[doc = "Create a getter reflecting the current value of the register"]
+        #[inline(always)]
+        pub fn new(reg: &Rnr) -> RnrGet {
+            RnrGet { value: reg.load() }
+        }
+        #[inline(always)]
+        pub fn region(&self) -> u32 {
+            (self.value & 255)
+        }
+    }
+    #[derive(Clone, Copy, PartialEq, Eq, Default)]
+    #[repr(transparent)]
+    pub struct RnrValue {
+        value: u32,
+    }
+    impl RnrValue {
+        #[doc = "Create a value with every field cleared"]
+        #[inline(always)]
+        pub const fn new() -> RnrValue {
+            RnrValue { value: 0 }
+        }
+        #[inline(always)]
+        pub const fn from_bits(bits: u32) -> RnrValue {
+            RnrValue { value: bits }
+        }
+        #[inline(always)]
+        pub const fn to_bits(&self) -> u32 {
+            self.value
+        }
+        #[inline(always)]
+        pub fn region(&self) -> u32 {
+            (self.value & 255)
+        }
+        #[inline(always)]
+        pub const fn with_region(self, new_value: u8) -> Self {
+            let context_mask: u32 = 255 << 0;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 255) << 0),
+            }
+        }
+    }
+    impl From<RnrGet> for RnrValue {
+        #[inline(always)]
+        fn from(get: RnrGet) -> RnrValue {
+            RnrValue::from_bits(get.value)
+        }
+    }
+    impl From<RnrValue> for RnrGet {
+        #[inline(always)]
+        fn from(value: RnrValue) -> RnrGet {
+            RnrGet {
+                value: value.to_bits(),
+            }
+        }
+    }
+    impl Rnr {
+        #[doc = "Write `value` to the register in a single store, without reading it first"]
+        #[inline(always)]
+        pub fn write(&self, value: RnrValue) {
+            self.store(value.to_bits());
+        }
+    }
+    impl<'a> RnrUpdate<'a> {
+        #[doc = "Replace every field of the register with those of `value`"]
+        #[inline(always)]
+        pub fn assign<'b>(&'b mut self, value: RnrValue) -> &'b mut Self {
+            self.value = value.to_bits();
+            self.mask = !0;
+            self
+        }
+    }
+    impl RnrGet {
+        pub const FIELDS: &'static [::volatile_cell::field::FieldDescriptor] =
+            &[::volatile_cell::field::FieldDescriptor {
+                name: "region",
+                offset: 0u32,
+                width: 8u32,
+                count: 1u32,
+            }];
+        #[doc = "Iterate over the readable fields that differ from those of `other`"]
+        #[inline]
+        pub fn changed_fields(&self, other: &RnrGet) -> ::volatile_cell::field::FieldChanges {
+            ::volatile_cell::field::FieldChanges::new(
+                Self::FIELDS,
+                self.value as u64,
+                other.value as u64,
+            )
+        }
+    }
+    pub mod rbar {}
+    #[repr(C)]
+    pub struct Rbar {
+        value: ::volatile_cell::VolatileCell<u32>,
+    }
+    impl Rbar {
+        #[inline(always)]
+        fn load(&self) -> u32 {
+            let value = self.value.get();
+            value
+        }
+        #[inline(always)]
+        fn store(&self, value: u32) {
+            self.value.set(value);
+        }
+        #[doc = "Create a new updater"]
+        #[inline(always)]
+        pub fn update<'a>(&'a self) -> RbarUpdate<'a> {
+            RbarUpdate::new(self)
+        }
+        #[doc = "Create an updater whose read-modify-write happens inside the critical section `cs`"]
+        #[inline(always)]
+        pub fn update_in<'a, C: ::volatile_cell::sync::CriticalSection>(
+            &'a self,
+            _cs: &'a C,
+        ) -> RbarUpdate<'a> {
+            RbarUpdate::new(self)
+        }
+        #[doc = "Update the register with exclusive loads and stores (LDREX/STREX), retrying `f` until no other access intervened"]
+        #[doc = ""]
+        #[doc = "The exclusive accesses go to the register's memory directly rather than through its cell, so they aren't seen by the replayer or the tracing hook."]
+        #[cfg(target_has_atomic = "32")]
+        #[inline]
+        pub fn update_exclusive<F: FnMut(&mut RbarUpdate)>(&self, mut f: F) {
+            use ::core::sync::atomic::Ordering;
+            let cell = unsafe { &*(self.value.as_ptr() as *const ::core::sync::atomic::AtomicU32) };
+            let mut current = cell.load(Ordering::Relaxed);
+            loop {
+                let mut update = RbarUpdate::detached(self);
+                f(&mut update);
+                let merged = update.merge(current);
+                if update.mask == 0 {
+                    return;
+                }
+                match cell.compare_exchange_weak(
+                    current,
+                    merged,
+                    Ordering::SeqCst,
+                    Ordering::Relaxed,
+                ) {
+                    Ok(_) => return,
+                    Err(actual) => current = actual,
+                }
+            }
+        }
+        #[doc = "Create a getter representing the current state of the register"]
+        #[inline(always)]
+        pub fn get(&self) -> RbarGet {
+            RbarGet::new(self)
+        }
+        #[doc = "Spin until the bit is set"]
+        #[inline]
+        pub fn wait_valid(&self) {
+            while !self.get().valid() {}
+        }
+        #[doc = "Poll until the bit is set, at most `max_polls` times"]
+        #[inline]
+        pub fn wait_valid_timeout(
+            &self,
+            max_polls: u32,
+        ) -> Result<(), ::volatile_cell::wait::Timeout> {
+            ::volatile_cell::wait::poll_until(|| self.get().valid(), max_polls)
+        }
+        #[doc = "Future resolving once the bit is set"]
+        #[inline]
+        pub fn wait_valid_async<'a>(
+            &'a self,
+        ) -> ::volatile_cell::wait::WaitUntil<'a, impl FnMut() -> bool + Unpin + 'a> {
+            ::volatile_cell::wait::WaitUntil::new(move || self.get().valid())
+        }
+    }
+    pub struct RbarUpdate<'a> {
+        value: u32,
+        mask: u32,
+        write_only: bool,
+        store_on_drop: bool,
+        reg: &'a Rbar,
+    }
+    impl<'a> RbarUpdate<'a> {
+        #[inline(always)]
+        pub fn new(reg: &'a Rbar) -> RbarUpdate<'a> {
+            RbarUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[inline(always)]
+        pub fn new_ignoring_state(reg: &'a Rbar) -> RbarUpdate<'a> {
+            RbarUpdate {
+                value: 0,
+                mask: 0,
+                write_only: true,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[doc = "An update that is never stored, for the caller to store itself"]
+        #[inline(always)]
+        fn detached(reg: &'a Rbar) -> RbarUpdate<'a> {
+            RbarUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: false,
+                reg: reg,
+            }
+        }
+        const fn clear_mask() -> u32 {
+            0u64 as u32
+        }
+        const fn update_offset(base: usize, size: usize, index: usize) -> usize {
+            base + (size * index)
+        }
+        #[inline(always)]
+        fn merge(&self, current: u32) -> u32 {
+            let v: u32 = current & (!Self::clear_mask()) & (!self.mask);
+            self.value | v
+        }
+        #[inline(always)]
+        pub fn set_region<'b>(&'b mut self, new_value: u8) -> &'b mut Self {
+            let context_mask: u32 = 15 << 0;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 15) << 0);
+            self.mask |= context_mask;
+            self
+        }
+        #[inline(always)]
+        pub fn set_valid<'b>(&'b mut self, new_value: bool) -> &'b mut Self {
+            let context_mask: u32 = 1 << 4;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 1) << 4);
+            self.mask |= context_mask;
+            self
+        }
+        #[inline(always)]
+        pub fn set_addr<'b>(&'b mut self, new_value: u32) -> &'b mut Self {
+            let context_mask: u32 = 134217727 << 5;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 134217727) << 5);
+            self.mask |= context_mask;
+            self
+        }
+    }
+    impl<'a> Drop for RbarUpdate<'a> {
+        #[inline(always)]
+        fn drop(&mut self) {
+            if self.store_on_drop && self.mask != 0 {
+                let current: u32 = if self.write_only { 0 } else { self.reg.load() };
+                self.reg.store(self.merge(current));
+            }
+        }
+    }
+    #[derive(Clone, PartialEq, Eq)]
+    pub struct RbarGet {
+        value: u32,
+    }
+    impl RbarGet {
+        #[doc = "Create a getter reflecting the current value of the register"]
+        #[inline(always)]
+        pub fn new(reg: &Rbar) -> RbarGet {
+            RbarGet { value: reg.load() }
+        }
+        #[inline(always)]
+        pub fn region(&self) -> u32 {
+            (self.value & 15)
+        }
+        #[inline(always)]
+        pub fn valid(&self) -> bool {
+            let val = ((self.value >> 4) & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub fn addr(&self) -> u32 {
+            (self.value >> 5)
+        }
+    }
+    #[derive(Clone, Copy, PartialEq, Eq, Default)]
+    #[repr(transparent)]
+    pub struct RbarValue {
+        value: u32,
+    }
+    impl RbarValue {
+        #[doc = "Create a value with every field cleared"]
+        #[inline(always)]
+        pub const fn new() -> RbarValue {
+            RbarValue { value: 0 }
+        }
+        #[inline(always)]
+        pub const fn from_bits(bits: u32) -> RbarValue {
+            RbarValue { value: bits }
+        }
+        #[inline(always)]
+        pub const fn to_bits(&self) -> u32 {
+            self.value
+        }
+        #[inline(always)]
+        pub fn region(&self) -> u32 {
+            (self.value & 15)
+        }
+        #[inline(always)]
+        pub fn valid(&self) -> bool {
+            let val = ((self.value >> 4) & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub fn addr(&self) -> u32 {
+            (self.value >> 5)
+        }
+        #[inline(always)]
+        pub const fn with_region(self, new_value: u8) -> Self {
+            let context_mask: u32 = 15 << 0;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 15) << 0),
+            }
+        }
+        #[inline(always)]
+        pub const fn with_valid(self, new_value: bool) -> Self {
+            let context_mask: u32 = 1 << 4;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 1) << 4),
+            }
+        }
+        #[inline(always)]
+        pub const fn with_addr(self, new_value: u32) -> Self {
+            let context_mask: u32 = 134217727 << 5;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 134217727) << 5),
+            }
+        }
+    }
+    impl From<RbarGet> for RbarValue {
+        #[inline(always)]
+        fn from(get: RbarGet) -> RbarValue {
+            RbarValue::from_bits(get.value)
+        }
+    }
+    impl From<RbarValue> for RbarGet {
+        #[inline(always)]
+        fn from(value: RbarValue) -> RbarGet {
+            RbarGet {
+                value: value.to_bits(),
+            }
+        }
+    }
+    impl Rbar {
+        #[doc = "Write `value` to the register in a single store, without reading it first"]
+        #[inline(always)]
+        pub fn write(&self, value: RbarValue) {
+            self.store(value.to_bits());
+        }
+    }
+    impl<'a> RbarUpdate<'a> {
+        #[doc = "Replace every field of the register with those of `value`"]
+        #[inline(always)]
+        pub fn assign<'b>(&'b mut self, value: RbarValue) -> &'b mut Self {
+            self.value = value.to_bits();
+            self.mask = !0;
+            self
+        }
+    }
+    impl RbarGet {
+        pub const FIELDS: &'static [::volatile_cell::field::FieldDescriptor] = &[
+            ::volatile_cell::field::FieldDescriptor {
+                name: "region",
+                offset: 0u32,
+                width: 4u32,
+                count: 1u32,
+            },
+            ::volatile_cell::field::FieldDescriptor {
+                name: "valid",
+                offset: 4u32,
+                width: 1u32,
+                count: 1u32,
+            },
+            ::volatile_cell::field::FieldDescriptor {
+                name: "addr",
+                offset: 5u32,
+                width: 27u32,
+                count: 1u32,
+            },
+        ];
+        #[doc = "Iterate over the readable fields that differ from those of `other`"]
+        #[inline]
+        pub fn changed_fields(&self, other: &RbarGet) -> ::volatile_cell::field::FieldChanges {
+            ::volatile_cell::field::FieldChanges::new(
+                Self::FIELDS,
+                self.value as u64,
+                other.value as u64,
+            )
+        }
+    }
+    pub mod rasr {}
+    #[repr(C)]
+    pub struct Rasr {
+        value: ::volatile_cell::VolatileCell<u32>,
+    }
+    impl Rasr {
+        #[inline(always)]
+        fn load(&self) -> u32 {
+            let value = self.value.get();
+            value
+        }
+        #[inline(always)]
+        fn store(&self, value: u32) {
+            self.value.set(value);
+        }
+        #[doc = "Create a new updater"]
+        #[inline(always)]
+        pub fn update<'a>(&'a self) -> RasrUpdate<'a> {
+            RasrUpdate::new(self)
+        }
+        #[doc = "Create an updater whose read-modify-write happens inside the critical section `cs`"]
+        #[inline(always)]
+        pub fn update_in<'a, C: ::volatile_cell::sync::CriticalSection>(
+            &'a self,
+            _cs: &'a C,
+        ) -> RasrUpdate<'a> {
+            RasrUpdate::new(self)
+        }
+        #[doc = "Update the register with exclusive loads and stores (LDREX/STREX), retrying `f` until no other access intervened"]
+        #[doc = ""]
+        #[doc = "The exclusive accesses go to the register's memory directly rather than through its cell, so they aren't seen by the replayer or the tracing hook."]
+        #[cfg(target_has_atomic = "32")]
+        #[inline]
+        pub fn update_exclusive<F: FnMut(&mut RasrUpdate)>(&self, mut f: F) {
+            use ::core::sync::atomic::Ordering;
+            let cell = unsafe { &*(self.value.as_ptr() as *const ::core::sync::atomic::AtomicU32) };
+            let mut current = cell.load(Ordering::Relaxed);
+            loop {
+                let mut update = RasrUpdate::detached(self);
+                f(&mut update);
+                let merged = update.merge(current);
+                if update.mask == 0 {
+                    return;
+                }
+                match cell.compare_exchange_weak(
+                    current,
+                    merged,
+                    Ordering::SeqCst,
+                    Ordering::Relaxed,
+                ) {
+                    Ok(_) => return,
+                    Err(actual) => current = actual,
+                }
+            }
+        }
+        #[doc = "Create a getter representing the current state of the register"]
+        #[inline(always)]
+        pub fn get(&self) -> RasrGet {
+            RasrGet::new(self)
+        }
+        #[doc = "Spin until the bit is set"]
+        #[inline]
+        pub fn wait_enable(&self) {
+            while !self.get().enable() {}
+        }
+        #[doc = "Poll until the bit is set, at most `max_polls` times"]
+        #[inline]
+        pub fn wait_enable_timeout(
+            &self,
+            max_polls: u32,
+        ) -> Result<(), ::volatile_cell::wait::Timeout> {
+            ::volatile_cell::wait::poll_until(|| self.get().enable(), max_polls)
+        }
+        #[doc = "Future resolving once the bit is set"]
+        #[inline]
+        pub fn wait_enable_async<'a>(
+            &'a self,
+        ) -> ::volatile_cell::wait::WaitUntil<'a, impl FnMut() -> bool + Unpin + 'a> {
+            ::volatile_cell::wait::WaitUntil::new(move || self.get().enable())
+        }
+        #[doc = "Spin until the bit is set"]
+        #[inline]
+        pub fn wait_b(&self) {
+            while !self.get().b() {}
+        }
+        #[doc = "Poll until the bit is set, at most `max_polls` times"]
+        #[inline]
+        pub fn wait_b_timeout(&self, max_polls: u32) -> Result<(), ::volatile_cell::wait::Timeout> {
+            ::volatile_cell::wait::poll_until(|| self.get().b(), max_polls)
+        }
+        #[doc = "Future resolving once the bit is set"]
+        #[inline]
+        pub fn wait_b_async<'a>(
+            &'a self,
+        ) -> ::volatile_cell::wait::WaitUntil<'a, impl FnMut() -> bool + Unpin + 'a> {
+            ::volatile_cell::wait::WaitUntil::new(move || self.get().b())
+        }
+        #[doc = "Spin until the bit is set"]
+        #[inline]
+        pub fn wait_c(&self) {
+            while !self.get().c() {}
+        }
+        #[doc = "Poll until the bit is set, at most `max_polls` times"]
+        #[inline]
+        pub fn wait_c_timeout(&self, max_polls: u32) -> Result<(), ::volatile_cell::wait::Timeout> {
+            ::volatile_cell::wait::poll_until(|| self.get().c(), max_polls)
+        }
+        #[doc = "Future resolving once the bit is set"]
+        #[inline]
+        pub fn wait_c_async<'a>(
+            &'a self,
+        ) -> ::volatile_cell::wait::WaitUntil<'a, impl FnMut() -> bool + Unpin + 'a> {
+            ::volatile_cell::wait::WaitUntil::new(move || self.get().c())
+        }
+        #[doc = "Spin until the bit is set"]
+        #[inline]
+        pub fn wait_s(&self) {
+            while !self.get().s() {}
+        }
+        #[doc = "Poll until the bit is set, at most `max_polls` times"]
+        #[inline]
+        pub fn wait_s_timeout(&self, max_polls: u32) -> Result<(), ::volatile_cell::wait::Timeout> {
+            ::volatile_cell::wait::poll_until(|| self.get().s(), max_polls)
+        }
+        #[doc = "Future resolving once the bit is set"]
+        #[inline]
+        pub fn wait_s_async<'a>(
+            &'a self,
+        ) -> ::volatile_cell::wait::WaitUntil<'a, impl FnMut() -> bool + Unpin + 'a> {
+            ::volatile_cell::wait::WaitUntil::new(move || self.get().s())
+        }
+        #[doc = "Spin until the bit is set"]
+        #[inline]
+        pub fn wait_xn(&self) {
+            while !self.get().xn() {}
+        }
+        #[doc = "Poll until the bit is set, at most `max_polls` times"]
+        #[inline]
+        pub fn wait_xn_timeout(
+            &self,
+            max_polls: u32,
+        ) -> Result<(), ::volatile_cell::wait::Timeout> {
+            ::volatile_cell::wait::poll_until(|| self.get().xn(), max_polls)
+        }
+        #[doc = "Future resolving once the bit is set"]
+        #[inline]
+        pub fn wait_xn_async<'a>(
+            &'a self,
+        ) -> ::volatile_cell::wait::WaitUntil<'a, impl FnMut() -> bool + Unpin + 'a> {
+            ::volatile_cell::wait::WaitUntil::new(move || self.get().xn())
+        }
+    }
+    pub struct RasrUpdate<'a> {
+        value: u32,
+        mask: u32,
+        write_only: bool,
+        store_on_drop: bool,
+        reg: &'a Rasr,
+    }
+    impl<'a> RasrUpdate<'a> {
+        #[inline(always)]
+        pub fn new(reg: &'a Rasr) -> RasrUpdate<'a> {
+            RasrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[inline(always)]
+        pub fn new_ignoring_state(reg: &'a Rasr) -> RasrUpdate<'a> {
+            RasrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: true,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[doc = "An update that is never stored, for the caller to store itself"]
+        #[inline(always)]
+        fn detached(reg: &'a Rasr) -> RasrUpdate<'a> {
+            RasrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: false,
+                reg: reg,
+            }
+        }
+        const fn clear_mask() -> u32 {
+            0u64 as u32
+        }
+        const fn update_offset(base: usize, size: usize, index: usize) -> usize {
+            base + (size * index)
+        }
+        #[inline(always)]
+        fn merge(&self, current: u32) -> u32 {
+            let v: u32 = current & (!Self::clear_mask()) & (!self.mask);
+            self.value | v
+        }
+        #[inline(always)]
+        pub fn set_enable<'b>(&'b mut self, new_value: bool) -> &'b mut Self {
+            let context_mask: u32 = 1 << 0;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 1) << 0);
+            self.mask |= context_mask;
+            self
+        }
+        #[inline(always)]
+        pub fn set_size<'b>(&'b mut self, new_value: u8) -> &'b mut Self {
+            let context_mask: u32 = 31 << 1;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 31) << 1);
+            self.mask |= context_mask;
+            self
+        }
+        #[inline(always)]
+        pub fn set_srd<'b>(&'b mut self, new_value: u8) -> &'b mut Self {
+            let context_mask: u32 = 255 << 8;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 255) << 8);
+            self.mask |= context_mask;
+            self
+        }
+        #[inline(always)]
+        pub fn set_b<'b>(&'b mut self, new_value: bool) -> &'b mut Self {
+            let context_mask: u32 = 1 << 16;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 1) << 16);
+            self.mask |= context_mask;
+            self
+        }
+        #[inline(always)]
+        pub fn set_c<'b>(&'b mut self, new_value: bool) -> &'b mut Self {
+            let context_mask: u32 = 1 << 17;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 1) << 17);
+            self.mask |= context_mask;
+            self
+        }
+        #[inline(always)]
+        pub fn set_s<'b>(&'b mut self, new_value: bool) -> &'b mut Self {
+            let context_mask: u32 = 1 << 18;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 1) << 18);
+            self.mask |= context_mask;
+            self
+        }
+        #[inline(always)]
+        pub fn set_tex<'b>(&'b mut self, new_value: u8) -> &'b mut Self {
+            let context_mask: u32 = 7 << 19;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 7) << 19);
+            self.mask |= context_mask;
+            self
+        }
+        #[inline(always)]
+        pub fn set_ap<'b>(&'b mut self, new_value: u8) -> &'b mut Self {
+            let context_mask: u32 = 7 << 24;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 7) << 24);
+            self.mask |= context_mask;
+            self
+        }
+        #[inline(always)]
+        pub fn set_xn<'b>(&'b mut self, new_value: bool) -> &'b mut Self {
+            let context_mask: u32 = 1 << 28;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 1) << 28);
+            self.mask |= context_mask;
+            self
+        }
+    }
+    impl<'a> Drop for RasrUpdate<'a> {
+        #[inline(always)]
+        fn drop(&mut self) {
+            if self.store_on_drop && self.mask != 0 {
+                let current: u32 = if self.write_only { 0 } else { self.reg.load() };
+                self.reg.store(self.merge(current));
+            }
+        }
+    }
+    #[derive(Clone, PartialEq, Eq)]
+    pub struct RasrGet {
+        value: u32,
+    }
+    impl RasrGet {
+        #[doc = "Create a getter reflecting the current value of the register"]
+        #[inline(always)]
+        pub fn new(reg: &Rasr) -> RasrGet {
+            RasrGet { value: reg.load() }
+        }
+        #[inline(always)]
+        pub fn enable(&self) -> bool {
+            let val = (self.value & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub fn size(&self) -> u32 {
+            ((self.value >> 1) & 31)
+        }
+        #[inline(always)]
+        pub fn srd(&self) -> u32 {
+            ((self.value >> 8) & 255)
+        }
+        #[inline(always)]
+        pub fn b(&self) -> bool {
+            let val = ((self.value >> 16) & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub fn c(&self) -> bool {
+            let val = ((self.value >> 17) & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub fn s(&self) -> bool {
+            let val = ((self.value >> 18) & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub fn tex(&self) -> u32 {
+            ((self.value >> 19) & 7)
+        }
+        #[inline(always)]
+        pub fn ap(&self) -> u32 {
+            ((self.value >> 24) & 7)
+        }
+        #[inline(always)]
+        pub fn xn(&self) -> bool {
+            let val = ((self.value >> 28) & 1);
+            val != 0x0
+        }
+    }
+    #[derive(Clone, Copy, PartialEq, Eq, Default)]
+    #[repr(transparent)]
+    pub struct RasrValue {
+        value: u32,
+    }
+    impl RasrValue {
+        #[doc = "Create a value with every field cleared"]
+        #[inline(always)]
+        pub const fn new() -> RasrValue {
+            RasrValue { value: 0 }
+        }
+        #[inline(always)]
+        pub const fn from_bits(bits: u32) -> RasrValue {
+            RasrValue { value: bits }
+        }
+        #[inline(always)]
+        pub const fn to_bits(&self) -> u32 {
+            self.value
+        }
+        #[inline(always)]
+        pub fn enable(&self) -> bool {
+            let val = (self.value & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub fn size(&self) -> u32 {
+            ((self.value >> 1) & 31)
+        }
+        #[inline(always)]
+        pub fn srd(&self) -> u32 {
+            ((self.value >> 8) & 255)
+        }
+        #[inline(always)]
+        pub fn b(&self) -> bool {
+            let val = ((self.value >> 16) & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub fn c(&self) -> bool {
+            let val = ((self.value >> 17) & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub fn s(&self) -> bool {
+            let val = ((self.value >> 18) & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub fn tex(&self) -> u32 {
+            ((self.value >> 19) & 7)
+        }
+        #[inline(always)]
+        pub fn ap(&self) -> u32 {
+            ((self.value >> 24) & 7)
+        }
+        #[inline(always)]
+        pub fn xn(&self) -> bool {
+            let val = ((self.value >> 28) & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub const fn with_enable(self, new_value: bool) -> Self {
+            let context_mask: u32 = 1 << 0;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 1) << 0),
+            }
+        }
+        #[inline(always)]
+        pub const fn with_size(self, new_value: u8) -> Self {
+            let context_mask: u32 = 31 << 1;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 31) << 1),
+            }
+        }
+        #[inline(always)]
+        pub const fn with_srd(self, new_value: u8) -> Self {
+            let context_mask: u32 = 255 << 8;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 255) << 8),
+            }
+        }
+        #[inline(always)]
+        pub const fn with_b(self, new_value: bool) -> Self {
+            let context_mask: u32 = 1 << 16;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 1) << 16),
+            }
+        }
+        #[inline(always)]
+        pub const fn with_c(self, new_value: bool) -> Self {
+            let context_mask: u32 = 1 << 17;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 1) << 17),
+            }
+        }
+        #[inline(always)]
+        pub const fn with_s(self, new_value: bool) -> Self {
+            let context_mask: u32 = 1 << 18;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 1) << 18),
+            }
+        }
+        #[inline(always)]
+        pub const fn with_tex(self, new_value: u8) -> Self {
+            let context_mask: u32 = 7 << 19;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 7) << 19),
+            }
+        }
+        #[inline(always)]
+        pub const fn with_ap(self, new_value: u8) -> Self {
+            let context_mask: u32 = 7 << 24;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 7) << 24),
+            }
+        }
+        #[inline(always)]
+        pub const fn with_xn(self, new_value: bool) -> Self {
+            let context_mask: u32 = 1 << 28;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 1) << 28),
+            }
+        }
+    }
+    impl From<RasrGet> for RasrValue {
+        #[inline(always)]
+        fn from(get: RasrGet) -> RasrValue {
+            RasrValue::from_bits(get.value)
+        }
+    }
+    impl From<RasrValue> for RasrGet {
+        #[inline(always)]
+        fn from(value: RasrValue) -> RasrGet {
+            RasrGet {
+                value: value.to_bits(),
+            }
+        }
+    }
+    impl Rasr {
+        #[doc = "Write `value` to the register in a single store, without reading it first"]
+        #[inline(always)]
+        pub fn write(&self, value: RasrValue) {
+            self.store(value.to_bits());
+        }
+    }
+    impl<'a> RasrUpdate<'a> {
+        #[doc = "Replace every field of the register with those of `value`"]
+        #[inline(always)]
+        pub fn assign<'b>(&'b mut self, value: RasrValue) -> &'b mut Self {
+            self.value = value.to_bits();
+            self.mask = !0;
+            self
+        }
+    }
+    impl RasrGet {
+        pub const FIELDS: &'static [::volatile_cell::field::FieldDescriptor] = &[
+            ::volatile_cell::field::FieldDescriptor {
+                name: "enable",
+                offset: 0u32,
+                width: 1u32,
+                count: 1u32,
+            },
+            ::volatile_cell::field::FieldDescriptor {
+                name: "size",
+                offset: 1u32,
+                width: 5u32,
+                count: 1u32,
+            },
+            ::volatile_cell::field::FieldDescriptor {
+                name: "srd",
+                offset: 8u32,
+                width: 8u32,
+                count: 1u32,
+            },
+            ::volatile_cell::field::FieldDescriptor {
+                name: "b",
+                offset: 16u32,
+                width: 1u32,
+                count: 1u32,
+            },
+            ::volatile_cell::field::FieldDescriptor {
+                name: "c",
+                offset: 17u32,
+                width: 1u32,
+                count: 1u32,
+            },
+            ::volatile_cell::field::FieldDescriptor {
+                name: "s",
+                offset: 18u32,
+                width: 1u32,
+                count: 1u32,
+            },
+            ::volatile_cell::field::FieldDescriptor {
+                name: "tex",
+                offset: 19u32,
+                width: 3u32,
+                count: 1u32,
+            },
+            ::volatile_cell::field::FieldDescriptor {
+                name: "ap",
+                offset: 24u32,
+                width: 3u32,
+                count: 1u32,
+            },
+            ::volatile_cell::field::FieldDescriptor {
+                name: "xn",
+                offset: 28u32,
+                width: 1u32,
+                count: 1u32,
+            },
+        ];
+        #[doc = "Iterate over the readable fields that differ from those of `other`"]
+        #[inline]
+        pub fn changed_fields(&self, other: &RasrGet) -> ::volatile_cell::field::FieldChanges {
+            ::volatile_cell::field::FieldChanges::new(
+                Self::FIELDS,
+                self.value as u64,
+                other.value as u64,
+            )
+        }
+    }
+    #[repr(C)]
+    pub struct Mpu {
+        pub mpu_type: MpuType,
+        pub ctrl: Ctrl,
+        pub rnr: Rnr,
+        pub rbar: Rbar,
+        pub rasr: Rasr,
+    }
+    #[doc = "Saved state of every readable register without side effects"]
+    #[derive(Clone, Copy)]
+    pub struct Snapshot {
+        pub mpu_type: MpuTypeValue,
+        pub ctrl: CtrlValue,
+        pub rnr: RnrValue,
+        pub rbar: RbarValue,
+        pub rasr: RasrValue,
+    }
+    #[doc = "Read the current state of the peripheral"]
+    #[allow(unused_variables)]
+    pub fn capture(p: &Mpu) -> Snapshot {
+        Snapshot {
+            mpu_type: MpuTypeValue::from(p.mpu_type.get()),
+            ctrl: CtrlValue::from(p.ctrl.get()),
+            rnr: RnrValue::from(p.rnr.get()),
+            rbar: RbarValue::from(p.rbar.get()),
+            rasr: RasrValue::from(p.rasr.get()),
+        }
+    }
+    #[doc = "Write a previously captured state back to the peripheral"]
+    #[allow(unused_variables)]
+    pub fn restore(p: &Mpu, snapshot: &Snapshot) {
+        CtrlUpdate::new_ignoring_state(&p.ctrl).assign(snapshot.ctrl);
+        RnrUpdate::new_ignoring_state(&p.rnr).assign(snapshot.rnr);
+        RbarUpdate::new_ignoring_state(&p.rbar).assign(snapshot.rbar);
+        RasrUpdate::new_ignoring_state(&p.rasr).assign(snapshot.rasr);
+    }
+    #[doc = "Owned handle granting exclusive access to the peripheral"]
+    pub struct MpuPeripheral {
+        regs: &'static mut Mpu,
+    }
+    impl MpuPeripheral {
+        #[doc = "Create a token for the peripheral registers at `regs`."]
+        #[doc = ""]
+        #[doc = "Unsafe because the caller must ensure it's the only token for `regs`."]
+        #[inline(always)]
+        pub const unsafe fn new(regs: &'static mut Mpu) -> MpuPeripheral {
+            MpuPeripheral { regs: regs }
+        }
+    }
+    impl ::core::ops::Deref for MpuPeripheral {
+        type Target = Mpu;
+        #[inline(always)]
+        fn deref(&self) -> &Mpu {
+            self.regs
+        }
+    }
+    impl ::core::ops::DerefMut for MpuPeripheral {
+        #[inline(always)]
+        fn deref_mut(&mut self) -> &mut Mpu {
+            self.regs
+        }
+    }
+}
@@ -0,0 +1,1809 @@
+pub mod nvic {
+    pub mod iser {}
+    #[repr(C)]
+    pub struct Iser {
+        value: ::volatile_cell::VolatileCell<u32>,
+    }
+    impl Iser {
+        #[inline(always)]
+        fn load(&self) -> u32 {
+            let value = self.value.get();
+            value
+        }
+        #[inline(always)]
+        fn store(&self, value: u32) {
+            self.value.set(value);
+        }
+        #[doc = "Create a new updater"]
+        #[inline(always)]
+        pub fn update<'a>(&'a self) -> IserUpdate<'a> {
+            IserUpdate::new(self)
+        }
+        #[doc = "Create an updater whose read-modify-write happens inside the critical section `cs`"]
+        #[inline(always)]
+        pub fn update_in<'a, C: ::volatile_cell::sync::CriticalSection>(
+            &'a self,
+            _cs: &'a C,
+        ) -> IserUpdate<'a> {
+            IserUpdate::new(self)
+        }
+        #[doc = "Update the register with exclusive loads and stores (LDREX/STREX), retrying `f` until no other access intervened"]
+        #[doc = ""]
+        #[doc = "The exclusive accesses go to the register's memory directly rather than through its cell, so they aren't seen by the replayer or the tracing hook."]
+        #[cfg(target_has_atomic = "32")]
+        #[inline]
+        pub fn update_exclusive<F: FnMut(&mut IserUpdate)>(&self, mut f: F) {
+            use ::core::sync::atomic::Ordering;
+            let cell = unsafe { &*(self.value.as_ptr() as *const ::core::sync::atomic::AtomicU32) };
+            let mut current = cell.load(Ordering::Relaxed);
+            loop {
+                let mut update = IserUpdate::detached(self);
+                f(&mut update);
+                let merged = update.merge(current);
+                if update.mask == 0 {
+                    return;
+                }
+                match cell.compare_exchange_weak(
+                    current,
+                    merged,
+                    Ordering::SeqCst,
+                    Ordering::Relaxed,
+                ) {
+                    Ok(_) => return,
+                    Err(actual) => current = actual,
+                }
+            }
+        }
+        #[doc = "Create a getter representing the current state of the register"]
+        #[inline(always)]
+        pub fn get(&self) -> IserGet {
+            IserGet::new(self)
+        }
+        #[doc = "Spin until the bit is set"]
+        #[inline]
+        pub fn wait_iser(&self, index: usize) {
+            while !self.get().iser(index) {}
+        }
+        #[doc = "Poll until the bit is set, at most `max_polls` times"]
+        #[inline]
+        pub fn wait_iser_timeout(
+            &self,
+            index: usize,
+            max_polls: u32,
+        ) -> Result<(), ::volatile_cell::wait::Timeout> {
+            ::volatile_cell::wait::poll_until(|| self.get().iser(index), max_polls)
+        }
+        #[doc = "Future resolving once the bit is set"]
+        #[inline]
+        pub fn wait_iser_async<'a>(
+            &'a self,
+            index: usize,
+        ) -> ::volatile_cell::wait::WaitUntil<'a, impl FnMut() -> bool + Unpin + 'a> {
+            ::volatile_cell::wait::WaitUntil::new(move || self.get().iser(index))
+        }
+    }
+    pub struct IserUpdate<'a> {
+        value: u32,
+        mask: u32,
+        write_only: bool,
+        store_on_drop: bool,
+        reg: &'a Iser,
+    }
+    impl<'a> IserUpdate<'a> {
+        #[inline(always)]
+        pub fn new(reg: &'a Iser) -> IserUpdate<'a> {
+            IserUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[inline(always)]
+        pub fn new_ignoring_state(reg: &'a Iser) -> IserUpdate<'a> {
+            IserUpdate {
+                value: 0,
+                mask: 0,
+                write_only: true,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[doc = "An update that is never stored, for the caller to store itself"]
+        #[inline(always)]
+        fn detached(reg: &'a Iser) -> IserUpdate<'a> {
+            IserUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: false,
+                reg: reg,
+            }
+        }
+        const fn clear_mask() -> u32 {
+            4294967295u64 as u32
+        }
+        const fn update_offset(base: usize, size: usize, index: usize) -> usize {
+            base + (size * index)
+        }
+        #[inline(always)]
+        fn merge(&self, current: u32) -> u32 {
+            let v: u32 = current & (!Self::clear_mask()) & (!self.mask);
+            self.value | v
+        }
+        #[inline(always)]
+        pub fn clear_iser<'b>(&'b mut self, index: usize) -> &'b mut Self {
+            let shift = (0 + (1 * index));
+            self.value |= 1 << shift;
+            self.mask |= 1 << shift;
+            self
+        }
+    }
+    impl<'a> Drop for IserUpdate<'a> {
+        #[inline(always)]
+        fn drop(&mut self) {
+            if self.store_on_drop && self.mask != 0 {
+                let current: u32 = if self.write_only { 0 } else { self.reg.load() };
+                self.reg.store(self.merge(current));
+            }
+        }
+    }
+    #[derive(Clone, PartialEq, Eq)]
+    pub struct IserGet {
+        value: u32,
+    }
+    impl IserGet {
+        #[doc = "Create a getter reflecting the current value of the register"]
+        #[inline(always)]
+        pub fn new(reg: &Iser) -> IserGet {
+            IserGet { value: reg.load() }
+        }
+        #[inline(always)]
+        pub fn iser(&self, index: usize) -> bool {
+            let val = (self.value >> (0 + (1 * index))) & 1;
+            val != 0x0
+        }
+    }
+    #[derive(Clone, Copy, PartialEq, Eq, Default)]
+    #[repr(transparent)]
+    pub struct IserValue {
+        value: u32,
+    }
+    impl IserValue {
+        #[doc = "Create a value with every field cleared"]
+        #[inline(always)]
+        pub const fn new() -> IserValue {
+            IserValue { value: 0 }
+        }
+        #[inline(always)]
+        pub const fn from_bits(bits: u32) -> IserValue {
+            IserValue { value: bits }
+        }
+        #[inline(always)]
+        pub const fn to_bits(&self) -> u32 {
+            self.value
+        }
+        #[inline(always)]
+        pub fn iser(&self, index: usize) -> bool {
+            let val = (self.value >> (0 + (1 * index))) & 1;
+            val != 0x0
+        }
+        #[inline(always)]
+        pub const fn with_iser(self, index: usize, new_value: bool) -> Self {
+            if index >= 32 {
+                panic!();
+            }
+            let shift = 0 + (1 * index);
+            let context_mask: u32 = 1 << shift;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 1) << shift),
+            }
+        }
+    }
+    impl From<IserGet> for IserValue {
+        #[inline(always)]
+        fn from(get: IserGet) -> IserValue {
+            IserValue::from_bits(get.value)
+        }
+    }
+    impl From<IserValue> for IserGet {
+        #[inline(always)]
+        fn from(value: IserValue) -> IserGet {
+            IserGet {
+                value: value.to_bits(),
+            }
+        }
+    }
+    impl Iser {
+        #[doc = "Write `value` to the register in a single store, without reading it first"]
+        #[inline(always)]
+        pub fn write(&self, value: IserValue) {
+            self.store(value.to_bits());
+        }
+    }
+    impl<'a> IserUpdate<'a> {
+        #[doc = "Replace every field of the register with those of `value`"]
+        #[inline(always)]
+        pub fn assign<'b>(&'b mut self, value: IserValue) -> &'b mut Self {
+            self.value = value.to_bits();
+            self.mask = !0;
+            self
+        }
+    }
+    impl IserGet {
+        pub const FIELDS: &'static [::volatile_cell::field::FieldDescriptor] =
+            &[::volatile_cell::field::FieldDescriptor {
+                name: "iser",
+                offset: 0u32,
+                width: 1u32,
+                count: 32u32,
+            }];
+        #[doc = "Iterate over the readable fields that differ from those of `other`"]
+        #[inline]
+        pub fn changed_fields(&self, other: &IserGet) -> ::volatile_cell::field::FieldChanges {
+            ::volatile_cell::field::FieldChanges::new(
+                Self::FIELDS,
+                self.value as u64,
+                other.value as u64,
+            )
+        }
+    }
+    pub mod icer {}
+    #[repr(C)]
+    pub struct Icer {
+        value: ::volatile_cell::VolatileCell<u32>,
+    }
+    impl Icer {
+        #[inline(always)]
+        fn load(&self) -> u32 {
+            let value = self.value.get();
+            value
+        }
+        #[inline(always)]
+        fn store(&self, value: u32) {
+            self.value.set(value);
+        }
+        #[doc = "Create a new updater"]
+        #[inline(always)]
+        pub fn update<'a>(&'a self) -> IcerUpdate<'a> {
+            IcerUpdate::new(self)
+        }
+        #[doc = "Create an updater whose read-modify-write happens inside the critical section `cs`"]
+        #[inline(always)]
+        pub fn update_in<'a, C: ::volatile_cell::sync::CriticalSection>(
+            &'a self,
+            _cs: &'a C,
+        ) -> IcerUpdate<'a> {
+            IcerUpdate::new(self)
+        }
+        #[doc = "Update the register with exclusive loads and stores (LDREX/STREX), retrying `f` until no other access intervened"]
+        #[doc = ""]
+        #[doc = "The exclusive accesses go to the register's memory directly rather than through its cell, so they aren't seen by the replayer or the tracing hook."]
+        #[cfg(target_has_atomic = "32")]
+        #[inline]
+        pub fn update_exclusive<F: FnMut(&mut IcerUpdate)>(&self, mut f: F) {
+            use ::core::sync::atomic::Ordering;
+            let cell = unsafe { &*(self.value.as_ptr() as *const ::core::sync::atomic::AtomicU32) };
+            let mut current = cell.load(Ordering::Relaxed);
+            loop {
+                let mut update = IcerUpdate::detached(self);
+                f(&mut update);
+                let merged = update.merge(current);
+                if update.mask == 0 {
+                    return;
+                }
+                match cell.compare_exchange_weak(
+                    current,
+                    merged,
+                    Ordering::SeqCst,
+                    Ordering::Relaxed,
+                ) {
+                    Ok(_) => return,
+                    Err(actual) => current = actual,
+                }
+            }
+        }
+        #[doc = "Create a getter representing the current state of the register"]
+        #[inline(always)]
+        pub fn get(&self) -> IcerGet {
+            IcerGet::new(self)
+        }
+        #[doc = "Spin until the bit is set"]
+        #[inline]
+        pub fn wait_icer(&self, index: usize) {
+            while !self.get().icer(index) {}
+        }
+        #[doc = "Poll until the bit is set, at most `max_polls` times"]
+        #[inline]
+        pub fn wait_icer_timeout(
+            &self,
+            index: usize,
+            max_polls: u32,
+        ) -> Result<(), ::volatile_cell::wait::Timeout> {
+            ::volatile_cell::wait::poll_until(|| self.get().icer(index), max_polls)
+        }
+        #[doc = "Future resolving once the bit is set"]
+        #[inline]
+        pub fn wait_icer_async<'a>(
+            &'a self,
+            index: usize,
+        ) -> ::volatile_cell::wait::WaitUntil<'a, impl FnMut() -> bool + Unpin + 'a> {
+            ::volatile_cell::wait::WaitUntil::new(move || self.get().icer(index))
+        }
+    }
+    pub struct IcerUpdate<'a> {
+        value: u32,
+        mask: u32,
+        write_only: bool,
+        store_on_drop: bool,
+        reg: &'a Icer,
+    }
+    impl<'a> IcerUpdate<'a> {
+        #[inline(always)]
+        pub fn new(reg: &'a Icer) -> IcerUpdate<'a> {
+            IcerUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[inline(always)]
+        pub fn new_ignoring_state(reg: &'a Icer) -> IcerUpdate<'a> {
+            IcerUpdate {
+                value: 0,
+                mask: 0,
+                write_only: true,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[doc = "An update that is never stored, for the caller to store itself"]
+        #[inline(always)]
+        fn detached(reg: &'a Icer) -> IcerUpdate<'a> {
+            IcerUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: false,
+                reg: reg,
+            }
+        }
+        const fn clear_mask() -> u32 {
+            4294967295u64 as u32
+        }
+        const fn update_offset(base: usize, size: usize, index: usize) -> usize {
+            base + (size * index)
+        }
+        #[inline(always)]
+        fn merge(&self, current: u32) -> u32 {
+            let v: u32 = current & (!Self::clear_mask()) & (!self.mask);
+            self.value | v
+        }
+        #[inline(always)]
+        pub fn clear_icer<'b>(&'b mut self, index: usize) -> &'b mut Self {
+            let shift = (0 + (1 * index));
+            self.value |= 1 << shift;
+            self.mask |= 1 << shift;
+            self
+        }
+    }
+    impl<'a> Drop for IcerUpdate<'a> {
+        #[inline(always)]
+        fn drop(&mut self) {
+            if self.store_on_drop && self.mask != 0 {
+                let current: u32 = if self.write_only { 0 } else { self.reg.load() };
+                self.reg.store(self.merge(current));
+            }
+        }
+    }
+    #[derive(Clone, PartialEq, Eq)]
+    pub struct IcerGet {
+        value: u32,
+    }
+    impl IcerGet {
+        #[doc = "Create a getter reflecting the current value of the register"]
+        #[inline(always)]
+        pub fn new(reg: &Icer) -> IcerGet {
+            IcerGet { value: reg.load() }
+        }
+        #[inline(always)]
+        pub fn icer(&self, index: usize) -> bool {
+            let val = (self.value >> (0 + (1 * index))) & 1;
+            val != 0x0
+        }
+    }
+    #[derive(Clone, Copy, PartialEq, Eq, Default)]
+    #[repr(transparent)]
+    pub struct IcerValue {
+        value: u32,
+    }
+    impl IcerValue {
+        #[doc = "Create a value with every field cleared"]
+        #[inline(always)]
+        pub const fn new() -> IcerValue {
+            IcerValue { value: 0 }
+        }
+        #[inline(always)]
+        pub const fn from_bits(bits: u32) -> IcerValue {
+            IcerValue { value: bits }
+        }
+        #[inline(always)]
+        pub const fn to_bits(&self) -> u32 {
+            self.value
+        }
+        #[inline(always)]
+        pub fn icer(&self, index: usize) -> bool {
+            let val = (self.value >> (0 + (1 * index))) & 1;
+            val != 0x0
+        }
+        #[inline(always)]
+        pub const fn with_icer(self, index: usize, new_value: bool) -> Self {
+            if index >= 32 {
+                panic!();
+            }
+            let shift = 0 + (1 * index);
+            let context_mask: u32 = 1 << shift;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 1) << shift),
+            }
+        }
+    }
+    impl From<IcerGet> for IcerValue {
+        #[inline(always)]
+        fn from(get: IcerGet) -> IcerValue {
+            IcerValue::from_bits(get.value)
+        }
+    }
+    impl From<IcerValue> for IcerGet {
+        #[inline(always)]
+        fn from(value: IcerValue) -> IcerGet {
+            IcerGet {
+                value: value.to_bits(),
+            }
+        }
+    }
+    impl Icer {
+        #[doc = "Write `value` to the register in a single store, without reading it first"]
+        #[inline(always)]
+        pub fn write(&self, value: IcerValue) {
+            self.store(value.to_bits());
+        }
+    }
+    impl<'a> IcerUpdate<'a> {
+        #[doc = "Replace every field of the register with those of `value`"]
+        #[inline(always)]
+        pub fn assign<'b>(&'b mut self, value: IcerValue) -> &'b mut Self {
+            self.value = value.to_bits();
+            self.mask = !0;
+            self
+        }
+    }
+    impl IcerGet {
+        pub const FIELDS: &'static [::volatile_cell::field::FieldDescriptor] =
+            &[::volatile_cell::field::FieldDescriptor {
+                name: "icer",
+                offset: 0u32,
+                width: 1u32,
+                count: 32u32,
+            }];
+        #[doc = "Iterate over the readable fields that differ from those of `other`"]
+        #[inline]
+        pub fn changed_fields(&self, other: &IcerGet) -> ::volatile_cell::field::FieldChanges {
+            ::volatile_cell::field::FieldChanges::new(
+                Self::FIELDS,
+                self.value as u64,
+                other.value as u64,
+            )
+        }
+    }
+    pub mod ispr {}
+    #[repr(C)]
+    pub struct Ispr {
+        value: ::volatile_cell::VolatileCell<u32>,
+    }
+    impl Ispr {
+        #[inline(always)]
+        fn load(&self) -> u32 {
+            let value = self.value.get();
+            value
+        }
+        #[inline(always)]
+        fn store(&self, value: u32) {
+            self.value.set(value);
+        }
+        #[doc = "Create a new updater"]
+        #[inline(always)]
+        pub fn update<'a>(&'a self) -> IsprUpdate<'a> {
+            IsprUpdate::new(self)
+        }
+        #[doc = "Create an updater whose read-modify-write happens inside the critical section `cs`"]
+        #[inline(always)]
+        pub fn update_in<'a, C: ::volatile_cell::sync::CriticalSection>(
+            &'a self,
+            _cs: &'a C,
+        ) -> IsprUpdate<'a> {
+            IsprUpdate::new(self)
+        }
+        #[doc = "Update the register with exclusive loads and stores (LDREX/STREX), retrying `f` until no other access intervened"]
+        #[doc = ""]
+        #[doc = "The exclusive accesses go to the register's memory directly rather than through its cell, so they aren't seen by the replayer or the tracing hook."]
+        #[cfg(target_has_atomic = "32")]
+        #[inline]
+        pub fn update_exclusive<F: FnMut(&mut IsprUpdate)>(&self, mut f: F) {
+            use ::core::sync::atomic::Ordering;
+            let cell = unsafe { &*(self.value.as_ptr() as *const ::core::sync::atomic::AtomicU32) };
+            let mut current = cell.load(Ordering::Relaxed);
+            loop {
+                let mut update = IsprUpdate::detached(self);
+                f(&mut update);
+                let merged = update.merge(current);
+                if update.mask == 0 {
+                    return;
+                }
+                match cell.compare_exchange_weak(
+                    current,
+                    merged,
+                    Ordering::SeqCst,
+                    Ordering::Relaxed,
+                ) {
+                    Ok(_) => return,
+                    Err(actual) => current = actual,
+                }
+            }
+        }
+        #[doc = "Create a getter representing the current state of the register"]
+        #[inline(always)]
+        pub fn get(&self) -> IsprGet {
+            IsprGet::new(self)
+        }
+        #[doc = "Spin until the bit is set"]
+        #[inline]
+        pub fn wait_ispr(&self, index: usize) {
+            while !self.get().ispr(index) {}
+        }
+        #[doc = "Poll until the bit is set, at most `max_polls` times"]
+        #[inline]
+        pub fn wait_ispr_timeout(
+            &self,
+            index: usize,
+            max_polls: u32,
+        ) -> Result<(), ::volatile_cell::wait::Timeout> {
+            ::volatile_cell::wait::poll_until(|| self.get().ispr(index), max_polls)
+        }
+        #[doc = "Future resolving once the bit is set"]
+        #[inline]
+        pub fn wait_ispr_async<'a>(
+            &'a self,
+            index: usize,
+        ) -> ::volatile_cell::wait::WaitUntil<'a, impl FnMut() -> bool + Unpin + 'a> {
+            ::volatile_cell::wait::WaitUntil::new(move || self.get().ispr(index))
+        }
+    }
+    pub struct IsprUpdate<'a> {
+        value: u32,
+        mask: u32,
+        write_only: bool,
+        store_on_drop: bool,
+        reg: &'a Ispr,
+    }
+    impl<'a> IsprUpdate<'a> {
+        #[inline(always)]
+        pub fn new(reg: &'a Ispr) -> IsprUpdate<'a> {
+            IsprUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[inline(always)]
+        pub fn new_ignoring_state(reg: &'a Ispr) -> IsprUpdate<'a> {
+            IsprUpdate {
+                value: 0,
+                mask: 0,
+                write_only: true,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[doc = "An update that is never stored, for the caller to store itself"]
+        #[inline(always)]
+        fn detached(reg: &'a Ispr) -> IsprUpdate<'a> {
+            IsprUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: false,
+                reg: reg,
+            }
+        }
+        const fn clear_mask() -> u32 {
+            4294967295u64 as u32
+        }
+        const fn update_offset(base: usize, size: usize, index: usize) -> usize {
+            base + (size * index)
+        }
+        #[inline(always)]
+        fn merge(&self, current: u32) -> u32 {
+            let v: u32 = current & (!Self::clear_mask()) & (!self.mask);
+            self.value | v
+        }
+        #[inline(always)]
+        pub fn clear_ispr<'b>(&'b mut self, index: usize) -> &'b mut Self {
+            let shift = (0 + (1 * index));
+            self.value |= 1 << shift;
+            self.mask |= 1 << shift;
+            self
+        }
+    }
+    impl<'a> Drop for IsprUpdate<'a> {
+        #[inline(always)]
+        fn drop(&mut self) {
+            if self.store_on_drop && self.mask != 0 {
+                let current: u32 = if self.write_only { 0 } else { self.reg.load() };
+                self.reg.store(self.merge(current));
+            }
+        }
+    }
+    #[derive(Clone, PartialEq, Eq)]
+    pub struct IsprGet {
+        value: u32,
+    }
+    impl IsprGet {
+        #[doc = "Create a getter reflecting the current value of the register"]
+        #[inline(always)]
+        pub fn new(reg: &Ispr) -> IsprGet {
+            IsprGet { value: reg.load() }
+        }
+        #[inline(always)]
+        pub fn ispr(&self, index: usize) -> bool {
+            let val = (self.value >> (0 + (1 * index))) & 1;
+            val != 0x0
+        }
+    }
+    #[derive(Clone, Copy, PartialEq, Eq, Default)]
+    #[repr(transparent)]
+    pub struct IsprValue {
+        value: u32,
+    }
+    impl IsprValue {
+        #[doc = "Create a value with every field cleared"]
+        #[inline(always)]
+        pub const fn new() -> IsprValue {
+            IsprValue { value: 0 }
+        }
+        #[inline(always)]
+        pub const fn from_bits(bits: u32) -> IsprValue {
+            IsprValue { value: bits }
+        }
+        #[inline(always)]
+        pub const fn to_bits(&self) -> u32 {
+            self.value
+        }
+        #[inline(always)]
+        pub fn ispr(&self, index: usize) -> bool {
+            let val = (self.value >> (0 + (1 * index))) & 1;
+            val != 0x0
+        }
+        #[inline(always)]
+        pub const fn with_ispr(self, index: usize, new_value: bool) -> Self {
+            if index >= 32 {
+                panic!();
+            }
+            let shift = 0 + (1 * index);
+            let context_mask: u32 = 1 << shift;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 1) << shift),
+            }
+        }
+    }
+    impl From<IsprGet> for IsprValue {
+        #[inline(always)]
+        fn from(get: IsprGet) -> IsprValue {
+            IsprValue::from_bits(get.value)
+        }
+    }
+    impl From<IsprValue> for IsprGet {
+        #[inline(always)]
+        fn from(value: IsprValue) -> IsprGet {
+            IsprGet {
+                value: value.to_bits(),
+            }
+        }
+    }
+    impl Ispr {
+        #[doc = "Write `value` to the register in a single store, without reading it first"]
+        #[inline(always)]
+        pub fn write(&self, value: IsprValue) {
+            self.store(value.to_bits());
+        }
+    }
+    impl<'a> IsprUpdate<'a> {
+        #[doc = "Replace every field of the register with those of `value`"]
+        #[inline(always)]
+        pub fn assign<'b>(&'b mut self, value: IsprValue) -> &'b mut Self {
+            self.value = value.to_bits();
+            self.mask = !0;
+            self
+        }
+    }
+    impl IsprGet {
+        pub const FIELDS: &'static [::volatile_cell::field::FieldDescriptor] =
+            &[::volatile_cell::field::FieldDescriptor {
+                name: "ispr",
+                offset: 0u32,
+                width: 1u32,
+                count: 32u32,
+            }];
+        #[doc = "Iterate over the readable fields that differ from those of `other`"]
+        #[inline]
+        pub fn changed_fields(&self, other: &IsprGet) -> ::volatile_cell::field::FieldChanges {
+            ::volatile_cell::field::FieldChanges::new(
+                Self::FIELDS,
+                self.value as u64,
+                other.value as u64,
+            )
+        }
+    }
+    pub mod icpr {}
+    #[repr(C)]
+    pub struct Icpr {
+        value: ::volatile_cell::VolatileCell<u32>,
+    }
+    impl Icpr {
+        #[inline(always)]
+        fn load(&self) -> u32 {
+            let value = self.value.get();
+            value
+        }
+        #[inline(always)]
+        fn store(&self, value: u32) {
+            self.value.set(value);
+        }
+        #[doc = "Create a new updater"]
+        #[inline(always)]
+        pub fn update<'a>(&'a self) -> IcprUpdate<'a> {
+            IcprUpdate::new(self)
+        }
+        #[doc = "Create an updater whose read-modify-write happens inside the critical section `cs`"]
+        #[inline(always)]
+        pub fn update_in<'a, C: ::volatile_cell::sync::CriticalSection>(
+            &'a self,
+            _cs: &'a C,
+        ) -> IcprUpdate<'a> {
+            IcprUpdate::new(self)
+        }
+        #[doc = "Update the register with exclusive loads and stores (LDREX/STREX), retrying `f` until no other access intervened"]
+        #[doc = ""]
+        #[doc = "The exclusive accesses go to the register's memory directly rather than through its cell, so they aren't seen by the replayer or the tracing hook."]
+        #[cfg(target_has_atomic = "32")]
+        #[inline]
+        pub fn update_exclusive<F: FnMut(&mut IcprUpdate)>(&self, mut f: F) {
+            use ::core::sync::atomic::Ordering;
+            let cell = unsafe { &*(self.value.as_ptr() as *const ::core::sync::atomic::AtomicU32) };
+            let mut current = cell.load(Ordering::Relaxed);
+            loop {
+                let mut update = IcprUpdate::detached(self);
+                f(&mut update);
+                let merged = update.merge(current);
+                if update.mask == 0 {
+                    return;
+                }
+                match cell.compare_exchange_weak(
+                    current,
+                    merged,
+                    Ordering::SeqCst,
+                    Ordering::Relaxed,
+                ) {
+                    Ok(_) => return,
+                    Err(actual) => current = actual,
+                }
+            }
+        }
+        #[doc = "Create a getter representing the current state of the register"]
+        #[inline(always)]
+        pub fn get(&self) -> IcprGet {
+            IcprGet::new(self)
+        }
+        #[doc = "Spin until the bit is set"]
+        #[inline]
+        pub fn wait_icpr(&self, index: usize) {
+            while !self.get().icpr(index) {}
+        }
+        #[doc = "Poll until the bit is set, at most `max_polls` times"]
+        #[inline]
+        pub fn wait_icpr_timeout(
+            &self,
+            index: usize,
+            max_polls: u32,
+        ) -> Result<(), ::volatile_cell::wait::Timeout> {
+            ::volatile_cell::wait::poll_until(|| self.get().icpr(index), max_polls)
+        }
+        #[doc = "Future resolving once the bit is set"]
+        #[inline]
+        pub fn wait_icpr_async<'a>(
+            &'a self,
+            index: usize,
+        ) -> ::volatile_cell::wait::WaitUntil<'a, impl FnMut() -> bool + Unpin + 'a> {
+            ::volatile_cell::wait::WaitUntil::new(move || self.get().icpr(index))
+        }
+    }
+    pub struct IcprUpdate<'a> {
+        value: u32,
+        mask: u32,
+        write_only: bool,
+        store_on_drop: bool,
+        reg: &'a Icpr,
+    }
+    impl<'a> IcprUpdate<'a> {
+        #[inline(always)]
+        pub fn new(reg: &'a Icpr) -> IcprUpdate<'a> {
+            IcprUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[inline(always)]
+        pub fn new_ignoring_state(reg: &'a Icpr) -> IcprUpdate<'a> {
+            IcprUpdate {
+                value: 0,
+                mask: 0,
+                write_only: true,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[doc = "An update that is never stored, for the caller to store itself"]
+        #[inline(always)]
+        fn detached(reg: &'a Icpr) -> IcprUpdate<'a> {
+            IcprUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: false,
+                reg: reg,
+            }
+        }
+        const fn clear_mask() -> u32 {
+            4294967295u64 as u32
+        }
+        const fn update_offset(base: usize, size: usize, index: usize) -> usize {
+            base + (size * index)
+        }
+        #[inline(always)]
+        fn merge(&self, current: u32) -> u32 {
+            let v: u32 = current & (!Self::clear_mask()) & (!self.mask);
+            self.value | v
+        }
+        #[inline(always)]
+        pub fn clear_icpr<'b>(&'b mut self, index: usize) -> &'b mut Self {
+            let shift = (0 + (1 * index));
+            self.value |= 1 << shift;
+            self.mask |= 1 << shift;
+            self
+        }
+    }
+    impl<'a> Drop for IcprUpdate<'a> {
+        #[inline(always)]
+        fn drop(&mut self) {
+            if self.store_on_drop && self.mask != 0 {
+                let current: u32 = if self.write_only { 0 } else { self.reg.load() };
+                self.reg.store(self.merge(current));
+            }
+        }
+    }
+    #[derive(Clone, PartialEq, Eq)]
+    pub struct IcprGet {
+        value: u32,
+    }
+    impl IcprGet {
+        #[doc = "Create a getter reflecting the current value of the register"]
+        #[inline(always)]
+        pub fn new(reg: &Icpr) -> IcprGet {
+            IcprGet { value: reg.load() }
+        }
+        #[inline(always)]
+        pub fn icpr(&self, index: usize) -> bool {
+            let val = (self.value >> (0 + (1 * index))) & 1;
+            val != 0x0
+        }
+    }
+    #[derive(Clone, Copy, PartialEq, Eq, Default)]
+    #[repr(transparent)]
+    pub struct IcprValue {
+        value: u32,
+    }
+    impl IcprValue {
+        #[doc = "Create a value with every field cleared"]
+        #[inline(always)]
+        pub const fn new() -> IcprValue {
+            IcprValue { value: 0 }
+        }
+        #[inline(always)]
+        pub const fn from_bits(bits: u32) -> IcprValue {
+            IcprValue { value: bits }
+        }
+        #[inline(always)]
+        pub const fn to_bits(&self) -> u32 {
+            self.value
+        }
+        #[inline(always)]
+        pub fn icpr(&self, index: usize) -> bool {
+            let val = (self.value >> (0 + (1 * index))) & 1;
+            val != 0x0
+        }
+        #[inline(always)]
+        pub const fn with_icpr(self, index: usize, new_value: bool) -> Self {
+            if index >= 32 {
+                panic!();
+            }
+            let shift = 0 + (1 * index);
+            let context_mask: u32 = 1 << shift;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 1) << shift),
+            }
+        }
+    }
+    impl From<IcprGet> for IcprValue {
+        #[inline(always)]
+        fn from(get: IcprGet) -> IcprValue {
+            IcprValue::from_bits(get.value)
+        }
+    }
+    impl From<IcprValue> for IcprGet {
+        #[inline(always)]
+        fn from(value: IcprValue) -> IcprGet {
+            IcprGet {
+                value: value.to_bits(),
+            }
+        }
+    }
+    impl Icpr {
+        #[doc = "Write `value` to the register in a single store, without reading it first"]
+        #[inline(always)]
+        pub fn write(&self, value: IcprValue) {
+            self.store(value.to_bits());
+        }
+    }
+    impl<'a> IcprUpdate<'a> {
+        #[doc = "Replace every field of the register with those of `value`"]
+        #[inline(always)]
+        pub fn assign<'b>(&'b mut self, value: IcprValue) -> &'b mut Self {
+            self.value = value.to_bits();
+            self.mask = !0;
+            self
+        }
+    }
+    impl IcprGet {
+        pub const FIELDS: &'static [::volatile_cell::field::FieldDescriptor] =
+            &[::volatile_cell::field::FieldDescriptor {
+                name: "icpr",
+                offset: 0u32,
+                width: 1u32,
+                count: 32u32,
+            }];
+        #[doc = "Iterate over the readable fields that differ from those of `other`"]
+        #[inline]
+        pub fn changed_fields(&self, other: &IcprGet) -> ::volatile_cell::field::FieldChanges {
+            ::volatile_cell::field::FieldChanges::new(
+                Self::FIELDS,
+                self.value as u64,
+                other.value as u64,
+            )
+        }
+    }
+    pub mod iabr {}
+    #[repr(C)]
+    pub struct Iabr {
+        value: ::volatile_cell::VolatileCell<u32>,
+    }
+    impl Iabr {
+        #[inline(always)]
+        fn load(&self) -> u32 {
+            let value = self.value.get();
+            value
+        }
+        #[inline(always)]
+        fn store(&self, value: u32) {
+            self.value.set(value);
+        }
+        #[doc = "Create a new updater"]
+        #[inline(always)]
+        pub fn update<'a>(&'a self) -> IabrUpdate<'a> {
+            IabrUpdate::new(self)
+        }
+        #[doc = "Create an updater whose read-modify-write happens inside the critical section `cs`"]
+        #[inline(always)]
+        pub fn update_in<'a, C: ::volatile_cell::sync::CriticalSection>(
+            &'a self,
+            _cs: &'a C,
+        ) -> IabrUpdate<'a> {
+            IabrUpdate::new(self)
+        }
+        #[doc = "Update the register with exclusive loads and stores (LDREX/STREX), retrying `f` until no other access intervened"]
+        #[doc = ""]
+        #[doc = "The exclusive accesses go to the register's memory directly rather than through its cell, so they aren't seen by the replayer or the tracing hook."]
+        #[cfg(target_has_atomic = "32")]
+        #[inline]
+        pub fn update_exclusive<F: FnMut(&mut IabrUpdate)>(&self, mut f: F) {
+            use ::core::sync::atomic::Ordering;
+            let cell = unsafe { &*(self.value.as_ptr() as *const ::core::sync::atomic::AtomicU32) };
+            let mut current = cell.load(Ordering::Relaxed);
+            loop {
+                let mut update = IabrUpdate::detached(self);
+                f(&mut update);
+                let merged = update.merge(current);
+                if update.mask == 0 {
+                    return;
+                }
+                match cell.compare_exchange_weak(
+                    current,
+                    merged,
+                    Ordering::SeqCst,
+                    Ordering::Relaxed,
+                ) {
+                    Ok(_) => return,
+                    Err(actual) => current = actual,
+                }
+            }
+        }
+        #[doc = "Create a getter representing the current state of the register"]
+        #[inline(always)]
+        pub fn get(&self) -> IabrGet {
+            IabrGet::new(self)
+        }
+        #[doc = "Spin until the bit is set"]
+        #[inline]
+        pub fn wait_iabr(&self, index: usize) {
+            while !self.get().iabr(index) {}
+        }
+        #[doc = "Poll until the bit is set, at most `max_polls` times"]
+        #[inline]
+        pub fn wait_iabr_timeout(
+            &self,
+            index: usize,
+            max_polls: u32,
+        ) -> Result<(), ::volatile_cell::wait::Timeout> {
+            ::volatile_cell::wait::poll_until(|| self.get().iabr(index), max_polls)
+        }
+        #[doc = "Future resolving once the bit is set"]
+        #[inline]
+        pub fn wait_iabr_async<'a>(
+            &'a self,
+            index: usize,
+        ) -> ::volatile_cell::wait::WaitUntil<'a, impl FnMut() -> bool + Unpin + 'a> {
+            ::volatile_cell::wait::WaitUntil::new(move || self.get().iabr(index))
+        }
+    }
+    pub struct IabrUpdate<'a> {
+        value: u32,
+        mask: u32,
+        write_only: bool,
+        store_on_drop: bool,
+        reg: &'a Iabr,
+    }
+    impl<'a> IabrUpdate<'a> {
+        #[inline(always)]
+        pub fn new(reg: &'a Iabr) -> IabrUpdate<'a> {
+            IabrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[inline(always)]
+        pub fn new_ignoring_state(reg: &'a Iabr) -> IabrUpdate<'a> {
+            IabrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: true,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[doc = "An update that is never stored, for the caller to store itself"]
+        #[inline(always)]
+        fn detached(reg: &'a Iabr) -> IabrUpdate<'a> {
+            IabrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: false,
+                reg: reg,
+            }
+        }
+        const fn clear_mask() -> u32 {
+            0u64 as u32
+        }
+        const fn update_offset(base: usize, size: usize, index: usize) -> usize {
+            base + (size * index)
+        }
+        #[inline(always)]
+        fn merge(&self, current: u32) -> u32 {
+            let v: u32 = current & (!Self::clear_mask()) & (!self.mask);
+            self.value | v
+        }
+    }
+    impl<'a> Drop for IabrUpdate<'a> {
+        #[inline(always)]
+        fn drop(&mut self) {
+            if self.store_on_drop && self.mask != 0 {
+                let current: u32 = if self.write_only { 0 } else { self.reg.load() };
+                self.reg.store(self.merge(current));
+            }
+        }
+    }
+    #[derive(Clone, PartialEq, Eq)]
+    pub struct IabrGet {
+        value: u32,
+    }
+    impl IabrGet {
+        #[doc = "Create a getter reflecting the current value of the register"]
+        #[inline(always)]
+        pub fn new(reg: &Iabr) -> IabrGet {
+            IabrGet { value: reg.load() }
+        }
+        #[inline(always)]
+        pub fn iabr(&self, index: usize) -> bool {
+            let val = (self.value >> (0 + (1 * index))) & 1;
+            val != 0x0
+        }
+    }
+    #[derive(Clone, Copy, PartialEq, Eq, Default)]
+    #[repr(transparent)]
+    pub struct IabrValue {
+        value: u32,
+    }
+    impl IabrValue {
+        #[doc = "Create a value with every field cleared"]
+        #[inline(always)]
+        pub const fn new() -> IabrValue {
+            IabrValue { value: 0 }
+        }
+        #[inline(always)]
+        pub const fn from_bits(bits: u32) -> IabrValue {
+            IabrValue { value: bits }
+        }
+        #[inline(always)]
+        pub const fn to_bits(&self) -> u32 {
+            self.value
+        }
+        #[inline(always)]
+        pub fn iabr(&self, index: usize) -> bool {
+            let val = (self.value >> (0 + (1 * index))) & 1;
+            val != 0x0
+        }
+        #[inline(always)]
+        pub const fn with_iabr(self, index: usize, new_value: bool) -> Self {
+            if index >= 32 {
+                panic!();
+            }
+            let shift = 0 + (1 * index);
+            let context_mask: u32 = 1 << shift;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 1) << shift),
+            }
+        }
+    }
+    impl From<IabrGet> for IabrValue {
+        #[inline(always)]
+        fn from(get: IabrGet) -> IabrValue {
+            IabrValue::from_bits(get.value)
+        }
+    }
+    impl From<IabrValue> for IabrGet {
+        #[inline(always)]
+        fn from(value: IabrValue) -> IabrGet {
+            IabrGet {
+                value: value.to_bits(),
+            }
+        }
+    }
+    impl Iabr {
+        #[doc = "Write `value` to the register in a single store, without reading it first"]
+        #[inline(always)]
+        pub fn write(&self, value: IabrValue) {
+            self.store(value.to_bits());
+        }
+    }
+    impl<'a> IabrUpdate<'a> {
+        #[doc = "Replace every field of the register with those of `value`"]
+        #[inline(always)]
+        pub fn assign<'b>(&'b mut self, value: IabrValue) -> &'b mut Self {
+            self.value = value.to_bits();
+            self.mask = !0;
+            self
+        }
+    }
+    impl IabrGet {
+        pub const FIELDS: &'static [::volatile_cell::field::FieldDescriptor] =
+            &[::volatile_cell::field::FieldDescriptor {
+                name: "iabr",
+                offset: 0u32,
+                width: 1u32,
+                count: 32u32,
+            }];
+        #[doc = "Iterate over the readable fields that differ from those of `other`"]
+        #[inline]
+        pub fn changed_fields(&self, other: &IabrGet) -> ::volatile_cell::field::FieldChanges {
+            ::volatile_cell::field::FieldChanges::new(
+                Self::FIELDS,
+                self.value as u64,
+                other.value as u64,
+            )
+        }
+    }
+    pub mod ipr {}
+    #[repr(C)]
+    pub struct Ipr {
+        value: ::volatile_cell::VolatileCell<u32>,
+    }
+    impl Ipr {
+        #[inline(always)]
+        fn load(&self) -> u32 {
+            let value = self.value.get();
+            value
+        }
+        #[inline(always)]
+        fn store(&self, value: u32) {
+            self.value.set(value);
+        }
+        #[doc = "Create a new updater"]
+        #[inline(always)]
+        pub fn update<'a>(&'a self) -> IprUpdate<'a> {
+            IprUpdate::new(self)
+        }
+        #[doc = "Create an updater whose read-modify-write happens inside the critical section `cs`"]
+        #[inline(always)]
+        pub fn update_in<'a, C: ::volatile_cell::sync::CriticalSection>(
+            &'a self,
+            _cs: &'a C,
+        ) -> IprUpdate<'a> {
+            IprUpdate::new(self)
+        }
+        #[doc = "Update the register with exclusive loads and stores (LDREX/STREX), retrying `f` until no other access intervened"]
+        #[doc = ""]
+        #[doc = "The exclusive accesses go to the register's memory directly rather than through its cell, so they aren't seen by the replayer or the tracing hook."]
+        #[cfg(target_has_atomic = "32")]
+        #[inline]
+        pub fn update_exclusive<F: FnMut(&mut IprUpdate)>(&self, mut f: F) {
+            use ::core::sync::atomic::Ordering;
+            let cell = unsafe { &*(self.value.as_ptr() as *const ::core::sync::atomic::AtomicU32) };
+            let mut current = cell.load(Ordering::Relaxed);
+            loop {
+                let mut update = IprUpdate::detached(self);
+                f(&mut update);
+                let merged = update.merge(current);
+                if update.mask == 0 {
+                    return;
+                }
+                match cell.compare_exchange_weak(
+                    current,
+                    merged,
+                    Ordering::SeqCst,
+                    Ordering::Relaxed,
+                ) {
+                    Ok(_) => return,
+                    Err(actual) => current = actual,
+                }
+            }
+        }
+        #[doc = "Create a getter representing the current state of the register"]
+        #[inline(always)]
+        pub fn get(&self) -> IprGet {
+            IprGet::new(self)
+        }
+        #[doc = "The lanes of the register, in address order"]
+        #[inline(always)]
+        pub fn lanes(&self) -> &[::volatile_cell::VolatileCell<u8>; 4usize] {
+            unsafe { &*(self.value.as_ptr() as *const [::volatile_cell::VolatileCell<u8>; 4usize]) }
+        }
+        #[doc = "Lane `index` of the register, counting from the least significant. Its value is in the byte order of the device."]
+        #[inline(always)]
+        pub fn lane(&self, index: usize) -> &::volatile_cell::VolatileCell<u8> {
+            #[cfg(target_endian = "little")]
+            let index = index;
+            #[cfg(target_endian = "big")]
+            let index = 3usize - index;
+            &self.lanes()[index]
+        }
+        #[doc = "Read the field with a single narrow load of its lane"]
+        #[inline(always)]
+        pub fn read_ipr(&self, index: usize) -> u8 {
+            self.lane(0usize + index).get()
+        }
+        #[doc = "Write the field with a single narrow store to its lane, leaving the rest of the register untouched"]
+        #[inline(always)]
+        pub fn write_ipr(&self, index: usize, new_value: u8) {
+            self.lane(0usize + index).set(new_value);
+        }
+    }
+    pub struct IprUpdate<'a> {
+        value: u32,
+        mask: u32,
+        write_only: bool,
+        store_on_drop: bool,
+        reg: &'a Ipr,
+    }
+    impl<'a> IprUpdate<'a> {
+        #[inline(always)]
+        pub fn new(reg: &'a Ipr) -> IprUpdate<'a> {
+            IprUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[inline(always)]
+        pub fn new_ignoring_state(reg: &'a Ipr) -> IprUpdate<'a> {
+            IprUpdate {
+                value: 0,
+                mask: 0,
+                write_only: true,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[doc = "An update that is never stored, for the caller to store itself"]
+        #[inline(always)]
+        fn detached(reg: &'a Ipr) -> IprUpdate<'a> {
+            IprUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: false,
+                reg: reg,
+            }
+        }
+        const fn clear_mask() -> u32 {
+            0u64 as u32
+        }
+        const fn update_offset(base: usize, size: usize, index: usize) -> usize {
+            base + (size * index)
+        }
+        #[inline(always)]
+        fn merge(&self, current: u32) -> u32 {
+            let v: u32 = current & (!Self::clear_mask()) & (!self.mask);
+            self.value | v
+        }
+        #[inline(always)]
+        pub fn set_ipr<'b>(&'b mut self, index: usize, new_value: u8) -> &'b mut Self {
+            if index > 4 {
+                panic!();
+            }
+            let update_offset = Self::update_offset(0, 8, index);
+            let context_mask: u32 = 255 << update_offset;
+            self.value =
+                (self.value & !context_mask) | (((new_value as u32) & 255) << update_offset);
+            self.mask |= context_mask;
+            self
+        }
+    }
+    impl<'a> Drop for IprUpdate<'a> {
+        #[inline(always)]
+        fn drop(&mut self) {
+            if self.store_on_drop && self.mask != 0 {
+                let current: u32 = if self.write_only { 0 } else { self.reg.load() };
+                self.reg.store(self.merge(current));
+            }
+        }
+    }
+    #[derive(Clone, PartialEq, Eq)]
+    pub struct IprGet {
+        value: u32,
+    }
+    impl IprGet {
+        #[doc = "Create a getter reflecting the current value of the register"]
+        #[inline(always)]
+        pub fn new(reg: &Ipr) -> IprGet {
+            IprGet { value: reg.load() }
+        }
+        #[inline(always)]
+        pub fn ipr(&self, index: usize) -> u32 {
+            (self.value >> (0 + (8 * index))) & 255
+        }
+    }
+    #[derive(Clone, Copy, PartialEq, Eq, Default)]
+    #[repr(transparent)]
+    pub struct IprValue {
+        value: u32,
+    }
+    impl IprValue {
+        #[doc = "Create a value with every field cleared"]
+        #[inline(always)]
+        pub const fn new() -> IprValue {
+            IprValue { value: 0 }
+        }
+        #[inline(always)]
+        pub const fn from_bits(bits: u32) -> IprValue {
+            IprValue { value: bits }
+        }
+        #[inline(always)]
+        pub const fn to_bits(&self) -> u32 {
+            self.value
+        }
+        #[inline(always)]
+        pub fn ipr(&self, index: usize) -> u32 {
+            (self.value >> (0 + (8 * index))) & 255
+        }
+        #[inline(always)]
+        pub const fn with_ipr(self, index: usize, new_value: u8) -> Self {
+            if index >= 4 {
+                panic!();
+            }
+            let shift = 0 + (8 * index);
+            let context_mask: u32 = 255 << shift;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 255) << shift),
+            }
+        }
+    }
+    impl From<IprGet> for IprValue {
+        #[inline(always)]
+        fn from(get: IprGet) -> IprValue {
+            IprValue::from_bits(get.value)
+        }
+    }
+    impl From<IprValue> for IprGet {
+        #[inline(always)]
+        fn from(value: IprValue) -> IprGet {
+            IprGet {
+                value: value.to_bits(),
+            }
+        }
+    }
+    impl Ipr {
+        #[doc = "Write `value` to the register in a single store, without reading it first"]
+        #[inline(always)]
+        pub fn write(&self, value: IprValue) {
+            self.store(value.to_bits());
+        }
+    }
+    impl<'a> IprUpdate<'a> {
+        #[doc = "Replace every field of the register with those of `value`"]
+        #[inline(always)]
+        pub fn assign<'b>(&'b mut self, value: IprValue) -> &'b mut Self {
+            self.value = value.to_bits();
+            self.mask = !0;
+            self
+        }
+    }
+    impl IprGet {
+        pub const FIELDS: &'static [::volatile_cell::field::FieldDescriptor] =
+            &[::volatile_cell::field::FieldDescriptor {
+                name: "ipr",
+                offset: 0u32,
+                width: 8u32,
+                count: 4u32,
+            }];
+        #[doc = "Iterate over the readable fields that differ from those of `other`"]
+        #[inline]
+        pub fn changed_fields(&self, other: &IprGet) -> ::volatile_cell::field::FieldChanges {
+            ::volatile_cell::field::FieldChanges::new(
+                Self::FIELDS,
+                self.value as u64,
+                other.value as u64,
+            )
+        }
+    }
+    pub mod stir {}
+    #[repr(C)]
+    pub struct Stir {
+        value: ::volatile_cell::VolatileCell<u32>,
+    }
+    impl Stir {
+        #[inline(always)]
+        fn load(&self) -> u32 {
+            let value = self.value.get();
+            value
+        }
+        #[inline(always)]
+        fn store(&self, value: u32) {
+            self.value.set(value);
+        }
+        #[doc = "Create a new updater"]
+        #[inline(always)]
+        pub fn update<'a>(&'a self) -> StirUpdate<'a> {
+            StirUpdate::new(self)
+        }
+        #[doc = "Create an updater whose read-modify-write happens inside the critical section `cs`"]
+        #[inline(always)]
+        pub fn update_in<'a, C: ::volatile_cell::sync::CriticalSection>(
+            &'a self,
+            _cs: &'a C,
+        ) -> StirUpdate<'a> {
+            StirUpdate::new(self)
+        }
+        #[doc = "Update the register with exclusive loads and stores (LDREX/STREX), retrying `f` until no other access intervened"]
+        #[doc = ""]
+        #[doc = "The exclusive accesses go to the register's memory directly rather than through its cell, so they aren't seen by the replayer or the tracing hook."]
+        #[cfg(target_has_atomic = "32")]
+        #[inline]
+        pub fn update_exclusive<F: FnMut(&mut StirUpdate)>(&self, mut f: F) {
+            use ::core::sync::atomic::Ordering;
+            let cell = unsafe { &*(self.value.as_ptr() as *const ::core::sync::atomic::AtomicU32) };
+            let mut current = cell.load(Ordering::Relaxed);
+            loop {
+                let mut update = StirUpdate::detached(self);
+                f(&mut update);
+                let merged = update.merge(current);
+                if update.mask == 0 {
+                    return;
+                }
+                match cell.compare_exchange_weak(
+                    current,
+                    merged,
+                    Ordering::SeqCst,
+                    Ordering::Relaxed,
+                ) {
+                    Ok(_) => return,
+                    Err(actual) => current = actual,
+                }
+            }
+        }
+        #[doc = "Create a getter representing the current state of the register"]
+        #[inline(always)]
+        pub fn get(&self) -> StirGet {
+            StirGet::new(self)
+        }
+    }
+    pub struct StirUpdate<'a> {
+        value: u32,
+        mask: u32,
+        write_only: bool,
+        store_on_drop: bool,
+        reg: &'a Stir,
+    }
+    impl<'a> StirUpdate<'a> {
+        #[inline(always)]
+        pub fn new(reg: &'a Stir) -> StirUpdate<'a> {
+            StirUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[inline(always)]
+        pub fn new_ignoring_state(reg: &'a Stir) -> StirUpdate<'a> {
+            StirUpdate {
+                value: 0,
+                mask: 0,
+                write_only: true,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[doc = "An update that is never stored, for the caller to store itself"]
+        #[inline(always)]
+        fn detached(reg: &'a Stir) -> StirUpdate<'a> {
+            StirUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: false,
+                reg: reg,
+            }
+        }
+        const fn clear_mask() -> u32 {
+            0u64 as u32
+        }
+        const fn update_offset(base: usize, size: usize, index: usize) -> usize {
+            base + (size * index)
+        }
+        #[inline(always)]
+        fn merge(&self, current: u32) -> u32 {
+            let v: u32 = current & (!Self::clear_mask()) & (!self.mask);
+            self.value | v
+        }
+        #[inline(always)]
+        pub fn set_stir<'b>(&'b mut self, new_value: u16) -> &'b mut Self {
+            let context_mask: u32 = 511 << 0;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 511) << 0);
+            self.mask |= context_mask;
+            self
+        }
+    }
+    impl<'a> Drop for StirUpdate<'a> {
+        #[inline(always)]
+        fn drop(&mut self) {
+            if self.store_on_drop && self.mask != 0 {
+                let current: u32 = if self.write_only { 0 } else { self.reg.load() };
+                self.reg.store(self.merge(current));
+            }
+        }
+    }
+    #[derive(Clone, PartialEq, Eq)]
+    pub struct StirGet {
+        value: u32,
+    }
+    impl StirGet {
+        #[doc = "Create a getter reflecting the current value of the register"]
+        #[inline(always)]
+        pub fn new(reg: &Stir) -> StirGet {
+            StirGet { value: reg.load() }
+        }
+        #[inline(always)]
+        pub fn stir(&self) -> u32 {
+            (self.value & 511)
+        }
+    }
+    #[derive(Clone, Copy, PartialEq, Eq, Default)]
+    #[repr(transparent)]
+    pub struct StirValue {
+        value: u32,
+    }
+    impl StirValue {
+        #[doc = "Create a value with every field cleared"]
+        #[inline(always)]
+        pub const fn new() -> StirValue {
+            StirValue { value: 0 }
+        }
+        #[inline(always)]
+        pub const fn from_bits(bits: u32) -> StirValue {
+            StirValue { value: bits }
+        }
+        #[inline(always)]
+        pub const fn to_bits(&self) -> u32 {
+            self.value
+        }
+        #[inline(always)]
+        pub fn stir(&self) -> u32 {
+            (self.value & 511)
+        }
+        #[inline(always)]
+        pub const fn with_stir(self, new_value: u16) -> Self {
+            let context_mask: u32 = 511 << 0;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 511) << 0),
+            }
+        }
+    }
+    impl From<StirGet> for StirValue {
+        #[inline(always)]
+        fn from(get: StirGet) -> StirValue {
+            StirValue::from_bits(get.value)
+        }
+    }
+    impl From<StirValue> for StirGet {
+        #[inline(always)]
+        fn from(value: StirValue) -> StirGet {
+            StirGet {
+                value: value.to_bits(),
+            }
+        }
+    }
+    impl Stir {
+        #[doc = "Write `value` to the register in a single store, without reading it first"]
+        #[inline(always)]
+        pub fn write(&self, value: StirValue) {
+            self.store(value.to_bits());
+        }
+    }
+    impl<'a> StirUpdate<'a> {
+        #[doc = "Replace every field of the register with those of `value`"]
+        #[inline(always)]
+        pub fn assign<'b>(&'b mut self, value: StirValue) -> &'b mut Self {
+            self.value = value.to_bits();
+            self.mask = !0;
+            self
+        }
+    }
+    impl StirGet {
+        pub const FIELDS: &'static [::volatile_cell::field::FieldDescriptor] =
+            &[::volatile_cell::field::FieldDescriptor {
+                name: "stir",
+                offset: 0u32,
+                width: 9u32,
+                count: 1u32,
+            }];
+        #[doc = "Iterate over the readable fields that differ from those of `other`"]
+        #[inline]
+        pub fn changed_fields(&self, other: &StirGet) -> ::volatile_cell::field::FieldChanges {
+            ::volatile_cell::field::FieldChanges::new(
+                Self::FIELDS,
+                self.value as u64,
+                other.value as u64,
+            )
+        }
+    }
+    #[repr(C)]
+    pub struct Nvic {
+        _padding0: [u8; 256],
+        pub iser: [Iser; 8],
+        _padding1: [u8; 96],
+        pub icer: [Icer; 8],
+        _padding2: [u8; 96],
+        pub ispr: [Ispr; 8],
+        _padding3: [u8; 96],
+        pub icpr: [Icpr; 8],
+        _padding4: [u8; 96],
+        pub iabr: [Iabr; 8],
+        _padding5: [u8; 224],
+        pub ipr: [Ipr; 8],
+        _padding6: [u8; 2784],
+        pub stir: [Stir; 8],
+    }
+    #[doc = "Saved state of every readable register without side effects"]
+    #[derive(Clone, Copy)]
+    pub struct Snapshot {
+        pub iabr: [IabrValue; 8],
+        pub ipr: [IprValue; 8],
+        pub stir: [StirValue; 8],
+    }
+    #[doc = "Read the current state of the peripheral"]
+    #[allow(unused_variables)]
+    pub fn capture(p: &Nvic) -> Snapshot {
+        Snapshot {
+            iabr: {
+                let mut values = [IabrValue::new(); 8];
+                for i in 0..8 {
+                    values[i] = IabrValue::from(p.iabr[i].get());
+                }
+                values
+            },
+            ipr: {
+                let mut values = [IprValue::new(); 8];
+                for i in 0..8 {
+                    values[i] = IprValue::from(p.ipr[i].get());
+                }
+                values
+            },
+            stir: {
+                let mut values = [StirValue::new(); 8];
+                for i in 0..8 {
+                    values[i] = StirValue::from(p.stir[i].get());
+                }
+                values
+            },
+        }
+    }
+    #[doc = "Write a previously captured state back to the peripheral"]
+    #[allow(unused_variables)]
+    pub fn restore(p: &Nvic, snapshot: &Snapshot) {
+        for i in 0..8 {
+            IprUpdate::new_ignoring_state(&p.ipr[i]).assign(snapshot.ipr[i]);
+        }
+        for i in 0..8 {
+            StirUpdate::new_ignoring_state(&p.stir[i]).assign(snapshot.stir[i]);
+        }
+    }
+    #[doc = "Owned handle granting exclusive access to the peripheral"]
+    pub struct NvicPeripheral {
+        regs: &'static mut Nvic,
+    }
+    impl NvicPeripheral {
+        #[doc = "Create a token for the peripheral registers at `regs`."]
+        #[doc = ""]
+        #[doc = "Unsafe because the caller must ensure it's the only token for `regs`."]
+        #[inline(always)]
+        pub const unsafe fn new(regs: &'static mut Nvic) -> NvicPeripheral {
+            NvicPeripheral { regs: regs }
+        }
+    }
+    impl ::core::ops::Deref for NvicPeripheral {
+        type Target = Nvic;
+        #[inline(always)]
+        fn deref(&self) -> &Nvic {
+            self.regs
+        }
+    }
+    impl ::core::ops::DerefMut for NvicPeripheral {
+        #[inline(always)]
+        fn deref_mut(&mut self) -> &mut Nvic {
+            self.regs
+        }
+    }
+}
@@ -0,0 +1,5700 @@
+pub mod scb {
+    pub mod cpuid {}
+    #[repr(C)]
+    pub struct Cpuid {
+        value: ::volatile_cell::VolatileCell<u32>,
+    }
+    impl Cpuid {
+        #[inline(always)]
+        fn load(&self) -> u32 {
+            let value = self.value.get();
+            value
+        }
+        #[inline(always)]
+        fn store(&self, value: u32) {
+            self.value.set(value);
+        }
+        #[doc = "Create a new updater"]
+        #[inline(always)]
+        pub fn update<'a>(&'a self) -> CpuidUpdate<'a> {
+            CpuidUpdate::new(self)
+        }
+        #[doc = "Create an updater whose read-modify-write happens inside the critical section `cs`"]
+        #[inline(always)]
+        pub fn update_in<'a, C: ::volatile_cell::sync::CriticalSection>(
+            &'a self,
+            _cs: &'a C,
+        ) -> CpuidUpdate<'a> {
+            CpuidUpdate::new(self)
+        }
+        #[doc = "Update the register with exclusive loads and stores (LDREX/STREX), retrying `f` until no other access intervened"]
+        #[doc = ""]
+        #[doc = "The exclusive accesses go to the register's memory directly rather than through its cell, so they aren't seen by the replayer or the tracing hook."]
+        #[cfg(target_has_atomic = "32")]
+        #[inline]
+        pub fn update_exclusive<F: FnMut(&mut CpuidUpdate)>(&self, mut f: F) {
+            use ::core::sync::atomic::Ordering;
+            let cell = unsafe { &*(self.value.as_ptr() as *const ::core::sync::atomic::AtomicU32) };
+            let mut current = cell.load(Ordering::Relaxed);
+            loop {
+                let mut update = CpuidUpdate::detached(self);
+                f(&mut update);
+                let merged = update.merge(current);
+                if update.mask == 0 {
+                    return;
+                }
+                match cell.compare_exchange_weak(
+                    current,
+                    merged,
+                    Ordering::SeqCst,
+                    Ordering::Relaxed,
+                ) {
+                    Ok(_) => return,
+                    Err(actual) => current = actual,
+                }
+            }
+        }
+        #[doc = "Create a getter representing the current state of the register"]
+        #[inline(always)]
+        pub fn get(&self) -> CpuidGet {
+            CpuidGet::new(self)
+        }
+    }
+    pub struct CpuidUpdate<'a> {
+        value: u32,
+        mask: u32,
+        write_only: bool,
+        store_on_drop: bool,
+        reg: &'a Cpuid,
+    }
+    impl<'a> CpuidUpdate<'a> {
+        #[inline(always)]
+        pub fn new(reg: &'a Cpuid) -> CpuidUpdate<'a> {
+            CpuidUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[inline(always)]
+        pub fn new_ignoring_state(reg: &'a Cpuid) -> CpuidUpdate<'a> {
+            CpuidUpdate {
+                value: 0,
+                mask: 0,
+                write_only: true,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[doc = "An update that is never stored, for the caller to store itself"]
+        #[inline(always)]
+        fn detached(reg: &'a Cpuid) -> CpuidUpdate<'a> {
+            CpuidUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: false,
+                reg: reg,
+            }
+        }
+        const fn clear_mask() -> u32 {
+            0u64 as u32
+        }
+        const fn update_offset(base: usize, size: usize, index: usize) -> usize {
+            base + (size * index)
+        }
+        #[inline(always)]
+        fn merge(&self, current: u32) -> u32 {
+            let v: u32 = current & (!Self::clear_mask()) & (!self.mask);
+            self.value | v
+        }
+        #[inline(always)]
+        pub fn set_revision<'b>(&'b mut self, new_value: u8) -> &'b mut Self {
+            let context_mask: u32 = 15 << 0;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 15) << 0);
+            self.mask |= context_mask;
+            self
+        }
+        #[inline(always)]
+        pub fn set_partno<'b>(&'b mut self, new_value: u16) -> &'b mut Self {
+            let context_mask: u32 = 4095 << 4;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 4095) << 4);
+            self.mask |= context_mask;
+            self
+        }
+        #[inline(always)]
+        pub fn set_variant<'b>(&'b mut self, new_value: u8) -> &'b mut Self {
+            let context_mask: u32 = 15 << 20;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 15) << 20);
+            self.mask |= context_mask;
+            self
+        }
+        #[inline(always)]
+        pub fn set_implementer<'b>(&'b mut self, new_value: u8) -> &'b mut Self {
+            let context_mask: u32 = 255 << 24;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 255) << 24);
+            self.mask |= context_mask;
+            self
+        }
+    }
+    impl<'a> Drop for CpuidUpdate<'a> {
+        #[inline(always)]
+        fn drop(&mut self) {
+            if self.store_on_drop && self.mask != 0 {
+                let current: u32 = if self.write_only { 0 } else { self.reg.load() };
+                self.reg.store(self.merge(current));
+            }
+        }
+    }
+    #[derive(Clone, PartialEq, Eq)]
+    pub struct CpuidGet {
+        value: u32,
+    }
+    impl CpuidGet {
+        #[doc = "Create a getter reflecting the current value of the register"]
+        #[inline(always)]
+        pub fn new(reg: &Cpuid) -> CpuidGet {
+            CpuidGet { value: reg.load() }
+        }
+        #[inline(always)]
+        pub fn revision(&self) -> u32 {
+            (self.value & 15)
+        }
+        #[inline(always)]
+        pub fn partno(&self) -> u32 {
+            ((self.value >> 4) & 4095)
+        }
+        #[inline(always)]
+        pub fn variant(&self) -> u32 {
+            ((self.value >> 20) & 15)
+        }
+        #[inline(always)]
+        pub fn implementer(&self) -> u32 {
+            (self.value >> 24)
+        }
+    }
+    #[derive(Clone, Copy, PartialEq, Eq, Default)]
+    #[repr(transparent)]
+    pub struct CpuidValue {
+        value: u32,
+    }
+    impl CpuidValue {
+        #[doc = "Create a value with every field cleared"]
+        #[inline(always)]
+        pub const fn new() -> CpuidValue {
+            CpuidValue { value: 0 }
+        }
+        #[inline(always)]
+        pub const fn from_bits(bits: u32) -> CpuidValue {
+            CpuidValue { value: bits }
+        }
+        #[inline(always)]
+        pub const fn to_bits(&self) -> u32 {
+            self.value
+        }
+        #[inline(always)]
+        pub fn revision(&self) -> u32 {
+            (self.value & 15)
+        }
+        #[inline(always)]
+        pub fn partno(&self) -> u32 {
+            ((self.value >> 4) & 4095)
+        }
+        #[inline(always)]
+        pub fn variant(&self) -> u32 {
+            ((self.value >> 20) & 15)
+        }
+        #[inline(always)]
+        pub fn implementer(&self) -> u32 {
+            (self.value >> 24)
+        }
+        #[inline(always)]
+        pub const fn with_revision(self, new_value: u8) -> Self {
+            let context_mask: u32 = 15 << 0;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 15) << 0),
+            }
+        }
+        #[inline(always)]
+        pub const fn with_partno(self, new_value: u16) -> Self {
+            let context_mask: u32 = 4095 << 4;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 4095) << 4),
+            }
+        }
+        #[inline(always)]
+        pub const fn with_variant(self, new_value: u8) -> Self {
+            let context_mask: u32 = 15 << 20;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 15) << 20),
+            }
+        }
+        #[inline(always)]
+        pub const fn with_implementer(self, new_value: u8) -> Self {
+            let context_mask: u32 = 255 << 24;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 255) << 24),
+            }
+        }
+    }
+    impl From<CpuidGet> for CpuidValue {
+        #[inline(always)]
+        fn from(get: CpuidGet) -> CpuidValue {
+            CpuidValue::from_bits(get.value)
+        }
+    }
+    impl From<CpuidValue> for CpuidGet {
+        #[inline(always)]
+        fn from(value: CpuidValue) -> CpuidGet {
+            CpuidGet {
+                value: value.to_bits(),
+            }
+        }
+    }
+    impl Cpuid {
+        #[doc = "Write `value` to the register in a single store, without reading it first"]
+        #[inline(always)]
+        pub fn write(&self, value: CpuidValue) {
+            self.store(value.to_bits());
+        }
+    }
+    impl<'a> CpuidUpdate<'a> {
+        #[doc = "Replace every field of the register with those of `value`"]
+        #[inline(always)]
+        pub fn assign<'b>(&'b mut self, value: CpuidValue) -> &'b mut Self {
+            self.value = value.to_bits();
+            self.mask = !0;
+            self
+        }
+    }
+    impl CpuidGet {
+        pub const FIELDS: &'static [::volatile_cell::field::FieldDescriptor] = &[
+            ::volatile_cell::field::FieldDescriptor {
+                name: "revision",
+                offset: 0u32,
+                width: 4u32,
+                count: 1u32,
+            },
+            ::volatile_cell::field::FieldDescriptor {
+                name: "partno",
+                offset: 4u32,
+                width: 12u32,
+                count: 1u32,
+            },
+            ::volatile_cell::field::FieldDescriptor {
+                name: "variant",
+                offset: 20u32,
+                width: 4u32,
+                count: 1u32,
+            },
+            ::volatile_cell::field::FieldDescriptor {
+                name: "implementer",
+                offset: 24u32,
+                width: 8u32,
+                count: 1u32,
+            },
+        ];
+        #[doc = "Iterate over the readable fields that differ from those of `other`"]
+        #[inline]
+        pub fn changed_fields(&self, other: &CpuidGet) -> ::volatile_cell::field::FieldChanges {
+            ::volatile_cell::field::FieldChanges::new(
+                Self::FIELDS,
+                self.value as u64,
+                other.value as u64,
+            )
+        }
+    }
+    pub mod icsr {}
+    #[repr(C)]
+    pub struct Icsr {
+        value: ::volatile_cell::VolatileCell<u32>,
+    }
+    impl Icsr {
+        #[inline(always)]
+        fn load(&self) -> u32 {
+            let value = self.value.get();
+            value
+        }
+        #[inline(always)]
+        fn store(&self, value: u32) {
+            self.value.set(value);
+        }
+        #[doc = "Create a new updater"]
+        #[inline(always)]
+        pub fn update<'a>(&'a self) -> IcsrUpdate<'a> {
+            IcsrUpdate::new(self)
+        }
+        #[doc = "Create an updater whose read-modify-write happens inside the critical section `cs`"]
+        #[inline(always)]
+        pub fn update_in<'a, C: ::volatile_cell::sync::CriticalSection>(
+            &'a self,
+            _cs: &'a C,
+        ) -> IcsrUpdate<'a> {
+            IcsrUpdate::new(self)
+        }
+        #[doc = "Update the register with exclusive loads and stores (LDREX/STREX), retrying `f` until no other access intervened"]
+        #[doc = ""]
+        #[doc = "The exclusive accesses go to the register's memory directly rather than through its cell, so they aren't seen by the replayer or the tracing hook."]
+        #[cfg(target_has_atomic = "32")]
+        #[inline]
+        pub fn update_exclusive<F: FnMut(&mut IcsrUpdate)>(&self, mut f: F) {
+            use ::core::sync::atomic::Ordering;
+            let cell = unsafe { &*(self.value.as_ptr() as *const ::core::sync::atomic::AtomicU32) };
+            let mut current = cell.load(Ordering::Relaxed);
+            loop {
+                let mut update = IcsrUpdate::detached(self);
+                f(&mut update);
+                let merged = update.merge(current);
+                if update.mask == 0 {
+                    return;
+                }
+                match cell.compare_exchange_weak(
+                    current,
+                    merged,
+                    Ordering::SeqCst,
+                    Ordering::Relaxed,
+                ) {
+                    Ok(_) => return,
+                    Err(actual) => current = actual,
+                }
+            }
+        }
+        #[doc = "Create a getter representing the current state of the register"]
+        #[inline(always)]
+        pub fn get(&self) -> IcsrGet {
+            IcsrGet::new(self)
+        }
+        #[doc = "Spin until the bit is set"]
+        #[inline]
+        pub fn wait_rettobase(&self) {
+            while !self.get().rettobase() {}
+        }
+        #[doc = "Poll until the bit is set, at most `max_polls` times"]
+        #[inline]
+        pub fn wait_rettobase_timeout(
+            &self,
+            max_polls: u32,
+        ) -> Result<(), ::volatile_cell::wait::Timeout> {
+            ::volatile_cell::wait::poll_until(|| self.get().rettobase(), max_polls)
+        }
+        #[doc = "Future resolving once the bit is set"]
+        #[inline]
+        pub fn wait_rettobase_async<'a>(
+            &'a self,
+        ) -> ::volatile_cell::wait::WaitUntil<'a, impl FnMut() -> bool + Unpin + 'a> {
+            ::volatile_cell::wait::WaitUntil::new(move || self.get().rettobase())
+        }
+        #[doc = "Spin until the bit is set"]
+        #[inline]
+        pub fn wait_isrpending(&self) {
+            while !self.get().isrpending() {}
+        }
+        #[doc = "Poll until the bit is set, at most `max_polls` times"]
+        #[inline]
+        pub fn wait_isrpending_timeout(
+            &self,
+            max_polls: u32,
+        ) -> Result<(), ::volatile_cell::wait::Timeout> {
+            ::volatile_cell::wait::poll_until(|| self.get().isrpending(), max_polls)
+        }
+        #[doc = "Future resolving once the bit is set"]
+        #[inline]
+        pub fn wait_isrpending_async<'a>(
+            &'a self,
+        ) -> ::volatile_cell::wait::WaitUntil<'a, impl FnMut() -> bool + Unpin + 'a> {
+            ::volatile_cell::wait::WaitUntil::new(move || self.get().isrpending())
+        }
+        #[doc = "Spin until the bit is set"]
+        #[inline]
+        pub fn wait_isrprempt(&self) {
+            while !self.get().isrprempt() {}
+        }
+        #[doc = "Poll until the bit is set, at most `max_polls` times"]
+        #[inline]
+        pub fn wait_isrprempt_timeout(
+            &self,
+            max_polls: u32,
+        ) -> Result<(), ::volatile_cell::wait::Timeout> {
+            ::volatile_cell::wait::poll_until(|| self.get().isrprempt(), max_polls)
+        }
+        #[doc = "Future resolving once the bit is set"]
+        #[inline]
+        pub fn wait_isrprempt_async<'a>(
+            &'a self,
+        ) -> ::volatile_cell::wait::WaitUntil<'a, impl FnMut() -> bool + Unpin + 'a> {
+            ::volatile_cell::wait::WaitUntil::new(move || self.get().isrprempt())
+        }
+        #[doc = "Spin until the bit is set"]
+        #[inline]
+        pub fn wait_pendstclr(&self) {
+            while !self.get().pendstclr() {}
+        }
+        #[doc = "Poll until the bit is set, at most `max_polls` times"]
+        #[inline]
+        pub fn wait_pendstclr_timeout(
+            &self,
+            max_polls: u32,
+        ) -> Result<(), ::volatile_cell::wait::Timeout> {
+            ::volatile_cell::wait::poll_until(|| self.get().pendstclr(), max_polls)
+        }
+        #[doc = "Future resolving once the bit is set"]
+        #[inline]
+        pub fn wait_pendstclr_async<'a>(
+            &'a self,
+        ) -> ::volatile_cell::wait::WaitUntil<'a, impl FnMut() -> bool + Unpin + 'a> {
+            ::volatile_cell::wait::WaitUntil::new(move || self.get().pendstclr())
+        }
+        #[doc = "Spin until the bit is set"]
+        #[inline]
+        pub fn wait_pendstset(&self) {
+            while !self.get().pendstset() {}
+        }
+        #[doc = "Poll until the bit is set, at most `max_polls` times"]
+        #[inline]
+        pub fn wait_pendstset_timeout(
+            &self,
+            max_polls: u32,
+        ) -> Result<(), ::volatile_cell::wait::Timeout> {
+            ::volatile_cell::wait::poll_until(|| self.get().pendstset(), max_polls)
+        }
+        #[doc = "Future resolving once the bit is set"]
+        #[inline]
+        pub fn wait_pendstset_async<'a>(
+            &'a self,
+        ) -> ::volatile_cell::wait::WaitUntil<'a, impl FnMut() -> bool + Unpin + 'a> {
+            ::volatile_cell::wait::WaitUntil::new(move || self.get().pendstset())
+        }
+        #[doc = "Spin until the bit is set"]
+        #[inline]
+        pub fn wait_pendsvclr(&self) {
+            while !self.get().pendsvclr() {}
+        }
+        #[doc = "Poll until the bit is set, at most `max_polls` times"]
+        #[inline]
+        pub fn wait_pendsvclr_timeout(
+            &self,
+            max_polls: u32,
+        ) -> Result<(), ::volatile_cell::wait::Timeout> {
+            ::volatile_cell::wait::poll_until(|| self.get().pendsvclr(), max_polls)
+        }
+        #[doc = "Future resolving once the bit is set"]
+        #[inline]
+        pub fn wait_pendsvclr_async<'a>(
+            &'a self,
+        ) -> ::volatile_cell::wait::WaitUntil<'a, impl FnMut() -> bool + Unpin + 'a> {
+            ::volatile_cell::wait::WaitUntil::new(move || self.get().pendsvclr())
+        }
+        #[doc = "Spin until the bit is set"]
+        #[inline]
+        pub fn wait_pendsvset(&self) {
+            while !self.get().pendsvset() {}
+        }
+        #[doc = "Poll until the bit is set, at most `max_polls` times"]
+        #[inline]
+        pub fn wait_pendsvset_timeout(
+            &self,
+            max_polls: u32,
+        ) -> Result<(), ::volatile_cell::wait::Timeout> {
+            ::volatile_cell::wait::poll_until(|| self.get().pendsvset(), max_polls)
+        }
+        #[doc = "Future resolving once the bit is set"]
+        #[inline]
+        pub fn wait_pendsvset_async<'a>(
+            &'a self,
+        ) -> ::volatile_cell::wait::WaitUntil<'a, impl FnMut() -> bool + Unpin + 'a> {
+            ::volatile_cell::wait::WaitUntil::new(move || self.get().pendsvset())
+        }
+        #[doc = "Spin until the bit is set"]
+        #[inline]
+        pub fn wait_nmipendset(&self) {
+            while !self.get().nmipendset() {}
+        }
+        #[doc = "Poll until the bit is set, at most `max_polls` times"]
+        #[inline]
+        pub fn wait_nmipendset_timeout(
+            &self,
+            max_polls: u32,
+        ) -> Result<(), ::volatile_cell::wait::Timeout> {
+            ::volatile_cell::wait::poll_until(|| self.get().nmipendset(), max_polls)
+        }
+        #[doc = "Future resolving once the bit is set"]
+        #[inline]
+        pub fn wait_nmipendset_async<'a>(
+            &'a self,
+        ) -> ::volatile_cell::wait::WaitUntil<'a, impl FnMut() -> bool + Unpin + 'a> {
+            ::volatile_cell::wait::WaitUntil::new(move || self.get().nmipendset())
+        }
+    }
+    pub struct IcsrUpdate<'a> {
+        value: u32,
+        mask: u32,
+        write_only: bool,
+        store_on_drop: bool,
+        reg: &'a Icsr,
+    }
+    impl<'a> IcsrUpdate<'a> {
+        #[inline(always)]
+        pub fn new(reg: &'a Icsr) -> IcsrUpdate<'a> {
+            IcsrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[inline(always)]
+        pub fn new_ignoring_state(reg: &'a Icsr) -> IcsrUpdate<'a> {
+            IcsrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: true,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[doc = "An update that is never stored, for the caller to store itself"]
+        #[inline(always)]
+        fn detached(reg: &'a Icsr) -> IcsrUpdate<'a> {
+            IcsrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: false,
+                reg: reg,
+            }
+        }
+        const fn clear_mask() -> u32 {
+            0u64 as u32
+        }
+        const fn update_offset(base: usize, size: usize, index: usize) -> usize {
+            base + (size * index)
+        }
+        #[inline(always)]
+        fn merge(&self, current: u32) -> u32 {
+            let v: u32 = current & (!Self::clear_mask()) & (!self.mask);
+            self.value | v
+        }
+        #[inline(always)]
+        pub fn set_vectactive<'b>(&'b mut self, new_value: u16) -> &'b mut Self {
+            let context_mask: u32 = 511 << 0;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 511) << 0);
+            self.mask |= context_mask;
+            self
+        }
+        #[inline(always)]
+        pub fn set_rettobase<'b>(&'b mut self, new_value: bool) -> &'b mut Self {
+            let context_mask: u32 = 1 << 11;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 1) << 11);
+            self.mask |= context_mask;
+            self
+        }
+        #[inline(always)]
+        pub fn set_vectpending<'b>(&'b mut self, new_value: u16) -> &'b mut Self {
+            let context_mask: u32 = 511 << 12;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 511) << 12);
+            self.mask |= context_mask;
+            self
+        }
+        #[inline(always)]
+        pub fn set_isrpending<'b>(&'b mut self, new_value: bool) -> &'b mut Self {
+            let context_mask: u32 = 1 << 22;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 1) << 22);
+            self.mask |= context_mask;
+            self
+        }
+        #[inline(always)]
+        pub fn set_isrprempt<'b>(&'b mut self, new_value: bool) -> &'b mut Self {
+            let context_mask: u32 = 1 << 23;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 1) << 23);
+            self.mask |= context_mask;
+            self
+        }
+        #[inline(always)]
+        pub fn set_pendstclr<'b>(&'b mut self, new_value: bool) -> &'b mut Self {
+            let context_mask: u32 = 1 << 25;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 1) << 25);
+            self.mask |= context_mask;
+            self
+        }
+        #[inline(always)]
+        pub fn set_pendstset<'b>(&'b mut self, new_value: bool) -> &'b mut Self {
+            let context_mask: u32 = 1 << 26;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 1) << 26);
+            self.mask |= context_mask;
+            self
+        }
+        #[inline(always)]
+        pub fn set_pendsvclr<'b>(&'b mut self, new_value: bool) -> &'b mut Self {
+            let context_mask: u32 = 1 << 27;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 1) << 27);
+            self.mask |= context_mask;
+            self
+        }
+        #[inline(always)]
+        pub fn set_pendsvset<'b>(&'b mut self, new_value: bool) -> &'b mut Self {
+            let context_mask: u32 = 1 << 28;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 1) << 28);
+            self.mask |= context_mask;
+            self
+        }
+        #[inline(always)]
+        pub fn set_nmipendset<'b>(&'b mut self, new_value: bool) -> &'b mut Self {
+            let context_mask: u32 = 1 << 31;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 1) << 31);
+            self.mask |= context_mask;
+            self
+        }
+    }
+    impl<'a> Drop for IcsrUpdate<'a> {
+        #[inline(always)]
+        fn drop(&mut self) {
+            if self.store_on_drop && self.mask != 0 {
+                let current: u32 = if self.write_only { 0 } else { self.reg.load() };
+                self.reg.store(self.merge(current));
+            }
+        }
+    }
+    #[derive(Clone, PartialEq, Eq)]
+    pub struct IcsrGet {
+        value: u32,
+    }
+    impl IcsrGet {
+        #[doc = "Create a getter reflecting the current value of the register"]
+        #[inline(always)]
+        pub fn new(reg: &Icsr) -> IcsrGet {
+            IcsrGet { value: reg.load() }
+        }
+        #[inline(always)]
+        pub fn vectactive(&self) -> u32 {
+            (self.value & 511)
+        }
+        #[inline(always)]
+        pub fn rettobase(&self) -> bool {
+            let val = ((self.value >> 11) & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub fn vectpending(&self) -> u32 {
+            ((self.value >> 12) & 511)
+        }
+        #[inline(always)]
+        pub fn isrpending(&self) -> bool {
+            let val = ((self.value >> 22) & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub fn isrprempt(&self) -> bool {
+            let val = ((self.value >> 23) & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub fn pendstclr(&self) -> bool {
+            let val = ((self.value >> 25) & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub fn pendstset(&self) -> bool {
+            let val = ((self.value >> 26) & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub fn pendsvclr(&self) -> bool {
+            let val = ((self.value >> 27) & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub fn pendsvset(&self) -> bool {
+            let val = ((self.value >> 28) & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub fn nmipendset(&self) -> bool {
+            let val = (self.value >> 31);
+            val != 0x0
+        }
+    }
+    #[derive(Clone, Copy, PartialEq, Eq, Default)]
+    #[repr(transparent)]
+    pub struct IcsrValue {
+        value: u32,
+    }
+    impl IcsrValue {
+        #[doc = "Create a value with every field cleared"]
+        #[inline(always)]
+        pub const fn new() -> IcsrValue {
+            IcsrValue { value: 0 }
+        }
+        #[inline(always)]
+        pub const fn from_bits(bits: u32) -> IcsrValue {
+            IcsrValue { value: bits }
+        }
+        #[inline(always)]
+        pub const fn to_bits(&self) -> u32 {
+            self.value
+        }
+        #[inline(always)]
+        pub fn vectactive(&self) -> u32 {
+            (self.value & 511)
+        }
+        #[inline(always)]
+        pub fn rettobase(&self) -> bool {
+            let val = ((self.value >> 11) & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub fn vectpending(&self) -> u32 {
+            ((self.value >> 12) & 511)
+        }
+        #[inline(always)]
+        pub fn isrpending(&self) -> bool {
+            let val = ((self.value >> 22) & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub fn isrprempt(&self) -> bool {
+            let val = ((self.value >> 23) & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub fn pendstclr(&self) -> bool {
+            let val = ((self.value >> 25) & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub fn pendstset(&self) -> bool {
+            let val = ((self.value >> 26) & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub fn pendsvclr(&self) -> bool {
+            let val = ((self.value >> 27) & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub fn pendsvset(&self) -> bool {
+            let val = ((self.value >> 28) & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub fn nmipendset(&self) -> bool {
+            let val = (self.value >> 31);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub const fn with_vectactive(self, new_value: u16) -> Self {
+            let context_mask: u32 = 511 << 0;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 511) << 0),
+            }
+        }
+        #[inline(always)]
+        pub const fn with_rettobase(self, new_value: bool) -> Self {
+            let context_mask: u32 = 1 << 11;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 1) << 11),
+            }
+        }
+        #[inline(always)]
+        pub const fn with_vectpending(self, new_value: u16) -> Self {
+            let context_mask: u32 = 511 << 12;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 511) << 12),
+            }
+        }
+        #[inline(always)]
+        pub const fn with_isrpending(self, new_value: bool) -> Self {
+            let context_mask: u32 = 1 << 22;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 1) << 22),
+            }
+        }
+        #[inline(always)]
+        pub const fn with_isrprempt(self, new_value: bool) -> Self {
+            let context_mask: u32 = 1 << 23;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 1) << 23),
+            }
+        }
+        #[inline(always)]
+        pub const fn with_pendstclr(self, new_value: bool) -> Self {
+            let context_mask: u32 = 1 << 25;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 1) << 25),
+            }
+        }
+        #[inline(always)]
+        pub const fn with_pendstset(self, new_value: bool) -> Self {
+            let context_mask: u32 = 1 << 26;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 1) << 26),
+            }
+        }
+        #[inline(always)]
+        pub const fn with_pendsvclr(self, new_value: bool) -> Self {
+            let context_mask: u32 = 1 << 27;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 1) << 27),
+            }
+        }
+        #[inline(always)]
+        pub const fn with_pendsvset(self, new_value: bool) -> Self {
+            let context_mask: u32 = 1 << 28;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 1) << 28),
+            }
+        }
+        #[inline(always)]
+        pub const fn with_nmipendset(self, new_value: bool) -> Self {
+            let context_mask: u32 = 1 << 31;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 1) << 31),
+            }
+        }
+    }
+    impl From<IcsrGet> for IcsrValue {
+        #[inline(always)]
+        fn from(get: IcsrGet) -> IcsrValue {
+            IcsrValue::from_bits(get.value)
+        }
+    }
+    impl From<IcsrValue> for IcsrGet {
+        #[inline(always)]
+        fn from(value: IcsrValue) -> IcsrGet {
+            IcsrGet {
+                value: value.to_bits(),
+            }
+        }
+    }
+    impl Icsr {
+        #[doc = "Write `value` to the register in a single store, without reading it first"]
+        #[inline(always)]
+        pub fn write(&self, value: IcsrValue) {
+            self.store(value.to_bits());
+        }
+    }
+    impl<'a> IcsrUpdate<'a> {
+        #[doc = "Replace every field of the register with those of `value`"]
+        #[inline(always)]
+        pub fn assign<'b>(&'b mut self, value: IcsrValue) -> &'b mut Self {
+            self.value = value.to_bits();
+            self.mask = !0;
+            self
+        }
+    }
+    impl IcsrGet {
+        pub const FIELDS: &'static [::volatile_cell::field::FieldDescriptor] = &[
+            ::volatile_cell::field::FieldDescriptor {
+                name: "vectactive",
+                offset: 0u32,
+                width: 9u32,
+                count: 1u32,
+            },
+            ::volatile_cell::field::FieldDescriptor {
+                name: "rettobase",
+                offset: 11u32,
+                width: 1u32,
+                count: 1u32,
+            },
+            ::volatile_cell::field::FieldDescriptor {
+                name: "vectpending",
+                offset: 12u32,
+                width: 9u32,
+                count: 1u32,
+            },
+            ::volatile_cell::field::FieldDescriptor {
+                name: "isrpending",
+                offset: 22u32,
+                width: 1u32,
+                count: 1u32,
+            },
+            ::volatile_cell::field::FieldDescriptor {
+                name: "isrprempt",
+                offset: 23u32,
+                width: 1u32,
+                count: 1u32,
+            },
+            ::volatile_cell::field::FieldDescriptor {
+                name: "pendstclr",
+                offset: 25u32,
+                width: 1u32,
+                count: 1u32,
+            },
+            ::volatile_cell::field::FieldDescriptor {
+                name: "pendstset",
+                offset: 26u32,
+                width: 1u32,
+                count: 1u32,
+            },
+            ::volatile_cell::field::FieldDescriptor {
+                name: "pendsvclr",
+                offset: 27u32,
+                width: 1u32,
+                count: 1u32,
+            },
+            ::volatile_cell::field::FieldDescriptor {
+                name: "pendsvset",
+                offset: 28u32,
+                width: 1u32,
+                count: 1u32,
+            },
+            ::volatile_cell::field::FieldDescriptor {
+                name: "nmipendset",
+                offset: 31u32,
+                width: 1u32,
+                count: 1u32,
+            },
+        ];
+        #[doc = "Iterate over the readable fields that differ from those of `other`"]
+        #[inline]
+        pub fn changed_fields(&self, other: &IcsrGet) -> ::volatile_cell::field::FieldChanges {
+            ::volatile_cell::field::FieldChanges::new(
+                Self::FIELDS,
+                self.value as u64,
+                other.value as u64,
+            )
+        }
+    }
+    pub mod vtor {}
+    #[repr(C)]
+    pub struct Vtor {
+        value: ::volatile_cell::VolatileCell<u32>,
+    }
+    impl Vtor {
+        #[inline(always)]
+        fn load(&self) -> u32 {
+            let value = self.value.get();
+            value
+        }
+        #[inline(always)]
+        fn store(&self, value: u32) {
+            self.value.set(value);
+        }
+        #[doc = "Create a new updater"]
+        #[inline(always)]
+        pub fn update<'a>(&'a self) -> VtorUpdate<'a> {
+            VtorUpdate::new(self)
+        }
+        #[doc = "Create an updater whose read-modify-write happens inside the critical section `cs`"]
+        #[inline(always)]
+        pub fn update_in<'a, C: ::volatile_cell::sync::CriticalSection>(
+            &'a self,
+            _cs: &'a C,
+        ) -> VtorUpdate<'a> {
+            VtorUpdate::new(self)
+        }
+        #[doc = "Update the register with exclusive loads and stores (LDREX/STREX), retrying `f` until no other access intervened"]
+        #[doc = ""]
+        #[doc = "The exclusive accesses go to the register's memory directly rather than through its cell, so they aren't seen by the replayer or the tracing hook."]
+        #[cfg(target_has_atomic = "32")]
+        #[inline]
+        pub fn update_exclusive<F: FnMut(&mut VtorUpdate)>(&self, mut f: F) {
+            use ::core::sync::atomic::Ordering;
+            let cell = unsafe { &*(self.value.as_ptr() as *const ::core::sync::atomic::AtomicU32) };
+            let mut current = cell.load(Ordering::Relaxed);
+            loop {
+                let mut update = VtorUpdate::detached(self);
+                f(&mut update);
+                let merged = update.merge(current);
+                if update.mask == 0 {
+                    return;
+                }
+                match cell.compare_exchange_weak(
+                    current,
+                    merged,
+                    Ordering::SeqCst,
+                    Ordering::Relaxed,
+                ) {
+                    Ok(_) => return,
+                    Err(actual) => current = actual,
+                }
+            }
+        }
+        #[doc = "Create a getter representing the current state of the register"]
+        #[inline(always)]
+        pub fn get(&self) -> VtorGet {
+            VtorGet::new(self)
+        }
+    }
+    pub struct VtorUpdate<'a> {
+        value: u32,
+        mask: u32,
+        write_only: bool,
+        store_on_drop: bool,
+        reg: &'a Vtor,
+    }
+    impl<'a> VtorUpdate<'a> {
+        #[inline(always)]
+        pub fn new(reg: &'a Vtor) -> VtorUpdate<'a> {
+            VtorUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[inline(always)]
+        pub fn new_ignoring_state(reg: &'a Vtor) -> VtorUpdate<'a> {
+            VtorUpdate {
+                value: 0,
+                mask: 0,
+                write_only: true,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[doc = "An update that is never stored, for the caller to store itself"]
+        #[inline(always)]
+        fn detached(reg: &'a Vtor) -> VtorUpdate<'a> {
+            VtorUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: false,
+                reg: reg,
+            }
+        }
+        const fn clear_mask() -> u32 {
+            0u64 as u32
+        }
+        const fn update_offset(base: usize, size: usize, index: usize) -> usize {
+            base + (size * index)
+        }
+        #[inline(always)]
+        fn merge(&self, current: u32) -> u32 {
+            let v: u32 = current & (!Self::clear_mask()) & (!self.mask);
+            self.value | v
+        }
+        #[inline(always)]
+        pub fn set_tbloff<'b>(&'b mut self, new_value: u32) -> &'b mut Self {
+            let context_mask: u32 = 33554431 << 7;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 33554431) << 7);
+            self.mask |= context_mask;
+            self
+        }
+    }
+    impl<'a> Drop for VtorUpdate<'a> {
+        #[inline(always)]
+        fn drop(&mut self) {
+            if self.store_on_drop && self.mask != 0 {
+                let current: u32 = if self.write_only { 0 } else { self.reg.load() };
+                self.reg.store(self.merge(current));
+            }
+        }
+    }
+    #[derive(Clone, PartialEq, Eq)]
+    pub struct VtorGet {
+        value: u32,
+    }
+    impl VtorGet {
+        #[doc = "Create a getter reflecting the current value of the register"]
+        #[inline(always)]
+        pub fn new(reg: &Vtor) -> VtorGet {
+            VtorGet { value: reg.load() }
+        }
+        #[inline(always)]
+        pub fn tbloff(&self) -> u32 {
+            (self.value >> 7)
+        }
+    }
+    #[derive(Clone, Copy, PartialEq, Eq, Default)]
+    #[repr(transparent)]
+    pub struct VtorValue {
+        value: u32,
+    }
+    impl VtorValue {
+        #[doc = "Create a value with every field cleared"]
+        #[inline(always)]
+        pub const fn new() -> VtorValue {
+            VtorValue { value: 0 }
+        }
+        #[inline(always)]
+        pub const fn from_bits(bits: u32) -> VtorValue {
+            VtorValue { value: bits }
+        }
+        #[inline(always)]
+        pub const fn to_bits(&self) -> u32 {
+            self.value
+        }
+        #[inline(always)]
+        pub fn tbloff(&self) -> u32 {
+            (self.value >> 7)
+        }
+        #[inline(always)]
+        pub const fn with_tbloff(self, new_value: u32) -> Self {
+            let context_mask: u32 = 33554431 << 7;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 33554431) << 7),
+            }
+        }
+    }
+    impl From<VtorGet> for VtorValue {
+        #[inline(always)]
+        fn from(get: VtorGet) -> VtorValue {
+            VtorValue::from_bits(get.value)
+        }
+    }
+    impl From<VtorValue> for VtorGet {
+        #[inline(always)]
+        fn from(value: VtorValue) -> VtorGet {
+            VtorGet {
+                value: value.to_bits(),
+            }
+        }
+    }
+    impl Vtor {
+        #[doc = "Write `value` to the register in a single store, without reading it first"]
+        #[inline(always)]
+        pub fn write(&self, value: VtorValue) {
+            self.store(value.to_bits());
+        }
+    }
+    impl<'a> VtorUpdate<'a> {
+        #[doc = "Replace every field of the register with those of `value`"]
+        #[inline(always)]
+        pub fn assign<'b>(&'b mut self, value: VtorValue) -> &'b mut Self {
+            self.value = value.to_bits();
+            self.mask = !0;
+            self
+        }
+    }
+    impl VtorGet {
+        pub const FIELDS: &'static [::volatile_cell::field::FieldDescriptor] =
+            &[::volatile_cell::field::FieldDescriptor {
+                name: "tbloff",
+                offset: 7u32,
+                width: 25u32,
+                count: 1u32,
+            }];
+        #[doc = "Iterate over the readable fields that differ from those of `other`"]
+        #[inline]
+        pub fn changed_fields(&self, other: &VtorGet) -> ::volatile_cell::field::FieldChanges {
+            ::volatile_cell::field::FieldChanges::new(
+                Self::FIELDS,
+                self.value as u64,
+                other.value as u64,
+            )
+        }
+    }
+    pub mod aircr {}
+    #[repr(C)]
+    pub struct Aircr {
+        value: ::volatile_cell::VolatileCell<u32>,
+    }
+    impl Aircr {
+        #[inline(always)]
+        fn load(&self) -> u32 {
+            let value = self.value.get();
+            value
+        }
+        #[inline(always)]
+        fn store(&self, value: u32) {
+            self.value.set(value);
+        }
+        #[doc = "Create a new updater"]
+        #[inline(always)]
+        pub fn update<'a>(&'a self) -> AircrUpdate<'a> {
+            AircrUpdate::new(self)
+        }
+        #[doc = "Create an updater whose read-modify-write happens inside the critical section `cs`"]
+        #[inline(always)]
+        pub fn update_in<'a, C: ::volatile_cell::sync::CriticalSection>(
+            &'a self,
+            _cs: &'a C,
+        ) -> AircrUpdate<'a> {
+            AircrUpdate::new(self)
+        }
+        #[doc = "Update the register with exclusive loads and stores (LDREX/STREX), retrying `f` until no other access intervened"]
+        #[doc = ""]
+        #[doc = "The exclusive accesses go to the register's memory directly rather than through its cell, so they aren't seen by the replayer or the tracing hook."]
+        #[cfg(target_has_atomic = "32")]
+        #[inline]
+        pub fn update_exclusive<F: FnMut(&mut AircrUpdate)>(&self, mut f: F) {
+            use ::core::sync::atomic::Ordering;
+            let cell = unsafe { &*(self.value.as_ptr() as *const ::core::sync::atomic::AtomicU32) };
+            let mut current = cell.load(Ordering::Relaxed);
+            loop {
+                let mut update = AircrUpdate::detached(self);
+                f(&mut update);
+                let merged = update.merge(current);
+                if update.mask == 0 {
+                    return;
+                }
+                match cell.compare_exchange_weak(
+                    current,
+                    merged,
+                    Ordering::SeqCst,
+                    Ordering::Relaxed,
+                ) {
+                    Ok(_) => return,
+                    Err(actual) => current = actual,
+                }
+            }
+        }
+        #[doc = "Create a getter representing the current state of the register"]
+        #[inline(always)]
+        pub fn get(&self) -> AircrGet {
+            AircrGet::new(self)
+        }
+        #[doc = "Spin until the bit is set"]
+        #[inline]
+        pub fn wait_vectreset(&self) {
+            while !self.get().vectreset() {}
+        }
+        #[doc = "Poll until the bit is set, at most `max_polls` times"]
+        #[inline]
+        pub fn wait_vectreset_timeout(
+            &self,
+            max_polls: u32,
+        ) -> Result<(), ::volatile_cell::wait::Timeout> {
+            ::volatile_cell::wait::poll_until(|| self.get().vectreset(), max_polls)
+        }
+        #[doc = "Future resolving once the bit is set"]
+        #[inline]
+        pub fn wait_vectreset_async<'a>(
+            &'a self,
+        ) -> ::volatile_cell::wait::WaitUntil<'a, impl FnMut() -> bool + Unpin + 'a> {
+            ::volatile_cell::wait::WaitUntil::new(move || self.get().vectreset())
+        }
+        #[doc = "Spin until the bit is set"]
+        #[inline]
+        pub fn wait_vectclractive(&self) {
+            while !self.get().vectclractive() {}
+        }
+        #[doc = "Poll until the bit is set, at most `max_polls` times"]
+        #[inline]
+        pub fn wait_vectclractive_timeout(
+            &self,
+            max_polls: u32,
+        ) -> Result<(), ::volatile_cell::wait::Timeout> {
+            ::volatile_cell::wait::poll_until(|| self.get().vectclractive(), max_polls)
+        }
+        #[doc = "Future resolving once the bit is set"]
+        #[inline]
+        pub fn wait_vectclractive_async<'a>(
+            &'a self,
+        ) -> ::volatile_cell::wait::WaitUntil<'a, impl FnMut() -> bool + Unpin + 'a> {
+            ::volatile_cell::wait::WaitUntil::new(move || self.get().vectclractive())
+        }
+        #[doc = "Spin until the bit is set"]
+        #[inline]
+        pub fn wait_sysresetreq(&self) {
+            while !self.get().sysresetreq() {}
+        }
+        #[doc = "Poll until the bit is set, at most `max_polls` times"]
+        #[inline]
+        pub fn wait_sysresetreq_timeout(
+            &self,
+            max_polls: u32,
+        ) -> Result<(), ::volatile_cell::wait::Timeout> {
+            ::volatile_cell::wait::poll_until(|| self.get().sysresetreq(), max_polls)
+        }
+        #[doc = "Future resolving once the bit is set"]
+        #[inline]
+        pub fn wait_sysresetreq_async<'a>(
+            &'a self,
+        ) -> ::volatile_cell::wait::WaitUntil<'a, impl FnMut() -> bool + Unpin + 'a> {
+            ::volatile_cell::wait::WaitUntil::new(move || self.get().sysresetreq())
+        }
+        #[doc = "Spin until the bit is set"]
+        #[inline]
+        pub fn wait_endianness(&self) {
+            while !self.get().endianness() {}
+        }
+        #[doc = "Poll until the bit is set, at most `max_polls` times"]
+        #[inline]
+        pub fn wait_endianness_timeout(
+            &self,
+            max_polls: u32,
+        ) -> Result<(), ::volatile_cell::wait::Timeout> {
+            ::volatile_cell::wait::poll_until(|| self.get().endianness(), max_polls)
+        }
+        #[doc = "Future resolving once the bit is set"]
+        #[inline]
+        pub fn wait_endianness_async<'a>(
+            &'a self,
+        ) -> ::volatile_cell::wait::WaitUntil<'a, impl FnMut() -> bool + Unpin + 'a> {
+            ::volatile_cell::wait::WaitUntil::new(move || self.get().endianness())
+        }
+    }
+    pub struct AircrUpdate<'a> {
+        value: u32,
+        mask: u32,
+        write_only: bool,
+        store_on_drop: bool,
+        reg: &'a Aircr,
+    }
+    impl<'a> AircrUpdate<'a> {
+        #[inline(always)]
+        pub fn new(reg: &'a Aircr) -> AircrUpdate<'a> {
+            AircrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[inline(always)]
+        pub fn new_ignoring_state(reg: &'a Aircr) -> AircrUpdate<'a> {
+            AircrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: true,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[doc = "An update that is never stored, for the caller to store itself"]
+        #[inline(always)]
+        fn detached(reg: &'a Aircr) -> AircrUpdate<'a> {
+            AircrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: false,
+                reg: reg,
+            }
+        }
+        const fn clear_mask() -> u32 {
+            0u64 as u32
+        }
+        const fn update_offset(base: usize, size: usize, index: usize) -> usize {
+            base + (size * index)
+        }
+        #[inline(always)]
+        fn merge(&self, current: u32) -> u32 {
+            let v: u32 = current & (!Self::clear_mask()) & (!self.mask);
+            self.value | v
+        }
+        #[inline(always)]
+        pub fn set_vectreset<'b>(&'b mut self, new_value: bool) -> &'b mut Self {
+            let context_mask: u32 = 1 << 0;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 1) << 0);
+            self.mask |= context_mask;
+            self
+        }
+        #[inline(always)]
+        pub fn set_vectclractive<'b>(&'b mut self, new_value: bool) -> &'b mut Self {
+            let context_mask: u32 = 1 << 1;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 1) << 1);
+            self.mask |= context_mask;
+            self
+        }
+        #[inline(always)]
+        pub fn set_sysresetreq<'b>(&'b mut self, new_value: bool) -> &'b mut Self {
+            let context_mask: u32 = 1 << 2;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 1) << 2);
+            self.mask |= context_mask;
+            self
+        }
+        #[inline(always)]
+        pub fn set_prigroup<'b>(&'b mut self, new_value: u8) -> &'b mut Self {
+            let context_mask: u32 = 7 << 8;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 7) << 8);
+            self.mask |= context_mask;
+            self
+        }
+        #[inline(always)]
+        pub fn set_endianness<'b>(&'b mut self, new_value: bool) -> &'b mut Self {
+            let context_mask: u32 = 1 << 15;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 1) << 15);
+            self.mask |= context_mask;
+            self
+        }
+        #[inline(always)]
+        pub fn set_vectkey<'b>(&'b mut self, new_value: u16) -> &'b mut Self {
+            let context_mask: u32 = 65535 << 16;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 65535) << 16);
+            self.mask |= context_mask;
+            self
+        }
+    }
+    impl<'a> Drop for AircrUpdate<'a> {
+        #[inline(always)]
+        fn drop(&mut self) {
+            if self.store_on_drop && self.mask != 0 {
+                let current: u32 = if self.write_only { 0 } else { self.reg.load() };
+                self.reg.store(self.merge(current));
+            }
+        }
+    }
+    #[derive(Clone, PartialEq, Eq)]
+    pub struct AircrGet {
+        value: u32,
+    }
+    impl AircrGet {
+        #[doc = "Create a getter reflecting the current value of the register"]
+        #[inline(always)]
+        pub fn new(reg: &Aircr) -> AircrGet {
+            AircrGet { value: reg.load() }
+        }
+        #[inline(always)]
+        pub fn vectreset(&self) -> bool {
+            let val = (self.value & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub fn vectclractive(&self) -> bool {
+            let val = ((self.value >> 1) & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub fn sysresetreq(&self) -> bool {
+            let val = ((self.value >> 2) & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub fn prigroup(&self) -> u32 {
+            ((self.value >> 8) & 7)
+        }
+        #[inline(always)]
+        pub fn endianness(&self) -> bool {
+            let val = ((self.value >> 15) & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub fn vectkey(&self) -> u32 {
+            (self.value >> 16)
+        }
+    }
+    #[derive(Clone, Copy, PartialEq, Eq, Default)]
+    #[repr(transparent)]
+    pub struct AircrValue {
+        value: u32,
+    }
+    impl AircrValue {
+        #[doc = "Create a value with every field cleared"]
+        #[inline(always)]
+        pub const fn new() -> AircrValue {
+            AircrValue { value: 0 }
+        }
+        #[inline(always)]
+        pub const fn from_bits(bits: u32) -> AircrValue {
+            AircrValue { value: bits }
+        }
+        #[inline(always)]
+        pub const fn to_bits(&self) -> u32 {
+            self.value
+        }
+        #[inline(always)]
+        pub fn vectreset(&self) -> bool {
+            let val = (self.value & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub fn vectclractive(&self) -> bool {
+            let val = ((self.value >> 1) & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub fn sysresetreq(&self) -> bool {
+            let val = ((self.value >> 2) & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub fn prigroup(&self) -> u32 {
+            ((self.value >> 8) & 7)
+        }
+        #[inline(always)]
+        pub fn endianness(&self) -> bool {
+            let val = ((self.value >> 15) & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub fn vectkey(&self) -> u32 {
+            (self.value >> 16)
+        }
+        #[inline(always)]
+        pub const fn with_vectreset(self, new_value: bool) -> Self {
+            let context_mask: u32 = 1 << 0;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 1) << 0),
+            }
+        }
+        #[inline(always)]
+        pub const fn with_vectclractive(self, new_value: bool) -> Self {
+            let context_mask: u32 = 1 << 1;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 1) << 1),
+            }
+        }
+        #[inline(always)]
+        pub const fn with_sysresetreq(self, new_value: bool) -> Self {
+            let context_mask: u32 = 1 << 2;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 1) << 2),
+            }
+        }
+        #[inline(always)]
+        pub const fn with_prigroup(self, new_value: u8) -> Self {
+            let context_mask: u32 = 7 << 8;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 7) << 8),
+            }
+        }
+        #[inline(always)]
+        pub const fn with_endianness(self, new_value: bool) -> Self {
+            let context_mask: u32 = 1 << 15;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 1) << 15),
+            }
+        }
+        #[inline(always)]
+        pub const fn with_vectkey(self, new_value: u16) -> Self {
+            let context_mask: u32 = 65535 << 16;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 65535) << 16),
+            }
+        }
+    }
+    impl From<AircrGet> for AircrValue {
+        #[inline(always)]
+        fn from(get: AircrGet) -> AircrValue {
+            AircrValue::from_bits(get.value)
+        }
+    }
+    impl From<AircrValue> for AircrGet {
+        #[inline(always)]
+        fn from(value: AircrValue) -> AircrGet {
+            AircrGet {
+                value: value.to_bits(),
+            }
+        }
+    }
+    impl Aircr {
+        #[doc = "Write `value` to the register in a single store, without reading it first"]
+        #[inline(always)]
+        pub fn write(&self, value: AircrValue) {
+            self.store(value.to_bits());
+        }
+    }
+    impl<'a> AircrUpdate<'a> {
+        #[doc = "Replace every field of the register with those of `value`"]
+        #[inline(always)]
+        pub fn assign<'b>(&'b mut self, value: AircrValue) -> &'b mut Self {
+            self.value = value.to_bits();
+            self.mask = !0;
+            self
+        }
+    }
+    impl AircrGet {
+        pub const FIELDS: &'static [::volatile_cell::field::FieldDescriptor] = &[
+            ::volatile_cell::field::FieldDescriptor {
+                name: "vectreset",
+                offset: 0u32,
+                width: 1u32,
+                count: 1u32,
+            },
+            ::volatile_cell::field::FieldDescriptor {
+                name: "vectclractive",
+                offset: 1u32,
+                width: 1u32,
+                count: 1u32,
+            },
+            ::volatile_cell::field::FieldDescriptor {
+                name: "sysresetreq",
+                offset: 2u32,
+                width: 1u32,
+                count: 1u32,
+            },
+            ::volatile_cell::field::FieldDescriptor {
+                name: "prigroup",
+                offset: 8u32,
+                width: 3u32,
+                count: 1u32,
+            },
+            ::volatile_cell::field::FieldDescriptor {
+                name: "endianness",
+                offset: 15u32,
+                width: 1u32,
+                count: 1u32,
+            },
+            ::volatile_cell::field::FieldDescriptor {
+                name: "vectkey",
+                offset: 16u32,
+                width: 16u32,
+                count: 1u32,
+            },
+        ];
+        #[doc = "Iterate over the readable fields that differ from those of `other`"]
+        #[inline]
+        pub fn changed_fields(&self, other: &AircrGet) -> ::volatile_cell::field::FieldChanges {
+            ::volatile_cell::field::FieldChanges::new(
+                Self::FIELDS,
+                self.value as u64,
+                other.value as u64,
+            )
+        }
+    }
+    pub mod scr {}
+    #[repr(C)]
+    pub struct Scr {
+        value: ::volatile_cell::VolatileCell<u32>,
+    }
+    impl Scr {
+        #[inline(always)]
+        fn load(&self) -> u32 {
+            let value = self.value.get();
+            value
+        }
+        #[inline(always)]
+        fn store(&self, value: u32) {
+            self.value.set(value);
+        }
+        #[doc = "Create a new updater"]
+        #[inline(always)]
+        pub fn update<'a>(&'a self) -> ScrUpdate<'a> {
+            ScrUpdate::new(self)
+        }
+        #[doc = "Create an updater whose read-modify-write happens inside the critical section `cs`"]
+        #[inline(always)]
+        pub fn update_in<'a, C: ::volatile_cell::sync::CriticalSection>(
+            &'a self,
+            _cs: &'a C,
+        ) -> ScrUpdate<'a> {
+            ScrUpdate::new(self)
+        }
+        #[doc = "Update the register with exclusive loads and stores (LDREX/STREX), retrying `f` until no other access intervened"]
+        #[doc = ""]
+        #[doc = "The exclusive accesses go to the register's memory directly rather than through its cell, so they aren't seen by the replayer or the tracing hook."]
+        #[cfg(target_has_atomic = "32")]
+        #[inline]
+        pub fn update_exclusive<F: FnMut(&mut ScrUpdate)>(&self, mut f: F) {
+            use ::core::sync::atomic::Ordering;
+            let cell = unsafe { &*(self.value.as_ptr() as *const ::core::sync::atomic::AtomicU32) };
+            let mut current = cell.load(Ordering::Relaxed);
+            loop {
+                let mut update = ScrUpdate::detached(self);
+                f(&mut update);
+                let merged = update.merge(current);
+                if update.mask == 0 {
+                    return;
+                }
+                match cell.compare_exchange_weak(
+                    current,
+                    merged,
+                    Ordering::SeqCst,
+                    Ordering::Relaxed,
+                ) {
+                    Ok(_) => return,
+                    Err(actual) => current = actual,
+                }
+            }
+        }
+        #[doc = "Create a getter representing the current state of the register"]
+        #[inline(always)]
+        pub fn get(&self) -> ScrGet {
+            ScrGet::new(self)
+        }
+        #[doc = "Spin until the bit is set"]
+        #[inline]
+        pub fn wait_sleeponexit(&self) {
+            while !self.get().sleeponexit() {}
+        }
+        #[doc = "Poll until the bit is set, at most `max_polls` times"]
+        #[inline]
+        pub fn wait_sleeponexit_timeout(
+            &self,
+            max_polls: u32,
+        ) -> Result<(), ::volatile_cell::wait::Timeout> {
+            ::volatile_cell::wait::poll_until(|| self.get().sleeponexit(), max_polls)
+        }
+        #[doc = "Future resolving once the bit is set"]
+        #[inline]
+        pub fn wait_sleeponexit_async<'a>(
+            &'a self,
+        ) -> ::volatile_cell::wait::WaitUntil<'a, impl FnMut() -> bool + Unpin + 'a> {
+            ::volatile_cell::wait::WaitUntil::new(move || self.get().sleeponexit())
+        }
+        #[doc = "Spin until the bit is set"]
+        #[inline]
+        pub fn wait_sleepdeep(&self) {
+            while !self.get().sleepdeep() {}
+        }
+        #[doc = "Poll until the bit is set, at most `max_polls` times"]
+        #[inline]
+        pub fn wait_sleepdeep_timeout(
+            &self,
+            max_polls: u32,
+        ) -> Result<(), ::volatile_cell::wait::Timeout> {
+            ::volatile_cell::wait::poll_until(|| self.get().sleepdeep(), max_polls)
+        }
+        #[doc = "Future resolving once the bit is set"]
+        #[inline]
+        pub fn wait_sleepdeep_async<'a>(
+            &'a self,
+        ) -> ::volatile_cell::wait::WaitUntil<'a, impl FnMut() -> bool + Unpin + 'a> {
+            ::volatile_cell::wait::WaitUntil::new(move || self.get().sleepdeep())
+        }
+        #[doc = "Spin until the bit is set"]
+        #[inline]
+        pub fn wait_sevonpend(&self) {
+            while !self.get().sevonpend() {}
+        }
+        #[doc = "Poll until the bit is set, at most `max_polls` times"]
+        #[inline]
+        pub fn wait_sevonpend_timeout(
+            &self,
+            max_polls: u32,
+        ) -> Result<(), ::volatile_cell::wait::Timeout> {
+            ::volatile_cell::wait::poll_until(|| self.get().sevonpend(), max_polls)
+        }
+        #[doc = "Future resolving once the bit is set"]
+        #[inline]
+        pub fn wait_sevonpend_async<'a>(
+            &'a self,
+        ) -> ::volatile_cell::wait::WaitUntil<'a, impl FnMut() -> bool + Unpin + 'a> {
+            ::volatile_cell::wait::WaitUntil::new(move || self.get().sevonpend())
+        }
+    }
+    pub struct ScrUpdate<'a> {
+        value: u32,
+        mask: u32,
+        write_only: bool,
+        store_on_drop: bool,
+        reg: &'a Scr,
+    }
+    impl<'a> ScrUpdate<'a> {
+        #[inline(always)]
+        pub fn new(reg: &'a Scr) -> ScrUpdate<'a> {
+            ScrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[inline(always)]
+        pub fn new_ignoring_state(reg: &'a Scr) -> ScrUpdate<'a> {
+            ScrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: true,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[doc = "An update that is never stored, for the caller to store itself"]
+        #[inline(always)]
+        fn detached(reg: &'a Scr) -> ScrUpdate<'a> {
+            ScrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: false,
+                reg: reg,
+            }
+        }
+        const fn clear_mask() -> u32 {
+            0u64 as u32
+        }
+        const fn update_offset(base: usize, size: usize, index: usize) -> usize {
+            base + (size * index)
+        }
+        #[inline(always)]
+        fn merge(&self, current: u32) -> u32 {
+            let v: u32 = current & (!Self::clear_mask()) & (!self.mask);
+            self.value | v
+        }
+        #[inline(always)]
+        pub fn set_sleeponexit<'b>(&'b mut self, new_value: bool) -> &'b mut Self {
+            let context_mask: u32 = 1 << 1;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 1) << 1);
+            self.mask |= context_mask;
+            self
+        }
+        #[inline(always)]
+        pub fn set_sleepdeep<'b>(&'b mut self, new_value: bool) -> &'b mut Self {
+            let context_mask: u32 = 1 << 2;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 1) << 2);
+            self.mask |= context_mask;
+            self
+        }
+        #[inline(always)]
+        pub fn set_sevonpend<'b>(&'b mut self, new_value: bool) -> &'b mut Self {
+            let context_mask: u32 = 1 << 4;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 1) << 4);
+            self.mask |= context_mask;
+            self
+        }
+    }
+    impl<'a> Drop for ScrUpdate<'a> {
+        #[inline(always)]
+        fn drop(&mut self) {
+            if self.store_on_drop && self.mask != 0 {
+                let current: u32 = if self.write_only { 0 } else { self.reg.load() };
+                self.reg.store(self.merge(current));
+            }
+        }
+    }
+    #[derive(Clone, PartialEq, Eq)]
+    pub struct ScrGet {
+        value: u32,
+    }
+    impl ScrGet {
+        #[doc = "Create a getter reflecting the current value of the register"]
+        #[inline(always)]
+        pub fn new(reg: &Scr) -> ScrGet {
+            ScrGet { value: reg.load() }
+        }
+        #[inline(always)]
+        pub fn sleeponexit(&self) -> bool {
+            let val = ((self.value >> 1) & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub fn sleepdeep(&self) -> bool {
+            let val = ((self.value >> 2) & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub fn sevonpend(&self) -> bool {
+            let val = ((self.value >> 4) & 1);
+            val != 0x0
+        }
+    }
+    #[derive(Clone, Copy, PartialEq, Eq, Default)]
+    #[repr(transparent)]
+    pub struct ScrValue {
+        value: u32,
+    }
+    impl ScrValue {
+        #[doc = "Create a value with every field cleared"]
+        #[inline(always)]
+        pub const fn new() -> ScrValue {
+            ScrValue { value: 0 }
+        }
+        #[inline(always)]
+        pub const fn from_bits(bits: u32) -> ScrValue {
+            ScrValue { value: bits }
+        }
+        #[inline(always)]
+        pub const fn to_bits(&self) -> u32 {
+            self.value
+        }
+        #[inline(always)]
+        pub fn sleeponexit(&self) -> bool {
+            let val = ((self.value >> 1) & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub fn sleepdeep(&self) -> bool {
+            let val = ((self.value >> 2) & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub fn sevonpend(&self) -> bool {
+            let val = ((self.value >> 4) & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub const fn with_sleeponexit(self, new_value: bool) -> Self {
+            let context_mask: u32 = 1 << 1;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 1) << 1),
+            }
+        }
+        #[inline(always)]
+        pub const fn with_sleepdeep(self, new_value: bool) -> Self {
+            let context_mask: u32 = 1 << 2;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 1) << 2),
+            }
+        }
+        #[inline(always)]
+        pub const fn with_sevonpend(self, new_value: bool) -> Self {
+            let context_mask: u32 = 1 << 4;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 1) << 4),
+            }
+        }
+    }
+    impl From<ScrGet> for ScrValue {
+        #[inline(always)]
+        fn from(get: ScrGet) -> ScrValue {
+            ScrValue::from_bits(get.value)
+        }
+    }
+    impl From<ScrValue> for ScrGet {
+        #[inline(always)]
+        fn from(value: ScrValue) -> ScrGet {
+            ScrGet {
+                value: value.to_bits(),
+            }
+        }
+    }
+    impl Scr {
+        #[doc = "Write `value` to the register in a single store, without reading it first"]
+        #[inline(always)]
+        pub fn write(&self, value: ScrValue) {
+            self.store(value.to_bits());
+        }
+    }
+    impl<'a> ScrUpdate<'a> {
+        #[doc = "Replace every field of the register with those of `value`"]
+        #[inline(always)]
+        pub fn assign<'b>(&'b mut self, value: ScrValue) -> &'b mut Self {
+            self.value = value.to_bits();
+            self.mask = !0;
+            self
+        }
+    }
+    impl ScrGet {
+        pub const FIELDS: &'static [::volatile_cell::field::FieldDescriptor] = &[
+            ::volatile_cell::field::FieldDescriptor {
+                name: "sleeponexit",
+                offset: 1u32,
+                width: 1u32,
+                count: 1u32,
+            },
+            ::volatile_cell::field::FieldDescriptor {
+                name: "sleepdeep",
+                offset: 2u32,
+                width: 1u32,
+                count: 1u32,
+            },
+            ::volatile_cell::field::FieldDescriptor {
+                name: "sevonpend",
+                offset: 4u32,
+                width: 1u32,
+                count: 1u32,
+            },
+        ];
+        #[doc = "Iterate over the readable fields that differ from those of `other`"]
+        #[inline]
+        pub fn changed_fields(&self, other: &ScrGet) -> ::volatile_cell::field::FieldChanges {
+            ::volatile_cell::field::FieldChanges::new(
+                Self::FIELDS,
+                self.value as u64,
+                other.value as u64,
+            )
+        }
+    }
+    pub mod ccr {}
+    #[repr(C)]
+    pub struct Ccr {
+        value: ::volatile_cell::VolatileCell<u32>,
+    }
+    impl Ccr {
+        #[inline(always)]
+        fn load(&self) -> u32 {
+            let value = self.value.get();
+            value
+        }
+        #[inline(always)]
+        fn store(&self, value: u32) {
+            self.value.set(value);
+        }
+        #[doc = "Create a new updater"]
+        #[inline(always)]
+        pub fn update<'a>(&'a self) -> CcrUpdate<'a> {
+            CcrUpdate::new(self)
+        }
+        #[doc = "Create an updater whose read-modify-write happens inside the critical section `cs`"]
+        #[inline(always)]
+        pub fn update_in<'a, C: ::volatile_cell::sync::CriticalSection>(
+            &'a self,
+            _cs: &'a C,
+        ) -> CcrUpdate<'a> {
+            CcrUpdate::new(self)
+        }
+        #[doc = "Update the register with exclusive loads and stores (LDREX/STREX), retrying `f` until no other access intervened"]
+        #[doc = ""]
+        #[doc = "The exclusive accesses go to the register's memory directly rather than through its cell, so they aren't seen by the replayer or the tracing hook."]
+        #[cfg(target_has_atomic = "32")]
+        #[inline]
+        pub fn update_exclusive<F: FnMut(&mut CcrUpdate)>(&self, mut f: F) {
+            use ::core::sync::atomic::Ordering;
+            let cell = unsafe { &*(self.value.as_ptr() as *const ::core::sync::atomic::AtomicU32) };
+            let mut current = cell.load(Ordering::Relaxed);
+            loop {
+                let mut update = CcrUpdate::detached(self);
+                f(&mut update);
+                let merged = update.merge(current);
+                if update.mask == 0 {
+                    return;
+                }
+                match cell.compare_exchange_weak(
+                    current,
+                    merged,
+                    Ordering::SeqCst,
+                    Ordering::Relaxed,
+                ) {
+                    Ok(_) => return,
+                    Err(actual) => current = actual,
+                }
+            }
+        }
+        #[doc = "Create a getter representing the current state of the register"]
+        #[inline(always)]
+        pub fn get(&self) -> CcrGet {
+            CcrGet::new(self)
+        }
+        #[doc = "Spin until the bit is set"]
+        #[inline]
+        pub fn wait_nonbasethrdena(&self) {
+            while !self.get().nonbasethrdena() {}
+        }
+        #[doc = "Poll until the bit is set, at most `max_polls` times"]
+        #[inline]
+        pub fn wait_nonbasethrdena_timeout(
+            &self,
+            max_polls: u32,
+        ) -> Result<(), ::volatile_cell::wait::Timeout> {
+            ::volatile_cell::wait::poll_until(|| self.get().nonbasethrdena(), max_polls)
+        }
+        #[doc = "Future resolving once the bit is set"]
+        #[inline]
+        pub fn wait_nonbasethrdena_async<'a>(
+            &'a self,
+        ) -> ::volatile_cell::wait::WaitUntil<'a, impl FnMut() -> bool + Unpin + 'a> {
+            ::volatile_cell::wait::WaitUntil::new(move || self.get().nonbasethrdena())
+        }
+        #[doc = "Spin until the bit is set"]
+        #[inline]
+        pub fn wait_usersetmpend(&self) {
+            while !self.get().usersetmpend() {}
+        }
+        #[doc = "Poll until the bit is set, at most `max_polls` times"]
+        #[inline]
+        pub fn wait_usersetmpend_timeout(
+            &self,
+            max_polls: u32,
+        ) -> Result<(), ::volatile_cell::wait::Timeout> {
+            ::volatile_cell::wait::poll_until(|| self.get().usersetmpend(), max_polls)
+        }
+        #[doc = "Future resolving once the bit is set"]
+        #[inline]
+        pub fn wait_usersetmpend_async<'a>(
+            &'a self,
+        ) -> ::volatile_cell::wait::WaitUntil<'a, impl FnMut() -> bool + Unpin + 'a> {
+            ::volatile_cell::wait::WaitUntil::new(move || self.get().usersetmpend())
+        }
+        #[doc = "Spin until the bit is set"]
+        #[inline]
+        pub fn wait_unalign_trp(&self) {
+            while !self.get().unalign_trp() {}
+        }
+        #[doc = "Poll until the bit is set, at most `max_polls` times"]
+        #[inline]
+        pub fn wait_unalign_trp_timeout(
+            &self,
+            max_polls: u32,
+        ) -> Result<(), ::volatile_cell::wait::Timeout> {
+            ::volatile_cell::wait::poll_until(|| self.get().unalign_trp(), max_polls)
+        }
+        #[doc = "Future resolving once the bit is set"]
+        #[inline]
+        pub fn wait_unalign_trp_async<'a>(
+            &'a self,
+        ) -> ::volatile_cell::wait::WaitUntil<'a, impl FnMut() -> bool + Unpin + 'a> {
+            ::volatile_cell::wait::WaitUntil::new(move || self.get().unalign_trp())
+        }
+        #[doc = "Spin until the bit is set"]
+        #[inline]
+        pub fn wait_div_0_trp(&self) {
+            while !self.get().div_0_trp() {}
+        }
+        #[doc = "Poll until the bit is set, at most `max_polls` times"]
+        #[inline]
+        pub fn wait_div_0_trp_timeout(
+            &self,
+            max_polls: u32,
+        ) -> Result<(), ::volatile_cell::wait::Timeout> {
+            ::volatile_cell::wait::poll_until(|| self.get().div_0_trp(), max_polls)
+        }
+        #[doc = "Future resolving once the bit is set"]
+        #[inline]
+        pub fn wait_div_0_trp_async<'a>(
+            &'a self,
+        ) -> ::volatile_cell::wait::WaitUntil<'a, impl FnMut() -> bool + Unpin + 'a> {
+            ::volatile_cell::wait::WaitUntil::new(move || self.get().div_0_trp())
+        }
+        #[doc = "Spin until the bit is set"]
+        #[inline]
+        pub fn wait_bfhfnmign(&self) {
+            while !self.get().bfhfnmign() {}
+        }
+        #[doc = "Poll until the bit is set, at most `max_polls` times"]
+        #[inline]
+        pub fn wait_bfhfnmign_timeout(
+            &self,
+            max_polls: u32,
+        ) -> Result<(), ::volatile_cell::wait::Timeout> {
+            ::volatile_cell::wait::poll_until(|| self.get().bfhfnmign(), max_polls)
+        }
+        #[doc = "Future resolving once the bit is set"]
+        #[inline]
+        pub fn wait_bfhfnmign_async<'a>(
+            &'a self,
+        ) -> ::volatile_cell::wait::WaitUntil<'a, impl FnMut() -> bool + Unpin + 'a> {
+            ::volatile_cell::wait::WaitUntil::new(move || self.get().bfhfnmign())
+        }
+        #[doc = "Spin until the bit is set"]
+        #[inline]
+        pub fn wait_stkalign(&self) {
+            while !self.get().stkalign() {}
+        }
+        #[doc = "Poll until the bit is set, at most `max_polls` times"]
+        #[inline]
+        pub fn wait_stkalign_timeout(
+            &self,
+            max_polls: u32,
+        ) -> Result<(), ::volatile_cell::wait::Timeout> {
+            ::volatile_cell::wait::poll_until(|| self.get().stkalign(), max_polls)
+        }
+        #[doc = "Future resolving once the bit is set"]
+        #[inline]
+        pub fn wait_stkalign_async<'a>(
+            &'a self,
+        ) -> ::volatile_cell::wait::WaitUntil<'a, impl FnMut() -> bool + Unpin + 'a> {
+            ::volatile_cell::wait::WaitUntil::new(move || self.get().stkalign())
+        }
+    }
+    pub struct CcrUpdate<'a> {
+        value: u32,
+        mask: u32,
+        write_only: bool,
+        store_on_drop: bool,
+        reg: &'a Ccr,
+    }
+    impl<'a> CcrUpdate<'a> {
+        #[inline(always)]
+        pub fn new(reg: &'a Ccr) -> CcrUpdate<'a> {
+            CcrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[inline(always)]
+        pub fn new_ignoring_state(reg: &'a Ccr) -> CcrUpdate<'a> {
+            CcrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: true,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[doc = "An update that is never stored, for the caller to store itself"]
+        #[inline(always)]
+        fn detached(reg: &'a Ccr) -> CcrUpdate<'a> {
+            CcrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: false,
+                reg: reg,
+            }
+        }
+        const fn clear_mask() -> u32 {
+            0u64 as u32
+        }
+        const fn update_offset(base: usize, size: usize, index: usize) -> usize {
+            base + (size * index)
+        }
+        #[inline(always)]
+        fn merge(&self, current: u32) -> u32 {
+            let v: u32 = current & (!Self::clear_mask()) & (!self.mask);
+            self.value | v
+        }
+        #[inline(always)]
+        pub fn set_nonbasethrdena<'b>(&'b mut self, new_value: bool) -> &'b mut Self {
+            let context_mask: u32 = 1 << 0;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 1) << 0);
+            self.mask |= context_mask;
+            self
+        }
+        #[inline(always)]
+        pub fn set_usersetmpend<'b>(&'b mut self, new_value: bool) -> &'b mut Self {
+            let context_mask: u32 = 1 << 1;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 1) << 1);
+            self.mask |= context_mask;
+            self
+        }
+        #[inline(always)]
+        pub fn set_unalign_trp<'b>(&'b mut self, new_value: bool) -> &'b mut Self {
+            let context_mask: u32 = 1 << 3;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 1) << 3);
+            self.mask |= context_mask;
+            self
+        }
+        #[inline(always)]
+        pub fn set_div_0_trp<'b>(&'b mut self, new_value: bool) -> &'b mut Self {
+            let context_mask: u32 = 1 << 4;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 1) << 4);
+            self.mask |= context_mask;
+            self
+        }
+        #[inline(always)]
+        pub fn set_bfhfnmign<'b>(&'b mut self, new_value: bool) -> &'b mut Self {
+            let context_mask: u32 = 1 << 8;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 1) << 8);
+            self.mask |= context_mask;
+            self
+        }
+        #[inline(always)]
+        pub fn set_stkalign<'b>(&'b mut self, new_value: bool) -> &'b mut Self {
+            let context_mask: u32 = 1 << 9;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 1) << 9);
+            self.mask |= context_mask;
+            self
+        }
+    }
+    impl<'a> Drop for CcrUpdate<'a> {
+        #[inline(always)]
+        fn drop(&mut self) {
+            if self.store_on_drop && self.mask != 0 {
+                let current: u32 = if self.write_only { 0 } else { self.reg.load() };
+                self.reg.store(self.merge(current));
+            }
+        }
+    }
+    #[derive(Clone, PartialEq, Eq)]
+    pub struct CcrGet {
+        value: u32,
+    }
+    impl CcrGet {
+        #[doc = "Create a getter reflecting the current value of the register"]
+        #[inline(always)]
+        pub fn new(reg: &Ccr) -> CcrGet {
+            CcrGet { value: reg.load() }
+        }
+        #[inline(always)]
+        pub fn nonbasethrdena(&self) -> bool {
+            let val = (self.value & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub fn usersetmpend(&self) -> bool {
+            let val = ((self.value >> 1) & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub fn unalign_trp(&self) -> bool {
+            let val = ((self.value >> 3) & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub fn div_0_trp(&self) -> bool {
+            let val = ((self.value >> 4) & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub fn bfhfnmign(&self) -> bool {
+            let val = ((self.value >> 8) & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub fn stkalign(&self) -> bool {
+            let val = ((self.value >> 9) & 1);
+            val != 0x0
+        }
+    }
+    #[derive(Clone, Copy, PartialEq, Eq, Default)]
+    #[repr(transparent)]
+    pub struct CcrValue {
+        value: u32,
+    }
+    impl CcrValue {
+        #[doc = "Create a value with every field cleared"]
+        #[inline(always)]
+        pub const fn new() -> CcrValue {
+            CcrValue { value: 0 }
+        }
+        #[inline(always)]
+        pub const fn from_bits(bits: u32) -> CcrValue {
+            CcrValue { value: bits }
+        }
+        #[inline(always)]
+        pub const fn to_bits(&self) -> u32 {
+            self.value
+        }
+        #[inline(always)]
+        pub fn nonbasethrdena(&self) -> bool {
+            let val = (self.value & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub fn usersetmpend(&self) -> bool {
+            let val = ((self.value >> 1) & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub fn unalign_trp(&self) -> bool {
+            let val = ((self.value >> 3) & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub fn div_0_trp(&self) -> bool {
+            let val = ((self.value >> 4) & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub fn bfhfnmign(&self) -> bool {
+            let val = ((self.value >> 8) & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub fn stkalign(&self) -> bool {
+            let val = ((self.value >> 9) & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub const fn with_nonbasethrdena(self, new_value: bool) -> Self {
+            let context_mask: u32 = 1 << 0;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 1) << 0),
+            }
+        }
+        #[inline(always)]
+        pub const fn with_usersetmpend(self, new_value: bool) -> Self {
+            let context_mask: u32 = 1 << 1;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 1) << 1),
+            }
+        }
+        #[inline(always)]
+        pub const fn with_unalign_trp(self, new_value: bool) -> Self {
+            let context_mask: u32 = 1 << 3;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 1) << 3),
+            }
+        }
+        #[inline(always)]
+        pub const fn with_div_0_trp(self, new_value: bool) -> Self {
+            let context_mask: u32 = 1 << 4;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 1) << 4),
+            }
+        }
+        #[inline(always)]
+        pub const fn with_bfhfnmign(self, new_value: bool) -> Self {
+            let context_mask: u32 = 1 << 8;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 1) << 8),
+            }
+        }
+        #[inline(always)]
+        pub const fn with_stkalign(self, new_value: bool) -> Self {
+            let context_mask: u32 = 1 << 9;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 1) << 9),
+            }
+        }
+    }
+    impl From<CcrGet> for CcrValue {
+        #[inline(always)]
+        fn from(get: CcrGet) -> CcrValue {
+            CcrValue::from_bits(get.value)
+        }
+    }
+    impl From<CcrValue> for CcrGet {
+        #[inline(always)]
+        fn from(value: CcrValue) -> CcrGet {
+            CcrGet {
+                value: value.to_bits(),
+            }
+        }
+    }
+    impl Ccr {
+        #[doc = "Write `value` to the register in a single store, without reading it first"]
+        #[inline(always)]
+        pub fn write(&self, value: CcrValue) {
+            self.store(value.to_bits());
+        }
+    }
+    impl<'a> CcrUpdate<'a> {
+        #[doc = "Replace every field of the register with those of `value`"]
+        #[inline(always)]
+        pub fn assign<'b>(&'b mut self, value: CcrValue) -> &'b mut Self {
+            self.value = value.to_bits();
+            self.mask = !0;
+            self
+        }
+    }
+    impl CcrGet {
+        pub const FIELDS: &'static [::volatile_cell::field::FieldDescriptor] = &[
+            ::volatile_cell::field::FieldDescriptor {
+                name: "nonbasethrdena",
+                offset: 0u32,
+                width: 1u32,
+                count: 1u32,
+            },
+            ::volatile_cell::field::FieldDescriptor {
+                name: "usersetmpend",
+                offset: 1u32,
+                width: 1u32,
+                count: 1u32,
+            },
+            ::volatile_cell::field::FieldDescriptor {
+                name: "unalign_trp",
+                offset: 3u32,
+                width: 1u32,
+                count: 1u32,
+            },
+            ::volatile_cell::field::FieldDescriptor {
+                name: "div_0_trp",
+                offset: 4u32,
+                width: 1u32,
+                count: 1u32,
+            },
+            ::volatile_cell::field::FieldDescriptor {
+                name: "bfhfnmign",
+                offset: 8u32,
+                width: 1u32,
+                count: 1u32,
+            },
+            ::volatile_cell::field::FieldDescriptor {
+                name: "stkalign",
+                offset: 9u32,
+                width: 1u32,
+                count: 1u32,
+            },
+        ];
+        #[doc = "Iterate over the readable fields that differ from those of `other`"]
+        #[inline]
+        pub fn changed_fields(&self, other: &CcrGet) -> ::volatile_cell::field::FieldChanges {
+            ::volatile_cell::field::FieldChanges::new(
+                Self::FIELDS,
+                self.value as u64,
+                other.value as u64,
+            )
+        }
+    }
+    pub mod shpr {}
+    #[repr(C)]
+    pub struct Shpr {
+        value: ::volatile_cell::VolatileCell<u32>,
+    }
+    impl Shpr {
+        #[inline(always)]
+        fn load(&self) -> u32 {
+            let value = self.value.get();
+            value
+        }
+        #[inline(always)]
+        fn store(&self, value: u32) {
+            self.value.set(value);
+        }
+        #[doc = "Create a new updater"]
+        #[inline(always)]
+        pub fn update<'a>(&'a self) -> ShprUpdate<'a> {
+            ShprUpdate::new(self)
+        }
+        #[doc = "Create an updater whose read-modify-write happens inside the critical section `cs`"]
+        #[inline(always)]
+        pub fn update_in<'a, C: ::volatile_cell::sync::CriticalSection>(
+            &'a self,
+            _cs: &'a C,
+        ) -> ShprUpdate<'a> {
+            ShprUpdate::new(self)
+        }
+        #[doc = "Update the register with exclusive loads and stores (LDREX/STREX), retrying `f` until no other access intervened"]
+        #[doc = ""]
+        #[doc = "The exclusive accesses go to the register's memory directly rather than through its cell, so they aren't seen by the replayer or the tracing hook."]
+        #[cfg(target_has_atomic = "32")]
+        #[inline]
+        pub fn update_exclusive<F: FnMut(&mut ShprUpdate)>(&self, mut f: F) {
+            use ::core::sync::atomic::Ordering;
+            let cell = unsafe { &*(self.value.as_ptr() as *const ::core::sync::atomic::AtomicU32) };
+            let mut current = cell.load(Ordering::Relaxed);
+            loop {
+                let mut update = ShprUpdate::detached(self);
+                f(&mut update);
+                let merged = update.merge(current);
+                if update.mask == 0 {
+                    return;
+                }
+                match cell.compare_exchange_weak(
+                    current,
+                    merged,
+                    Ordering::SeqCst,
+                    Ordering::Relaxed,
+                ) {
+                    Ok(_) => return,
+                    Err(actual) => current = actual,
+                }
+            }
+        }
+        #[doc = "Create a getter representing the current state of the register"]
+        #[inline(always)]
+        pub fn get(&self) -> ShprGet {
+            ShprGet::new(self)
+        }
+    }
+    pub struct ShprUpdate<'a> {
+        value: u32,
+        mask: u32,
+        write_only: bool,
+        store_on_drop: bool,
+        reg: &'a Shpr,
+    }
+    impl<'a> ShprUpdate<'a> {
+        #[inline(always)]
+        pub fn new(reg: &'a Shpr) -> ShprUpdate<'a> {
+            ShprUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[inline(always)]
+        pub fn new_ignoring_state(reg: &'a Shpr) -> ShprUpdate<'a> {
+            ShprUpdate {
+                value: 0,
+                mask: 0,
+                write_only: true,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[doc = "An update that is never stored, for the caller to store itself"]
+        #[inline(always)]
+        fn detached(reg: &'a Shpr) -> ShprUpdate<'a> {
+            ShprUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: false,
+                reg: reg,
+            }
+        }
+        const fn clear_mask() -> u32 {
+            0u64 as u32
+        }
+        const fn update_offset(base: usize, size: usize, index: usize) -> usize {
+            base + (size * index)
+        }
+        #[inline(always)]
+        fn merge(&self, current: u32) -> u32 {
+            let v: u32 = current & (!Self::clear_mask()) & (!self.mask);
+            self.value | v
+        }
+        #[inline(always)]
+        pub fn set_pri<'b>(&'b mut self, index: usize, new_value: u8) -> &'b mut Self {
+            if index > 4 {
+                panic!();
+            }
+            let update_offset = Self::update_offset(0, 8, index);
+            let context_mask: u32 = 255 << update_offset;
+            self.value =
+                (self.value & !context_mask) | (((new_value as u32) & 255) << update_offset);
+            self.mask |= context_mask;
+            self
+        }
+    }
+    impl<'a> Drop for ShprUpdate<'a> {
+        #[inline(always)]
+        fn drop(&mut self) {
+            if self.store_on_drop && self.mask != 0 {
+                let current: u32 = if self.write_only { 0 } else { self.reg.load() };
+                self.reg.store(self.merge(current));
+            }
+        }
+    }
+    #[derive(Clone, PartialEq, Eq)]
+    pub struct ShprGet {
+        value: u32,
+    }
+    impl ShprGet {
+        #[doc = "Create a getter reflecting the current value of the register"]
+        #[inline(always)]
+        pub fn new(reg: &Shpr) -> ShprGet {
+            ShprGet { value: reg.load() }
+        }
+        #[inline(always)]
+        pub fn pri(&self, index: usize) -> u32 {
+            (self.value >> (0 + (8 * index))) & 255
+        }
+    }
+    #[derive(Clone, Copy, PartialEq, Eq, Default)]
+    #[repr(transparent)]
+    pub struct ShprValue {
+        value: u32,
+    }
+    impl ShprValue {
+        #[doc = "Create a value with every field cleared"]
+        #[inline(always)]
+        pub const fn new() -> ShprValue {
+            ShprValue { value: 0 }
+        }
+        #[inline(always)]
+        pub const fn from_bits(bits: u32) -> ShprValue {
+            ShprValue { value: bits }
+        }
+        #[inline(always)]
+        pub const fn to_bits(&self) -> u32 {
+            self.value
+        }
+        #[inline(always)]
+        pub fn pri(&self, index: usize) -> u32 {
+            (self.value >> (0 + (8 * index))) & 255
+        }
+        #[inline(always)]
+        pub const fn with_pri(self, index: usize, new_value: u8) -> Self {
+            if index >= 4 {
+                panic!();
+            }
+            let shift = 0 + (8 * index);
+            let context_mask: u32 = 255 << shift;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 255) << shift),
+            }
+        }
+    }
+    impl From<ShprGet> for ShprValue {
+        #[inline(always)]
+        fn from(get: ShprGet) -> ShprValue {
+            ShprValue::from_bits(get.value)
+        }
+    }
+    impl From<ShprValue> for ShprGet {
+        #[inline(always)]
+        fn from(value: ShprValue) -> ShprGet {
+            ShprGet {
+                value: value.to_bits(),
+            }
+        }
+    }
+    impl Shpr {
+        #[doc = "Write `value` to the register in a single store, without reading it first"]
+        #[inline(always)]
+        pub fn write(&self, value: ShprValue) {
+            self.store(value.to_bits());
+        }
+    }
+    impl<'a> ShprUpdate<'a> {
+        #[doc = "Replace every field of the register with those of `value`"]
+        #[inline(always)]
+        pub fn assign<'b>(&'b mut self, value: ShprValue) -> &'b mut Self {
+            self.value = value.to_bits();
+            self.mask = !0;
+            self
+        }
+    }
+    impl ShprGet {
+        pub const FIELDS: &'static [::volatile_cell::field::FieldDescriptor] =
+            &[::volatile_cell::field::FieldDescriptor {
+                name: "pri",
+                offset: 0u32,
+                width: 8u32,
+                count: 4u32,
+            }];
+        #[doc = "Iterate over the readable fields that differ from those of `other`"]
+        #[inline]
+        pub fn changed_fields(&self, other: &ShprGet) -> ::volatile_cell::field::FieldChanges {
+            ::volatile_cell::field::FieldChanges::new(
+                Self::FIELDS,
+                self.value as u64,
+                other.value as u64,
+            )
+        }
+    }
+    pub mod shcsr {}
+    #[repr(C)]
+    pub struct Shcsr {
+        value: ::volatile_cell::VolatileCell<u32>,
+    }
+    impl Shcsr {
+        #[inline(always)]
+        fn load(&self) -> u32 {
+            let value = self.value.get();
+            value
+        }
+        #[inline(always)]
+        fn store(&self, value: u32) {
+            self.value.set(value);
+        }
+        #[doc = "Create a new updater"]
+        #[inline(always)]
+        pub fn update<'a>(&'a self) -> ShcsrUpdate<'a> {
+            ShcsrUpdate::new(self)
+        }
+        #[doc = "Create an updater whose read-modify-write happens inside the critical section `cs`"]
+        #[inline(always)]
+        pub fn update_in<'a, C: ::volatile_cell::sync::CriticalSection>(
+            &'a self,
+            _cs: &'a C,
+        ) -> ShcsrUpdate<'a> {
+            ShcsrUpdate::new(self)
+        }
+        #[doc = "Update the register with exclusive loads and stores (LDREX/STREX), retrying `f` until no other access intervened"]
+        #[doc = ""]
+        #[doc = "The exclusive accesses go to the register's memory directly rather than through its cell, so they aren't seen by the replayer or the tracing hook."]
+        #[cfg(target_has_atomic = "32")]
+        #[inline]
+        pub fn update_exclusive<F: FnMut(&mut ShcsrUpdate)>(&self, mut f: F) {
+            use ::core::sync::atomic::Ordering;
+            let cell = unsafe { &*(self.value.as_ptr() as *const ::core::sync::atomic::AtomicU32) };
+            let mut current = cell.load(Ordering::Relaxed);
+            loop {
+                let mut update = ShcsrUpdate::detached(self);
+                f(&mut update);
+                let merged = update.merge(current);
+                if update.mask == 0 {
+                    return;
+                }
+                match cell.compare_exchange_weak(
+                    current,
+                    merged,
+                    Ordering::SeqCst,
+                    Ordering::Relaxed,
+                ) {
+                    Ok(_) => return,
+                    Err(actual) => current = actual,
+                }
+            }
+        }
+        #[doc = "Create a getter representing the current state of the register"]
+        #[inline(always)]
+        pub fn get(&self) -> ShcsrGet {
+            ShcsrGet::new(self)
+        }
+        #[doc = "Spin until the bit is set"]
+        #[inline]
+        pub fn wait_memfaultact(&self) {
+            while !self.get().memfaultact() {}
+        }
+        #[doc = "Poll until the bit is set, at most `max_polls` times"]
+        #[inline]
+        pub fn wait_memfaultact_timeout(
+            &self,
+            max_polls: u32,
+        ) -> Result<(), ::volatile_cell::wait::Timeout> {
+            ::volatile_cell::wait::poll_until(|| self.get().memfaultact(), max_polls)
+        }
+        #[doc = "Future resolving once the bit is set"]
+        #[inline]
+        pub fn wait_memfaultact_async<'a>(
+            &'a self,
+        ) -> ::volatile_cell::wait::WaitUntil<'a, impl FnMut() -> bool + Unpin + 'a> {
+            ::volatile_cell::wait::WaitUntil::new(move || self.get().memfaultact())
+        }
+        #[doc = "Spin until the bit is set"]
+        #[inline]
+        pub fn wait_busfaultact(&self) {
+            while !self.get().busfaultact() {}
+        }
+        #[doc = "Poll until the bit is set, at most `max_polls` times"]
+        #[inline]
+        pub fn wait_busfaultact_timeout(
+            &self,
+            max_polls: u32,
+        ) -> Result<(), ::volatile_cell::wait::Timeout> {
+            ::volatile_cell::wait::poll_until(|| self.get().busfaultact(), max_polls)
+        }
+        #[doc = "Future resolving once the bit is set"]
+        #[inline]
+        pub fn wait_busfaultact_async<'a>(
+            &'a self,
+        ) -> ::volatile_cell::wait::WaitUntil<'a, impl FnMut() -> bool + Unpin + 'a> {
+            ::volatile_cell::wait::WaitUntil::new(move || self.get().busfaultact())
+        }
+        #[doc = "Spin until the bit is set"]
+        #[inline]
+        pub fn wait_usgfaultact(&self) {
+            while !self.get().usgfaultact() {}
+        }
+        #[doc = "Poll until the bit is set, at most `max_polls` times"]
+        #[inline]
+        pub fn wait_usgfaultact_timeout(
+            &self,
+            max_polls: u32,
+        ) -> Result<(), ::volatile_cell::wait::Timeout> {
+            ::volatile_cell::wait::poll_until(|| self.get().usgfaultact(), max_polls)
+        }
+        #[doc = "Future resolving once the bit is set"]
+        #[inline]
+        pub fn wait_usgfaultact_async<'a>(
+            &'a self,
+        ) -> ::volatile_cell::wait::WaitUntil<'a, impl FnMut() -> bool + Unpin + 'a> {
+            ::volatile_cell::wait::WaitUntil::new(move || self.get().usgfaultact())
+        }
+        #[doc = "Spin until the bit is set"]
+        #[inline]
+        pub fn wait_svcallact(&self) {
+            while !self.get().svcallact() {}
+        }
+        #[doc = "Poll until the bit is set, at most `max_polls` times"]
+        #[inline]
+        pub fn wait_svcallact_timeout(
+            &self,
+            max_polls: u32,
+        ) -> Result<(), ::volatile_cell::wait::Timeout> {
+            ::volatile_cell::wait::poll_until(|| self.get().svcallact(), max_polls)
+        }
+        #[doc = "Future resolving once the bit is set"]
+        #[inline]
+        pub fn wait_svcallact_async<'a>(
+            &'a self,
+        ) -> ::volatile_cell::wait::WaitUntil<'a, impl FnMut() -> bool + Unpin + 'a> {
+            ::volatile_cell::wait::WaitUntil::new(move || self.get().svcallact())
+        }
+        #[doc = "Spin until the bit is set"]
+        #[inline]
+        pub fn wait_monitoract(&self) {
+            while !self.get().monitoract() {}
+        }
+        #[doc = "Poll until the bit is set, at most `max_polls` times"]
+        #[inline]
+        pub fn wait_monitoract_timeout(
+            &self,
+            max_polls: u32,
+        ) -> Result<(), ::volatile_cell::wait::Timeout> {
+            ::volatile_cell::wait::poll_until(|| self.get().monitoract(), max_polls)
+        }
+        #[doc = "Future resolving once the bit is set"]
+        #[inline]
+        pub fn wait_monitoract_async<'a>(
+            &'a self,
+        ) -> ::volatile_cell::wait::WaitUntil<'a, impl FnMut() -> bool + Unpin + 'a> {
+            ::volatile_cell::wait::WaitUntil::new(move || self.get().monitoract())
+        }
+        #[doc = "Spin until the bit is set"]
+        #[inline]
+        pub fn wait_pendsvact(&self) {
+            while !self.get().pendsvact() {}
+        }
+        #[doc = "Poll until the bit is set, at most `max_polls` times"]
+        #[inline]
+        pub fn wait_pendsvact_timeout(
+            &self,
+            max_polls: u32,
+        ) -> Result<(), ::volatile_cell::wait::Timeout> {
+            ::volatile_cell::wait::poll_until(|| self.get().pendsvact(), max_polls)
+        }
+        #[doc = "Future resolving once the bit is set"]
+        #[inline]
+        pub fn wait_pendsvact_async<'a>(
+            &'a self,
+        ) -> ::volatile_cell::wait::WaitUntil<'a, impl FnMut() -> bool + Unpin + 'a> {
+            ::volatile_cell::wait::WaitUntil::new(move || self.get().pendsvact())
+        }
+        #[doc = "Spin until the bit is set"]
+        #[inline]
+        pub fn wait_systickact(&self) {
+            while !self.get().systickact() {}
+        }
+        #[doc = "Poll until the bit is set, at most `max_polls` times"]
+        #[inline]
+        pub fn wait_systickact_timeout(
+            &self,
+            max_polls: u32,
+        ) -> Result<(), ::volatile_cell::wait::Timeout> {
+            ::volatile_cell::wait::poll_until(|| self.get().systickact(), max_polls)
+        }
+        #[doc = "Future resolving once the bit is set"]
+        #[inline]
+        pub fn wait_systickact_async<'a>(
+            &'a self,
+        ) -> ::volatile_cell::wait::WaitUntil<'a, impl FnMut() -> bool + Unpin + 'a> {
+            ::volatile_cell::wait::WaitUntil::new(move || self.get().systickact())
+        }
+        #[doc = "Spin until the bit is set"]
+        #[inline]
+        pub fn wait_usgfaultpended(&self) {
+            while !self.get().usgfaultpended() {}
+        }
+        #[doc = "Poll until the bit is set, at most `max_polls` times"]
+        #[inline]
+        pub fn wait_usgfaultpended_timeout(
+            &self,
+            max_polls: u32,
+        ) -> Result<(), ::volatile_cell::wait::Timeout> {
+            ::volatile_cell::wait::poll_until(|| self.get().usgfaultpended(), max_polls)
+        }
+        #[doc = "Future resolving once the bit is set"]
+        #[inline]
+        pub fn wait_usgfaultpended_async<'a>(
+            &'a self,
+        ) -> ::volatile_cell::wait::WaitUntil<'a, impl FnMut() -> bool + Unpin + 'a> {
+            ::volatile_cell::wait::WaitUntil::new(move || self.get().usgfaultpended())
+        }
+        #[doc = "Spin until the bit is set"]
+        #[inline]
+        pub fn wait_memfaultpended(&self) {
+            while !self.get().memfaultpended() {}
+        }
+        #[doc = "Poll until the bit is set, at most `max_polls` times"]
+        #[inline]
+        pub fn wait_memfaultpended_timeout(
+            &self,
+            max_polls: u32,
+        ) -> Result<(), ::volatile_cell::wait::Timeout> {
+            ::volatile_cell::wait::poll_until(|| self.get().memfaultpended(), max_polls)
+        }
+        #[doc = "Future resolving once the bit is set"]
+        #[inline]
+        pub fn wait_memfaultpended_async<'a>(
+            &'a self,
+        ) -> ::volatile_cell::wait::WaitUntil<'a, impl FnMut() -> bool + Unpin + 'a> {
+            ::volatile_cell::wait::WaitUntil::new(move || self.get().memfaultpended())
+        }
+        #[doc = "Spin until the bit is set"]
+        #[inline]
+        pub fn wait_busfaultpended(&self) {
+            while !self.get().busfaultpended() {}
+        }
+        #[doc = "Poll until the bit is set, at most `max_polls` times"]
+        #[inline]
+        pub fn wait_busfaultpended_timeout(
+            &self,
+            max_polls: u32,
+        ) -> Result<(), ::volatile_cell::wait::Timeout> {
+            ::volatile_cell::wait::poll_until(|| self.get().busfaultpended(), max_polls)
+        }
+        #[doc = "Future resolving once the bit is set"]
+        #[inline]
+        pub fn wait_busfaultpended_async<'a>(
+            &'a self,
+        ) -> ::volatile_cell::wait::WaitUntil<'a, impl FnMut() -> bool + Unpin + 'a> {
+            ::volatile_cell::wait::WaitUntil::new(move || self.get().busfaultpended())
+        }
+        #[doc = "Spin until the bit is set"]
+        #[inline]
+        pub fn wait_svfaultpended(&self) {
+            while !self.get().svfaultpended() {}
+        }
+        #[doc = "Poll until the bit is set, at most `max_polls` times"]
+        #[inline]
+        pub fn wait_svfaultpended_timeout(
+            &self,
+            max_polls: u32,
+        ) -> Result<(), ::volatile_cell::wait::Timeout> {
+            ::volatile_cell::wait::poll_until(|| self.get().svfaultpended(), max_polls)
+        }
+        #[doc = "Future resolving once the bit is set"]
+        #[inline]
+        pub fn wait_svfaultpended_async<'a>(
+            &'a self,
+        ) -> ::volatile_cell::wait::WaitUntil<'a, impl FnMut() -> bool + Unpin + 'a> {
+            ::volatile_cell::wait::WaitUntil::new(move || self.get().svfaultpended())
+        }
+        #[doc = "Spin until the bit is set"]
+        #[inline]
+        pub fn wait_memfaultpendena(&self) {
+            while !self.get().memfaultpendena() {}
+        }
+        #[doc = "Poll until the bit is set, at most `max_polls` times"]
+        #[inline]
+        pub fn wait_memfaultpendena_timeout(
+            &self,
+            max_polls: u32,
+        ) -> Result<(), ::volatile_cell::wait::Timeout> {
+            ::volatile_cell::wait::poll_until(|| self.get().memfaultpendena(), max_polls)
+        }
+        #[doc = "Future resolving once the bit is set"]
+        #[inline]
+        pub fn wait_memfaultpendena_async<'a>(
+            &'a self,
+        ) -> ::volatile_cell::wait::WaitUntil<'a, impl FnMut() -> bool + Unpin + 'a> {
+            ::volatile_cell::wait::WaitUntil::new(move || self.get().memfaultpendena())
+        }
+        #[doc = "Spin until the bit is set"]
+        #[inline]
+        pub fn wait_busfaultena(&self) {
+            while !self.get().busfaultena() {}
+        }
+        #[doc = "Poll until the bit is set, at most `max_polls` times"]
+        #[inline]
+        pub fn wait_busfaultena_timeout(
+            &self,
+            max_polls: u32,
+        ) -> Result<(), ::volatile_cell::wait::Timeout> {
+            ::volatile_cell::wait::poll_until(|| self.get().busfaultena(), max_polls)
+        }
+        #[doc = "Future resolving once the bit is set"]
+        #[inline]
+        pub fn wait_busfaultena_async<'a>(
+            &'a self,
+        ) -> ::volatile_cell::wait::WaitUntil<'a, impl FnMut() -> bool + Unpin + 'a> {
+            ::volatile_cell::wait::WaitUntil::new(move || self.get().busfaultena())
+        }
+        #[doc = "Spin until the bit is set"]
+        #[inline]
+        pub fn wait_usgfaultena(&self) {
+            while !self.get().usgfaultena() {}
+        }
+        #[doc = "Poll until the bit is set, at most `max_polls` times"]
+        #[inline]
+        pub fn wait_usgfaultena_timeout(
+            &self,
+            max_polls: u32,
+        ) -> Result<(), ::volatile_cell::wait::Timeout> {
+            ::volatile_cell::wait::poll_until(|| self.get().usgfaultena(), max_polls)
+        }
+        #[doc = "Future resolving once the bit is set"]
+        #[inline]
+        pub fn wait_usgfaultena_async<'a>(
+            &'a self,
+        ) -> ::volatile_cell::wait::WaitUntil<'a, impl FnMut() -> bool + Unpin + 'a> {
+            ::volatile_cell::wait::WaitUntil::new(move || self.get().usgfaultena())
+        }
+    }
+    pub struct ShcsrUpdate<'a> {
+        value: u32,
+        mask: u32,
+        write_only: bool,
+        store_on_drop: bool,
+        reg: &'a Shcsr,
+    }
+    impl<'a> ShcsrUpdate<'a> {
+        #[inline(always)]
+        pub fn new(reg: &'a Shcsr) -> ShcsrUpdate<'a> {
+            ShcsrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[inline(always)]
+        pub fn new_ignoring_state(reg: &'a Shcsr) -> ShcsrUpdate<'a> {
+            ShcsrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: true,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[doc = "An update that is never stored, for the caller to store itself"]
+        #[inline(always)]
+        fn detached(reg: &'a Shcsr) -> ShcsrUpdate<'a> {
+            ShcsrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: false,
+                reg: reg,
+            }
+        }
+        const fn clear_mask() -> u32 {
+            0u64 as u32
+        }
+        const fn update_offset(base: usize, size: usize, index: usize) -> usize {
+            base + (size * index)
+        }
+        #[inline(always)]
+        fn merge(&self, current: u32) -> u32 {
+            let v: u32 = current & (!Self::clear_mask()) & (!self.mask);
+            self.value | v
+        }
+        #[inline(always)]
+        pub fn set_memfaultact<'b>(&'b mut self, new_value: bool) -> &'b mut Self {
+            let context_mask: u32 = 1 << 0;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 1) << 0);
+            self.mask |= context_mask;
+            self
+        }
+        #[inline(always)]
+        pub fn set_busfaultact<'b>(&'b mut self, new_value: bool) -> &'b mut Self {
+            let context_mask: u32 = 1 << 1;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 1) << 1);
+            self.mask |= context_mask;
+            self
+        }
+        #[inline(always)]
+        pub fn set_usgfaultact<'b>(&'b mut self, new_value: bool) -> &'b mut Self {
+            let context_mask: u32 = 1 << 3;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 1) << 3);
+            self.mask |= context_mask;
+            self
+        }
+        #[inline(always)]
+        pub fn set_svcallact<'b>(&'b mut self, new_value: bool) -> &'b mut Self {
+            let context_mask: u32 = 1 << 7;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 1) << 7);
+            self.mask |= context_mask;
+            self
+        }
+        #[inline(always)]
+        pub fn set_monitoract<'b>(&'b mut self, new_value: bool) -> &'b mut Self {
+            let context_mask: u32 = 1 << 8;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 1) << 8);
+            self.mask |= context_mask;
+            self
+        }
+        #[inline(always)]
+        pub fn set_pendsvact<'b>(&'b mut self, new_value: bool) -> &'b mut Self {
+            let context_mask: u32 = 1 << 10;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 1) << 10);
+            self.mask |= context_mask;
+            self
+        }
+        #[inline(always)]
+        pub fn set_systickact<'b>(&'b mut self, new_value: bool) -> &'b mut Self {
+            let context_mask: u32 = 1 << 11;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 1) << 11);
+            self.mask |= context_mask;
+            self
+        }
+        #[inline(always)]
+        pub fn set_usgfaultpended<'b>(&'b mut self, new_value: bool) -> &'b mut Self {
+            let context_mask: u32 = 1 << 12;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 1) << 12);
+            self.mask |= context_mask;
+            self
+        }
+        #[inline(always)]
+        pub fn set_memfaultpended<'b>(&'b mut self, new_value: bool) -> &'b mut Self {
+            let context_mask: u32 = 1 << 13;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 1) << 13);
+            self.mask |= context_mask;
+            self
+        }
+        #[inline(always)]
+        pub fn set_busfaultpended<'b>(&'b mut self, new_value: bool) -> &'b mut Self {
+            let context_mask: u32 = 1 << 14;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 1) << 14);
+            self.mask |= context_mask;
+            self
+        }
+        #[inline(always)]
+        pub fn set_svfaultpended<'b>(&'b mut self, new_value: bool) -> &'b mut Self {
+            let context_mask: u32 = 1 << 15;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 1) << 15);
+            self.mask |= context_mask;
+            self
+        }
+        #[inline(always)]
+        pub fn set_memfaultpendena<'b>(&'b mut self, new_value: bool) -> &'b mut Self {
+            let context_mask: u32 = 1 << 16;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 1) << 16);
+            self.mask |= context_mask;
+            self
+        }
+        #[inline(always)]
+        pub fn set_busfaultena<'b>(&'b mut self, new_value: bool) -> &'b mut Self {
+            let context_mask: u32 = 1 << 17;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 1) << 17);
+            self.mask |= context_mask;
+            self
+        }
+        #[inline(always)]
+        pub fn set_usgfaultena<'b>(&'b mut self, new_value: bool) -> &'b mut Self {
+            let context_mask: u32 = 1 << 18;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 1) << 18);
+            self.mask |= context_mask;
+            self
+        }
+    }
+    impl<'a> Drop for ShcsrUpdate<'a> {
+        #[inline(always)]
+        fn drop(&mut self) {
+            if self.store_on_drop && self.mask != 0 {
+                let current: u32 = if self.write_only { 0 } else { self.reg.load() };
+                self.reg.store(self.merge(current));
+            }
+        }
+    }
+    #[derive(Clone, PartialEq, Eq)]
+    pub struct ShcsrGet {
+        value: u32,
+    }
+    impl ShcsrGet {
+        #[doc = "Create a getter reflecting the current value of the register"]
+        #[inline(always)]
+        pub fn new(reg: &Shcsr) -> ShcsrGet {
+            ShcsrGet { value: reg.load() }
+        }
+        #[inline(always)]
+        pub fn memfaultact(&self) -> bool {
+            let val = (self.value & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub fn busfaultact(&self) -> bool {
+            let val = ((self.value >> 1) & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub fn usgfaultact(&self) -> bool {
+            let val = ((self.value >> 3) & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub fn svcallact(&self) -> bool {
+            let val = ((self.value >> 7) & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub fn monitoract(&self) -> bool {
+            let val = ((self.value >> 8) & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub fn pendsvact(&self) -> bool {
+            let val = ((self.value >> 10) & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub fn systickact(&self) -> bool {
+            let val = ((self.value >> 11) & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub fn usgfaultpended(&self) -> bool {
+            let val = ((self.value >> 12) & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub fn memfaultpended(&self) -> bool {
+            let val = ((self.value >> 13) & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub fn busfaultpended(&self) -> bool {
+            let val = ((self.value >> 14) & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub fn svfaultpended(&self) -> bool {
+            let val = ((self.value >> 15) & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub fn memfaultpendena(&self) -> bool {
+            let val = ((self.value >> 16) & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub fn busfaultena(&self) -> bool {
+            let val = ((self.value >> 17) & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub fn usgfaultena(&self) -> bool {
+            let val = ((self.value >> 18) & 1);
+            val != 0x0
+        }
+    }
+    #[derive(Clone, Copy, PartialEq, Eq, Default)]
+    #[repr(transparent)]
+    pub struct ShcsrValue {
+        value: u32,
+    }
+    impl ShcsrValue {
+        #[doc = "Create a value with every field cleared"]
+        #[inline(always)]
+        pub const fn new() -> ShcsrValue {
+            ShcsrValue { value: 0 }
+        }
+        #[inline(always)]
+        pub const fn from_bits(bits: u32) -> ShcsrValue {
+            ShcsrValue { value: bits }
+        }
+        #[inline(always)]
+        pub const fn to_bits(&self) -> u32 {
+            self.value
+        }
+        #[inline(always)]
+        pub fn memfaultact(&self) -> bool {
+            let val = (self.value & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub fn busfaultact(&self) -> bool {
+            let val = ((self.value >> 1) & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub fn usgfaultact(&self) -> bool {
+            let val = ((self.value >> 3) & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub fn svcallact(&self) -> bool {
+            let val = ((self.value >> 7) & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub fn monitoract(&self) -> bool {
+            let val = ((self.value >> 8) & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub fn pendsvact(&self) -> bool {
+            let val = ((self.value >> 10) & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub fn systickact(&self) -> bool {
+            let val = ((self.value >> 11) & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub fn usgfaultpended(&self) -> bool {
+            let val = ((self.value >> 12) & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub fn memfaultpended(&self) -> bool {
+            let val = ((self.value >> 13) & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub fn busfaultpended(&self) -> bool {
+            let val = ((self.value >> 14) & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub fn svfaultpended(&self) -> bool {
+            let val = ((self.value >> 15) & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub fn memfaultpendena(&self) -> bool {
+            let val = ((self.value >> 16) & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub fn busfaultena(&self) -> bool {
+            let val = ((self.value >> 17) & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub fn usgfaultena(&self) -> bool {
+            let val = ((self.value >> 18) & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub const fn with_memfaultact(self, new_value: bool) -> Self {
+            let context_mask: u32 = 1 << 0;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 1) << 0),
+            }
+        }
+        #[inline(always)]
+        pub const fn with_busfaultact(self, new_value: bool) -> Self {
+            let context_mask: u32 = 1 << 1;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 1) << 1),
+            }
+        }
+        #[inline(always)]
+        pub const fn with_usgfaultact(self, new_value: bool) -> Self {
+            let context_mask: u32 = 1 << 3;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 1) << 3),
+            }
+        }
+        #[inline(always)]
+        pub const fn with_svcallact(self, new_value: bool) -> Self {
+            let context_mask: u32 = 1 << 7;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 1) << 7),
+            }
+        }
+        #[inline(always)]
+        pub const fn with_monitoract(self, new_value: bool) -> Self {
+            let context_mask: u32 = 1 << 8;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 1) << 8),
+            }
+        }
+        #[inline(always)]
+        pub const fn with_pendsvact(self, new_value: bool) -> Self {
+            let context_mask: u32 = 1 << 10;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 1) << 10),
+            }
+        }
+        #[inline(always)]
+        pub const fn with_systickact(self, new_value: bool) -> Self {
+            let context_mask: u32 = 1 << 11;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 1) << 11),
+            }
+        }
+        #[inline(always)]
+        pub const fn with_usgfaultpended(self, new_value: bool) -> Self {
+            let context_mask: u32 = 1 << 12;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 1) << 12),
+            }
+        }
+        #[inline(always)]
+        pub const fn with_memfaultpended(self, new_value: bool) -> Self {
+            let context_mask: u32 = 1 << 13;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 1) << 13),
+            }
+        }
+        #[inline(always)]
+        pub const fn with_busfaultpended(self, new_value: bool) -> Self {
+            let context_mask: u32 = 1 << 14;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 1) << 14),
+            }
+        }
+        #[inline(always)]
+        pub const fn with_svfaultpended(self, new_value: bool) -> Self {
+            let context_mask: u32 = 1 << 15;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 1) << 15),
+            }
+        }
+        #[inline(always)]
+        pub const fn with_memfaultpendena(self, new_value: bool) -> Self {
+            let context_mask: u32 = 1 << 16;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 1) << 16),
+            }
+        }
+        #[inline(always)]
+        pub const fn with_busfaultena(self, new_value: bool) -> Self {
+            let context_mask: u32 = 1 << 17;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 1) << 17),
+            }
+        }
+        #[inline(always)]
+        pub const fn with_usgfaultena(self, new_value: bool) -> Self {
+            let context_mask: u32 = 1 << 18;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 1) << 18),
+            }
+        }
+    }
+    impl From<ShcsrGet> for ShcsrValue {
+        #[inline(always)]
+        fn from(get: ShcsrGet) -> ShcsrValue {
+            ShcsrValue::from_bits(get.value)
+        }
+    }
+    impl From<ShcsrValue> for ShcsrGet {
+        #[inline(always)]
+        fn from(value: ShcsrValue) -> ShcsrGet {
+            ShcsrGet {
+                value: value.to_bits(),
+            }
+        }
+    }
+    impl Shcsr {
+        #[doc = "Write `value` to the register in a single store, without reading it first"]
+        #[inline(always)]
+        pub fn write(&self, value: ShcsrValue) {
+            self.store(value.to_bits());
+        }
+    }
+    impl<'a> ShcsrUpdate<'a> {
+        #[doc = "Replace every field of the register with those of `value`"]
+        #[inline(always)]
+        pub fn assign<'b>(&'b mut self, value: ShcsrValue) -> &'b mut Self {
+            self.value = value.to_bits();
+            self.mask = !0;
+            self
+        }
+    }
+    impl ShcsrGet {
+        pub const FIELDS: &'static [::volatile_cell::field::FieldDescriptor] = &[
+            ::volatile_cell::field::FieldDescriptor {
+                name: "memfaultact",
+                offset: 0u32,
+                width: 1u32,
+                count: 1u32,
+            },
+            ::volatile_cell::field::FieldDescriptor {
+                name: "busfaultact",
+                offset: 1u32,
+                width: 1u32,
+                count: 1u32,
+            },
+            ::volatile_cell::field::FieldDescriptor {
+                name: "usgfaultact",
+                offset: 3u32,
+                width: 1u32,
+                count: 1u32,
+            },
+            ::volatile_cell::field::FieldDescriptor {
+                name: "svcallact",
+                offset: 7u32,
+                width: 1u32,
+                count: 1u32,
+            },
+            ::volatile_cell::field::FieldDescriptor {
+                name: "monitoract",
+                offset: 8u32,
+                width: 1u32,
+                count: 1u32,
+            },
+            ::volatile_cell::field::FieldDescriptor {
+                name: "pendsvact",
+                offset: 10u32,
+                width: 1u32,
+                count: 1u32,
+            },
+            ::volatile_cell::field::FieldDescriptor {
+                name: "systickact",
+                offset: 11u32,
+                width: 1u32,
+                count: 1u32,
+            },
+            ::volatile_cell::field::FieldDescriptor {
+                name: "usgfaultpended",
+                offset: 12u32,
+                width: 1u32,
+                count: 1u32,
+            },
+            ::volatile_cell::field::FieldDescriptor {
+                name: "memfaultpended",
+                offset: 13u32,
+                width: 1u32,
+                count: 1u32,
+            },
+            ::volatile_cell::field::FieldDescriptor {
+                name: "busfaultpended",
+                offset: 14u32,
+                width: 1u32,
+                count: 1u32,
+            },
+            ::volatile_cell::field::FieldDescriptor {
+                name: "svfaultpended",
+                offset: 15u32,
+                width: 1u32,
+                count: 1u32,
+            },
+            ::volatile_cell::field::FieldDescriptor {
+                name: "memfaultpendena",
+                offset: 16u32,
+                width: 1u32,
+                count: 1u32,
+            },
+            ::volatile_cell::field::FieldDescriptor {
+                name: "busfaultena",
+                offset: 17u32,
+                width: 1u32,
+                count: 1u32,
+            },
+            ::volatile_cell::field::FieldDescriptor {
+                name: "usgfaultena",
+                offset: 18u32,
+                width: 1u32,
+                count: 1u32,
+            },
+        ];
+        #[doc = "Iterate over the readable fields that differ from those of `other`"]
+        #[inline]
+        pub fn changed_fields(&self, other: &ShcsrGet) -> ::volatile_cell::field::FieldChanges {
+            ::volatile_cell::field::FieldChanges::new(
+                Self::FIELDS,
+                self.value as u64,
+                other.value as u64,
+            )
+        }
+    }
+    pub mod cfsr {}
+    #[repr(C)]
+    pub struct Cfsr {
+        value: ::volatile_cell::VolatileCell<u32>,
+    }
+    impl Cfsr {
+        #[inline(always)]
+        fn load(&self) -> u32 {
+            let value = self.value.get();
+            value
+        }
+        #[inline(always)]
+        fn store(&self, value: u32) {
+            self.value.set(value);
+        }
+        #[doc = "Create a new updater"]
+        #[inline(always)]
+        pub fn update<'a>(&'a self) -> CfsrUpdate<'a> {
+            CfsrUpdate::new(self)
+        }
+        #[doc = "Create an updater whose read-modify-write happens inside the critical section `cs`"]
+        #[inline(always)]
+        pub fn update_in<'a, C: ::volatile_cell::sync::CriticalSection>(
+            &'a self,
+            _cs: &'a C,
+        ) -> CfsrUpdate<'a> {
+            CfsrUpdate::new(self)
+        }
+        #[doc = "Update the register with exclusive loads and stores (LDREX/STREX), retrying `f` until no other access intervened"]
+        #[doc = ""]
+        #[doc = "The exclusive accesses go to the register's memory directly rather than through its cell, so they aren't seen by the replayer or the tracing hook."]
+        #[cfg(target_has_atomic = "32")]
+        #[inline]
+        pub fn update_exclusive<F: FnMut(&mut CfsrUpdate)>(&self, mut f: F) {
+            use ::core::sync::atomic::Ordering;
+            let cell = unsafe { &*(self.value.as_ptr() as *const ::core::sync::atomic::AtomicU32) };
+            let mut current = cell.load(Ordering::Relaxed);
+            loop {
+                let mut update = CfsrUpdate::detached(self);
+                f(&mut update);
+                let merged = update.merge(current);
+                if update.mask == 0 {
+                    return;
+                }
+                match cell.compare_exchange_weak(
+                    current,
+                    merged,
+                    Ordering::SeqCst,
+                    Ordering::Relaxed,
+                ) {
+                    Ok(_) => return,
+                    Err(actual) => current = actual,
+                }
+            }
+        }
+        #[doc = "Create a getter representing the current state of the register"]
+        #[inline(always)]
+        pub fn get(&self) -> CfsrGet {
+            CfsrGet::new(self)
+        }
+    }
+    pub struct CfsrUpdate<'a> {
+        value: u32,
+        mask: u32,
+        write_only: bool,
+        store_on_drop: bool,
+        reg: &'a Cfsr,
+    }
+    impl<'a> CfsrUpdate<'a> {
+        #[inline(always)]
+        pub fn new(reg: &'a Cfsr) -> CfsrUpdate<'a> {
+            CfsrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[inline(always)]
+        pub fn new_ignoring_state(reg: &'a Cfsr) -> CfsrUpdate<'a> {
+            CfsrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: true,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[doc = "An update that is never stored, for the caller to store itself"]
+        #[inline(always)]
+        fn detached(reg: &'a Cfsr) -> CfsrUpdate<'a> {
+            CfsrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: false,
+                reg: reg,
+            }
+        }
+        const fn clear_mask() -> u32 {
+            0u64 as u32
+        }
+        const fn update_offset(base: usize, size: usize, index: usize) -> usize {
+            base + (size * index)
+        }
+        #[inline(always)]
+        fn merge(&self, current: u32) -> u32 {
+            let v: u32 = current & (!Self::clear_mask()) & (!self.mask);
+            self.value | v
+        }
+        #[inline(always)]
+        pub fn set_memmanage<'b>(&'b mut self, new_value: u8) -> &'b mut Self {
+            let context_mask: u32 = 255 << 0;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 255) << 0);
+            self.mask |= context_mask;
+            self
+        }
+        #[inline(always)]
+        pub fn set_busfault<'b>(&'b mut self, new_value: u8) -> &'b mut Self {
+            let context_mask: u32 = 255 << 8;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 255) << 8);
+            self.mask |= context_mask;
+            self
+        }
+        #[inline(always)]
+        pub fn set_usagefault<'b>(&'b mut self, new_value: u16) -> &'b mut Self {
+            let context_mask: u32 = 65535 << 16;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 65535) << 16);
+            self.mask |= context_mask;
+            self
+        }
+    }
+    impl<'a> Drop for CfsrUpdate<'a> {
+        #[inline(always)]
+        fn drop(&mut self) {
+            if self.store_on_drop && self.mask != 0 {
+                let current: u32 = if self.write_only { 0 } else { self.reg.load() };
+                self.reg.store(self.merge(current));
+            }
+        }
+    }
+    #[derive(Clone, PartialEq, Eq)]
+    pub struct CfsrGet {
+        value: u32,
+    }
+    impl CfsrGet {
+        #[doc = "Create a getter reflecting the current value of the register"]
+        #[inline(always)]
+        pub fn new(reg: &Cfsr) -> CfsrGet {
+            CfsrGet { value: reg.load() }
+        }
+        #[inline(always)]
+        pub fn memmanage(&self) -> u32 {
+            (self.value & 255)
+        }
+        #[inline(always)]
+        pub fn busfault(&self) -> u32 {
+            ((self.value >> 8) & 255)
+        }
+        #[inline(always)]
+        pub fn usagefault(&self) -> u32 {
+            (self.value >> 16)
+        }
+    }
+    #[derive(Clone, Copy, PartialEq, Eq, Default)]
+    #[repr(transparent)]
+    pub struct CfsrValue {
+        value: u32,
+    }
+    impl CfsrValue {
+        #[doc = "Create a value with every field cleared"]
+        #[inline(always)]
+        pub const fn new() -> CfsrValue {
+            CfsrValue { value: 0 }
+        }
+        #[inline(always)]
+        pub const fn from_bits(bits: u32) -> CfsrValue {
+            CfsrValue { value: bits }
+        }
+        #[inline(always)]
+        pub const fn to_bits(&self) -> u32 {
+            self.value
+        }
+        #[inline(always)]
+        pub fn memmanage(&self) -> u32 {
+            (self.value & 255)
+        }
+        #[inline(always)]
+        pub fn busfault(&self) -> u32 {
+            ((self.value >> 8) & 255)
+        }
+        #[inline(always)]
+        pub fn usagefault(&self) -> u32 {
+            (self.value >> 16)
+        }
+        #[inline(always)]
+        pub const fn with_memmanage(self, new_value: u8) -> Self {
+            let context_mask: u32 = 255 << 0;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 255) << 0),
+            }
+        }
+        #[inline(always)]
+        pub const fn with_busfault(self, new_value: u8) -> Self {
+            let context_mask: u32 = 255 << 8;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 255) << 8),
+            }
+        }
+        #[inline(always)]
+        pub const fn with_usagefault(self, new_value: u16) -> Self {
+            let context_mask: u32 = 65535 << 16;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 65535) << 16),
+            }
+        }
+    }
+    impl From<CfsrGet> for CfsrValue {
+        #[inline(always)]
+        fn from(get: CfsrGet) -> CfsrValue {
+            CfsrValue::from_bits(get.value)
+        }
+    }
+    impl From<CfsrValue> for CfsrGet {
+        #[inline(always)]
+        fn from(value: CfsrValue) -> CfsrGet {
+            CfsrGet {
+                value: value.to_bits(),
+            }
+        }
+    }
+    impl Cfsr {
+        #[doc = "Write `value` to the register in a single store, without reading it first"]
+        #[inline(always)]
+        pub fn write(&self, value: CfsrValue) {
+            self.store(value.to_bits());
+        }
+    }
+    impl<'a> CfsrUpdate<'a> {
+        #[doc = "Replace every field of the register with those of `value`"]
+        #[inline(always)]
+        pub fn assign<'b>(&'b mut self, value: CfsrValue) -> &'b mut Self {
+            self.value = value.to_bits();
+            self.mask = !0;
+            self
+        }
+    }
+    impl CfsrGet {
+        pub const FIELDS: &'static [::volatile_cell::field::FieldDescriptor] = &[
+            ::volatile_cell::field::FieldDescriptor {
+                name: "memmanage",
+                offset: 0u32,
+                width: 8u32,
+                count: 1u32,
+            },
+            ::volatile_cell::field::FieldDescriptor {
+                name: "busfault",
+                offset: 8u32,
+                width: 8u32,
+                count: 1u32,
+            },
+            ::volatile_cell::field::FieldDescriptor {
+                name: "usagefault",
+                offset: 16u32,
+                width: 16u32,
+                count: 1u32,
+            },
+        ];
+        #[doc = "Iterate over the readable fields that differ from those of `other`"]
+        #[inline]
+        pub fn changed_fields(&self, other: &CfsrGet) -> ::volatile_cell::field::FieldChanges {
+            ::volatile_cell::field::FieldChanges::new(
+                Self::FIELDS,
+                self.value as u64,
+                other.value as u64,
+            )
+        }
+    }
+    pub mod hfsr {}
+    #[repr(C)]
+    pub struct Hfsr {
+        value: ::volatile_cell::VolatileCell<u32>,
+    }
+    impl Hfsr {
+        #[inline(always)]
+        fn load(&self) -> u32 {
+            let value = self.value.get();
+            value
+        }
+        #[inline(always)]
+        fn store(&self, value: u32) {
+            self.value.set(value);
+        }
+        #[doc = "Create a new updater"]
+        #[inline(always)]
+        pub fn update<'a>(&'a self) -> HfsrUpdate<'a> {
+            HfsrUpdate::new(self)
+        }
+        #[doc = "Create an updater whose read-modify-write happens inside the critical section `cs`"]
+        #[inline(always)]
+        pub fn update_in<'a, C: ::volatile_cell::sync::CriticalSection>(
+            &'a self,
+            _cs: &'a C,
+        ) -> HfsrUpdate<'a> {
+            HfsrUpdate::new(self)
+        }
+        #[doc = "Update the register with exclusive loads and stores (LDREX/STREX), retrying `f` until no other access intervened"]
+        #[doc = ""]
+        #[doc = "The exclusive accesses go to the register's memory directly rather than through its cell, so they aren't seen by the replayer or the tracing hook."]
+        #[cfg(target_has_atomic = "32")]
+        #[inline]
+        pub fn update_exclusive<F: FnMut(&mut HfsrUpdate)>(&self, mut f: F) {
+            use ::core::sync::atomic::Ordering;
+            let cell = unsafe { &*(self.value.as_ptr() as *const ::core::sync::atomic::AtomicU32) };
+            let mut current = cell.load(Ordering::Relaxed);
+            loop {
+                let mut update = HfsrUpdate::detached(self);
+                f(&mut update);
+                let merged = update.merge(current);
+                if update.mask == 0 {
+                    return;
+                }
+                match cell.compare_exchange_weak(
+                    current,
+                    merged,
+                    Ordering::SeqCst,
+                    Ordering::Relaxed,
+                ) {
+                    Ok(_) => return,
+                    Err(actual) => current = actual,
+                }
+            }
+        }
+        #[doc = "Create a getter representing the current state of the register"]
+        #[inline(always)]
+        pub fn get(&self) -> HfsrGet {
+            HfsrGet::new(self)
+        }
+        #[doc = "Spin until the bit is set"]
+        #[inline]
+        pub fn wait_vecttbl(&self) {
+            while !self.get().vecttbl() {}
+        }
+        #[doc = "Poll until the bit is set, at most `max_polls` times"]
+        #[inline]
+        pub fn wait_vecttbl_timeout(
+            &self,
+            max_polls: u32,
+        ) -> Result<(), ::volatile_cell::wait::Timeout> {
+            ::volatile_cell::wait::poll_until(|| self.get().vecttbl(), max_polls)
+        }
+        #[doc = "Future resolving once the bit is set"]
+        #[inline]
+        pub fn wait_vecttbl_async<'a>(
+            &'a self,
+        ) -> ::volatile_cell::wait::WaitUntil<'a, impl FnMut() -> bool + Unpin + 'a> {
+            ::volatile_cell::wait::WaitUntil::new(move || self.get().vecttbl())
+        }
+        #[doc = "Spin until the bit is set"]
+        #[inline]
+        pub fn wait_forced(&self) {
+            while !self.get().forced() {}
+        }
+        #[doc = "Poll until the bit is set, at most `max_polls` times"]
+        #[inline]
+        pub fn wait_forced_timeout(
+            &self,
+            max_polls: u32,
+        ) -> Result<(), ::volatile_cell::wait::Timeout> {
+            ::volatile_cell::wait::poll_until(|| self.get().forced(), max_polls)
+        }
+        #[doc = "Future resolving once the bit is set"]
+        #[inline]
+        pub fn wait_forced_async<'a>(
+            &'a self,
+        ) -> ::volatile_cell::wait::WaitUntil<'a, impl FnMut() -> bool + Unpin + 'a> {
+            ::volatile_cell::wait::WaitUntil::new(move || self.get().forced())
+        }
+        #[doc = "Spin until the bit is set"]
+        #[inline]
+        pub fn wait_debugevt(&self) {
+            while !self.get().debugevt() {}
+        }
+        #[doc = "Poll until the bit is set, at most `max_polls` times"]
+        #[inline]
+        pub fn wait_debugevt_timeout(
+            &self,
+            max_polls: u32,
+        ) -> Result<(), ::volatile_cell::wait::Timeout> {
+            ::volatile_cell::wait::poll_until(|| self.get().debugevt(), max_polls)
+        }
+        #[doc = "Future resolving once the bit is set"]
+        #[inline]
+        pub fn wait_debugevt_async<'a>(
+            &'a self,
+        ) -> ::volatile_cell::wait::WaitUntil<'a, impl FnMut() -> bool + Unpin + 'a> {
+            ::volatile_cell::wait::WaitUntil::new(move || self.get().debugevt())
+        }
+    }
+    pub struct HfsrUpdate<'a> {
+        value: u32,
+        mask: u32,
+        write_only: bool,
+        store_on_drop: bool,
+        reg: &'a Hfsr,
+    }
+    impl<'a> HfsrUpdate<'a> {
+        #[inline(always)]
+        pub fn new(reg: &'a Hfsr) -> HfsrUpdate<'a> {
+            HfsrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[inline(always)]
+        pub fn new_ignoring_state(reg: &'a Hfsr) -> HfsrUpdate<'a> {
+            HfsrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: true,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[doc = "An update that is never stored, for the caller to store itself"]
+        #[inline(always)]
+        fn detached(reg: &'a Hfsr) -> HfsrUpdate<'a> {
+            HfsrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: false,
+                reg: reg,
+            }
+        }
+        const fn clear_mask() -> u32 {
+            0u64 as u32
+        }
+        const fn update_offset(base: usize, size: usize, index: usize) -> usize {
+            base + (size * index)
+        }
+        #[inline(always)]
+        fn merge(&self, current: u32) -> u32 {
+            let v: u32 = current & (!Self::clear_mask()) & (!self.mask);
+            self.value | v
+        }
+        #[inline(always)]
+        pub fn set_vecttbl<'b>(&'b mut self, new_value: bool) -> &'b mut Self {
+            let context_mask: u32 = 1 << 1;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 1) << 1);
+            self.mask |= context_mask;
+            self
+        }
+        #[inline(always)]
+        pub fn set_forced<'b>(&'b mut self, new_value: bool) -> &'b mut Self {
+            let context_mask: u32 = 1 << 30;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 1) << 30);
+            self.mask |= context_mask;
+            self
+        }
+        #[inline(always)]
+        pub fn set_debugevt<'b>(&'b mut self, new_value: bool) -> &'b mut Self {
+            let context_mask: u32 = 1 << 31;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 1) << 31);
+            self.mask |= context_mask;
+            self
+        }
+    }
+    impl<'a> Drop for HfsrUpdate<'a> {
+        #[inline(always)]
+        fn drop(&mut self) {
+            if self.store_on_drop && self.mask != 0 {
+                let current: u32 = if self.write_only { 0 } else { self.reg.load() };
+                self.reg.store(self.merge(current));
+            }
+        }
+    }
+    #[derive(Clone, PartialEq, Eq)]
+    pub struct HfsrGet {
+        value: u32,
+    }
+    impl HfsrGet {
+        #[doc = "Create a getter reflecting the current value of the register"]
+        #[inline(always)]
+        pub fn new(reg: &Hfsr) -> HfsrGet {
+            HfsrGet { value: reg.load() }
+        }
+        #[inline(always)]
+        pub fn vecttbl(&self) -> bool {
+            let val = ((self.value >> 1) & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub fn forced(&self) -> bool {
+            let val = ((self.value >> 30) & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub fn debugevt(&self) -> bool {
+            let val = (self.value >> 31);
+            val != 0x0
+        }
+    }
+    #[derive(Clone, Copy, PartialEq, Eq, Default)]
+    #[repr(transparent)]
+    pub struct HfsrValue {
+        value: u32,
+    }
+    impl HfsrValue {
+        #[doc = "Create a value with every field cleared"]
+        #[inline(always)]
+        pub const fn new() -> HfsrValue {
+            HfsrValue { value: 0 }
+        }
+        #[inline(always)]
+        pub const fn from_bits(bits: u32) -> HfsrValue {
+            HfsrValue { value: bits }
+        }
+        #[inline(always)]
+        pub const fn to_bits(&self) -> u32 {
+            self.value
+        }
+        #[inline(always)]
+        pub fn vecttbl(&self) -> bool {
+            let val = ((self.value >> 1) & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub fn forced(&self) -> bool {
+            let val = ((self.value >> 30) & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub fn debugevt(&self) -> bool {
+            let val = (self.value >> 31);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub const fn with_vecttbl(self, new_value: bool) -> Self {
+            let context_mask: u32 = 1 << 1;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 1) << 1),
+            }
+        }
+        #[inline(always)]
+        pub const fn with_forced(self, new_value: bool) -> Self {
+            let context_mask: u32 = 1 << 30;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 1) << 30),
+            }
+        }
+        #[inline(always)]
+        pub const fn with_debugevt(self, new_value: bool) -> Self {
+            let context_mask: u32 = 1 << 31;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 1) << 31),
+            }
+        }
+    }
+    impl From<HfsrGet> for HfsrValue {
+        #[inline(always)]
+        fn from(get: HfsrGet) -> HfsrValue {
+            HfsrValue::from_bits(get.value)
+        }
+    }
+    impl From<HfsrValue> for HfsrGet {
+        #[inline(always)]
+        fn from(value: HfsrValue) -> HfsrGet {
+            HfsrGet {
+                value: value.to_bits(),
+            }
+        }
+    }
+    impl Hfsr {
+        #[doc = "Write `value` to the register in a single store, without reading it first"]
+        #[inline(always)]
+        pub fn write(&self, value: HfsrValue) {
+            self.store(value.to_bits());
+        }
+    }
+    impl<'a> HfsrUpdate<'a> {
+        #[doc = "Replace every field of the register with those of `value`"]
+        #[inline(always)]
+        pub fn assign<'b>(&'b mut self, value: HfsrValue) -> &'b mut Self {
+            self.value = value.to_bits();
+            self.mask = !0;
+            self
+        }
+    }
+    impl HfsrGet {
+        pub const FIELDS: &'static [::volatile_cell::field::FieldDescriptor] = &[
+            ::volatile_cell::field::FieldDescriptor {
+                name: "vecttbl",
+                offset: 1u32,
+                width: 1u32,
+                count: 1u32,
+            },
+            ::volatile_cell::field::FieldDescriptor {
+                name: "forced",
+                offset: 30u32,
+                width: 1u32,
+                count: 1u32,
+            },
+            ::volatile_cell::field::FieldDescriptor {
+                name: "debugevt",
+                offset: 31u32,
+                width: 1u32,
+                count: 1u32,
+            },
+        ];
+        #[doc = "Iterate over the readable fields that differ from those of `other`"]
+        #[inline]
+        pub fn changed_fields(&self, other: &HfsrGet) -> ::volatile_cell::field::FieldChanges {
+            ::volatile_cell::field::FieldChanges::new(
+                Self::FIELDS,
+                self.value as u64,
+                other.value as u64,
+            )
+        }
+    }
+    pub mod dfsr {}
+    #[repr(C)]
+    pub struct Dfsr {
+        value: ::volatile_cell::VolatileCell<u32>,
+    }
+    impl Dfsr {
+        #[inline(always)]
+        fn load(&self) -> u32 {
+            let value = self.value.get();
+            value
+        }
+        #[inline(always)]
+        fn store(&self, value: u32) {
+            self.value.set(value);
+        }
+        #[doc = "Create a new updater"]
+        #[inline(always)]
+        pub fn update<'a>(&'a self) -> DfsrUpdate<'a> {
+            DfsrUpdate::new(self)
+        }
+        #[doc = "Create an updater whose read-modify-write happens inside the critical section `cs`"]
+        #[inline(always)]
+        pub fn update_in<'a, C: ::volatile_cell::sync::CriticalSection>(
+            &'a self,
+            _cs: &'a C,
+        ) -> DfsrUpdate<'a> {
+            DfsrUpdate::new(self)
+        }
+        #[doc = "Update the register with exclusive loads and stores (LDREX/STREX), retrying `f` until no other access intervened"]
+        #[doc = ""]
+        #[doc = "The exclusive accesses go to the register's memory directly rather than through its cell, so they aren't seen by the replayer or the tracing hook."]
+        #[cfg(target_has_atomic = "32")]
+        #[inline]
+        pub fn update_exclusive<F: FnMut(&mut DfsrUpdate)>(&self, mut f: F) {
+            use ::core::sync::atomic::Ordering;
+            let cell = unsafe { &*(self.value.as_ptr() as *const ::core::sync::atomic::AtomicU32) };
+            let mut current = cell.load(Ordering::Relaxed);
+            loop {
+                let mut update = DfsrUpdate::detached(self);
+                f(&mut update);
+                let merged = update.merge(current);
+                if update.mask == 0 {
+                    return;
+                }
+                match cell.compare_exchange_weak(
+                    current,
+                    merged,
+                    Ordering::SeqCst,
+                    Ordering::Relaxed,
+                ) {
+                    Ok(_) => return,
+                    Err(actual) => current = actual,
+                }
+            }
+        }
+        #[doc = "Create a getter representing the current state of the register"]
+        #[inline(always)]
+        pub fn get(&self) -> DfsrGet {
+            DfsrGet::new(self)
+        }
+        #[doc = "Spin until the bit is set"]
+        #[inline]
+        pub fn wait_halted(&self) {
+            while !self.get().halted() {}
+        }
+        #[doc = "Poll until the bit is set, at most `max_polls` times"]
+        #[inline]
+        pub fn wait_halted_timeout(
+            &self,
+            max_polls: u32,
+        ) -> Result<(), ::volatile_cell::wait::Timeout> {
+            ::volatile_cell::wait::poll_until(|| self.get().halted(), max_polls)
+        }
+        #[doc = "Future resolving once the bit is set"]
+        #[inline]
+        pub fn wait_halted_async<'a>(
+            &'a self,
+        ) -> ::volatile_cell::wait::WaitUntil<'a, impl FnMut() -> bool + Unpin + 'a> {
+            ::volatile_cell::wait::WaitUntil::new(move || self.get().halted())
+        }
+        #[doc = "Spin until the bit is set"]
+        #[inline]
+        pub fn wait_bkpt(&self) {
+            while !self.get().bkpt() {}
+        }
+        #[doc = "Poll until the bit is set, at most `max_polls` times"]
+        #[inline]
+        pub fn wait_bkpt_timeout(
+            &self,
+            max_polls: u32,
+        ) -> Result<(), ::volatile_cell::wait::Timeout> {
+            ::volatile_cell::wait::poll_until(|| self.get().bkpt(), max_polls)
+        }
+        #[doc = "Future resolving once the bit is set"]
+        #[inline]
+        pub fn wait_bkpt_async<'a>(
+            &'a self,
+        ) -> ::volatile_cell::wait::WaitUntil<'a, impl FnMut() -> bool + Unpin + 'a> {
+            ::volatile_cell::wait::WaitUntil::new(move || self.get().bkpt())
+        }
+        #[doc = "Spin until the bit is set"]
+        #[inline]
+        pub fn wait_dwttrap(&self) {
+            while !self.get().dwttrap() {}
+        }
+        #[doc = "Poll until the bit is set, at most `max_polls` times"]
+        #[inline]
+        pub fn wait_dwttrap_timeout(
+            &self,
+            max_polls: u32,
+        ) -> Result<(), ::volatile_cell::wait::Timeout> {
+            ::volatile_cell::wait::poll_until(|| self.get().dwttrap(), max_polls)
+        }
+        #[doc = "Future resolving once the bit is set"]
+        #[inline]
+        pub fn wait_dwttrap_async<'a>(
+            &'a self,
+        ) -> ::volatile_cell::wait::WaitUntil<'a, impl FnMut() -> bool + Unpin + 'a> {
+            ::volatile_cell::wait::WaitUntil::new(move || self.get().dwttrap())
+        }
+        #[doc = "Spin until the bit is set"]
+        #[inline]
+        pub fn wait_vcatch(&self) {
+            while !self.get().vcatch() {}
+        }
+        #[doc = "Poll until the bit is set, at most `max_polls` times"]
+        #[inline]
+        pub fn wait_vcatch_timeout(
+            &self,
+            max_polls: u32,
+        ) -> Result<(), ::volatile_cell::wait::Timeout> {
+            ::volatile_cell::wait::poll_until(|| self.get().vcatch(), max_polls)
+        }
+        #[doc = "Future resolving once the bit is set"]
+        #[inline]
+        pub fn wait_vcatch_async<'a>(
+            &'a self,
+        ) -> ::volatile_cell::wait::WaitUntil<'a, impl FnMut() -> bool + Unpin + 'a> {
+            ::volatile_cell::wait::WaitUntil::new(move || self.get().vcatch())
+        }
+        #[doc = "Spin until the bit is set"]
+        #[inline]
+        pub fn wait_external(&self) {
+            while !self.get().external() {}
+        }
+        #[doc = "Poll until the bit is set, at most `max_polls` times"]
+        #[inline]
+        pub fn wait_external_timeout(
+            &self,
+            max_polls: u32,
+        ) -> Result<(), ::volatile_cell::wait::Timeout> {
+            ::volatile_cell::wait::poll_until(|| self.get().external(), max_polls)
+        }
+        #[doc = "Future resolving once the bit is set"]
+        #[inline]
+        pub fn wait_external_async<'a>(
+            &'a self,
+        ) -> ::volatile_cell::wait::WaitUntil<'a, impl FnMut() -> bool + Unpin + 'a> {
+            ::volatile_cell::wait::WaitUntil::new(move || self.get().external())
+        }
+    }
+    pub struct DfsrUpdate<'a> {
+        value: u32,
+        mask: u32,
+        write_only: bool,
+        store_on_drop: bool,
+        reg: &'a Dfsr,
+    }
+    impl<'a> DfsrUpdate<'a> {
+        #[inline(always)]
+        pub fn new(reg: &'a Dfsr) -> DfsrUpdate<'a> {
+            DfsrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[inline(always)]
+        pub fn new_ignoring_state(reg: &'a Dfsr) -> DfsrUpdate<'a> {
+            DfsrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: true,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[doc = "An update that is never stored, for the caller to store itself"]
+        #[inline(always)]
+        fn detached(reg: &'a Dfsr) -> DfsrUpdate<'a> {
+            DfsrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: false,
+                reg: reg,
+            }
+        }
+        const fn clear_mask() -> u32 {
+            0u64 as u32
+        }
+        const fn update_offset(base: usize, size: usize, index: usize) -> usize {
+            base + (size * index)
+        }
+        #[inline(always)]
+        fn merge(&self, current: u32) -> u32 {
+            let v: u32 = current & (!Self::clear_mask()) & (!self.mask);
+            self.value | v
+        }
+        #[inline(always)]
+        pub fn set_halted<'b>(&'b mut self, new_value: bool) -> &'b mut Self {
+            let context_mask: u32 = 1 << 0;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 1) << 0);
+            self.mask |= context_mask;
+            self
+        }
+        #[inline(always)]
+        pub fn set_bkpt<'b>(&'b mut self, new_value: bool) -> &'b mut Self {
+            let context_mask: u32 = 1 << 1;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 1) << 1);
+            self.mask |= context_mask;
+            self
+        }
+        #[inline(always)]
+        pub fn set_dwttrap<'b>(&'b mut self, new_value: bool) -> &'b mut Self {
+            let context_mask: u32 = 1 << 2;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 1) << 2);
+            self.mask |= context_mask;
+            self
+        }
+        #[inline(always)]
+        pub fn set_vcatch<'b>(&'b mut self, new_value: bool) -> &'b mut Self {
+            let context_mask: u32 = 1 << 3;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 1) << 3);
+            self.mask |= context_mask;
+            self
+        }
+        #[inline(always)]
+        pub fn set_external<'b>(&'b mut self, new_value: bool) -> &'b mut Self {
+            let context_mask: u32 = 1 << 4;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 1) << 4);
+            self.mask |= context_mask;
+            self
+        }
+    }
+    impl<'a> Drop for DfsrUpdate<'a> {
+        #[inline(always)]
+        fn drop(&mut self) {
+            if self.store_on_drop && self.mask != 0 {
+                let current: u32 = if self.write_only { 0 } else { self.reg.load() };
+                self.reg.store(self.merge(current));
+            }
+        }
+    }
+    #[derive(Clone, PartialEq, Eq)]
+    pub struct DfsrGet {
+        value: u32,
+    }
+    impl DfsrGet {
+        #[doc = "Create a getter reflecting the current value of the register"]
+        #[inline(always)]
+        pub fn new(reg: &Dfsr) -> DfsrGet {
+            DfsrGet { value: reg.load() }
+        }
+        #[inline(always)]
+        pub fn halted(&self) -> bool {
+            let val = (self.value & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub fn bkpt(&self) -> bool {
+            let val = ((self.value >> 1) & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub fn dwttrap(&self) -> bool {
+            let val = ((self.value >> 2) & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub fn vcatch(&self) -> bool {
+            let val = ((self.value >> 3) & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub fn external(&self) -> bool {
+            let val = ((self.value >> 4) & 1);
+            val != 0x0
+        }
+    }
+    #[derive(Clone, Copy, PartialEq, Eq, Default)]
+    #[repr(transparent)]
+    pub struct DfsrValue {
+        value: u32,
+    }
+    impl DfsrValue {
+        #[doc = "Create a value with every field cleared"]
+        #[inline(always)]
+        pub const fn new() -> DfsrValue {
+            DfsrValue { value: 0 }
+        }
+        #[inline(always)]
+        pub const fn from_bits(bits: u32) -> DfsrValue {
+            DfsrValue { value: bits }
+        }
+        #[inline(always)]
+        pub const fn to_bits(&self) -> u32 {
+            self.value
+        }
+        #[inline(always)]
+        pub fn halted(&self) -> bool {
+            let val = (self.value & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub fn bkpt(&self) -> bool {
+            let val = ((self.value >> 1) & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub fn dwttrap(&self) -> bool {
+            let val = ((self.value >> 2) & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub fn vcatch(&self) -> bool {
+            let val = ((self.value >> 3) & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub fn external(&self) -> bool {
+            let val = ((self.value >> 4) & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub const fn with_halted(self, new_value: bool) -> Self {
+            let context_mask: u32 = 1 << 0;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 1) << 0),
+            }
+        }
+        #[inline(always)]
+        pub const fn with_bkpt(self, new_value: bool) -> Self {
+            let context_mask: u32 = 1 << 1;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 1) << 1),
+            }
+        }
+        #[inline(always)]
+        pub const fn with_dwttrap(self, new_value: bool) -> Self {
+            let context_mask: u32 = 1 << 2;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 1) << 2),
+            }
+        }
+        #[inline(always)]
+        pub const fn with_vcatch(self, new_value: bool) -> Self {
+            let context_mask: u32 = 1 << 3;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 1) << 3),
+            }
+        }
+        #[inline(always)]
+        pub const fn with_external(self, new_value: bool) -> Self {
+            let context_mask: u32 = 1 << 4;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 1) << 4),
+            }
+        }
+    }
+    impl From<DfsrGet> for DfsrValue {
+        #[inline(always)]
+        fn from(get: DfsrGet) -> DfsrValue {
+            DfsrValue::from_bits(get.value)
+        }
+    }
+    impl From<DfsrValue> for DfsrGet {
+        #[inline(always)]
+        fn from(value: DfsrValue) -> DfsrGet {
+            DfsrGet {
+                value: value.to_bits(),
+            }
+        }
+    }
+    impl Dfsr {
+        #[doc = "Write `value` to the register in a single store, without reading it first"]
+        #[inline(always)]
+        pub fn write(&self, value: DfsrValue) {
+            self.store(value.to_bits());
+        }
+    }
+    impl<'a> DfsrUpdate<'a> {
+        #[doc = "Replace every field of the register with those of `value`"]
+        #[inline(always)]
+        pub fn assign<'b>(&'b mut self, value: DfsrValue) -> &'b mut Self {
+            self.value = value.to_bits();
+            self.mask = !0;
+            self
+        }
+    }
+    impl DfsrGet {
+        pub const FIELDS: &'static [::volatile_cell::field::FieldDescriptor] = &[
+            ::volatile_cell::field::FieldDescriptor {
+                name: "halted",
+                offset: 0u32,
+                width: 1u32,
+                count: 1u32,
+            },
+            ::volatile_cell::field::FieldDescriptor {
+                name: "bkpt",
+                offset: 1u32,
+                width: 1u32,
+                count: 1u32,
+            },
+            ::volatile_cell::field::FieldDescriptor {
+                name: "dwttrap",
+                offset: 2u32,
+                width: 1u32,
+                count: 1u32,
+            },
+            ::volatile_cell::field::FieldDescriptor {
+                name: "vcatch",
+                offset: 3u32,
+                width: 1u32,
+                count: 1u32,
+            },
+            ::volatile_cell::field::FieldDescriptor {
+                name: "external",
+                offset: 4u32,
+                width: 1u32,
+                count: 1u32,
+            },
+        ];
+        #[doc = "Iterate over the readable fields that differ from those of `other`"]
+        #[inline]
+        pub fn changed_fields(&self, other: &DfsrGet) -> ::volatile_cell::field::FieldChanges {
+            ::volatile_cell::field::FieldChanges::new(
+                Self::FIELDS,
+                self.value as u64,
+                other.value as u64,
+            )
+        }
+    }
+    pub mod mmfar {}
+    #[repr(C)]
+    pub struct Mmfar {
+        value: ::volatile_cell::VolatileCell<u32>,
+    }
+    impl Mmfar {
+        #[inline(always)]
+        fn load(&self) -> u32 {
+            let value = self.value.get();
+            value
+        }
+        #[inline(always)]
+        fn store(&self, value: u32) {
+            self.value.set(value);
+        }
+        #[doc = "Create a new updater"]
+        #[inline(always)]
+        pub fn update<'a>(&'a self) -> MmfarUpdate<'a> {
+            MmfarUpdate::new(self)
+        }
+        #[doc = "Create an updater whose read-modify-write happens inside the critical section `cs`"]
+        #[inline(always)]
+        pub fn update_in<'a, C: ::volatile_cell::sync::CriticalSection>(
+            &'a self,
+            _cs: &'a C,
+        ) -> MmfarUpdate<'a> {
+            MmfarUpdate::new(self)
+        }
+        #[doc = "Update the register with exclusive loads and stores (LDREX/STREX), retrying `f` until no other access intervened"]
+        #[doc = ""]
+        #[doc = "The exclusive accesses go to the register's memory directly rather than through its cell, so they aren't seen by the replayer or the tracing hook."]
+        #[cfg(target_has_atomic = "32")]
+        #[inline]
+        pub fn update_exclusive<F: FnMut(&mut MmfarUpdate)>(&self, mut f: F) {
+            use ::core::sync::atomic::Ordering;
+            let cell = unsafe { &*(self.value.as_ptr() as *const ::core::sync::atomic::AtomicU32) };
+            let mut current = cell.load(Ordering::Relaxed);
+            loop {
+                let mut update = MmfarUpdate::detached(self);
+                f(&mut update);
+                let merged = update.merge(current);
+                if update.mask == 0 {
+                    return;
+                }
+                match cell.compare_exchange_weak(
+                    current,
+                    merged,
+                    Ordering::SeqCst,
+                    Ordering::Relaxed,
+                ) {
+                    Ok(_) => return,
+                    Err(actual) => current = actual,
+                }
+            }
+        }
+        #[doc = "Create a getter representing the current state of the register"]
+        #[inline(always)]
+        pub fn get(&self) -> MmfarGet {
+            MmfarGet::new(self)
+        }
+    }
+    pub struct MmfarUpdate<'a> {
+        value: u32,
+        mask: u32,
+        write_only: bool,
+        store_on_drop: bool,
+        reg: &'a Mmfar,
+    }
+    impl<'a> MmfarUpdate<'a> {
+        #[inline(always)]
+        pub fn new(reg: &'a Mmfar) -> MmfarUpdate<'a> {
+            MmfarUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[inline(always)]
+        pub fn new_ignoring_state(reg: &'a Mmfar) -> MmfarUpdate<'a> {
+            MmfarUpdate {
+                value: 0,
+                mask: 0,
+                write_only: true,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[doc = "An update that is never stored, for the caller to store itself"]
+        #[inline(always)]
+        fn detached(reg: &'a Mmfar) -> MmfarUpdate<'a> {
+            MmfarUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: false,
+                reg: reg,
+            }
+        }
+        const fn clear_mask() -> u32 {
+            0u64 as u32
+        }
+        const fn update_offset(base: usize, size: usize, index: usize) -> usize {
+            base + (size * index)
+        }
+        #[inline(always)]
+        fn merge(&self, current: u32) -> u32 {
+            let v: u32 = current & (!Self::clear_mask()) & (!self.mask);
+            self.value | v
+        }
+        #[inline(always)]
+        pub fn set_address<'b>(&'b mut self, new_value: u32) -> &'b mut Self {
+            let context_mask: u32 = 4294967295 << 0;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 4294967295) << 0);
+            self.mask |= context_mask;
+            self
+        }
+    }
+    impl<'a> Drop for MmfarUpdate<'a> {
+        #[inline(always)]
+        fn drop(&mut self) {
+            if self.store_on_drop && self.mask != 0 {
+                let current: u32 = if self.write_only { 0 } else { self.reg.load() };
+                self.reg.store(self.merge(current));
+            }
+        }
+    }
+    #[derive(Clone, PartialEq, Eq)]
+    pub struct MmfarGet {
+        value: u32,
+    }
+    impl MmfarGet {
+        #[doc = "Create a getter reflecting the current value of the register"]
+        #[inline(always)]
+        pub fn new(reg: &Mmfar) -> MmfarGet {
+            MmfarGet { value: reg.load() }
+        }
+        #[inline(always)]
+        pub fn address(&self) -> u32 {
+            self.value
+        }
+    }
+    #[derive(Clone, Copy, PartialEq, Eq, Default)]
+    #[repr(transparent)]
+    pub struct MmfarValue {
+        value: u32,
+    }
+    impl MmfarValue {
+        #[doc = "Create a value with every field cleared"]
+        #[inline(always)]
+        pub const fn new() -> MmfarValue {
+            MmfarValue { value: 0 }
+        }
+        #[inline(always)]
+        pub const fn from_bits(bits: u32) -> MmfarValue {
+            MmfarValue { value: bits }
+        }
+        #[inline(always)]
+        pub const fn to_bits(&self) -> u32 {
+            self.value
+        }
+        #[inline(always)]
+        pub fn address(&self) -> u32 {
+            self.value
+        }
+        #[inline(always)]
+        pub const fn with_address(self, new_value: u32) -> Self {
+            let context_mask: u32 = 4294967295 << 0;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 4294967295) << 0),
+            }
+        }
+    }
+    impl From<MmfarGet> for MmfarValue {
+        #[inline(always)]
+        fn from(get: MmfarGet) -> MmfarValue {
+            MmfarValue::from_bits(get.value)
+        }
+    }
+    impl From<MmfarValue> for MmfarGet {
+        #[inline(always)]
+        fn from(value: MmfarValue) -> MmfarGet {
+            MmfarGet {
+                value: value.to_bits(),
+            }
+        }
+    }
+    impl Mmfar {
+        #[doc = "Write `value` to the register in a single store, without reading it first"]
+        #[inline(always)]
+        pub fn write(&self, value: MmfarValue) {
+            self.store(value.to_bits());
+        }
+    }
+    impl<'a> MmfarUpdate<'a> {
+        #[doc = "Replace every field of the register with those of `value`"]
+        #[inline(always)]
+        pub fn assign<'b>(&'b mut self, value: MmfarValue) -> &'b mut Self {
+            self.value = value.to_bits();
+            self.mask = !0;
+            self
+        }
+    }
+    impl MmfarGet {
+        pub const FIELDS: &'static [::volatile_cell::field::FieldDescriptor] =
+            &[::volatile_cell::field::FieldDescriptor {
+                name: "address",
+                offset: 0u32,
+                width: 32u32,
+                count: 1u32,
+            }];
+        #[doc = "Iterate over the readable fields that differ from those of `other`"]
+        #[inline]
+        pub fn changed_fields(&self, other: &MmfarGet) -> ::volatile_cell::field::FieldChanges {
+            ::volatile_cell::field::FieldChanges::new(
+                Self::FIELDS,
+                self.value as u64,
+                other.value as u64,
+            )
+        }
+    }
+    pub mod bfar {}
+    #[repr(C)]
+    pub struct Bfar {
+        value: ::volatile_cell::VolatileCell<u32>,
+    }
+    impl Bfar {
+        #[inline(always)]
+        fn load(&self) -> u32 {
+            let value = self.value.get();
+            value
+        }
+        #[inline(always)]
+        fn store(&self, value: u32) {
+            self.value.set(value);
+        }
+        #[doc = "Create a new updater"]
+        #[inline(always)]
+        pub fn update<'a>(&'a self) -> BfarUpdate<'a> {
+            BfarUpdate::new(self)
+        }
+        #[doc = "Create an updater whose read-modify-write happens inside the critical section `cs`"]
+        #[inline(always)]
+        pub fn update_in<'a, C: ::volatile_cell::sync::CriticalSection>(
+            &'a self,
+            _cs: &'a C,
+        ) -> BfarUpdate<'a> {
+            BfarUpdate::new(self)
+        }
+        #[doc = "Update the register with exclusive loads and stores (LDREX/STREX), retrying `f` until no other access intervened"]
+        #[doc = ""]
+        #[doc = "The exclusive accesses go to the register's memory directly rather than through its cell, so they aren't seen by the replayer or the tracing hook."]
+        #[cfg(target_has_atomic = "32")]
+        #[inline]
+        pub fn update_exclusive<F: FnMut(&mut BfarUpdate)>(&self, mut f: F) {
+            use ::core::sync::atomic::Ordering;
+            let cell = unsafe { &*(self.value.as_ptr() as *const ::core::sync::atomic::AtomicU32) };
+            let mut current = cell.load(Ordering::Relaxed);
+            loop {
+                let mut update = BfarUpdate::detached(self);
+                f(&mut update);
+                let merged = update.merge(current);
+                if update.mask == 0 {
+                    return;
+                }
+                match cell.compare_exchange_weak(
+                    current,
+                    merged,
+                    Ordering::SeqCst,
+                    Ordering::Relaxed,
+                ) {
+                    Ok(_) => return,
+                    Err(actual) => current = actual,
+                }
+            }
+        }
+        #[doc = "Create a getter representing the current state of the register"]
+        #[inline(always)]
+        pub fn get(&self) -> BfarGet {
+            BfarGet::new(self)
+        }
+    }
+    pub struct BfarUpdate<'a> {
+        value: u32,
+        mask: u32,
+        write_only: bool,
+        store_on_drop: bool,
+        reg: &'a Bfar,
+    }
+    impl<'a> BfarUpdate<'a> {
+        #[inline(always)]
+        pub fn new(reg: &'a Bfar) -> BfarUpdate<'a> {
+            BfarUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[inline(always)]
+        pub fn new_ignoring_state(reg: &'a Bfar) -> BfarUpdate<'a> {
+            BfarUpdate {
+                value: 0,
+                mask: 0,
+                write_only: true,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[doc = "An update that is never stored, for the caller to store itself"]
+        #[inline(always)]
+        fn detached(reg: &'a Bfar) -> BfarUpdate<'a> {
+            BfarUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: false,
+                reg: reg,
+            }
+        }
+        const fn clear_mask() -> u32 {
+            0u64 as u32
+        }
+        const fn update_offset(base: usize, size: usize, index: usize) -> usize {
+            base + (size * index)
+        }
+        #[inline(always)]
+        fn merge(&self, current: u32) -> u32 {
+            let v: u32 = current & (!Self::clear_mask()) & (!self.mask);
+            self.value | v
+        }
+        #[inline(always)]
+        pub fn set_address<'b>(&'b mut self, new_value: u32) -> &'b mut Self {
+            let context_mask: u32 = 4294967295 << 0;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 4294967295) << 0);
+            self.mask |= context_mask;
+            self
+        }
+    }
+    impl<'a> Drop for BfarUpdate<'a> {
+        #[inline(always)]
+        fn drop(&mut self) {
+            if self.store_on_drop && self.mask != 0 {
+                let current: u32 = if self.write_only { 0 } else { self.reg.load() };
+                self.reg.store(self.merge(current));
+            }
+        }
+    }
+    #[derive(Clone, PartialEq, Eq)]
+    pub struct BfarGet {
+        value: u32,
+    }
+    impl BfarGet {
+        #[doc = "Create a getter reflecting the current value of the register"]
+        #[inline(always)]
+        pub fn new(reg: &Bfar) -> BfarGet {
+            BfarGet { value: reg.load() }
+        }
+        #[inline(always)]
+        pub fn address(&self) -> u32 {
+            self.value
+        }
+    }
+    #[derive(Clone, Copy, PartialEq, Eq, Default)]
+    #[repr(transparent)]
+    pub struct BfarValue {
+        value: u32,
+    }
+    impl BfarValue {
+        #[doc = "Create a value with every field cleared"]
+        #[inline(always)]
+        pub const fn new() -> BfarValue {
+            BfarValue { value: 0 }
+        }
+        #[inline(always)]
+        pub const fn from_bits(bits: u32) -> BfarValue {
+            BfarValue { value: bits }
+        }
+        #[inline(always)]
+        pub const fn to_bits(&self) -> u32 {
+            self.value
+        }
+        #[inline(always)]
+        pub fn address(&self) -> u32 {
+            self.value
+        }
+        #[inline(always)]
+        pub const fn with_address(self, new_value: u32) -> Self {
+            let context_mask: u32 = 4294967295 << 0;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 4294967295) << 0),
+            }
+        }
+    }
+    impl From<BfarGet> for BfarValue {
+        #[inline(always)]
+        fn from(get: BfarGet) -> BfarValue {
+            BfarValue::from_bits(get.value)
+        }
+    }
+    impl From<BfarValue> for BfarGet {
+        #[inline(always)]
+        fn from(value: BfarValue) -> BfarGet {
+            BfarGet {
+                value: value.to_bits(),
+            }
+        }
+    }
+    impl Bfar {
+        #[doc = "Write `value` to the register in a single store, without reading it first"]
+        #[inline(always)]
+        pub fn write(&self, value: BfarValue) {
+            self.store(value.to_bits());
+        }
+    }
+    impl<'a> BfarUpdate<'a> {
+        #[doc = "Replace every field of the register with those of `value`"]
+        #[inline(always)]
+        pub fn assign<'b>(&'b mut self, value: BfarValue) -> &'b mut Self {
+            self.value = value.to_bits();
+            self.mask = !0;
+            self
+        }
+    }
+    impl BfarGet {
+        pub const FIELDS: &'static [::volatile_cell::field::FieldDescriptor] =
+            &[::volatile_cell::field::FieldDescriptor {
+                name: "address",
+                offset: 0u32,
+                width: 32u32,
+                count: 1u32,
+            }];
+        #[doc = "Iterate over the readable fields that differ from those of `other`"]
+        #[inline]
+        pub fn changed_fields(&self, other: &BfarGet) -> ::volatile_cell::field::FieldChanges {
+            ::volatile_cell::field::FieldChanges::new(
+                Self::FIELDS,
+                self.value as u64,
+                other.value as u64,
+            )
+        }
+    }
+    pub mod afsr {}
+    #[repr(C)]
+    pub struct Afsr {
+        value: ::volatile_cell::VolatileCell<u32>,
+    }
+    impl Afsr {
+        #[inline(always)]
+        fn load(&self) -> u32 {
+            let value = self.value.get();
+            value
+        }
+        #[inline(always)]
+        fn store(&self, value: u32) {
+            self.value.set(value);
+        }
+        #[doc = "Create a new updater"]
+        #[inline(always)]
+        pub fn update<'a>(&'a self) -> AfsrUpdate<'a> {
+            AfsrUpdate::new(self)
+        }
+        #[doc = "Create an updater whose read-modify-write happens inside the critical section `cs`"]
+        #[inline(always)]
+        pub fn update_in<'a, C: ::volatile_cell::sync::CriticalSection>(
+            &'a self,
+            _cs: &'a C,
+        ) -> AfsrUpdate<'a> {
+            AfsrUpdate::new(self)
+        }
+        #[doc = "Update the register with exclusive loads and stores (LDREX/STREX), retrying `f` until no other access intervened"]
+        #[doc = ""]
+        #[doc = "The exclusive accesses go to the register's memory directly rather than through its cell, so they aren't seen by the replayer or the tracing hook."]
+        #[cfg(target_has_atomic = "32")]
+        #[inline]
+        pub fn update_exclusive<F: FnMut(&mut AfsrUpdate)>(&self, mut f: F) {
+            use ::core::sync::atomic::Ordering;
+            let cell = unsafe { &*(self.value.as_ptr() as *const ::core::sync::atomic::AtomicU32) };
+            let mut current = cell.load(Ordering::Relaxed);
+            loop {
+                let mut update = AfsrUpdate::detached(self);
+                f(&mut update);
+                let merged = update.merge(current);
+                if update.mask == 0 {
+                    return;
+                }
+                match cell.compare_exchange_weak(
+                    current,
+                    merged,
+                    Ordering::SeqCst,
+                    Ordering::Relaxed,
+                ) {
+                    Ok(_) => return,
+                    Err(actual) => current = actual,
+                }
+            }
+        }
+        #[doc = "Create a getter representing the current state of the register"]
+        #[inline(always)]
+        pub fn get(&self) -> AfsrGet {
+            AfsrGet::new(self)
+        }
+    }
+    pub struct AfsrUpdate<'a> {
+        value: u32,
+        mask: u32,
+        write_only: bool,
+        store_on_drop: bool,
+        reg: &'a Afsr,
+    }
+    impl<'a> AfsrUpdate<'a> {
+        #[inline(always)]
+        pub fn new(reg: &'a Afsr) -> AfsrUpdate<'a> {
+            AfsrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[inline(always)]
+        pub fn new_ignoring_state(reg: &'a Afsr) -> AfsrUpdate<'a> {
+            AfsrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: true,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[doc = "An update that is never stored, for the caller to store itself"]
+        #[inline(always)]
+        fn detached(reg: &'a Afsr) -> AfsrUpdate<'a> {
+            AfsrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: false,
+                reg: reg,
+            }
+        }
+        const fn clear_mask() -> u32 {
+            0u64 as u32
+        }
+        const fn update_offset(base: usize, size: usize, index: usize) -> usize {
+            base + (size * index)
+        }
+        #[inline(always)]
+        fn merge(&self, current: u32) -> u32 {
+            let v: u32 = current & (!Self::clear_mask()) & (!self.mask);
+            self.value | v
+        }
+        #[inline(always)]
+        pub fn set_afsr<'b>(&'b mut self, new_value: u32) -> &'b mut Self {
+            let context_mask: u32 = 4294967295 << 0;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 4294967295) << 0);
+            self.mask |= context_mask;
+            self
+        }
+    }
+    impl<'a> Drop for AfsrUpdate<'a> {
+        #[inline(always)]
+        fn drop(&mut self) {
+            if self.store_on_drop && self.mask != 0 {
+                let current: u32 = if self.write_only { 0 } else { self.reg.load() };
+                self.reg.store(self.merge(current));
+            }
+        }
+    }
+    #[derive(Clone, PartialEq, Eq)]
+    pub struct AfsrGet {
+        value: u32,
+    }
+    impl AfsrGet {
+        #[doc = "Create a getter reflecting the current value of the register"]
+        #[inline(always)]
+        pub fn new(reg: &Afsr) -> AfsrGet {
+            AfsrGet { value: reg.load() }
+        }
+        #[inline(always)]
+        pub fn afsr(&self) -> u32 {
+            self.value
+        }
+    }
+    #[derive(Clone, Copy, PartialEq, Eq, Default)]
+    #[repr(transparent)]
+    pub struct AfsrValue {
+        value: u32,
+    }
+    impl AfsrValue {
+        #[doc = "Create a value with every field cleared"]
+        #[inline(always)]
+        pub const fn new() -> AfsrValue {
+            AfsrValue { value: 0 }
+        }
+        #[inline(always)]
+        pub const fn from_bits(bits: u32) -> AfsrValue {
+            AfsrValue { value: bits }
+        }
+        #[inline(always)]
+        pub const fn to_bits(&self) -> u32 {
+            self.value
+        }
+        #[inline(always)]
+        pub fn afsr(&self) -> u32 {
+            self.value
+        }
+        #[inline(always)]
+        pub const fn with_afsr(self, new_value: u32) -> Self {
+            let context_mask: u32 = 4294967295 << 0;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 4294967295) << 0),
+            }
+        }
+    }
+    impl From<AfsrGet> for AfsrValue {
+        #[inline(always)]
+        fn from(get: AfsrGet) -> AfsrValue {
+            AfsrValue::from_bits(get.value)
+        }
+    }
+    impl From<AfsrValue> for AfsrGet {
+        #[inline(always)]
+        fn from(value: AfsrValue) -> AfsrGet {
+            AfsrGet {
+                value: value.to_bits(),
+            }
+        }
+    }
+    impl Afsr {
+        #[doc = "Write `value` to the register in a single store, without reading it first"]
+        #[inline(always)]
+        pub fn write(&self, value: AfsrValue) {
+            self.store(value.to_bits());
+        }
+    }
+    impl<'a> AfsrUpdate<'a> {
+        #[doc = "Replace every field of the register with those of `value`"]
+        #[inline(always)]
+        pub fn assign<'b>(&'b mut self, value: AfsrValue) -> &'b mut Self {
+            self.value = value.to_bits();
+            self.mask = !0;
+            self
+        }
+    }
+    impl AfsrGet {
+        pub const FIELDS: &'static [::volatile_cell::field::FieldDescriptor] =
+            &[::volatile_cell::field::FieldDescriptor {
+                name: "afsr",
+                offset: 0u32,
+                width: 32u32,
+                count: 1u32,
+            }];
+        #[doc = "Iterate over the readable fields that differ from those of `other`"]
+        #[inline]
+        pub fn changed_fields(&self, other: &AfsrGet) -> ::volatile_cell::field::FieldChanges {
+            ::volatile_cell::field::FieldChanges::new(
+                Self::FIELDS,
+                self.value as u64,
+                other.value as u64,
+            )
+        }
+    }
+    pub mod cpacr {}
+    #[repr(C)]
+    pub struct Cpacr {
+        value: ::volatile_cell::VolatileCell<u32>,
+    }
+    impl Cpacr {
+        #[inline(always)]
+        fn load(&self) -> u32 {
+            let value = self.value.get();
+            value
+        }
+        #[inline(always)]
+        fn store(&self, value: u32) {
+            self.value.set(value);
+        }
+        #[doc = "Create a new updater"]
+        #[inline(always)]
+        pub fn update<'a>(&'a self) -> CpacrUpdate<'a> {
+            CpacrUpdate::new(self)
+        }
+        #[doc = "Create an updater whose read-modify-write happens inside the critical section `cs`"]
+        #[inline(always)]
+        pub fn update_in<'a, C: ::volatile_cell::sync::CriticalSection>(
+            &'a self,
+            _cs: &'a C,
+        ) -> CpacrUpdate<'a> {
+            CpacrUpdate::new(self)
+        }
+        #[doc = "Update the register with exclusive loads and stores (LDREX/STREX), retrying `f` until no other access intervened"]
+        #[doc = ""]
+        #[doc = "The exclusive accesses go to the register's memory directly rather than through its cell, so they aren't seen by the replayer or the tracing hook."]
+        #[cfg(target_has_atomic = "32")]
+        #[inline]
+        pub fn update_exclusive<F: FnMut(&mut CpacrUpdate)>(&self, mut f: F) {
+            use ::core::sync::atomic::Ordering;
+            let cell = unsafe { &*(self.value.as_ptr() as *const ::core::sync::atomic::AtomicU32) };
+            let mut current = cell.load(Ordering::Relaxed);
+            loop {
+                let mut update = CpacrUpdate::detached(self);
+                f(&mut update);
+                let merged = update.merge(current);
+                if update.mask == 0 {
+                    return;
+                }
+                match cell.compare_exchange_weak(
+                    current,
+                    merged,
+                    Ordering::SeqCst,
+                    Ordering::Relaxed,
+                ) {
+                    Ok(_) => return,
+                    Err(actual) => current = actual,
+                }
+            }
+        }
+        #[doc = "Create a getter representing the current state of the register"]
+        #[inline(always)]
+        pub fn get(&self) -> CpacrGet {
+            CpacrGet::new(self)
+        }
+        #[doc = "Spin until the bit is set"]
+        #[inline]
+        pub fn wait_cp(&self, index: usize) {
+            while !self.get().cp(index) {}
+        }
+        #[doc = "Poll until the bit is set, at most `max_polls` times"]
+        #[inline]
+        pub fn wait_cp_timeout(
+            &self,
+            index: usize,
+            max_polls: u32,
+        ) -> Result<(), ::volatile_cell::wait::Timeout> {
+            ::volatile_cell::wait::poll_until(|| self.get().cp(index), max_polls)
+        }
+        #[doc = "Future resolving once the bit is set"]
+        #[inline]
+        pub fn wait_cp_async<'a>(
+            &'a self,
+            index: usize,
+        ) -> ::volatile_cell::wait::WaitUntil<'a, impl FnMut() -> bool + Unpin + 'a> {
+            ::volatile_cell::wait::WaitUntil::new(move || self.get().cp(index))
+        }
+    }
+    pub struct CpacrUpdate<'a> {
+        value: u32,
+        mask: u32,
+        write_only: bool,
+        store_on_drop: bool,
+        reg: &'a Cpacr,
+    }
+    impl<'a> CpacrUpdate<'a> {
+        #[inline(always)]
+        pub fn new(reg: &'a Cpacr) -> CpacrUpdate<'a> {
+            CpacrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[inline(always)]
+        pub fn new_ignoring_state(reg: &'a Cpacr) -> CpacrUpdate<'a> {
+            CpacrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: true,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[doc = "An update that is never stored, for the caller to store itself"]
+        #[inline(always)]
+        fn detached(reg: &'a Cpacr) -> CpacrUpdate<'a> {
+            CpacrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: false,
+                reg: reg,
+            }
+        }
+        const fn clear_mask() -> u32 {
+            0u64 as u32
+        }
+        const fn update_offset(base: usize, size: usize, index: usize) -> usize {
+            base + (size * index)
+        }
+        #[inline(always)]
+        fn merge(&self, current: u32) -> u32 {
+            let v: u32 = current & (!Self::clear_mask()) & (!self.mask);
+            self.value | v
+        }
+        #[inline(always)]
+        pub fn set_cp<'b>(&'b mut self, index: usize, new_value: bool) -> &'b mut Self {
+            if index > 24 {
+                panic!();
+            }
+            let update_offset = Self::update_offset(0, 1, index);
+            let context_mask: u32 = 1 << update_offset;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 1) << update_offset);
+            self.mask |= context_mask;
+            self
+        }
+    }
+    impl<'a> Drop for CpacrUpdate<'a> {
+        #[inline(always)]
+        fn drop(&mut self) {
+            if self.store_on_drop && self.mask != 0 {
+                let current: u32 = if self.write_only { 0 } else { self.reg.load() };
+                self.reg.store(self.merge(current));
+            }
+        }
+    }
+    #[derive(Clone, PartialEq, Eq)]
+    pub struct CpacrGet {
+        value: u32,
+    }
+    impl CpacrGet {
+        #[doc = "Create a getter reflecting the current value of the register"]
+        #[inline(always)]
+        pub fn new(reg: &Cpacr) -> CpacrGet {
+            CpacrGet { value: reg.load() }
+        }
+        #[inline(always)]
+        pub fn cp(&self, index: usize) -> bool {
+            let val = (self.value >> (0 + (1 * index))) & 1;
+            val != 0x0
+        }
+    }
+    #[derive(Clone, Copy, PartialEq, Eq, Default)]
+    #[repr(transparent)]
+    pub struct CpacrValue {
+        value: u32,
+    }
+    impl CpacrValue {
+        #[doc = "Create a value with every field cleared"]
+        #[inline(always)]
+        pub const fn new() -> CpacrValue {
+            CpacrValue { value: 0 }
+        }
+        #[inline(always)]
+        pub const fn from_bits(bits: u32) -> CpacrValue {
+            CpacrValue { value: bits }
+        }
+        #[inline(always)]
+        pub const fn to_bits(&self) -> u32 {
+            self.value
+        }
+        #[inline(always)]
+        pub fn cp(&self, index: usize) -> bool {
+            let val = (self.value >> (0 + (1 * index))) & 1;
+            val != 0x0
+        }
+        #[inline(always)]
+        pub const fn with_cp(self, index: usize, new_value: bool) -> Self {
+            if index >= 24 {
+                panic!();
+            }
+            let shift = 0 + (1 * index);
+            let context_mask: u32 = 1 << shift;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 1) << shift),
+            }
+        }
+    }
+    impl From<CpacrGet> for CpacrValue {
+        #[inline(always)]
+        fn from(get: CpacrGet) -> CpacrValue {
+            CpacrValue::from_bits(get.value)
+        }
+    }
+    impl From<CpacrValue> for CpacrGet {
+        #[inline(always)]
+        fn from(value: CpacrValue) -> CpacrGet {
+            CpacrGet {
+                value: value.to_bits(),
+            }
+        }
+    }
+    impl Cpacr {
+        #[doc = "Write `value` to the register in a single store, without reading it first"]
+        #[inline(always)]
+        pub fn write(&self, value: CpacrValue) {
+            self.store(value.to_bits());
+        }
+    }
+    impl<'a> CpacrUpdate<'a> {
+        #[doc = "Replace every field of the register with those of `value`"]
+        #[inline(always)]
+        pub fn assign<'b>(&'b mut self, value: CpacrValue) -> &'b mut Self {
+            self.value = value.to_bits();
+            self.mask = !0;
+            self
+        }
+    }
+    impl CpacrGet {
+        pub const FIELDS: &'static [::volatile_cell::field::FieldDescriptor] =
+            &[::volatile_cell::field::FieldDescriptor {
+                name: "cp",
+                offset: 0u32,
+                width: 1u32,
+                count: 24u32,
+            }];
+        #[doc = "Iterate over the readable fields that differ from those of `other`"]
+        #[inline]
+        pub fn changed_fields(&self, other: &CpacrGet) -> ::volatile_cell::field::FieldChanges {
+            ::volatile_cell::field::FieldChanges::new(
+                Self::FIELDS,
+                self.value as u64,
+                other.value as u64,
+            )
+        }
+    }
+    #[repr(C)]
+    pub struct Scb {
+        pub cpuid: Cpuid,
+        pub icsr: Icsr,
+        pub vtor: Vtor,
+        pub aircr: Aircr,
+        pub scr: Scr,
+        pub ccr: Ccr,
+        pub shpr: [Shpr; 3],
+        pub shcsr: Shcsr,
+        pub cfsr: Cfsr,
+        pub hfsr: Hfsr,
+        pub dfsr: Dfsr,
+        pub mmfar: Mmfar,
+        pub bfar: Bfar,
+        pub afsr: Afsr,
+        _padding0: [u8; 72],
+        pub cpacr: Cpacr,
+    }
+    #[doc = "Saved state of every readable register without side effects"]
+    #[derive(Clone, Copy)]
+    pub struct Snapshot {
+        pub cpuid: CpuidValue,
+        pub icsr: IcsrValue,
+        pub vtor: VtorValue,
+        pub aircr: AircrValue,
+        pub scr: ScrValue,
+        pub ccr: CcrValue,
+        pub shpr: [ShprValue; 3],
+        pub shcsr: ShcsrValue,
+        pub cfsr: CfsrValue,
+        pub hfsr: HfsrValue,
+        pub dfsr: DfsrValue,
+        pub mmfar: MmfarValue,
+        pub bfar: BfarValue,
+        pub afsr: AfsrValue,
+        pub cpacr: CpacrValue,
+    }
+    #[doc = "Read the current state of the peripheral"]
+    #[allow(unused_variables)]
+    pub fn capture(p: &Scb) -> Snapshot {
+        Snapshot {
+            cpuid: CpuidValue::from(p.cpuid.get()),
+            icsr: IcsrValue::from(p.icsr.get()),
+            vtor: VtorValue::from(p.vtor.get()),
+            aircr: AircrValue::from(p.aircr.get()),
+            scr: ScrValue::from(p.scr.get()),
+            ccr: CcrValue::from(p.ccr.get()),
+            shpr: {
+                let mut values = [ShprValue::new(); 3];
+                for i in 0..3 {
+                    values[i] = ShprValue::from(p.shpr[i].get());
+                }
+                values
+            },
+            shcsr: ShcsrValue::from(p.shcsr.get()),
+            cfsr: CfsrValue::from(p.cfsr.get()),
+            hfsr: HfsrValue::from(p.hfsr.get()),
+            dfsr: DfsrValue::from(p.dfsr.get()),
+            mmfar: MmfarValue::from(p.mmfar.get()),
+            bfar: BfarValue::from(p.bfar.get()),
+            afsr: AfsrValue::from(p.afsr.get()),
+            cpacr: CpacrValue::from(p.cpacr.get()),
+        }
+    }
+    #[doc = "Write a previously captured state back to the peripheral"]
+    #[allow(unused_variables)]
+    pub fn restore(p: &Scb, snapshot: &Snapshot) {
+        CpuidUpdate::new_ignoring_state(&p.cpuid).assign(snapshot.cpuid);
+        IcsrUpdate::new_ignoring_state(&p.icsr).assign(snapshot.icsr);
+        VtorUpdate::new_ignoring_state(&p.vtor).assign(snapshot.vtor);
+        AircrUpdate::new_ignoring_state(&p.aircr).assign(snapshot.aircr);
+        ScrUpdate::new_ignoring_state(&p.scr).assign(snapshot.scr);
+        CcrUpdate::new_ignoring_state(&p.ccr).assign(snapshot.ccr);
+        for i in 0..3 {
+            ShprUpdate::new_ignoring_state(&p.shpr[i]).assign(snapshot.shpr[i]);
+        }
+        ShcsrUpdate::new_ignoring_state(&p.shcsr).assign(snapshot.shcsr);
+        CfsrUpdate::new_ignoring_state(&p.cfsr).assign(snapshot.cfsr);
+        HfsrUpdate::new_ignoring_state(&p.hfsr).assign(snapshot.hfsr);
+        DfsrUpdate::new_ignoring_state(&p.dfsr).assign(snapshot.dfsr);
+        MmfarUpdate::new_ignoring_state(&p.mmfar).assign(snapshot.mmfar);
+        BfarUpdate::new_ignoring_state(&p.bfar).assign(snapshot.bfar);
+        AfsrUpdate::new_ignoring_state(&p.afsr).assign(snapshot.afsr);
+        CpacrUpdate::new_ignoring_state(&p.cpacr).assign(snapshot.cpacr);
+    }
+    #[doc = "Owned handle granting exclusive access to the peripheral"]
+    pub struct ScbPeripheral {
+        regs: &'static mut Scb,
+    }
+    impl ScbPeripheral {
+        #[doc = "Create a token for the peripheral registers at `regs`."]
+        #[doc = ""]
+        #[doc = "Unsafe because the caller must ensure it's the only token for `regs`."]
+        #[inline(always)]
+        pub const unsafe fn new(regs: &'static mut Scb) -> ScbPeripheral {
+            ScbPeripheral { regs: regs }
+        }
+    }
+    impl ::core::ops::Deref for ScbPeripheral {
+        type Target = Scb;
+        #[inline(always)]
+        fn deref(&self) -> &Scb {
+            self.regs
+        }
+    }
+    impl ::core::ops::DerefMut for ScbPeripheral {
+        #[inline(always)]
+        fn deref_mut(&mut self) -> &mut Scb {
+            self.regs
+        }
+    }
+}
@@ -0,0 +1,1190 @@
+pub mod systick {
+    pub mod csr {
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        #[repr(u32)]
+        pub enum Clksource {
+            External = 0,
+            CPU = 1,
+        }
+        impl core::convert::TryFrom<u32> for Clksource {
+            type Error = u32;
+            #[inline(always)]
+            fn try_from(primitive: u32) -> Result<Self, Self::Error> {
+                match primitive {
+                    0 => Ok(Clksource::External),
+                    1 => Ok(Clksource::CPU),
+                    v => Err(v),
+                }
+            }
+        }
+    }
+    #[repr(C)]
+    pub struct Csr {
+        value: ::volatile_cell::VolatileCell<u32>,
+    }
+    impl Csr {
+        #[inline(always)]
+        fn load(&self) -> u32 {
+            let value = self.value.get();
+            value
+        }
+        #[inline(always)]
+        fn store(&self, value: u32) {
+            self.value.set(value);
+        }
+        #[doc = "Create a new updater"]
+        #[inline(always)]
+        pub fn update<'a>(&'a self) -> CsrUpdate<'a> {
+            CsrUpdate::new(self)
+        }
+        #[doc = "Create an updater whose read-modify-write happens inside the critical section `cs`"]
+        #[inline(always)]
+        pub fn update_in<'a, C: ::volatile_cell::sync::CriticalSection>(
+            &'a self,
+            _cs: &'a C,
+        ) -> CsrUpdate<'a> {
+            CsrUpdate::new(self)
+        }
+        #[doc = "Update the register with exclusive loads and stores (LDREX/STREX), retrying `f` until no other access intervened"]
+        #[doc = ""]
+        #[doc = "The exclusive accesses go to the register's memory directly rather than through its cell, so they aren't seen by the replayer or the tracing hook."]
+        #[cfg(target_has_atomic = "32")]
+        #[inline]
+        pub fn update_exclusive<F: FnMut(&mut CsrUpdate)>(&self, mut f: F) {
+            use ::core::sync::atomic::Ordering;
+            let cell = unsafe { &*(self.value.as_ptr() as *const ::core::sync::atomic::AtomicU32) };
+            let mut current = cell.load(Ordering::Relaxed);
+            loop {
+                let mut update = CsrUpdate::detached(self);
+                f(&mut update);
+                let merged = update.merge(current);
+                if update.mask == 0 {
+                    return;
+                }
+                match cell.compare_exchange_weak(
+                    current,
+                    merged,
+                    Ordering::SeqCst,
+                    Ordering::Relaxed,
+                ) {
+                    Ok(_) => return,
+                    Err(actual) => current = actual,
+                }
+            }
+        }
+        #[doc = "Create a getter representing the current state of the register"]
+        #[inline(always)]
+        pub fn get(&self) -> CsrGet {
+            CsrGet::new(self)
+        }
+        #[doc = "Spin until the bit is set"]
+        #[inline]
+        pub fn wait_countflag(&self) {
+            while !self.get().countflag() {}
+        }
+        #[doc = "Poll until the bit is set, at most `max_polls` times"]
+        #[inline]
+        pub fn wait_countflag_timeout(
+            &self,
+            max_polls: u32,
+        ) -> Result<(), ::volatile_cell::wait::Timeout> {
+            ::volatile_cell::wait::poll_until(|| self.get().countflag(), max_polls)
+        }
+        #[doc = "Future resolving once the bit is set"]
+        #[inline]
+        pub fn wait_countflag_async<'a>(
+            &'a self,
+        ) -> ::volatile_cell::wait::WaitUntil<'a, impl FnMut() -> bool + Unpin + 'a> {
+            ::volatile_cell::wait::WaitUntil::new(move || self.get().countflag())
+        }
+        #[doc = "Spin until the bit is set"]
+        #[inline]
+        pub fn wait_tickint(&self) {
+            while !self.get().tickint() {}
+        }
+        #[doc = "Poll until the bit is set, at most `max_polls` times"]
+        #[inline]
+        pub fn wait_tickint_timeout(
+            &self,
+            max_polls: u32,
+        ) -> Result<(), ::volatile_cell::wait::Timeout> {
+            ::volatile_cell::wait::poll_until(|| self.get().tickint(), max_polls)
+        }
+        #[doc = "Future resolving once the bit is set"]
+        #[inline]
+        pub fn wait_tickint_async<'a>(
+            &'a self,
+        ) -> ::volatile_cell::wait::WaitUntil<'a, impl FnMut() -> bool + Unpin + 'a> {
+            ::volatile_cell::wait::WaitUntil::new(move || self.get().tickint())
+        }
+        #[doc = "Spin until the bit is set"]
+        #[inline]
+        pub fn wait_enable(&self) {
+            while !self.get().enable() {}
+        }
+        #[doc = "Poll until the bit is set, at most `max_polls` times"]
+        #[inline]
+        pub fn wait_enable_timeout(
+            &self,
+            max_polls: u32,
+        ) -> Result<(), ::volatile_cell::wait::Timeout> {
+            ::volatile_cell::wait::poll_until(|| self.get().enable(), max_polls)
+        }
+        #[doc = "Future resolving once the bit is set"]
+        #[inline]
+        pub fn wait_enable_async<'a>(
+            &'a self,
+        ) -> ::volatile_cell::wait::WaitUntil<'a, impl FnMut() -> bool + Unpin + 'a> {
+            ::volatile_cell::wait::WaitUntil::new(move || self.get().enable())
+        }
+    }
+    pub struct CsrUpdate<'a> {
+        value: u32,
+        mask: u32,
+        write_only: bool,
+        store_on_drop: bool,
+        reg: &'a Csr,
+    }
+    impl<'a> CsrUpdate<'a> {
+        #[inline(always)]
+        pub fn new(reg: &'a Csr) -> CsrUpdate<'a> {
+            CsrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[inline(always)]
+        pub fn new_ignoring_state(reg: &'a Csr) -> CsrUpdate<'a> {
+            CsrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: true,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[doc = "An update that is never stored, for the caller to store itself"]
+        #[inline(always)]
+        fn detached(reg: &'a Csr) -> CsrUpdate<'a> {
+            CsrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: false,
+                reg: reg,
+            }
+        }
+        const fn clear_mask() -> u32 {
+            0u64 as u32
+        }
+        const fn update_offset(base: usize, size: usize, index: usize) -> usize {
+            base + (size * index)
+        }
+        #[inline(always)]
+        fn merge(&self, current: u32) -> u32 {
+            let v: u32 = current & (!Self::clear_mask()) & (!self.mask);
+            self.value | v
+        }
+        #[inline(always)]
+        pub fn set_clksource<'b>(&'b mut self, new_value: csr::Clksource) -> &'b mut Self {
+            let context_mask: u32 = 1 << 2;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 1) << 2);
+            self.mask |= context_mask;
+            self
+        }
+        #[inline(always)]
+        pub fn set_tickint<'b>(&'b mut self, new_value: bool) -> &'b mut Self {
+            let context_mask: u32 = 1 << 1;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 1) << 1);
+            self.mask |= context_mask;
+            self
+        }
+        #[inline(always)]
+        pub fn set_enable<'b>(&'b mut self, new_value: bool) -> &'b mut Self {
+            let context_mask: u32 = 1 << 0;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 1) << 0);
+            self.mask |= context_mask;
+            self
+        }
+    }
+    impl<'a> Drop for CsrUpdate<'a> {
+        #[inline(always)]
+        fn drop(&mut self) {
+            if self.store_on_drop && self.mask != 0 {
+                let current: u32 = if self.write_only { 0 } else { self.reg.load() };
+                self.reg.store(self.merge(current));
+            }
+        }
+    }
+    #[derive(Clone, PartialEq, Eq)]
+    pub struct CsrGet {
+        value: u32,
+    }
+    impl CsrGet {
+        #[doc = "Create a getter reflecting the current value of the register"]
+        #[inline(always)]
+        pub fn new(reg: &Csr) -> CsrGet {
+            CsrGet { value: reg.load() }
+        }
+        #[inline(always)]
+        pub fn countflag(&self) -> bool {
+            let val = ((self.value >> 16) & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub fn clksource(&self) -> csr::Clksource {
+            unsafe { core::mem::transmute::<_, csr::Clksource>(((self.value >> 2) & 1)) }
+        }
+        #[inline(always)]
+        pub fn tickint(&self) -> bool {
+            let val = ((self.value >> 1) & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub fn enable(&self) -> bool {
+            let val = (self.value & 1);
+            val != 0x0
+        }
+    }
+    #[derive(Clone, Copy, PartialEq, Eq, Default)]
+    #[repr(transparent)]
+    pub struct CsrValue {
+        value: u32,
+    }
+    impl CsrValue {
+        #[doc = "Create a value with every field cleared"]
+        #[inline(always)]
+        pub const fn new() -> CsrValue {
+            CsrValue { value: 0 }
+        }
+        #[inline(always)]
+        pub const fn from_bits(bits: u32) -> CsrValue {
+            CsrValue { value: bits }
+        }
+        #[inline(always)]
+        pub const fn to_bits(&self) -> u32 {
+            self.value
+        }
+        #[inline(always)]
+        pub fn countflag(&self) -> bool {
+            let val = ((self.value >> 16) & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub fn clksource(&self) -> csr::Clksource {
+            unsafe { core::mem::transmute::<_, csr::Clksource>(((self.value >> 2) & 1)) }
+        }
+        #[inline(always)]
+        pub fn tickint(&self) -> bool {
+            let val = ((self.value >> 1) & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub fn enable(&self) -> bool {
+            let val = (self.value & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub const fn with_countflag(self, new_value: bool) -> Self {
+            let context_mask: u32 = 1 << 16;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 1) << 16),
+            }
+        }
+        #[inline(always)]
+        pub const fn with_clksource(self, new_value: csr::Clksource) -> Self {
+            let context_mask: u32 = 1 << 2;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 1) << 2),
+            }
+        }
+        #[inline(always)]
+        pub const fn with_tickint(self, new_value: bool) -> Self {
+            let context_mask: u32 = 1 << 1;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 1) << 1),
+            }
+        }
+        #[inline(always)]
+        pub const fn with_enable(self, new_value: bool) -> Self {
+            let context_mask: u32 = 1 << 0;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 1) << 0),
+            }
+        }
+    }
+    impl From<CsrGet> for CsrValue {
+        #[inline(always)]
+        fn from(get: CsrGet) -> CsrValue {
+            CsrValue::from_bits(get.value)
+        }
+    }
+    impl From<CsrValue> for CsrGet {
+        #[inline(always)]
+        fn from(value: CsrValue) -> CsrGet {
+            CsrGet {
+                value: value.to_bits(),
+            }
+        }
+    }
+    impl Csr {
+        #[doc = "Write `value` to the register in a single store, without reading it first"]
+        #[inline(always)]
+        pub fn write(&self, value: CsrValue) {
+            self.store(value.to_bits());
+        }
+    }
+    impl<'a> CsrUpdate<'a> {
+        #[doc = "Replace every field of the register with those of `value`"]
+        #[inline(always)]
+        pub fn assign<'b>(&'b mut self, value: CsrValue) -> &'b mut Self {
+            self.value = value.to_bits();
+            self.mask = !0;
+            self
+        }
+    }
+    impl CsrGet {
+        pub const FIELDS: &'static [::volatile_cell::field::FieldDescriptor] = &[
+            ::volatile_cell::field::FieldDescriptor {
+                name: "countflag",
+                offset: 16u32,
+                width: 1u32,
+                count: 1u32,
+            },
+            ::volatile_cell::field::FieldDescriptor {
+                name: "clksource",
+                offset: 2u32,
+                width: 1u32,
+                count: 1u32,
+            },
+            ::volatile_cell::field::FieldDescriptor {
+                name: "tickint",
+                offset: 1u32,
+                width: 1u32,
+                count: 1u32,
+            },
+            ::volatile_cell::field::FieldDescriptor {
+                name: "enable",
+                offset: 0u32,
+                width: 1u32,
+                count: 1u32,
+            },
+        ];
+        #[doc = "Iterate over the readable fields that differ from those of `other`"]
+        #[inline]
+        pub fn changed_fields(&self, other: &CsrGet) -> ::volatile_cell::field::FieldChanges {
+            ::volatile_cell::field::FieldChanges::new(
+                Self::FIELDS,
+                self.value as u64,
+                other.value as u64,
+            )
+        }
+    }
+    pub mod rvr {}
+    #[repr(C)]
+    pub struct Rvr {
+        value: ::volatile_cell::VolatileCell<u32>,
+    }
+    impl Rvr {
+        #[inline(always)]
+        fn load(&self) -> u32 {
+            let value = self.value.get();
+            value
+        }
+        #[inline(always)]
+        fn store(&self, value: u32) {
+            self.value.set(value);
+        }
+        #[doc = "Create a new updater"]
+        #[inline(always)]
+        pub fn update<'a>(&'a self) -> RvrUpdate<'a> {
+            RvrUpdate::new(self)
+        }
+        #[doc = "Create an updater whose read-modify-write happens inside the critical section `cs`"]
+        #[inline(always)]
+        pub fn update_in<'a, C: ::volatile_cell::sync::CriticalSection>(
+            &'a self,
+            _cs: &'a C,
+        ) -> RvrUpdate<'a> {
+            RvrUpdate::new(self)
+        }
+        #[doc = "Update the register with exclusive loads and stores (LDREX/STREX), retrying `f` until no other access intervened"]
+        #[doc = ""]
+        #[doc = "The exclusive accesses go to the register's memory directly rather than through its cell, so they aren't seen by the replayer or the tracing hook."]
+        #[cfg(target_has_atomic = "32")]
+        #[inline]
+        pub fn update_exclusive<F: FnMut(&mut RvrUpdate)>(&self, mut f: F) {
+            use ::core::sync::atomic::Ordering;
+            let cell = unsafe { &*(self.value.as_ptr() as *const ::core::sync::atomic::AtomicU32) };
+            let mut current = cell.load(Ordering::Relaxed);
+            loop {
+                let mut update = RvrUpdate::detached(self);
+                f(&mut update);
+                let merged = update.merge(current);
+                if update.mask == 0 {
+                    return;
+                }
+                match cell.compare_exchange_weak(
+                    current,
+                    merged,
+                    Ordering::SeqCst,
+                    Ordering::Relaxed,
+                ) {
+                    Ok(_) => return,
+                    Err(actual) => current = actual,
+                }
+            }
+        }
+        #[doc = "Create a getter representing the current state of the register"]
+        #[inline(always)]
+        pub fn get(&self) -> RvrGet {
+            RvrGet::new(self)
+        }
+    }
+    pub struct RvrUpdate<'a> {
+        value: u32,
+        mask: u32,
+        write_only: bool,
+        store_on_drop: bool,
+        reg: &'a Rvr,
+    }
+    impl<'a> RvrUpdate<'a> {
+        #[inline(always)]
+        pub fn new(reg: &'a Rvr) -> RvrUpdate<'a> {
+            RvrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[inline(always)]
+        pub fn new_ignoring_state(reg: &'a Rvr) -> RvrUpdate<'a> {
+            RvrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: true,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[doc = "An update that is never stored, for the caller to store itself"]
+        #[inline(always)]
+        fn detached(reg: &'a Rvr) -> RvrUpdate<'a> {
+            RvrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: false,
+                reg: reg,
+            }
+        }
+        const fn clear_mask() -> u32 {
+            0u64 as u32
+        }
+        const fn update_offset(base: usize, size: usize, index: usize) -> usize {
+            base + (size * index)
+        }
+        #[inline(always)]
+        fn merge(&self, current: u32) -> u32 {
+            let v: u32 = current & (!Self::clear_mask()) & (!self.mask);
+            self.value | v
+        }
+        #[inline(always)]
+        pub fn set_reload<'b>(&'b mut self, new_value: u32) -> &'b mut Self {
+            let context_mask: u32 = 16777215 << 0;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 16777215) << 0);
+            self.mask |= context_mask;
+            self
+        }
+    }
+    impl<'a> Drop for RvrUpdate<'a> {
+        #[inline(always)]
+        fn drop(&mut self) {
+            if self.store_on_drop && self.mask != 0 {
+                let current: u32 = if self.write_only { 0 } else { self.reg.load() };
+                self.reg.store(self.merge(current));
+            }
+        }
+    }
+    #[derive(Clone, PartialEq, Eq)]
+    pub struct RvrGet {
+        value: u32,
+    }
+    impl RvrGet {
+        #[doc = "Create a getter reflecting the current value of the register"]
+        #[inline(always)]
+        pub fn new(reg: &Rvr) -> RvrGet {
+            RvrGet { value: reg.load() }
+        }
+        #[inline(always)]
+        pub fn reload(&self) -> u32 {
+            (self.value & 16777215)
+        }
+    }
+    #[derive(Clone, Copy, PartialEq, Eq, Default)]
+    #[repr(transparent)]
+    pub struct RvrValue {
+        value: u32,
+    }
+    impl RvrValue {
+        #[doc = "Create a value with every field cleared"]
+        #[inline(always)]
+        pub const fn new() -> RvrValue {
+            RvrValue { value: 0 }
+        }
+        #[inline(always)]
+        pub const fn from_bits(bits: u32) -> RvrValue {
+            RvrValue { value: bits }
+        }
+        #[inline(always)]
+        pub const fn to_bits(&self) -> u32 {
+            self.value
+        }
+        #[inline(always)]
+        pub fn reload(&self) -> u32 {
+            (self.value & 16777215)
+        }
+        #[inline(always)]
+        pub const fn with_reload(self, new_value: u32) -> Self {
+            let context_mask: u32 = 16777215 << 0;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 16777215) << 0),
+            }
+        }
+    }
+    impl From<RvrGet> for RvrValue {
+        #[inline(always)]
+        fn from(get: RvrGet) -> RvrValue {
+            RvrValue::from_bits(get.value)
+        }
+    }
+    impl From<RvrValue> for RvrGet {
+        #[inline(always)]
+        fn from(value: RvrValue) -> RvrGet {
+            RvrGet {
+                value: value.to_bits(),
+            }
+        }
+    }
+    impl Rvr {
+        #[doc = "Write `value` to the register in a single store, without reading it first"]
+        #[inline(always)]
+        pub fn write(&self, value: RvrValue) {
+            self.store(value.to_bits());
+        }
+    }
+    impl<'a> RvrUpdate<'a> {
+        #[doc = "Replace every field of the register with those of `value`"]
+        #[inline(always)]
+        pub fn assign<'b>(&'b mut self, value: RvrValue) -> &'b mut Self {
+            self.value = value.to_bits();
+            self.mask = !0;
+            self
+        }
+    }
+    impl RvrGet {
+        pub const FIELDS: &'static [::volatile_cell::field::FieldDescriptor] =
+            &[::volatile_cell::field::FieldDescriptor {
+                name: "reload",
+                offset: 0u32,
+                width: 24u32,
+                count: 1u32,
+            }];
+        #[doc = "Iterate over the readable fields that differ from those of `other`"]
+        #[inline]
+        pub fn changed_fields(&self, other: &RvrGet) -> ::volatile_cell::field::FieldChanges {
+            ::volatile_cell::field::FieldChanges::new(
+                Self::FIELDS,
+                self.value as u64,
+                other.value as u64,
+            )
+        }
+    }
+    pub mod cvr {}
+    #[repr(C)]
+    pub struct Cvr {
+        value: ::volatile_cell::VolatileCell<u32>,
+    }
+    impl Cvr {
+        #[inline(always)]
+        fn load(&self) -> u32 {
+            let value = self.value.get();
+            value
+        }
+        #[inline(always)]
+        fn store(&self, value: u32) {
+            self.value.set(value);
+        }
+        #[doc = "Create a new updater"]
+        #[inline(always)]
+        pub fn update<'a>(&'a self) -> CvrUpdate<'a> {
+            CvrUpdate::new(self)
+        }
+        #[doc = "Create an updater whose read-modify-write happens inside the critical section `cs`"]
+        #[inline(always)]
+        pub fn update_in<'a, C: ::volatile_cell::sync::CriticalSection>(
+            &'a self,
+            _cs: &'a C,
+        ) -> CvrUpdate<'a> {
+            CvrUpdate::new(self)
+        }
+        #[doc = "Update the register with exclusive loads and stores (LDREX/STREX), retrying `f` until no other access intervened"]
+        #[doc = ""]
+        #[doc = "The exclusive accesses go to the register's memory directly rather than through its cell, so they aren't seen by the replayer or the tracing hook."]
+        #[cfg(target_has_atomic = "32")]
+        #[inline]
+        pub fn update_exclusive<F: FnMut(&mut CvrUpdate)>(&self, mut f: F) {
+            use ::core::sync::atomic::Ordering;
+            let cell = unsafe { &*(self.value.as_ptr() as *const ::core::sync::atomic::AtomicU32) };
+            let mut current = cell.load(Ordering::Relaxed);
+            loop {
+                let mut update = CvrUpdate::detached(self);
+                f(&mut update);
+                let merged = update.merge(current);
+                if update.mask == 0 {
+                    return;
+                }
+                match cell.compare_exchange_weak(
+                    current,
+                    merged,
+                    Ordering::SeqCst,
+                    Ordering::Relaxed,
+                ) {
+                    Ok(_) => return,
+                    Err(actual) => current = actual,
+                }
+            }
+        }
+        #[doc = "Create a getter representing the current state of the register"]
+        #[inline(always)]
+        pub fn get(&self) -> CvrGet {
+            CvrGet::new(self)
+        }
+    }
+    pub struct CvrUpdate<'a> {
+        value: u32,
+        mask: u32,
+        write_only: bool,
+        store_on_drop: bool,
+        reg: &'a Cvr,
+    }
+    impl<'a> CvrUpdate<'a> {
+        #[inline(always)]
+        pub fn new(reg: &'a Cvr) -> CvrUpdate<'a> {
+            CvrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[inline(always)]
+        pub fn new_ignoring_state(reg: &'a Cvr) -> CvrUpdate<'a> {
+            CvrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: true,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[doc = "An update that is never stored, for the caller to store itself"]
+        #[inline(always)]
+        fn detached(reg: &'a Cvr) -> CvrUpdate<'a> {
+            CvrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: false,
+                reg: reg,
+            }
+        }
+        const fn clear_mask() -> u32 {
+            0u64 as u32
+        }
+        const fn update_offset(base: usize, size: usize, index: usize) -> usize {
+            base + (size * index)
+        }
+        #[inline(always)]
+        fn merge(&self, current: u32) -> u32 {
+            let v: u32 = current & (!Self::clear_mask()) & (!self.mask);
+            self.value | v
+        }
+        #[inline(always)]
+        pub fn set_current<'b>(&'b mut self, new_value: u32) -> &'b mut Self {
+            let context_mask: u32 = 4294967295 << 0;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 4294967295) << 0);
+            self.mask |= context_mask;
+            self
+        }
+    }
+    impl<'a> Drop for CvrUpdate<'a> {
+        #[inline(always)]
+        fn drop(&mut self) {
+            if self.store_on_drop && self.mask != 0 {
+                let current: u32 = if self.write_only { 0 } else { self.reg.load() };
+                self.reg.store(self.merge(current));
+            }
+        }
+    }
+    #[derive(Clone, PartialEq, Eq)]
+    pub struct CvrGet {
+        value: u32,
+    }
+    impl CvrGet {
+        #[doc = "Create a getter reflecting the current value of the register"]
+        #[inline(always)]
+        pub fn new(reg: &Cvr) -> CvrGet {
+            CvrGet { value: reg.load() }
+        }
+        #[inline(always)]
+        pub fn current(&self) -> u32 {
+            self.value
+        }
+    }
+    #[derive(Clone, Copy, PartialEq, Eq, Default)]
+    #[repr(transparent)]
+    pub struct CvrValue {
+        value: u32,
+    }
+    impl CvrValue {
+        #[doc = "Create a value with every field cleared"]
+        #[inline(always)]
+        pub const fn new() -> CvrValue {
+            CvrValue { value: 0 }
+        }
+        #[inline(always)]
+        pub const fn from_bits(bits: u32) -> CvrValue {
+            CvrValue { value: bits }
+        }
+        #[inline(always)]
+        pub const fn to_bits(&self) -> u32 {
+            self.value
+        }
+        #[inline(always)]
+        pub fn current(&self) -> u32 {
+            self.value
+        }
+        #[inline(always)]
+        pub const fn with_current(self, new_value: u32) -> Self {
+            let context_mask: u32 = 4294967295 << 0;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 4294967295) << 0),
+            }
+        }
+    }
+    impl From<CvrGet> for CvrValue {
+        #[inline(always)]
+        fn from(get: CvrGet) -> CvrValue {
+            CvrValue::from_bits(get.value)
+        }
+    }
+    impl From<CvrValue> for CvrGet {
+        #[inline(always)]
+        fn from(value: CvrValue) -> CvrGet {
+            CvrGet {
+                value: value.to_bits(),
+            }
+        }
+    }
+    impl Cvr {
+        #[doc = "Write `value` to the register in a single store, without reading it first"]
+        #[inline(always)]
+        pub fn write(&self, value: CvrValue) {
+            self.store(value.to_bits());
+        }
+    }
+    impl<'a> CvrUpdate<'a> {
+        #[doc = "Replace every field of the register with those of `value`"]
+        #[inline(always)]
+        pub fn assign<'b>(&'b mut self, value: CvrValue) -> &'b mut Self {
+            self.value = value.to_bits();
+            self.mask = !0;
+            self
+        }
+    }
+    impl CvrGet {
+        pub const FIELDS: &'static [::volatile_cell::field::FieldDescriptor] =
+            &[::volatile_cell::field::FieldDescriptor {
+                name: "current",
+                offset: 0u32,
+                width: 32u32,
+                count: 1u32,
+            }];
+        #[doc = "Iterate over the readable fields that differ from those of `other`"]
+        #[inline]
+        pub fn changed_fields(&self, other: &CvrGet) -> ::volatile_cell::field::FieldChanges {
+            ::volatile_cell::field::FieldChanges::new(
+                Self::FIELDS,
+                self.value as u64,
+                other.value as u64,
+            )
+        }
+    }
+    pub mod calib {}
+    #[repr(C)]
+    pub struct Calib {
+        value: ::volatile_cell::VolatileCell<u32>,
+    }
+    impl Calib {
+        #[inline(always)]
+        fn load(&self) -> u32 {
+            let value = self.value.get();
+            value
+        }
+        #[inline(always)]
+        fn store(&self, value: u32) {
+            self.value.set(value);
+        }
+        #[doc = "Create a new updater"]
+        #[inline(always)]
+        pub fn update<'a>(&'a self) -> CalibUpdate<'a> {
+            CalibUpdate::new(self)
+        }
+        #[doc = "Create an updater whose read-modify-write happens inside the critical section `cs`"]
+        #[inline(always)]
+        pub fn update_in<'a, C: ::volatile_cell::sync::CriticalSection>(
+            &'a self,
+            _cs: &'a C,
+        ) -> CalibUpdate<'a> {
+            CalibUpdate::new(self)
+        }
+        #[doc = "Update the register with exclusive loads and stores (LDREX/STREX), retrying `f` until no other access intervened"]
+        #[doc = ""]
+        #[doc = "The exclusive accesses go to the register's memory directly rather than through its cell, so they aren't seen by the replayer or the tracing hook."]
+        #[cfg(target_has_atomic = "32")]
+        #[inline]
+        pub fn update_exclusive<F: FnMut(&mut CalibUpdate)>(&self, mut f: F) {
+            use ::core::sync::atomic::Ordering;
+            let cell = unsafe { &*(self.value.as_ptr() as *const ::core::sync::atomic::AtomicU32) };
+            let mut current = cell.load(Ordering::Relaxed);
+            loop {
+                let mut update = CalibUpdate::detached(self);
+                f(&mut update);
+                let merged = update.merge(current);
+                if update.mask == 0 {
+                    return;
+                }
+                match cell.compare_exchange_weak(
+                    current,
+                    merged,
+                    Ordering::SeqCst,
+                    Ordering::Relaxed,
+                ) {
+                    Ok(_) => return,
+                    Err(actual) => current = actual,
+                }
+            }
+        }
+        #[doc = "Create a getter representing the current state of the register"]
+        #[inline(always)]
+        pub fn get(&self) -> CalibGet {
+            CalibGet::new(self)
+        }
+        #[doc = "Spin until the bit is set"]
+        #[inline]
+        pub fn wait_noref(&self) {
+            while !self.get().noref() {}
+        }
+        #[doc = "Poll until the bit is set, at most `max_polls` times"]
+        #[inline]
+        pub fn wait_noref_timeout(
+            &self,
+            max_polls: u32,
+        ) -> Result<(), ::volatile_cell::wait::Timeout> {
+            ::volatile_cell::wait::poll_until(|| self.get().noref(), max_polls)
+        }
+        #[doc = "Future resolving once the bit is set"]
+        #[inline]
+        pub fn wait_noref_async<'a>(
+            &'a self,
+        ) -> ::volatile_cell::wait::WaitUntil<'a, impl FnMut() -> bool + Unpin + 'a> {
+            ::volatile_cell::wait::WaitUntil::new(move || self.get().noref())
+        }
+        #[doc = "Spin until the bit is set"]
+        #[inline]
+        pub fn wait_skew(&self) {
+            while !self.get().skew() {}
+        }
+        #[doc = "Poll until the bit is set, at most `max_polls` times"]
+        #[inline]
+        pub fn wait_skew_timeout(
+            &self,
+            max_polls: u32,
+        ) -> Result<(), ::volatile_cell::wait::Timeout> {
+            ::volatile_cell::wait::poll_until(|| self.get().skew(), max_polls)
+        }
+        #[doc = "Future resolving once the bit is set"]
+        #[inline]
+        pub fn wait_skew_async<'a>(
+            &'a self,
+        ) -> ::volatile_cell::wait::WaitUntil<'a, impl FnMut() -> bool + Unpin + 'a> {
+            ::volatile_cell::wait::WaitUntil::new(move || self.get().skew())
+        }
+    }
+    pub struct CalibUpdate<'a> {
+        value: u32,
+        mask: u32,
+        write_only: bool,
+        store_on_drop: bool,
+        reg: &'a Calib,
+    }
+    impl<'a> CalibUpdate<'a> {
+        #[inline(always)]
+        pub fn new(reg: &'a Calib) -> CalibUpdate<'a> {
+            CalibUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[inline(always)]
+        pub fn new_ignoring_state(reg: &'a Calib) -> CalibUpdate<'a> {
+            CalibUpdate {
+                value: 0,
+                mask: 0,
+                write_only: true,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[doc = "An update that is never stored, for the caller to store itself"]
+        #[inline(always)]
+        fn detached(reg: &'a Calib) -> CalibUpdate<'a> {
+            CalibUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: false,
+                reg: reg,
+            }
+        }
+        const fn clear_mask() -> u32 {
+            0u64 as u32
+        }
+        const fn update_offset(base: usize, size: usize, index: usize) -> usize {
+            base + (size * index)
+        }
+        #[inline(always)]
+        fn merge(&self, current: u32) -> u32 {
+            let v: u32 = current & (!Self::clear_mask()) & (!self.mask);
+            self.value | v
+        }
+    }
+    impl<'a> Drop for CalibUpdate<'a> {
+        #[inline(always)]
+        fn drop(&mut self) {
+            if self.store_on_drop && self.mask != 0 {
+                let current: u32 = if self.write_only { 0 } else { self.reg.load() };
+                self.reg.store(self.merge(current));
+            }
+        }
+    }
+    #[derive(Clone, PartialEq, Eq)]
+    pub struct CalibGet {
+        value: u32,
+    }
+    impl CalibGet {
+        #[doc = "Create a getter reflecting the current value of the register"]
+        #[inline(always)]
+        pub fn new(reg: &Calib) -> CalibGet {
+            CalibGet { value: reg.load() }
+        }
+        #[inline(always)]
+        pub fn noref(&self) -> bool {
+            let val = (self.value >> 31);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub fn skew(&self) -> bool {
+            let val = ((self.value >> 30) & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub fn tenms(&self) -> u32 {
+            (self.value & 16777215)
+        }
+    }
+    #[derive(Clone, Copy, PartialEq, Eq, Default)]
+    #[repr(transparent)]
+    pub struct CalibValue {
+        value: u32,
+    }
+    impl CalibValue {
+        #[doc = "Create a value with every field cleared"]
+        #[inline(always)]
+        pub const fn new() -> CalibValue {
+            CalibValue { value: 0 }
+        }
+        #[inline(always)]
+        pub const fn from_bits(bits: u32) -> CalibValue {
+            CalibValue { value: bits }
+        }
+        #[inline(always)]
+        pub const fn to_bits(&self) -> u32 {
+            self.value
+        }
+        #[inline(always)]
+        pub fn noref(&self) -> bool {
+            let val = (self.value >> 31);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub fn skew(&self) -> bool {
+            let val = ((self.value >> 30) & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub fn tenms(&self) -> u32 {
+            (self.value & 16777215)
+        }
+        #[inline(always)]
+        pub const fn with_noref(self, new_value: bool) -> Self {
+            let context_mask: u32 = 1 << 31;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 1) << 31),
+            }
+        }
+        #[inline(always)]
+        pub const fn with_skew(self, new_value: bool) -> Self {
+            let context_mask: u32 = 1 << 30;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 1) << 30),
+            }
+        }
+        #[inline(always)]
+        pub const fn with_tenms(self, new_value: u32) -> Self {
+            let context_mask: u32 = 16777215 << 0;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 16777215) << 0),
+            }
+        }
+    }
+    impl From<CalibGet> for CalibValue {
+        #[inline(always)]
+        fn from(get: CalibGet) -> CalibValue {
+            CalibValue::from_bits(get.value)
+        }
+    }
+    impl From<CalibValue> for CalibGet {
+        #[inline(always)]
+        fn from(value: CalibValue) -> CalibGet {
+            CalibGet {
+                value: value.to_bits(),
+            }
+        }
+    }
+    impl Calib {
+        #[doc = "Write `value` to the register in a single store, without reading it first"]
+        #[inline(always)]
+        pub fn write(&self, value: CalibValue) {
+            self.store(value.to_bits());
+        }
+    }
+    impl<'a> CalibUpdate<'a> {
+        #[doc = "Replace every field of the register with those of `value`"]
+        #[inline(always)]
+        pub fn assign<'b>(&'b mut self, value: CalibValue) -> &'b mut Self {
+            self.value = value.to_bits();
+            self.mask = !0;
+            self
+        }
+    }
+    impl CalibGet {
+        pub const FIELDS: &'static [::volatile_cell::field::FieldDescriptor] = &[
+            ::volatile_cell::field::FieldDescriptor {
+                name: "noref",
+                offset: 31u32,
+                width: 1u32,
+                count: 1u32,
+            },
+            ::volatile_cell::field::FieldDescriptor {
+                name: "skew",
+                offset: 30u32,
+                width: 1u32,
+                count: 1u32,
+            },
+            ::volatile_cell::field::FieldDescriptor {
+                name: "tenms",
+                offset: 0u32,
+                width: 24u32,
+                count: 1u32,
+            },
+        ];
+        #[doc = "Iterate over the readable fields that differ from those of `other`"]
+        #[inline]
+        pub fn changed_fields(&self, other: &CalibGet) -> ::volatile_cell::field::FieldChanges {
+            ::volatile_cell::field::FieldChanges::new(
+                Self::FIELDS,
+                self.value as u64,
+                other.value as u64,
+            )
+        }
+    }
+    #[repr(C)]
+    pub struct Systick {
+        pub csr: Csr,
+        pub rvr: Rvr,
+        pub cvr: Cvr,
+        pub calib: Calib,
+    }
+    #[doc = "Saved state of every readable register without side effects"]
+    #[derive(Clone, Copy)]
+    pub struct Snapshot {
+        pub csr: CsrValue,
+        pub rvr: RvrValue,
+        pub cvr: CvrValue,
+        pub calib: CalibValue,
+    }
+    #[doc = "Read the current state of the peripheral"]
+    #[allow(unused_variables)]
+    pub fn capture(p: &Systick) -> Snapshot {
+        Snapshot {
+            csr: CsrValue::from(p.csr.get()),
+            rvr: RvrValue::from(p.rvr.get()),
+            cvr: CvrValue::from(p.cvr.get()),
+            calib: CalibValue::from(p.calib.get()),
+        }
+    }
+    #[doc = "Write a previously captured state back to the peripheral"]
+    #[allow(unused_variables)]
+    pub fn restore(p: &Systick, snapshot: &Snapshot) {
+        CsrUpdate::new_ignoring_state(&p.csr).assign(snapshot.csr);
+        RvrUpdate::new_ignoring_state(&p.rvr).assign(snapshot.rvr);
+        CvrUpdate::new_ignoring_state(&p.cvr).assign(snapshot.cvr);
+    }
+    #[doc = "Owned handle granting exclusive access to the peripheral"]
+    pub struct SystickPeripheral {
+        regs: &'static mut Systick,
+    }
+    impl SystickPeripheral {
+        #[doc = "Create a token for the peripheral registers at `regs`."]
+        #[doc = ""]
+        #[doc = "Unsafe because the caller must ensure it's the only token for `regs`."]
+        #[inline(always)]
+        pub const unsafe fn new(regs: &'static mut Systick) -> SystickPeripheral {
+            SystickPeripheral { regs: regs }
+        }
+    }
+    impl ::core::ops::Deref for SystickPeripheral {
+        type Target = Systick;
+        #[inline(always)]
+        fn deref(&self) -> &Systick {
+            self.regs
+        }
+    }
+    impl ::core::ops::DerefMut for SystickPeripheral {
+        #[inline(always)]
+        fn deref_mut(&mut self) -> &mut Systick {
+            self.regs
+        }
+    }
+}
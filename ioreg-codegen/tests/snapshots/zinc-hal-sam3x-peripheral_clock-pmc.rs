@@ -0,0 +1,1318 @@
+pub mod pmc {
+    pub mod pcer0 {}
+    #[repr(C)]
+    pub struct Pcer0 {
+        value: ::volatile_cell::VolatileCell<u32>,
+    }
+    impl Pcer0 {
+        #[inline(always)]
+        fn load(&self) -> u32 {
+            let value = self.value.get();
+            value
+        }
+        #[inline(always)]
+        fn store(&self, value: u32) {
+            self.value.set(value);
+        }
+        #[doc = "Create a new updater"]
+        #[inline(always)]
+        pub fn update<'a>(&'a self) -> Pcer0Update<'a> {
+            Pcer0Update::new(self)
+        }
+        #[doc = "Create an updater whose read-modify-write happens inside the critical section `cs`"]
+        #[inline(always)]
+        pub fn update_in<'a, C: ::volatile_cell::sync::CriticalSection>(
+            &'a self,
+            _cs: &'a C,
+        ) -> Pcer0Update<'a> {
+            Pcer0Update::new(self)
+        }
+        #[doc = "Create a getter representing the current state of the register"]
+        #[inline(always)]
+        pub fn get(&self) -> Pcer0Get {
+            Pcer0Get::new(self)
+        }
+    }
+    pub struct Pcer0Update<'a> {
+        value: u32,
+        mask: u32,
+        write_only: bool,
+        store_on_drop: bool,
+        reg: &'a Pcer0,
+    }
+    impl<'a> Pcer0Update<'a> {
+        #[inline(always)]
+        pub fn new(reg: &'a Pcer0) -> Pcer0Update<'a> {
+            Pcer0Update {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[inline(always)]
+        pub fn new_ignoring_state(reg: &'a Pcer0) -> Pcer0Update<'a> {
+            Pcer0Update {
+                value: 0,
+                mask: 0,
+                write_only: true,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[doc = "An update that is never stored, for the caller to store itself"]
+        #[inline(always)]
+        fn detached(reg: &'a Pcer0) -> Pcer0Update<'a> {
+            Pcer0Update {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: false,
+                reg: reg,
+            }
+        }
+        const fn clear_mask() -> u32 {
+            0u64 as u32
+        }
+        const fn update_offset(base: usize, size: usize, index: usize) -> usize {
+            base + (size * index)
+        }
+        #[inline(always)]
+        fn merge(&self, current: u32) -> u32 {
+            let v: u32 = current & (!Self::clear_mask()) & (!self.mask);
+            self.value | v
+        }
+        #[inline(always)]
+        pub fn set_pce<'b>(&'b mut self, index: usize, new_value: bool) -> &'b mut Self {
+            if index > 32 {
+                panic!();
+            }
+            let update_offset = Self::update_offset(0, 1, index);
+            let context_mask: u32 = 1 << update_offset;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 1) << update_offset);
+            self.mask |= context_mask;
+            self
+        }
+    }
+    impl<'a> Drop for Pcer0Update<'a> {
+        #[inline(always)]
+        fn drop(&mut self) {
+            if self.store_on_drop && self.mask != 0 {
+                let current: u32 = 0;
+                self.reg.store(self.merge(current));
+            }
+        }
+    }
+    #[derive(Clone, PartialEq, Eq)]
+    pub struct Pcer0Get {
+        value: u32,
+    }
+    impl Pcer0Get {
+        #[doc = "Create a getter reflecting the current value of the register"]
+        #[inline(always)]
+        pub fn new(reg: &Pcer0) -> Pcer0Get {
+            Pcer0Get { value: reg.load() }
+        }
+    }
+    #[derive(Clone, Copy, PartialEq, Eq, Default)]
+    #[repr(transparent)]
+    pub struct Pcer0Value {
+        value: u32,
+    }
+    impl Pcer0Value {
+        #[doc = "Create a value with every field cleared"]
+        #[inline(always)]
+        pub const fn new() -> Pcer0Value {
+            Pcer0Value { value: 0 }
+        }
+        #[inline(always)]
+        pub const fn from_bits(bits: u32) -> Pcer0Value {
+            Pcer0Value { value: bits }
+        }
+        #[inline(always)]
+        pub const fn to_bits(&self) -> u32 {
+            self.value
+        }
+        #[inline(always)]
+        pub fn pce(&self, index: usize) -> bool {
+            let val = (self.value >> (0 + (1 * index))) & 1;
+            val != 0x0
+        }
+        #[inline(always)]
+        pub const fn with_pce(self, index: usize, new_value: bool) -> Self {
+            if index >= 32 {
+                panic!();
+            }
+            let shift = 0 + (1 * index);
+            let context_mask: u32 = 1 << shift;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 1) << shift),
+            }
+        }
+    }
+    impl From<Pcer0Get> for Pcer0Value {
+        #[inline(always)]
+        fn from(get: Pcer0Get) -> Pcer0Value {
+            Pcer0Value::from_bits(get.value)
+        }
+    }
+    impl From<Pcer0Value> for Pcer0Get {
+        #[inline(always)]
+        fn from(value: Pcer0Value) -> Pcer0Get {
+            Pcer0Get {
+                value: value.to_bits(),
+            }
+        }
+    }
+    impl Pcer0 {
+        #[doc = "Write `value` to the register in a single store, without reading it first"]
+        #[inline(always)]
+        pub fn write(&self, value: Pcer0Value) {
+            self.store(value.to_bits());
+        }
+    }
+    impl<'a> Pcer0Update<'a> {
+        #[doc = "Replace every field of the register with those of `value`"]
+        #[inline(always)]
+        pub fn assign<'b>(&'b mut self, value: Pcer0Value) -> &'b mut Self {
+            self.value = value.to_bits();
+            self.mask = !0;
+            self
+        }
+    }
+    impl Pcer0Get {
+        pub const FIELDS: &'static [::volatile_cell::field::FieldDescriptor] = &[];
+        #[doc = "Iterate over the readable fields that differ from those of `other`"]
+        #[inline]
+        pub fn changed_fields(&self, other: &Pcer0Get) -> ::volatile_cell::field::FieldChanges {
+            ::volatile_cell::field::FieldChanges::new(
+                Self::FIELDS,
+                self.value as u64,
+                other.value as u64,
+            )
+        }
+    }
+    pub mod pcdr0 {}
+    #[repr(C)]
+    pub struct Pcdr0 {
+        value: ::volatile_cell::VolatileCell<u32>,
+    }
+    impl Pcdr0 {
+        #[inline(always)]
+        fn load(&self) -> u32 {
+            let value = self.value.get();
+            value
+        }
+        #[inline(always)]
+        fn store(&self, value: u32) {
+            self.value.set(value);
+        }
+        #[doc = "Create a new updater"]
+        #[inline(always)]
+        pub fn update<'a>(&'a self) -> Pcdr0Update<'a> {
+            Pcdr0Update::new(self)
+        }
+        #[doc = "Create an updater whose read-modify-write happens inside the critical section `cs`"]
+        #[inline(always)]
+        pub fn update_in<'a, C: ::volatile_cell::sync::CriticalSection>(
+            &'a self,
+            _cs: &'a C,
+        ) -> Pcdr0Update<'a> {
+            Pcdr0Update::new(self)
+        }
+        #[doc = "Create a getter representing the current state of the register"]
+        #[inline(always)]
+        pub fn get(&self) -> Pcdr0Get {
+            Pcdr0Get::new(self)
+        }
+    }
+    pub struct Pcdr0Update<'a> {
+        value: u32,
+        mask: u32,
+        write_only: bool,
+        store_on_drop: bool,
+        reg: &'a Pcdr0,
+    }
+    impl<'a> Pcdr0Update<'a> {
+        #[inline(always)]
+        pub fn new(reg: &'a Pcdr0) -> Pcdr0Update<'a> {
+            Pcdr0Update {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[inline(always)]
+        pub fn new_ignoring_state(reg: &'a Pcdr0) -> Pcdr0Update<'a> {
+            Pcdr0Update {
+                value: 0,
+                mask: 0,
+                write_only: true,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[doc = "An update that is never stored, for the caller to store itself"]
+        #[inline(always)]
+        fn detached(reg: &'a Pcdr0) -> Pcdr0Update<'a> {
+            Pcdr0Update {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: false,
+                reg: reg,
+            }
+        }
+        const fn clear_mask() -> u32 {
+            0u64 as u32
+        }
+        const fn update_offset(base: usize, size: usize, index: usize) -> usize {
+            base + (size * index)
+        }
+        #[inline(always)]
+        fn merge(&self, current: u32) -> u32 {
+            let v: u32 = current & (!Self::clear_mask()) & (!self.mask);
+            self.value | v
+        }
+        #[inline(always)]
+        pub fn set_pcd<'b>(&'b mut self, index: usize, new_value: bool) -> &'b mut Self {
+            if index > 32 {
+                panic!();
+            }
+            let update_offset = Self::update_offset(0, 1, index);
+            let context_mask: u32 = 1 << update_offset;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 1) << update_offset);
+            self.mask |= context_mask;
+            self
+        }
+    }
+    impl<'a> Drop for Pcdr0Update<'a> {
+        #[inline(always)]
+        fn drop(&mut self) {
+            if self.store_on_drop && self.mask != 0 {
+                let current: u32 = 0;
+                self.reg.store(self.merge(current));
+            }
+        }
+    }
+    #[derive(Clone, PartialEq, Eq)]
+    pub struct Pcdr0Get {
+        value: u32,
+    }
+    impl Pcdr0Get {
+        #[doc = "Create a getter reflecting the current value of the register"]
+        #[inline(always)]
+        pub fn new(reg: &Pcdr0) -> Pcdr0Get {
+            Pcdr0Get { value: reg.load() }
+        }
+    }
+    #[derive(Clone, Copy, PartialEq, Eq, Default)]
+    #[repr(transparent)]
+    pub struct Pcdr0Value {
+        value: u32,
+    }
+    impl Pcdr0Value {
+        #[doc = "Create a value with every field cleared"]
+        #[inline(always)]
+        pub const fn new() -> Pcdr0Value {
+            Pcdr0Value { value: 0 }
+        }
+        #[inline(always)]
+        pub const fn from_bits(bits: u32) -> Pcdr0Value {
+            Pcdr0Value { value: bits }
+        }
+        #[inline(always)]
+        pub const fn to_bits(&self) -> u32 {
+            self.value
+        }
+        #[inline(always)]
+        pub fn pcd(&self, index: usize) -> bool {
+            let val = (self.value >> (0 + (1 * index))) & 1;
+            val != 0x0
+        }
+        #[inline(always)]
+        pub const fn with_pcd(self, index: usize, new_value: bool) -> Self {
+            if index >= 32 {
+                panic!();
+            }
+            let shift = 0 + (1 * index);
+            let context_mask: u32 = 1 << shift;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 1) << shift),
+            }
+        }
+    }
+    impl From<Pcdr0Get> for Pcdr0Value {
+        #[inline(always)]
+        fn from(get: Pcdr0Get) -> Pcdr0Value {
+            Pcdr0Value::from_bits(get.value)
+        }
+    }
+    impl From<Pcdr0Value> for Pcdr0Get {
+        #[inline(always)]
+        fn from(value: Pcdr0Value) -> Pcdr0Get {
+            Pcdr0Get {
+                value: value.to_bits(),
+            }
+        }
+    }
+    impl Pcdr0 {
+        #[doc = "Write `value` to the register in a single store, without reading it first"]
+        #[inline(always)]
+        pub fn write(&self, value: Pcdr0Value) {
+            self.store(value.to_bits());
+        }
+    }
+    impl<'a> Pcdr0Update<'a> {
+        #[doc = "Replace every field of the register with those of `value`"]
+        #[inline(always)]
+        pub fn assign<'b>(&'b mut self, value: Pcdr0Value) -> &'b mut Self {
+            self.value = value.to_bits();
+            self.mask = !0;
+            self
+        }
+    }
+    impl Pcdr0Get {
+        pub const FIELDS: &'static [::volatile_cell::field::FieldDescriptor] = &[];
+        #[doc = "Iterate over the readable fields that differ from those of `other`"]
+        #[inline]
+        pub fn changed_fields(&self, other: &Pcdr0Get) -> ::volatile_cell::field::FieldChanges {
+            ::volatile_cell::field::FieldChanges::new(
+                Self::FIELDS,
+                self.value as u64,
+                other.value as u64,
+            )
+        }
+    }
+    pub mod pcsr0 {}
+    #[repr(C)]
+    pub struct Pcsr0 {
+        value: ::volatile_cell::VolatileCell<u32>,
+    }
+    impl Pcsr0 {
+        #[inline(always)]
+        fn load(&self) -> u32 {
+            let value = self.value.get();
+            value
+        }
+        #[inline(always)]
+        fn store(&self, value: u32) {
+            self.value.set(value);
+        }
+        #[doc = "Create a new updater"]
+        #[inline(always)]
+        pub fn update<'a>(&'a self) -> Pcsr0Update<'a> {
+            Pcsr0Update::new(self)
+        }
+        #[doc = "Create an updater whose read-modify-write happens inside the critical section `cs`"]
+        #[inline(always)]
+        pub fn update_in<'a, C: ::volatile_cell::sync::CriticalSection>(
+            &'a self,
+            _cs: &'a C,
+        ) -> Pcsr0Update<'a> {
+            Pcsr0Update::new(self)
+        }
+        #[doc = "Update the register with exclusive loads and stores (LDREX/STREX), retrying `f` until no other access intervened"]
+        #[doc = ""]
+        #[doc = "The exclusive accesses go to the register's memory directly rather than through its cell, so they aren't seen by the replayer or the tracing hook."]
+        #[cfg(target_has_atomic = "32")]
+        #[inline]
+        pub fn update_exclusive<F: FnMut(&mut Pcsr0Update)>(&self, mut f: F) {
+            use ::core::sync::atomic::Ordering;
+            let cell = unsafe { &*(self.value.as_ptr() as *const ::core::sync::atomic::AtomicU32) };
+            let mut current = cell.load(Ordering::Relaxed);
+            loop {
+                let mut update = Pcsr0Update::detached(self);
+                f(&mut update);
+                let merged = update.merge(current);
+                if update.mask == 0 {
+                    return;
+                }
+                match cell.compare_exchange_weak(
+                    current,
+                    merged,
+                    Ordering::SeqCst,
+                    Ordering::Relaxed,
+                ) {
+                    Ok(_) => return,
+                    Err(actual) => current = actual,
+                }
+            }
+        }
+        #[doc = "Create a getter representing the current state of the register"]
+        #[inline(always)]
+        pub fn get(&self) -> Pcsr0Get {
+            Pcsr0Get::new(self)
+        }
+        #[doc = "Spin until the bit is set"]
+        #[inline]
+        pub fn wait_pcs(&self, index: usize) {
+            while !self.get().pcs(index) {}
+        }
+        #[doc = "Poll until the bit is set, at most `max_polls` times"]
+        #[inline]
+        pub fn wait_pcs_timeout(
+            &self,
+            index: usize,
+            max_polls: u32,
+        ) -> Result<(), ::volatile_cell::wait::Timeout> {
+            ::volatile_cell::wait::poll_until(|| self.get().pcs(index), max_polls)
+        }
+        #[doc = "Future resolving once the bit is set"]
+        #[inline]
+        pub fn wait_pcs_async<'a>(
+            &'a self,
+            index: usize,
+        ) -> ::volatile_cell::wait::WaitUntil<'a, impl FnMut() -> bool + Unpin + 'a> {
+            ::volatile_cell::wait::WaitUntil::new(move || self.get().pcs(index))
+        }
+    }
+    pub struct Pcsr0Update<'a> {
+        value: u32,
+        mask: u32,
+        write_only: bool,
+        store_on_drop: bool,
+        reg: &'a Pcsr0,
+    }
+    impl<'a> Pcsr0Update<'a> {
+        #[inline(always)]
+        pub fn new(reg: &'a Pcsr0) -> Pcsr0Update<'a> {
+            Pcsr0Update {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[inline(always)]
+        pub fn new_ignoring_state(reg: &'a Pcsr0) -> Pcsr0Update<'a> {
+            Pcsr0Update {
+                value: 0,
+                mask: 0,
+                write_only: true,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[doc = "An update that is never stored, for the caller to store itself"]
+        #[inline(always)]
+        fn detached(reg: &'a Pcsr0) -> Pcsr0Update<'a> {
+            Pcsr0Update {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: false,
+                reg: reg,
+            }
+        }
+        const fn clear_mask() -> u32 {
+            0u64 as u32
+        }
+        const fn update_offset(base: usize, size: usize, index: usize) -> usize {
+            base + (size * index)
+        }
+        #[inline(always)]
+        fn merge(&self, current: u32) -> u32 {
+            let v: u32 = current & (!Self::clear_mask()) & (!self.mask);
+            self.value | v
+        }
+    }
+    impl<'a> Drop for Pcsr0Update<'a> {
+        #[inline(always)]
+        fn drop(&mut self) {
+            if self.store_on_drop && self.mask != 0 {
+                let current: u32 = if self.write_only { 0 } else { self.reg.load() };
+                self.reg.store(self.merge(current));
+            }
+        }
+    }
+    #[derive(Clone, PartialEq, Eq)]
+    pub struct Pcsr0Get {
+        value: u32,
+    }
+    impl Pcsr0Get {
+        #[doc = "Create a getter reflecting the current value of the register"]
+        #[inline(always)]
+        pub fn new(reg: &Pcsr0) -> Pcsr0Get {
+            Pcsr0Get { value: reg.load() }
+        }
+        #[inline(always)]
+        pub fn pcs(&self, index: usize) -> bool {
+            let val = (self.value >> (0 + (1 * index))) & 1;
+            val != 0x0
+        }
+    }
+    #[derive(Clone, Copy, PartialEq, Eq, Default)]
+    #[repr(transparent)]
+    pub struct Pcsr0Value {
+        value: u32,
+    }
+    impl Pcsr0Value {
+        #[doc = "Create a value with every field cleared"]
+        #[inline(always)]
+        pub const fn new() -> Pcsr0Value {
+            Pcsr0Value { value: 0 }
+        }
+        #[inline(always)]
+        pub const fn from_bits(bits: u32) -> Pcsr0Value {
+            Pcsr0Value { value: bits }
+        }
+        #[inline(always)]
+        pub const fn to_bits(&self) -> u32 {
+            self.value
+        }
+        #[inline(always)]
+        pub fn pcs(&self, index: usize) -> bool {
+            let val = (self.value >> (0 + (1 * index))) & 1;
+            val != 0x0
+        }
+        #[inline(always)]
+        pub const fn with_pcs(self, index: usize, new_value: bool) -> Self {
+            if index >= 32 {
+                panic!();
+            }
+            let shift = 0 + (1 * index);
+            let context_mask: u32 = 1 << shift;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 1) << shift),
+            }
+        }
+    }
+    impl From<Pcsr0Get> for Pcsr0Value {
+        #[inline(always)]
+        fn from(get: Pcsr0Get) -> Pcsr0Value {
+            Pcsr0Value::from_bits(get.value)
+        }
+    }
+    impl From<Pcsr0Value> for Pcsr0Get {
+        #[inline(always)]
+        fn from(value: Pcsr0Value) -> Pcsr0Get {
+            Pcsr0Get {
+                value: value.to_bits(),
+            }
+        }
+    }
+    impl Pcsr0 {
+        #[doc = "Write `value` to the register in a single store, without reading it first"]
+        #[inline(always)]
+        pub fn write(&self, value: Pcsr0Value) {
+            self.store(value.to_bits());
+        }
+    }
+    impl<'a> Pcsr0Update<'a> {
+        #[doc = "Replace every field of the register with those of `value`"]
+        #[inline(always)]
+        pub fn assign<'b>(&'b mut self, value: Pcsr0Value) -> &'b mut Self {
+            self.value = value.to_bits();
+            self.mask = !0;
+            self
+        }
+    }
+    impl Pcsr0Get {
+        pub const FIELDS: &'static [::volatile_cell::field::FieldDescriptor] =
+            &[::volatile_cell::field::FieldDescriptor {
+                name: "pcs",
+                offset: 0u32,
+                width: 1u32,
+                count: 32u32,
+            }];
+        #[doc = "Iterate over the readable fields that differ from those of `other`"]
+        #[inline]
+        pub fn changed_fields(&self, other: &Pcsr0Get) -> ::volatile_cell::field::FieldChanges {
+            ::volatile_cell::field::FieldChanges::new(
+                Self::FIELDS,
+                self.value as u64,
+                other.value as u64,
+            )
+        }
+    }
+    pub mod pcer1 {}
+    #[repr(C)]
+    pub struct Pcer1 {
+        value: ::volatile_cell::VolatileCell<u32>,
+    }
+    impl Pcer1 {
+        #[inline(always)]
+        fn load(&self) -> u32 {
+            let value = self.value.get();
+            value
+        }
+        #[inline(always)]
+        fn store(&self, value: u32) {
+            self.value.set(value);
+        }
+        #[doc = "Create a new updater"]
+        #[inline(always)]
+        pub fn update<'a>(&'a self) -> Pcer1Update<'a> {
+            Pcer1Update::new(self)
+        }
+        #[doc = "Create an updater whose read-modify-write happens inside the critical section `cs`"]
+        #[inline(always)]
+        pub fn update_in<'a, C: ::volatile_cell::sync::CriticalSection>(
+            &'a self,
+            _cs: &'a C,
+        ) -> Pcer1Update<'a> {
+            Pcer1Update::new(self)
+        }
+        #[doc = "Create a getter representing the current state of the register"]
+        #[inline(always)]
+        pub fn get(&self) -> Pcer1Get {
+            Pcer1Get::new(self)
+        }
+    }
+    pub struct Pcer1Update<'a> {
+        value: u32,
+        mask: u32,
+        write_only: bool,
+        store_on_drop: bool,
+        reg: &'a Pcer1,
+    }
+    impl<'a> Pcer1Update<'a> {
+        #[inline(always)]
+        pub fn new(reg: &'a Pcer1) -> Pcer1Update<'a> {
+            Pcer1Update {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[inline(always)]
+        pub fn new_ignoring_state(reg: &'a Pcer1) -> Pcer1Update<'a> {
+            Pcer1Update {
+                value: 0,
+                mask: 0,
+                write_only: true,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[doc = "An update that is never stored, for the caller to store itself"]
+        #[inline(always)]
+        fn detached(reg: &'a Pcer1) -> Pcer1Update<'a> {
+            Pcer1Update {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: false,
+                reg: reg,
+            }
+        }
+        const fn clear_mask() -> u32 {
+            0u64 as u32
+        }
+        const fn update_offset(base: usize, size: usize, index: usize) -> usize {
+            base + (size * index)
+        }
+        #[inline(always)]
+        fn merge(&self, current: u32) -> u32 {
+            let v: u32 = current & (!Self::clear_mask()) & (!self.mask);
+            self.value | v
+        }
+        #[inline(always)]
+        pub fn set_pce<'b>(&'b mut self, index: usize, new_value: bool) -> &'b mut Self {
+            if index > 32 {
+                panic!();
+            }
+            let update_offset = Self::update_offset(0, 1, index);
+            let context_mask: u32 = 1 << update_offset;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 1) << update_offset);
+            self.mask |= context_mask;
+            self
+        }
+    }
+    impl<'a> Drop for Pcer1Update<'a> {
+        #[inline(always)]
+        fn drop(&mut self) {
+            if self.store_on_drop && self.mask != 0 {
+                let current: u32 = 0;
+                self.reg.store(self.merge(current));
+            }
+        }
+    }
+    #[derive(Clone, PartialEq, Eq)]
+    pub struct Pcer1Get {
+        value: u32,
+    }
+    impl Pcer1Get {
+        #[doc = "Create a getter reflecting the current value of the register"]
+        #[inline(always)]
+        pub fn new(reg: &Pcer1) -> Pcer1Get {
+            Pcer1Get { value: reg.load() }
+        }
+    }
+    #[derive(Clone, Copy, PartialEq, Eq, Default)]
+    #[repr(transparent)]
+    pub struct Pcer1Value {
+        value: u32,
+    }
+    impl Pcer1Value {
+        #[doc = "Create a value with every field cleared"]
+        #[inline(always)]
+        pub const fn new() -> Pcer1Value {
+            Pcer1Value { value: 0 }
+        }
+        #[inline(always)]
+        pub const fn from_bits(bits: u32) -> Pcer1Value {
+            Pcer1Value { value: bits }
+        }
+        #[inline(always)]
+        pub const fn to_bits(&self) -> u32 {
+            self.value
+        }
+        #[inline(always)]
+        pub fn pce(&self, index: usize) -> bool {
+            let val = (self.value >> (0 + (1 * index))) & 1;
+            val != 0x0
+        }
+        #[inline(always)]
+        pub const fn with_pce(self, index: usize, new_value: bool) -> Self {
+            if index >= 32 {
+                panic!();
+            }
+            let shift = 0 + (1 * index);
+            let context_mask: u32 = 1 << shift;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 1) << shift),
+            }
+        }
+    }
+    impl From<Pcer1Get> for Pcer1Value {
+        #[inline(always)]
+        fn from(get: Pcer1Get) -> Pcer1Value {
+            Pcer1Value::from_bits(get.value)
+        }
+    }
+    impl From<Pcer1Value> for Pcer1Get {
+        #[inline(always)]
+        fn from(value: Pcer1Value) -> Pcer1Get {
+            Pcer1Get {
+                value: value.to_bits(),
+            }
+        }
+    }
+    impl Pcer1 {
+        #[doc = "Write `value` to the register in a single store, without reading it first"]
+        #[inline(always)]
+        pub fn write(&self, value: Pcer1Value) {
+            self.store(value.to_bits());
+        }
+    }
+    impl<'a> Pcer1Update<'a> {
+        #[doc = "Replace every field of the register with those of `value`"]
+        #[inline(always)]
+        pub fn assign<'b>(&'b mut self, value: Pcer1Value) -> &'b mut Self {
+            self.value = value.to_bits();
+            self.mask = !0;
+            self
+        }
+    }
+    impl Pcer1Get {
+        pub const FIELDS: &'static [::volatile_cell::field::FieldDescriptor] = &[];
+        #[doc = "Iterate over the readable fields that differ from those of `other`"]
+        #[inline]
+        pub fn changed_fields(&self, other: &Pcer1Get) -> ::volatile_cell::field::FieldChanges {
+            ::volatile_cell::field::FieldChanges::new(
+                Self::FIELDS,
+                self.value as u64,
+                other.value as u64,
+            )
+        }
+    }
+    pub mod pcdr1 {}
+    #[repr(C)]
+    pub struct Pcdr1 {
+        value: ::volatile_cell::VolatileCell<u32>,
+    }
+    impl Pcdr1 {
+        #[inline(always)]
+        fn load(&self) -> u32 {
+            let value = self.value.get();
+            value
+        }
+        #[inline(always)]
+        fn store(&self, value: u32) {
+            self.value.set(value);
+        }
+        #[doc = "Create a new updater"]
+        #[inline(always)]
+        pub fn update<'a>(&'a self) -> Pcdr1Update<'a> {
+            Pcdr1Update::new(self)
+        }
+        #[doc = "Create an updater whose read-modify-write happens inside the critical section `cs`"]
+        #[inline(always)]
+        pub fn update_in<'a, C: ::volatile_cell::sync::CriticalSection>(
+            &'a self,
+            _cs: &'a C,
+        ) -> Pcdr1Update<'a> {
+            Pcdr1Update::new(self)
+        }
+        #[doc = "Create a getter representing the current state of the register"]
+        #[inline(always)]
+        pub fn get(&self) -> Pcdr1Get {
+            Pcdr1Get::new(self)
+        }
+    }
+    pub struct Pcdr1Update<'a> {
+        value: u32,
+        mask: u32,
+        write_only: bool,
+        store_on_drop: bool,
+        reg: &'a Pcdr1,
+    }
+    impl<'a> Pcdr1Update<'a> {
+        #[inline(always)]
+        pub fn new(reg: &'a Pcdr1) -> Pcdr1Update<'a> {
+            Pcdr1Update {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[inline(always)]
+        pub fn new_ignoring_state(reg: &'a Pcdr1) -> Pcdr1Update<'a> {
+            Pcdr1Update {
+                value: 0,
+                mask: 0,
+                write_only: true,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[doc = "An update that is never stored, for the caller to store itself"]
+        #[inline(always)]
+        fn detached(reg: &'a Pcdr1) -> Pcdr1Update<'a> {
+            Pcdr1Update {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: false,
+                reg: reg,
+            }
+        }
+        const fn clear_mask() -> u32 {
+            0u64 as u32
+        }
+        const fn update_offset(base: usize, size: usize, index: usize) -> usize {
+            base + (size * index)
+        }
+        #[inline(always)]
+        fn merge(&self, current: u32) -> u32 {
+            let v: u32 = current & (!Self::clear_mask()) & (!self.mask);
+            self.value | v
+        }
+        #[inline(always)]
+        pub fn set_pcd<'b>(&'b mut self, index: usize, new_value: bool) -> &'b mut Self {
+            if index > 32 {
+                panic!();
+            }
+            let update_offset = Self::update_offset(0, 1, index);
+            let context_mask: u32 = 1 << update_offset;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 1) << update_offset);
+            self.mask |= context_mask;
+            self
+        }
+    }
+    impl<'a> Drop for Pcdr1Update<'a> {
+        #[inline(always)]
+        fn drop(&mut self) {
+            if self.store_on_drop && self.mask != 0 {
+                let current: u32 = 0;
+                self.reg.store(self.merge(current));
+            }
+        }
+    }
+    #[derive(Clone, PartialEq, Eq)]
+    pub struct Pcdr1Get {
+        value: u32,
+    }
+    impl Pcdr1Get {
+        #[doc = "Create a getter reflecting the current value of the register"]
+        #[inline(always)]
+        pub fn new(reg: &Pcdr1) -> Pcdr1Get {
+            Pcdr1Get { value: reg.load() }
+        }
+    }
+    #[derive(Clone, Copy, PartialEq, Eq, Default)]
+    #[repr(transparent)]
+    pub struct Pcdr1Value {
+        value: u32,
+    }
+    impl Pcdr1Value {
+        #[doc = "Create a value with every field cleared"]
+        #[inline(always)]
+        pub const fn new() -> Pcdr1Value {
+            Pcdr1Value { value: 0 }
+        }
+        #[inline(always)]
+        pub const fn from_bits(bits: u32) -> Pcdr1Value {
+            Pcdr1Value { value: bits }
+        }
+        #[inline(always)]
+        pub const fn to_bits(&self) -> u32 {
+            self.value
+        }
+        #[inline(always)]
+        pub fn pcd(&self, index: usize) -> bool {
+            let val = (self.value >> (0 + (1 * index))) & 1;
+            val != 0x0
+        }
+        #[inline(always)]
+        pub const fn with_pcd(self, index: usize, new_value: bool) -> Self {
+            if index >= 32 {
+                panic!();
+            }
+            let shift = 0 + (1 * index);
+            let context_mask: u32 = 1 << shift;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 1) << shift),
+            }
+        }
+    }
+    impl From<Pcdr1Get> for Pcdr1Value {
+        #[inline(always)]
+        fn from(get: Pcdr1Get) -> Pcdr1Value {
+            Pcdr1Value::from_bits(get.value)
+        }
+    }
+    impl From<Pcdr1Value> for Pcdr1Get {
+        #[inline(always)]
+        fn from(value: Pcdr1Value) -> Pcdr1Get {
+            Pcdr1Get {
+                value: value.to_bits(),
+            }
+        }
+    }
+    impl Pcdr1 {
+        #[doc = "Write `value` to the register in a single store, without reading it first"]
+        #[inline(always)]
+        pub fn write(&self, value: Pcdr1Value) {
+            self.store(value.to_bits());
+        }
+    }
+    impl<'a> Pcdr1Update<'a> {
+        #[doc = "Replace every field of the register with those of `value`"]
+        #[inline(always)]
+        pub fn assign<'b>(&'b mut self, value: Pcdr1Value) -> &'b mut Self {
+            self.value = value.to_bits();
+            self.mask = !0;
+            self
+        }
+    }
+    impl Pcdr1Get {
+        pub const FIELDS: &'static [::volatile_cell::field::FieldDescriptor] = &[];
+        #[doc = "Iterate over the readable fields that differ from those of `other`"]
+        #[inline]
+        pub fn changed_fields(&self, other: &Pcdr1Get) -> ::volatile_cell::field::FieldChanges {
+            ::volatile_cell::field::FieldChanges::new(
+                Self::FIELDS,
+                self.value as u64,
+                other.value as u64,
+            )
+        }
+    }
+    pub mod pcsr1 {}
+    #[repr(C)]
+    pub struct Pcsr1 {
+        value: ::volatile_cell::VolatileCell<u32>,
+    }
+    impl Pcsr1 {
+        #[inline(always)]
+        fn load(&self) -> u32 {
+            let value = self.value.get();
+            value
+        }
+        #[inline(always)]
+        fn store(&self, value: u32) {
+            self.value.set(value);
+        }
+        #[doc = "Create a new updater"]
+        #[inline(always)]
+        pub fn update<'a>(&'a self) -> Pcsr1Update<'a> {
+            Pcsr1Update::new(self)
+        }
+        #[doc = "Create an updater whose read-modify-write happens inside the critical section `cs`"]
+        #[inline(always)]
+        pub fn update_in<'a, C: ::volatile_cell::sync::CriticalSection>(
+            &'a self,
+            _cs: &'a C,
+        ) -> Pcsr1Update<'a> {
+            Pcsr1Update::new(self)
+        }
+        #[doc = "Update the register with exclusive loads and stores (LDREX/STREX), retrying `f` until no other access intervened"]
+        #[doc = ""]
+        #[doc = "The exclusive accesses go to the register's memory directly rather than through its cell, so they aren't seen by the replayer or the tracing hook."]
+        #[cfg(target_has_atomic = "32")]
+        #[inline]
+        pub fn update_exclusive<F: FnMut(&mut Pcsr1Update)>(&self, mut f: F) {
+            use ::core::sync::atomic::Ordering;
+            let cell = unsafe { &*(self.value.as_ptr() as *const ::core::sync::atomic::AtomicU32) };
+            let mut current = cell.load(Ordering::Relaxed);
+            loop {
+                let mut update = Pcsr1Update::detached(self);
+                f(&mut update);
+                let merged = update.merge(current);
+                if update.mask == 0 {
+                    return;
+                }
+                match cell.compare_exchange_weak(
+                    current,
+                    merged,
+                    Ordering::SeqCst,
+                    Ordering::Relaxed,
+                ) {
+                    Ok(_) => return,
+                    Err(actual) => current = actual,
+                }
+            }
+        }
+        #[doc = "Create a getter representing the current state of the register"]
+        #[inline(always)]
+        pub fn get(&self) -> Pcsr1Get {
+            Pcsr1Get::new(self)
+        }
+        #[doc = "Spin until the bit is set"]
+        #[inline]
+        pub fn wait_pcs(&self, index: usize) {
+            while !self.get().pcs(index) {}
+        }
+        #[doc = "Poll until the bit is set, at most `max_polls` times"]
+        #[inline]
+        pub fn wait_pcs_timeout(
+            &self,
+            index: usize,
+            max_polls: u32,
+        ) -> Result<(), ::volatile_cell::wait::Timeout> {
+            ::volatile_cell::wait::poll_until(|| self.get().pcs(index), max_polls)
+        }
+        #[doc = "Future resolving once the bit is set"]
+        #[inline]
+        pub fn wait_pcs_async<'a>(
+            &'a self,
+            index: usize,
+        ) -> ::volatile_cell::wait::WaitUntil<'a, impl FnMut() -> bool + Unpin + 'a> {
+            ::volatile_cell::wait::WaitUntil::new(move || self.get().pcs(index))
+        }
+    }
+    pub struct Pcsr1Update<'a> {
+        value: u32,
+        mask: u32,
+        write_only: bool,
+        store_on_drop: bool,
+        reg: &'a Pcsr1,
+    }
+    impl<'a> Pcsr1Update<'a> {
+        #[inline(always)]
+        pub fn new(reg: &'a Pcsr1) -> Pcsr1Update<'a> {
+            Pcsr1Update {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[inline(always)]
+        pub fn new_ignoring_state(reg: &'a Pcsr1) -> Pcsr1Update<'a> {
+            Pcsr1Update {
+                value: 0,
+                mask: 0,
+                write_only: true,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[doc = "An update that is never stored, for the caller to store itself"]
+        #[inline(always)]
+        fn detached(reg: &'a Pcsr1) -> Pcsr1Update<'a> {
+            Pcsr1Update {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: false,
+                reg: reg,
+            }
+        }
+        const fn clear_mask() -> u32 {
+            0u64 as u32
+        }
+        const fn update_offset(base: usize, size: usize, index: usize) -> usize {
+            base + (size * index)
+        }
+        #[inline(always)]
+        fn merge(&self, current: u32) -> u32 {
+            let v: u32 = current & (!Self::clear_mask()) & (!self.mask);
+            self.value | v
+        }
+    }
+    impl<'a> Drop for Pcsr1Update<'a> {
+        #[inline(always)]
+        fn drop(&mut self) {
+            if self.store_on_drop && self.mask != 0 {
+                let current: u32 = if self.write_only { 0 } else { self.reg.load() };
+                self.reg.store(self.merge(current));
+            }
+        }
+    }
+    #[derive(Clone, PartialEq, Eq)]
+    pub struct Pcsr1Get {
+        value: u32,
+    }
+    impl Pcsr1Get {
+        #[doc = "Create a getter reflecting the current value of the register"]
+        #[inline(always)]
+        pub fn new(reg: &Pcsr1) -> Pcsr1Get {
+            Pcsr1Get { value: reg.load() }
+        }
+        #[inline(always)]
+        pub fn pcs(&self, index: usize) -> bool {
+            let val = (self.value >> (0 + (1 * index))) & 1;
+            val != 0x0
+        }
+    }
+    #[derive(Clone, Copy, PartialEq, Eq, Default)]
+    #[repr(transparent)]
+    pub struct Pcsr1Value {
+        value: u32,
+    }
+    impl Pcsr1Value {
+        #[doc = "Create a value with every field cleared"]
+        #[inline(always)]
+        pub const fn new() -> Pcsr1Value {
+            Pcsr1Value { value: 0 }
+        }
+        #[inline(always)]
+        pub const fn from_bits(bits: u32) -> Pcsr1Value {
+            Pcsr1Value { value: bits }
+        }
+        #[inline(always)]
+        pub const fn to_bits(&self) -> u32 {
+            self.value
+        }
+        #[inline(always)]
+        pub fn pcs(&self, index: usize) -> bool {
+            let val = (self.value >> (0 + (1 * index))) & 1;
+            val != 0x0
+        }
+        #[inline(always)]
+        pub const fn with_pcs(self, index: usize, new_value: bool) -> Self {
+            if index >= 32 {
+                panic!();
+            }
+            let shift = 0 + (1 * index);
+            let context_mask: u32 = 1 << shift;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 1) << shift),
+            }
+        }
+    }
+    impl From<Pcsr1Get> for Pcsr1Value {
+        #[inline(always)]
+        fn from(get: Pcsr1Get) -> Pcsr1Value {
+            Pcsr1Value::from_bits(get.value)
+        }
+    }
+    impl From<Pcsr1Value> for Pcsr1Get {
+        #[inline(always)]
+        fn from(value: Pcsr1Value) -> Pcsr1Get {
+            Pcsr1Get {
+                value: value.to_bits(),
+            }
+        }
+    }
+    impl Pcsr1 {
+        #[doc = "Write `value` to the register in a single store, without reading it first"]
+        #[inline(always)]
+        pub fn write(&self, value: Pcsr1Value) {
+            self.store(value.to_bits());
+        }
+    }
+    impl<'a> Pcsr1Update<'a> {
+        #[doc = "Replace every field of the register with those of `value`"]
+        #[inline(always)]
+        pub fn assign<'b>(&'b mut self, value: Pcsr1Value) -> &'b mut Self {
+            self.value = value.to_bits();
+            self.mask = !0;
+            self
+        }
+    }
+    impl Pcsr1Get {
+        pub const FIELDS: &'static [::volatile_cell::field::FieldDescriptor] =
+            &[::volatile_cell::field::FieldDescriptor {
+                name: "pcs",
+                offset: 0u32,
+                width: 1u32,
+                count: 32u32,
+            }];
+        #[doc = "Iterate over the readable fields that differ from those of `other`"]
+        #[inline]
+        pub fn changed_fields(&self, other: &Pcsr1Get) -> ::volatile_cell::field::FieldChanges {
+            ::volatile_cell::field::FieldChanges::new(
+                Self::FIELDS,
+                self.value as u64,
+                other.value as u64,
+            )
+        }
+    }
+    #[repr(C)]
+    pub struct Pmc {
+        _padding0: [u8; 16],
+        pub pcer0: Pcer0,
+        pub pcdr0: Pcdr0,
+        pub pcsr0: Pcsr0,
+        _padding1: [u8; 228],
+        pub pcer1: Pcer1,
+        pub pcdr1: Pcdr1,
+        pub pcsr1: Pcsr1,
+    }
+    #[doc = "Saved state of every readable register without side effects"]
+    #[derive(Clone, Copy)]
+    pub struct Snapshot {
+        pub pcsr0: Pcsr0Value,
+        pub pcsr1: Pcsr1Value,
+    }
+    #[doc = "Read the current state of the peripheral"]
+    #[allow(unused_variables)]
+    pub fn capture(p: &Pmc) -> Snapshot {
+        Snapshot {
+            pcsr0: Pcsr0Value::from(p.pcsr0.get()),
+            pcsr1: Pcsr1Value::from(p.pcsr1.get()),
+        }
+    }
+    #[doc = "Write a previously captured state back to the peripheral"]
+    #[allow(unused_variables)]
+    pub fn restore(p: &Pmc, snapshot: &Snapshot) {}
+    #[doc = "Owned handle granting exclusive access to the peripheral"]
+    pub struct PmcPeripheral {
+        regs: &'static mut Pmc,
+    }
+    impl PmcPeripheral {
+        #[doc = "Create a token for the peripheral registers at `regs`."]
+        #[doc = ""]
+        #[doc = "Unsafe because the caller must ensure it's the only token for `regs`."]
+        #[inline(always)]
+        pub const unsafe fn new(regs: &'static mut Pmc) -> PmcPeripheral {
+            PmcPeripheral { regs: regs }
+        }
+    }
+    impl ::core::ops::Deref for PmcPeripheral {
+        type Target = Pmc;
+        #[inline(always)]
+        fn deref(&self) -> &Pmc {
+            self.regs
+        }
+    }
+    impl ::core::ops::DerefMut for PmcPeripheral {
+        #[inline(always)]
+        fn deref_mut(&mut self) -> &mut Pmc {
+            self.regs
+        }
+    }
+}
@@ -0,0 +1,5310 @@
+pub mod pio {
+    pub mod per {}
+    #[repr(C)]
+    pub struct Per {
+        value: ::volatile_cell::VolatileCell<u32>,
+    }
+    impl Per {
+        #[inline(always)]
+        fn load(&self) -> u32 {
+            let value = self.value.get();
+            value
+        }
+        #[inline(always)]
+        fn store(&self, value: u32) {
+            self.value.set(value);
+        }
+        #[doc = "Create a new updater"]
+        #[inline(always)]
+        pub fn update<'a>(&'a self) -> PerUpdate<'a> {
+            PerUpdate::new(self)
+        }
+        #[doc = "Create an updater whose read-modify-write happens inside the critical section `cs`"]
+        #[inline(always)]
+        pub fn update_in<'a, C: ::volatile_cell::sync::CriticalSection>(
+            &'a self,
+            _cs: &'a C,
+        ) -> PerUpdate<'a> {
+            PerUpdate::new(self)
+        }
+        #[doc = "Create a getter representing the current state of the register"]
+        #[inline(always)]
+        pub fn get(&self) -> PerGet {
+            PerGet::new(self)
+        }
+    }
+    pub struct PerUpdate<'a> {
+        value: u32,
+        mask: u32,
+        write_only: bool,
+        store_on_drop: bool,
+        reg: &'a Per,
+    }
+    impl<'a> PerUpdate<'a> {
+        #[inline(always)]
+        pub fn new(reg: &'a Per) -> PerUpdate<'a> {
+            PerUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[inline(always)]
+        pub fn new_ignoring_state(reg: &'a Per) -> PerUpdate<'a> {
+            PerUpdate {
+                value: 0,
+                mask: 0,
+                write_only: true,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[doc = "An update that is never stored, for the caller to store itself"]
+        #[inline(always)]
+        fn detached(reg: &'a Per) -> PerUpdate<'a> {
+            PerUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: false,
+                reg: reg,
+            }
+        }
+        const fn clear_mask() -> u32 {
+            0u64 as u32
+        }
+        const fn update_offset(base: usize, size: usize, index: usize) -> usize {
+            base + (size * index)
+        }
+        #[inline(always)]
+        fn merge(&self, current: u32) -> u32 {
+            let v: u32 = current & (!Self::clear_mask()) & (!self.mask);
+            self.value | v
+        }
+        #[inline(always)]
+        pub fn set_pe<'b>(&'b mut self, index: usize, new_value: bool) -> &'b mut Self {
+            if index > 32 {
+                panic!();
+            }
+            let update_offset = Self::update_offset(0, 1, index);
+            let context_mask: u32 = 1 << update_offset;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 1) << update_offset);
+            self.mask |= context_mask;
+            self
+        }
+    }
+    impl<'a> Drop for PerUpdate<'a> {
+        #[inline(always)]
+        fn drop(&mut self) {
+            if self.store_on_drop && self.mask != 0 {
+                let current: u32 = 0;
+                self.reg.store(self.merge(current));
+            }
+        }
+    }
+    #[derive(Clone, PartialEq, Eq)]
+    pub struct PerGet {
+        value: u32,
+    }
+    impl PerGet {
+        #[doc = "Create a getter reflecting the current value of the register"]
+        #[inline(always)]
+        pub fn new(reg: &Per) -> PerGet {
+            PerGet { value: reg.load() }
+        }
+    }
+    #[derive(Clone, Copy, PartialEq, Eq, Default)]
+    #[repr(transparent)]
+    pub struct PerValue {
+        value: u32,
+    }
+    impl PerValue {
+        #[doc = "Create a value with every field cleared"]
+        #[inline(always)]
+        pub const fn new() -> PerValue {
+            PerValue { value: 0 }
+        }
+        #[inline(always)]
+        pub const fn from_bits(bits: u32) -> PerValue {
+            PerValue { value: bits }
+        }
+        #[inline(always)]
+        pub const fn to_bits(&self) -> u32 {
+            self.value
+        }
+        #[inline(always)]
+        pub fn pe(&self, index: usize) -> bool {
+            let val = (self.value >> (0 + (1 * index))) & 1;
+            val != 0x0
+        }
+        #[inline(always)]
+        pub const fn with_pe(self, index: usize, new_value: bool) -> Self {
+            if index >= 32 {
+                panic!();
+            }
+            let shift = 0 + (1 * index);
+            let context_mask: u32 = 1 << shift;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 1) << shift),
+            }
+        }
+    }
+    impl From<PerGet> for PerValue {
+        #[inline(always)]
+        fn from(get: PerGet) -> PerValue {
+            PerValue::from_bits(get.value)
+        }
+    }
+    impl From<PerValue> for PerGet {
+        #[inline(always)]
+        fn from(value: PerValue) -> PerGet {
+            PerGet {
+                value: value.to_bits(),
+            }
+        }
+    }
+    impl Per {
+        #[doc = "Write `value` to the register in a single store, without reading it first"]
+        #[inline(always)]
+        pub fn write(&self, value: PerValue) {
+            self.store(value.to_bits());
+        }
+    }
+    impl<'a> PerUpdate<'a> {
+        #[doc = "Replace every field of the register with those of `value`"]
+        #[inline(always)]
+        pub fn assign<'b>(&'b mut self, value: PerValue) -> &'b mut Self {
+            self.value = value.to_bits();
+            self.mask = !0;
+            self
+        }
+    }
+    impl PerGet {
+        pub const FIELDS: &'static [::volatile_cell::field::FieldDescriptor] = &[];
+        #[doc = "Iterate over the readable fields that differ from those of `other`"]
+        #[inline]
+        pub fn changed_fields(&self, other: &PerGet) -> ::volatile_cell::field::FieldChanges {
+            ::volatile_cell::field::FieldChanges::new(
+                Self::FIELDS,
+                self.value as u64,
+                other.value as u64,
+            )
+        }
+    }
+    pub mod pdr {}
+    #[repr(C)]
+    pub struct Pdr {
+        value: ::volatile_cell::VolatileCell<u32>,
+    }
+    impl Pdr {
+        #[inline(always)]
+        fn load(&self) -> u32 {
+            let value = self.value.get();
+            value
+        }
+        #[inline(always)]
+        fn store(&self, value: u32) {
+            self.value.set(value);
+        }
+        #[doc = "Create a new updater"]
+        #[inline(always)]
+        pub fn update<'a>(&'a self) -> PdrUpdate<'a> {
+            PdrUpdate::new(self)
+        }
+        #[doc = "Create an updater whose read-modify-write happens inside the critical section `cs`"]
+        #[inline(always)]
+        pub fn update_in<'a, C: ::volatile_cell::sync::CriticalSection>(
+            &'a self,
+            _cs: &'a C,
+        ) -> PdrUpdate<'a> {
+            PdrUpdate::new(self)
+        }
+        #[doc = "Create a getter representing the current state of the register"]
+        #[inline(always)]
+        pub fn get(&self) -> PdrGet {
+            PdrGet::new(self)
+        }
+    }
+    pub struct PdrUpdate<'a> {
+        value: u32,
+        mask: u32,
+        write_only: bool,
+        store_on_drop: bool,
+        reg: &'a Pdr,
+    }
+    impl<'a> PdrUpdate<'a> {
+        #[inline(always)]
+        pub fn new(reg: &'a Pdr) -> PdrUpdate<'a> {
+            PdrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[inline(always)]
+        pub fn new_ignoring_state(reg: &'a Pdr) -> PdrUpdate<'a> {
+            PdrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: true,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[doc = "An update that is never stored, for the caller to store itself"]
+        #[inline(always)]
+        fn detached(reg: &'a Pdr) -> PdrUpdate<'a> {
+            PdrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: false,
+                reg: reg,
+            }
+        }
+        const fn clear_mask() -> u32 {
+            0u64 as u32
+        }
+        const fn update_offset(base: usize, size: usize, index: usize) -> usize {
+            base + (size * index)
+        }
+        #[inline(always)]
+        fn merge(&self, current: u32) -> u32 {
+            let v: u32 = current & (!Self::clear_mask()) & (!self.mask);
+            self.value | v
+        }
+        #[inline(always)]
+        pub fn set_pd<'b>(&'b mut self, index: usize, new_value: bool) -> &'b mut Self {
+            if index > 32 {
+                panic!();
+            }
+            let update_offset = Self::update_offset(0, 1, index);
+            let context_mask: u32 = 1 << update_offset;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 1) << update_offset);
+            self.mask |= context_mask;
+            self
+        }
+    }
+    impl<'a> Drop for PdrUpdate<'a> {
+        #[inline(always)]
+        fn drop(&mut self) {
+            if self.store_on_drop && self.mask != 0 {
+                let current: u32 = 0;
+                self.reg.store(self.merge(current));
+            }
+        }
+    }
+    #[derive(Clone, PartialEq, Eq)]
+    pub struct PdrGet {
+        value: u32,
+    }
+    impl PdrGet {
+        #[doc = "Create a getter reflecting the current value of the register"]
+        #[inline(always)]
+        pub fn new(reg: &Pdr) -> PdrGet {
+            PdrGet { value: reg.load() }
+        }
+    }
+    #[derive(Clone, Copy, PartialEq, Eq, Default)]
+    #[repr(transparent)]
+    pub struct PdrValue {
+        value: u32,
+    }
+    impl PdrValue {
+        #[doc = "Create a value with every field cleared"]
+        #[inline(always)]
+        pub const fn new() -> PdrValue {
+            PdrValue { value: 0 }
+        }
+        #[inline(always)]
+        pub const fn from_bits(bits: u32) -> PdrValue {
+            PdrValue { value: bits }
+        }
+        #[inline(always)]
+        pub const fn to_bits(&self) -> u32 {
+            self.value
+        }
+        #[inline(always)]
+        pub fn pd(&self, index: usize) -> bool {
+            let val = (self.value >> (0 + (1 * index))) & 1;
+            val != 0x0
+        }
+        #[inline(always)]
+        pub const fn with_pd(self, index: usize, new_value: bool) -> Self {
+            if index >= 32 {
+                panic!();
+            }
+            let shift = 0 + (1 * index);
+            let context_mask: u32 = 1 << shift;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 1) << shift),
+            }
+        }
+    }
+    impl From<PdrGet> for PdrValue {
+        #[inline(always)]
+        fn from(get: PdrGet) -> PdrValue {
+            PdrValue::from_bits(get.value)
+        }
+    }
+    impl From<PdrValue> for PdrGet {
+        #[inline(always)]
+        fn from(value: PdrValue) -> PdrGet {
+            PdrGet {
+                value: value.to_bits(),
+            }
+        }
+    }
+    impl Pdr {
+        #[doc = "Write `value` to the register in a single store, without reading it first"]
+        #[inline(always)]
+        pub fn write(&self, value: PdrValue) {
+            self.store(value.to_bits());
+        }
+    }
+    impl<'a> PdrUpdate<'a> {
+        #[doc = "Replace every field of the register with those of `value`"]
+        #[inline(always)]
+        pub fn assign<'b>(&'b mut self, value: PdrValue) -> &'b mut Self {
+            self.value = value.to_bits();
+            self.mask = !0;
+            self
+        }
+    }
+    impl PdrGet {
+        pub const FIELDS: &'static [::volatile_cell::field::FieldDescriptor] = &[];
+        #[doc = "Iterate over the readable fields that differ from those of `other`"]
+        #[inline]
+        pub fn changed_fields(&self, other: &PdrGet) -> ::volatile_cell::field::FieldChanges {
+            ::volatile_cell::field::FieldChanges::new(
+                Self::FIELDS,
+                self.value as u64,
+                other.value as u64,
+            )
+        }
+    }
+    pub mod psr {}
+    #[repr(C)]
+    pub struct Psr {
+        value: ::volatile_cell::VolatileCell<u32>,
+    }
+    impl Psr {
+        #[inline(always)]
+        fn load(&self) -> u32 {
+            let value = self.value.get();
+            value
+        }
+        #[inline(always)]
+        fn store(&self, value: u32) {
+            self.value.set(value);
+        }
+        #[doc = "Create a new updater"]
+        #[inline(always)]
+        pub fn update<'a>(&'a self) -> PsrUpdate<'a> {
+            PsrUpdate::new(self)
+        }
+        #[doc = "Create an updater whose read-modify-write happens inside the critical section `cs`"]
+        #[inline(always)]
+        pub fn update_in<'a, C: ::volatile_cell::sync::CriticalSection>(
+            &'a self,
+            _cs: &'a C,
+        ) -> PsrUpdate<'a> {
+            PsrUpdate::new(self)
+        }
+        #[doc = "Update the register with exclusive loads and stores (LDREX/STREX), retrying `f` until no other access intervened"]
+        #[doc = ""]
+        #[doc = "The exclusive accesses go to the register's memory directly rather than through its cell, so they aren't seen by the replayer or the tracing hook."]
+        #[cfg(target_has_atomic = "32")]
+        #[inline]
+        pub fn update_exclusive<F: FnMut(&mut PsrUpdate)>(&self, mut f: F) {
+            use ::core::sync::atomic::Ordering;
+            let cell = unsafe { &*(self.value.as_ptr() as *const ::core::sync::atomic::AtomicU32) };
+            let mut current = cell.load(Ordering::Relaxed);
+            loop {
+                let mut update = PsrUpdate::detached(self);
+                f(&mut update);
+                let merged = update.merge(current);
+                if update.mask == 0 {
+                    return;
+                }
+                match cell.compare_exchange_weak(
+                    current,
+                    merged,
+                    Ordering::SeqCst,
+                    Ordering::Relaxed,
+                ) {
+                    Ok(_) => return,
+                    Err(actual) => current = actual,
+                }
+            }
+        }
+        #[doc = "Create a getter representing the current state of the register"]
+        #[inline(always)]
+        pub fn get(&self) -> PsrGet {
+            PsrGet::new(self)
+        }
+        #[doc = "Spin until the bit is set"]
+        #[inline]
+        pub fn wait_ps(&self, index: usize) {
+            while !self.get().ps(index) {}
+        }
+        #[doc = "Poll until the bit is set, at most `max_polls` times"]
+        #[inline]
+        pub fn wait_ps_timeout(
+            &self,
+            index: usize,
+            max_polls: u32,
+        ) -> Result<(), ::volatile_cell::wait::Timeout> {
+            ::volatile_cell::wait::poll_until(|| self.get().ps(index), max_polls)
+        }
+        #[doc = "Future resolving once the bit is set"]
+        #[inline]
+        pub fn wait_ps_async<'a>(
+            &'a self,
+            index: usize,
+        ) -> ::volatile_cell::wait::WaitUntil<'a, impl FnMut() -> bool + Unpin + 'a> {
+            ::volatile_cell::wait::WaitUntil::new(move || self.get().ps(index))
+        }
+    }
+    pub struct PsrUpdate<'a> {
+        value: u32,
+        mask: u32,
+        write_only: bool,
+        store_on_drop: bool,
+        reg: &'a Psr,
+    }
+    impl<'a> PsrUpdate<'a> {
+        #[inline(always)]
+        pub fn new(reg: &'a Psr) -> PsrUpdate<'a> {
+            PsrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[inline(always)]
+        pub fn new_ignoring_state(reg: &'a Psr) -> PsrUpdate<'a> {
+            PsrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: true,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[doc = "An update that is never stored, for the caller to store itself"]
+        #[inline(always)]
+        fn detached(reg: &'a Psr) -> PsrUpdate<'a> {
+            PsrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: false,
+                reg: reg,
+            }
+        }
+        const fn clear_mask() -> u32 {
+            0u64 as u32
+        }
+        const fn update_offset(base: usize, size: usize, index: usize) -> usize {
+            base + (size * index)
+        }
+        #[inline(always)]
+        fn merge(&self, current: u32) -> u32 {
+            let v: u32 = current & (!Self::clear_mask()) & (!self.mask);
+            self.value | v
+        }
+    }
+    impl<'a> Drop for PsrUpdate<'a> {
+        #[inline(always)]
+        fn drop(&mut self) {
+            if self.store_on_drop && self.mask != 0 {
+                let current: u32 = if self.write_only { 0 } else { self.reg.load() };
+                self.reg.store(self.merge(current));
+            }
+        }
+    }
+    #[derive(Clone, PartialEq, Eq)]
+    pub struct PsrGet {
+        value: u32,
+    }
+    impl PsrGet {
+        #[doc = "Create a getter reflecting the current value of the register"]
+        #[inline(always)]
+        pub fn new(reg: &Psr) -> PsrGet {
+            PsrGet { value: reg.load() }
+        }
+        #[inline(always)]
+        pub fn ps(&self, index: usize) -> bool {
+            let val = (self.value >> (0 + (1 * index))) & 1;
+            val != 0x0
+        }
+    }
+    #[derive(Clone, Copy, PartialEq, Eq, Default)]
+    #[repr(transparent)]
+    pub struct PsrValue {
+        value: u32,
+    }
+    impl PsrValue {
+        #[doc = "Create a value with every field cleared"]
+        #[inline(always)]
+        pub const fn new() -> PsrValue {
+            PsrValue { value: 0 }
+        }
+        #[inline(always)]
+        pub const fn from_bits(bits: u32) -> PsrValue {
+            PsrValue { value: bits }
+        }
+        #[inline(always)]
+        pub const fn to_bits(&self) -> u32 {
+            self.value
+        }
+        #[inline(always)]
+        pub fn ps(&self, index: usize) -> bool {
+            let val = (self.value >> (0 + (1 * index))) & 1;
+            val != 0x0
+        }
+        #[inline(always)]
+        pub const fn with_ps(self, index: usize, new_value: bool) -> Self {
+            if index >= 32 {
+                panic!();
+            }
+            let shift = 0 + (1 * index);
+            let context_mask: u32 = 1 << shift;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 1) << shift),
+            }
+        }
+    }
+    impl From<PsrGet> for PsrValue {
+        #[inline(always)]
+        fn from(get: PsrGet) -> PsrValue {
+            PsrValue::from_bits(get.value)
+        }
+    }
+    impl From<PsrValue> for PsrGet {
+        #[inline(always)]
+        fn from(value: PsrValue) -> PsrGet {
+            PsrGet {
+                value: value.to_bits(),
+            }
+        }
+    }
+    impl Psr {
+        #[doc = "Write `value` to the register in a single store, without reading it first"]
+        #[inline(always)]
+        pub fn write(&self, value: PsrValue) {
+            self.store(value.to_bits());
+        }
+    }
+    impl<'a> PsrUpdate<'a> {
+        #[doc = "Replace every field of the register with those of `value`"]
+        #[inline(always)]
+        pub fn assign<'b>(&'b mut self, value: PsrValue) -> &'b mut Self {
+            self.value = value.to_bits();
+            self.mask = !0;
+            self
+        }
+    }
+    impl PsrGet {
+        pub const FIELDS: &'static [::volatile_cell::field::FieldDescriptor] =
+            &[::volatile_cell::field::FieldDescriptor {
+                name: "ps",
+                offset: 0u32,
+                width: 1u32,
+                count: 32u32,
+            }];
+        #[doc = "Iterate over the readable fields that differ from those of `other`"]
+        #[inline]
+        pub fn changed_fields(&self, other: &PsrGet) -> ::volatile_cell::field::FieldChanges {
+            ::volatile_cell::field::FieldChanges::new(
+                Self::FIELDS,
+                self.value as u64,
+                other.value as u64,
+            )
+        }
+    }
+    pub mod oer {}
+    #[repr(C)]
+    pub struct Oer {
+        value: ::volatile_cell::VolatileCell<u32>,
+    }
+    impl Oer {
+        #[inline(always)]
+        fn load(&self) -> u32 {
+            let value = self.value.get();
+            value
+        }
+        #[inline(always)]
+        fn store(&self, value: u32) {
+            self.value.set(value);
+        }
+        #[doc = "Create a new updater"]
+        #[inline(always)]
+        pub fn update<'a>(&'a self) -> OerUpdate<'a> {
+            OerUpdate::new(self)
+        }
+        #[doc = "Create an updater whose read-modify-write happens inside the critical section `cs`"]
+        #[inline(always)]
+        pub fn update_in<'a, C: ::volatile_cell::sync::CriticalSection>(
+            &'a self,
+            _cs: &'a C,
+        ) -> OerUpdate<'a> {
+            OerUpdate::new(self)
+        }
+        #[doc = "Create a getter representing the current state of the register"]
+        #[inline(always)]
+        pub fn get(&self) -> OerGet {
+            OerGet::new(self)
+        }
+    }
+    pub struct OerUpdate<'a> {
+        value: u32,
+        mask: u32,
+        write_only: bool,
+        store_on_drop: bool,
+        reg: &'a Oer,
+    }
+    impl<'a> OerUpdate<'a> {
+        #[inline(always)]
+        pub fn new(reg: &'a Oer) -> OerUpdate<'a> {
+            OerUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[inline(always)]
+        pub fn new_ignoring_state(reg: &'a Oer) -> OerUpdate<'a> {
+            OerUpdate {
+                value: 0,
+                mask: 0,
+                write_only: true,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[doc = "An update that is never stored, for the caller to store itself"]
+        #[inline(always)]
+        fn detached(reg: &'a Oer) -> OerUpdate<'a> {
+            OerUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: false,
+                reg: reg,
+            }
+        }
+        const fn clear_mask() -> u32 {
+            0u64 as u32
+        }
+        const fn update_offset(base: usize, size: usize, index: usize) -> usize {
+            base + (size * index)
+        }
+        #[inline(always)]
+        fn merge(&self, current: u32) -> u32 {
+            let v: u32 = current & (!Self::clear_mask()) & (!self.mask);
+            self.value | v
+        }
+        #[inline(always)]
+        pub fn set_oe<'b>(&'b mut self, index: usize, new_value: bool) -> &'b mut Self {
+            if index > 32 {
+                panic!();
+            }
+            let update_offset = Self::update_offset(0, 1, index);
+            let context_mask: u32 = 1 << update_offset;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 1) << update_offset);
+            self.mask |= context_mask;
+            self
+        }
+    }
+    impl<'a> Drop for OerUpdate<'a> {
+        #[inline(always)]
+        fn drop(&mut self) {
+            if self.store_on_drop && self.mask != 0 {
+                let current: u32 = 0;
+                self.reg.store(self.merge(current));
+            }
+        }
+    }
+    #[derive(Clone, PartialEq, Eq)]
+    pub struct OerGet {
+        value: u32,
+    }
+    impl OerGet {
+        #[doc = "Create a getter reflecting the current value of the register"]
+        #[inline(always)]
+        pub fn new(reg: &Oer) -> OerGet {
+            OerGet { value: reg.load() }
+        }
+    }
+    #[derive(Clone, Copy, PartialEq, Eq, Default)]
+    #[repr(transparent)]
+    pub struct OerValue {
+        value: u32,
+    }
+    impl OerValue {
+        #[doc = "Create a value with every field cleared"]
+        #[inline(always)]
+        pub const fn new() -> OerValue {
+            OerValue { value: 0 }
+        }
+        #[inline(always)]
+        pub const fn from_bits(bits: u32) -> OerValue {
+            OerValue { value: bits }
+        }
+        #[inline(always)]
+        pub const fn to_bits(&self) -> u32 {
+            self.value
+        }
+        #[inline(always)]
+        pub fn oe(&self, index: usize) -> bool {
+            let val = (self.value >> (0 + (1 * index))) & 1;
+            val != 0x0
+        }
+        #[inline(always)]
+        pub const fn with_oe(self, index: usize, new_value: bool) -> Self {
+            if index >= 32 {
+                panic!();
+            }
+            let shift = 0 + (1 * index);
+            let context_mask: u32 = 1 << shift;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 1) << shift),
+            }
+        }
+    }
+    impl From<OerGet> for OerValue {
+        #[inline(always)]
+        fn from(get: OerGet) -> OerValue {
+            OerValue::from_bits(get.value)
+        }
+    }
+    impl From<OerValue> for OerGet {
+        #[inline(always)]
+        fn from(value: OerValue) -> OerGet {
+            OerGet {
+                value: value.to_bits(),
+            }
+        }
+    }
+    impl Oer {
+        #[doc = "Write `value` to the register in a single store, without reading it first"]
+        #[inline(always)]
+        pub fn write(&self, value: OerValue) {
+            self.store(value.to_bits());
+        }
+    }
+    impl<'a> OerUpdate<'a> {
+        #[doc = "Replace every field of the register with those of `value`"]
+        #[inline(always)]
+        pub fn assign<'b>(&'b mut self, value: OerValue) -> &'b mut Self {
+            self.value = value.to_bits();
+            self.mask = !0;
+            self
+        }
+    }
+    impl OerGet {
+        pub const FIELDS: &'static [::volatile_cell::field::FieldDescriptor] = &[];
+        #[doc = "Iterate over the readable fields that differ from those of `other`"]
+        #[inline]
+        pub fn changed_fields(&self, other: &OerGet) -> ::volatile_cell::field::FieldChanges {
+            ::volatile_cell::field::FieldChanges::new(
+                Self::FIELDS,
+                self.value as u64,
+                other.value as u64,
+            )
+        }
+    }
+    pub mod odr {}
+    #[repr(C)]
+    pub struct Odr {
+        value: ::volatile_cell::VolatileCell<u32>,
+    }
+    impl Odr {
+        #[inline(always)]
+        fn load(&self) -> u32 {
+            let value = self.value.get();
+            value
+        }
+        #[inline(always)]
+        fn store(&self, value: u32) {
+            self.value.set(value);
+        }
+        #[doc = "Create a new updater"]
+        #[inline(always)]
+        pub fn update<'a>(&'a self) -> OdrUpdate<'a> {
+            OdrUpdate::new(self)
+        }
+        #[doc = "Create an updater whose read-modify-write happens inside the critical section `cs`"]
+        #[inline(always)]
+        pub fn update_in<'a, C: ::volatile_cell::sync::CriticalSection>(
+            &'a self,
+            _cs: &'a C,
+        ) -> OdrUpdate<'a> {
+            OdrUpdate::new(self)
+        }
+        #[doc = "Create a getter representing the current state of the register"]
+        #[inline(always)]
+        pub fn get(&self) -> OdrGet {
+            OdrGet::new(self)
+        }
+    }
+    pub struct OdrUpdate<'a> {
+        value: u32,
+        mask: u32,
+        write_only: bool,
+        store_on_drop: bool,
+        reg: &'a Odr,
+    }
+    impl<'a> OdrUpdate<'a> {
+        #[inline(always)]
+        pub fn new(reg: &'a Odr) -> OdrUpdate<'a> {
+            OdrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[inline(always)]
+        pub fn new_ignoring_state(reg: &'a Odr) -> OdrUpdate<'a> {
+            OdrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: true,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[doc = "An update that is never stored, for the caller to store itself"]
+        #[inline(always)]
+        fn detached(reg: &'a Odr) -> OdrUpdate<'a> {
+            OdrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: false,
+                reg: reg,
+            }
+        }
+        const fn clear_mask() -> u32 {
+            0u64 as u32
+        }
+        const fn update_offset(base: usize, size: usize, index: usize) -> usize {
+            base + (size * index)
+        }
+        #[inline(always)]
+        fn merge(&self, current: u32) -> u32 {
+            let v: u32 = current & (!Self::clear_mask()) & (!self.mask);
+            self.value | v
+        }
+        #[inline(always)]
+        pub fn set_od<'b>(&'b mut self, index: usize, new_value: bool) -> &'b mut Self {
+            if index > 32 {
+                panic!();
+            }
+            let update_offset = Self::update_offset(0, 1, index);
+            let context_mask: u32 = 1 << update_offset;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 1) << update_offset);
+            self.mask |= context_mask;
+            self
+        }
+    }
+    impl<'a> Drop for OdrUpdate<'a> {
+        #[inline(always)]
+        fn drop(&mut self) {
+            if self.store_on_drop && self.mask != 0 {
+                let current: u32 = 0;
+                self.reg.store(self.merge(current));
+            }
+        }
+    }
+    #[derive(Clone, PartialEq, Eq)]
+    pub struct OdrGet {
+        value: u32,
+    }
+    impl OdrGet {
+        #[doc = "Create a getter reflecting the current value of the register"]
+        #[inline(always)]
+        pub fn new(reg: &Odr) -> OdrGet {
+            OdrGet { value: reg.load() }
+        }
+    }
+    #[derive(Clone, Copy, PartialEq, Eq, Default)]
+    #[repr(transparent)]
+    pub struct OdrValue {
+        value: u32,
+    }
+    impl OdrValue {
+        #[doc = "Create a value with every field cleared"]
+        #[inline(always)]
+        pub const fn new() -> OdrValue {
+            OdrValue { value: 0 }
+        }
+        #[inline(always)]
+        pub const fn from_bits(bits: u32) -> OdrValue {
+            OdrValue { value: bits }
+        }
+        #[inline(always)]
+        pub const fn to_bits(&self) -> u32 {
+            self.value
+        }
+        #[inline(always)]
+        pub fn od(&self, index: usize) -> bool {
+            let val = (self.value >> (0 + (1 * index))) & 1;
+            val != 0x0
+        }
+        #[inline(always)]
+        pub const fn with_od(self, index: usize, new_value: bool) -> Self {
+            if index >= 32 {
+                panic!();
+            }
+            let shift = 0 + (1 * index);
+            let context_mask: u32 = 1 << shift;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 1) << shift),
+            }
+        }
+    }
+    impl From<OdrGet> for OdrValue {
+        #[inline(always)]
+        fn from(get: OdrGet) -> OdrValue {
+            OdrValue::from_bits(get.value)
+        }
+    }
+    impl From<OdrValue> for OdrGet {
+        #[inline(always)]
+        fn from(value: OdrValue) -> OdrGet {
+            OdrGet {
+                value: value.to_bits(),
+            }
+        }
+    }
+    impl Odr {
+        #[doc = "Write `value` to the register in a single store, without reading it first"]
+        #[inline(always)]
+        pub fn write(&self, value: OdrValue) {
+            self.store(value.to_bits());
+        }
+    }
+    impl<'a> OdrUpdate<'a> {
+        #[doc = "Replace every field of the register with those of `value`"]
+        #[inline(always)]
+        pub fn assign<'b>(&'b mut self, value: OdrValue) -> &'b mut Self {
+            self.value = value.to_bits();
+            self.mask = !0;
+            self
+        }
+    }
+    impl OdrGet {
+        pub const FIELDS: &'static [::volatile_cell::field::FieldDescriptor] = &[];
+        #[doc = "Iterate over the readable fields that differ from those of `other`"]
+        #[inline]
+        pub fn changed_fields(&self, other: &OdrGet) -> ::volatile_cell::field::FieldChanges {
+            ::volatile_cell::field::FieldChanges::new(
+                Self::FIELDS,
+                self.value as u64,
+                other.value as u64,
+            )
+        }
+    }
+    pub mod osr {}
+    #[repr(C)]
+    pub struct Osr {
+        value: ::volatile_cell::VolatileCell<u32>,
+    }
+    impl Osr {
+        #[inline(always)]
+        fn load(&self) -> u32 {
+            let value = self.value.get();
+            value
+        }
+        #[inline(always)]
+        fn store(&self, value: u32) {
+            self.value.set(value);
+        }
+        #[doc = "Create a new updater"]
+        #[inline(always)]
+        pub fn update<'a>(&'a self) -> OsrUpdate<'a> {
+            OsrUpdate::new(self)
+        }
+        #[doc = "Create an updater whose read-modify-write happens inside the critical section `cs`"]
+        #[inline(always)]
+        pub fn update_in<'a, C: ::volatile_cell::sync::CriticalSection>(
+            &'a self,
+            _cs: &'a C,
+        ) -> OsrUpdate<'a> {
+            OsrUpdate::new(self)
+        }
+        #[doc = "Update the register with exclusive loads and stores (LDREX/STREX), retrying `f` until no other access intervened"]
+        #[doc = ""]
+        #[doc = "The exclusive accesses go to the register's memory directly rather than through its cell, so they aren't seen by the replayer or the tracing hook."]
+        #[cfg(target_has_atomic = "32")]
+        #[inline]
+        pub fn update_exclusive<F: FnMut(&mut OsrUpdate)>(&self, mut f: F) {
+            use ::core::sync::atomic::Ordering;
+            let cell = unsafe { &*(self.value.as_ptr() as *const ::core::sync::atomic::AtomicU32) };
+            let mut current = cell.load(Ordering::Relaxed);
+            loop {
+                let mut update = OsrUpdate::detached(self);
+                f(&mut update);
+                let merged = update.merge(current);
+                if update.mask == 0 {
+                    return;
+                }
+                match cell.compare_exchange_weak(
+                    current,
+                    merged,
+                    Ordering::SeqCst,
+                    Ordering::Relaxed,
+                ) {
+                    Ok(_) => return,
+                    Err(actual) => current = actual,
+                }
+            }
+        }
+        #[doc = "Create a getter representing the current state of the register"]
+        #[inline(always)]
+        pub fn get(&self) -> OsrGet {
+            OsrGet::new(self)
+        }
+        #[doc = "Spin until the bit is set"]
+        #[inline]
+        pub fn wait_os(&self, index: usize) {
+            while !self.get().os(index) {}
+        }
+        #[doc = "Poll until the bit is set, at most `max_polls` times"]
+        #[inline]
+        pub fn wait_os_timeout(
+            &self,
+            index: usize,
+            max_polls: u32,
+        ) -> Result<(), ::volatile_cell::wait::Timeout> {
+            ::volatile_cell::wait::poll_until(|| self.get().os(index), max_polls)
+        }
+        #[doc = "Future resolving once the bit is set"]
+        #[inline]
+        pub fn wait_os_async<'a>(
+            &'a self,
+            index: usize,
+        ) -> ::volatile_cell::wait::WaitUntil<'a, impl FnMut() -> bool + Unpin + 'a> {
+            ::volatile_cell::wait::WaitUntil::new(move || self.get().os(index))
+        }
+    }
+    pub struct OsrUpdate<'a> {
+        value: u32,
+        mask: u32,
+        write_only: bool,
+        store_on_drop: bool,
+        reg: &'a Osr,
+    }
+    impl<'a> OsrUpdate<'a> {
+        #[inline(always)]
+        pub fn new(reg: &'a Osr) -> OsrUpdate<'a> {
+            OsrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[inline(always)]
+        pub fn new_ignoring_state(reg: &'a Osr) -> OsrUpdate<'a> {
+            OsrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: true,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[doc = "An update that is never stored, for the caller to store itself"]
+        #[inline(always)]
+        fn detached(reg: &'a Osr) -> OsrUpdate<'a> {
+            OsrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: false,
+                reg: reg,
+            }
+        }
+        const fn clear_mask() -> u32 {
+            0u64 as u32
+        }
+        const fn update_offset(base: usize, size: usize, index: usize) -> usize {
+            base + (size * index)
+        }
+        #[inline(always)]
+        fn merge(&self, current: u32) -> u32 {
+            let v: u32 = current & (!Self::clear_mask()) & (!self.mask);
+            self.value | v
+        }
+    }
+    impl<'a> Drop for OsrUpdate<'a> {
+        #[inline(always)]
+        fn drop(&mut self) {
+            if self.store_on_drop && self.mask != 0 {
+                let current: u32 = if self.write_only { 0 } else { self.reg.load() };
+                self.reg.store(self.merge(current));
+            }
+        }
+    }
+    #[derive(Clone, PartialEq, Eq)]
+    pub struct OsrGet {
+        value: u32,
+    }
+    impl OsrGet {
+        #[doc = "Create a getter reflecting the current value of the register"]
+        #[inline(always)]
+        pub fn new(reg: &Osr) -> OsrGet {
+            OsrGet { value: reg.load() }
+        }
+        #[inline(always)]
+        pub fn os(&self, index: usize) -> bool {
+            let val = (self.value >> (0 + (1 * index))) & 1;
+            val != 0x0
+        }
+    }
+    #[derive(Clone, Copy, PartialEq, Eq, Default)]
+    #[repr(transparent)]
+    pub struct OsrValue {
+        value: u32,
+    }
+    impl OsrValue {
+        #[doc = "Create a value with every field cleared"]
+        #[inline(always)]
+        pub const fn new() -> OsrValue {
+            OsrValue { value: 0 }
+        }
+        #[inline(always)]
+        pub const fn from_bits(bits: u32) -> OsrValue {
+            OsrValue { value: bits }
+        }
+        #[inline(always)]
+        pub const fn to_bits(&self) -> u32 {
+            self.value
+        }
+        #[inline(always)]
+        pub fn os(&self, index: usize) -> bool {
+            let val = (self.value >> (0 + (1 * index))) & 1;
+            val != 0x0
+        }
+        #[inline(always)]
+        pub const fn with_os(self, index: usize, new_value: bool) -> Self {
+            if index >= 32 {
+                panic!();
+            }
+            let shift = 0 + (1 * index);
+            let context_mask: u32 = 1 << shift;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 1) << shift),
+            }
+        }
+    }
+    impl From<OsrGet> for OsrValue {
+        #[inline(always)]
+        fn from(get: OsrGet) -> OsrValue {
+            OsrValue::from_bits(get.value)
+        }
+    }
+    impl From<OsrValue> for OsrGet {
+        #[inline(always)]
+        fn from(value: OsrValue) -> OsrGet {
+            OsrGet {
+                value: value.to_bits(),
+            }
+        }
+    }
+    impl Osr {
+        #[doc = "Write `value` to the register in a single store, without reading it first"]
+        #[inline(always)]
+        pub fn write(&self, value: OsrValue) {
+            self.store(value.to_bits());
+        }
+    }
+    impl<'a> OsrUpdate<'a> {
+        #[doc = "Replace every field of the register with those of `value`"]
+        #[inline(always)]
+        pub fn assign<'b>(&'b mut self, value: OsrValue) -> &'b mut Self {
+            self.value = value.to_bits();
+            self.mask = !0;
+            self
+        }
+    }
+    impl OsrGet {
+        pub const FIELDS: &'static [::volatile_cell::field::FieldDescriptor] =
+            &[::volatile_cell::field::FieldDescriptor {
+                name: "os",
+                offset: 0u32,
+                width: 1u32,
+                count: 32u32,
+            }];
+        #[doc = "Iterate over the readable fields that differ from those of `other`"]
+        #[inline]
+        pub fn changed_fields(&self, other: &OsrGet) -> ::volatile_cell::field::FieldChanges {
+            ::volatile_cell::field::FieldChanges::new(
+                Self::FIELDS,
+                self.value as u64,
+                other.value as u64,
+            )
+        }
+    }
+    pub mod ifer {}
+    #[repr(C)]
+    pub struct Ifer {
+        value: ::volatile_cell::VolatileCell<u32>,
+    }
+    impl Ifer {
+        #[inline(always)]
+        fn load(&self) -> u32 {
+            let value = self.value.get();
+            value
+        }
+        #[inline(always)]
+        fn store(&self, value: u32) {
+            self.value.set(value);
+        }
+        #[doc = "Create a new updater"]
+        #[inline(always)]
+        pub fn update<'a>(&'a self) -> IferUpdate<'a> {
+            IferUpdate::new(self)
+        }
+        #[doc = "Create an updater whose read-modify-write happens inside the critical section `cs`"]
+        #[inline(always)]
+        pub fn update_in<'a, C: ::volatile_cell::sync::CriticalSection>(
+            &'a self,
+            _cs: &'a C,
+        ) -> IferUpdate<'a> {
+            IferUpdate::new(self)
+        }
+        #[doc = "Create a getter representing the current state of the register"]
+        #[inline(always)]
+        pub fn get(&self) -> IferGet {
+            IferGet::new(self)
+        }
+    }
+    pub struct IferUpdate<'a> {
+        value: u32,
+        mask: u32,
+        write_only: bool,
+        store_on_drop: bool,
+        reg: &'a Ifer,
+    }
+    impl<'a> IferUpdate<'a> {
+        #[inline(always)]
+        pub fn new(reg: &'a Ifer) -> IferUpdate<'a> {
+            IferUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[inline(always)]
+        pub fn new_ignoring_state(reg: &'a Ifer) -> IferUpdate<'a> {
+            IferUpdate {
+                value: 0,
+                mask: 0,
+                write_only: true,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[doc = "An update that is never stored, for the caller to store itself"]
+        #[inline(always)]
+        fn detached(reg: &'a Ifer) -> IferUpdate<'a> {
+            IferUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: false,
+                reg: reg,
+            }
+        }
+        const fn clear_mask() -> u32 {
+            0u64 as u32
+        }
+        const fn update_offset(base: usize, size: usize, index: usize) -> usize {
+            base + (size * index)
+        }
+        #[inline(always)]
+        fn merge(&self, current: u32) -> u32 {
+            let v: u32 = current & (!Self::clear_mask()) & (!self.mask);
+            self.value | v
+        }
+        #[inline(always)]
+        pub fn set_ife<'b>(&'b mut self, index: usize, new_value: bool) -> &'b mut Self {
+            if index > 32 {
+                panic!();
+            }
+            let update_offset = Self::update_offset(0, 1, index);
+            let context_mask: u32 = 1 << update_offset;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 1) << update_offset);
+            self.mask |= context_mask;
+            self
+        }
+    }
+    impl<'a> Drop for IferUpdate<'a> {
+        #[inline(always)]
+        fn drop(&mut self) {
+            if self.store_on_drop && self.mask != 0 {
+                let current: u32 = 0;
+                self.reg.store(self.merge(current));
+            }
+        }
+    }
+    #[derive(Clone, PartialEq, Eq)]
+    pub struct IferGet {
+        value: u32,
+    }
+    impl IferGet {
+        #[doc = "Create a getter reflecting the current value of the register"]
+        #[inline(always)]
+        pub fn new(reg: &Ifer) -> IferGet {
+            IferGet { value: reg.load() }
+        }
+    }
+    #[derive(Clone, Copy, PartialEq, Eq, Default)]
+    #[repr(transparent)]
+    pub struct IferValue {
+        value: u32,
+    }
+    impl IferValue {
+        #[doc = "Create a value with every field cleared"]
+        #[inline(always)]
+        pub const fn new() -> IferValue {
+            IferValue { value: 0 }
+        }
+        #[inline(always)]
+        pub const fn from_bits(bits: u32) -> IferValue {
+            IferValue { value: bits }
+        }
+        #[inline(always)]
+        pub const fn to_bits(&self) -> u32 {
+            self.value
+        }
+        #[inline(always)]
+        pub fn ife(&self, index: usize) -> bool {
+            let val = (self.value >> (0 + (1 * index))) & 1;
+            val != 0x0
+        }
+        #[inline(always)]
+        pub const fn with_ife(self, index: usize, new_value: bool) -> Self {
+            if index >= 32 {
+                panic!();
+            }
+            let shift = 0 + (1 * index);
+            let context_mask: u32 = 1 << shift;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 1) << shift),
+            }
+        }
+    }
+    impl From<IferGet> for IferValue {
+        #[inline(always)]
+        fn from(get: IferGet) -> IferValue {
+            IferValue::from_bits(get.value)
+        }
+    }
+    impl From<IferValue> for IferGet {
+        #[inline(always)]
+        fn from(value: IferValue) -> IferGet {
+            IferGet {
+                value: value.to_bits(),
+            }
+        }
+    }
+    impl Ifer {
+        #[doc = "Write `value` to the register in a single store, without reading it first"]
+        #[inline(always)]
+        pub fn write(&self, value: IferValue) {
+            self.store(value.to_bits());
+        }
+    }
+    impl<'a> IferUpdate<'a> {
+        #[doc = "Replace every field of the register with those of `value`"]
+        #[inline(always)]
+        pub fn assign<'b>(&'b mut self, value: IferValue) -> &'b mut Self {
+            self.value = value.to_bits();
+            self.mask = !0;
+            self
+        }
+    }
+    impl IferGet {
+        pub const FIELDS: &'static [::volatile_cell::field::FieldDescriptor] = &[];
+        #[doc = "Iterate over the readable fields that differ from those of `other`"]
+        #[inline]
+        pub fn changed_fields(&self, other: &IferGet) -> ::volatile_cell::field::FieldChanges {
+            ::volatile_cell::field::FieldChanges::new(
+                Self::FIELDS,
+                self.value as u64,
+                other.value as u64,
+            )
+        }
+    }
+    pub mod ifdr {}
+    #[repr(C)]
+    pub struct Ifdr {
+        value: ::volatile_cell::VolatileCell<u32>,
+    }
+    impl Ifdr {
+        #[inline(always)]
+        fn load(&self) -> u32 {
+            let value = self.value.get();
+            value
+        }
+        #[inline(always)]
+        fn store(&self, value: u32) {
+            self.value.set(value);
+        }
+        #[doc = "Create a new updater"]
+        #[inline(always)]
+        pub fn update<'a>(&'a self) -> IfdrUpdate<'a> {
+            IfdrUpdate::new(self)
+        }
+        #[doc = "Create an updater whose read-modify-write happens inside the critical section `cs`"]
+        #[inline(always)]
+        pub fn update_in<'a, C: ::volatile_cell::sync::CriticalSection>(
+            &'a self,
+            _cs: &'a C,
+        ) -> IfdrUpdate<'a> {
+            IfdrUpdate::new(self)
+        }
+        #[doc = "Create a getter representing the current state of the register"]
+        #[inline(always)]
+        pub fn get(&self) -> IfdrGet {
+            IfdrGet::new(self)
+        }
+    }
+    pub struct IfdrUpdate<'a> {
+        value: u32,
+        mask: u32,
+        write_only: bool,
+        store_on_drop: bool,
+        reg: &'a Ifdr,
+    }
+    impl<'a> IfdrUpdate<'a> {
+        #[inline(always)]
+        pub fn new(reg: &'a Ifdr) -> IfdrUpdate<'a> {
+            IfdrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[inline(always)]
+        pub fn new_ignoring_state(reg: &'a Ifdr) -> IfdrUpdate<'a> {
+            IfdrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: true,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[doc = "An update that is never stored, for the caller to store itself"]
+        #[inline(always)]
+        fn detached(reg: &'a Ifdr) -> IfdrUpdate<'a> {
+            IfdrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: false,
+                reg: reg,
+            }
+        }
+        const fn clear_mask() -> u32 {
+            0u64 as u32
+        }
+        const fn update_offset(base: usize, size: usize, index: usize) -> usize {
+            base + (size * index)
+        }
+        #[inline(always)]
+        fn merge(&self, current: u32) -> u32 {
+            let v: u32 = current & (!Self::clear_mask()) & (!self.mask);
+            self.value | v
+        }
+        #[inline(always)]
+        pub fn set_ifd<'b>(&'b mut self, index: usize, new_value: bool) -> &'b mut Self {
+            if index > 32 {
+                panic!();
+            }
+            let update_offset = Self::update_offset(0, 1, index);
+            let context_mask: u32 = 1 << update_offset;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 1) << update_offset);
+            self.mask |= context_mask;
+            self
+        }
+    }
+    impl<'a> Drop for IfdrUpdate<'a> {
+        #[inline(always)]
+        fn drop(&mut self) {
+            if self.store_on_drop && self.mask != 0 {
+                let current: u32 = 0;
+                self.reg.store(self.merge(current));
+            }
+        }
+    }
+    #[derive(Clone, PartialEq, Eq)]
+    pub struct IfdrGet {
+        value: u32,
+    }
+    impl IfdrGet {
+        #[doc = "Create a getter reflecting the current value of the register"]
+        #[inline(always)]
+        pub fn new(reg: &Ifdr) -> IfdrGet {
+            IfdrGet { value: reg.load() }
+        }
+    }
+    #[derive(Clone, Copy, PartialEq, Eq, Default)]
+    #[repr(transparent)]
+    pub struct IfdrValue {
+        value: u32,
+    }
+    impl IfdrValue {
+        #[doc = "Create a value with every field cleared"]
+        #[inline(always)]
+        pub const fn new() -> IfdrValue {
+            IfdrValue { value: 0 }
+        }
+        #[inline(always)]
+        pub const fn from_bits(bits: u32) -> IfdrValue {
+            IfdrValue { value: bits }
+        }
+        #[inline(always)]
+        pub const fn to_bits(&self) -> u32 {
+            self.value
+        }
+        #[inline(always)]
+        pub fn ifd(&self, index: usize) -> bool {
+            let val = (self.value >> (0 + (1 * index))) & 1;
+            val != 0x0
+        }
+        #[inline(always)]
+        pub const fn with_ifd(self, index: usize, new_value: bool) -> Self {
+            if index >= 32 {
+                panic!();
+            }
+            let shift = 0 + (1 * index);
+            let context_mask: u32 = 1 << shift;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 1) << shift),
+            }
+        }
+    }
+    impl From<IfdrGet> for IfdrValue {
+        #[inline(always)]
+        fn from(get: IfdrGet) -> IfdrValue {
+            IfdrValue::from_bits(get.value)
+        }
+    }
+    impl From<IfdrValue> for IfdrGet {
+        #[inline(always)]
+        fn from(value: IfdrValue) -> IfdrGet {
+            IfdrGet {
+                value: value.to_bits(),
+            }
+        }
+    }
+    impl Ifdr {
+        #[doc = "Write `value` to the register in a single store, without reading it first"]
+        #[inline(always)]
+        pub fn write(&self, value: IfdrValue) {
+            self.store(value.to_bits());
+        }
+    }
+    impl<'a> IfdrUpdate<'a> {
+        #[doc = "Replace every field of the register with those of `value`"]
+        #[inline(always)]
+        pub fn assign<'b>(&'b mut self, value: IfdrValue) -> &'b mut Self {
+            self.value = value.to_bits();
+            self.mask = !0;
+            self
+        }
+    }
+    impl IfdrGet {
+        pub const FIELDS: &'static [::volatile_cell::field::FieldDescriptor] = &[];
+        #[doc = "Iterate over the readable fields that differ from those of `other`"]
+        #[inline]
+        pub fn changed_fields(&self, other: &IfdrGet) -> ::volatile_cell::field::FieldChanges {
+            ::volatile_cell::field::FieldChanges::new(
+                Self::FIELDS,
+                self.value as u64,
+                other.value as u64,
+            )
+        }
+    }
+    pub mod ifsr {}
+    #[repr(C)]
+    pub struct Ifsr {
+        value: ::volatile_cell::VolatileCell<u32>,
+    }
+    impl Ifsr {
+        #[inline(always)]
+        fn load(&self) -> u32 {
+            let value = self.value.get();
+            value
+        }
+        #[inline(always)]
+        fn store(&self, value: u32) {
+            self.value.set(value);
+        }
+        #[doc = "Create a new updater"]
+        #[inline(always)]
+        pub fn update<'a>(&'a self) -> IfsrUpdate<'a> {
+            IfsrUpdate::new(self)
+        }
+        #[doc = "Create an updater whose read-modify-write happens inside the critical section `cs`"]
+        #[inline(always)]
+        pub fn update_in<'a, C: ::volatile_cell::sync::CriticalSection>(
+            &'a self,
+            _cs: &'a C,
+        ) -> IfsrUpdate<'a> {
+            IfsrUpdate::new(self)
+        }
+        #[doc = "Update the register with exclusive loads and stores (LDREX/STREX), retrying `f` until no other access intervened"]
+        #[doc = ""]
+        #[doc = "The exclusive accesses go to the register's memory directly rather than through its cell, so they aren't seen by the replayer or the tracing hook."]
+        #[cfg(target_has_atomic = "32")]
+        #[inline]
+        pub fn update_exclusive<F: FnMut(&mut IfsrUpdate)>(&self, mut f: F) {
+            use ::core::sync::atomic::Ordering;
+            let cell = unsafe { &*(self.value.as_ptr() as *const ::core::sync::atomic::AtomicU32) };
+            let mut current = cell.load(Ordering::Relaxed);
+            loop {
+                let mut update = IfsrUpdate::detached(self);
+                f(&mut update);
+                let merged = update.merge(current);
+                if update.mask == 0 {
+                    return;
+                }
+                match cell.compare_exchange_weak(
+                    current,
+                    merged,
+                    Ordering::SeqCst,
+                    Ordering::Relaxed,
+                ) {
+                    Ok(_) => return,
+                    Err(actual) => current = actual,
+                }
+            }
+        }
+        #[doc = "Create a getter representing the current state of the register"]
+        #[inline(always)]
+        pub fn get(&self) -> IfsrGet {
+            IfsrGet::new(self)
+        }
+        #[doc = "Spin until the bit is set"]
+        #[inline]
+        pub fn wait_ifd(&self, index: usize) {
+            while !self.get().ifd(index) {}
+        }
+        #[doc = "Poll until the bit is set, at most `max_polls` times"]
+        #[inline]
+        pub fn wait_ifd_timeout(
+            &self,
+            index: usize,
+            max_polls: u32,
+        ) -> Result<(), ::volatile_cell::wait::Timeout> {
+            ::volatile_cell::wait::poll_until(|| self.get().ifd(index), max_polls)
+        }
+        #[doc = "Future resolving once the bit is set"]
+        #[inline]
+        pub fn wait_ifd_async<'a>(
+            &'a self,
+            index: usize,
+        ) -> ::volatile_cell::wait::WaitUntil<'a, impl FnMut() -> bool + Unpin + 'a> {
+            ::volatile_cell::wait::WaitUntil::new(move || self.get().ifd(index))
+        }
+    }
+    pub struct IfsrUpdate<'a> {
+        value: u32,
+        mask: u32,
+        write_only: bool,
+        store_on_drop: bool,
+        reg: &'a Ifsr,
+    }
+    impl<'a> IfsrUpdate<'a> {
+        #[inline(always)]
+        pub fn new(reg: &'a Ifsr) -> IfsrUpdate<'a> {
+            IfsrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[inline(always)]
+        pub fn new_ignoring_state(reg: &'a Ifsr) -> IfsrUpdate<'a> {
+            IfsrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: true,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[doc = "An update that is never stored, for the caller to store itself"]
+        #[inline(always)]
+        fn detached(reg: &'a Ifsr) -> IfsrUpdate<'a> {
+            IfsrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: false,
+                reg: reg,
+            }
+        }
+        const fn clear_mask() -> u32 {
+            0u64 as u32
+        }
+        const fn update_offset(base: usize, size: usize, index: usize) -> usize {
+            base + (size * index)
+        }
+        #[inline(always)]
+        fn merge(&self, current: u32) -> u32 {
+            let v: u32 = current & (!Self::clear_mask()) & (!self.mask);
+            self.value | v
+        }
+    }
+    impl<'a> Drop for IfsrUpdate<'a> {
+        #[inline(always)]
+        fn drop(&mut self) {
+            if self.store_on_drop && self.mask != 0 {
+                let current: u32 = if self.write_only { 0 } else { self.reg.load() };
+                self.reg.store(self.merge(current));
+            }
+        }
+    }
+    #[derive(Clone, PartialEq, Eq)]
+    pub struct IfsrGet {
+        value: u32,
+    }
+    impl IfsrGet {
+        #[doc = "Create a getter reflecting the current value of the register"]
+        #[inline(always)]
+        pub fn new(reg: &Ifsr) -> IfsrGet {
+            IfsrGet { value: reg.load() }
+        }
+        #[inline(always)]
+        pub fn ifd(&self, index: usize) -> bool {
+            let val = (self.value >> (0 + (1 * index))) & 1;
+            val != 0x0
+        }
+    }
+    #[derive(Clone, Copy, PartialEq, Eq, Default)]
+    #[repr(transparent)]
+    pub struct IfsrValue {
+        value: u32,
+    }
+    impl IfsrValue {
+        #[doc = "Create a value with every field cleared"]
+        #[inline(always)]
+        pub const fn new() -> IfsrValue {
+            IfsrValue { value: 0 }
+        }
+        #[inline(always)]
+        pub const fn from_bits(bits: u32) -> IfsrValue {
+            IfsrValue { value: bits }
+        }
+        #[inline(always)]
+        pub const fn to_bits(&self) -> u32 {
+            self.value
+        }
+        #[inline(always)]
+        pub fn ifd(&self, index: usize) -> bool {
+            let val = (self.value >> (0 + (1 * index))) & 1;
+            val != 0x0
+        }
+        #[inline(always)]
+        pub const fn with_ifd(self, index: usize, new_value: bool) -> Self {
+            if index >= 32 {
+                panic!();
+            }
+            let shift = 0 + (1 * index);
+            let context_mask: u32 = 1 << shift;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 1) << shift),
+            }
+        }
+    }
+    impl From<IfsrGet> for IfsrValue {
+        #[inline(always)]
+        fn from(get: IfsrGet) -> IfsrValue {
+            IfsrValue::from_bits(get.value)
+        }
+    }
+    impl From<IfsrValue> for IfsrGet {
+        #[inline(always)]
+        fn from(value: IfsrValue) -> IfsrGet {
+            IfsrGet {
+                value: value.to_bits(),
+            }
+        }
+    }
+    impl Ifsr {
+        #[doc = "Write `value` to the register in a single store, without reading it first"]
+        #[inline(always)]
+        pub fn write(&self, value: IfsrValue) {
+            self.store(value.to_bits());
+        }
+    }
+    impl<'a> IfsrUpdate<'a> {
+        #[doc = "Replace every field of the register with those of `value`"]
+        #[inline(always)]
+        pub fn assign<'b>(&'b mut self, value: IfsrValue) -> &'b mut Self {
+            self.value = value.to_bits();
+            self.mask = !0;
+            self
+        }
+    }
+    impl IfsrGet {
+        pub const FIELDS: &'static [::volatile_cell::field::FieldDescriptor] =
+            &[::volatile_cell::field::FieldDescriptor {
+                name: "ifd",
+                offset: 0u32,
+                width: 1u32,
+                count: 32u32,
+            }];
+        #[doc = "Iterate over the readable fields that differ from those of `other`"]
+        #[inline]
+        pub fn changed_fields(&self, other: &IfsrGet) -> ::volatile_cell::field::FieldChanges {
+            ::volatile_cell::field::FieldChanges::new(
+                Self::FIELDS,
+                self.value as u64,
+                other.value as u64,
+            )
+        }
+    }
+    pub mod sodr {}
+    #[repr(C)]
+    pub struct Sodr {
+        value: ::volatile_cell::VolatileCell<u32>,
+    }
+    impl Sodr {
+        #[inline(always)]
+        fn load(&self) -> u32 {
+            let value = self.value.get();
+            value
+        }
+        #[inline(always)]
+        fn store(&self, value: u32) {
+            self.value.set(value);
+        }
+        #[doc = "Create a new updater"]
+        #[inline(always)]
+        pub fn update<'a>(&'a self) -> SodrUpdate<'a> {
+            SodrUpdate::new(self)
+        }
+        #[doc = "Create an updater whose read-modify-write happens inside the critical section `cs`"]
+        #[inline(always)]
+        pub fn update_in<'a, C: ::volatile_cell::sync::CriticalSection>(
+            &'a self,
+            _cs: &'a C,
+        ) -> SodrUpdate<'a> {
+            SodrUpdate::new(self)
+        }
+        #[doc = "Create a getter representing the current state of the register"]
+        #[inline(always)]
+        pub fn get(&self) -> SodrGet {
+            SodrGet::new(self)
+        }
+    }
+    pub struct SodrUpdate<'a> {
+        value: u32,
+        mask: u32,
+        write_only: bool,
+        store_on_drop: bool,
+        reg: &'a Sodr,
+    }
+    impl<'a> SodrUpdate<'a> {
+        #[inline(always)]
+        pub fn new(reg: &'a Sodr) -> SodrUpdate<'a> {
+            SodrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[inline(always)]
+        pub fn new_ignoring_state(reg: &'a Sodr) -> SodrUpdate<'a> {
+            SodrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: true,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[doc = "An update that is never stored, for the caller to store itself"]
+        #[inline(always)]
+        fn detached(reg: &'a Sodr) -> SodrUpdate<'a> {
+            SodrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: false,
+                reg: reg,
+            }
+        }
+        const fn clear_mask() -> u32 {
+            0u64 as u32
+        }
+        const fn update_offset(base: usize, size: usize, index: usize) -> usize {
+            base + (size * index)
+        }
+        #[inline(always)]
+        fn merge(&self, current: u32) -> u32 {
+            let v: u32 = current & (!Self::clear_mask()) & (!self.mask);
+            self.value | v
+        }
+        #[inline(always)]
+        pub fn set_sod<'b>(&'b mut self, index: usize, new_value: bool) -> &'b mut Self {
+            if index > 32 {
+                panic!();
+            }
+            let update_offset = Self::update_offset(0, 1, index);
+            let context_mask: u32 = 1 << update_offset;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 1) << update_offset);
+            self.mask |= context_mask;
+            self
+        }
+    }
+    impl<'a> Drop for SodrUpdate<'a> {
+        #[inline(always)]
+        fn drop(&mut self) {
+            if self.store_on_drop && self.mask != 0 {
+                let current: u32 = 0;
+                self.reg.store(self.merge(current));
+            }
+        }
+    }
+    #[derive(Clone, PartialEq, Eq)]
+    pub struct SodrGet {
+        value: u32,
+    }
+    impl SodrGet {
+        #[doc = "Create a getter reflecting the current value of the register"]
+        #[inline(always)]
+        pub fn new(reg: &Sodr) -> SodrGet {
+            SodrGet { value: reg.load() }
+        }
+    }
+    #[derive(Clone, Copy, PartialEq, Eq, Default)]
+    #[repr(transparent)]
+    pub struct SodrValue {
+        value: u32,
+    }
+    impl SodrValue {
+        #[doc = "Create a value with every field cleared"]
+        #[inline(always)]
+        pub const fn new() -> SodrValue {
+            SodrValue { value: 0 }
+        }
+        #[inline(always)]
+        pub const fn from_bits(bits: u32) -> SodrValue {
+            SodrValue { value: bits }
+        }
+        #[inline(always)]
+        pub const fn to_bits(&self) -> u32 {
+            self.value
+        }
+        #[inline(always)]
+        pub fn sod(&self, index: usize) -> bool {
+            let val = (self.value >> (0 + (1 * index))) & 1;
+            val != 0x0
+        }
+        #[inline(always)]
+        pub const fn with_sod(self, index: usize, new_value: bool) -> Self {
+            if index >= 32 {
+                panic!();
+            }
+            let shift = 0 + (1 * index);
+            let context_mask: u32 = 1 << shift;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 1) << shift),
+            }
+        }
+    }
+    impl From<SodrGet> for SodrValue {
+        #[inline(always)]
+        fn from(get: SodrGet) -> SodrValue {
+            SodrValue::from_bits(get.value)
+        }
+    }
+    impl From<SodrValue> for SodrGet {
+        #[inline(always)]
+        fn from(value: SodrValue) -> SodrGet {
+            SodrGet {
+                value: value.to_bits(),
+            }
+        }
+    }
+    impl Sodr {
+        #[doc = "Write `value` to the register in a single store, without reading it first"]
+        #[inline(always)]
+        pub fn write(&self, value: SodrValue) {
+            self.store(value.to_bits());
+        }
+    }
+    impl<'a> SodrUpdate<'a> {
+        #[doc = "Replace every field of the register with those of `value`"]
+        #[inline(always)]
+        pub fn assign<'b>(&'b mut self, value: SodrValue) -> &'b mut Self {
+            self.value = value.to_bits();
+            self.mask = !0;
+            self
+        }
+    }
+    impl SodrGet {
+        pub const FIELDS: &'static [::volatile_cell::field::FieldDescriptor] = &[];
+        #[doc = "Iterate over the readable fields that differ from those of `other`"]
+        #[inline]
+        pub fn changed_fields(&self, other: &SodrGet) -> ::volatile_cell::field::FieldChanges {
+            ::volatile_cell::field::FieldChanges::new(
+                Self::FIELDS,
+                self.value as u64,
+                other.value as u64,
+            )
+        }
+    }
+    pub mod codr {}
+    #[repr(C)]
+    pub struct Codr {
+        value: ::volatile_cell::VolatileCell<u32>,
+    }
+    impl Codr {
+        #[inline(always)]
+        fn load(&self) -> u32 {
+            let value = self.value.get();
+            value
+        }
+        #[inline(always)]
+        fn store(&self, value: u32) {
+            self.value.set(value);
+        }
+        #[doc = "Create a new updater"]
+        #[inline(always)]
+        pub fn update<'a>(&'a self) -> CodrUpdate<'a> {
+            CodrUpdate::new(self)
+        }
+        #[doc = "Create an updater whose read-modify-write happens inside the critical section `cs`"]
+        #[inline(always)]
+        pub fn update_in<'a, C: ::volatile_cell::sync::CriticalSection>(
+            &'a self,
+            _cs: &'a C,
+        ) -> CodrUpdate<'a> {
+            CodrUpdate::new(self)
+        }
+        #[doc = "Create a getter representing the current state of the register"]
+        #[inline(always)]
+        pub fn get(&self) -> CodrGet {
+            CodrGet::new(self)
+        }
+    }
+    pub struct CodrUpdate<'a> {
+        value: u32,
+        mask: u32,
+        write_only: bool,
+        store_on_drop: bool,
+        reg: &'a Codr,
+    }
+    impl<'a> CodrUpdate<'a> {
+        #[inline(always)]
+        pub fn new(reg: &'a Codr) -> CodrUpdate<'a> {
+            CodrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[inline(always)]
+        pub fn new_ignoring_state(reg: &'a Codr) -> CodrUpdate<'a> {
+            CodrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: true,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[doc = "An update that is never stored, for the caller to store itself"]
+        #[inline(always)]
+        fn detached(reg: &'a Codr) -> CodrUpdate<'a> {
+            CodrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: false,
+                reg: reg,
+            }
+        }
+        const fn clear_mask() -> u32 {
+            0u64 as u32
+        }
+        const fn update_offset(base: usize, size: usize, index: usize) -> usize {
+            base + (size * index)
+        }
+        #[inline(always)]
+        fn merge(&self, current: u32) -> u32 {
+            let v: u32 = current & (!Self::clear_mask()) & (!self.mask);
+            self.value | v
+        }
+        #[inline(always)]
+        pub fn set_cod<'b>(&'b mut self, index: usize, new_value: bool) -> &'b mut Self {
+            if index > 32 {
+                panic!();
+            }
+            let update_offset = Self::update_offset(0, 1, index);
+            let context_mask: u32 = 1 << update_offset;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 1) << update_offset);
+            self.mask |= context_mask;
+            self
+        }
+    }
+    impl<'a> Drop for CodrUpdate<'a> {
+        #[inline(always)]
+        fn drop(&mut self) {
+            if self.store_on_drop && self.mask != 0 {
+                let current: u32 = 0;
+                self.reg.store(self.merge(current));
+            }
+        }
+    }
+    #[derive(Clone, PartialEq, Eq)]
+    pub struct CodrGet {
+        value: u32,
+    }
+    impl CodrGet {
+        #[doc = "Create a getter reflecting the current value of the register"]
+        #[inline(always)]
+        pub fn new(reg: &Codr) -> CodrGet {
+            CodrGet { value: reg.load() }
+        }
+    }
+    #[derive(Clone, Copy, PartialEq, Eq, Default)]
+    #[repr(transparent)]
+    pub struct CodrValue {
+        value: u32,
+    }
+    impl CodrValue {
+        #[doc = "Create a value with every field cleared"]
+        #[inline(always)]
+        pub const fn new() -> CodrValue {
+            CodrValue { value: 0 }
+        }
+        #[inline(always)]
+        pub const fn from_bits(bits: u32) -> CodrValue {
+            CodrValue { value: bits }
+        }
+        #[inline(always)]
+        pub const fn to_bits(&self) -> u32 {
+            self.value
+        }
+        #[inline(always)]
+        pub fn cod(&self, index: usize) -> bool {
+            let val = (self.value >> (0 + (1 * index))) & 1;
+            val != 0x0
+        }
+        #[inline(always)]
+        pub const fn with_cod(self, index: usize, new_value: bool) -> Self {
+            if index >= 32 {
+                panic!();
+            }
+            let shift = 0 + (1 * index);
+            let context_mask: u32 = 1 << shift;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 1) << shift),
+            }
+        }
+    }
+    impl From<CodrGet> for CodrValue {
+        #[inline(always)]
+        fn from(get: CodrGet) -> CodrValue {
+            CodrValue::from_bits(get.value)
+        }
+    }
+    impl From<CodrValue> for CodrGet {
+        #[inline(always)]
+        fn from(value: CodrValue) -> CodrGet {
+            CodrGet {
+                value: value.to_bits(),
+            }
+        }
+    }
+    impl Codr {
+        #[doc = "Write `value` to the register in a single store, without reading it first"]
+        #[inline(always)]
+        pub fn write(&self, value: CodrValue) {
+            self.store(value.to_bits());
+        }
+    }
+    impl<'a> CodrUpdate<'a> {
+        #[doc = "Replace every field of the register with those of `value`"]
+        #[inline(always)]
+        pub fn assign<'b>(&'b mut self, value: CodrValue) -> &'b mut Self {
+            self.value = value.to_bits();
+            self.mask = !0;
+            self
+        }
+    }
+    impl CodrGet {
+        pub const FIELDS: &'static [::volatile_cell::field::FieldDescriptor] = &[];
+        #[doc = "Iterate over the readable fields that differ from those of `other`"]
+        #[inline]
+        pub fn changed_fields(&self, other: &CodrGet) -> ::volatile_cell::field::FieldChanges {
+            ::volatile_cell::field::FieldChanges::new(
+                Self::FIELDS,
+                self.value as u64,
+                other.value as u64,
+            )
+        }
+    }
+    pub mod odsr {}
+    #[repr(C)]
+    pub struct Odsr {
+        value: ::volatile_cell::VolatileCell<u32>,
+    }
+    impl Odsr {
+        #[inline(always)]
+        fn load(&self) -> u32 {
+            let value = self.value.get();
+            value
+        }
+        #[inline(always)]
+        fn store(&self, value: u32) {
+            self.value.set(value);
+        }
+        #[doc = "Create a new updater"]
+        #[inline(always)]
+        pub fn update<'a>(&'a self) -> OdsrUpdate<'a> {
+            OdsrUpdate::new(self)
+        }
+        #[doc = "Create an updater whose read-modify-write happens inside the critical section `cs`"]
+        #[inline(always)]
+        pub fn update_in<'a, C: ::volatile_cell::sync::CriticalSection>(
+            &'a self,
+            _cs: &'a C,
+        ) -> OdsrUpdate<'a> {
+            OdsrUpdate::new(self)
+        }
+        #[doc = "Update the register with exclusive loads and stores (LDREX/STREX), retrying `f` until no other access intervened"]
+        #[doc = ""]
+        #[doc = "The exclusive accesses go to the register's memory directly rather than through its cell, so they aren't seen by the replayer or the tracing hook."]
+        #[cfg(target_has_atomic = "32")]
+        #[inline]
+        pub fn update_exclusive<F: FnMut(&mut OdsrUpdate)>(&self, mut f: F) {
+            use ::core::sync::atomic::Ordering;
+            let cell = unsafe { &*(self.value.as_ptr() as *const ::core::sync::atomic::AtomicU32) };
+            let mut current = cell.load(Ordering::Relaxed);
+            loop {
+                let mut update = OdsrUpdate::detached(self);
+                f(&mut update);
+                let merged = update.merge(current);
+                if update.mask == 0 {
+                    return;
+                }
+                match cell.compare_exchange_weak(
+                    current,
+                    merged,
+                    Ordering::SeqCst,
+                    Ordering::Relaxed,
+                ) {
+                    Ok(_) => return,
+                    Err(actual) => current = actual,
+                }
+            }
+        }
+        #[doc = "Create a getter representing the current state of the register"]
+        #[inline(always)]
+        pub fn get(&self) -> OdsrGet {
+            OdsrGet::new(self)
+        }
+        #[doc = "Spin until the bit is set"]
+        #[inline]
+        pub fn wait_ods(&self, index: usize) {
+            while !self.get().ods(index) {}
+        }
+        #[doc = "Poll until the bit is set, at most `max_polls` times"]
+        #[inline]
+        pub fn wait_ods_timeout(
+            &self,
+            index: usize,
+            max_polls: u32,
+        ) -> Result<(), ::volatile_cell::wait::Timeout> {
+            ::volatile_cell::wait::poll_until(|| self.get().ods(index), max_polls)
+        }
+        #[doc = "Future resolving once the bit is set"]
+        #[inline]
+        pub fn wait_ods_async<'a>(
+            &'a self,
+            index: usize,
+        ) -> ::volatile_cell::wait::WaitUntil<'a, impl FnMut() -> bool + Unpin + 'a> {
+            ::volatile_cell::wait::WaitUntil::new(move || self.get().ods(index))
+        }
+    }
+    pub struct OdsrUpdate<'a> {
+        value: u32,
+        mask: u32,
+        write_only: bool,
+        store_on_drop: bool,
+        reg: &'a Odsr,
+    }
+    impl<'a> OdsrUpdate<'a> {
+        #[inline(always)]
+        pub fn new(reg: &'a Odsr) -> OdsrUpdate<'a> {
+            OdsrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[inline(always)]
+        pub fn new_ignoring_state(reg: &'a Odsr) -> OdsrUpdate<'a> {
+            OdsrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: true,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[doc = "An update that is never stored, for the caller to store itself"]
+        #[inline(always)]
+        fn detached(reg: &'a Odsr) -> OdsrUpdate<'a> {
+            OdsrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: false,
+                reg: reg,
+            }
+        }
+        const fn clear_mask() -> u32 {
+            0u64 as u32
+        }
+        const fn update_offset(base: usize, size: usize, index: usize) -> usize {
+            base + (size * index)
+        }
+        #[inline(always)]
+        fn merge(&self, current: u32) -> u32 {
+            let v: u32 = current & (!Self::clear_mask()) & (!self.mask);
+            self.value | v
+        }
+        #[inline(always)]
+        pub fn set_ods<'b>(&'b mut self, index: usize, new_value: bool) -> &'b mut Self {
+            if index > 32 {
+                panic!();
+            }
+            let update_offset = Self::update_offset(0, 1, index);
+            let context_mask: u32 = 1 << update_offset;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 1) << update_offset);
+            self.mask |= context_mask;
+            self
+        }
+    }
+    impl<'a> Drop for OdsrUpdate<'a> {
+        #[inline(always)]
+        fn drop(&mut self) {
+            if self.store_on_drop && self.mask != 0 {
+                let current: u32 = if self.write_only { 0 } else { self.reg.load() };
+                self.reg.store(self.merge(current));
+            }
+        }
+    }
+    #[derive(Clone, PartialEq, Eq)]
+    pub struct OdsrGet {
+        value: u32,
+    }
+    impl OdsrGet {
+        #[doc = "Create a getter reflecting the current value of the register"]
+        #[inline(always)]
+        pub fn new(reg: &Odsr) -> OdsrGet {
+            OdsrGet { value: reg.load() }
+        }
+        #[inline(always)]
+        pub fn ods(&self, index: usize) -> bool {
+            let val = (self.value >> (0 + (1 * index))) & 1;
+            val != 0x0
+        }
+    }
+    #[derive(Clone, Copy, PartialEq, Eq, Default)]
+    #[repr(transparent)]
+    pub struct OdsrValue {
+        value: u32,
+    }
+    impl OdsrValue {
+        #[doc = "Create a value with every field cleared"]
+        #[inline(always)]
+        pub const fn new() -> OdsrValue {
+            OdsrValue { value: 0 }
+        }
+        #[inline(always)]
+        pub const fn from_bits(bits: u32) -> OdsrValue {
+            OdsrValue { value: bits }
+        }
+        #[inline(always)]
+        pub const fn to_bits(&self) -> u32 {
+            self.value
+        }
+        #[inline(always)]
+        pub fn ods(&self, index: usize) -> bool {
+            let val = (self.value >> (0 + (1 * index))) & 1;
+            val != 0x0
+        }
+        #[inline(always)]
+        pub const fn with_ods(self, index: usize, new_value: bool) -> Self {
+            if index >= 32 {
+                panic!();
+            }
+            let shift = 0 + (1 * index);
+            let context_mask: u32 = 1 << shift;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 1) << shift),
+            }
+        }
+    }
+    impl From<OdsrGet> for OdsrValue {
+        #[inline(always)]
+        fn from(get: OdsrGet) -> OdsrValue {
+            OdsrValue::from_bits(get.value)
+        }
+    }
+    impl From<OdsrValue> for OdsrGet {
+        #[inline(always)]
+        fn from(value: OdsrValue) -> OdsrGet {
+            OdsrGet {
+                value: value.to_bits(),
+            }
+        }
+    }
+    impl Odsr {
+        #[doc = "Write `value` to the register in a single store, without reading it first"]
+        #[inline(always)]
+        pub fn write(&self, value: OdsrValue) {
+            self.store(value.to_bits());
+        }
+    }
+    impl<'a> OdsrUpdate<'a> {
+        #[doc = "Replace every field of the register with those of `value`"]
+        #[inline(always)]
+        pub fn assign<'b>(&'b mut self, value: OdsrValue) -> &'b mut Self {
+            self.value = value.to_bits();
+            self.mask = !0;
+            self
+        }
+    }
+    impl OdsrGet {
+        pub const FIELDS: &'static [::volatile_cell::field::FieldDescriptor] =
+            &[::volatile_cell::field::FieldDescriptor {
+                name: "ods",
+                offset: 0u32,
+                width: 1u32,
+                count: 32u32,
+            }];
+        #[doc = "Iterate over the readable fields that differ from those of `other`"]
+        #[inline]
+        pub fn changed_fields(&self, other: &OdsrGet) -> ::volatile_cell::field::FieldChanges {
+            ::volatile_cell::field::FieldChanges::new(
+                Self::FIELDS,
+                self.value as u64,
+                other.value as u64,
+            )
+        }
+    }
+    pub mod pdsr {}
+    #[repr(C)]
+    pub struct Pdsr {
+        value: ::volatile_cell::VolatileCell<u32>,
+    }
+    impl Pdsr {
+        #[inline(always)]
+        fn load(&self) -> u32 {
+            let value = self.value.get();
+            value
+        }
+        #[inline(always)]
+        fn store(&self, value: u32) {
+            self.value.set(value);
+        }
+        #[doc = "Create a new updater"]
+        #[inline(always)]
+        pub fn update<'a>(&'a self) -> PdsrUpdate<'a> {
+            PdsrUpdate::new(self)
+        }
+        #[doc = "Create an updater whose read-modify-write happens inside the critical section `cs`"]
+        #[inline(always)]
+        pub fn update_in<'a, C: ::volatile_cell::sync::CriticalSection>(
+            &'a self,
+            _cs: &'a C,
+        ) -> PdsrUpdate<'a> {
+            PdsrUpdate::new(self)
+        }
+        #[doc = "Update the register with exclusive loads and stores (LDREX/STREX), retrying `f` until no other access intervened"]
+        #[doc = ""]
+        #[doc = "The exclusive accesses go to the register's memory directly rather than through its cell, so they aren't seen by the replayer or the tracing hook."]
+        #[cfg(target_has_atomic = "32")]
+        #[inline]
+        pub fn update_exclusive<F: FnMut(&mut PdsrUpdate)>(&self, mut f: F) {
+            use ::core::sync::atomic::Ordering;
+            let cell = unsafe { &*(self.value.as_ptr() as *const ::core::sync::atomic::AtomicU32) };
+            let mut current = cell.load(Ordering::Relaxed);
+            loop {
+                let mut update = PdsrUpdate::detached(self);
+                f(&mut update);
+                let merged = update.merge(current);
+                if update.mask == 0 {
+                    return;
+                }
+                match cell.compare_exchange_weak(
+                    current,
+                    merged,
+                    Ordering::SeqCst,
+                    Ordering::Relaxed,
+                ) {
+                    Ok(_) => return,
+                    Err(actual) => current = actual,
+                }
+            }
+        }
+        #[doc = "Create a getter representing the current state of the register"]
+        #[inline(always)]
+        pub fn get(&self) -> PdsrGet {
+            PdsrGet::new(self)
+        }
+        #[doc = "Spin until the bit is set"]
+        #[inline]
+        pub fn wait_pds(&self, index: usize) {
+            while !self.get().pds(index) {}
+        }
+        #[doc = "Poll until the bit is set, at most `max_polls` times"]
+        #[inline]
+        pub fn wait_pds_timeout(
+            &self,
+            index: usize,
+            max_polls: u32,
+        ) -> Result<(), ::volatile_cell::wait::Timeout> {
+            ::volatile_cell::wait::poll_until(|| self.get().pds(index), max_polls)
+        }
+        #[doc = "Future resolving once the bit is set"]
+        #[inline]
+        pub fn wait_pds_async<'a>(
+            &'a self,
+            index: usize,
+        ) -> ::volatile_cell::wait::WaitUntil<'a, impl FnMut() -> bool + Unpin + 'a> {
+            ::volatile_cell::wait::WaitUntil::new(move || self.get().pds(index))
+        }
+    }
+    pub struct PdsrUpdate<'a> {
+        value: u32,
+        mask: u32,
+        write_only: bool,
+        store_on_drop: bool,
+        reg: &'a Pdsr,
+    }
+    impl<'a> PdsrUpdate<'a> {
+        #[inline(always)]
+        pub fn new(reg: &'a Pdsr) -> PdsrUpdate<'a> {
+            PdsrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[inline(always)]
+        pub fn new_ignoring_state(reg: &'a Pdsr) -> PdsrUpdate<'a> {
+            PdsrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: true,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[doc = "An update that is never stored, for the caller to store itself"]
+        #[inline(always)]
+        fn detached(reg: &'a Pdsr) -> PdsrUpdate<'a> {
+            PdsrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: false,
+                reg: reg,
+            }
+        }
+        const fn clear_mask() -> u32 {
+            0u64 as u32
+        }
+        const fn update_offset(base: usize, size: usize, index: usize) -> usize {
+            base + (size * index)
+        }
+        #[inline(always)]
+        fn merge(&self, current: u32) -> u32 {
+            let v: u32 = current & (!Self::clear_mask()) & (!self.mask);
+            self.value | v
+        }
+    }
+    impl<'a> Drop for PdsrUpdate<'a> {
+        #[inline(always)]
+        fn drop(&mut self) {
+            if self.store_on_drop && self.mask != 0 {
+                let current: u32 = if self.write_only { 0 } else { self.reg.load() };
+                self.reg.store(self.merge(current));
+            }
+        }
+    }
+    #[derive(Clone, PartialEq, Eq)]
+    pub struct PdsrGet {
+        value: u32,
+    }
+    impl PdsrGet {
+        #[doc = "Create a getter reflecting the current value of the register"]
+        #[inline(always)]
+        pub fn new(reg: &Pdsr) -> PdsrGet {
+            PdsrGet { value: reg.load() }
+        }
+        #[inline(always)]
+        pub fn pds(&self, index: usize) -> bool {
+            let val = (self.value >> (0 + (1 * index))) & 1;
+            val != 0x0
+        }
+    }
+    #[derive(Clone, Copy, PartialEq, Eq, Default)]
+    #[repr(transparent)]
+    pub struct PdsrValue {
+        value: u32,
+    }
+    impl PdsrValue {
+        #[doc = "Create a value with every field cleared"]
+        #[inline(always)]
+        pub const fn new() -> PdsrValue {
+            PdsrValue { value: 0 }
+        }
+        #[inline(always)]
+        pub const fn from_bits(bits: u32) -> PdsrValue {
+            PdsrValue { value: bits }
+        }
+        #[inline(always)]
+        pub const fn to_bits(&self) -> u32 {
+            self.value
+        }
+        #[inline(always)]
+        pub fn pds(&self, index: usize) -> bool {
+            let val = (self.value >> (0 + (1 * index))) & 1;
+            val != 0x0
+        }
+        #[inline(always)]
+        pub const fn with_pds(self, index: usize, new_value: bool) -> Self {
+            if index >= 32 {
+                panic!();
+            }
+            let shift = 0 + (1 * index);
+            let context_mask: u32 = 1 << shift;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 1) << shift),
+            }
+        }
+    }
+    impl From<PdsrGet> for PdsrValue {
+        #[inline(always)]
+        fn from(get: PdsrGet) -> PdsrValue {
+            PdsrValue::from_bits(get.value)
+        }
+    }
+    impl From<PdsrValue> for PdsrGet {
+        #[inline(always)]
+        fn from(value: PdsrValue) -> PdsrGet {
+            PdsrGet {
+                value: value.to_bits(),
+            }
+        }
+    }
+    impl Pdsr {
+        #[doc = "Write `value` to the register in a single store, without reading it first"]
+        #[inline(always)]
+        pub fn write(&self, value: PdsrValue) {
+            self.store(value.to_bits());
+        }
+    }
+    impl<'a> PdsrUpdate<'a> {
+        #[doc = "Replace every field of the register with those of `value`"]
+        #[inline(always)]
+        pub fn assign<'b>(&'b mut self, value: PdsrValue) -> &'b mut Self {
+            self.value = value.to_bits();
+            self.mask = !0;
+            self
+        }
+    }
+    impl PdsrGet {
+        pub const FIELDS: &'static [::volatile_cell::field::FieldDescriptor] =
+            &[::volatile_cell::field::FieldDescriptor {
+                name: "pds",
+                offset: 0u32,
+                width: 1u32,
+                count: 32u32,
+            }];
+        #[doc = "Iterate over the readable fields that differ from those of `other`"]
+        #[inline]
+        pub fn changed_fields(&self, other: &PdsrGet) -> ::volatile_cell::field::FieldChanges {
+            ::volatile_cell::field::FieldChanges::new(
+                Self::FIELDS,
+                self.value as u64,
+                other.value as u64,
+            )
+        }
+    }
+    pub mod irq {
+        pub mod ier {}
+        #[repr(C)]
+        pub struct Ier {
+            value: ::volatile_cell::VolatileCell<u32>,
+        }
+        impl Ier {
+            #[inline(always)]
+            fn load(&self) -> u32 {
+                let value = self.value.get();
+                value
+            }
+            #[inline(always)]
+            fn store(&self, value: u32) {
+                self.value.set(value);
+            }
+            #[doc = "Create a new updater"]
+            #[inline(always)]
+            pub fn update<'a>(&'a self) -> IerUpdate<'a> {
+                IerUpdate::new(self)
+            }
+            #[doc = "Create an updater whose read-modify-write happens inside the critical section `cs`"]
+            #[inline(always)]
+            pub fn update_in<'a, C: ::volatile_cell::sync::CriticalSection>(
+                &'a self,
+                _cs: &'a C,
+            ) -> IerUpdate<'a> {
+                IerUpdate::new(self)
+            }
+            #[doc = "Create a getter representing the current state of the register"]
+            #[inline(always)]
+            pub fn get(&self) -> IerGet {
+                IerGet::new(self)
+            }
+        }
+        pub struct IerUpdate<'a> {
+            value: u32,
+            mask: u32,
+            write_only: bool,
+            store_on_drop: bool,
+            reg: &'a Ier,
+        }
+        impl<'a> IerUpdate<'a> {
+            #[inline(always)]
+            pub fn new(reg: &'a Ier) -> IerUpdate<'a> {
+                IerUpdate {
+                    value: 0,
+                    mask: 0,
+                    write_only: false,
+                    store_on_drop: true,
+                    reg: reg,
+                }
+            }
+            #[inline(always)]
+            pub fn new_ignoring_state(reg: &'a Ier) -> IerUpdate<'a> {
+                IerUpdate {
+                    value: 0,
+                    mask: 0,
+                    write_only: true,
+                    store_on_drop: true,
+                    reg: reg,
+                }
+            }
+            #[doc = "An update that is never stored, for the caller to store itself"]
+            #[inline(always)]
+            fn detached(reg: &'a Ier) -> IerUpdate<'a> {
+                IerUpdate {
+                    value: 0,
+                    mask: 0,
+                    write_only: false,
+                    store_on_drop: false,
+                    reg: reg,
+                }
+            }
+            const fn clear_mask() -> u32 {
+                0u64 as u32
+            }
+            const fn update_offset(base: usize, size: usize, index: usize) -> usize {
+                base + (size * index)
+            }
+            #[inline(always)]
+            fn merge(&self, current: u32) -> u32 {
+                let v: u32 = current & (!Self::clear_mask()) & (!self.mask);
+                self.value | v
+            }
+            #[inline(always)]
+            pub fn set_bits<'b>(&'b mut self, new_value: u32) -> &'b mut Self {
+                let context_mask: u32 = 4294967295 << 0;
+                self.value =
+                    (self.value & !context_mask) | (((new_value as u32) & 4294967295) << 0);
+                self.mask |= context_mask;
+                self
+            }
+        }
+        impl<'a> Drop for IerUpdate<'a> {
+            #[inline(always)]
+            fn drop(&mut self) {
+                if self.store_on_drop && self.mask != 0 {
+                    let current: u32 = 0;
+                    self.reg.store(self.merge(current));
+                }
+            }
+        }
+        #[derive(Clone, PartialEq, Eq)]
+        pub struct IerGet {
+            value: u32,
+        }
+        impl IerGet {
+            #[doc = "Create a getter reflecting the current value of the register"]
+            #[inline(always)]
+            pub fn new(reg: &Ier) -> IerGet {
+                IerGet { value: reg.load() }
+            }
+        }
+        #[derive(Clone, Copy, PartialEq, Eq, Default)]
+        #[repr(transparent)]
+        pub struct IerValue {
+            value: u32,
+        }
+        impl IerValue {
+            #[doc = "Create a value with every field cleared"]
+            #[inline(always)]
+            pub const fn new() -> IerValue {
+                IerValue { value: 0 }
+            }
+            #[inline(always)]
+            pub const fn from_bits(bits: u32) -> IerValue {
+                IerValue { value: bits }
+            }
+            #[inline(always)]
+            pub const fn to_bits(&self) -> u32 {
+                self.value
+            }
+            #[inline(always)]
+            pub fn bits(&self) -> u32 {
+                self.value
+            }
+            #[inline(always)]
+            pub const fn with_bits(self, new_value: u32) -> Self {
+                let context_mask: u32 = 4294967295 << 0;
+                Self {
+                    value: (self.value & !context_mask) | (((new_value as u32) & 4294967295) << 0),
+                }
+            }
+        }
+        impl From<IerGet> for IerValue {
+            #[inline(always)]
+            fn from(get: IerGet) -> IerValue {
+                IerValue::from_bits(get.value)
+            }
+        }
+        impl From<IerValue> for IerGet {
+            #[inline(always)]
+            fn from(value: IerValue) -> IerGet {
+                IerGet {
+                    value: value.to_bits(),
+                }
+            }
+        }
+        impl Ier {
+            #[doc = "Write `value` to the register in a single store, without reading it first"]
+            #[inline(always)]
+            pub fn write(&self, value: IerValue) {
+                self.store(value.to_bits());
+            }
+        }
+        impl<'a> IerUpdate<'a> {
+            #[doc = "Replace every field of the register with those of `value`"]
+            #[inline(always)]
+            pub fn assign<'b>(&'b mut self, value: IerValue) -> &'b mut Self {
+                self.value = value.to_bits();
+                self.mask = !0;
+                self
+            }
+        }
+        impl IerGet {
+            pub const FIELDS: &'static [::volatile_cell::field::FieldDescriptor] = &[];
+            #[doc = "Iterate over the readable fields that differ from those of `other`"]
+            #[inline]
+            pub fn changed_fields(&self, other: &IerGet) -> ::volatile_cell::field::FieldChanges {
+                ::volatile_cell::field::FieldChanges::new(
+                    Self::FIELDS,
+                    self.value as u64,
+                    other.value as u64,
+                )
+            }
+        }
+        pub mod idr {}
+        #[repr(C)]
+        pub struct Idr {
+            value: ::volatile_cell::VolatileCell<u32>,
+        }
+        impl Idr {
+            #[inline(always)]
+            fn load(&self) -> u32 {
+                let value = self.value.get();
+                value
+            }
+            #[inline(always)]
+            fn store(&self, value: u32) {
+                self.value.set(value);
+            }
+            #[doc = "Create a new updater"]
+            #[inline(always)]
+            pub fn update<'a>(&'a self) -> IdrUpdate<'a> {
+                IdrUpdate::new(self)
+            }
+            #[doc = "Create an updater whose read-modify-write happens inside the critical section `cs`"]
+            #[inline(always)]
+            pub fn update_in<'a, C: ::volatile_cell::sync::CriticalSection>(
+                &'a self,
+                _cs: &'a C,
+            ) -> IdrUpdate<'a> {
+                IdrUpdate::new(self)
+            }
+            #[doc = "Create a getter representing the current state of the register"]
+            #[inline(always)]
+            pub fn get(&self) -> IdrGet {
+                IdrGet::new(self)
+            }
+        }
+        pub struct IdrUpdate<'a> {
+            value: u32,
+            mask: u32,
+            write_only: bool,
+            store_on_drop: bool,
+            reg: &'a Idr,
+        }
+        impl<'a> IdrUpdate<'a> {
+            #[inline(always)]
+            pub fn new(reg: &'a Idr) -> IdrUpdate<'a> {
+                IdrUpdate {
+                    value: 0,
+                    mask: 0,
+                    write_only: false,
+                    store_on_drop: true,
+                    reg: reg,
+                }
+            }
+            #[inline(always)]
+            pub fn new_ignoring_state(reg: &'a Idr) -> IdrUpdate<'a> {
+                IdrUpdate {
+                    value: 0,
+                    mask: 0,
+                    write_only: true,
+                    store_on_drop: true,
+                    reg: reg,
+                }
+            }
+            #[doc = "An update that is never stored, for the caller to store itself"]
+            #[inline(always)]
+            fn detached(reg: &'a Idr) -> IdrUpdate<'a> {
+                IdrUpdate {
+                    value: 0,
+                    mask: 0,
+                    write_only: false,
+                    store_on_drop: false,
+                    reg: reg,
+                }
+            }
+            const fn clear_mask() -> u32 {
+                0u64 as u32
+            }
+            const fn update_offset(base: usize, size: usize, index: usize) -> usize {
+                base + (size * index)
+            }
+            #[inline(always)]
+            fn merge(&self, current: u32) -> u32 {
+                let v: u32 = current & (!Self::clear_mask()) & (!self.mask);
+                self.value | v
+            }
+            #[inline(always)]
+            pub fn set_bits<'b>(&'b mut self, new_value: u32) -> &'b mut Self {
+                let context_mask: u32 = 4294967295 << 0;
+                self.value =
+                    (self.value & !context_mask) | (((new_value as u32) & 4294967295) << 0);
+                self.mask |= context_mask;
+                self
+            }
+        }
+        impl<'a> Drop for IdrUpdate<'a> {
+            #[inline(always)]
+            fn drop(&mut self) {
+                if self.store_on_drop && self.mask != 0 {
+                    let current: u32 = 0;
+                    self.reg.store(self.merge(current));
+                }
+            }
+        }
+        #[derive(Clone, PartialEq, Eq)]
+        pub struct IdrGet {
+            value: u32,
+        }
+        impl IdrGet {
+            #[doc = "Create a getter reflecting the current value of the register"]
+            #[inline(always)]
+            pub fn new(reg: &Idr) -> IdrGet {
+                IdrGet { value: reg.load() }
+            }
+        }
+        #[derive(Clone, Copy, PartialEq, Eq, Default)]
+        #[repr(transparent)]
+        pub struct IdrValue {
+            value: u32,
+        }
+        impl IdrValue {
+            #[doc = "Create a value with every field cleared"]
+            #[inline(always)]
+            pub const fn new() -> IdrValue {
+                IdrValue { value: 0 }
+            }
+            #[inline(always)]
+            pub const fn from_bits(bits: u32) -> IdrValue {
+                IdrValue { value: bits }
+            }
+            #[inline(always)]
+            pub const fn to_bits(&self) -> u32 {
+                self.value
+            }
+            #[inline(always)]
+            pub fn bits(&self) -> u32 {
+                self.value
+            }
+            #[inline(always)]
+            pub const fn with_bits(self, new_value: u32) -> Self {
+                let context_mask: u32 = 4294967295 << 0;
+                Self {
+                    value: (self.value & !context_mask) | (((new_value as u32) & 4294967295) << 0),
+                }
+            }
+        }
+        impl From<IdrGet> for IdrValue {
+            #[inline(always)]
+            fn from(get: IdrGet) -> IdrValue {
+                IdrValue::from_bits(get.value)
+            }
+        }
+        impl From<IdrValue> for IdrGet {
+            #[inline(always)]
+            fn from(value: IdrValue) -> IdrGet {
+                IdrGet {
+                    value: value.to_bits(),
+                }
+            }
+        }
+        impl Idr {
+            #[doc = "Write `value` to the register in a single store, without reading it first"]
+            #[inline(always)]
+            pub fn write(&self, value: IdrValue) {
+                self.store(value.to_bits());
+            }
+        }
+        impl<'a> IdrUpdate<'a> {
+            #[doc = "Replace every field of the register with those of `value`"]
+            #[inline(always)]
+            pub fn assign<'b>(&'b mut self, value: IdrValue) -> &'b mut Self {
+                self.value = value.to_bits();
+                self.mask = !0;
+                self
+            }
+        }
+        impl IdrGet {
+            pub const FIELDS: &'static [::volatile_cell::field::FieldDescriptor] = &[];
+            #[doc = "Iterate over the readable fields that differ from those of `other`"]
+            #[inline]
+            pub fn changed_fields(&self, other: &IdrGet) -> ::volatile_cell::field::FieldChanges {
+                ::volatile_cell::field::FieldChanges::new(
+                    Self::FIELDS,
+                    self.value as u64,
+                    other.value as u64,
+                )
+            }
+        }
+        pub mod imr {}
+        #[repr(C)]
+        pub struct Imr {
+            value: ::volatile_cell::VolatileCell<u32>,
+        }
+        impl Imr {
+            #[inline(always)]
+            fn load(&self) -> u32 {
+                let value = self.value.get();
+                value
+            }
+            #[inline(always)]
+            fn store(&self, value: u32) {
+                self.value.set(value);
+            }
+            #[doc = "Create a new updater"]
+            #[inline(always)]
+            pub fn update<'a>(&'a self) -> ImrUpdate<'a> {
+                ImrUpdate::new(self)
+            }
+            #[doc = "Create an updater whose read-modify-write happens inside the critical section `cs`"]
+            #[inline(always)]
+            pub fn update_in<'a, C: ::volatile_cell::sync::CriticalSection>(
+                &'a self,
+                _cs: &'a C,
+            ) -> ImrUpdate<'a> {
+                ImrUpdate::new(self)
+            }
+            #[doc = "Update the register with exclusive loads and stores (LDREX/STREX), retrying `f` until no other access intervened"]
+            #[doc = ""]
+            #[doc = "The exclusive accesses go to the register's memory directly rather than through its cell, so they aren't seen by the replayer or the tracing hook."]
+            #[cfg(target_has_atomic = "32")]
+            #[inline]
+            pub fn update_exclusive<F: FnMut(&mut ImrUpdate)>(&self, mut f: F) {
+                use ::core::sync::atomic::Ordering;
+                let cell =
+                    unsafe { &*(self.value.as_ptr() as *const ::core::sync::atomic::AtomicU32) };
+                let mut current = cell.load(Ordering::Relaxed);
+                loop {
+                    let mut update = ImrUpdate::detached(self);
+                    f(&mut update);
+                    let merged = update.merge(current);
+                    if update.mask == 0 {
+                        return;
+                    }
+                    match cell.compare_exchange_weak(
+                        current,
+                        merged,
+                        Ordering::SeqCst,
+                        Ordering::Relaxed,
+                    ) {
+                        Ok(_) => return,
+                        Err(actual) => current = actual,
+                    }
+                }
+            }
+            #[doc = "Create a getter representing the current state of the register"]
+            #[inline(always)]
+            pub fn get(&self) -> ImrGet {
+                ImrGet::new(self)
+            }
+        }
+        pub struct ImrUpdate<'a> {
+            value: u32,
+            mask: u32,
+            write_only: bool,
+            store_on_drop: bool,
+            reg: &'a Imr,
+        }
+        impl<'a> ImrUpdate<'a> {
+            #[inline(always)]
+            pub fn new(reg: &'a Imr) -> ImrUpdate<'a> {
+                ImrUpdate {
+                    value: 0,
+                    mask: 0,
+                    write_only: false,
+                    store_on_drop: true,
+                    reg: reg,
+                }
+            }
+            #[inline(always)]
+            pub fn new_ignoring_state(reg: &'a Imr) -> ImrUpdate<'a> {
+                ImrUpdate {
+                    value: 0,
+                    mask: 0,
+                    write_only: true,
+                    store_on_drop: true,
+                    reg: reg,
+                }
+            }
+            #[doc = "An update that is never stored, for the caller to store itself"]
+            #[inline(always)]
+            fn detached(reg: &'a Imr) -> ImrUpdate<'a> {
+                ImrUpdate {
+                    value: 0,
+                    mask: 0,
+                    write_only: false,
+                    store_on_drop: false,
+                    reg: reg,
+                }
+            }
+            const fn clear_mask() -> u32 {
+                0u64 as u32
+            }
+            const fn update_offset(base: usize, size: usize, index: usize) -> usize {
+                base + (size * index)
+            }
+            #[inline(always)]
+            fn merge(&self, current: u32) -> u32 {
+                let v: u32 = current & (!Self::clear_mask()) & (!self.mask);
+                self.value | v
+            }
+        }
+        impl<'a> Drop for ImrUpdate<'a> {
+            #[inline(always)]
+            fn drop(&mut self) {
+                if self.store_on_drop && self.mask != 0 {
+                    let current: u32 = if self.write_only { 0 } else { self.reg.load() };
+                    self.reg.store(self.merge(current));
+                }
+            }
+        }
+        #[derive(Clone, PartialEq, Eq)]
+        pub struct ImrGet {
+            value: u32,
+        }
+        impl ImrGet {
+            #[doc = "Create a getter reflecting the current value of the register"]
+            #[inline(always)]
+            pub fn new(reg: &Imr) -> ImrGet {
+                ImrGet { value: reg.load() }
+            }
+            #[inline(always)]
+            pub fn bits(&self) -> u32 {
+                self.value
+            }
+        }
+        #[derive(Clone, Copy, PartialEq, Eq, Default)]
+        #[repr(transparent)]
+        pub struct ImrValue {
+            value: u32,
+        }
+        impl ImrValue {
+            #[doc = "Create a value with every field cleared"]
+            #[inline(always)]
+            pub const fn new() -> ImrValue {
+                ImrValue { value: 0 }
+            }
+            #[inline(always)]
+            pub const fn from_bits(bits: u32) -> ImrValue {
+                ImrValue { value: bits }
+            }
+            #[inline(always)]
+            pub const fn to_bits(&self) -> u32 {
+                self.value
+            }
+            #[inline(always)]
+            pub fn bits(&self) -> u32 {
+                self.value
+            }
+            #[inline(always)]
+            pub const fn with_bits(self, new_value: u32) -> Self {
+                let context_mask: u32 = 4294967295 << 0;
+                Self {
+                    value: (self.value & !context_mask) | (((new_value as u32) & 4294967295) << 0),
+                }
+            }
+        }
+        impl From<ImrGet> for ImrValue {
+            #[inline(always)]
+            fn from(get: ImrGet) -> ImrValue {
+                ImrValue::from_bits(get.value)
+            }
+        }
+        impl From<ImrValue> for ImrGet {
+            #[inline(always)]
+            fn from(value: ImrValue) -> ImrGet {
+                ImrGet {
+                    value: value.to_bits(),
+                }
+            }
+        }
+        impl Imr {
+            #[doc = "Write `value` to the register in a single store, without reading it first"]
+            #[inline(always)]
+            pub fn write(&self, value: ImrValue) {
+                self.store(value.to_bits());
+            }
+        }
+        impl<'a> ImrUpdate<'a> {
+            #[doc = "Replace every field of the register with those of `value`"]
+            #[inline(always)]
+            pub fn assign<'b>(&'b mut self, value: ImrValue) -> &'b mut Self {
+                self.value = value.to_bits();
+                self.mask = !0;
+                self
+            }
+        }
+        impl ImrGet {
+            pub const FIELDS: &'static [::volatile_cell::field::FieldDescriptor] =
+                &[::volatile_cell::field::FieldDescriptor {
+                    name: "bits",
+                    offset: 0u32,
+                    width: 32u32,
+                    count: 1u32,
+                }];
+            #[doc = "Iterate over the readable fields that differ from those of `other`"]
+            #[inline]
+            pub fn changed_fields(&self, other: &ImrGet) -> ::volatile_cell::field::FieldChanges {
+                ::volatile_cell::field::FieldChanges::new(
+                    Self::FIELDS,
+                    self.value as u64,
+                    other.value as u64,
+                )
+            }
+        }
+        pub mod isr {}
+        #[repr(C)]
+        pub struct Isr {
+            value: ::volatile_cell::VolatileCell<u32>,
+        }
+        impl Isr {
+            #[inline(always)]
+            fn load(&self) -> u32 {
+                let value = self.value.get();
+                value
+            }
+            #[inline(always)]
+            fn store(&self, value: u32) {
+                self.value.set(value);
+            }
+            #[doc = "Create a new updater"]
+            #[inline(always)]
+            pub fn update<'a>(&'a self) -> IsrUpdate<'a> {
+                IsrUpdate::new(self)
+            }
+            #[doc = "Create an updater whose read-modify-write happens inside the critical section `cs`"]
+            #[inline(always)]
+            pub fn update_in<'a, C: ::volatile_cell::sync::CriticalSection>(
+                &'a self,
+                _cs: &'a C,
+            ) -> IsrUpdate<'a> {
+                IsrUpdate::new(self)
+            }
+            #[doc = "Update the register with exclusive loads and stores (LDREX/STREX), retrying `f` until no other access intervened"]
+            #[doc = ""]
+            #[doc = "The exclusive accesses go to the register's memory directly rather than through its cell, so they aren't seen by the replayer or the tracing hook."]
+            #[cfg(target_has_atomic = "32")]
+            #[inline]
+            pub fn update_exclusive<F: FnMut(&mut IsrUpdate)>(&self, mut f: F) {
+                use ::core::sync::atomic::Ordering;
+                let cell =
+                    unsafe { &*(self.value.as_ptr() as *const ::core::sync::atomic::AtomicU32) };
+                let mut current = cell.load(Ordering::Relaxed);
+                loop {
+                    let mut update = IsrUpdate::detached(self);
+                    f(&mut update);
+                    let merged = update.merge(current);
+                    if update.mask == 0 {
+                        return;
+                    }
+                    match cell.compare_exchange_weak(
+                        current,
+                        merged,
+                        Ordering::SeqCst,
+                        Ordering::Relaxed,
+                    ) {
+                        Ok(_) => return,
+                        Err(actual) => current = actual,
+                    }
+                }
+            }
+            #[doc = "Create a getter representing the current state of the register"]
+            #[inline(always)]
+            pub fn get(&self) -> IsrGet {
+                IsrGet::new(self)
+            }
+        }
+        pub struct IsrUpdate<'a> {
+            value: u32,
+            mask: u32,
+            write_only: bool,
+            store_on_drop: bool,
+            reg: &'a Isr,
+        }
+        impl<'a> IsrUpdate<'a> {
+            #[inline(always)]
+            pub fn new(reg: &'a Isr) -> IsrUpdate<'a> {
+                IsrUpdate {
+                    value: 0,
+                    mask: 0,
+                    write_only: false,
+                    store_on_drop: true,
+                    reg: reg,
+                }
+            }
+            #[inline(always)]
+            pub fn new_ignoring_state(reg: &'a Isr) -> IsrUpdate<'a> {
+                IsrUpdate {
+                    value: 0,
+                    mask: 0,
+                    write_only: true,
+                    store_on_drop: true,
+                    reg: reg,
+                }
+            }
+            #[doc = "An update that is never stored, for the caller to store itself"]
+            #[inline(always)]
+            fn detached(reg: &'a Isr) -> IsrUpdate<'a> {
+                IsrUpdate {
+                    value: 0,
+                    mask: 0,
+                    write_only: false,
+                    store_on_drop: false,
+                    reg: reg,
+                }
+            }
+            const fn clear_mask() -> u32 {
+                0u64 as u32
+            }
+            const fn update_offset(base: usize, size: usize, index: usize) -> usize {
+                base + (size * index)
+            }
+            #[inline(always)]
+            fn merge(&self, current: u32) -> u32 {
+                let v: u32 = current & (!Self::clear_mask()) & (!self.mask);
+                self.value | v
+            }
+        }
+        impl<'a> Drop for IsrUpdate<'a> {
+            #[inline(always)]
+            fn drop(&mut self) {
+                if self.store_on_drop && self.mask != 0 {
+                    let current: u32 = if self.write_only { 0 } else { self.reg.load() };
+                    self.reg.store(self.merge(current));
+                }
+            }
+        }
+        #[derive(Clone, PartialEq, Eq)]
+        pub struct IsrGet {
+            value: u32,
+        }
+        impl IsrGet {
+            #[doc = "Create a getter reflecting the current value of the register"]
+            #[inline(always)]
+            pub fn new(reg: &Isr) -> IsrGet {
+                IsrGet { value: reg.load() }
+            }
+            #[inline(always)]
+            pub fn bits(&self) -> u32 {
+                self.value
+            }
+        }
+        #[derive(Clone, Copy, PartialEq, Eq, Default)]
+        #[repr(transparent)]
+        pub struct IsrValue {
+            value: u32,
+        }
+        impl IsrValue {
+            #[doc = "Create a value with every field cleared"]
+            #[inline(always)]
+            pub const fn new() -> IsrValue {
+                IsrValue { value: 0 }
+            }
+            #[inline(always)]
+            pub const fn from_bits(bits: u32) -> IsrValue {
+                IsrValue { value: bits }
+            }
+            #[inline(always)]
+            pub const fn to_bits(&self) -> u32 {
+                self.value
+            }
+            #[inline(always)]
+            pub fn bits(&self) -> u32 {
+                self.value
+            }
+            #[inline(always)]
+            pub const fn with_bits(self, new_value: u32) -> Self {
+                let context_mask: u32 = 4294967295 << 0;
+                Self {
+                    value: (self.value & !context_mask) | (((new_value as u32) & 4294967295) << 0),
+                }
+            }
+        }
+        impl From<IsrGet> for IsrValue {
+            #[inline(always)]
+            fn from(get: IsrGet) -> IsrValue {
+                IsrValue::from_bits(get.value)
+            }
+        }
+        impl From<IsrValue> for IsrGet {
+            #[inline(always)]
+            fn from(value: IsrValue) -> IsrGet {
+                IsrGet {
+                    value: value.to_bits(),
+                }
+            }
+        }
+        impl Isr {
+            #[doc = "Write `value` to the register in a single store, without reading it first"]
+            #[inline(always)]
+            pub fn write(&self, value: IsrValue) {
+                self.store(value.to_bits());
+            }
+        }
+        impl<'a> IsrUpdate<'a> {
+            #[doc = "Replace every field of the register with those of `value`"]
+            #[inline(always)]
+            pub fn assign<'b>(&'b mut self, value: IsrValue) -> &'b mut Self {
+                self.value = value.to_bits();
+                self.mask = !0;
+                self
+            }
+        }
+        impl IsrGet {
+            pub const FIELDS: &'static [::volatile_cell::field::FieldDescriptor] =
+                &[::volatile_cell::field::FieldDescriptor {
+                    name: "bits",
+                    offset: 0u32,
+                    width: 32u32,
+                    count: 1u32,
+                }];
+            #[doc = "Iterate over the readable fields that differ from those of `other`"]
+            #[inline]
+            pub fn changed_fields(&self, other: &IsrGet) -> ::volatile_cell::field::FieldChanges {
+                ::volatile_cell::field::FieldChanges::new(
+                    Self::FIELDS,
+                    self.value as u64,
+                    other.value as u64,
+                )
+            }
+        }
+        #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+        pub struct IrqFlags(u32);
+        #[allow(non_upper_case_globals)]
+        impl IrqFlags {
+            #[inline(always)]
+            pub const fn empty() -> IrqFlags {
+                IrqFlags(0)
+            }
+            #[inline(always)]
+            pub const fn all() -> IrqFlags {
+                IrqFlags(4294967295 as u32)
+            }
+            #[doc = "Create a set from raw bits, dropping those outside the field"]
+            #[inline(always)]
+            pub const fn from_bits_truncate(bits: u32) -> IrqFlags {
+                IrqFlags(bits & (4294967295 as u32))
+            }
+            #[inline(always)]
+            pub const fn bits(&self) -> u32 {
+                self.0
+            }
+            #[inline(always)]
+            pub const fn is_empty(&self) -> bool {
+                self.0 == 0
+            }
+            #[inline(always)]
+            pub const fn contains(&self, other: IrqFlags) -> bool {
+                (self.0 & other.0) == other.0
+            }
+            #[inline(always)]
+            pub const fn intersects(&self, other: IrqFlags) -> bool {
+                (self.0 & other.0) != 0
+            }
+        }
+        impl From<u32> for IrqFlags {
+            #[inline(always)]
+            fn from(bits: u32) -> IrqFlags {
+                IrqFlags::from_bits_truncate(bits)
+            }
+        }
+        impl core::ops::BitOr for IrqFlags {
+            type Output = IrqFlags;
+            #[inline(always)]
+            fn bitor(self, other: IrqFlags) -> IrqFlags {
+                IrqFlags(self.0 | other.0)
+            }
+        }
+        impl core::ops::BitOrAssign for IrqFlags {
+            #[inline(always)]
+            fn bitor_assign(&mut self, other: IrqFlags) {
+                self.0 |= other.0;
+            }
+        }
+        impl core::ops::BitAnd for IrqFlags {
+            type Output = IrqFlags;
+            #[inline(always)]
+            fn bitand(self, other: IrqFlags) -> IrqFlags {
+                IrqFlags(self.0 & other.0)
+            }
+        }
+        impl core::ops::BitAndAssign for IrqFlags {
+            #[inline(always)]
+            fn bitand_assign(&mut self, other: IrqFlags) {
+                self.0 &= other.0;
+            }
+        }
+        impl core::ops::Sub for IrqFlags {
+            type Output = IrqFlags;
+            #[inline(always)]
+            fn sub(self, other: IrqFlags) -> IrqFlags {
+                IrqFlags(self.0 & !other.0)
+            }
+        }
+        impl core::ops::Not for IrqFlags {
+            type Output = IrqFlags;
+            #[inline(always)]
+            fn not(self) -> IrqFlags {
+                IrqFlags(!self.0 & (4294967295 as u32))
+            }
+        }
+        impl Irq {
+            #[doc = "Enable the interrupts in `flags`, leaving the others as they are"]
+            #[inline(always)]
+            pub fn enable<F: Into<IrqFlags>>(&self, flags: F) {
+                self.ier.update().set_bits(flags.into().bits());
+            }
+            #[doc = "Disable the interrupts in `flags`, leaving the others as they are"]
+            #[inline(always)]
+            pub fn disable<F: Into<IrqFlags>>(&self, flags: F) {
+                self.idr.update().set_bits(flags.into().bits());
+            }
+            #[doc = "The enabled interrupts"]
+            #[inline(always)]
+            pub fn mask(&self) -> IrqFlags {
+                IrqFlags::from_bits_truncate(self.imr.get().bits())
+            }
+            #[doc = "The pending interrupts. On many devices reading them acknowledges them"]
+            #[inline(always)]
+            pub fn status(&self) -> IrqFlags {
+                IrqFlags::from_bits_truncate(self.isr.get().bits())
+            }
+        }
+        #[repr(C)]
+        pub struct Irq {
+            pub ier: Ier,
+            pub idr: Idr,
+            pub imr: Imr,
+            pub isr: Isr,
+        }
+        #[doc = "Saved state of every readable register without side effects"]
+        #[derive(Clone, Copy)]
+        pub struct Snapshot {
+            pub imr: ImrValue,
+        }
+        #[doc = "Read the current state of the peripheral"]
+        #[allow(unused_variables)]
+        pub fn capture(p: &Irq) -> Snapshot {
+            Snapshot {
+                imr: ImrValue::from(p.imr.get()),
+            }
+        }
+        #[doc = "Write a previously captured state back to the peripheral"]
+        #[allow(unused_variables)]
+        pub fn restore(p: &Irq, snapshot: &Snapshot) {}
+    }
+    pub mod mder {}
+    #[repr(C)]
+    pub struct Mder {
+        value: ::volatile_cell::VolatileCell<u32>,
+    }
+    impl Mder {
+        #[inline(always)]
+        fn load(&self) -> u32 {
+            let value = self.value.get();
+            value
+        }
+        #[inline(always)]
+        fn store(&self, value: u32) {
+            self.value.set(value);
+        }
+        #[doc = "Create a new updater"]
+        #[inline(always)]
+        pub fn update<'a>(&'a self) -> MderUpdate<'a> {
+            MderUpdate::new(self)
+        }
+        #[doc = "Create an updater whose read-modify-write happens inside the critical section `cs`"]
+        #[inline(always)]
+        pub fn update_in<'a, C: ::volatile_cell::sync::CriticalSection>(
+            &'a self,
+            _cs: &'a C,
+        ) -> MderUpdate<'a> {
+            MderUpdate::new(self)
+        }
+        #[doc = "Create a getter representing the current state of the register"]
+        #[inline(always)]
+        pub fn get(&self) -> MderGet {
+            MderGet::new(self)
+        }
+    }
+    pub struct MderUpdate<'a> {
+        value: u32,
+        mask: u32,
+        write_only: bool,
+        store_on_drop: bool,
+        reg: &'a Mder,
+    }
+    impl<'a> MderUpdate<'a> {
+        #[inline(always)]
+        pub fn new(reg: &'a Mder) -> MderUpdate<'a> {
+            MderUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[inline(always)]
+        pub fn new_ignoring_state(reg: &'a Mder) -> MderUpdate<'a> {
+            MderUpdate {
+                value: 0,
+                mask: 0,
+                write_only: true,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[doc = "An update that is never stored, for the caller to store itself"]
+        #[inline(always)]
+        fn detached(reg: &'a Mder) -> MderUpdate<'a> {
+            MderUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: false,
+                reg: reg,
+            }
+        }
+        const fn clear_mask() -> u32 {
+            0u64 as u32
+        }
+        const fn update_offset(base: usize, size: usize, index: usize) -> usize {
+            base + (size * index)
+        }
+        #[inline(always)]
+        fn merge(&self, current: u32) -> u32 {
+            let v: u32 = current & (!Self::clear_mask()) & (!self.mask);
+            self.value | v
+        }
+        #[inline(always)]
+        pub fn set_mde<'b>(&'b mut self, index: usize, new_value: bool) -> &'b mut Self {
+            if index > 32 {
+                panic!();
+            }
+            let update_offset = Self::update_offset(0, 1, index);
+            let context_mask: u32 = 1 << update_offset;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 1) << update_offset);
+            self.mask |= context_mask;
+            self
+        }
+    }
+    impl<'a> Drop for MderUpdate<'a> {
+        #[inline(always)]
+        fn drop(&mut self) {
+            if self.store_on_drop && self.mask != 0 {
+                let current: u32 = 0;
+                self.reg.store(self.merge(current));
+            }
+        }
+    }
+    #[derive(Clone, PartialEq, Eq)]
+    pub struct MderGet {
+        value: u32,
+    }
+    impl MderGet {
+        #[doc = "Create a getter reflecting the current value of the register"]
+        #[inline(always)]
+        pub fn new(reg: &Mder) -> MderGet {
+            MderGet { value: reg.load() }
+        }
+    }
+    #[derive(Clone, Copy, PartialEq, Eq, Default)]
+    #[repr(transparent)]
+    pub struct MderValue {
+        value: u32,
+    }
+    impl MderValue {
+        #[doc = "Create a value with every field cleared"]
+        #[inline(always)]
+        pub const fn new() -> MderValue {
+            MderValue { value: 0 }
+        }
+        #[inline(always)]
+        pub const fn from_bits(bits: u32) -> MderValue {
+            MderValue { value: bits }
+        }
+        #[inline(always)]
+        pub const fn to_bits(&self) -> u32 {
+            self.value
+        }
+        #[inline(always)]
+        pub fn mde(&self, index: usize) -> bool {
+            let val = (self.value >> (0 + (1 * index))) & 1;
+            val != 0x0
+        }
+        #[inline(always)]
+        pub const fn with_mde(self, index: usize, new_value: bool) -> Self {
+            if index >= 32 {
+                panic!();
+            }
+            let shift = 0 + (1 * index);
+            let context_mask: u32 = 1 << shift;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 1) << shift),
+            }
+        }
+    }
+    impl From<MderGet> for MderValue {
+        #[inline(always)]
+        fn from(get: MderGet) -> MderValue {
+            MderValue::from_bits(get.value)
+        }
+    }
+    impl From<MderValue> for MderGet {
+        #[inline(always)]
+        fn from(value: MderValue) -> MderGet {
+            MderGet {
+                value: value.to_bits(),
+            }
+        }
+    }
+    impl Mder {
+        #[doc = "Write `value` to the register in a single store, without reading it first"]
+        #[inline(always)]
+        pub fn write(&self, value: MderValue) {
+            self.store(value.to_bits());
+        }
+    }
+    impl<'a> MderUpdate<'a> {
+        #[doc = "Replace every field of the register with those of `value`"]
+        #[inline(always)]
+        pub fn assign<'b>(&'b mut self, value: MderValue) -> &'b mut Self {
+            self.value = value.to_bits();
+            self.mask = !0;
+            self
+        }
+    }
+    impl MderGet {
+        pub const FIELDS: &'static [::volatile_cell::field::FieldDescriptor] = &[];
+        #[doc = "Iterate over the readable fields that differ from those of `other`"]
+        #[inline]
+        pub fn changed_fields(&self, other: &MderGet) -> ::volatile_cell::field::FieldChanges {
+            ::volatile_cell::field::FieldChanges::new(
+                Self::FIELDS,
+                self.value as u64,
+                other.value as u64,
+            )
+        }
+    }
+    pub mod mddr {}
+    #[repr(C)]
+    pub struct Mddr {
+        value: ::volatile_cell::VolatileCell<u32>,
+    }
+    impl Mddr {
+        #[inline(always)]
+        fn load(&self) -> u32 {
+            let value = self.value.get();
+            value
+        }
+        #[inline(always)]
+        fn store(&self, value: u32) {
+            self.value.set(value);
+        }
+        #[doc = "Create a new updater"]
+        #[inline(always)]
+        pub fn update<'a>(&'a self) -> MddrUpdate<'a> {
+            MddrUpdate::new(self)
+        }
+        #[doc = "Create an updater whose read-modify-write happens inside the critical section `cs`"]
+        #[inline(always)]
+        pub fn update_in<'a, C: ::volatile_cell::sync::CriticalSection>(
+            &'a self,
+            _cs: &'a C,
+        ) -> MddrUpdate<'a> {
+            MddrUpdate::new(self)
+        }
+        #[doc = "Create a getter representing the current state of the register"]
+        #[inline(always)]
+        pub fn get(&self) -> MddrGet {
+            MddrGet::new(self)
+        }
+    }
+    pub struct MddrUpdate<'a> {
+        value: u32,
+        mask: u32,
+        write_only: bool,
+        store_on_drop: bool,
+        reg: &'a Mddr,
+    }
+    impl<'a> MddrUpdate<'a> {
+        #[inline(always)]
+        pub fn new(reg: &'a Mddr) -> MddrUpdate<'a> {
+            MddrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[inline(always)]
+        pub fn new_ignoring_state(reg: &'a Mddr) -> MddrUpdate<'a> {
+            MddrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: true,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[doc = "An update that is never stored, for the caller to store itself"]
+        #[inline(always)]
+        fn detached(reg: &'a Mddr) -> MddrUpdate<'a> {
+            MddrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: false,
+                reg: reg,
+            }
+        }
+        const fn clear_mask() -> u32 {
+            0u64 as u32
+        }
+        const fn update_offset(base: usize, size: usize, index: usize) -> usize {
+            base + (size * index)
+        }
+        #[inline(always)]
+        fn merge(&self, current: u32) -> u32 {
+            let v: u32 = current & (!Self::clear_mask()) & (!self.mask);
+            self.value | v
+        }
+        #[inline(always)]
+        pub fn set_mdd<'b>(&'b mut self, index: usize, new_value: bool) -> &'b mut Self {
+            if index > 32 {
+                panic!();
+            }
+            let update_offset = Self::update_offset(0, 1, index);
+            let context_mask: u32 = 1 << update_offset;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 1) << update_offset);
+            self.mask |= context_mask;
+            self
+        }
+    }
+    impl<'a> Drop for MddrUpdate<'a> {
+        #[inline(always)]
+        fn drop(&mut self) {
+            if self.store_on_drop && self.mask != 0 {
+                let current: u32 = 0;
+                self.reg.store(self.merge(current));
+            }
+        }
+    }
+    #[derive(Clone, PartialEq, Eq)]
+    pub struct MddrGet {
+        value: u32,
+    }
+    impl MddrGet {
+        #[doc = "Create a getter reflecting the current value of the register"]
+        #[inline(always)]
+        pub fn new(reg: &Mddr) -> MddrGet {
+            MddrGet { value: reg.load() }
+        }
+    }
+    #[derive(Clone, Copy, PartialEq, Eq, Default)]
+    #[repr(transparent)]
+    pub struct MddrValue {
+        value: u32,
+    }
+    impl MddrValue {
+        #[doc = "Create a value with every field cleared"]
+        #[inline(always)]
+        pub const fn new() -> MddrValue {
+            MddrValue { value: 0 }
+        }
+        #[inline(always)]
+        pub const fn from_bits(bits: u32) -> MddrValue {
+            MddrValue { value: bits }
+        }
+        #[inline(always)]
+        pub const fn to_bits(&self) -> u32 {
+            self.value
+        }
+        #[inline(always)]
+        pub fn mdd(&self, index: usize) -> bool {
+            let val = (self.value >> (0 + (1 * index))) & 1;
+            val != 0x0
+        }
+        #[inline(always)]
+        pub const fn with_mdd(self, index: usize, new_value: bool) -> Self {
+            if index >= 32 {
+                panic!();
+            }
+            let shift = 0 + (1 * index);
+            let context_mask: u32 = 1 << shift;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 1) << shift),
+            }
+        }
+    }
+    impl From<MddrGet> for MddrValue {
+        #[inline(always)]
+        fn from(get: MddrGet) -> MddrValue {
+            MddrValue::from_bits(get.value)
+        }
+    }
+    impl From<MddrValue> for MddrGet {
+        #[inline(always)]
+        fn from(value: MddrValue) -> MddrGet {
+            MddrGet {
+                value: value.to_bits(),
+            }
+        }
+    }
+    impl Mddr {
+        #[doc = "Write `value` to the register in a single store, without reading it first"]
+        #[inline(always)]
+        pub fn write(&self, value: MddrValue) {
+            self.store(value.to_bits());
+        }
+    }
+    impl<'a> MddrUpdate<'a> {
+        #[doc = "Replace every field of the register with those of `value`"]
+        #[inline(always)]
+        pub fn assign<'b>(&'b mut self, value: MddrValue) -> &'b mut Self {
+            self.value = value.to_bits();
+            self.mask = !0;
+            self
+        }
+    }
+    impl MddrGet {
+        pub const FIELDS: &'static [::volatile_cell::field::FieldDescriptor] = &[];
+        #[doc = "Iterate over the readable fields that differ from those of `other`"]
+        #[inline]
+        pub fn changed_fields(&self, other: &MddrGet) -> ::volatile_cell::field::FieldChanges {
+            ::volatile_cell::field::FieldChanges::new(
+                Self::FIELDS,
+                self.value as u64,
+                other.value as u64,
+            )
+        }
+    }
+    pub mod mdsr {}
+    #[repr(C)]
+    pub struct Mdsr {
+        value: ::volatile_cell::VolatileCell<u32>,
+    }
+    impl Mdsr {
+        #[inline(always)]
+        fn load(&self) -> u32 {
+            let value = self.value.get();
+            value
+        }
+        #[inline(always)]
+        fn store(&self, value: u32) {
+            self.value.set(value);
+        }
+        #[doc = "Create a new updater"]
+        #[inline(always)]
+        pub fn update<'a>(&'a self) -> MdsrUpdate<'a> {
+            MdsrUpdate::new(self)
+        }
+        #[doc = "Create an updater whose read-modify-write happens inside the critical section `cs`"]
+        #[inline(always)]
+        pub fn update_in<'a, C: ::volatile_cell::sync::CriticalSection>(
+            &'a self,
+            _cs: &'a C,
+        ) -> MdsrUpdate<'a> {
+            MdsrUpdate::new(self)
+        }
+        #[doc = "Update the register with exclusive loads and stores (LDREX/STREX), retrying `f` until no other access intervened"]
+        #[doc = ""]
+        #[doc = "The exclusive accesses go to the register's memory directly rather than through its cell, so they aren't seen by the replayer or the tracing hook."]
+        #[cfg(target_has_atomic = "32")]
+        #[inline]
+        pub fn update_exclusive<F: FnMut(&mut MdsrUpdate)>(&self, mut f: F) {
+            use ::core::sync::atomic::Ordering;
+            let cell = unsafe { &*(self.value.as_ptr() as *const ::core::sync::atomic::AtomicU32) };
+            let mut current = cell.load(Ordering::Relaxed);
+            loop {
+                let mut update = MdsrUpdate::detached(self);
+                f(&mut update);
+                let merged = update.merge(current);
+                if update.mask == 0 {
+                    return;
+                }
+                match cell.compare_exchange_weak(
+                    current,
+                    merged,
+                    Ordering::SeqCst,
+                    Ordering::Relaxed,
+                ) {
+                    Ok(_) => return,
+                    Err(actual) => current = actual,
+                }
+            }
+        }
+        #[doc = "Create a getter representing the current state of the register"]
+        #[inline(always)]
+        pub fn get(&self) -> MdsrGet {
+            MdsrGet::new(self)
+        }
+        #[doc = "Spin until the bit is set"]
+        #[inline]
+        pub fn wait_mds(&self, index: usize) {
+            while !self.get().mds(index) {}
+        }
+        #[doc = "Poll until the bit is set, at most `max_polls` times"]
+        #[inline]
+        pub fn wait_mds_timeout(
+            &self,
+            index: usize,
+            max_polls: u32,
+        ) -> Result<(), ::volatile_cell::wait::Timeout> {
+            ::volatile_cell::wait::poll_until(|| self.get().mds(index), max_polls)
+        }
+        #[doc = "Future resolving once the bit is set"]
+        #[inline]
+        pub fn wait_mds_async<'a>(
+            &'a self,
+            index: usize,
+        ) -> ::volatile_cell::wait::WaitUntil<'a, impl FnMut() -> bool + Unpin + 'a> {
+            ::volatile_cell::wait::WaitUntil::new(move || self.get().mds(index))
+        }
+    }
+    pub struct MdsrUpdate<'a> {
+        value: u32,
+        mask: u32,
+        write_only: bool,
+        store_on_drop: bool,
+        reg: &'a Mdsr,
+    }
+    impl<'a> MdsrUpdate<'a> {
+        #[inline(always)]
+        pub fn new(reg: &'a Mdsr) -> MdsrUpdate<'a> {
+            MdsrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[inline(always)]
+        pub fn new_ignoring_state(reg: &'a Mdsr) -> MdsrUpdate<'a> {
+            MdsrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: true,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[doc = "An update that is never stored, for the caller to store itself"]
+        #[inline(always)]
+        fn detached(reg: &'a Mdsr) -> MdsrUpdate<'a> {
+            MdsrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: false,
+                reg: reg,
+            }
+        }
+        const fn clear_mask() -> u32 {
+            0u64 as u32
+        }
+        const fn update_offset(base: usize, size: usize, index: usize) -> usize {
+            base + (size * index)
+        }
+        #[inline(always)]
+        fn merge(&self, current: u32) -> u32 {
+            let v: u32 = current & (!Self::clear_mask()) & (!self.mask);
+            self.value | v
+        }
+    }
+    impl<'a> Drop for MdsrUpdate<'a> {
+        #[inline(always)]
+        fn drop(&mut self) {
+            if self.store_on_drop && self.mask != 0 {
+                let current: u32 = if self.write_only { 0 } else { self.reg.load() };
+                self.reg.store(self.merge(current));
+            }
+        }
+    }
+    #[derive(Clone, PartialEq, Eq)]
+    pub struct MdsrGet {
+        value: u32,
+    }
+    impl MdsrGet {
+        #[doc = "Create a getter reflecting the current value of the register"]
+        #[inline(always)]
+        pub fn new(reg: &Mdsr) -> MdsrGet {
+            MdsrGet { value: reg.load() }
+        }
+        #[inline(always)]
+        pub fn mds(&self, index: usize) -> bool {
+            let val = (self.value >> (0 + (1 * index))) & 1;
+            val != 0x0
+        }
+    }
+    #[derive(Clone, Copy, PartialEq, Eq, Default)]
+    #[repr(transparent)]
+    pub struct MdsrValue {
+        value: u32,
+    }
+    impl MdsrValue {
+        #[doc = "Create a value with every field cleared"]
+        #[inline(always)]
+        pub const fn new() -> MdsrValue {
+            MdsrValue { value: 0 }
+        }
+        #[inline(always)]
+        pub const fn from_bits(bits: u32) -> MdsrValue {
+            MdsrValue { value: bits }
+        }
+        #[inline(always)]
+        pub const fn to_bits(&self) -> u32 {
+            self.value
+        }
+        #[inline(always)]
+        pub fn mds(&self, index: usize) -> bool {
+            let val = (self.value >> (0 + (1 * index))) & 1;
+            val != 0x0
+        }
+        #[inline(always)]
+        pub const fn with_mds(self, index: usize, new_value: bool) -> Self {
+            if index >= 32 {
+                panic!();
+            }
+            let shift = 0 + (1 * index);
+            let context_mask: u32 = 1 << shift;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 1) << shift),
+            }
+        }
+    }
+    impl From<MdsrGet> for MdsrValue {
+        #[inline(always)]
+        fn from(get: MdsrGet) -> MdsrValue {
+            MdsrValue::from_bits(get.value)
+        }
+    }
+    impl From<MdsrValue> for MdsrGet {
+        #[inline(always)]
+        fn from(value: MdsrValue) -> MdsrGet {
+            MdsrGet {
+                value: value.to_bits(),
+            }
+        }
+    }
+    impl Mdsr {
+        #[doc = "Write `value` to the register in a single store, without reading it first"]
+        #[inline(always)]
+        pub fn write(&self, value: MdsrValue) {
+            self.store(value.to_bits());
+        }
+    }
+    impl<'a> MdsrUpdate<'a> {
+        #[doc = "Replace every field of the register with those of `value`"]
+        #[inline(always)]
+        pub fn assign<'b>(&'b mut self, value: MdsrValue) -> &'b mut Self {
+            self.value = value.to_bits();
+            self.mask = !0;
+            self
+        }
+    }
+    impl MdsrGet {
+        pub const FIELDS: &'static [::volatile_cell::field::FieldDescriptor] =
+            &[::volatile_cell::field::FieldDescriptor {
+                name: "mds",
+                offset: 0u32,
+                width: 1u32,
+                count: 32u32,
+            }];
+        #[doc = "Iterate over the readable fields that differ from those of `other`"]
+        #[inline]
+        pub fn changed_fields(&self, other: &MdsrGet) -> ::volatile_cell::field::FieldChanges {
+            ::volatile_cell::field::FieldChanges::new(
+                Self::FIELDS,
+                self.value as u64,
+                other.value as u64,
+            )
+        }
+    }
+    pub mod pudr {}
+    #[repr(C)]
+    pub struct Pudr {
+        value: ::volatile_cell::VolatileCell<u32>,
+    }
+    impl Pudr {
+        #[inline(always)]
+        fn load(&self) -> u32 {
+            let value = self.value.get();
+            value
+        }
+        #[inline(always)]
+        fn store(&self, value: u32) {
+            self.value.set(value);
+        }
+        #[doc = "Create a new updater"]
+        #[inline(always)]
+        pub fn update<'a>(&'a self) -> PudrUpdate<'a> {
+            PudrUpdate::new(self)
+        }
+        #[doc = "Create an updater whose read-modify-write happens inside the critical section `cs`"]
+        #[inline(always)]
+        pub fn update_in<'a, C: ::volatile_cell::sync::CriticalSection>(
+            &'a self,
+            _cs: &'a C,
+        ) -> PudrUpdate<'a> {
+            PudrUpdate::new(self)
+        }
+        #[doc = "Create a getter representing the current state of the register"]
+        #[inline(always)]
+        pub fn get(&self) -> PudrGet {
+            PudrGet::new(self)
+        }
+    }
+    pub struct PudrUpdate<'a> {
+        value: u32,
+        mask: u32,
+        write_only: bool,
+        store_on_drop: bool,
+        reg: &'a Pudr,
+    }
+    impl<'a> PudrUpdate<'a> {
+        #[inline(always)]
+        pub fn new(reg: &'a Pudr) -> PudrUpdate<'a> {
+            PudrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[inline(always)]
+        pub fn new_ignoring_state(reg: &'a Pudr) -> PudrUpdate<'a> {
+            PudrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: true,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[doc = "An update that is never stored, for the caller to store itself"]
+        #[inline(always)]
+        fn detached(reg: &'a Pudr) -> PudrUpdate<'a> {
+            PudrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: false,
+                reg: reg,
+            }
+        }
+        const fn clear_mask() -> u32 {
+            0u64 as u32
+        }
+        const fn update_offset(base: usize, size: usize, index: usize) -> usize {
+            base + (size * index)
+        }
+        #[inline(always)]
+        fn merge(&self, current: u32) -> u32 {
+            let v: u32 = current & (!Self::clear_mask()) & (!self.mask);
+            self.value | v
+        }
+        #[inline(always)]
+        pub fn set_pud<'b>(&'b mut self, index: usize, new_value: bool) -> &'b mut Self {
+            if index > 32 {
+                panic!();
+            }
+            let update_offset = Self::update_offset(0, 1, index);
+            let context_mask: u32 = 1 << update_offset;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 1) << update_offset);
+            self.mask |= context_mask;
+            self
+        }
+    }
+    impl<'a> Drop for PudrUpdate<'a> {
+        #[inline(always)]
+        fn drop(&mut self) {
+            if self.store_on_drop && self.mask != 0 {
+                let current: u32 = 0;
+                self.reg.store(self.merge(current));
+            }
+        }
+    }
+    #[derive(Clone, PartialEq, Eq)]
+    pub struct PudrGet {
+        value: u32,
+    }
+    impl PudrGet {
+        #[doc = "Create a getter reflecting the current value of the register"]
+        #[inline(always)]
+        pub fn new(reg: &Pudr) -> PudrGet {
+            PudrGet { value: reg.load() }
+        }
+    }
+    #[derive(Clone, Copy, PartialEq, Eq, Default)]
+    #[repr(transparent)]
+    pub struct PudrValue {
+        value: u32,
+    }
+    impl PudrValue {
+        #[doc = "Create a value with every field cleared"]
+        #[inline(always)]
+        pub const fn new() -> PudrValue {
+            PudrValue { value: 0 }
+        }
+        #[inline(always)]
+        pub const fn from_bits(bits: u32) -> PudrValue {
+            PudrValue { value: bits }
+        }
+        #[inline(always)]
+        pub const fn to_bits(&self) -> u32 {
+            self.value
+        }
+        #[inline(always)]
+        pub fn pud(&self, index: usize) -> bool {
+            let val = (self.value >> (0 + (1 * index))) & 1;
+            val != 0x0
+        }
+        #[inline(always)]
+        pub const fn with_pud(self, index: usize, new_value: bool) -> Self {
+            if index >= 32 {
+                panic!();
+            }
+            let shift = 0 + (1 * index);
+            let context_mask: u32 = 1 << shift;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 1) << shift),
+            }
+        }
+    }
+    impl From<PudrGet> for PudrValue {
+        #[inline(always)]
+        fn from(get: PudrGet) -> PudrValue {
+            PudrValue::from_bits(get.value)
+        }
+    }
+    impl From<PudrValue> for PudrGet {
+        #[inline(always)]
+        fn from(value: PudrValue) -> PudrGet {
+            PudrGet {
+                value: value.to_bits(),
+            }
+        }
+    }
+    impl Pudr {
+        #[doc = "Write `value` to the register in a single store, without reading it first"]
+        #[inline(always)]
+        pub fn write(&self, value: PudrValue) {
+            self.store(value.to_bits());
+        }
+    }
+    impl<'a> PudrUpdate<'a> {
+        #[doc = "Replace every field of the register with those of `value`"]
+        #[inline(always)]
+        pub fn assign<'b>(&'b mut self, value: PudrValue) -> &'b mut Self {
+            self.value = value.to_bits();
+            self.mask = !0;
+            self
+        }
+    }
+    impl PudrGet {
+        pub const FIELDS: &'static [::volatile_cell::field::FieldDescriptor] = &[];
+        #[doc = "Iterate over the readable fields that differ from those of `other`"]
+        #[inline]
+        pub fn changed_fields(&self, other: &PudrGet) -> ::volatile_cell::field::FieldChanges {
+            ::volatile_cell::field::FieldChanges::new(
+                Self::FIELDS,
+                self.value as u64,
+                other.value as u64,
+            )
+        }
+    }
+    pub mod puer {}
+    #[repr(C)]
+    pub struct Puer {
+        value: ::volatile_cell::VolatileCell<u32>,
+    }
+    impl Puer {
+        #[inline(always)]
+        fn load(&self) -> u32 {
+            let value = self.value.get();
+            value
+        }
+        #[inline(always)]
+        fn store(&self, value: u32) {
+            self.value.set(value);
+        }
+        #[doc = "Create a new updater"]
+        #[inline(always)]
+        pub fn update<'a>(&'a self) -> PuerUpdate<'a> {
+            PuerUpdate::new(self)
+        }
+        #[doc = "Create an updater whose read-modify-write happens inside the critical section `cs`"]
+        #[inline(always)]
+        pub fn update_in<'a, C: ::volatile_cell::sync::CriticalSection>(
+            &'a self,
+            _cs: &'a C,
+        ) -> PuerUpdate<'a> {
+            PuerUpdate::new(self)
+        }
+        #[doc = "Create a getter representing the current state of the register"]
+        #[inline(always)]
+        pub fn get(&self) -> PuerGet {
+            PuerGet::new(self)
+        }
+    }
+    pub struct PuerUpdate<'a> {
+        value: u32,
+        mask: u32,
+        write_only: bool,
+        store_on_drop: bool,
+        reg: &'a Puer,
+    }
+    impl<'a> PuerUpdate<'a> {
+        #[inline(always)]
+        pub fn new(reg: &'a Puer) -> PuerUpdate<'a> {
+            PuerUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[inline(always)]
+        pub fn new_ignoring_state(reg: &'a Puer) -> PuerUpdate<'a> {
+            PuerUpdate {
+                value: 0,
+                mask: 0,
+                write_only: true,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[doc = "An update that is never stored, for the caller to store itself"]
+        #[inline(always)]
+        fn detached(reg: &'a Puer) -> PuerUpdate<'a> {
+            PuerUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: false,
+                reg: reg,
+            }
+        }
+        const fn clear_mask() -> u32 {
+            0u64 as u32
+        }
+        const fn update_offset(base: usize, size: usize, index: usize) -> usize {
+            base + (size * index)
+        }
+        #[inline(always)]
+        fn merge(&self, current: u32) -> u32 {
+            let v: u32 = current & (!Self::clear_mask()) & (!self.mask);
+            self.value | v
+        }
+        #[inline(always)]
+        pub fn set_pue<'b>(&'b mut self, index: usize, new_value: bool) -> &'b mut Self {
+            if index > 32 {
+                panic!();
+            }
+            let update_offset = Self::update_offset(0, 1, index);
+            let context_mask: u32 = 1 << update_offset;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 1) << update_offset);
+            self.mask |= context_mask;
+            self
+        }
+    }
+    impl<'a> Drop for PuerUpdate<'a> {
+        #[inline(always)]
+        fn drop(&mut self) {
+            if self.store_on_drop && self.mask != 0 {
+                let current: u32 = 0;
+                self.reg.store(self.merge(current));
+            }
+        }
+    }
+    #[derive(Clone, PartialEq, Eq)]
+    pub struct PuerGet {
+        value: u32,
+    }
+    impl PuerGet {
+        #[doc = "Create a getter reflecting the current value of the register"]
+        #[inline(always)]
+        pub fn new(reg: &Puer) -> PuerGet {
+            PuerGet { value: reg.load() }
+        }
+    }
+    #[derive(Clone, Copy, PartialEq, Eq, Default)]
+    #[repr(transparent)]
+    pub struct PuerValue {
+        value: u32,
+    }
+    impl PuerValue {
+        #[doc = "Create a value with every field cleared"]
+        #[inline(always)]
+        pub const fn new() -> PuerValue {
+            PuerValue { value: 0 }
+        }
+        #[inline(always)]
+        pub const fn from_bits(bits: u32) -> PuerValue {
+            PuerValue { value: bits }
+        }
+        #[inline(always)]
+        pub const fn to_bits(&self) -> u32 {
+            self.value
+        }
+        #[inline(always)]
+        pub fn pue(&self, index: usize) -> bool {
+            let val = (self.value >> (0 + (1 * index))) & 1;
+            val != 0x0
+        }
+        #[inline(always)]
+        pub const fn with_pue(self, index: usize, new_value: bool) -> Self {
+            if index >= 32 {
+                panic!();
+            }
+            let shift = 0 + (1 * index);
+            let context_mask: u32 = 1 << shift;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 1) << shift),
+            }
+        }
+    }
+    impl From<PuerGet> for PuerValue {
+        #[inline(always)]
+        fn from(get: PuerGet) -> PuerValue {
+            PuerValue::from_bits(get.value)
+        }
+    }
+    impl From<PuerValue> for PuerGet {
+        #[inline(always)]
+        fn from(value: PuerValue) -> PuerGet {
+            PuerGet {
+                value: value.to_bits(),
+            }
+        }
+    }
+    impl Puer {
+        #[doc = "Write `value` to the register in a single store, without reading it first"]
+        #[inline(always)]
+        pub fn write(&self, value: PuerValue) {
+            self.store(value.to_bits());
+        }
+    }
+    impl<'a> PuerUpdate<'a> {
+        #[doc = "Replace every field of the register with those of `value`"]
+        #[inline(always)]
+        pub fn assign<'b>(&'b mut self, value: PuerValue) -> &'b mut Self {
+            self.value = value.to_bits();
+            self.mask = !0;
+            self
+        }
+    }
+    impl PuerGet {
+        pub const FIELDS: &'static [::volatile_cell::field::FieldDescriptor] = &[];
+        #[doc = "Iterate over the readable fields that differ from those of `other`"]
+        #[inline]
+        pub fn changed_fields(&self, other: &PuerGet) -> ::volatile_cell::field::FieldChanges {
+            ::volatile_cell::field::FieldChanges::new(
+                Self::FIELDS,
+                self.value as u64,
+                other.value as u64,
+            )
+        }
+    }
+    pub mod pusr {}
+    #[repr(C)]
+    pub struct Pusr {
+        value: ::volatile_cell::VolatileCell<u32>,
+    }
+    impl Pusr {
+        #[inline(always)]
+        fn load(&self) -> u32 {
+            let value = self.value.get();
+            value
+        }
+        #[inline(always)]
+        fn store(&self, value: u32) {
+            self.value.set(value);
+        }
+        #[doc = "Create a new updater"]
+        #[inline(always)]
+        pub fn update<'a>(&'a self) -> PusrUpdate<'a> {
+            PusrUpdate::new(self)
+        }
+        #[doc = "Create an updater whose read-modify-write happens inside the critical section `cs`"]
+        #[inline(always)]
+        pub fn update_in<'a, C: ::volatile_cell::sync::CriticalSection>(
+            &'a self,
+            _cs: &'a C,
+        ) -> PusrUpdate<'a> {
+            PusrUpdate::new(self)
+        }
+        #[doc = "Update the register with exclusive loads and stores (LDREX/STREX), retrying `f` until no other access intervened"]
+        #[doc = ""]
+        #[doc = "The exclusive accesses go to the register's memory directly rather than through its cell, so they aren't seen by the replayer or the tracing hook."]
+        #[cfg(target_has_atomic = "32")]
+        #[inline]
+        pub fn update_exclusive<F: FnMut(&mut PusrUpdate)>(&self, mut f: F) {
+            use ::core::sync::atomic::Ordering;
+            let cell = unsafe { &*(self.value.as_ptr() as *const ::core::sync::atomic::AtomicU32) };
+            let mut current = cell.load(Ordering::Relaxed);
+            loop {
+                let mut update = PusrUpdate::detached(self);
+                f(&mut update);
+                let merged = update.merge(current);
+                if update.mask == 0 {
+                    return;
+                }
+                match cell.compare_exchange_weak(
+                    current,
+                    merged,
+                    Ordering::SeqCst,
+                    Ordering::Relaxed,
+                ) {
+                    Ok(_) => return,
+                    Err(actual) => current = actual,
+                }
+            }
+        }
+        #[doc = "Create a getter representing the current state of the register"]
+        #[inline(always)]
+        pub fn get(&self) -> PusrGet {
+            PusrGet::new(self)
+        }
+        #[doc = "Spin until the bit is set"]
+        #[inline]
+        pub fn wait_pus(&self, index: usize) {
+            while !self.get().pus(index) {}
+        }
+        #[doc = "Poll until the bit is set, at most `max_polls` times"]
+        #[inline]
+        pub fn wait_pus_timeout(
+            &self,
+            index: usize,
+            max_polls: u32,
+        ) -> Result<(), ::volatile_cell::wait::Timeout> {
+            ::volatile_cell::wait::poll_until(|| self.get().pus(index), max_polls)
+        }
+        #[doc = "Future resolving once the bit is set"]
+        #[inline]
+        pub fn wait_pus_async<'a>(
+            &'a self,
+            index: usize,
+        ) -> ::volatile_cell::wait::WaitUntil<'a, impl FnMut() -> bool + Unpin + 'a> {
+            ::volatile_cell::wait::WaitUntil::new(move || self.get().pus(index))
+        }
+    }
+    pub struct PusrUpdate<'a> {
+        value: u32,
+        mask: u32,
+        write_only: bool,
+        store_on_drop: bool,
+        reg: &'a Pusr,
+    }
+    impl<'a> PusrUpdate<'a> {
+        #[inline(always)]
+        pub fn new(reg: &'a Pusr) -> PusrUpdate<'a> {
+            PusrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[inline(always)]
+        pub fn new_ignoring_state(reg: &'a Pusr) -> PusrUpdate<'a> {
+            PusrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: true,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[doc = "An update that is never stored, for the caller to store itself"]
+        #[inline(always)]
+        fn detached(reg: &'a Pusr) -> PusrUpdate<'a> {
+            PusrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: false,
+                reg: reg,
+            }
+        }
+        const fn clear_mask() -> u32 {
+            0u64 as u32
+        }
+        const fn update_offset(base: usize, size: usize, index: usize) -> usize {
+            base + (size * index)
+        }
+        #[inline(always)]
+        fn merge(&self, current: u32) -> u32 {
+            let v: u32 = current & (!Self::clear_mask()) & (!self.mask);
+            self.value | v
+        }
+    }
+    impl<'a> Drop for PusrUpdate<'a> {
+        #[inline(always)]
+        fn drop(&mut self) {
+            if self.store_on_drop && self.mask != 0 {
+                let current: u32 = if self.write_only { 0 } else { self.reg.load() };
+                self.reg.store(self.merge(current));
+            }
+        }
+    }
+    #[derive(Clone, PartialEq, Eq)]
+    pub struct PusrGet {
+        value: u32,
+    }
+    impl PusrGet {
+        #[doc = "Create a getter reflecting the current value of the register"]
+        #[inline(always)]
+        pub fn new(reg: &Pusr) -> PusrGet {
+            PusrGet { value: reg.load() }
+        }
+        #[inline(always)]
+        pub fn pus(&self, index: usize) -> bool {
+            let val = (self.value >> (0 + (1 * index))) & 1;
+            val != 0x0
+        }
+    }
+    #[derive(Clone, Copy, PartialEq, Eq, Default)]
+    #[repr(transparent)]
+    pub struct PusrValue {
+        value: u32,
+    }
+    impl PusrValue {
+        #[doc = "Create a value with every field cleared"]
+        #[inline(always)]
+        pub const fn new() -> PusrValue {
+            PusrValue { value: 0 }
+        }
+        #[inline(always)]
+        pub const fn from_bits(bits: u32) -> PusrValue {
+            PusrValue { value: bits }
+        }
+        #[inline(always)]
+        pub const fn to_bits(&self) -> u32 {
+            self.value
+        }
+        #[inline(always)]
+        pub fn pus(&self, index: usize) -> bool {
+            let val = (self.value >> (0 + (1 * index))) & 1;
+            val != 0x0
+        }
+        #[inline(always)]
+        pub const fn with_pus(self, index: usize, new_value: bool) -> Self {
+            if index >= 32 {
+                panic!();
+            }
+            let shift = 0 + (1 * index);
+            let context_mask: u32 = 1 << shift;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 1) << shift),
+            }
+        }
+    }
+    impl From<PusrGet> for PusrValue {
+        #[inline(always)]
+        fn from(get: PusrGet) -> PusrValue {
+            PusrValue::from_bits(get.value)
+        }
+    }
+    impl From<PusrValue> for PusrGet {
+        #[inline(always)]
+        fn from(value: PusrValue) -> PusrGet {
+            PusrGet {
+                value: value.to_bits(),
+            }
+        }
+    }
+    impl Pusr {
+        #[doc = "Write `value` to the register in a single store, without reading it first"]
+        #[inline(always)]
+        pub fn write(&self, value: PusrValue) {
+            self.store(value.to_bits());
+        }
+    }
+    impl<'a> PusrUpdate<'a> {
+        #[doc = "Replace every field of the register with those of `value`"]
+        #[inline(always)]
+        pub fn assign<'b>(&'b mut self, value: PusrValue) -> &'b mut Self {
+            self.value = value.to_bits();
+            self.mask = !0;
+            self
+        }
+    }
+    impl PusrGet {
+        pub const FIELDS: &'static [::volatile_cell::field::FieldDescriptor] =
+            &[::volatile_cell::field::FieldDescriptor {
+                name: "pus",
+                offset: 0u32,
+                width: 1u32,
+                count: 32u32,
+            }];
+        #[doc = "Iterate over the readable fields that differ from those of `other`"]
+        #[inline]
+        pub fn changed_fields(&self, other: &PusrGet) -> ::volatile_cell::field::FieldChanges {
+            ::volatile_cell::field::FieldChanges::new(
+                Self::FIELDS,
+                self.value as u64,
+                other.value as u64,
+            )
+        }
+    }
+    pub mod absr {
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        #[repr(u32)]
+        pub enum Abs {
+            PeripheralA = 0,
+            PeripheralB = 1,
+        }
+        impl core::convert::TryFrom<u32> for Abs {
+            type Error = u32;
+            #[inline(always)]
+            fn try_from(primitive: u32) -> Result<Self, Self::Error> {
+                match primitive {
+                    0 => Ok(Abs::PeripheralA),
+                    1 => Ok(Abs::PeripheralB),
+                    v => Err(v),
+                }
+            }
+        }
+    }
+    #[repr(C)]
+    pub struct Absr {
+        value: ::volatile_cell::VolatileCell<u32>,
+    }
+    impl Absr {
+        #[inline(always)]
+        fn load(&self) -> u32 {
+            let value = self.value.get();
+            value
+        }
+        #[inline(always)]
+        fn store(&self, value: u32) {
+            self.value.set(value);
+        }
+        #[doc = "Create a new updater"]
+        #[inline(always)]
+        pub fn update<'a>(&'a self) -> AbsrUpdate<'a> {
+            AbsrUpdate::new(self)
+        }
+        #[doc = "Create an updater whose read-modify-write happens inside the critical section `cs`"]
+        #[inline(always)]
+        pub fn update_in<'a, C: ::volatile_cell::sync::CriticalSection>(
+            &'a self,
+            _cs: &'a C,
+        ) -> AbsrUpdate<'a> {
+            AbsrUpdate::new(self)
+        }
+        #[doc = "Update the register with exclusive loads and stores (LDREX/STREX), retrying `f` until no other access intervened"]
+        #[doc = ""]
+        #[doc = "The exclusive accesses go to the register's memory directly rather than through its cell, so they aren't seen by the replayer or the tracing hook."]
+        #[cfg(target_has_atomic = "32")]
+        #[inline]
+        pub fn update_exclusive<F: FnMut(&mut AbsrUpdate)>(&self, mut f: F) {
+            use ::core::sync::atomic::Ordering;
+            let cell = unsafe { &*(self.value.as_ptr() as *const ::core::sync::atomic::AtomicU32) };
+            let mut current = cell.load(Ordering::Relaxed);
+            loop {
+                let mut update = AbsrUpdate::detached(self);
+                f(&mut update);
+                let merged = update.merge(current);
+                if update.mask == 0 {
+                    return;
+                }
+                match cell.compare_exchange_weak(
+                    current,
+                    merged,
+                    Ordering::SeqCst,
+                    Ordering::Relaxed,
+                ) {
+                    Ok(_) => return,
+                    Err(actual) => current = actual,
+                }
+            }
+        }
+        #[doc = "Create a getter representing the current state of the register"]
+        #[inline(always)]
+        pub fn get(&self) -> AbsrGet {
+            AbsrGet::new(self)
+        }
+    }
+    pub struct AbsrUpdate<'a> {
+        value: u32,
+        mask: u32,
+        write_only: bool,
+        store_on_drop: bool,
+        reg: &'a Absr,
+    }
+    impl<'a> AbsrUpdate<'a> {
+        #[inline(always)]
+        pub fn new(reg: &'a Absr) -> AbsrUpdate<'a> {
+            AbsrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[inline(always)]
+        pub fn new_ignoring_state(reg: &'a Absr) -> AbsrUpdate<'a> {
+            AbsrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: true,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[doc = "An update that is never stored, for the caller to store itself"]
+        #[inline(always)]
+        fn detached(reg: &'a Absr) -> AbsrUpdate<'a> {
+            AbsrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: false,
+                reg: reg,
+            }
+        }
+        const fn clear_mask() -> u32 {
+            0u64 as u32
+        }
+        const fn update_offset(base: usize, size: usize, index: usize) -> usize {
+            base + (size * index)
+        }
+        #[inline(always)]
+        fn merge(&self, current: u32) -> u32 {
+            let v: u32 = current & (!Self::clear_mask()) & (!self.mask);
+            self.value | v
+        }
+        #[inline(always)]
+        pub fn set_abs<'b>(&'b mut self, index: usize, new_value: absr::Abs) -> &'b mut Self {
+            if index > 32 {
+                panic!();
+            }
+            let update_offset = Self::update_offset(0, 1, index);
+            let context_mask: u32 = 1 << update_offset;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 1) << update_offset);
+            self.mask |= context_mask;
+            self
+        }
+    }
+    impl<'a> Drop for AbsrUpdate<'a> {
+        #[inline(always)]
+        fn drop(&mut self) {
+            if self.store_on_drop && self.mask != 0 {
+                let current: u32 = if self.write_only { 0 } else { self.reg.load() };
+                self.reg.store(self.merge(current));
+            }
+        }
+    }
+    #[derive(Clone, PartialEq, Eq)]
+    pub struct AbsrGet {
+        value: u32,
+    }
+    impl AbsrGet {
+        #[doc = "Create a getter reflecting the current value of the register"]
+        #[inline(always)]
+        pub fn new(reg: &Absr) -> AbsrGet {
+            AbsrGet { value: reg.load() }
+        }
+        #[inline(always)]
+        pub fn abs(&self, index: usize) -> absr::Abs {
+            unsafe { core::mem::transmute::<_, absr::Abs>((self.value >> (0 + (1 * index))) & 1) }
+        }
+    }
+    #[derive(Clone, Copy, PartialEq, Eq, Default)]
+    #[repr(transparent)]
+    pub struct AbsrValue {
+        value: u32,
+    }
+    impl AbsrValue {
+        #[doc = "Create a value with every field cleared"]
+        #[inline(always)]
+        pub const fn new() -> AbsrValue {
+            AbsrValue { value: 0 }
+        }
+        #[inline(always)]
+        pub const fn from_bits(bits: u32) -> AbsrValue {
+            AbsrValue { value: bits }
+        }
+        #[inline(always)]
+        pub const fn to_bits(&self) -> u32 {
+            self.value
+        }
+        #[inline(always)]
+        pub fn abs(&self, index: usize) -> absr::Abs {
+            unsafe { core::mem::transmute::<_, absr::Abs>((self.value >> (0 + (1 * index))) & 1) }
+        }
+        #[inline(always)]
+        pub const fn with_abs(self, index: usize, new_value: absr::Abs) -> Self {
+            if index >= 32 {
+                panic!();
+            }
+            let shift = 0 + (1 * index);
+            let context_mask: u32 = 1 << shift;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 1) << shift),
+            }
+        }
+    }
+    impl From<AbsrGet> for AbsrValue {
+        #[inline(always)]
+        fn from(get: AbsrGet) -> AbsrValue {
+            AbsrValue::from_bits(get.value)
+        }
+    }
+    impl From<AbsrValue> for AbsrGet {
+        #[inline(always)]
+        fn from(value: AbsrValue) -> AbsrGet {
+            AbsrGet {
+                value: value.to_bits(),
+            }
+        }
+    }
+    impl Absr {
+        #[doc = "Write `value` to the register in a single store, without reading it first"]
+        #[inline(always)]
+        pub fn write(&self, value: AbsrValue) {
+            self.store(value.to_bits());
+        }
+    }
+    impl<'a> AbsrUpdate<'a> {
+        #[doc = "Replace every field of the register with those of `value`"]
+        #[inline(always)]
+        pub fn assign<'b>(&'b mut self, value: AbsrValue) -> &'b mut Self {
+            self.value = value.to_bits();
+            self.mask = !0;
+            self
+        }
+    }
+    impl AbsrGet {
+        pub const FIELDS: &'static [::volatile_cell::field::FieldDescriptor] =
+            &[::volatile_cell::field::FieldDescriptor {
+                name: "abs",
+                offset: 0u32,
+                width: 1u32,
+                count: 32u32,
+            }];
+        #[doc = "Iterate over the readable fields that differ from those of `other`"]
+        #[inline]
+        pub fn changed_fields(&self, other: &AbsrGet) -> ::volatile_cell::field::FieldChanges {
+            ::volatile_cell::field::FieldChanges::new(
+                Self::FIELDS,
+                self.value as u64,
+                other.value as u64,
+            )
+        }
+    }
+    #[repr(C)]
+    pub struct Pio {
+        pub per: Per,
+        pub pdr: Pdr,
+        pub psr: Psr,
+        _padding0: [u8; 4],
+        pub oer: Oer,
+        pub odr: Odr,
+        pub osr: Osr,
+        _padding1: [u8; 4],
+        pub ifer: Ifer,
+        pub ifdr: Ifdr,
+        pub ifsr: Ifsr,
+        _padding2: [u8; 4],
+        pub sodr: Sodr,
+        pub codr: Codr,
+        pub odsr: Odsr,
+        pub pdsr: Pdsr,
+        pub irq: irq::Irq,
+        pub mder: Mder,
+        pub mddr: Mddr,
+        pub mdsr: Mdsr,
+        _padding3: [u8; 4],
+        pub pudr: Pudr,
+        pub puer: Puer,
+        pub pusr: Pusr,
+        _padding4: [u8; 4],
+        pub absr: Absr,
+    }
+    #[doc = "Saved state of every readable register without side effects"]
+    #[derive(Clone, Copy)]
+    pub struct Snapshot {
+        pub psr: PsrValue,
+        pub osr: OsrValue,
+        pub ifsr: IfsrValue,
+        pub odsr: OdsrValue,
+        pub pdsr: PdsrValue,
+        pub irq: irq::Snapshot,
+        pub mdsr: MdsrValue,
+        pub pusr: PusrValue,
+        pub absr: AbsrValue,
+    }
+    #[doc = "Read the current state of the peripheral"]
+    #[allow(unused_variables)]
+    pub fn capture(p: &Pio) -> Snapshot {
+        Snapshot {
+            psr: PsrValue::from(p.psr.get()),
+            osr: OsrValue::from(p.osr.get()),
+            ifsr: IfsrValue::from(p.ifsr.get()),
+            odsr: OdsrValue::from(p.odsr.get()),
+            pdsr: PdsrValue::from(p.pdsr.get()),
+            irq: irq::capture(&p.irq),
+            mdsr: MdsrValue::from(p.mdsr.get()),
+            pusr: PusrValue::from(p.pusr.get()),
+            absr: AbsrValue::from(p.absr.get()),
+        }
+    }
+    #[doc = "Write a previously captured state back to the peripheral"]
+    #[allow(unused_variables)]
+    pub fn restore(p: &Pio, snapshot: &Snapshot) {
+        OdsrUpdate::new_ignoring_state(&p.odsr).assign(snapshot.odsr);
+        irq::restore(&p.irq, &snapshot.irq);
+        AbsrUpdate::new_ignoring_state(&p.absr).assign(snapshot.absr);
+    }
+    #[doc = "Owned handle granting exclusive access to the peripheral"]
+    pub struct PioPeripheral {
+        regs: &'static mut Pio,
+    }
+    impl PioPeripheral {
+        #[doc = "Create a token for the peripheral registers at `regs`."]
+        #[doc = ""]
+        #[doc = "Unsafe because the caller must ensure it's the only token for `regs`."]
+        #[inline(always)]
+        pub const unsafe fn new(regs: &'static mut Pio) -> PioPeripheral {
+            PioPeripheral { regs: regs }
+        }
+    }
+    impl ::core::ops::Deref for PioPeripheral {
+        type Target = Pio;
+        #[inline(always)]
+        fn deref(&self) -> &Pio {
+            self.regs
+        }
+    }
+    impl ::core::ops::DerefMut for PioPeripheral {
+        #[inline(always)]
+        fn deref_mut(&mut self) -> &mut Pio {
+            self.regs
+        }
+    }
+}
@@ -0,0 +1,715 @@
+pub mod scb {
+    pub mod cpuid {}
+    #[repr(C)]
+    pub struct Cpuid {
+        value: ::volatile_cell::VolatileCell<u32>,
+    }
+    impl Cpuid {
+        #[inline(always)]
+        fn load(&self) -> u32 {
+            let value = self.value.get();
+            value
+        }
+        #[inline(always)]
+        fn store(&self, value: u32) {
+            self.value.set(value);
+        }
+        #[doc = "Create a new updater"]
+        #[inline(always)]
+        pub fn update<'a>(&'a self) -> CpuidUpdate<'a> {
+            CpuidUpdate::new(self)
+        }
+        #[doc = "Create an updater whose read-modify-write happens inside the critical section `cs`"]
+        #[inline(always)]
+        pub fn update_in<'a, C: ::volatile_cell::sync::CriticalSection>(
+            &'a self,
+            _cs: &'a C,
+        ) -> CpuidUpdate<'a> {
+            CpuidUpdate::new(self)
+        }
+        #[doc = "Update the register with exclusive loads and stores (LDREX/STREX), retrying `f` until no other access intervened"]
+        #[doc = ""]
+        #[doc = "The exclusive accesses go to the register's memory directly rather than through its cell, so they aren't seen by the replayer or the tracing hook."]
+        #[cfg(target_has_atomic = "32")]
+        #[inline]
+        pub fn update_exclusive<F: FnMut(&mut CpuidUpdate)>(&self, mut f: F) {
+            use ::core::sync::atomic::Ordering;
+            let cell = unsafe { &*(self.value.as_ptr() as *const ::core::sync::atomic::AtomicU32) };
+            let mut current = cell.load(Ordering::Relaxed);
+            loop {
+                let mut update = CpuidUpdate::detached(self);
+                f(&mut update);
+                let merged = update.merge(current);
+                if update.mask == 0 {
+                    return;
+                }
+                match cell.compare_exchange_weak(
+                    current,
+                    merged,
+                    Ordering::SeqCst,
+                    Ordering::Relaxed,
+                ) {
+                    Ok(_) => return,
+                    Err(actual) => current = actual,
+                }
+            }
+        }
+        #[doc = "Create a getter representing the current state of the register"]
+        #[inline(always)]
+        pub fn get(&self) -> CpuidGet {
+            CpuidGet::new(self)
+        }
+    }
+    pub struct CpuidUpdate<'a> {
+        value: u32,
+        mask: u32,
+        write_only: bool,
+        store_on_drop: bool,
+        reg: &'a Cpuid,
+    }
+    impl<'a> CpuidUpdate<'a> {
+        #[inline(always)]
+        pub fn new(reg: &'a Cpuid) -> CpuidUpdate<'a> {
+            CpuidUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[inline(always)]
+        pub fn new_ignoring_state(reg: &'a Cpuid) -> CpuidUpdate<'a> {
+            CpuidUpdate {
+                value: 0,
+                mask: 0,
+                write_only: true,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[doc = "An update that is never stored, for the caller to store itself"]
+        #[inline(always)]
+        fn detached(reg: &'a Cpuid) -> CpuidUpdate<'a> {
+            CpuidUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: false,
+                reg: reg,
+            }
+        }
+        const fn clear_mask() -> u32 {
+            0u64 as u32
+        }
+        const fn update_offset(base: usize, size: usize, index: usize) -> usize {
+            base + (size * index)
+        }
+        #[inline(always)]
+        fn merge(&self, current: u32) -> u32 {
+            let v: u32 = current & (!Self::clear_mask()) & (!self.mask);
+            self.value | v
+        }
+    }
+    impl<'a> Drop for CpuidUpdate<'a> {
+        #[inline(always)]
+        fn drop(&mut self) {
+            if self.store_on_drop && self.mask != 0 {
+                let current: u32 = if self.write_only { 0 } else { self.reg.load() };
+                self.reg.store(self.merge(current));
+            }
+        }
+    }
+    #[derive(Clone, PartialEq, Eq)]
+    pub struct CpuidGet {
+        value: u32,
+    }
+    impl CpuidGet {
+        #[doc = "Create a getter reflecting the current value of the register"]
+        #[inline(always)]
+        pub fn new(reg: &Cpuid) -> CpuidGet {
+            CpuidGet { value: reg.load() }
+        }
+        #[inline(always)]
+        pub fn value(&self) -> u32 {
+            self.value
+        }
+    }
+    #[derive(Clone, Copy, PartialEq, Eq, Default)]
+    #[repr(transparent)]
+    pub struct CpuidValue {
+        value: u32,
+    }
+    impl CpuidValue {
+        #[doc = "Create a value with every field cleared"]
+        #[inline(always)]
+        pub const fn new() -> CpuidValue {
+            CpuidValue { value: 0 }
+        }
+        #[inline(always)]
+        pub const fn from_bits(bits: u32) -> CpuidValue {
+            CpuidValue { value: bits }
+        }
+        #[inline(always)]
+        pub const fn to_bits(&self) -> u32 {
+            self.value
+        }
+        #[inline(always)]
+        pub fn value(&self) -> u32 {
+            self.value
+        }
+        #[inline(always)]
+        pub const fn with_value(self, new_value: u32) -> Self {
+            let context_mask: u32 = 4294967295 << 0;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 4294967295) << 0),
+            }
+        }
+    }
+    impl From<CpuidGet> for CpuidValue {
+        #[inline(always)]
+        fn from(get: CpuidGet) -> CpuidValue {
+            CpuidValue::from_bits(get.value)
+        }
+    }
+    impl From<CpuidValue> for CpuidGet {
+        #[inline(always)]
+        fn from(value: CpuidValue) -> CpuidGet {
+            CpuidGet {
+                value: value.to_bits(),
+            }
+        }
+    }
+    impl Cpuid {
+        #[doc = "Write `value` to the register in a single store, without reading it first"]
+        #[inline(always)]
+        pub fn write(&self, value: CpuidValue) {
+            self.store(value.to_bits());
+        }
+    }
+    impl<'a> CpuidUpdate<'a> {
+        #[doc = "Replace every field of the register with those of `value`"]
+        #[inline(always)]
+        pub fn assign<'b>(&'b mut self, value: CpuidValue) -> &'b mut Self {
+            self.value = value.to_bits();
+            self.mask = !0;
+            self
+        }
+    }
+    impl CpuidGet {
+        pub const FIELDS: &'static [::volatile_cell::field::FieldDescriptor] =
+            &[::volatile_cell::field::FieldDescriptor {
+                name: "value",
+                offset: 0u32,
+                width: 32u32,
+                count: 1u32,
+            }];
+        #[doc = "Iterate over the readable fields that differ from those of `other`"]
+        #[inline]
+        pub fn changed_fields(&self, other: &CpuidGet) -> ::volatile_cell::field::FieldChanges {
+            ::volatile_cell::field::FieldChanges::new(
+                Self::FIELDS,
+                self.value as u64,
+                other.value as u64,
+            )
+        }
+    }
+    pub mod icsr {}
+    #[repr(C)]
+    pub struct Icsr {
+        value: ::volatile_cell::VolatileCell<u32>,
+    }
+    impl Icsr {
+        #[inline(always)]
+        fn load(&self) -> u32 {
+            let value = self.value.get();
+            value
+        }
+        #[inline(always)]
+        fn store(&self, value: u32) {
+            self.value.set(value);
+        }
+        #[doc = "Create a new updater"]
+        #[inline(always)]
+        pub fn update<'a>(&'a self) -> IcsrUpdate<'a> {
+            IcsrUpdate::new(self)
+        }
+        #[doc = "Create an updater whose read-modify-write happens inside the critical section `cs`"]
+        #[inline(always)]
+        pub fn update_in<'a, C: ::volatile_cell::sync::CriticalSection>(
+            &'a self,
+            _cs: &'a C,
+        ) -> IcsrUpdate<'a> {
+            IcsrUpdate::new(self)
+        }
+        #[doc = "Update the register with exclusive loads and stores (LDREX/STREX), retrying `f` until no other access intervened"]
+        #[doc = ""]
+        #[doc = "The exclusive accesses go to the register's memory directly rather than through its cell, so they aren't seen by the replayer or the tracing hook."]
+        #[cfg(target_has_atomic = "32")]
+        #[inline]
+        pub fn update_exclusive<F: FnMut(&mut IcsrUpdate)>(&self, mut f: F) {
+            use ::core::sync::atomic::Ordering;
+            let cell = unsafe { &*(self.value.as_ptr() as *const ::core::sync::atomic::AtomicU32) };
+            let mut current = cell.load(Ordering::Relaxed);
+            loop {
+                let mut update = IcsrUpdate::detached(self);
+                f(&mut update);
+                let merged = update.merge(current);
+                if update.mask == 0 {
+                    return;
+                }
+                match cell.compare_exchange_weak(
+                    current,
+                    merged,
+                    Ordering::SeqCst,
+                    Ordering::Relaxed,
+                ) {
+                    Ok(_) => return,
+                    Err(actual) => current = actual,
+                }
+            }
+        }
+        #[doc = "Create a getter representing the current state of the register"]
+        #[inline(always)]
+        pub fn get(&self) -> IcsrGet {
+            IcsrGet::new(self)
+        }
+    }
+    pub struct IcsrUpdate<'a> {
+        value: u32,
+        mask: u32,
+        write_only: bool,
+        store_on_drop: bool,
+        reg: &'a Icsr,
+    }
+    impl<'a> IcsrUpdate<'a> {
+        #[inline(always)]
+        pub fn new(reg: &'a Icsr) -> IcsrUpdate<'a> {
+            IcsrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[inline(always)]
+        pub fn new_ignoring_state(reg: &'a Icsr) -> IcsrUpdate<'a> {
+            IcsrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: true,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[doc = "An update that is never stored, for the caller to store itself"]
+        #[inline(always)]
+        fn detached(reg: &'a Icsr) -> IcsrUpdate<'a> {
+            IcsrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: false,
+                reg: reg,
+            }
+        }
+        const fn clear_mask() -> u32 {
+            0u64 as u32
+        }
+        const fn update_offset(base: usize, size: usize, index: usize) -> usize {
+            base + (size * index)
+        }
+        #[inline(always)]
+        fn merge(&self, current: u32) -> u32 {
+            let v: u32 = current & (!Self::clear_mask()) & (!self.mask);
+            self.value | v
+        }
+        #[inline(always)]
+        pub fn set_value<'b>(&'b mut self, new_value: u32) -> &'b mut Self {
+            let context_mask: u32 = 4294967295 << 0;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 4294967295) << 0);
+            self.mask |= context_mask;
+            self
+        }
+    }
+    impl<'a> Drop for IcsrUpdate<'a> {
+        #[inline(always)]
+        fn drop(&mut self) {
+            if self.store_on_drop && self.mask != 0 {
+                let current: u32 = if self.write_only { 0 } else { self.reg.load() };
+                self.reg.store(self.merge(current));
+            }
+        }
+    }
+    #[derive(Clone, PartialEq, Eq)]
+    pub struct IcsrGet {
+        value: u32,
+    }
+    impl IcsrGet {
+        #[doc = "Create a getter reflecting the current value of the register"]
+        #[inline(always)]
+        pub fn new(reg: &Icsr) -> IcsrGet {
+            IcsrGet { value: reg.load() }
+        }
+        #[inline(always)]
+        pub fn value(&self) -> u32 {
+            self.value
+        }
+    }
+    #[derive(Clone, Copy, PartialEq, Eq, Default)]
+    #[repr(transparent)]
+    pub struct IcsrValue {
+        value: u32,
+    }
+    impl IcsrValue {
+        #[doc = "Create a value with every field cleared"]
+        #[inline(always)]
+        pub const fn new() -> IcsrValue {
+            IcsrValue { value: 0 }
+        }
+        #[inline(always)]
+        pub const fn from_bits(bits: u32) -> IcsrValue {
+            IcsrValue { value: bits }
+        }
+        #[inline(always)]
+        pub const fn to_bits(&self) -> u32 {
+            self.value
+        }
+        #[inline(always)]
+        pub fn value(&self) -> u32 {
+            self.value
+        }
+        #[inline(always)]
+        pub const fn with_value(self, new_value: u32) -> Self {
+            let context_mask: u32 = 4294967295 << 0;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 4294967295) << 0),
+            }
+        }
+    }
+    impl From<IcsrGet> for IcsrValue {
+        #[inline(always)]
+        fn from(get: IcsrGet) -> IcsrValue {
+            IcsrValue::from_bits(get.value)
+        }
+    }
+    impl From<IcsrValue> for IcsrGet {
+        #[inline(always)]
+        fn from(value: IcsrValue) -> IcsrGet {
+            IcsrGet {
+                value: value.to_bits(),
+            }
+        }
+    }
+    impl Icsr {
+        #[doc = "Write `value` to the register in a single store, without reading it first"]
+        #[inline(always)]
+        pub fn write(&self, value: IcsrValue) {
+            self.store(value.to_bits());
+        }
+    }
+    impl<'a> IcsrUpdate<'a> {
+        #[doc = "Replace every field of the register with those of `value`"]
+        #[inline(always)]
+        pub fn assign<'b>(&'b mut self, value: IcsrValue) -> &'b mut Self {
+            self.value = value.to_bits();
+            self.mask = !0;
+            self
+        }
+    }
+    impl IcsrGet {
+        pub const FIELDS: &'static [::volatile_cell::field::FieldDescriptor] =
+            &[::volatile_cell::field::FieldDescriptor {
+                name: "value",
+                offset: 0u32,
+                width: 32u32,
+                count: 1u32,
+            }];
+        #[doc = "Iterate over the readable fields that differ from those of `other`"]
+        #[inline]
+        pub fn changed_fields(&self, other: &IcsrGet) -> ::volatile_cell::field::FieldChanges {
+            ::volatile_cell::field::FieldChanges::new(
+                Self::FIELDS,
+                self.value as u64,
+                other.value as u64,
+            )
+        }
+    }
+    pub mod vtor {}
+    #[repr(C)]
+    pub struct Vtor {
+        value: ::volatile_cell::VolatileCell<u32>,
+    }
+    impl Vtor {
+        #[inline(always)]
+        fn load(&self) -> u32 {
+            let value = self.value.get();
+            value
+        }
+        #[inline(always)]
+        fn store(&self, value: u32) {
+            self.value.set(value);
+        }
+        #[doc = "Create a new updater"]
+        #[inline(always)]
+        pub fn update<'a>(&'a self) -> VtorUpdate<'a> {
+            VtorUpdate::new(self)
+        }
+        #[doc = "Create an updater whose read-modify-write happens inside the critical section `cs`"]
+        #[inline(always)]
+        pub fn update_in<'a, C: ::volatile_cell::sync::CriticalSection>(
+            &'a self,
+            _cs: &'a C,
+        ) -> VtorUpdate<'a> {
+            VtorUpdate::new(self)
+        }
+        #[doc = "Update the register with exclusive loads and stores (LDREX/STREX), retrying `f` until no other access intervened"]
+        #[doc = ""]
+        #[doc = "The exclusive accesses go to the register's memory directly rather than through its cell, so they aren't seen by the replayer or the tracing hook."]
+        #[cfg(target_has_atomic = "32")]
+        #[inline]
+        pub fn update_exclusive<F: FnMut(&mut VtorUpdate)>(&self, mut f: F) {
+            use ::core::sync::atomic::Ordering;
+            let cell = unsafe { &*(self.value.as_ptr() as *const ::core::sync::atomic::AtomicU32) };
+            let mut current = cell.load(Ordering::Relaxed);
+            loop {
+                let mut update = VtorUpdate::detached(self);
+                f(&mut update);
+                let merged = update.merge(current);
+                if update.mask == 0 {
+                    return;
+                }
+                match cell.compare_exchange_weak(
+                    current,
+                    merged,
+                    Ordering::SeqCst,
+                    Ordering::Relaxed,
+                ) {
+                    Ok(_) => return,
+                    Err(actual) => current = actual,
+                }
+            }
+        }
+        #[doc = "Create a getter representing the current state of the register"]
+        #[inline(always)]
+        pub fn get(&self) -> VtorGet {
+            VtorGet::new(self)
+        }
+    }
+    pub struct VtorUpdate<'a> {
+        value: u32,
+        mask: u32,
+        write_only: bool,
+        store_on_drop: bool,
+        reg: &'a Vtor,
+    }
+    impl<'a> VtorUpdate<'a> {
+        #[inline(always)]
+        pub fn new(reg: &'a Vtor) -> VtorUpdate<'a> {
+            VtorUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[inline(always)]
+        pub fn new_ignoring_state(reg: &'a Vtor) -> VtorUpdate<'a> {
+            VtorUpdate {
+                value: 0,
+                mask: 0,
+                write_only: true,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[doc = "An update that is never stored, for the caller to store itself"]
+        #[inline(always)]
+        fn detached(reg: &'a Vtor) -> VtorUpdate<'a> {
+            VtorUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: false,
+                reg: reg,
+            }
+        }
+        const fn clear_mask() -> u32 {
+            0u64 as u32
+        }
+        const fn update_offset(base: usize, size: usize, index: usize) -> usize {
+            base + (size * index)
+        }
+        #[inline(always)]
+        fn merge(&self, current: u32) -> u32 {
+            let v: u32 = current & (!Self::clear_mask()) & (!self.mask);
+            self.value | v
+        }
+        #[inline(always)]
+        pub fn set_tbloff<'b>(&'b mut self, new_value: u32) -> &'b mut Self {
+            let context_mask: u32 = 4294967295 << 0;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 4294967295) << 0);
+            self.mask |= context_mask;
+            self
+        }
+    }
+    impl<'a> Drop for VtorUpdate<'a> {
+        #[inline(always)]
+        fn drop(&mut self) {
+            if self.store_on_drop && self.mask != 0 {
+                let current: u32 = if self.write_only { 0 } else { self.reg.load() };
+                self.reg.store(self.merge(current));
+            }
+        }
+    }
+    #[derive(Clone, PartialEq, Eq)]
+    pub struct VtorGet {
+        value: u32,
+    }
+    impl VtorGet {
+        #[doc = "Create a getter reflecting the current value of the register"]
+        #[inline(always)]
+        pub fn new(reg: &Vtor) -> VtorGet {
+            VtorGet { value: reg.load() }
+        }
+        #[inline(always)]
+        pub fn tbloff(&self) -> u32 {
+            self.value
+        }
+    }
+    #[derive(Clone, Copy, PartialEq, Eq, Default)]
+    #[repr(transparent)]
+    pub struct VtorValue {
+        value: u32,
+    }
+    impl VtorValue {
+        #[doc = "Create a value with every field cleared"]
+        #[inline(always)]
+        pub const fn new() -> VtorValue {
+            VtorValue { value: 0 }
+        }
+        #[inline(always)]
+        pub const fn from_bits(bits: u32) -> VtorValue {
+            VtorValue { value: bits }
+        }
+        #[inline(always)]
+        pub const fn to_bits(&self) -> u32 {
+            self.value
+        }
+        #[inline(always)]
+        pub fn tbloff(&self) -> u32 {
+            self.value
+        }
+        #[inline(always)]
+        pub const fn with_tbloff(self, new_value: u32) -> Self {
+            let context_mask: u32 = 4294967295 << 0;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 4294967295) << 0),
+            }
+        }
+    }
+    impl From<VtorGet> for VtorValue {
+        #[inline(always)]
+        fn from(get: VtorGet) -> VtorValue {
+            VtorValue::from_bits(get.value)
+        }
+    }
+    impl From<VtorValue> for VtorGet {
+        #[inline(always)]
+        fn from(value: VtorValue) -> VtorGet {
+            VtorGet {
+                value: value.to_bits(),
+            }
+        }
+    }
+    impl Vtor {
+        #[doc = "Write `value` to the register in a single store, without reading it first"]
+        #[inline(always)]
+        pub fn write(&self, value: VtorValue) {
+            self.store(value.to_bits());
+        }
+    }
+    impl<'a> VtorUpdate<'a> {
+        #[doc = "Replace every field of the register with those of `value`"]
+        #[inline(always)]
+        pub fn assign<'b>(&'b mut self, value: VtorValue) -> &'b mut Self {
+            self.value = value.to_bits();
+            self.mask = !0;
+            self
+        }
+    }
+    impl VtorGet {
+        pub const FIELDS: &'static [::volatile_cell::field::FieldDescriptor] =
+            &[::volatile_cell::field::FieldDescriptor {
+                name: "tbloff",
+                offset: 0u32,
+                width: 32u32,
+                count: 1u32,
+            }];
+        #[doc = "Iterate over the readable fields that differ from those of `other`"]
+        #[inline]
+        pub fn changed_fields(&self, other: &VtorGet) -> ::volatile_cell::field::FieldChanges {
+            ::volatile_cell::field::FieldChanges::new(
+                Self::FIELDS,
+                self.value as u64,
+                other.value as u64,
+            )
+        }
+    }
+    #[repr(C)]
+    pub struct Scb {
+        pub cpuid: Cpuid,
+        pub icsr: Icsr,
+        pub vtor: Vtor,
+    }
+    #[doc = "Saved state of every readable register without side effects"]
+    #[derive(Clone, Copy)]
+    pub struct Snapshot {
+        pub cpuid: CpuidValue,
+        pub icsr: IcsrValue,
+        pub vtor: VtorValue,
+    }
+    #[doc = "Read the current state of the peripheral"]
+    #[allow(unused_variables)]
+    pub fn capture(p: &Scb) -> Snapshot {
+        Snapshot {
+            cpuid: CpuidValue::from(p.cpuid.get()),
+            icsr: IcsrValue::from(p.icsr.get()),
+            vtor: VtorValue::from(p.vtor.get()),
+        }
+    }
+    #[doc = "Write a previously captured state back to the peripheral"]
+    #[allow(unused_variables)]
+    pub fn restore(p: &Scb, snapshot: &Snapshot) {
+        IcsrUpdate::new_ignoring_state(&p.icsr).assign(snapshot.icsr);
+        VtorUpdate::new_ignoring_state(&p.vtor).assign(snapshot.vtor);
+    }
+    #[doc = "Owned handle granting exclusive access to the peripheral"]
+    pub struct ScbPeripheral {
+        regs: &'static mut Scb,
+    }
+    impl ScbPeripheral {
+        #[doc = "Create a token for the peripheral registers at `regs`."]
+        #[doc = ""]
+        #[doc = "Unsafe because the caller must ensure it's the only token for `regs`."]
+        #[inline(always)]
+        pub const unsafe fn new(regs: &'static mut Scb) -> ScbPeripheral {
+            ScbPeripheral { regs: regs }
+        }
+    }
+    impl ::core::ops::Deref for ScbPeripheral {
+        type Target = Scb;
+        #[inline(always)]
+        fn deref(&self) -> &Scb {
+            self.regs
+        }
+    }
+    impl ::core::ops::DerefMut for ScbPeripheral {
+        #[inline(always)]
+        fn deref_mut(&mut self) -> &mut Scb {
+            self.regs
+        }
+    }
+}
@@ -0,0 +1,512 @@
+pub mod eefc {
+    pub mod fmr {}
+    #[repr(C)]
+    pub struct Fmr {
+        value: ::volatile_cell::VolatileCell<u32>,
+    }
+    impl Fmr {
+        #[inline(always)]
+        fn load(&self) -> u32 {
+            let value = self.value.get();
+            value
+        }
+        #[inline(always)]
+        fn store(&self, value: u32) {
+            self.value.set(value);
+        }
+        #[doc = "Create a new updater"]
+        #[inline(always)]
+        pub fn update<'a>(&'a self) -> FmrUpdate<'a> {
+            FmrUpdate::new(self)
+        }
+        #[doc = "Create an updater whose read-modify-write happens inside the critical section `cs`"]
+        #[inline(always)]
+        pub fn update_in<'a, C: ::volatile_cell::sync::CriticalSection>(
+            &'a self,
+            _cs: &'a C,
+        ) -> FmrUpdate<'a> {
+            FmrUpdate::new(self)
+        }
+        #[doc = "Update the register with exclusive loads and stores (LDREX/STREX), retrying `f` until no other access intervened"]
+        #[doc = ""]
+        #[doc = "The exclusive accesses go to the register's memory directly rather than through its cell, so they aren't seen by the replayer or the tracing hook."]
+        #[cfg(target_has_atomic = "32")]
+        #[inline]
+        pub fn update_exclusive<F: FnMut(&mut FmrUpdate)>(&self, mut f: F) {
+            use ::core::sync::atomic::Ordering;
+            let cell = unsafe { &*(self.value.as_ptr() as *const ::core::sync::atomic::AtomicU32) };
+            let mut current = cell.load(Ordering::Relaxed);
+            loop {
+                let mut update = FmrUpdate::detached(self);
+                f(&mut update);
+                let merged = update.merge(current);
+                if update.mask == 0 {
+                    return;
+                }
+                match cell.compare_exchange_weak(
+                    current,
+                    merged,
+                    Ordering::SeqCst,
+                    Ordering::Relaxed,
+                ) {
+                    Ok(_) => return,
+                    Err(actual) => current = actual,
+                }
+            }
+        }
+        #[doc = "Create a getter representing the current state of the register"]
+        #[inline(always)]
+        pub fn get(&self) -> FmrGet {
+            FmrGet::new(self)
+        }
+    }
+    pub struct FmrUpdate<'a> {
+        value: u32,
+        mask: u32,
+        write_only: bool,
+        store_on_drop: bool,
+        reg: &'a Fmr,
+    }
+    impl<'a> FmrUpdate<'a> {
+        #[inline(always)]
+        pub fn new(reg: &'a Fmr) -> FmrUpdate<'a> {
+            FmrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[inline(always)]
+        pub fn new_ignoring_state(reg: &'a Fmr) -> FmrUpdate<'a> {
+            FmrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: true,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[doc = "An update that is never stored, for the caller to store itself"]
+        #[inline(always)]
+        fn detached(reg: &'a Fmr) -> FmrUpdate<'a> {
+            FmrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: false,
+                reg: reg,
+            }
+        }
+        const fn clear_mask() -> u32 {
+            0u64 as u32
+        }
+        const fn update_offset(base: usize, size: usize, index: usize) -> usize {
+            base + (size * index)
+        }
+        #[inline(always)]
+        fn merge(&self, current: u32) -> u32 {
+            let v: u32 = current & (!Self::clear_mask()) & (!self.mask);
+            self.value | v
+        }
+        #[inline(always)]
+        pub fn set_fws<'b>(&'b mut self, new_value: u16) -> &'b mut Self {
+            let context_mask: u32 = 4095 << 0;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 4095) << 0);
+            self.mask |= context_mask;
+            self
+        }
+    }
+    impl<'a> Drop for FmrUpdate<'a> {
+        #[inline(always)]
+        fn drop(&mut self) {
+            if self.store_on_drop && self.mask != 0 {
+                let current: u32 = if self.write_only { 0 } else { self.reg.load() };
+                self.reg.store(self.merge(current));
+            }
+        }
+    }
+    #[derive(Clone, PartialEq, Eq)]
+    pub struct FmrGet {
+        value: u32,
+    }
+    impl FmrGet {
+        #[doc = "Create a getter reflecting the current value of the register"]
+        #[inline(always)]
+        pub fn new(reg: &Fmr) -> FmrGet {
+            FmrGet { value: reg.load() }
+        }
+        #[inline(always)]
+        pub fn fws(&self) -> u32 {
+            (self.value & 4095)
+        }
+    }
+    #[derive(Clone, Copy, PartialEq, Eq, Default)]
+    #[repr(transparent)]
+    pub struct FmrValue {
+        value: u32,
+    }
+    impl FmrValue {
+        #[doc = "Create a value with every field cleared"]
+        #[inline(always)]
+        pub const fn new() -> FmrValue {
+            FmrValue { value: 0 }
+        }
+        #[inline(always)]
+        pub const fn from_bits(bits: u32) -> FmrValue {
+            FmrValue { value: bits }
+        }
+        #[inline(always)]
+        pub const fn to_bits(&self) -> u32 {
+            self.value
+        }
+        #[inline(always)]
+        pub fn fws(&self) -> u32 {
+            (self.value & 4095)
+        }
+        #[inline(always)]
+        pub const fn with_fws(self, new_value: u16) -> Self {
+            let context_mask: u32 = 4095 << 0;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 4095) << 0),
+            }
+        }
+    }
+    impl From<FmrGet> for FmrValue {
+        #[inline(always)]
+        fn from(get: FmrGet) -> FmrValue {
+            FmrValue::from_bits(get.value)
+        }
+    }
+    impl From<FmrValue> for FmrGet {
+        #[inline(always)]
+        fn from(value: FmrValue) -> FmrGet {
+            FmrGet {
+                value: value.to_bits(),
+            }
+        }
+    }
+    impl Fmr {
+        #[doc = "Write `value` to the register in a single store, without reading it first"]
+        #[inline(always)]
+        pub fn write(&self, value: FmrValue) {
+            self.store(value.to_bits());
+        }
+    }
+    impl<'a> FmrUpdate<'a> {
+        #[doc = "Replace every field of the register with those of `value`"]
+        #[inline(always)]
+        pub fn assign<'b>(&'b mut self, value: FmrValue) -> &'b mut Self {
+            self.value = value.to_bits();
+            self.mask = !0;
+            self
+        }
+    }
+    impl FmrGet {
+        pub const FIELDS: &'static [::volatile_cell::field::FieldDescriptor] =
+            &[::volatile_cell::field::FieldDescriptor {
+                name: "fws",
+                offset: 0u32,
+                width: 12u32,
+                count: 1u32,
+            }];
+        #[doc = "Iterate over the readable fields that differ from those of `other`"]
+        #[inline]
+        pub fn changed_fields(&self, other: &FmrGet) -> ::volatile_cell::field::FieldChanges {
+            ::volatile_cell::field::FieldChanges::new(
+                Self::FIELDS,
+                self.value as u64,
+                other.value as u64,
+            )
+        }
+    }
+    pub mod fsr {}
+    #[repr(C)]
+    pub struct Fsr {
+        value: ::volatile_cell::VolatileCell<u32>,
+    }
+    impl Fsr {
+        #[inline(always)]
+        fn load(&self) -> u32 {
+            let value = self.value.get();
+            value
+        }
+        #[inline(always)]
+        fn store(&self, value: u32) {
+            self.value.set(value);
+        }
+        #[doc = "Create a new updater"]
+        #[inline(always)]
+        pub fn update<'a>(&'a self) -> FsrUpdate<'a> {
+            FsrUpdate::new(self)
+        }
+        #[doc = "Create an updater whose read-modify-write happens inside the critical section `cs`"]
+        #[inline(always)]
+        pub fn update_in<'a, C: ::volatile_cell::sync::CriticalSection>(
+            &'a self,
+            _cs: &'a C,
+        ) -> FsrUpdate<'a> {
+            FsrUpdate::new(self)
+        }
+        #[doc = "Update the register with exclusive loads and stores (LDREX/STREX), retrying `f` until no other access intervened"]
+        #[doc = ""]
+        #[doc = "The exclusive accesses go to the register's memory directly rather than through its cell, so they aren't seen by the replayer or the tracing hook."]
+        #[cfg(target_has_atomic = "32")]
+        #[inline]
+        pub fn update_exclusive<F: FnMut(&mut FsrUpdate)>(&self, mut f: F) {
+            use ::core::sync::atomic::Ordering;
+            let cell = unsafe { &*(self.value.as_ptr() as *const ::core::sync::atomic::AtomicU32) };
+            let mut current = cell.load(Ordering::Relaxed);
+            loop {
+                let mut update = FsrUpdate::detached(self);
+                f(&mut update);
+                let merged = update.merge(current);
+                if update.mask == 0 {
+                    return;
+                }
+                match cell.compare_exchange_weak(
+                    current,
+                    merged,
+                    Ordering::SeqCst,
+                    Ordering::Relaxed,
+                ) {
+                    Ok(_) => return,
+                    Err(actual) => current = actual,
+                }
+            }
+        }
+        #[doc = "Create a getter representing the current state of the register"]
+        #[inline(always)]
+        pub fn get(&self) -> FsrGet {
+            FsrGet::new(self)
+        }
+        #[doc = "Spin until the bit is set"]
+        #[inline]
+        pub fn wait_fready(&self) {
+            while !self.get().fready() {}
+        }
+        #[doc = "Poll until the bit is set, at most `max_polls` times"]
+        #[inline]
+        pub fn wait_fready_timeout(
+            &self,
+            max_polls: u32,
+        ) -> Result<(), ::volatile_cell::wait::Timeout> {
+            ::volatile_cell::wait::poll_until(|| self.get().fready(), max_polls)
+        }
+        #[doc = "Future resolving once the bit is set"]
+        #[inline]
+        pub fn wait_fready_async<'a>(
+            &'a self,
+        ) -> ::volatile_cell::wait::WaitUntil<'a, impl FnMut() -> bool + Unpin + 'a> {
+            ::volatile_cell::wait::WaitUntil::new(move || self.get().fready())
+        }
+    }
+    pub struct FsrUpdate<'a> {
+        value: u32,
+        mask: u32,
+        write_only: bool,
+        store_on_drop: bool,
+        reg: &'a Fsr,
+    }
+    impl<'a> FsrUpdate<'a> {
+        #[inline(always)]
+        pub fn new(reg: &'a Fsr) -> FsrUpdate<'a> {
+            FsrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[inline(always)]
+        pub fn new_ignoring_state(reg: &'a Fsr) -> FsrUpdate<'a> {
+            FsrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: true,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[doc = "An update that is never stored, for the caller to store itself"]
+        #[inline(always)]
+        fn detached(reg: &'a Fsr) -> FsrUpdate<'a> {
+            FsrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: false,
+                reg: reg,
+            }
+        }
+        const fn clear_mask() -> u32 {
+            0u64 as u32
+        }
+        const fn update_offset(base: usize, size: usize, index: usize) -> usize {
+            base + (size * index)
+        }
+        #[inline(always)]
+        fn merge(&self, current: u32) -> u32 {
+            let v: u32 = current & (!Self::clear_mask()) & (!self.mask);
+            self.value | v
+        }
+    }
+    impl<'a> Drop for FsrUpdate<'a> {
+        #[inline(always)]
+        fn drop(&mut self) {
+            if self.store_on_drop && self.mask != 0 {
+                let current: u32 = if self.write_only { 0 } else { self.reg.load() };
+                self.reg.store(self.merge(current));
+            }
+        }
+    }
+    #[derive(Clone, PartialEq, Eq)]
+    pub struct FsrGet {
+        value: u32,
+    }
+    impl FsrGet {
+        #[doc = "Create a getter reflecting the current value of the register"]
+        #[inline(always)]
+        pub fn new(reg: &Fsr) -> FsrGet {
+            FsrGet { value: reg.load() }
+        }
+        #[inline(always)]
+        pub fn fready(&self) -> bool {
+            let val = (self.value & 1);
+            val != 0x0
+        }
+    }
+    #[derive(Clone, Copy, PartialEq, Eq, Default)]
+    #[repr(transparent)]
+    pub struct FsrValue {
+        value: u32,
+    }
+    impl FsrValue {
+        #[doc = "Create a value with every field cleared"]
+        #[inline(always)]
+        pub const fn new() -> FsrValue {
+            FsrValue { value: 0 }
+        }
+        #[inline(always)]
+        pub const fn from_bits(bits: u32) -> FsrValue {
+            FsrValue { value: bits }
+        }
+        #[inline(always)]
+        pub const fn to_bits(&self) -> u32 {
+            self.value
+        }
+        #[inline(always)]
+        pub fn fready(&self) -> bool {
+            let val = (self.value & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub const fn with_fready(self, new_value: bool) -> Self {
+            let context_mask: u32 = 1 << 0;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 1) << 0),
+            }
+        }
+    }
+    impl From<FsrGet> for FsrValue {
+        #[inline(always)]
+        fn from(get: FsrGet) -> FsrValue {
+            FsrValue::from_bits(get.value)
+        }
+    }
+    impl From<FsrValue> for FsrGet {
+        #[inline(always)]
+        fn from(value: FsrValue) -> FsrGet {
+            FsrGet {
+                value: value.to_bits(),
+            }
+        }
+    }
+    impl Fsr {
+        #[doc = "Write `value` to the register in a single store, without reading it first"]
+        #[inline(always)]
+        pub fn write(&self, value: FsrValue) {
+            self.store(value.to_bits());
+        }
+    }
+    impl<'a> FsrUpdate<'a> {
+        #[doc = "Replace every field of the register with those of `value`"]
+        #[inline(always)]
+        pub fn assign<'b>(&'b mut self, value: FsrValue) -> &'b mut Self {
+            self.value = value.to_bits();
+            self.mask = !0;
+            self
+        }
+    }
+    impl FsrGet {
+        pub const FIELDS: &'static [::volatile_cell::field::FieldDescriptor] =
+            &[::volatile_cell::field::FieldDescriptor {
+                name: "fready",
+                offset: 0u32,
+                width: 1u32,
+                count: 1u32,
+            }];
+        #[doc = "Iterate over the readable fields that differ from those of `other`"]
+        #[inline]
+        pub fn changed_fields(&self, other: &FsrGet) -> ::volatile_cell::field::FieldChanges {
+            ::volatile_cell::field::FieldChanges::new(
+                Self::FIELDS,
+                self.value as u64,
+                other.value as u64,
+            )
+        }
+    }
+    #[repr(C)]
+    pub struct Eefc {
+        pub fmr: Fmr,
+        _padding0: [u8; 4],
+        pub fsr: Fsr,
+    }
+    #[doc = "Saved state of every readable register without side effects"]
+    #[derive(Clone, Copy)]
+    pub struct Snapshot {
+        pub fmr: FmrValue,
+        pub fsr: FsrValue,
+    }
+    #[doc = "Read the current state of the peripheral"]
+    #[allow(unused_variables)]
+    pub fn capture(p: &Eefc) -> Snapshot {
+        Snapshot {
+            fmr: FmrValue::from(p.fmr.get()),
+            fsr: FsrValue::from(p.fsr.get()),
+        }
+    }
+    #[doc = "Write a previously captured state back to the peripheral"]
+    #[allow(unused_variables)]
+    pub fn restore(p: &Eefc, snapshot: &Snapshot) {
+        FmrUpdate::new_ignoring_state(&p.fmr).assign(snapshot.fmr);
+    }
+    #[doc = "Owned handle granting exclusive access to the peripheral"]
+    pub struct EefcPeripheral {
+        regs: &'static mut Eefc,
+    }
+    impl EefcPeripheral {
+        #[doc = "Create a token for the peripheral registers at `regs`."]
+        #[doc = ""]
+        #[doc = "Unsafe because the caller must ensure it's the only token for `regs`."]
+        #[inline(always)]
+        pub const unsafe fn new(regs: &'static mut Eefc) -> EefcPeripheral {
+            EefcPeripheral { regs: regs }
+        }
+    }
+    impl ::core::ops::Deref for EefcPeripheral {
+        type Target = Eefc;
+        #[inline(always)]
+        fn deref(&self) -> &Eefc {
+            self.regs
+        }
+    }
+    impl ::core::ops::DerefMut for EefcPeripheral {
+        #[inline(always)]
+        fn deref_mut(&mut self) -> &mut Eefc {
+            self.regs
+        }
+    }
+}
@@ -0,0 +1,2014 @@
+pub mod pmc {
+    pub mod mor {
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        #[repr(u32)]
+        pub enum Moscrcf {
+            MHz_4 = 0,
+            MHz_8 = 1,
+            MHz_12 = 2,
+        }
+        impl core::convert::TryFrom<u32> for Moscrcf {
+            type Error = u32;
+            #[inline(always)]
+            fn try_from(primitive: u32) -> Result<Self, Self::Error> {
+                match primitive {
+                    0 => Ok(Moscrcf::MHz_4),
+                    1 => Ok(Moscrcf::MHz_8),
+                    2 => Ok(Moscrcf::MHz_12),
+                    v => Err(v),
+                }
+            }
+        }
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        #[repr(u32)]
+        pub enum Moscsel {
+            MOSCRC = 0,
+            MOSCXT = 1,
+        }
+        impl core::convert::TryFrom<u32> for Moscsel {
+            type Error = u32;
+            #[inline(always)]
+            fn try_from(primitive: u32) -> Result<Self, Self::Error> {
+                match primitive {
+                    0 => Ok(Moscsel::MOSCRC),
+                    1 => Ok(Moscsel::MOSCXT),
+                    v => Err(v),
+                }
+            }
+        }
+    }
+    #[repr(C)]
+    pub struct Mor {
+        value: ::volatile_cell::VolatileCell<u32>,
+    }
+    impl Mor {
+        #[inline(always)]
+        fn load(&self) -> u32 {
+            let value = self.value.get();
+            value
+        }
+        #[inline(always)]
+        fn store(&self, value: u32) {
+            self.value.set(value);
+        }
+        #[doc = "Create a new updater"]
+        #[inline(always)]
+        pub fn update<'a>(&'a self) -> MorUpdate<'a> {
+            MorUpdate::new(self)
+        }
+        #[doc = "Create an updater whose read-modify-write happens inside the critical section `cs`"]
+        #[inline(always)]
+        pub fn update_in<'a, C: ::volatile_cell::sync::CriticalSection>(
+            &'a self,
+            _cs: &'a C,
+        ) -> MorUpdate<'a> {
+            MorUpdate::new(self)
+        }
+        #[doc = "Update the register with exclusive loads and stores (LDREX/STREX), retrying `f` until no other access intervened"]
+        #[doc = ""]
+        #[doc = "The exclusive accesses go to the register's memory directly rather than through its cell, so they aren't seen by the replayer or the tracing hook."]
+        #[cfg(target_has_atomic = "32")]
+        #[inline]
+        pub fn update_exclusive<F: FnMut(&mut MorUpdate)>(&self, mut f: F) {
+            use ::core::sync::atomic::Ordering;
+            let cell = unsafe { &*(self.value.as_ptr() as *const ::core::sync::atomic::AtomicU32) };
+            let mut current = cell.load(Ordering::Relaxed);
+            loop {
+                let mut update = MorUpdate::detached(self);
+                f(&mut update);
+                let merged = update.merge(current);
+                if update.mask == 0 {
+                    return;
+                }
+                match cell.compare_exchange_weak(
+                    current,
+                    merged,
+                    Ordering::SeqCst,
+                    Ordering::Relaxed,
+                ) {
+                    Ok(_) => return,
+                    Err(actual) => current = actual,
+                }
+            }
+        }
+        #[doc = "Create a getter representing the current state of the register"]
+        #[inline(always)]
+        pub fn get(&self) -> MorGet {
+            MorGet::new(self)
+        }
+        #[doc = "Spin until the bit is set"]
+        #[inline]
+        pub fn wait_moscxten(&self) {
+            while !self.get().moscxten() {}
+        }
+        #[doc = "Poll until the bit is set, at most `max_polls` times"]
+        #[inline]
+        pub fn wait_moscxten_timeout(
+            &self,
+            max_polls: u32,
+        ) -> Result<(), ::volatile_cell::wait::Timeout> {
+            ::volatile_cell::wait::poll_until(|| self.get().moscxten(), max_polls)
+        }
+        #[doc = "Future resolving once the bit is set"]
+        #[inline]
+        pub fn wait_moscxten_async<'a>(
+            &'a self,
+        ) -> ::volatile_cell::wait::WaitUntil<'a, impl FnMut() -> bool + Unpin + 'a> {
+            ::volatile_cell::wait::WaitUntil::new(move || self.get().moscxten())
+        }
+        #[doc = "Spin until the bit is set"]
+        #[inline]
+        pub fn wait_moscxtby(&self) {
+            while !self.get().moscxtby() {}
+        }
+        #[doc = "Poll until the bit is set, at most `max_polls` times"]
+        #[inline]
+        pub fn wait_moscxtby_timeout(
+            &self,
+            max_polls: u32,
+        ) -> Result<(), ::volatile_cell::wait::Timeout> {
+            ::volatile_cell::wait::poll_until(|| self.get().moscxtby(), max_polls)
+        }
+        #[doc = "Future resolving once the bit is set"]
+        #[inline]
+        pub fn wait_moscxtby_async<'a>(
+            &'a self,
+        ) -> ::volatile_cell::wait::WaitUntil<'a, impl FnMut() -> bool + Unpin + 'a> {
+            ::volatile_cell::wait::WaitUntil::new(move || self.get().moscxtby())
+        }
+        #[doc = "Spin until the bit is set"]
+        #[inline]
+        pub fn wait_moscrcen(&self) {
+            while !self.get().moscrcen() {}
+        }
+        #[doc = "Poll until the bit is set, at most `max_polls` times"]
+        #[inline]
+        pub fn wait_moscrcen_timeout(
+            &self,
+            max_polls: u32,
+        ) -> Result<(), ::volatile_cell::wait::Timeout> {
+            ::volatile_cell::wait::poll_until(|| self.get().moscrcen(), max_polls)
+        }
+        #[doc = "Future resolving once the bit is set"]
+        #[inline]
+        pub fn wait_moscrcen_async<'a>(
+            &'a self,
+        ) -> ::volatile_cell::wait::WaitUntil<'a, impl FnMut() -> bool + Unpin + 'a> {
+            ::volatile_cell::wait::WaitUntil::new(move || self.get().moscrcen())
+        }
+        #[doc = "Spin until the bit is set"]
+        #[inline]
+        pub fn wait_cfden(&self) {
+            while !self.get().cfden() {}
+        }
+        #[doc = "Poll until the bit is set, at most `max_polls` times"]
+        #[inline]
+        pub fn wait_cfden_timeout(
+            &self,
+            max_polls: u32,
+        ) -> Result<(), ::volatile_cell::wait::Timeout> {
+            ::volatile_cell::wait::poll_until(|| self.get().cfden(), max_polls)
+        }
+        #[doc = "Future resolving once the bit is set"]
+        #[inline]
+        pub fn wait_cfden_async<'a>(
+            &'a self,
+        ) -> ::volatile_cell::wait::WaitUntil<'a, impl FnMut() -> bool + Unpin + 'a> {
+            ::volatile_cell::wait::WaitUntil::new(move || self.get().cfden())
+        }
+    }
+    pub struct MorUpdate<'a> {
+        value: u32,
+        mask: u32,
+        write_only: bool,
+        store_on_drop: bool,
+        reg: &'a Mor,
+    }
+    impl<'a> MorUpdate<'a> {
+        #[inline(always)]
+        pub fn new(reg: &'a Mor) -> MorUpdate<'a> {
+            MorUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[inline(always)]
+        pub fn new_ignoring_state(reg: &'a Mor) -> MorUpdate<'a> {
+            MorUpdate {
+                value: 0,
+                mask: 0,
+                write_only: true,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[doc = "An update that is never stored, for the caller to store itself"]
+        #[inline(always)]
+        fn detached(reg: &'a Mor) -> MorUpdate<'a> {
+            MorUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: false,
+                reg: reg,
+            }
+        }
+        const fn clear_mask() -> u32 {
+            0u64 as u32
+        }
+        const fn update_offset(base: usize, size: usize, index: usize) -> usize {
+            base + (size * index)
+        }
+        #[inline(always)]
+        fn merge(&self, current: u32) -> u32 {
+            let v: u32 = current & (!Self::clear_mask()) & (!self.mask);
+            self.value | v
+        }
+        #[inline(always)]
+        pub fn set_moscxten<'b>(&'b mut self, new_value: bool) -> &'b mut Self {
+            let context_mask: u32 = 1 << 0;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 1) << 0);
+            self.mask |= context_mask;
+            self
+        }
+        #[inline(always)]
+        pub fn set_moscxtby<'b>(&'b mut self, new_value: bool) -> &'b mut Self {
+            let context_mask: u32 = 1 << 1;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 1) << 1);
+            self.mask |= context_mask;
+            self
+        }
+        #[inline(always)]
+        pub fn set_moscrcen<'b>(&'b mut self, new_value: bool) -> &'b mut Self {
+            let context_mask: u32 = 1 << 3;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 1) << 3);
+            self.mask |= context_mask;
+            self
+        }
+        #[inline(always)]
+        pub fn set_moscrcf<'b>(&'b mut self, new_value: mor::Moscrcf) -> &'b mut Self {
+            let context_mask: u32 = 7 << 4;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 7) << 4);
+            self.mask |= context_mask;
+            self
+        }
+        #[inline(always)]
+        pub fn set_moscxtst<'b>(&'b mut self, new_value: u8) -> &'b mut Self {
+            let context_mask: u32 = 255 << 8;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 255) << 8);
+            self.mask |= context_mask;
+            self
+        }
+        #[inline(always)]
+        pub fn set_key<'b>(&'b mut self, new_value: u8) -> &'b mut Self {
+            let context_mask: u32 = 255 << 16;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 255) << 16);
+            self.mask |= context_mask;
+            self
+        }
+        #[inline(always)]
+        pub fn set_moscsel<'b>(&'b mut self, new_value: mor::Moscsel) -> &'b mut Self {
+            let context_mask: u32 = 1 << 24;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 1) << 24);
+            self.mask |= context_mask;
+            self
+        }
+        #[inline(always)]
+        pub fn set_cfden<'b>(&'b mut self, new_value: bool) -> &'b mut Self {
+            let context_mask: u32 = 1 << 25;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 1) << 25);
+            self.mask |= context_mask;
+            self
+        }
+    }
+    impl<'a> Drop for MorUpdate<'a> {
+        #[inline(always)]
+        fn drop(&mut self) {
+            if self.store_on_drop && self.mask != 0 {
+                let current: u32 = if self.write_only { 0 } else { self.reg.load() };
+                self.reg.store(self.merge(current));
+            }
+        }
+    }
+    #[derive(Clone, PartialEq, Eq)]
+    pub struct MorGet {
+        value: u32,
+    }
+    impl MorGet {
+        #[doc = "Create a getter reflecting the current value of the register"]
+        #[inline(always)]
+        pub fn new(reg: &Mor) -> MorGet {
+            MorGet { value: reg.load() }
+        }
+        #[inline(always)]
+        pub fn moscxten(&self) -> bool {
+            let val = (self.value & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub fn moscxtby(&self) -> bool {
+            let val = ((self.value >> 1) & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub fn moscrcen(&self) -> bool {
+            let val = ((self.value >> 3) & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub fn moscrcf(&self) -> mor::Moscrcf {
+            use core::convert::TryFrom;
+            let primitive_value: u32 = ((self.value >> 4) & 7);
+            mor::Moscrcf::try_from(primitive_value).unwrap()
+        }
+        #[inline(always)]
+        pub fn moscxtst(&self) -> u32 {
+            ((self.value >> 8) & 255)
+        }
+        #[inline(always)]
+        pub fn key(&self) -> u32 {
+            ((self.value >> 16) & 255)
+        }
+        #[inline(always)]
+        pub fn moscsel(&self) -> mor::Moscsel {
+            unsafe { core::mem::transmute::<_, mor::Moscsel>(((self.value >> 24) & 1)) }
+        }
+        #[inline(always)]
+        pub fn cfden(&self) -> bool {
+            let val = ((self.value >> 25) & 1);
+            val != 0x0
+        }
+    }
+    #[derive(Clone, Copy, PartialEq, Eq, Default)]
+    #[repr(transparent)]
+    pub struct MorValue {
+        value: u32,
+    }
+    impl MorValue {
+        #[doc = "Create a value with every field cleared"]
+        #[inline(always)]
+        pub const fn new() -> MorValue {
+            MorValue { value: 0 }
+        }
+        #[inline(always)]
+        pub const fn from_bits(bits: u32) -> MorValue {
+            MorValue { value: bits }
+        }
+        #[inline(always)]
+        pub const fn to_bits(&self) -> u32 {
+            self.value
+        }
+        #[inline(always)]
+        pub fn moscxten(&self) -> bool {
+            let val = (self.value & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub fn moscxtby(&self) -> bool {
+            let val = ((self.value >> 1) & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub fn moscrcen(&self) -> bool {
+            let val = ((self.value >> 3) & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub fn moscrcf(&self) -> Result<mor::Moscrcf, u32> {
+            use core::convert::TryFrom;
+            let primitive_value: u32 = ((self.value >> 4) & 7);
+            mor::Moscrcf::try_from(primitive_value)
+        }
+        #[inline(always)]
+        pub fn moscxtst(&self) -> u32 {
+            ((self.value >> 8) & 255)
+        }
+        #[inline(always)]
+        pub fn key(&self) -> u32 {
+            ((self.value >> 16) & 255)
+        }
+        #[inline(always)]
+        pub fn moscsel(&self) -> mor::Moscsel {
+            unsafe { core::mem::transmute::<_, mor::Moscsel>(((self.value >> 24) & 1)) }
+        }
+        #[inline(always)]
+        pub fn cfden(&self) -> bool {
+            let val = ((self.value >> 25) & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub const fn with_moscxten(self, new_value: bool) -> Self {
+            let context_mask: u32 = 1 << 0;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 1) << 0),
+            }
+        }
+        #[inline(always)]
+        pub const fn with_moscxtby(self, new_value: bool) -> Self {
+            let context_mask: u32 = 1 << 1;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 1) << 1),
+            }
+        }
+        #[inline(always)]
+        pub const fn with_moscrcen(self, new_value: bool) -> Self {
+            let context_mask: u32 = 1 << 3;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 1) << 3),
+            }
+        }
+        #[inline(always)]
+        pub const fn with_moscrcf(self, new_value: mor::Moscrcf) -> Self {
+            let context_mask: u32 = 7 << 4;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 7) << 4),
+            }
+        }
+        #[inline(always)]
+        pub const fn with_moscxtst(self, new_value: u8) -> Self {
+            let context_mask: u32 = 255 << 8;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 255) << 8),
+            }
+        }
+        #[inline(always)]
+        pub const fn with_key(self, new_value: u8) -> Self {
+            let context_mask: u32 = 255 << 16;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 255) << 16),
+            }
+        }
+        #[inline(always)]
+        pub const fn with_moscsel(self, new_value: mor::Moscsel) -> Self {
+            let context_mask: u32 = 1 << 24;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 1) << 24),
+            }
+        }
+        #[inline(always)]
+        pub const fn with_cfden(self, new_value: bool) -> Self {
+            let context_mask: u32 = 1 << 25;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 1) << 25),
+            }
+        }
+    }
+    impl From<MorGet> for MorValue {
+        #[inline(always)]
+        fn from(get: MorGet) -> MorValue {
+            MorValue::from_bits(get.value)
+        }
+    }
+    impl From<MorValue> for MorGet {
+        #[inline(always)]
+        fn from(value: MorValue) -> MorGet {
+            MorGet {
+                value: value.to_bits(),
+            }
+        }
+    }
+    impl Mor {
+        #[doc = "Write `value` to the register in a single store, without reading it first"]
+        #[inline(always)]
+        pub fn write(&self, value: MorValue) {
+            self.store(value.to_bits());
+        }
+    }
+    impl<'a> MorUpdate<'a> {
+        #[doc = "Replace every field of the register with those of `value`"]
+        #[inline(always)]
+        pub fn assign<'b>(&'b mut self, value: MorValue) -> &'b mut Self {
+            self.value = value.to_bits();
+            self.mask = !0;
+            self
+        }
+    }
+    impl MorGet {
+        pub const FIELDS: &'static [::volatile_cell::field::FieldDescriptor] = &[
+            ::volatile_cell::field::FieldDescriptor {
+                name: "moscxten",
+                offset: 0u32,
+                width: 1u32,
+                count: 1u32,
+            },
+            ::volatile_cell::field::FieldDescriptor {
+                name: "moscxtby",
+                offset: 1u32,
+                width: 1u32,
+                count: 1u32,
+            },
+            ::volatile_cell::field::FieldDescriptor {
+                name: "moscrcen",
+                offset: 3u32,
+                width: 1u32,
+                count: 1u32,
+            },
+            ::volatile_cell::field::FieldDescriptor {
+                name: "moscrcf",
+                offset: 4u32,
+                width: 3u32,
+                count: 1u32,
+            },
+            ::volatile_cell::field::FieldDescriptor {
+                name: "moscxtst",
+                offset: 8u32,
+                width: 8u32,
+                count: 1u32,
+            },
+            ::volatile_cell::field::FieldDescriptor {
+                name: "key",
+                offset: 16u32,
+                width: 8u32,
+                count: 1u32,
+            },
+            ::volatile_cell::field::FieldDescriptor {
+                name: "moscsel",
+                offset: 24u32,
+                width: 1u32,
+                count: 1u32,
+            },
+            ::volatile_cell::field::FieldDescriptor {
+                name: "cfden",
+                offset: 25u32,
+                width: 1u32,
+                count: 1u32,
+            },
+        ];
+        #[doc = "Iterate over the readable fields that differ from those of `other`"]
+        #[inline]
+        pub fn changed_fields(&self, other: &MorGet) -> ::volatile_cell::field::FieldChanges {
+            ::volatile_cell::field::FieldChanges::new(
+                Self::FIELDS,
+                self.value as u64,
+                other.value as u64,
+            )
+        }
+    }
+    pub mod mcfr {}
+    #[repr(C)]
+    pub struct Mcfr {
+        value: ::volatile_cell::VolatileCell<u32>,
+    }
+    impl Mcfr {
+        #[inline(always)]
+        fn load(&self) -> u32 {
+            let value = self.value.get();
+            value
+        }
+        #[inline(always)]
+        fn store(&self, value: u32) {
+            self.value.set(value);
+        }
+        #[doc = "Create a new updater"]
+        #[inline(always)]
+        pub fn update<'a>(&'a self) -> McfrUpdate<'a> {
+            McfrUpdate::new(self)
+        }
+        #[doc = "Create an updater whose read-modify-write happens inside the critical section `cs`"]
+        #[inline(always)]
+        pub fn update_in<'a, C: ::volatile_cell::sync::CriticalSection>(
+            &'a self,
+            _cs: &'a C,
+        ) -> McfrUpdate<'a> {
+            McfrUpdate::new(self)
+        }
+        #[doc = "Update the register with exclusive loads and stores (LDREX/STREX), retrying `f` until no other access intervened"]
+        #[doc = ""]
+        #[doc = "The exclusive accesses go to the register's memory directly rather than through its cell, so they aren't seen by the replayer or the tracing hook."]
+        #[cfg(target_has_atomic = "32")]
+        #[inline]
+        pub fn update_exclusive<F: FnMut(&mut McfrUpdate)>(&self, mut f: F) {
+            use ::core::sync::atomic::Ordering;
+            let cell = unsafe { &*(self.value.as_ptr() as *const ::core::sync::atomic::AtomicU32) };
+            let mut current = cell.load(Ordering::Relaxed);
+            loop {
+                let mut update = McfrUpdate::detached(self);
+                f(&mut update);
+                let merged = update.merge(current);
+                if update.mask == 0 {
+                    return;
+                }
+                match cell.compare_exchange_weak(
+                    current,
+                    merged,
+                    Ordering::SeqCst,
+                    Ordering::Relaxed,
+                ) {
+                    Ok(_) => return,
+                    Err(actual) => current = actual,
+                }
+            }
+        }
+        #[doc = "Create a getter representing the current state of the register"]
+        #[inline(always)]
+        pub fn get(&self) -> McfrGet {
+            McfrGet::new(self)
+        }
+        #[doc = "Spin until the bit is set"]
+        #[inline]
+        pub fn wait_mainfrdy(&self) {
+            while !self.get().mainfrdy() {}
+        }
+        #[doc = "Poll until the bit is set, at most `max_polls` times"]
+        #[inline]
+        pub fn wait_mainfrdy_timeout(
+            &self,
+            max_polls: u32,
+        ) -> Result<(), ::volatile_cell::wait::Timeout> {
+            ::volatile_cell::wait::poll_until(|| self.get().mainfrdy(), max_polls)
+        }
+        #[doc = "Future resolving once the bit is set"]
+        #[inline]
+        pub fn wait_mainfrdy_async<'a>(
+            &'a self,
+        ) -> ::volatile_cell::wait::WaitUntil<'a, impl FnMut() -> bool + Unpin + 'a> {
+            ::volatile_cell::wait::WaitUntil::new(move || self.get().mainfrdy())
+        }
+    }
+    pub struct McfrUpdate<'a> {
+        value: u32,
+        mask: u32,
+        write_only: bool,
+        store_on_drop: bool,
+        reg: &'a Mcfr,
+    }
+    impl<'a> McfrUpdate<'a> {
+        #[inline(always)]
+        pub fn new(reg: &'a Mcfr) -> McfrUpdate<'a> {
+            McfrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[inline(always)]
+        pub fn new_ignoring_state(reg: &'a Mcfr) -> McfrUpdate<'a> {
+            McfrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: true,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[doc = "An update that is never stored, for the caller to store itself"]
+        #[inline(always)]
+        fn detached(reg: &'a Mcfr) -> McfrUpdate<'a> {
+            McfrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: false,
+                reg: reg,
+            }
+        }
+        const fn clear_mask() -> u32 {
+            0u64 as u32
+        }
+        const fn update_offset(base: usize, size: usize, index: usize) -> usize {
+            base + (size * index)
+        }
+        #[inline(always)]
+        fn merge(&self, current: u32) -> u32 {
+            let v: u32 = current & (!Self::clear_mask()) & (!self.mask);
+            self.value | v
+        }
+    }
+    impl<'a> Drop for McfrUpdate<'a> {
+        #[inline(always)]
+        fn drop(&mut self) {
+            if self.store_on_drop && self.mask != 0 {
+                let current: u32 = if self.write_only { 0 } else { self.reg.load() };
+                self.reg.store(self.merge(current));
+            }
+        }
+    }
+    #[derive(Clone, PartialEq, Eq)]
+    pub struct McfrGet {
+        value: u32,
+    }
+    impl McfrGet {
+        #[doc = "Create a getter reflecting the current value of the register"]
+        #[inline(always)]
+        pub fn new(reg: &Mcfr) -> McfrGet {
+            McfrGet { value: reg.load() }
+        }
+        #[inline(always)]
+        pub fn mainf(&self) -> u32 {
+            (self.value & 65535)
+        }
+        #[inline(always)]
+        pub fn mainfrdy(&self) -> bool {
+            let val = ((self.value >> 16) & 1);
+            val != 0x0
+        }
+    }
+    #[derive(Clone, Copy, PartialEq, Eq, Default)]
+    #[repr(transparent)]
+    pub struct McfrValue {
+        value: u32,
+    }
+    impl McfrValue {
+        #[doc = "Create a value with every field cleared"]
+        #[inline(always)]
+        pub const fn new() -> McfrValue {
+            McfrValue { value: 0 }
+        }
+        #[inline(always)]
+        pub const fn from_bits(bits: u32) -> McfrValue {
+            McfrValue { value: bits }
+        }
+        #[inline(always)]
+        pub const fn to_bits(&self) -> u32 {
+            self.value
+        }
+        #[inline(always)]
+        pub fn mainf(&self) -> u32 {
+            (self.value & 65535)
+        }
+        #[inline(always)]
+        pub fn mainfrdy(&self) -> bool {
+            let val = ((self.value >> 16) & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub const fn with_mainf(self, new_value: u16) -> Self {
+            let context_mask: u32 = 65535 << 0;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 65535) << 0),
+            }
+        }
+        #[inline(always)]
+        pub const fn with_mainfrdy(self, new_value: bool) -> Self {
+            let context_mask: u32 = 1 << 16;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 1) << 16),
+            }
+        }
+    }
+    impl From<McfrGet> for McfrValue {
+        #[inline(always)]
+        fn from(get: McfrGet) -> McfrValue {
+            McfrValue::from_bits(get.value)
+        }
+    }
+    impl From<McfrValue> for McfrGet {
+        #[inline(always)]
+        fn from(value: McfrValue) -> McfrGet {
+            McfrGet {
+                value: value.to_bits(),
+            }
+        }
+    }
+    impl Mcfr {
+        #[doc = "Write `value` to the register in a single store, without reading it first"]
+        #[inline(always)]
+        pub fn write(&self, value: McfrValue) {
+            self.store(value.to_bits());
+        }
+    }
+    impl<'a> McfrUpdate<'a> {
+        #[doc = "Replace every field of the register with those of `value`"]
+        #[inline(always)]
+        pub fn assign<'b>(&'b mut self, value: McfrValue) -> &'b mut Self {
+            self.value = value.to_bits();
+            self.mask = !0;
+            self
+        }
+    }
+    impl McfrGet {
+        pub const FIELDS: &'static [::volatile_cell::field::FieldDescriptor] = &[
+            ::volatile_cell::field::FieldDescriptor {
+                name: "mainf",
+                offset: 0u32,
+                width: 16u32,
+                count: 1u32,
+            },
+            ::volatile_cell::field::FieldDescriptor {
+                name: "mainfrdy",
+                offset: 16u32,
+                width: 1u32,
+                count: 1u32,
+            },
+        ];
+        #[doc = "Iterate over the readable fields that differ from those of `other`"]
+        #[inline]
+        pub fn changed_fields(&self, other: &McfrGet) -> ::volatile_cell::field::FieldChanges {
+            ::volatile_cell::field::FieldChanges::new(
+                Self::FIELDS,
+                self.value as u64,
+                other.value as u64,
+            )
+        }
+    }
+    pub mod pllar {}
+    #[repr(C)]
+    pub struct Pllar {
+        value: ::volatile_cell::VolatileCell<u32>,
+    }
+    impl Pllar {
+        #[inline(always)]
+        fn load(&self) -> u32 {
+            let value = self.value.get();
+            value
+        }
+        #[inline(always)]
+        fn store(&self, value: u32) {
+            self.value.set(value);
+        }
+        #[doc = "Create a new updater"]
+        #[inline(always)]
+        pub fn update<'a>(&'a self) -> PllarUpdate<'a> {
+            PllarUpdate::new(self)
+        }
+        #[doc = "Create an updater whose read-modify-write happens inside the critical section `cs`"]
+        #[inline(always)]
+        pub fn update_in<'a, C: ::volatile_cell::sync::CriticalSection>(
+            &'a self,
+            _cs: &'a C,
+        ) -> PllarUpdate<'a> {
+            PllarUpdate::new(self)
+        }
+        #[doc = "Update the register with exclusive loads and stores (LDREX/STREX), retrying `f` until no other access intervened"]
+        #[doc = ""]
+        #[doc = "The exclusive accesses go to the register's memory directly rather than through its cell, so they aren't seen by the replayer or the tracing hook."]
+        #[cfg(target_has_atomic = "32")]
+        #[inline]
+        pub fn update_exclusive<F: FnMut(&mut PllarUpdate)>(&self, mut f: F) {
+            use ::core::sync::atomic::Ordering;
+            let cell = unsafe { &*(self.value.as_ptr() as *const ::core::sync::atomic::AtomicU32) };
+            let mut current = cell.load(Ordering::Relaxed);
+            loop {
+                let mut update = PllarUpdate::detached(self);
+                f(&mut update);
+                let merged = update.merge(current);
+                if update.mask == 0 {
+                    return;
+                }
+                match cell.compare_exchange_weak(
+                    current,
+                    merged,
+                    Ordering::SeqCst,
+                    Ordering::Relaxed,
+                ) {
+                    Ok(_) => return,
+                    Err(actual) => current = actual,
+                }
+            }
+        }
+        #[doc = "Create a getter representing the current state of the register"]
+        #[inline(always)]
+        pub fn get(&self) -> PllarGet {
+            PllarGet::new(self)
+        }
+        #[doc = "Spin until the bit is set"]
+        #[inline]
+        pub fn wait_one(&self) {
+            while !self.get().one() {}
+        }
+        #[doc = "Poll until the bit is set, at most `max_polls` times"]
+        #[inline]
+        pub fn wait_one_timeout(
+            &self,
+            max_polls: u32,
+        ) -> Result<(), ::volatile_cell::wait::Timeout> {
+            ::volatile_cell::wait::poll_until(|| self.get().one(), max_polls)
+        }
+        #[doc = "Future resolving once the bit is set"]
+        #[inline]
+        pub fn wait_one_async<'a>(
+            &'a self,
+        ) -> ::volatile_cell::wait::WaitUntil<'a, impl FnMut() -> bool + Unpin + 'a> {
+            ::volatile_cell::wait::WaitUntil::new(move || self.get().one())
+        }
+    }
+    pub struct PllarUpdate<'a> {
+        value: u32,
+        mask: u32,
+        write_only: bool,
+        store_on_drop: bool,
+        reg: &'a Pllar,
+    }
+    impl<'a> PllarUpdate<'a> {
+        #[inline(always)]
+        pub fn new(reg: &'a Pllar) -> PllarUpdate<'a> {
+            PllarUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[inline(always)]
+        pub fn new_ignoring_state(reg: &'a Pllar) -> PllarUpdate<'a> {
+            PllarUpdate {
+                value: 0,
+                mask: 0,
+                write_only: true,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[doc = "An update that is never stored, for the caller to store itself"]
+        #[inline(always)]
+        fn detached(reg: &'a Pllar) -> PllarUpdate<'a> {
+            PllarUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: false,
+                reg: reg,
+            }
+        }
+        const fn clear_mask() -> u32 {
+            0u64 as u32
+        }
+        const fn update_offset(base: usize, size: usize, index: usize) -> usize {
+            base + (size * index)
+        }
+        #[inline(always)]
+        fn merge(&self, current: u32) -> u32 {
+            let v: u32 = current & (!Self::clear_mask()) & (!self.mask);
+            self.value | v
+        }
+        #[inline(always)]
+        pub fn set_diva<'b>(&'b mut self, new_value: u8) -> &'b mut Self {
+            let context_mask: u32 = 255 << 0;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 255) << 0);
+            self.mask |= context_mask;
+            self
+        }
+        #[inline(always)]
+        pub fn set_pllacount<'b>(&'b mut self, new_value: u8) -> &'b mut Self {
+            let context_mask: u32 = 63 << 8;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 63) << 8);
+            self.mask |= context_mask;
+            self
+        }
+        #[inline(always)]
+        pub fn set_mula<'b>(&'b mut self, new_value: u16) -> &'b mut Self {
+            let context_mask: u32 = 2047 << 16;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 2047) << 16);
+            self.mask |= context_mask;
+            self
+        }
+        #[inline(always)]
+        pub fn set_one<'b>(&'b mut self, new_value: bool) -> &'b mut Self {
+            let context_mask: u32 = 1 << 29;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 1) << 29);
+            self.mask |= context_mask;
+            self
+        }
+    }
+    impl<'a> Drop for PllarUpdate<'a> {
+        #[inline(always)]
+        fn drop(&mut self) {
+            if self.store_on_drop && self.mask != 0 {
+                let current: u32 = if self.write_only { 0 } else { self.reg.load() };
+                self.reg.store(self.merge(current));
+            }
+        }
+    }
+    #[derive(Clone, PartialEq, Eq)]
+    pub struct PllarGet {
+        value: u32,
+    }
+    impl PllarGet {
+        #[doc = "Create a getter reflecting the current value of the register"]
+        #[inline(always)]
+        pub fn new(reg: &Pllar) -> PllarGet {
+            PllarGet { value: reg.load() }
+        }
+        #[inline(always)]
+        pub fn diva(&self) -> u32 {
+            (self.value & 255)
+        }
+        #[inline(always)]
+        pub fn pllacount(&self) -> u32 {
+            ((self.value >> 8) & 63)
+        }
+        #[inline(always)]
+        pub fn mula(&self) -> u32 {
+            ((self.value >> 16) & 2047)
+        }
+        #[inline(always)]
+        pub fn one(&self) -> bool {
+            let val = ((self.value >> 29) & 1);
+            val != 0x0
+        }
+    }
+    #[derive(Clone, Copy, PartialEq, Eq, Default)]
+    #[repr(transparent)]
+    pub struct PllarValue {
+        value: u32,
+    }
+    impl PllarValue {
+        #[doc = "Create a value with every field cleared"]
+        #[inline(always)]
+        pub const fn new() -> PllarValue {
+            PllarValue { value: 0 }
+        }
+        #[inline(always)]
+        pub const fn from_bits(bits: u32) -> PllarValue {
+            PllarValue { value: bits }
+        }
+        #[inline(always)]
+        pub const fn to_bits(&self) -> u32 {
+            self.value
+        }
+        #[inline(always)]
+        pub fn diva(&self) -> u32 {
+            (self.value & 255)
+        }
+        #[inline(always)]
+        pub fn pllacount(&self) -> u32 {
+            ((self.value >> 8) & 63)
+        }
+        #[inline(always)]
+        pub fn mula(&self) -> u32 {
+            ((self.value >> 16) & 2047)
+        }
+        #[inline(always)]
+        pub fn one(&self) -> bool {
+            let val = ((self.value >> 29) & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub const fn with_diva(self, new_value: u8) -> Self {
+            let context_mask: u32 = 255 << 0;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 255) << 0),
+            }
+        }
+        #[inline(always)]
+        pub const fn with_pllacount(self, new_value: u8) -> Self {
+            let context_mask: u32 = 63 << 8;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 63) << 8),
+            }
+        }
+        #[inline(always)]
+        pub const fn with_mula(self, new_value: u16) -> Self {
+            let context_mask: u32 = 2047 << 16;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 2047) << 16),
+            }
+        }
+        #[inline(always)]
+        pub const fn with_one(self, new_value: bool) -> Self {
+            let context_mask: u32 = 1 << 29;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 1) << 29),
+            }
+        }
+    }
+    impl From<PllarGet> for PllarValue {
+        #[inline(always)]
+        fn from(get: PllarGet) -> PllarValue {
+            PllarValue::from_bits(get.value)
+        }
+    }
+    impl From<PllarValue> for PllarGet {
+        #[inline(always)]
+        fn from(value: PllarValue) -> PllarGet {
+            PllarGet {
+                value: value.to_bits(),
+            }
+        }
+    }
+    impl Pllar {
+        #[doc = "Write `value` to the register in a single store, without reading it first"]
+        #[inline(always)]
+        pub fn write(&self, value: PllarValue) {
+            self.store(value.to_bits());
+        }
+    }
+    impl<'a> PllarUpdate<'a> {
+        #[doc = "Replace every field of the register with those of `value`"]
+        #[inline(always)]
+        pub fn assign<'b>(&'b mut self, value: PllarValue) -> &'b mut Self {
+            self.value = value.to_bits();
+            self.mask = !0;
+            self
+        }
+    }
+    impl PllarGet {
+        pub const FIELDS: &'static [::volatile_cell::field::FieldDescriptor] = &[
+            ::volatile_cell::field::FieldDescriptor {
+                name: "diva",
+                offset: 0u32,
+                width: 8u32,
+                count: 1u32,
+            },
+            ::volatile_cell::field::FieldDescriptor {
+                name: "pllacount",
+                offset: 8u32,
+                width: 6u32,
+                count: 1u32,
+            },
+            ::volatile_cell::field::FieldDescriptor {
+                name: "mula",
+                offset: 16u32,
+                width: 11u32,
+                count: 1u32,
+            },
+            ::volatile_cell::field::FieldDescriptor {
+                name: "one",
+                offset: 29u32,
+                width: 1u32,
+                count: 1u32,
+            },
+        ];
+        #[doc = "Iterate over the readable fields that differ from those of `other`"]
+        #[inline]
+        pub fn changed_fields(&self, other: &PllarGet) -> ::volatile_cell::field::FieldChanges {
+            ::volatile_cell::field::FieldChanges::new(
+                Self::FIELDS,
+                self.value as u64,
+                other.value as u64,
+            )
+        }
+    }
+    pub mod mckr {
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        #[repr(u32)]
+        pub enum Css {
+            SlowClk = 0,
+            MainClk = 1,
+            PllaClk = 2,
+            UpplClk = 3,
+        }
+        impl core::convert::TryFrom<u32> for Css {
+            type Error = u32;
+            #[inline(always)]
+            fn try_from(primitive: u32) -> Result<Self, Self::Error> {
+                match primitive {
+                    0 => Ok(Css::SlowClk),
+                    1 => Ok(Css::MainClk),
+                    2 => Ok(Css::PllaClk),
+                    3 => Ok(Css::UpplClk),
+                    v => Err(v),
+                }
+            }
+        }
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        #[repr(u32)]
+        pub enum Pres {
+            CLK = 0,
+            CLK_2 = 1,
+            CLK_4 = 2,
+            CLK_8 = 3,
+            CLK_16 = 4,
+            CLK_32 = 5,
+            CLK_64 = 6,
+            CLK_3 = 7,
+        }
+        impl core::convert::TryFrom<u32> for Pres {
+            type Error = u32;
+            #[inline(always)]
+            fn try_from(primitive: u32) -> Result<Self, Self::Error> {
+                match primitive {
+                    0 => Ok(Pres::CLK),
+                    1 => Ok(Pres::CLK_2),
+                    2 => Ok(Pres::CLK_4),
+                    3 => Ok(Pres::CLK_8),
+                    4 => Ok(Pres::CLK_16),
+                    5 => Ok(Pres::CLK_32),
+                    6 => Ok(Pres::CLK_64),
+                    7 => Ok(Pres::CLK_3),
+                    v => Err(v),
+                }
+            }
+        }
+    }
+    #[repr(C)]
+    pub struct Mckr {
+        value: ::volatile_cell::VolatileCell<u32>,
+    }
+    impl Mckr {
+        #[inline(always)]
+        fn load(&self) -> u32 {
+            let value = self.value.get();
+            value
+        }
+        #[inline(always)]
+        fn store(&self, value: u32) {
+            self.value.set(value);
+        }
+        #[doc = "Create a new updater"]
+        #[inline(always)]
+        pub fn update<'a>(&'a self) -> MckrUpdate<'a> {
+            MckrUpdate::new(self)
+        }
+        #[doc = "Create an updater whose read-modify-write happens inside the critical section `cs`"]
+        #[inline(always)]
+        pub fn update_in<'a, C: ::volatile_cell::sync::CriticalSection>(
+            &'a self,
+            _cs: &'a C,
+        ) -> MckrUpdate<'a> {
+            MckrUpdate::new(self)
+        }
+        #[doc = "Update the register with exclusive loads and stores (LDREX/STREX), retrying `f` until no other access intervened"]
+        #[doc = ""]
+        #[doc = "The exclusive accesses go to the register's memory directly rather than through its cell, so they aren't seen by the replayer or the tracing hook."]
+        #[cfg(target_has_atomic = "32")]
+        #[inline]
+        pub fn update_exclusive<F: FnMut(&mut MckrUpdate)>(&self, mut f: F) {
+            use ::core::sync::atomic::Ordering;
+            let cell = unsafe { &*(self.value.as_ptr() as *const ::core::sync::atomic::AtomicU32) };
+            let mut current = cell.load(Ordering::Relaxed);
+            loop {
+                let mut update = MckrUpdate::detached(self);
+                f(&mut update);
+                let merged = update.merge(current);
+                if update.mask == 0 {
+                    return;
+                }
+                match cell.compare_exchange_weak(
+                    current,
+                    merged,
+                    Ordering::SeqCst,
+                    Ordering::Relaxed,
+                ) {
+                    Ok(_) => return,
+                    Err(actual) => current = actual,
+                }
+            }
+        }
+        #[doc = "Create a getter representing the current state of the register"]
+        #[inline(always)]
+        pub fn get(&self) -> MckrGet {
+            MckrGet::new(self)
+        }
+        #[doc = "Spin until the bit is set"]
+        #[inline]
+        pub fn wait_plladiv2(&self) {
+            while !self.get().plladiv2() {}
+        }
+        #[doc = "Poll until the bit is set, at most `max_polls` times"]
+        #[inline]
+        pub fn wait_plladiv2_timeout(
+            &self,
+            max_polls: u32,
+        ) -> Result<(), ::volatile_cell::wait::Timeout> {
+            ::volatile_cell::wait::poll_until(|| self.get().plladiv2(), max_polls)
+        }
+        #[doc = "Future resolving once the bit is set"]
+        #[inline]
+        pub fn wait_plladiv2_async<'a>(
+            &'a self,
+        ) -> ::volatile_cell::wait::WaitUntil<'a, impl FnMut() -> bool + Unpin + 'a> {
+            ::volatile_cell::wait::WaitUntil::new(move || self.get().plladiv2())
+        }
+        #[doc = "Spin until the bit is set"]
+        #[inline]
+        pub fn wait_uplldiv2(&self) {
+            while !self.get().uplldiv2() {}
+        }
+        #[doc = "Poll until the bit is set, at most `max_polls` times"]
+        #[inline]
+        pub fn wait_uplldiv2_timeout(
+            &self,
+            max_polls: u32,
+        ) -> Result<(), ::volatile_cell::wait::Timeout> {
+            ::volatile_cell::wait::poll_until(|| self.get().uplldiv2(), max_polls)
+        }
+        #[doc = "Future resolving once the bit is set"]
+        #[inline]
+        pub fn wait_uplldiv2_async<'a>(
+            &'a self,
+        ) -> ::volatile_cell::wait::WaitUntil<'a, impl FnMut() -> bool + Unpin + 'a> {
+            ::volatile_cell::wait::WaitUntil::new(move || self.get().uplldiv2())
+        }
+    }
+    pub struct MckrUpdate<'a> {
+        value: u32,
+        mask: u32,
+        write_only: bool,
+        store_on_drop: bool,
+        reg: &'a Mckr,
+    }
+    impl<'a> MckrUpdate<'a> {
+        #[inline(always)]
+        pub fn new(reg: &'a Mckr) -> MckrUpdate<'a> {
+            MckrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[inline(always)]
+        pub fn new_ignoring_state(reg: &'a Mckr) -> MckrUpdate<'a> {
+            MckrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: true,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[doc = "An update that is never stored, for the caller to store itself"]
+        #[inline(always)]
+        fn detached(reg: &'a Mckr) -> MckrUpdate<'a> {
+            MckrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: false,
+                reg: reg,
+            }
+        }
+        const fn clear_mask() -> u32 {
+            0u64 as u32
+        }
+        const fn update_offset(base: usize, size: usize, index: usize) -> usize {
+            base + (size * index)
+        }
+        #[inline(always)]
+        fn merge(&self, current: u32) -> u32 {
+            let v: u32 = current & (!Self::clear_mask()) & (!self.mask);
+            self.value | v
+        }
+        #[inline(always)]
+        pub fn set_css<'b>(&'b mut self, new_value: mckr::Css) -> &'b mut Self {
+            let context_mask: u32 = 3 << 0;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 3) << 0);
+            self.mask |= context_mask;
+            self
+        }
+        #[inline(always)]
+        pub fn set_pres<'b>(&'b mut self, new_value: mckr::Pres) -> &'b mut Self {
+            let context_mask: u32 = 7 << 4;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 7) << 4);
+            self.mask |= context_mask;
+            self
+        }
+        #[inline(always)]
+        pub fn set_plladiv2<'b>(&'b mut self, new_value: bool) -> &'b mut Self {
+            let context_mask: u32 = 1 << 12;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 1) << 12);
+            self.mask |= context_mask;
+            self
+        }
+        #[inline(always)]
+        pub fn set_uplldiv2<'b>(&'b mut self, new_value: bool) -> &'b mut Self {
+            let context_mask: u32 = 1 << 13;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 1) << 13);
+            self.mask |= context_mask;
+            self
+        }
+    }
+    impl<'a> Drop for MckrUpdate<'a> {
+        #[inline(always)]
+        fn drop(&mut self) {
+            if self.store_on_drop && self.mask != 0 {
+                let current: u32 = if self.write_only { 0 } else { self.reg.load() };
+                self.reg.store(self.merge(current));
+            }
+        }
+    }
+    #[derive(Clone, PartialEq, Eq)]
+    pub struct MckrGet {
+        value: u32,
+    }
+    impl MckrGet {
+        #[doc = "Create a getter reflecting the current value of the register"]
+        #[inline(always)]
+        pub fn new(reg: &Mckr) -> MckrGet {
+            MckrGet { value: reg.load() }
+        }
+        #[inline(always)]
+        pub fn css(&self) -> mckr::Css {
+            unsafe { core::mem::transmute::<_, mckr::Css>((self.value & 3)) }
+        }
+        #[inline(always)]
+        pub fn pres(&self) -> mckr::Pres {
+            unsafe { core::mem::transmute::<_, mckr::Pres>(((self.value >> 4) & 7)) }
+        }
+        #[inline(always)]
+        pub fn plladiv2(&self) -> bool {
+            let val = ((self.value >> 12) & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub fn uplldiv2(&self) -> bool {
+            let val = ((self.value >> 13) & 1);
+            val != 0x0
+        }
+    }
+    #[derive(Clone, Copy, PartialEq, Eq, Default)]
+    #[repr(transparent)]
+    pub struct MckrValue {
+        value: u32,
+    }
+    impl MckrValue {
+        #[doc = "Create a value with every field cleared"]
+        #[inline(always)]
+        pub const fn new() -> MckrValue {
+            MckrValue { value: 0 }
+        }
+        #[inline(always)]
+        pub const fn from_bits(bits: u32) -> MckrValue {
+            MckrValue { value: bits }
+        }
+        #[inline(always)]
+        pub const fn to_bits(&self) -> u32 {
+            self.value
+        }
+        #[inline(always)]
+        pub fn css(&self) -> mckr::Css {
+            unsafe { core::mem::transmute::<_, mckr::Css>((self.value & 3)) }
+        }
+        #[inline(always)]
+        pub fn pres(&self) -> mckr::Pres {
+            unsafe { core::mem::transmute::<_, mckr::Pres>(((self.value >> 4) & 7)) }
+        }
+        #[inline(always)]
+        pub fn plladiv2(&self) -> bool {
+            let val = ((self.value >> 12) & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub fn uplldiv2(&self) -> bool {
+            let val = ((self.value >> 13) & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub const fn with_css(self, new_value: mckr::Css) -> Self {
+            let context_mask: u32 = 3 << 0;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 3) << 0),
+            }
+        }
+        #[inline(always)]
+        pub const fn with_pres(self, new_value: mckr::Pres) -> Self {
+            let context_mask: u32 = 7 << 4;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 7) << 4),
+            }
+        }
+        #[inline(always)]
+        pub const fn with_plladiv2(self, new_value: bool) -> Self {
+            let context_mask: u32 = 1 << 12;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 1) << 12),
+            }
+        }
+        #[inline(always)]
+        pub const fn with_uplldiv2(self, new_value: bool) -> Self {
+            let context_mask: u32 = 1 << 13;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 1) << 13),
+            }
+        }
+    }
+    impl From<MckrGet> for MckrValue {
+        #[inline(always)]
+        fn from(get: MckrGet) -> MckrValue {
+            MckrValue::from_bits(get.value)
+        }
+    }
+    impl From<MckrValue> for MckrGet {
+        #[inline(always)]
+        fn from(value: MckrValue) -> MckrGet {
+            MckrGet {
+                value: value.to_bits(),
+            }
+        }
+    }
+    impl Mckr {
+        #[doc = "Write `value` to the register in a single store, without reading it first"]
+        #[inline(always)]
+        pub fn write(&self, value: MckrValue) {
+            self.store(value.to_bits());
+        }
+    }
+    impl<'a> MckrUpdate<'a> {
+        #[doc = "Replace every field of the register with those of `value`"]
+        #[inline(always)]
+        pub fn assign<'b>(&'b mut self, value: MckrValue) -> &'b mut Self {
+            self.value = value.to_bits();
+            self.mask = !0;
+            self
+        }
+    }
+    impl MckrGet {
+        pub const FIELDS: &'static [::volatile_cell::field::FieldDescriptor] = &[
+            ::volatile_cell::field::FieldDescriptor {
+                name: "css",
+                offset: 0u32,
+                width: 2u32,
+                count: 1u32,
+            },
+            ::volatile_cell::field::FieldDescriptor {
+                name: "pres",
+                offset: 4u32,
+                width: 3u32,
+                count: 1u32,
+            },
+            ::volatile_cell::field::FieldDescriptor {
+                name: "plladiv2",
+                offset: 12u32,
+                width: 1u32,
+                count: 1u32,
+            },
+            ::volatile_cell::field::FieldDescriptor {
+                name: "uplldiv2",
+                offset: 13u32,
+                width: 1u32,
+                count: 1u32,
+            },
+        ];
+        #[doc = "Iterate over the readable fields that differ from those of `other`"]
+        #[inline]
+        pub fn changed_fields(&self, other: &MckrGet) -> ::volatile_cell::field::FieldChanges {
+            ::volatile_cell::field::FieldChanges::new(
+                Self::FIELDS,
+                self.value as u64,
+                other.value as u64,
+            )
+        }
+    }
+    pub mod st {}
+    #[repr(C)]
+    pub struct St {
+        value: ::volatile_cell::VolatileCell<u32>,
+    }
+    impl St {
+        #[inline(always)]
+        fn load(&self) -> u32 {
+            let value = self.value.get();
+            value
+        }
+        #[inline(always)]
+        fn store(&self, value: u32) {
+            self.value.set(value);
+        }
+        #[doc = "Create a new updater"]
+        #[inline(always)]
+        pub fn update<'a>(&'a self) -> StUpdate<'a> {
+            StUpdate::new(self)
+        }
+        #[doc = "Create an updater whose read-modify-write happens inside the critical section `cs`"]
+        #[inline(always)]
+        pub fn update_in<'a, C: ::volatile_cell::sync::CriticalSection>(
+            &'a self,
+            _cs: &'a C,
+        ) -> StUpdate<'a> {
+            StUpdate::new(self)
+        }
+        #[doc = "Update the register with exclusive loads and stores (LDREX/STREX), retrying `f` until no other access intervened"]
+        #[doc = ""]
+        #[doc = "The exclusive accesses go to the register's memory directly rather than through its cell, so they aren't seen by the replayer or the tracing hook."]
+        #[cfg(target_has_atomic = "32")]
+        #[inline]
+        pub fn update_exclusive<F: FnMut(&mut StUpdate)>(&self, mut f: F) {
+            use ::core::sync::atomic::Ordering;
+            let cell = unsafe { &*(self.value.as_ptr() as *const ::core::sync::atomic::AtomicU32) };
+            let mut current = cell.load(Ordering::Relaxed);
+            loop {
+                let mut update = StUpdate::detached(self);
+                f(&mut update);
+                let merged = update.merge(current);
+                if update.mask == 0 {
+                    return;
+                }
+                match cell.compare_exchange_weak(
+                    current,
+                    merged,
+                    Ordering::SeqCst,
+                    Ordering::Relaxed,
+                ) {
+                    Ok(_) => return,
+                    Err(actual) => current = actual,
+                }
+            }
+        }
+        #[doc = "Create a getter representing the current state of the register"]
+        #[inline(always)]
+        pub fn get(&self) -> StGet {
+            StGet::new(self)
+        }
+        #[doc = "Spin until the bit is set"]
+        #[inline]
+        pub fn wait_moscxts(&self) {
+            while !self.get().moscxts() {}
+        }
+        #[doc = "Poll until the bit is set, at most `max_polls` times"]
+        #[inline]
+        pub fn wait_moscxts_timeout(
+            &self,
+            max_polls: u32,
+        ) -> Result<(), ::volatile_cell::wait::Timeout> {
+            ::volatile_cell::wait::poll_until(|| self.get().moscxts(), max_polls)
+        }
+        #[doc = "Future resolving once the bit is set"]
+        #[inline]
+        pub fn wait_moscxts_async<'a>(
+            &'a self,
+        ) -> ::volatile_cell::wait::WaitUntil<'a, impl FnMut() -> bool + Unpin + 'a> {
+            ::volatile_cell::wait::WaitUntil::new(move || self.get().moscxts())
+        }
+        #[doc = "Spin until the bit is set"]
+        #[inline]
+        pub fn wait_locka(&self) {
+            while !self.get().locka() {}
+        }
+        #[doc = "Poll until the bit is set, at most `max_polls` times"]
+        #[inline]
+        pub fn wait_locka_timeout(
+            &self,
+            max_polls: u32,
+        ) -> Result<(), ::volatile_cell::wait::Timeout> {
+            ::volatile_cell::wait::poll_until(|| self.get().locka(), max_polls)
+        }
+        #[doc = "Future resolving once the bit is set"]
+        #[inline]
+        pub fn wait_locka_async<'a>(
+            &'a self,
+        ) -> ::volatile_cell::wait::WaitUntil<'a, impl FnMut() -> bool + Unpin + 'a> {
+            ::volatile_cell::wait::WaitUntil::new(move || self.get().locka())
+        }
+        #[doc = "Spin until the bit is set"]
+        #[inline]
+        pub fn wait_mckrdy(&self) {
+            while !self.get().mckrdy() {}
+        }
+        #[doc = "Poll until the bit is set, at most `max_polls` times"]
+        #[inline]
+        pub fn wait_mckrdy_timeout(
+            &self,
+            max_polls: u32,
+        ) -> Result<(), ::volatile_cell::wait::Timeout> {
+            ::volatile_cell::wait::poll_until(|| self.get().mckrdy(), max_polls)
+        }
+        #[doc = "Future resolving once the bit is set"]
+        #[inline]
+        pub fn wait_mckrdy_async<'a>(
+            &'a self,
+        ) -> ::volatile_cell::wait::WaitUntil<'a, impl FnMut() -> bool + Unpin + 'a> {
+            ::volatile_cell::wait::WaitUntil::new(move || self.get().mckrdy())
+        }
+        #[doc = "Spin until the bit is set"]
+        #[inline]
+        pub fn wait_moscsels(&self) {
+            while !self.get().moscsels() {}
+        }
+        #[doc = "Poll until the bit is set, at most `max_polls` times"]
+        #[inline]
+        pub fn wait_moscsels_timeout(
+            &self,
+            max_polls: u32,
+        ) -> Result<(), ::volatile_cell::wait::Timeout> {
+            ::volatile_cell::wait::poll_until(|| self.get().moscsels(), max_polls)
+        }
+        #[doc = "Future resolving once the bit is set"]
+        #[inline]
+        pub fn wait_moscsels_async<'a>(
+            &'a self,
+        ) -> ::volatile_cell::wait::WaitUntil<'a, impl FnMut() -> bool + Unpin + 'a> {
+            ::volatile_cell::wait::WaitUntil::new(move || self.get().moscsels())
+        }
+        #[doc = "Spin until the bit is set"]
+        #[inline]
+        pub fn wait_moscrcs(&self) {
+            while !self.get().moscrcs() {}
+        }
+        #[doc = "Poll until the bit is set, at most `max_polls` times"]
+        #[inline]
+        pub fn wait_moscrcs_timeout(
+            &self,
+            max_polls: u32,
+        ) -> Result<(), ::volatile_cell::wait::Timeout> {
+            ::volatile_cell::wait::poll_until(|| self.get().moscrcs(), max_polls)
+        }
+        #[doc = "Future resolving once the bit is set"]
+        #[inline]
+        pub fn wait_moscrcs_async<'a>(
+            &'a self,
+        ) -> ::volatile_cell::wait::WaitUntil<'a, impl FnMut() -> bool + Unpin + 'a> {
+            ::volatile_cell::wait::WaitUntil::new(move || self.get().moscrcs())
+        }
+    }
+    pub struct StUpdate<'a> {
+        value: u32,
+        mask: u32,
+        write_only: bool,
+        store_on_drop: bool,
+        reg: &'a St,
+    }
+    impl<'a> StUpdate<'a> {
+        #[inline(always)]
+        pub fn new(reg: &'a St) -> StUpdate<'a> {
+            StUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[inline(always)]
+        pub fn new_ignoring_state(reg: &'a St) -> StUpdate<'a> {
+            StUpdate {
+                value: 0,
+                mask: 0,
+                write_only: true,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[doc = "An update that is never stored, for the caller to store itself"]
+        #[inline(always)]
+        fn detached(reg: &'a St) -> StUpdate<'a> {
+            StUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: false,
+                reg: reg,
+            }
+        }
+        const fn clear_mask() -> u32 {
+            0u64 as u32
+        }
+        const fn update_offset(base: usize, size: usize, index: usize) -> usize {
+            base + (size * index)
+        }
+        #[inline(always)]
+        fn merge(&self, current: u32) -> u32 {
+            let v: u32 = current & (!Self::clear_mask()) & (!self.mask);
+            self.value | v
+        }
+    }
+    impl<'a> Drop for StUpdate<'a> {
+        #[inline(always)]
+        fn drop(&mut self) {
+            if self.store_on_drop && self.mask != 0 {
+                let current: u32 = if self.write_only { 0 } else { self.reg.load() };
+                self.reg.store(self.merge(current));
+            }
+        }
+    }
+    #[derive(Clone, PartialEq, Eq)]
+    pub struct StGet {
+        value: u32,
+    }
+    impl StGet {
+        #[doc = "Create a getter reflecting the current value of the register"]
+        #[inline(always)]
+        pub fn new(reg: &St) -> StGet {
+            StGet { value: reg.load() }
+        }
+        #[inline(always)]
+        pub fn moscxts(&self) -> bool {
+            let val = (self.value & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub fn locka(&self) -> bool {
+            let val = ((self.value >> 1) & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub fn mckrdy(&self) -> bool {
+            let val = ((self.value >> 3) & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub fn moscsels(&self) -> bool {
+            let val = ((self.value >> 16) & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub fn moscrcs(&self) -> bool {
+            let val = ((self.value >> 17) & 1);
+            val != 0x0
+        }
+    }
+    #[derive(Clone, Copy, PartialEq, Eq, Default)]
+    #[repr(transparent)]
+    pub struct StValue {
+        value: u32,
+    }
+    impl StValue {
+        #[doc = "Create a value with every field cleared"]
+        #[inline(always)]
+        pub const fn new() -> StValue {
+            StValue { value: 0 }
+        }
+        #[inline(always)]
+        pub const fn from_bits(bits: u32) -> StValue {
+            StValue { value: bits }
+        }
+        #[inline(always)]
+        pub const fn to_bits(&self) -> u32 {
+            self.value
+        }
+        #[inline(always)]
+        pub fn moscxts(&self) -> bool {
+            let val = (self.value & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub fn locka(&self) -> bool {
+            let val = ((self.value >> 1) & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub fn mckrdy(&self) -> bool {
+            let val = ((self.value >> 3) & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub fn moscsels(&self) -> bool {
+            let val = ((self.value >> 16) & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub fn moscrcs(&self) -> bool {
+            let val = ((self.value >> 17) & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub const fn with_moscxts(self, new_value: bool) -> Self {
+            let context_mask: u32 = 1 << 0;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 1) << 0),
+            }
+        }
+        #[inline(always)]
+        pub const fn with_locka(self, new_value: bool) -> Self {
+            let context_mask: u32 = 1 << 1;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 1) << 1),
+            }
+        }
+        #[inline(always)]
+        pub const fn with_mckrdy(self, new_value: bool) -> Self {
+            let context_mask: u32 = 1 << 3;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 1) << 3),
+            }
+        }
+        #[inline(always)]
+        pub const fn with_moscsels(self, new_value: bool) -> Self {
+            let context_mask: u32 = 1 << 16;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 1) << 16),
+            }
+        }
+        #[inline(always)]
+        pub const fn with_moscrcs(self, new_value: bool) -> Self {
+            let context_mask: u32 = 1 << 17;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 1) << 17),
+            }
+        }
+    }
+    impl From<StGet> for StValue {
+        #[inline(always)]
+        fn from(get: StGet) -> StValue {
+            StValue::from_bits(get.value)
+        }
+    }
+    impl From<StValue> for StGet {
+        #[inline(always)]
+        fn from(value: StValue) -> StGet {
+            StGet {
+                value: value.to_bits(),
+            }
+        }
+    }
+    impl St {
+        #[doc = "Write `value` to the register in a single store, without reading it first"]
+        #[inline(always)]
+        pub fn write(&self, value: StValue) {
+            self.store(value.to_bits());
+        }
+    }
+    impl<'a> StUpdate<'a> {
+        #[doc = "Replace every field of the register with those of `value`"]
+        #[inline(always)]
+        pub fn assign<'b>(&'b mut self, value: StValue) -> &'b mut Self {
+            self.value = value.to_bits();
+            self.mask = !0;
+            self
+        }
+    }
+    impl StGet {
+        pub const FIELDS: &'static [::volatile_cell::field::FieldDescriptor] = &[
+            ::volatile_cell::field::FieldDescriptor {
+                name: "moscxts",
+                offset: 0u32,
+                width: 1u32,
+                count: 1u32,
+            },
+            ::volatile_cell::field::FieldDescriptor {
+                name: "locka",
+                offset: 1u32,
+                width: 1u32,
+                count: 1u32,
+            },
+            ::volatile_cell::field::FieldDescriptor {
+                name: "mckrdy",
+                offset: 3u32,
+                width: 1u32,
+                count: 1u32,
+            },
+            ::volatile_cell::field::FieldDescriptor {
+                name: "moscsels",
+                offset: 16u32,
+                width: 1u32,
+                count: 1u32,
+            },
+            ::volatile_cell::field::FieldDescriptor {
+                name: "moscrcs",
+                offset: 17u32,
+                width: 1u32,
+                count: 1u32,
+            },
+        ];
+        #[doc = "Iterate over the readable fields that differ from those of `other`"]
+        #[inline]
+        pub fn changed_fields(&self, other: &StGet) -> ::volatile_cell::field::FieldChanges {
+            ::volatile_cell::field::FieldChanges::new(
+                Self::FIELDS,
+                self.value as u64,
+                other.value as u64,
+            )
+        }
+    }
+    #[repr(C)]
+    pub struct Pmc {
+        _padding0: [u8; 32],
+        pub mor: Mor,
+        pub mcfr: Mcfr,
+        pub pllar: Pllar,
+        _padding1: [u8; 4],
+        pub mckr: Mckr,
+        _padding2: [u8; 52],
+        pub st: St,
+    }
+    #[doc = "Saved state of every readable register without side effects"]
+    #[derive(Clone, Copy)]
+    pub struct Snapshot {
+        pub mor: MorValue,
+        pub mcfr: McfrValue,
+        pub pllar: PllarValue,
+        pub mckr: MckrValue,
+        pub st: StValue,
+    }
+    #[doc = "Read the current state of the peripheral"]
+    #[allow(unused_variables)]
+    pub fn capture(p: &Pmc) -> Snapshot {
+        Snapshot {
+            mor: MorValue::from(p.mor.get()),
+            mcfr: McfrValue::from(p.mcfr.get()),
+            pllar: PllarValue::from(p.pllar.get()),
+            mckr: MckrValue::from(p.mckr.get()),
+            st: StValue::from(p.st.get()),
+        }
+    }
+    #[doc = "Write a previously captured state back to the peripheral"]
+    #[allow(unused_variables)]
+    pub fn restore(p: &Pmc, snapshot: &Snapshot) {
+        MorUpdate::new_ignoring_state(&p.mor).assign(snapshot.mor);
+        PllarUpdate::new_ignoring_state(&p.pllar).assign(snapshot.pllar);
+        MckrUpdate::new_ignoring_state(&p.mckr).assign(snapshot.mckr);
+    }
+    #[doc = "Owned handle granting exclusive access to the peripheral"]
+    pub struct PmcPeripheral {
+        regs: &'static mut Pmc,
+    }
+    impl PmcPeripheral {
+        #[doc = "Create a token for the peripheral registers at `regs`."]
+        #[doc = ""]
+        #[doc = "Unsafe because the caller must ensure it's the only token for `regs`."]
+        #[inline(always)]
+        pub const unsafe fn new(regs: &'static mut Pmc) -> PmcPeripheral {
+            PmcPeripheral { regs: regs }
+        }
+    }
+    impl ::core::ops::Deref for PmcPeripheral {
+        type Target = Pmc;
+        #[inline(always)]
+        fn deref(&self) -> &Pmc {
+            self.regs
+        }
+    }
+    impl ::core::ops::DerefMut for PmcPeripheral {
+        #[inline(always)]
+        fn deref_mut(&mut self) -> &mut Pmc {
+            self.regs
+        }
+    }
+}
@@ -0,0 +1,498 @@
+pub mod wdc {
+    pub mod cr {}
+    #[repr(C)]
+    pub struct Cr {
+        value: ::volatile_cell::VolatileCell<u32>,
+    }
+    impl Cr {
+        #[inline(always)]
+        fn load(&self) -> u32 {
+            let value = self.value.get();
+            value
+        }
+        #[inline(always)]
+        fn store(&self, value: u32) {
+            self.value.set(value);
+        }
+        #[doc = "Create a new updater"]
+        #[inline(always)]
+        pub fn update<'a>(&'a self) -> CrUpdate<'a> {
+            CrUpdate::new(self)
+        }
+        #[doc = "Create an updater whose read-modify-write happens inside the critical section `cs`"]
+        #[inline(always)]
+        pub fn update_in<'a, C: ::volatile_cell::sync::CriticalSection>(
+            &'a self,
+            _cs: &'a C,
+        ) -> CrUpdate<'a> {
+            CrUpdate::new(self)
+        }
+        #[doc = "Create a getter representing the current state of the register"]
+        #[inline(always)]
+        pub fn get(&self) -> CrGet {
+            CrGet::new(self)
+        }
+    }
+    pub struct CrUpdate<'a> {
+        value: u32,
+        mask: u32,
+        write_only: bool,
+        store_on_drop: bool,
+        reg: &'a Cr,
+    }
+    impl<'a> CrUpdate<'a> {
+        #[inline(always)]
+        pub fn new(reg: &'a Cr) -> CrUpdate<'a> {
+            CrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[inline(always)]
+        pub fn new_ignoring_state(reg: &'a Cr) -> CrUpdate<'a> {
+            CrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: true,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[doc = "An update that is never stored, for the caller to store itself"]
+        #[inline(always)]
+        fn detached(reg: &'a Cr) -> CrUpdate<'a> {
+            CrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: false,
+                reg: reg,
+            }
+        }
+        const fn clear_mask() -> u32 {
+            0u64 as u32
+        }
+        const fn update_offset(base: usize, size: usize, index: usize) -> usize {
+            base + (size * index)
+        }
+        #[inline(always)]
+        fn merge(&self, current: u32) -> u32 {
+            let v: u32 = current & (!Self::clear_mask()) & (!self.mask);
+            self.value | v
+        }
+        #[inline(always)]
+        pub fn set_wdrstt<'b>(&'b mut self, new_value: bool) -> &'b mut Self {
+            let context_mask: u32 = 1 << 0;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 1) << 0);
+            self.mask |= context_mask;
+            self
+        }
+        #[inline(always)]
+        pub fn set_key<'b>(&'b mut self, new_value: u8) -> &'b mut Self {
+            let context_mask: u32 = 255 << 24;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 255) << 24);
+            self.mask |= context_mask;
+            self
+        }
+    }
+    impl<'a> Drop for CrUpdate<'a> {
+        #[inline(always)]
+        fn drop(&mut self) {
+            if self.store_on_drop && self.mask != 0 {
+                let current: u32 = 0;
+                self.reg.store(self.merge(current));
+            }
+        }
+    }
+    #[derive(Clone, PartialEq, Eq)]
+    pub struct CrGet {
+        value: u32,
+    }
+    impl CrGet {
+        #[doc = "Create a getter reflecting the current value of the register"]
+        #[inline(always)]
+        pub fn new(reg: &Cr) -> CrGet {
+            CrGet { value: reg.load() }
+        }
+    }
+    #[derive(Clone, Copy, PartialEq, Eq, Default)]
+    #[repr(transparent)]
+    pub struct CrValue {
+        value: u32,
+    }
+    impl CrValue {
+        #[doc = "Create a value with every field cleared"]
+        #[inline(always)]
+        pub const fn new() -> CrValue {
+            CrValue { value: 0 }
+        }
+        #[inline(always)]
+        pub const fn from_bits(bits: u32) -> CrValue {
+            CrValue { value: bits }
+        }
+        #[inline(always)]
+        pub const fn to_bits(&self) -> u32 {
+            self.value
+        }
+        #[inline(always)]
+        pub fn wdrstt(&self) -> bool {
+            let val = (self.value & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub fn key(&self) -> u32 {
+            (self.value >> 24)
+        }
+        #[inline(always)]
+        pub const fn with_wdrstt(self, new_value: bool) -> Self {
+            let context_mask: u32 = 1 << 0;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 1) << 0),
+            }
+        }
+        #[inline(always)]
+        pub const fn with_key(self, new_value: u8) -> Self {
+            let context_mask: u32 = 255 << 24;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 255) << 24),
+            }
+        }
+    }
+    impl From<CrGet> for CrValue {
+        #[inline(always)]
+        fn from(get: CrGet) -> CrValue {
+            CrValue::from_bits(get.value)
+        }
+    }
+    impl From<CrValue> for CrGet {
+        #[inline(always)]
+        fn from(value: CrValue) -> CrGet {
+            CrGet {
+                value: value.to_bits(),
+            }
+        }
+    }
+    impl Cr {
+        #[doc = "Write `value` to the register in a single store, without reading it first"]
+        #[inline(always)]
+        pub fn write(&self, value: CrValue) {
+            self.store(value.to_bits());
+        }
+    }
+    impl<'a> CrUpdate<'a> {
+        #[doc = "Replace every field of the register with those of `value`"]
+        #[inline(always)]
+        pub fn assign<'b>(&'b mut self, value: CrValue) -> &'b mut Self {
+            self.value = value.to_bits();
+            self.mask = !0;
+            self
+        }
+    }
+    impl CrGet {
+        pub const FIELDS: &'static [::volatile_cell::field::FieldDescriptor] = &[];
+        #[doc = "Iterate over the readable fields that differ from those of `other`"]
+        #[inline]
+        pub fn changed_fields(&self, other: &CrGet) -> ::volatile_cell::field::FieldChanges {
+            ::volatile_cell::field::FieldChanges::new(
+                Self::FIELDS,
+                self.value as u64,
+                other.value as u64,
+            )
+        }
+    }
+    pub mod mr {}
+    #[repr(C)]
+    pub struct Mr {
+        value: ::volatile_cell::VolatileCell<u32>,
+    }
+    impl Mr {
+        #[inline(always)]
+        fn load(&self) -> u32 {
+            let value = self.value.get();
+            value
+        }
+        #[inline(always)]
+        fn store(&self, value: u32) {
+            self.value.set(value);
+        }
+        #[doc = "Create a new updater"]
+        #[inline(always)]
+        pub fn update<'a>(&'a self) -> MrUpdate<'a> {
+            MrUpdate::new(self)
+        }
+        #[doc = "Create an updater whose read-modify-write happens inside the critical section `cs`"]
+        #[inline(always)]
+        pub fn update_in<'a, C: ::volatile_cell::sync::CriticalSection>(
+            &'a self,
+            _cs: &'a C,
+        ) -> MrUpdate<'a> {
+            MrUpdate::new(self)
+        }
+        #[doc = "Update the register with exclusive loads and stores (LDREX/STREX), retrying `f` until no other access intervened"]
+        #[doc = ""]
+        #[doc = "The exclusive accesses go to the register's memory directly rather than through its cell, so they aren't seen by the replayer or the tracing hook."]
+        #[cfg(target_has_atomic = "32")]
+        #[inline]
+        pub fn update_exclusive<F: FnMut(&mut MrUpdate)>(&self, mut f: F) {
+            use ::core::sync::atomic::Ordering;
+            let cell = unsafe { &*(self.value.as_ptr() as *const ::core::sync::atomic::AtomicU32) };
+            let mut current = cell.load(Ordering::Relaxed);
+            loop {
+                let mut update = MrUpdate::detached(self);
+                f(&mut update);
+                let merged = update.merge(current);
+                if update.mask == 0 {
+                    return;
+                }
+                match cell.compare_exchange_weak(
+                    current,
+                    merged,
+                    Ordering::SeqCst,
+                    Ordering::Relaxed,
+                ) {
+                    Ok(_) => return,
+                    Err(actual) => current = actual,
+                }
+            }
+        }
+        #[doc = "Create a getter representing the current state of the register"]
+        #[inline(always)]
+        pub fn get(&self) -> MrGet {
+            MrGet::new(self)
+        }
+        #[doc = "Spin until the bit is set"]
+        #[inline]
+        pub fn wait_wddis(&self) {
+            while !self.get().wddis() {}
+        }
+        #[doc = "Poll until the bit is set, at most `max_polls` times"]
+        #[inline]
+        pub fn wait_wddis_timeout(
+            &self,
+            max_polls: u32,
+        ) -> Result<(), ::volatile_cell::wait::Timeout> {
+            ::volatile_cell::wait::poll_until(|| self.get().wddis(), max_polls)
+        }
+        #[doc = "Future resolving once the bit is set"]
+        #[inline]
+        pub fn wait_wddis_async<'a>(
+            &'a self,
+        ) -> ::volatile_cell::wait::WaitUntil<'a, impl FnMut() -> bool + Unpin + 'a> {
+            ::volatile_cell::wait::WaitUntil::new(move || self.get().wddis())
+        }
+    }
+    pub struct MrUpdate<'a> {
+        value: u32,
+        mask: u32,
+        write_only: bool,
+        store_on_drop: bool,
+        reg: &'a Mr,
+    }
+    impl<'a> MrUpdate<'a> {
+        #[inline(always)]
+        pub fn new(reg: &'a Mr) -> MrUpdate<'a> {
+            MrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[inline(always)]
+        pub fn new_ignoring_state(reg: &'a Mr) -> MrUpdate<'a> {
+            MrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: true,
+                store_on_drop: true,
+                reg: reg,
+            }
+        }
+        #[doc = "An update that is never stored, for the caller to store itself"]
+        #[inline(always)]
+        fn detached(reg: &'a Mr) -> MrUpdate<'a> {
+            MrUpdate {
+                value: 0,
+                mask: 0,
+                write_only: false,
+                store_on_drop: false,
+                reg: reg,
+            }
+        }
+        const fn clear_mask() -> u32 {
+            0u64 as u32
+        }
+        const fn update_offset(base: usize, size: usize, index: usize) -> usize {
+            base + (size * index)
+        }
+        #[inline(always)]
+        fn merge(&self, current: u32) -> u32 {
+            let v: u32 = current & (!Self::clear_mask()) & (!self.mask);
+            self.value | v
+        }
+        #[inline(always)]
+        pub fn set_wddis<'b>(&'b mut self, new_value: bool) -> &'b mut Self {
+            let context_mask: u32 = 1 << 15;
+            self.value = (self.value & !context_mask) | (((new_value as u32) & 1) << 15);
+            self.mask |= context_mask;
+            self
+        }
+    }
+    impl<'a> Drop for MrUpdate<'a> {
+        #[inline(always)]
+        fn drop(&mut self) {
+            if self.store_on_drop && self.mask != 0 {
+                let current: u32 = if self.write_only { 0 } else { self.reg.load() };
+                self.reg.store(self.merge(current));
+            }
+        }
+    }
+    #[derive(Clone, PartialEq, Eq)]
+    pub struct MrGet {
+        value: u32,
+    }
+    impl MrGet {
+        #[doc = "Create a getter reflecting the current value of the register"]
+        #[inline(always)]
+        pub fn new(reg: &Mr) -> MrGet {
+            MrGet { value: reg.load() }
+        }
+        #[inline(always)]
+        pub fn wddis(&self) -> bool {
+            let val = ((self.value >> 15) & 1);
+            val != 0x0
+        }
+    }
+    #[derive(Clone, Copy, PartialEq, Eq, Default)]
+    #[repr(transparent)]
+    pub struct MrValue {
+        value: u32,
+    }
+    impl MrValue {
+        #[doc = "Create a value with every field cleared"]
+        #[inline(always)]
+        pub const fn new() -> MrValue {
+            MrValue { value: 0 }
+        }
+        #[inline(always)]
+        pub const fn from_bits(bits: u32) -> MrValue {
+            MrValue { value: bits }
+        }
+        #[inline(always)]
+        pub const fn to_bits(&self) -> u32 {
+            self.value
+        }
+        #[inline(always)]
+        pub fn wddis(&self) -> bool {
+            let val = ((self.value >> 15) & 1);
+            val != 0x0
+        }
+        #[inline(always)]
+        pub const fn with_wddis(self, new_value: bool) -> Self {
+            let context_mask: u32 = 1 << 15;
+            Self {
+                value: (self.value & !context_mask) | (((new_value as u32) & 1) << 15),
+            }
+        }
+    }
+    impl From<MrGet> for MrValue {
+        #[inline(always)]
+        fn from(get: MrGet) -> MrValue {
+            MrValue::from_bits(get.value)
+        }
+    }
+    impl From<MrValue> for MrGet {
+        #[inline(always)]
+        fn from(value: MrValue) -> MrGet {
+            MrGet {
+                value: value.to_bits(),
+            }
+        }
+    }
+    impl Mr {
+        #[doc = "Write `value` to the register in a single store, without reading it first"]
+        #[inline(always)]
+        pub fn write(&self, value: MrValue) {
+            self.store(value.to_bits());
+        }
+    }
+    impl<'a> MrUpdate<'a> {
+        #[doc = "Replace every field of the register with those of `value`"]
+        #[inline(always)]
+        pub fn assign<'b>(&'b mut self, value: MrValue) -> &'b mut Self {
+            self.value = value.to_bits();
+            self.mask = !0;
+            self
+        }
+    }
+    impl MrGet {
+        pub const FIELDS: &'static [::volatile_cell::field::FieldDescriptor] =
+            &[::volatile_cell::field::FieldDescriptor {
+                name: "wddis",
+                offset: 15u32,
+                width: 1u32,
+                count: 1u32,
+            }];
+        #[doc = "Iterate over the readable fields that differ from those of `other`"]
+        #[inline]
+        pub fn changed_fields(&self, other: &MrGet) -> ::volatile_cell::field::FieldChanges {
+            ::volatile_cell::field::FieldChanges::new(
+                Self::FIELDS,
+                self.value as u64,
+                other.value as u64,
+            )
+        }
+    }
+    #[repr(C)]
+    pub struct Wdc {
+        pub cr: Cr,
+        pub mr: Mr,
+    }
+    #[doc = "Saved state of every readable register without side effects"]
+    #[derive(Clone, Copy)]
+    pub struct Snapshot {
+        pub mr: MrValue,
+    }
+    #[doc = "Read the current state of the peripheral"]
+    #[allow(unused_variables)]
+    pub fn capture(p: &Wdc) -> Snapshot {
+        Snapshot {
+            mr: MrValue::from(p.mr.get()),
+        }
+    }
+    #[doc = "Write a previously captured state back to the peripheral"]
+    #[allow(unused_variables)]
+    pub fn restore(p: &Wdc, snapshot: &Snapshot) {
+        MrUpdate::new_ignoring_state(&p.mr).assign(snapshot.mr);
+    }
+    #[doc = "Owned handle granting exclusive access to the peripheral"]
+    pub struct WdcPeripheral {
+        regs: &'static mut Wdc,
+    }
+    impl WdcPeripheral {
+        #[doc = "Create a token for the peripheral registers at `regs`."]
+        #[doc = ""]
+        #[doc = "Unsafe because the caller must ensure it's the only token for `regs`."]
+        #[inline(always)]
+        pub const unsafe fn new(regs: &'static mut Wdc) -> WdcPeripheral {
+            WdcPeripheral { regs: regs }
+        }
+    }
+    impl ::core::ops::Deref for WdcPeripheral {
+        type Target = Wdc;
+        #[inline(always)]
+        fn deref(&self) -> &Wdc {
+            self.regs
+        }
+    }
+    impl ::core::ops::DerefMut for WdcPeripheral {
+        #[inline(always)]
+        fn deref_mut(&mut self) -> &mut Wdc {
+            self.regs
+        }
+    }
+}
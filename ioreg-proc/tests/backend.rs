@@ -0,0 +1,150 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use volatile_cell::backend::{Mmio, RegisterBackend};
+
+ioreg_proc::ioregs!(EXPANDER = {
+    0x0 => reg8 input {
+        0..7   => pin[8]: ro,
+    },
+    0x1 => reg8 output {
+        0..7   => pin[8],
+    },
+    0x2 => reg16 config {
+        0..3   => mode {
+            0 => Idle,
+            5 => Active,
+        },
+        8      => irq,
+    },
+    0x4 => reg24 stamp[2] {
+        0..23  => ticks,
+    },
+    0x10 => group chan[2] {
+        0x0 => reg8 level {
+            0..7 => value,
+        },
+        0x1 => reg8 flags {
+            0 => overrun: set_to_clear,
+        },
+    },
+    0x14 => interrupts reg8 alert {
+        0 => Low,
+        1 => High,
+    },
+}: backend);
+
+#[derive(Default)]
+struct Sim {
+    registers: RefCell<HashMap<usize, u64>>,
+    log: RefCell<Vec<(&'static str, usize, u32, u64)>>,
+}
+
+impl RegisterBackend for Sim {
+    fn read(&self, offset: usize, width: u32) -> u64 {
+        let value = *self.registers.borrow().get(&offset).unwrap_or(&0);
+        self.log.borrow_mut().push(("read", offset, width, value));
+        value
+    }
+
+    fn write(&self, offset: usize, width: u32, value: u64) {
+        self.registers.borrow_mut().insert(offset, value);
+        self.log.borrow_mut().push(("write", offset, width, value));
+    }
+}
+
+#[test]
+fn accesses_go_through_backend() {
+    let sim = Sim::default();
+    sim.registers.borrow_mut().insert(0x0, 0b1010_0000);
+    let expander = expander::Expander::new(&sim);
+
+    assert!(expander.input.get().pin(5));
+    expander.config.update().set_mode(expander::config::Mode::Active).set_irq(true);
+    expander.stamp[1].update().set_ticks(0xabcdef);
+    expander.chan[1].level.update().set_value(0x7f);
+    expander.chan[0].flags.update().clear_overrun();
+
+    assert_eq!(expander.config.get().mode(), expander::config::Mode::Active);
+    assert_eq!(*sim.log.borrow(), vec![
+        ("read", 0x0, 8, 0b1010_0000),
+        ("read", 0x2, 16, 0),
+        ("write", 0x2, 16, 0x105),
+        ("read", 0x7, 24, 0),
+        ("write", 0x7, 24, 0xabcdef),
+        ("read", 0x12, 8, 0),
+        ("write", 0x12, 8, 0x7f),
+        ("read", 0x11, 8, 0),
+        ("write", 0x11, 8, 0x1),
+        ("read", 0x2, 16, 0x105),
+    ]);
+}
+
+#[test]
+fn mmio_backend_matches_memory() {
+    let mut memory = [0u32; 8];
+    let expander = expander::Expander::new(unsafe { Mmio::new(memory.as_mut_ptr() as usize) });
+    expander.output.write(expander::OutputValue::new().with_pin(3, true));
+    expander.stamp[0].update().set_ticks(0x123456);
+    let bytes: Vec<u8> = memory.iter().flat_map(|w| w.to_le_bytes().to_vec()).collect();
+    assert_eq!(bytes[1], 0x08);
+    assert_eq!(&bytes[4..7], &[0x56, 0x34, 0x12]);
+    assert_eq!(expander.stamp[0].get().ticks(), 0x123456);
+}
+
+#[test]
+fn interrupt_family_through_backend() {
+    use expander::alert::AlertFlags;
+    let sim = Sim::default();
+    sim.registers.borrow_mut().insert(0x17, 0x3);
+    let expander = expander::Expander::new(&sim);
+    expander.alert.enable(AlertFlags::High);
+    expander.alert.disable(AlertFlags::Low);
+    assert_eq!(expander.alert.status(), AlertFlags::Low | AlertFlags::High);
+    assert_eq!(*sim.log.borrow(), vec![
+        ("write", 0x14, 8, 0x2),
+        ("write", 0x15, 8, 0x1),
+        ("read", 0x17, 8, 0x3),
+    ]);
+}
+
+ioreg_proc::ioregs!(SENSOR = {
+    0x0 => reg16 id {
+        0..15  => value: ro,
+    },
+    0x2 => reg32 limit {
+        0..31  => value,
+    },
+}: (backend, endian = big));
+
+#[test]
+fn big_endian_backend_swaps_bytes() {
+    let sim = Sim::default();
+    sim.registers.borrow_mut().insert(0x0, 0x3412);
+    let sensor = sensor::Sensor::new(&sim);
+    assert_eq!(sensor.id.get().value(), 0x1234);
+    sensor.limit.write(sensor::LimitValue::new().with_value(0x1122_3344));
+    assert_eq!(sim.registers.borrow()[&0x2], 0x4433_2211);
+    assert_eq!(sensor.limit.get().value(), 0x1122_3344);
+}
+
+#[test]
+fn snapshot_through_backend() {
+    let sim = Sim::default();
+    let expander = expander::Expander::new(&sim);
+    expander.config.update().set_mode(expander::config::Mode::Active);
+    expander.chan[1].level.update().set_value(0x42);
+    let saved = expander::capture(&expander);
+    expander.config.update().set_mode(expander::config::Mode::Idle);
+    expander.chan[1].level.update().set_value(0);
+    expander::restore(&expander, &saved);
+    assert_eq!(expander.config.get().mode(), expander::config::Mode::Active);
+    assert_eq!(expander.chan[1].level.get().value(), 0x42);
+}
+
+#[test]
+fn token_through_backend() {
+    static MEMORY: [u32; 2] = [0; 2];
+    let regs: &'static mut sensor::Sensor<Mmio> = Box::leak(Box::new(sensor::Sensor::new(unsafe { Mmio::new(MEMORY.as_ptr() as usize) })));
+    let token = unsafe { sensor::SensorPeripheral::new(regs) };
+    assert_eq!(token.id.get().value(), 0);
+}
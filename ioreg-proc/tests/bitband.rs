@@ -0,0 +1,57 @@
+use std::mem;
+
+ioreg_proc::ioregs!(BITBAND_TEST @ 0x400E0600 = {
+    0x0 => reg32 ctrl {
+        0      => enable,
+        1..3   => mode,
+        4..7   => chan[4],
+        8      => busy: ro,
+        9      => start: wo,
+    },
+    0x4 => reg32 status {
+        0      => ready: ro,
+        1      => overrun: set_to_clear,
+    },
+});
+
+#[test]
+fn alias_addresses_follow_register_bits() {
+    let test: bitband_test::BitbandTest = unsafe { mem::zeroed() };
+    let bit0 = test.ctrl.bitband_alias(0) as usize;
+    assert_eq!(test.ctrl.bitband_alias(3) as usize - bit0, 3 * 4);
+    assert_eq!(test.ctrl.bitband_alias(31) as usize - bit0, 31 * 4);
+    assert_eq!(test.status.bitband_alias(0) as usize - bit0, 4 * 32);
+}
+
+#[test]
+fn alias_of_base_address() {
+    let ctrl = 0x400E0600usize as *const bitband_test::Ctrl;
+    let alias = unsafe { (*ctrl).bitband_alias(4) } as usize;
+    assert_eq!(alias, 0x4200_0000 + (0xE0600 * 32) + 4 * 4);
+}
+
+#[test]
+#[should_panic]
+fn counted_accessors_check_index() {
+    let test: bitband_test::BitbandTest = unsafe { mem::zeroed() };
+    // Checked before the alias of bit 8, busy, would be written
+    test.ctrl.bitband_set_chan(4);
+}
+
+#[test]
+#[should_panic]
+fn counted_reads_check_index() {
+    let test: bitband_test::BitbandTest = unsafe { mem::zeroed() };
+    let _ = test.ctrl.bitband_read_chan(4);
+}
+
+#[allow(dead_code)]
+fn accessors_exist(test: &bitband_test::BitbandTest) {
+    test.ctrl.bitband_set_enable();
+    test.ctrl.bitband_clear_enable();
+    let _ = test.ctrl.bitband_read_enable();
+    test.ctrl.bitband_set_chan(2);
+    let _ = test.ctrl.bitband_read_chan(2);
+    let _ = test.ctrl.bitband_read_busy();
+    let _ = test.status.bitband_read_overrun();
+}
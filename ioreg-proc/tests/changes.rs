@@ -0,0 +1,43 @@
+use std::mem;
+use volatile_cell::field::FieldChange;
+
+ioreg_proc::ioregs!(CHANGES_TEST = {
+    0x0 => reg32 mckr {
+        0..1   => css {
+            0 => SlowClk,
+            1 => MainClk,
+            2 => PllaClk,
+            3 => UpplClk,
+        },
+        4..6   => pres,
+        8..11  => lane[4],
+        16..23 => key: wo,
+    },
+});
+
+#[test]
+fn equal_readings_compare_equal() {
+    let test: changes_test::ChangesTest = unsafe { mem::zeroed() };
+    let before = test.mckr.get();
+    assert!(before == test.mckr.get());
+    assert_eq!(before.changed_fields(&test.mckr.get()).count(), 0);
+    test.mckr.update().set_pres(0x1);
+    assert!(before != test.mckr.get());
+}
+
+#[test]
+fn changed_fields_are_reported() {
+    use changes_test::mckr::Css;
+    let test: changes_test::ChangesTest = unsafe { mem::zeroed() };
+    let before = test.mckr.get();
+    test.mckr.update()
+        .set_css(Css::PllaClk)
+        .set_lane(2, true)
+        .set_key(0x37);
+    let after = test.mckr.get();
+    let changes: Vec<FieldChange> = before.changed_fields(&after).collect();
+    assert_eq!(changes, vec![
+        FieldChange { name: "css", index: None, old: 0, new: 2 },
+        FieldChange { name: "lane", index: Some(2), old: 0, new: 1 },
+    ]);
+}
@@ -0,0 +1,184 @@
+//! Checks the code generated for register accesses by building the fixture
+//! crate in `tests/codegen` and inspecting its LLVM IR and assembly, for the
+//! host and `thumbv7m-none-eabi`. The latter needs its standard library
+//! (`rustup target add thumbv7m-none-eabi`), and is skipped with a note when
+//! it isn't installed. Set `IOREG_REQUIRE_THUMBV7M` to fail instead, as a CI
+//! job that installs the target should.
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+const ARM_TARGET: &str = "thumbv7m-none-eabi";
+
+/// Set to fail rather than skip the checks of `ARM_TARGET` without it.
+const REQUIRE_ARM_VAR: &str = "IOREG_REQUIRE_THUMBV7M";
+
+struct Output {
+    ir: String,
+    asm: String,
+}
+
+impl Output {
+    /// The body of the IR definition of `name`.
+    fn ir_fn(&self, name: &str) -> &str {
+        function_body(&self.ir, &format!("@{}(", name), "\n}")
+    }
+
+    /// The instructions of `name` in the assembly.
+    fn asm_fn(&self, name: &str) -> &str {
+        function_body(&self.asm, &format!("\n{}:", name), ".Lfunc_end")
+    }
+}
+
+fn function_body<'a>(source: &'a str, start: &str, end: &str) -> &'a str {
+    let start = source.find(start).unwrap_or_else(|| panic!("{} not found", start));
+    let body = &source[start..];
+    &body[..body.find(end).unwrap_or(body.len())]
+}
+
+fn target_installed(target: &str) -> bool {
+    let output = Command::new("rustc")
+        .args(&["--print", "sysroot"])
+        .output()
+        .expect("failed to run rustc");
+    let sysroot = String::from_utf8(output.stdout).unwrap();
+    Path::new(sysroot.trim()).join("lib/rustlib").join(target).exists()
+}
+
+fn newest_with_extension(dir: &Path, extension: &str) -> PathBuf {
+    fs::read_dir(dir)
+        .unwrap()
+        .filter_map(|e| e.ok())
+        .map(|e| e.path())
+        .filter(|p| {
+            p.extension().map_or(false, |e| e == extension)
+                && p.file_name().unwrap().to_string_lossy().starts_with("ioreg_codegen_fixture")
+        })
+        .max_by_key(|p| fs::metadata(p).and_then(|m| m.modified()).unwrap())
+        .unwrap_or_else(|| panic!("no .{} output in {}", extension, dir.display()))
+}
+
+/// Where to build the fixture: a directory of its own in the target directory
+/// this test was built in, so that `CARGO_TARGET_DIR` and `build.target-dir`
+/// are followed.
+fn fixture_target_dir() -> PathBuf {
+    let exe = env::current_exe().expect("failed to find the test executable");
+    // <target dir>/debug/deps/codegen-<hash>
+    exe.ancestors()
+        .nth(3)
+        .expect("unexpected test executable path")
+        .join("codegen")
+}
+
+/// Builds the fixture for `target`, or the host.
+fn build(target: Option<&str>) -> Output {
+    if let Some(target) = target {
+        assert!(target_installed(target), "target {} isn't installed, see `rustup target add`", target);
+    }
+    let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let target_dir = fixture_target_dir();
+    let mut command = Command::new(env!("CARGO"));
+    command.arg("rustc")
+        .arg("--release")
+        .arg("--manifest-path").arg(manifest_dir.join("tests/codegen/Cargo.toml"))
+        .arg("--target-dir").arg(&target_dir);
+    if let Some(target) = target {
+        command.arg("--target").arg(target);
+    }
+    command.args(&["--", "--emit=llvm-ir,asm", "-C", "codegen-units=1"]);
+    let status = command.status().expect("failed to run cargo");
+    assert!(status.success(), "building the codegen fixture failed");
+    let deps = match target {
+        Some(target) => target_dir.join(target).join("release/deps"),
+        None => target_dir.join("release/deps"),
+    };
+    Output {
+        ir: fs::read_to_string(newest_with_extension(&deps, "ll")).unwrap(),
+        asm: fs::read_to_string(newest_with_extension(&deps, "s")).unwrap(),
+    }
+}
+
+/// Volatile loads and stores in the IR of `name`, checking that it doesn't
+/// call anything or contain unreachable (panic or bounds check) paths.
+fn volatile_accesses(output: &Output, name: &str) -> (usize, usize) {
+    let body = output.ir_fn(name);
+    assert!(!body.contains("call ") && !body.contains("invoke "), "{} calls out:\n{}", name, body);
+    assert!(!body.contains("unreachable"), "{} can panic:\n{}", name, body);
+    (body.matches("load volatile").count(), body.matches("store volatile").count())
+}
+
+fn check_ir(output: &Output) {
+    for name in &["fixture_get_enable", "fixture_get_mode", "fixture_get_top", "fixture_get_lane", "fixture_get_parity", "fixture_get_two"] {
+        assert_eq!(volatile_accesses(output, name), (1, 0), "{}", name);
+    }
+    // The topmost field only needs a shift
+    let top = output.ir_fn("fixture_get_top");
+    assert!(top.contains("lshr"), "{}", top);
+    assert!(!top.contains(" and "), "{}", top);
+
+    // Read-modify-write, however many fields are set
+    for name in &["fixture_update_two", "fixture_update_const", "fixture_update_lane_const", "fixture_update_set_to_clear", "fixture_clear"] {
+        assert_eq!(volatile_accesses(output, name), (1, 1), "{}", name);
+    }
+    // Nothing to preserve
+    for name in &["fixture_update_write_only", "fixture_write_value"] {
+        assert_eq!(volatile_accesses(output, name), (0, 1), "{}", name);
+    }
+    // Constant values are folded into a single store
+    let write = output.ir_fn("fixture_write_value");
+    assert!(write.contains("store volatile i32 16390"), "{}", write);
+}
+
+/// Lines of `body` that are instructions, without labels and directives.
+fn instructions(body: &str) -> Vec<&str> {
+    body.lines()
+        .skip(1)
+        .map(str::trim)
+        .filter(|l| !l.is_empty() && !l.starts_with('.') && !l.starts_with('@') && !l.starts_with('#') && !l.ends_with(':'))
+        .collect()
+}
+
+fn count_mnemonic(instructions: &[&str], prefix: &str) -> usize {
+    instructions.iter()
+        .filter(|i| i.split_whitespace().next().map_or(false, |m| m.starts_with(prefix)))
+        .count()
+}
+
+#[test]
+fn host_accesses() {
+    let output = build(None);
+    check_ir(&output);
+    assert!(!output.asm.contains("bextr"));
+    for name in &["fixture_update_two", "fixture_update_const", "fixture_write_value"] {
+        let body = output.asm_fn(name);
+        assert!(!body.contains("call"), "{}:\n{}", name, body);
+    }
+}
+
+#[test]
+fn thumbv7m_accesses() {
+    if !target_installed(ARM_TARGET) {
+        if env::var_os(REQUIRE_ARM_VAR).is_some() {
+            panic!("target {} isn't installed but {} is set, see `rustup target add`", ARM_TARGET, REQUIRE_ARM_VAR);
+        }
+        eprintln!("skipping thumbv7m_accesses: target {} isn't installed", ARM_TARGET);
+        return;
+    }
+    let output = build(Some(ARM_TARGET));
+    check_ir(&output);
+    let mode = instructions(output.asm_fn("fixture_get_mode"));
+    assert!(count_mnemonic(&mode, "ubfx") == 1, "{:?}", mode);
+    // One LDR/BIC/ORR/STR sequence
+    for name in &["fixture_update_two", "fixture_update_const", "fixture_update_set_to_clear"] {
+        let body = instructions(output.asm_fn(name));
+        assert_eq!(count_mnemonic(&body, "ldr"), 1, "{}: {:?}", name, body);
+        assert_eq!(count_mnemonic(&body, "str"), 1, "{}: {:?}", name, body);
+        assert_eq!(count_mnemonic(&body, "bl"), 0, "{}: {:?}", name, body);
+        assert!(body.len() <= 8, "{}: {:?}", name, body);
+    }
+    let write = instructions(output.asm_fn("fixture_write_value"));
+    assert_eq!(count_mnemonic(&write, "ldr"), 0, "{:?}", write);
+    assert_eq!(count_mnemonic(&write, "str"), 1, "{:?}", write);
+}
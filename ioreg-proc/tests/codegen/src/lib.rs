@@ -0,0 +1,103 @@
+#![no_std]
+
+use ioreg_proc::ioregs;
+
+ioregs!(FIXTURE = {
+    0x0 => reg32 ctrl {
+        0      => enable,
+        1..3   => mode,
+        4..5   => parity {
+            0 => None,
+            1 => Even,
+            2 => Odd,
+            3 => Mark,
+        },
+        8..15  => count,
+        24..31 => top,
+    },
+    0x4 => reg8 status {
+        0..7   => lane[4],
+    },
+    0x8 => reg32 cmd {
+        0..7   => op: wo,
+        8..15  => arg: wo,
+    },
+    0xc => reg32 isr {
+        0      => overrun: set_to_clear,
+        1      => ready: ro,
+        8..15  => threshold,
+    },
+});
+
+use fixture::{Fixture, CtrlValue};
+use fixture::ctrl::Parity;
+
+// Extraction
+
+#[no_mangle]
+pub extern "C" fn fixture_get_enable(f: &Fixture) -> bool {
+    f.ctrl.get().enable()
+}
+
+#[no_mangle]
+pub extern "C" fn fixture_get_mode(f: &Fixture) -> u32 {
+    f.ctrl.get().mode()
+}
+
+#[no_mangle]
+pub extern "C" fn fixture_get_top(f: &Fixture) -> u32 {
+    f.ctrl.get().top()
+}
+
+#[no_mangle]
+pub extern "C" fn fixture_get_lane(f: &Fixture, index: usize) -> u8 {
+    f.status.get().lane(index)
+}
+
+#[no_mangle]
+pub extern "C" fn fixture_get_parity(f: &Fixture) -> Parity {
+    f.ctrl.get().parity()
+}
+
+#[no_mangle]
+pub extern "C" fn fixture_get_two(f: &Fixture) -> u32 {
+    let ctrl = f.ctrl.get();
+    ctrl.mode() + ctrl.count()
+}
+
+// Updates
+
+#[no_mangle]
+pub extern "C" fn fixture_update_two(f: &Fixture, enable: bool, mode: u8) {
+    f.ctrl.update().set_enable(enable).set_mode(mode);
+}
+
+#[no_mangle]
+pub extern "C" fn fixture_update_const(f: &Fixture) {
+    f.ctrl.update().set_mode(5).set_parity(Parity::Odd);
+}
+
+#[no_mangle]
+pub extern "C" fn fixture_update_lane_const(f: &Fixture, value: u8) {
+    f.status.update().set_lane(2, value);
+}
+
+#[no_mangle]
+pub extern "C" fn fixture_update_write_only(f: &Fixture, op: u8, arg: u8) {
+    f.cmd.update().set_op(op).set_arg(arg);
+}
+
+#[no_mangle]
+pub extern "C" fn fixture_update_set_to_clear(f: &Fixture, threshold: u8) {
+    f.isr.update().set_threshold(threshold);
+}
+
+#[no_mangle]
+pub extern "C" fn fixture_clear(f: &Fixture) {
+    f.isr.update().clear_overrun();
+}
+
+#[no_mangle]
+pub extern "C" fn fixture_write_value(f: &Fixture) {
+    f.ctrl.write(CtrlValue::new().with_mode(3).with_count(0x40));
+}
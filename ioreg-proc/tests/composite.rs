@@ -0,0 +1,52 @@
+use std::mem;
+
+ioreg_proc::ioregs!(COMPOSITE_TEST = {
+    0x0 => reg24 addr {
+        0..7   => low,
+        8..23  => high,
+    },
+    0x3 => reg8 ctrl {
+        0      => enable,
+    },
+    0x4 => reg48 stamp {
+        0..47  => ticks,
+    }: high_first,
+    0xa => reg16 tail {
+        0..15  => value,
+    },
+});
+
+unsafe fn bytes_of<T>(v: &T) -> &[u8] {
+    std::slice::from_raw_parts(v as *const T as *const u8, mem::size_of::<T>())
+}
+
+#[test]
+fn composite_layout() {
+    assert_eq!(mem::size_of::<composite_test::Addr>(), 3);
+    assert_eq!(mem::size_of::<composite_test::Stamp>(), 6);
+    assert_eq!(mem::size_of::<composite_test::CompositeTest>(), 12);
+}
+
+#[test]
+fn composite_cells_little_endian() {
+    let test: composite_test::CompositeTest = unsafe { mem::zeroed() };
+    test.addr.update().set_low(0x12).set_high(0xabcd);
+    test.ctrl.update().set_enable(true);
+    test.stamp.update().set_ticks(0x1122_3344_5566);
+    test.tail.update().set_value(0xffff);
+    let bytes = unsafe { bytes_of(&test) };
+    assert_eq!(&bytes[..4], &[0x12, 0xcd, 0xab, 0x01]);
+    assert_eq!(&bytes[4..10], &[0x66, 0x55, 0x44, 0x33, 0x22, 0x11]);
+    assert_eq!(test.addr.get().high(), 0xabcd);
+    assert_eq!(test.stamp.get().ticks(), 0x1122_3344_5566);
+    assert_eq!(test.tail.get().value(), 0xffff);
+}
+
+#[test]
+fn composite_update_preserves_fields() {
+    let test: composite_test::CompositeTest = unsafe { mem::zeroed() };
+    test.addr.update().set_high(0xbeef);
+    test.addr.update().set_low(0x7f);
+    assert_eq!(test.addr.get().high(), 0xbeef);
+    assert_eq!(test.addr.get().low(), 0x7f);
+}
@@ -0,0 +1,76 @@
+use std::mem;
+use volatile_cell::sync::CriticalSection;
+
+ioreg_proc::ioregs!(SHARED_TEST = {
+    0x0 => reg32 ctrl {
+        0      => enable,
+        1..3   => mode,
+    },
+    0x4 => reg8 flags {
+        0      => ready: ro,
+        1      => overrun: set_to_clear,
+        2      => armed,
+    },
+    0x8 => reg16 cmd {
+        0..7   => op: wo,
+    },
+});
+
+struct TestSection;
+
+unsafe impl CriticalSection for TestSection {}
+
+#[test]
+fn update_in_critical_section() {
+    let test: shared_test::SharedTest = unsafe { mem::zeroed() };
+    let cs = TestSection;
+    test.ctrl.update_in(&cs).set_enable(true).set_mode(0x5);
+    assert!(test.ctrl.get().enable());
+    assert_eq!(test.ctrl.get().mode(), 0x5);
+    test.cmd.update_in(&cs).set_op(0x42);
+}
+
+#[test]
+fn update_exclusive_preserves_other_fields() {
+    let test: shared_test::SharedTest = unsafe { mem::zeroed() };
+    test.ctrl.update().set_mode(0x3);
+    test.ctrl.update_exclusive(|u| { u.set_enable(true); });
+    assert!(test.ctrl.get().enable());
+    assert_eq!(test.ctrl.get().mode(), 0x3);
+}
+
+#[test]
+fn update_exclusive_respects_set_to_clear() {
+    let test: shared_test::SharedTest = unsafe { mem::zeroed() };
+    unsafe { *(&test.flags as *const _ as *mut u8) = 0b011 };
+    test.flags.update_exclusive(|u| { u.set_armed(true); });
+    // Writing back the pending overrun bit would clear it
+    assert_eq!(unsafe { *(&test.flags as *const _ as *const u8) }, 0b101);
+}
+
+#[test]
+fn update_exclusive_retries_closure() {
+    let test: shared_test::SharedTest = unsafe { mem::zeroed() };
+    let mut calls = 0;
+    test.ctrl.update_exclusive(|u| {
+        calls += 1;
+        u.set_mode(0x1);
+    });
+    assert!(calls >= 1);
+    assert_eq!(test.ctrl.get().mode(), 0x1);
+}
+
+#[test]
+fn update_exclusive_stores_nothing_if_closure_panics() {
+    let test: shared_test::SharedTest = unsafe { mem::zeroed() };
+    test.ctrl.update().set_mode(0x3);
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        test.ctrl.update_exclusive(|u| {
+            u.set_enable(true);
+            panic!("interrupted");
+        });
+    }));
+    assert!(result.is_err());
+    assert!(!test.ctrl.get().enable());
+    assert_eq!(test.ctrl.get().mode(), 0x3);
+}
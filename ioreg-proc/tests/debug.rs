@@ -0,0 +1,50 @@
+#![cfg(any(feature = "debug_impls", feature = "binary_log"))]
+use std::mem;
+
+ioreg_proc::ioregs!(DEBUG_TEST = {
+    0x0 => reg32 cr {
+        0      => enable,
+        1..3   => mode,
+        4..5   => parity {
+            0x0 => NoParity,
+            0x2 => EvenParity,
+        },
+        6..7   => lane[2],
+        8..15  => key: wo,
+    },
+});
+
+unsafe fn set_raw<T>(v: &T, value: u32) {
+    *(v as *const T as *mut u32) = value;
+}
+
+#[cfg(feature = "debug_impls")]
+#[test]
+fn debug_prints_decoded_fields() {
+    let test: debug_test::DebugTest = unsafe { mem::zeroed() };
+    unsafe { set_raw(&test.cr, 0x1 | (0x5 << 1) | (0x2 << 4) | (0x1 << 7)) };
+    assert_eq!(format!("{:?}", test.cr.get()),
+               "CrGet { enable: true, mode: 5, parity: EvenParity, lane: [false, true] }");
+}
+
+#[cfg(feature = "debug_impls")]
+#[test]
+fn debug_prints_unknown_variants_raw() {
+    let test: debug_test::DebugTest = unsafe { mem::zeroed() };
+    unsafe { set_raw(&test.cr, 0x3 << 4) };
+    assert_eq!(format!("{:?}", test.cr.get()),
+               "CrGet { enable: false, mode: 0, parity: 0x3, lane: [false, false] }");
+}
+
+#[cfg(feature = "binary_log")]
+#[test]
+fn log_record_encoding() {
+    use debug_test::CrGet;
+    let test: debug_test::DebugTest = unsafe { mem::zeroed() };
+    unsafe { set_raw(&test.cr, 0xdeadbeef) };
+    let record = test.cr.get().to_log_bytes();
+    assert_eq!(&record[..4], &CrGet::LOG_ID.to_le_bytes());
+    assert_eq!(&record[4..], &[0xef, 0xbe, 0xad, 0xde]);
+    assert_eq!(CrGet::LOG_LAYOUT,
+               "debug_test::cr enable@0+1 mode@1+3 parity@4+2{0=NoParity,2=EvenParity} lane@6+1x2 key@8+8");
+}
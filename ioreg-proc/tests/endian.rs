@@ -0,0 +1,92 @@
+use std::mem;
+
+ioreg_proc::ioregs!(BIG_TEST = {
+    0x0 => reg32 ctrl {
+        0      => enable,
+        4..7   => mode,
+        16..31 => divisor,
+    },
+    0x4 => reg16 status {
+        0..3   => level: ro,
+        8      => ready: ro,
+    },
+    0x6 => reg8 cmd {
+        0..7   => op,
+    },
+    0x7 => reg24 addr {
+        0..23  => value,
+    },
+    0xa => reg16 pad {
+        0..15  => value,
+    },
+    0xc => reg32 prio {
+        0..31  => prio[4],
+    }: bytes,
+}: (endian = big));
+
+ioreg_proc::ioregs!(LITTLE_TEST = {
+    0x0 => reg32 ctrl {
+        0..31  => value,
+    },
+}: endian = little);
+
+unsafe fn bytes_of<T>(v: &T) -> &[u8] {
+    std::slice::from_raw_parts(v as *const T as *const u8, mem::size_of::<T>())
+}
+
+unsafe fn bytes_of_mut<T>(v: &mut T) -> &mut [u8] {
+    std::slice::from_raw_parts_mut(v as *mut T as *mut u8, mem::size_of::<T>())
+}
+
+#[test]
+fn stores_are_big_endian() {
+    let test: big_test::BigTest = unsafe { mem::zeroed() };
+    test.ctrl.update().set_enable(true).set_mode(0xa).set_divisor(0x1234);
+    test.cmd.update().set_op(0x5a);
+    test.addr.update().set_value(0xabcdef);
+    let bytes = unsafe { bytes_of(&test) };
+    assert_eq!(&bytes[0..4], &[0x12, 0x34, 0x00, 0xa1]);
+    assert_eq!(bytes[6], 0x5a);
+    assert_eq!(&bytes[7..10], &[0xab, 0xcd, 0xef]);
+    assert_eq!(test.addr.get().value(), 0xabcdef);
+}
+
+#[test]
+fn fields_extracted_from_big_endian() {
+    let mut test: big_test::BigTest = unsafe { mem::zeroed() };
+    {
+        let bytes = unsafe { bytes_of_mut(&mut test) };
+        bytes[0..4].copy_from_slice(&[0xbe, 0xef, 0x00, 0x31]);
+        bytes[4..6].copy_from_slice(&[0x01, 0x07]);
+    }
+    let ctrl = test.ctrl.get();
+    assert!(ctrl.enable());
+    assert_eq!(ctrl.mode(), 0x3);
+    assert_eq!(ctrl.divisor(), 0xbeef);
+    assert_eq!(test.status.get().level(), 0x7);
+    assert!(test.status.get().ready());
+
+    // Read-modify-write keeps the other fields in place
+    test.ctrl.update().set_mode(0x5);
+    let bytes = unsafe { bytes_of(&test) };
+    assert_eq!(&bytes[0..4], &[0xbe, 0xef, 0x00, 0x51]);
+}
+
+#[test]
+fn lanes_count_from_least_significant() {
+    let test: big_test::BigTest = unsafe { mem::zeroed() };
+    test.prio.write_prio(0, 0x11);
+    test.prio.write_prio(3, 0x44);
+    let bytes = unsafe { bytes_of(&test) };
+    assert_eq!(&bytes[0xc..0x10], &[0x44, 0x00, 0x00, 0x11]);
+    assert_eq!(test.prio.get().prio(0), 0x11);
+    assert_eq!(test.prio.read_prio(3), 0x44);
+}
+
+#[test]
+fn explicit_little_endian() {
+    let test: little_test::LittleTest = unsafe { mem::zeroed() };
+    test.ctrl.update().set_value(0x1122_3344);
+    let bytes = unsafe { bytes_of(&test) };
+    assert_eq!(&bytes[..], &[0x44, 0x33, 0x22, 0x11]);
+}
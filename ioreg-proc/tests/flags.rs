@@ -0,0 +1,61 @@
+use std::mem;
+
+ioreg_proc::ioregs!(FLAGS_TEST = {
+    0x0 => reg32 isr {
+        0..3   => irq {
+            0 => RxReady,
+            1 => TxEmpty,
+            3 => Overrun,
+        }: set_to_clear,
+        4      => done: set_to_clear,
+        8..15  => level,
+        16..23 => chan[2]: set_to_clear,
+    },
+});
+
+use flags_test::isr::{ChanFlags, IrqFlags};
+
+unsafe fn raw(test: &flags_test::FlagsTest) -> *mut u32 {
+    &test.isr as *const _ as *mut u32
+}
+
+#[test]
+fn flag_set_operations() {
+    let flags = IrqFlags::RxReady | IrqFlags::Overrun;
+    assert_eq!(flags.bits(), 0b1001);
+    assert!(flags.contains(IrqFlags::RxReady));
+    assert!(!flags.contains(IrqFlags::TxEmpty));
+    assert!(flags.intersects(IrqFlags::Overrun | IrqFlags::TxEmpty));
+    assert_eq!((flags - IrqFlags::RxReady), IrqFlags::Overrun);
+    assert_eq!((!flags).bits(), 0b0110);
+    assert_eq!(IrqFlags::all().bits(), 0xf);
+    assert!(IrqFlags::empty().is_empty());
+    assert_eq!(IrqFlags::from_bits_truncate(0xff), IrqFlags::all());
+}
+
+#[test]
+fn getter_returns_pending_flags() {
+    let test: flags_test::FlagsTest = unsafe { mem::zeroed() };
+    unsafe { *raw(&test) = 0x0005_0a0b };
+    assert_eq!(test.isr.get().irq(), IrqFlags::RxReady | IrqFlags::TxEmpty | IrqFlags::Overrun);
+    assert_eq!(test.isr.get().chan(0), ChanFlags::from_bits_truncate(0x05));
+    assert_eq!(test.isr.get().chan(1), ChanFlags::empty());
+}
+
+#[test]
+fn clear_writes_only_given_flags() {
+    let test: flags_test::FlagsTest = unsafe { mem::zeroed() };
+    unsafe { *raw(&test) = 0x0003_2a1b };
+    test.isr.update().clear_irq(IrqFlags::TxEmpty | IrqFlags::Overrun);
+    // Only the acknowledged bits are written as ones, the other w1c bits as
+    // zeros, and the normal field keeps its value
+    assert_eq!(unsafe { *raw(&test) }, 0x0000_2a0a);
+}
+
+#[test]
+fn clear_counted_with_raw_mask() {
+    let test: flags_test::FlagsTest = unsafe { mem::zeroed() };
+    unsafe { *raw(&test) = 0x00ff_0010 };
+    test.isr.update().clear_chan(1, 0x9u8).set_level(0x7);
+    assert_eq!(unsafe { *raw(&test) }, 0x0090_0700);
+}
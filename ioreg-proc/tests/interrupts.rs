@@ -0,0 +1,119 @@
+use std::mem;
+
+ioreg_proc::ioregs!(UART_TEST = {
+    0x0 => reg32 cr {
+        0..7 => cmd: wo,
+    },
+    0x8 => interrupts reg32 irq {
+        0 => RxReady,
+        1 => TxReady,
+        5 => Overrun,
+    },
+});
+
+ioreg_proc::ioregs!(PORT_TEST = {
+    0x40 => interrupts reg16 irq {},
+});
+
+// The SAM3X PMC puts its status ahead of the mask, with the family well
+// past the other registers
+ioreg_proc::ioregs!(PMC_TEST = {
+    0x0 => reg32 scer {
+        0..31 => bits: wo,
+    },
+    0x60 => interrupts reg32 irq {
+        0 => MainOscReady,
+        1 => PllaLocked,
+        3 => MasterClockReady,
+    }: (enable: 0x0, disable: 0x4, status: 0x8, mask: 0xC),
+});
+
+// Registers of a family needn't be next to each other either
+ioreg_proc::ioregs!(SPREAD_TEST = {
+    0x10 => interrupts reg16 irq {}: (enable: 0x0, disable: 0x8, mask: 0x10, status: 0x20),
+    0x40 => reg16 data {
+        0..15 => value,
+    },
+});
+
+use uart_test::irq::IrqFlags;
+
+unsafe fn word<T>(test: &T, offset: usize) -> *mut u32 {
+    (test as *const T as *mut u8).add(offset) as *mut u32
+}
+
+#[test]
+fn family_layout() {
+    let test: uart_test::UartTest = unsafe { mem::zeroed() };
+    let base = &test as *const _ as usize;
+    assert_eq!(&test.irq.ier as *const _ as usize - base, 0x8);
+    assert_eq!(&test.irq.idr as *const _ as usize - base, 0xc);
+    assert_eq!(&test.irq.imr as *const _ as usize - base, 0x10);
+    assert_eq!(&test.irq.isr as *const _ as usize - base, 0x14);
+    assert_eq!(mem::size_of::<port_test::PortTest>(), 0x48);
+}
+
+#[test]
+fn enable_and_disable_write_flags() {
+    let test: uart_test::UartTest = unsafe { mem::zeroed() };
+    test.irq.enable(IrqFlags::RxReady | IrqFlags::Overrun);
+    test.irq.disable(IrqFlags::TxReady);
+    unsafe {
+        assert_eq!(*word(&test, 0x8), 0x21);
+        assert_eq!(*word(&test, 0xc), 0x2);
+    }
+}
+
+#[test]
+fn mask_and_status_read_flags() {
+    let test: uart_test::UartTest = unsafe { mem::zeroed() };
+    unsafe {
+        *word(&test, 0x10) = 0x3;
+        // Bits without a name are left out
+        *word(&test, 0x14) = 0xf0 | 0x20 | 0x1;
+    }
+    assert_eq!(test.irq.mask(), IrqFlags::RxReady | IrqFlags::TxReady);
+    let status = test.irq.status();
+    assert_eq!(status, IrqFlags::RxReady | IrqFlags::Overrun);
+    assert!(status.contains(IrqFlags::Overrun));
+}
+
+#[test]
+fn unnamed_family_covers_register() {
+    let test: port_test::PortTest = unsafe { mem::zeroed() };
+    assert_eq!(port_test::irq::IrqFlags::all().bits(), 0xffff);
+    test.irq.enable(0x8001u16);
+    assert_eq!(unsafe { *((&test.irq.ier) as *const _ as *const u16) }, 0x8001);
+}
+
+#[test]
+fn explicit_layout_puts_status_before_mask() {
+    use pmc_test::irq::IrqFlags;
+    let test: pmc_test::PmcTest = unsafe { mem::zeroed() };
+    let base = &test as *const _ as usize;
+    assert_eq!(&test.irq.ier as *const _ as usize - base, 0x60);
+    assert_eq!(&test.irq.idr as *const _ as usize - base, 0x64);
+    assert_eq!(&test.irq.isr as *const _ as usize - base, 0x68);
+    assert_eq!(&test.irq.imr as *const _ as usize - base, 0x6c);
+    assert_eq!(mem::size_of::<pmc_test::PmcTest>(), 0x70);
+
+    test.irq.enable(IrqFlags::MasterClockReady);
+    unsafe {
+        *word(&test, 0x68) = 0x1;
+        *word(&test, 0x6c) = 0x2;
+        assert_eq!(*word(&test, 0x60), 0x8);
+    }
+    assert_eq!(test.irq.status(), IrqFlags::MainOscReady);
+    assert_eq!(test.irq.mask(), IrqFlags::PllaLocked);
+}
+
+#[test]
+fn explicit_layout_leaves_gaps() {
+    let test: spread_test::SpreadTest = unsafe { mem::zeroed() };
+    let base = &test as *const _ as usize;
+    assert_eq!(&test.irq.ier as *const _ as usize - base, 0x10);
+    assert_eq!(&test.irq.idr as *const _ as usize - base, 0x18);
+    assert_eq!(&test.irq.imr as *const _ as usize - base, 0x20);
+    assert_eq!(&test.irq.isr as *const _ as usize - base, 0x30);
+    assert_eq!(&test.data as *const _ as usize - base, 0x40);
+}
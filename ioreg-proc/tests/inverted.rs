@@ -1,13 +1,27 @@
+use std::mem;
+
 mod reg {
     use ioreg_proc::ioregs;
     ioregs!(INVERTED_RANGE_TEST = {
         0x0 => reg32 reg1 {
             31..0 => field1,
-        }
+        },
+        0x4 => reg32 reg2 {
+            7..0   => low,
+            31..16 => high,
+        },
     });
-    use inverted_range_test::InvertedRangeTest;
+    pub use inverted_range_test::InvertedRangeTest;
 }
 
 #[test]
 fn inverted_range() {
+    let test: reg::InvertedRangeTest = unsafe { mem::zeroed() };
+    test.reg1.update().set_field1(0xdead_beef);
+    test.reg2.update().set_low(0x5a).set_high(0x1234);
+    let raw = unsafe { *(&test as *const _ as *const [u32; 2]) };
+    assert_eq!(raw, [0xdead_beef, 0x1234_005a]);
+    assert_eq!(test.reg1.get().field1(), 0xdead_beef);
+    assert_eq!(test.reg2.get().low(), 0x5a);
+    assert_eq!(test.reg2.get().high(), 0x1234);
 }
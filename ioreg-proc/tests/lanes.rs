@@ -0,0 +1,40 @@
+use std::mem;
+
+ioreg_proc::ioregs!(LANES_TEST = {
+    0x0 => reg32 ipr[2] {
+        0..31  => ipr[4],
+    }: bytes,
+    0x8 => reg32 ctrl {
+        0..15  => low,
+        16..23 => mid,
+        24..31 => status: ro,
+    }: halfwords,
+});
+
+unsafe fn bytes_of<T>(v: &T) -> &[u8] {
+    std::slice::from_raw_parts(v as *const T as *const u8, mem::size_of::<T>())
+}
+
+#[test]
+fn byte_lanes_write_single_bytes() {
+    let test: lanes_test::LanesTest = unsafe { mem::zeroed() };
+    test.ipr[1].write_ipr(2, 0xa0);
+    test.ipr[0].write_ipr(0, 0x10);
+    assert_eq!(test.ipr[1].get().ipr(2), 0xa0);
+    assert_eq!(test.ipr[1].read_ipr(2), 0xa0);
+    assert_eq!(test.ipr[0].get().ipr(0), 0x10);
+    assert_eq!(test.ipr[1].get().ipr(1), 0);
+    assert_eq!(test.ipr[0].lanes().len(), 4);
+}
+
+#[test]
+fn lanes_follow_field_bits() {
+    let test: lanes_test::LanesTest = unsafe { mem::zeroed() };
+    test.ctrl.write_low(0x1234);
+    test.ctrl.lane(1).set(0xbeef);
+    assert_eq!(test.ctrl.get().low(), 0x1234);
+    assert_eq!(test.ctrl.get().mid(), 0xef);
+    assert_eq!(test.ctrl.get().status(), 0xbe);
+    let bytes = unsafe { bytes_of(&test.ctrl) };
+    assert_eq!(u32::from_ne_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]), 0xbeef_1234);
+}
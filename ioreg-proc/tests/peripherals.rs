@@ -0,0 +1,49 @@
+use std::mem;
+
+ioreg_proc::ioregs!(UART = {
+    0x0 => reg32 ctrl {
+        0      => enable,
+    },
+});
+
+ioreg_proc::ioregs!(PMC @ 0x400E0600 = {
+    0x0 => reg32 scer {
+        0..31  => pcken: wo,
+    },
+});
+
+static mut UART_A: uart::Uart = unsafe { mem::transmute(0u32) };
+static mut UART_B: uart::Uart = unsafe { mem::transmute(0u32) };
+
+ioreg_proc::peripherals!(Peripherals {
+    UARTA: uart::UartPeripheral = unsafe { &mut *::std::ptr::addr_of_mut!(UART_A) },
+    UARTB: uart::UartPeripheral = unsafe { &mut *::std::ptr::addr_of_mut!(UART_B) },
+    PMC: pmc::PmcPeripheral,
+});
+
+struct Driver {
+    uart: uart::UartPeripheral,
+}
+
+impl Driver {
+    fn new(uart: uart::UartPeripheral) -> Driver {
+        uart.ctrl.update().set_enable(true);
+        Driver { uart: uart }
+    }
+}
+
+#[test]
+fn take_hands_out_tokens_once() {
+    let p = Peripherals::take().expect("peripherals already taken");
+    assert!(Peripherals::take().is_none());
+
+    let a = Driver::new(p.UARTA);
+    assert!(a.uart.ctrl.get().enable());
+    assert!(!p.UARTB.ctrl.get().enable());
+    assert_eq!(&*p.PMC as *const pmc::Pmc as usize, 0x400E0600);
+}
+
+#[test]
+fn located_peripheral_address() {
+    assert_eq!(pmc::PmcPeripheral::ADDRESS, 0x400E0600);
+}
@@ -0,0 +1,451 @@
+//! Property tests of field packing and unpacking.
+//!
+//! Random register layouts (every width from `reg8` to `reg64`, single bit,
+//! ranged, inverted, counted, enum, read-only and `set_to_clear` fields, the
+//! latter also as multi-bit flags) are written out as `ioregs!` definitions
+//! in a scratch crate, along with checks against a model of the layout
+//! computed here:
+//!
+//! * getters extract the bits of their field from random register values,
+//! * setters round-trip through the getters,
+//! * updates leave the bits outside the fields they set untouched, and
+//! * `set_to_clear` bits are only ever written as ones when cleared, and then
+//!   only the flags given.
+//!
+//! The scratch crate is then tested with cargo. Set `IOREG_PROPERTY_SEED` to
+//! reproduce a failing run from the seed it prints.
+
+use std::env;
+use std::fmt::Write;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+const LAYOUTS: usize = 48;
+const CASES_PER_LAYOUT: usize = 16;
+const DEFAULT_SEED: u64 = 0x10c0_ffee_d00d_f00d;
+const WIDTHS: [u32; 8] = [8, 16, 24, 32, 40, 48, 56, 64];
+
+/// xorshift64*, enough to spread layouts and values around
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 >> 12;
+        self.0 ^= self.0 << 25;
+        self.0 ^= self.0 >> 27;
+        self.0.wrapping_mul(0x2545_f491_4f6c_dd1d)
+    }
+
+    fn below(&mut self, n: u64) -> u64 {
+        self.next() % n
+    }
+
+    fn chance(&mut self, percent: u64) -> bool {
+        self.below(100) < percent
+    }
+
+    /// A value of `bits` bits, favouring the all-zeros and all-ones edges
+    fn value(&mut self, bits: u32) -> u64 {
+        match self.below(8) {
+            0 => 0,
+            1 => ones(bits),
+            _ => self.next() & ones(bits),
+        }
+    }
+}
+
+fn ones(bits: u32) -> u64 {
+    if bits >= 64 { !0 } else { (1 << bits) - 1 }
+}
+
+#[derive(PartialEq)]
+enum Access {
+    ReadWrite,
+    ReadOnly,
+    SetToClear,
+}
+
+enum Kind {
+    Bool,
+    Int,
+    Enum(Vec<u64>),
+    Counted(u32),
+}
+
+struct Field {
+    name: String,
+    lo: u32,
+    hi: u32,
+    inverted: bool,
+    kind: Kind,
+    access: Access,
+}
+
+impl Field {
+    fn size(&self) -> u32 {
+        self.hi - self.lo + 1
+    }
+
+    fn count(&self) -> u32 {
+        match self.kind {
+            Kind::Counted(n) => n,
+            _ => 1,
+        }
+    }
+
+    fn element_size(&self) -> u32 {
+        self.size() / self.count()
+    }
+
+    fn element_shift(&self, index: u32) -> u32 {
+        self.lo + index * self.element_size()
+    }
+
+    fn mask(&self) -> u64 {
+        ones(self.size()) << self.lo
+    }
+
+    fn element_mask(&self, index: u32) -> u64 {
+        ones(self.element_size()) << self.element_shift(index)
+    }
+
+    fn extract(&self, raw: u64, index: u32) -> u64 {
+        (raw >> self.element_shift(index)) & ones(self.element_size())
+    }
+
+    fn enum_ty(&self) -> String {
+        let mut name = self.name.clone();
+        name[..1].make_ascii_uppercase();
+        name
+    }
+
+    /// Whether the field gets a flags type, being `set_to_clear` over several
+    /// bits per element.
+    fn is_flags(&self) -> bool {
+        self.access == Access::SetToClear && self.element_size() > 1
+    }
+
+    fn flags_ty(&self) -> String {
+        format!("{}Flags", self.enum_ty())
+    }
+
+    /// The integer type of the flags of the field.
+    fn flags_primitive(&self) -> &'static str {
+        match self.element_size() {
+            0..=8 => "u8",
+            9..=16 => "u16",
+            17..=32 => "u32",
+            _ => "u64",
+        }
+    }
+
+    fn variant(&self, value: u64) -> String {
+        format!("V{:x}", value)
+    }
+
+    fn definition(&self) -> String {
+        let offset = if self.size() == 1 {
+            format!("{}", self.lo)
+        } else if self.inverted {
+            format!("{}..{}", self.hi, self.lo)
+        } else {
+            format!("{}..{}", self.lo, self.hi)
+        };
+        let mut ret = match self.kind {
+            Kind::Counted(n) => format!("{} => {}[{}]", offset, self.name, n),
+            _ => format!("{} => {}", offset, self.name),
+        };
+        if let Kind::Enum(ref values) = self.kind {
+            ret.push_str(" {\n");
+            for &value in values.iter() {
+                let _ = writeln!(ret, "            {:#x} => {},", value, self.variant(value));
+            }
+            ret.push_str("        }");
+        }
+        match self.access {
+            Access::ReadWrite => {},
+            Access::ReadOnly => ret.push_str(": ro"),
+            Access::SetToClear => ret.push_str(": set_to_clear"),
+        }
+        ret
+    }
+}
+
+struct Layout {
+    index: usize,
+    width: u32,
+    fields: Vec<Field>,
+}
+
+impl Layout {
+    fn random(rng: &mut Rng, index: usize) -> Layout {
+        let width = WIDTHS[rng.below(WIDTHS.len() as u64) as usize];
+        let mut fields = Vec::new();
+        let mut bit = rng.below(3) as u32;
+        while bit < width {
+            let remaining = width - bit;
+            let max_size = if rng.chance(10) { remaining } else { remaining.min(12) };
+            let size = if rng.chance(30) { 1 } else { 1 + rng.below(max_size as u64) as u32 };
+            fields.push(Field::random(rng, fields.len(), bit, size));
+            bit += size + rng.below(3) as u32;
+        }
+        Layout {
+            index: index,
+            width: width,
+            fields: fields,
+        }
+    }
+
+    fn module(&self) -> String {
+        format!("prop_{}", self.index)
+    }
+
+    fn clear_mask(&self) -> u64 {
+        self.fields.iter()
+            .filter(|f| f.access == Access::SetToClear)
+            .fold(0, |mask, f| mask | f.mask())
+    }
+
+    fn definition(&self) -> String {
+        let mut ret = format!("ioreg_proc::ioregs!(PROP_{} = {{\n    0x0 => reg{} reg {{\n", self.index, self.width);
+        for field in self.fields.iter() {
+            let _ = writeln!(ret, "        {},", field.definition());
+        }
+        ret.push_str("    },\n});\n");
+        ret
+    }
+
+    fn literal(&self, field: &Field, value: u64) -> String {
+        match field.kind {
+            _ if field.is_flags() => format!("{}::reg::{}::from_bits_truncate({:#x})", self.module(), field.flags_ty(), value),
+            Kind::Enum(..) => format!("{}::reg::{}::{}", self.module(), field.enum_ty(), field.variant(value)),
+            _ if field.element_size() == 1 => format!("{}", value != 0),
+            _ => format!("{:#x}", value),
+        }
+    }
+
+    fn getter(&self, field: &Field, index: u32) -> String {
+        match field.kind {
+            Kind::Counted(..) => format!("regs.reg.get().{}({})", field.name, index),
+            _ => format!("regs.reg.get().{}()", field.name),
+        }
+    }
+
+    fn unpack_checks(&self, rng: &mut Rng, out: &mut String) {
+        let raw = rng.value(self.width);
+        let _ = writeln!(out, "    unsafe {{ store(&regs.reg, {}, {:#x}) }};", self.width / 8, raw);
+        for field in self.fields.iter() {
+            for index in 0..field.count() {
+                let value = field.extract(raw, index);
+                if let Kind::Enum(ref values) = field.kind {
+                    if !values.contains(&value) {
+                        continue;
+                    }
+                }
+                let _ = writeln!(out, "    assert_eq!({}, {});", self.getter(field, index), self.literal(field, value));
+            }
+        }
+    }
+
+    fn update_checks(&self, rng: &mut Rng, out: &mut String) {
+        let writable: Vec<&Field> = self.fields.iter()
+            .filter(|f| f.access != Access::ReadOnly)
+            .collect();
+        if writable.is_empty() {
+            return;
+        }
+        // Set-to-clear bits are pending in the register before the update
+        let raw = rng.value(self.width) | self.clear_mask();
+        let mut expected = raw & !self.clear_mask();
+        let mut written = 0;
+        let mut calls = Vec::new();
+        let mut reads = Vec::new();
+        for _ in 0..(1 + rng.below(3)) {
+            let field = writable[rng.below(writable.len() as u64) as usize];
+            let index = rng.below(field.count() as u64) as u32;
+            let mask = field.element_mask(index);
+            if field.is_flags() {
+                // Flags to acknowledge, as a raw mask converted to the flags
+                let flags = rng.value(field.element_size());
+                let flags_literal = format!("{:#x}{}", flags, field.flags_primitive());
+                calls.push(match field.kind {
+                    Kind::Counted(..) => format!(".clear_{}({}, {})", field.name, index, flags_literal),
+                    _ => format!(".clear_{}({})", field.name, flags_literal),
+                });
+                expected |= flags << field.element_shift(index);
+                written |= flags << field.element_shift(index);
+                continue;
+            }
+            if field.access == Access::SetToClear {
+                calls.push(format!(".clear_{}()", field.name));
+                expected |= mask;
+                written |= mask;
+                continue;
+            }
+            let value = match field.kind {
+                Kind::Enum(ref values) => values[rng.below(values.len() as u64) as usize],
+                _ => rng.value(field.element_size()),
+            };
+            calls.push(match field.kind {
+                Kind::Counted(..) => format!(".set_{}({}, {})", field.name, index, self.literal(field, value)),
+                _ => format!(".set_{}({})", field.name, self.literal(field, value)),
+            });
+            expected = (expected & !mask) | (value << field.element_shift(index));
+            written |= mask;
+            reads.push((field, index));
+        }
+        // An update that writes no bits, only acknowledging empty flags,
+        // doesn't touch the register
+        if written == 0 {
+            expected = raw;
+        }
+        let _ = writeln!(out, "    unsafe {{ store(&regs.reg, {}, {:#x}) }};", self.width / 8, raw);
+        let _ = writeln!(out, "    regs.reg.update(){};", calls.concat());
+        let _ = writeln!(out, "    assert_eq!(unsafe {{ load(&regs.reg, {}) }}, {:#x}, \"{}\");", self.width / 8, expected, calls.concat());
+        for (field, index) in reads {
+            let value = field.extract(expected, index);
+            let _ = writeln!(out, "    assert_eq!({}, {});", self.getter(field, index), self.literal(field, value));
+        }
+    }
+
+    fn test_fn(&self, rng: &mut Rng) -> String {
+        let mut ret = format!("#[test]\nfn layout_{}() {{\n", self.index);
+        let _ = writeln!(ret, "    let regs: {}::Prop{} = unsafe {{ core::mem::zeroed() }};", self.module(), self.index);
+        for _ in 0..CASES_PER_LAYOUT {
+            self.unpack_checks(rng, &mut ret);
+            self.update_checks(rng, &mut ret);
+        }
+        ret.push_str("}\n");
+        ret
+    }
+}
+
+impl Field {
+    fn random(rng: &mut Rng, index: usize, lo: u32, size: u32) -> Field {
+        let counts: Vec<u32> = (2..=size.min(8)).filter(|n| size % n == 0).collect();
+        let mut field = Field {
+            name: format!("f{}", index),
+            lo: lo,
+            hi: lo + size - 1,
+            inverted: rng.chance(30),
+            kind: Kind::Int,
+            access: Access::ReadWrite,
+        };
+        if size == 1 {
+            field.kind = Kind::Bool;
+            field.access = match rng.below(6) {
+                0 => Access::SetToClear,
+                1 => Access::ReadOnly,
+                _ => Access::ReadWrite,
+            };
+            return field;
+        }
+        if rng.chance(15) {
+            // Flags, with at least two bits per element
+            let counts: Vec<u32> = counts.into_iter().filter(|n| size / n > 1).collect();
+            field.access = Access::SetToClear;
+            field.kind = match rng.below(3) {
+                0 if !counts.is_empty() => Kind::Counted(counts[rng.below(counts.len() as u64) as usize]),
+                _ => Kind::Int,
+            };
+            return field;
+        }
+        field.kind = match rng.below(4) {
+            0 if size <= 4 => {
+                let mut values: Vec<u64> = (0..=ones(size)).filter(|_| rng.chance(50)).collect();
+                if values.is_empty() {
+                    values.push(rng.value(size));
+                }
+                Kind::Enum(values)
+            },
+            1 if !counts.is_empty() => Kind::Counted(counts[rng.below(counts.len() as u64) as usize]),
+            _ => Kind::Int,
+        };
+        if rng.chance(15) {
+            field.access = Access::ReadOnly;
+        }
+        field
+    }
+}
+
+const PRELUDE: &str = r#"// Generated by tests/properties.rs
+#![allow(unused_unsafe)]
+
+/// Writes the `bytes` low bytes of `value` to the register, little endian
+unsafe fn store<T>(reg: &T, bytes: usize, value: u64) {
+    let ptr = reg as *const T as *mut u8;
+    for i in 0..bytes {
+        core::ptr::write_volatile(ptr.add(i), (value >> (8 * i)) as u8);
+    }
+}
+
+unsafe fn load<T>(reg: &T, bytes: usize) -> u64 {
+    let ptr = reg as *const T as *const u8;
+    (0..bytes).fold(0, |value, i| value | (u64::from(core::ptr::read_volatile(ptr.add(i))) << (8 * i)))
+}
+"#;
+
+const MANIFEST: &str = r#"[package]
+name = "ioreg-properties"
+version = "0.1.0"
+edition = "2018"
+
+[lib]
+path = "lib.rs"
+
+[dependencies.ioreg-proc]
+path = "{proc}"
+
+[dependencies.volatile_cell]
+path = "{volatile_cell}"
+
+[workspace]
+"#;
+
+/// A directory of its own in the target directory this test was built in, so
+/// that `CARGO_TARGET_DIR` and `build.target-dir` are followed.
+fn properties_dir() -> PathBuf {
+    let exe = env::current_exe().expect("failed to find the test executable");
+    // <target dir>/debug/deps/properties-<hash>
+    exe.ancestors()
+        .nth(3)
+        .expect("unexpected test executable path")
+        .join("properties")
+}
+
+#[test]
+fn field_round_trips() {
+    let seed = env::var("IOREG_PROPERTY_SEED")
+        .map(|s| s.parse().expect("IOREG_PROPERTY_SEED must be an integer"))
+        .unwrap_or(DEFAULT_SEED);
+    eprintln!("property seed: {}", seed);
+    let mut rng = Rng(seed | 1);
+
+    let mut source = String::from(PRELUDE);
+    for index in 0..LAYOUTS {
+        let layout = Layout::random(&mut rng, index);
+        source.push('\n');
+        source.push_str(&layout.definition());
+        source.push('\n');
+        source.push_str(&layout.test_fn(&mut rng));
+    }
+
+    let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let crate_dir = properties_dir().join("crate");
+    fs::create_dir_all(&crate_dir).unwrap();
+    let manifest = MANIFEST
+        .replace("{proc}", &manifest_dir.display().to_string())
+        .replace("{volatile_cell}", &manifest_dir.join("../volatile_cell").display().to_string());
+    fs::write(crate_dir.join("Cargo.toml"), manifest).unwrap();
+    fs::write(crate_dir.join("lib.rs"), source).unwrap();
+
+    let status = Command::new(env!("CARGO"))
+        .arg("test")
+        .arg("--quiet")
+        .arg("--manifest-path").arg(crate_dir.join("Cargo.toml"))
+        .arg("--target-dir").arg(properties_dir().join("target"))
+        .status()
+        .expect("failed to run cargo");
+    assert!(status.success(), "property checks failed for seed {}, see {}", seed, crate_dir.join("lib.rs").display());
+}
@@ -0,0 +1,116 @@
+use std::mem;
+use ioreg_proc::register_map;
+
+#[register_map]
+mod map_test {
+    struct MapTest {
+        #[offset(0x0)]
+        ctrl: Reg32<Ctrl>,
+        #[offset(0x4)]
+        status: Reg16<Status>,
+        #[offset(0x8)]
+        prio: [Reg8<Prio>; 4],
+        #[offset(0x10)]
+        chan: [Chan; 2],
+    }
+
+    struct Ctrl {
+        #[bits(0)]
+        enable: bool,
+        #[bits(1..3)]
+        mode: Mode,
+        #[bits(8..15)]
+        count: u8,
+        #[bits(16..23, wo)]
+        cmd: u8,
+    }
+
+    enum Mode {
+        Off,
+        Slow = 0x3,
+        Fast,
+    }
+
+    struct Status {
+        #[bits(0, ro)]
+        ready: bool,
+        #[bits(4..7)]
+        flags: [bool; 4],
+    }
+
+    struct Prio {
+        #[bits(4..7)]
+        level: u8,
+    }
+
+    struct Chan {
+        #[offset(0x0)]
+        cfg: Reg32<Cfg>,
+        #[offset(0x4)]
+        data: Reg32<Data>,
+    }
+
+    struct Cfg {
+        #[bits(0..7)]
+        src: u8,
+    }
+
+    struct Data {
+        #[bits(0..31)]
+        value: u32,
+    }
+}
+
+ioreg_proc::ioregs!(MACRO_TEST = {
+    0x0 => reg32 ctrl {
+        0      => enable,
+        1..3   => mode {
+            0 => Off,
+            3 => Slow,
+            4 => Fast,
+        },
+        8..15  => count,
+        16..23 => cmd: wo,
+    },
+    0x4 => reg16 status {
+        0      => ready: ro,
+        4..7   => flags[4],
+    },
+    0x8 => reg8 prio[4] {
+        4..7   => level,
+    },
+    0x10 => group chan[2] {
+        0x0 => reg32 cfg {
+            0..7 => src,
+        },
+        0x4 => reg32 data {
+            0..31 => value,
+        },
+    },
+});
+
+#[test]
+fn matches_ioregs_layout() {
+    assert_eq!(mem::size_of::<map_test::MapTest>(), mem::size_of::<macro_test::MacroTest>());
+    let test: map_test::MapTest = unsafe { mem::zeroed() };
+    let base = &test as *const _ as usize;
+    assert_eq!(&test.status as *const _ as usize - base, 0x4);
+    assert_eq!(&test.prio[3] as *const _ as usize - base, 0xb);
+    assert_eq!(&test.chan[1].data as *const _ as usize - base, 0x1c);
+}
+
+#[test]
+fn fields_and_variants() {
+    let test: map_test::MapTest = unsafe { mem::zeroed() };
+    test.ctrl.update()
+        .set_enable(true)
+        .set_mode(map_test::ctrl::Mode::Fast)
+        .set_count(0x42);
+    let ctrl = unsafe { *(&test.ctrl as *const _ as *const u32) };
+    assert_eq!(ctrl, 0x4209);
+    assert_eq!(test.ctrl.get().mode(), map_test::ctrl::Mode::Fast);
+    test.status.update().set_flags(2, true);
+    assert!(test.status.get().flags(2));
+    test.chan[1].data.update().set_value(0xdead_beef);
+    assert_eq!(test.chan[1].data.get().value(), 0xdead_beef);
+}
@@ -0,0 +1,81 @@
+use std::mem;
+
+ioreg_proc::ioregs!(SNAPSHOT_TEST = {
+    0x0 => reg32 mode {
+        0..3   => div,
+        4      => enable,
+    }: restore_order = 1,
+    0x4 => reg32 cfg[2] {
+        0..15  => value,
+    },
+    0x10 => reg32 status {
+        0      => ready: ro,
+    },
+    0x14 => reg32 flags {
+        0      => overrun: set_to_clear,
+    },
+    0x18 => reg32 cmd {
+        0..7   => op: wo,
+    },
+    0x1c => reg32 scratch {
+        0..31  => value,
+    }: no_snapshot,
+    0x20 => group chan[2] {
+        0x0 => reg32 period {
+            0..15 => ticks,
+        },
+    },
+});
+
+unsafe fn set_raw<T>(v: &T, offset: usize, value: u32) {
+    let ptr = v as *const T as *mut u32;
+    *(ptr.offset(offset as isize)) = value;
+}
+
+#[test]
+fn capture_and_restore_round_trip() {
+    let test: snapshot_test::SnapshotTest = unsafe { mem::zeroed() };
+    test.mode.update().set_div(0x5).set_enable(true);
+    test.cfg[1].update().set_value(0x1234);
+    test.chan[1].period.update().set_ticks(0xbeef);
+
+    let saved = snapshot_test::capture(&test);
+    assert_eq!(saved.mode.div(), 0x5);
+    assert_eq!(saved.cfg[1].value(), 0x1234);
+    assert_eq!(saved.chan[1].period.ticks(), 0xbeef);
+
+    test.mode.update().set_div(0).set_enable(false);
+    test.cfg[1].update().set_value(0);
+    test.chan[1].period.update().set_ticks(0);
+
+    snapshot_test::restore(&test, &saved);
+    assert_eq!(test.mode.get().div(), 0x5);
+    assert!(test.mode.get().enable());
+    assert_eq!(test.cfg[0].get().value(), 0);
+    assert_eq!(test.cfg[1].get().value(), 0x1234);
+    assert_eq!(test.chan[0].period.get().ticks(), 0);
+    assert_eq!(test.chan[1].period.get().ticks(), 0xbeef);
+}
+
+#[test]
+fn read_only_registers_are_not_restored() {
+    let test: snapshot_test::SnapshotTest = unsafe { mem::zeroed() };
+    let saved = snapshot_test::capture(&test);
+    assert!(!saved.status.ready());
+    unsafe { set_raw(&test, 4, 0x1) };
+    snapshot_test::restore(&test, &saved);
+    assert!(test.status.get().ready());
+}
+
+#[test]
+fn excluded_registers_are_untouched() {
+    let test: snapshot_test::SnapshotTest = unsafe { mem::zeroed() };
+    let saved = snapshot_test::capture(&test);
+    unsafe {
+        set_raw(&test, 5, 0x1);
+        set_raw(&test, 7, 0xfeed);
+    }
+    snapshot_test::restore(&test, &saved);
+    assert_eq!(test.scratch.get().value(), 0xfeed);
+    assert_eq!(unsafe { *((&test as *const _ as *const u32).offset(5)) }, 0x1);
+}
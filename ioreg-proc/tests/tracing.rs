@@ -0,0 +1,57 @@
+#![cfg(feature = "tracing")]
+
+use std::cell::RefCell;
+use std::mem;
+use volatile_cell::trace::{self, Access, Event};
+
+ioreg_proc::ioregs!(TRACE_TEST = {
+    0x0 => reg32 ctrl {
+        0      => enable,
+        1..3   => mode,
+    },
+    0x4 => reg16 cmd {
+        0..7   => op: wo,
+    },
+});
+
+thread_local! {
+    static EVENTS: RefCell<Vec<Event>> = RefCell::new(Vec::new());
+}
+
+fn log_event(event: &Event) {
+    EVENTS.with(|events| events.borrow_mut().push(*event));
+}
+
+fn take_events() -> Vec<Event> {
+    EVENTS.with(|events| mem::replace(&mut *events.borrow_mut(), Vec::new()))
+}
+
+#[test]
+fn accesses_reported_to_hook() {
+    let test: trace_test::TraceTest = unsafe { mem::zeroed() };
+    let base = &test as *const _ as usize;
+    trace::set_hook(log_event);
+
+    test.ctrl.update().set_mode(0x5);
+    let _ = test.ctrl.get();
+    test.cmd.update().set_op(0x42);
+    let events = take_events();
+
+    let event = |access, register, offset, value| Event {
+        peripheral: "trace_test",
+        register: register,
+        address: base + offset,
+        value: value,
+        access: access,
+    };
+    assert_eq!(events, vec![
+        event(Access::Read, "ctrl", 0x0, 0x0),
+        event(Access::Write, "ctrl", 0x0, 0xa),
+        event(Access::Read, "ctrl", 0x0, 0xa),
+        event(Access::Write, "cmd", 0x4, 0x42),
+    ]);
+
+    trace::clear_hook();
+    let _ = test.ctrl.get();
+    assert!(take_events().is_empty());
+}
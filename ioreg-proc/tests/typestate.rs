@@ -0,0 +1,62 @@
+use std::mem;
+
+ioreg_proc::ioregs!(TYPESTATE_TEST = {
+    0x0 => reg32 mckr {
+        0..1   => css {
+            0 => SlowClk,
+            1 => MainClk,
+            2 => PllaClk,
+        },
+        4..6   => pres {
+            0 => Clk1,
+            1 => Clk2,
+        },
+        7      => ready,
+    }: typestate,
+});
+
+use typestate_test::mckr::{css, pres};
+use typestate_test::MckrTyped;
+
+/// Switching to the PLL is only allowed once the prescaler is known.
+fn select_pll<'a, P: pres::Known>(mckr: MckrTyped<'a, css::MainClk, P>) -> MckrTyped<'a, css::PllaClk, P> {
+    mckr.set_css(css::PllaClk)
+}
+
+#[test]
+fn transitions_write_register() {
+    let mut test: typestate_test::TypestateTest = unsafe { mem::zeroed() };
+    test.mckr.update().set_ready(true);
+    let mckr = test.mckr.typed()
+        .set_pres(pres::Clk2)
+        .set_css(css::MainClk);
+    let mckr = select_pll(mckr);
+    assert_eq!(mckr.css(), typestate_test::mckr::Css::PllaClk);
+    assert_eq!(mckr.pres(), typestate_test::mckr::Pres::Clk2);
+    let get = mckr.get();
+    assert_eq!(get.css(), typestate_test::mckr::Css::PllaClk);
+    assert_eq!(get.pres(), typestate_test::mckr::Pres::Clk2);
+    assert!(get.ready());
+}
+
+#[test]
+fn try_typed_checks_current_state() {
+    let mut test: typestate_test::TypestateTest = unsafe { mem::zeroed() };
+    test.mckr.update()
+        .set_css(typestate_test::mckr::Css::MainClk)
+        .set_pres(typestate_test::mckr::Pres::Clk1)
+        .set_ready(true);
+    assert!(test.mckr.try_typed::<css::MainClk, pres::Clk1>().is_some());
+    assert!(test.mckr.try_typed::<css::SlowClk, pres::Clk1>().is_none());
+    assert!(test.mckr.try_typed::<css::MainClk, pres::Clk2>().is_none());
+}
+
+static mut TYPESTATE: typestate_test::TypestateTest = unsafe { mem::transmute(0u32) };
+
+#[test]
+fn typed_through_token() {
+    let mut token = unsafe { typestate_test::TypestateTestPeripheral::new(&mut *std::ptr::addr_of_mut!(TYPESTATE)) };
+    let mckr = token.mckr.typed().set_pres(pres::Clk1);
+    assert_eq!(mckr.pres(), typestate_test::mckr::Pres::Clk1);
+    assert_eq!(token.mckr.get().pres(), typestate_test::mckr::Pres::Clk1);
+}
@@ -0,0 +1,128 @@
+use std::mem;
+
+ioreg_proc::ioregs!(VALUE_TEST = {
+    0x0 => reg32 cr {
+        0      => enable,
+        1..3   => mode,
+        4..7   => channel[2],
+        14..15 => parity {
+            0x0 => NoParity,
+            0x2 => EvenParity,
+            0x3 => OddParity,
+        },
+        16     => busy: ro,
+        17     => flag: set_to_clear,
+    },
+});
+
+ioreg_proc::bitfield!(reg16 header {
+    0..3   => version,
+    4      => ack,
+    8..15  => length,
+});
+
+use value_test::{CrValue, CrGet};
+use value_test::cr::Parity;
+
+#[test]
+fn value_round_trip() {
+    let value = CrValue::new()
+        .with_enable(true)
+        .with_mode(0b101)
+        .with_channel(1, 0x3)
+        .with_parity(Parity::OddParity)
+        .with_busy(true);
+    assert!(value.enable());
+    assert_eq!(value.mode(), 0b101);
+    assert_eq!(value.channel(0), 0);
+    assert_eq!(value.channel(1), 0x3);
+    assert_eq!(value.parity(), Ok(Parity::OddParity));
+    assert!(value.busy());
+    assert_eq!(value.to_bits(), 0x1 | (0b101 << 1) | (0x3 << 6) | (0x3 << 14) | (1 << 16));
+}
+
+#[test]
+fn value_from_register() {
+    let test: value_test::ValueTest = unsafe { mem::zeroed() };
+    test.cr.update().set_mode(0b11).set_parity(Parity::EvenParity);
+    let value = CrValue::from(test.cr.get());
+    assert_eq!(value.mode(), 0b11);
+    assert_eq!(value.parity(), Ok(Parity::EvenParity));
+
+    let get = CrGet::from(value.with_enable(true));
+    assert!(get.enable());
+    assert_eq!(get.mode(), 0b11);
+}
+
+#[test]
+fn value_decodes_unknown_variant() {
+    // 0b01 isn't a parity variant, e.g. in bytes received from elsewhere
+    let value = CrValue::from_bits(0x1 << 14);
+    assert_eq!(value.parity(), Err(0x1));
+    assert_eq!(value.with_parity(Parity::EvenParity).parity(), Ok(Parity::EvenParity));
+}
+
+#[test]
+fn value_assigned_to_register() {
+    let test: value_test::ValueTest = unsafe { mem::zeroed() };
+    test.cr.update().set_enable(true);
+    test.cr.update().assign(CrValue::new().with_mode(0b10));
+    let get = test.cr.get();
+    assert!(!get.enable());
+    assert_eq!(get.mode(), 0b10);
+}
+
+#[test]
+fn standalone_bitfield() {
+    let h = Header::from_bits(0x2a13);
+    assert_eq!(h.version(), 0x3);
+    assert!(h.ack());
+    assert_eq!(h.length(), 0x2a);
+    assert_eq!(h.with_ack(false).with_length(0xff).to_bits(), 0xff03);
+    assert_eq!(mem::size_of::<Header>(), mem::size_of::<u16>());
+}
+
+ioreg_proc::ioregs!(CONST_TEST = {
+    0x0 => reg32 pllar {
+        0..7    => diva,
+        16..26  => mula,
+        29      => one,
+        30..31  => mode {
+            0 => Off,
+            1 => On,
+        },
+    },
+    0x4 => reg8 flags[2] {
+        0..7    => lane[4],
+    },
+});
+
+const PLLA_CFG: const_test::PllarValue = const_test::PllarValue::new()
+    .with_mula(0xd)
+    .with_diva(1)
+    .with_one(true)
+    .with_mode(const_test::pllar::Mode::On);
+
+static FLAG_TABLE: [const_test::FlagsValue; 2] = [
+    const_test::FlagsValue::new().with_lane(0, 0x3),
+    const_test::FlagsValue::from_bits(0xa5),
+];
+
+const _: () = assert!(PLLA_CFG.to_bits() == (1 << 30) | (1 << 29) | (0xd << 16) | 1);
+
+#[test]
+fn const_values_written_in_one_store() {
+    let test: const_test::ConstTest = unsafe { mem::zeroed() };
+    test.pllar.write(PLLA_CFG);
+    assert_eq!(test.pllar.get().mula(), 0xd);
+    assert_eq!(test.pllar.get().diva(), 1);
+    assert!(test.pllar.get().one());
+    assert_eq!(test.pllar.get().mode(), const_test::pllar::Mode::On);
+
+    for (reg, value) in test.flags.iter().zip(FLAG_TABLE.iter()) {
+        reg.write(*value);
+    }
+    assert_eq!(test.flags[0].get().lane(0), 0x3);
+    assert_eq!(test.flags[1].get().lane(0), 0x1);
+    assert_eq!(test.flags[1].get().lane(3), 0x2);
+}
@@ -0,0 +1,66 @@
+use std::future::Future;
+use std::mem;
+use std::pin::Pin;
+use std::task::{Context, Poll, RawWaker, RawWakerVTable, Waker};
+use volatile_cell::wait::{Timeout, WakerSlot};
+
+ioreg_proc::ioregs!(WAIT_TEST = {
+    0x0 => reg32 status {
+        0      => ready: ro,
+        1..3   => busy[3]: ro,
+        4..5   => mode: ro,
+    },
+});
+
+unsafe fn set_status(test: &wait_test::WaitTest, value: u32) {
+    *(&test.status as *const _ as *mut u32) = value;
+}
+
+fn noop_raw_waker() -> RawWaker {
+    fn clone(_: *const ()) -> RawWaker { noop_raw_waker() }
+    fn noop(_: *const ()) {}
+    static VTABLE: RawWakerVTable = RawWakerVTable::new(clone, noop, noop, noop);
+    RawWaker::new(std::ptr::null(), &VTABLE)
+}
+
+#[test]
+fn wait_timeout_gives_up() {
+    let test: wait_test::WaitTest = unsafe { mem::zeroed() };
+    assert_eq!(test.status.wait_ready_timeout(10), Err(Timeout));
+    assert_eq!(test.status.wait_busy_timeout(2, 10), Err(Timeout));
+}
+
+#[test]
+fn wait_returns_once_set() {
+    let test: wait_test::WaitTest = unsafe { mem::zeroed() };
+    unsafe { set_status(&test, 0b1001) };
+    assert_eq!(test.status.wait_ready_timeout(1), Ok(()));
+    assert_eq!(test.status.wait_busy_timeout(2, 1), Ok(()));
+    assert_eq!(test.status.wait_busy_timeout(0, 1), Err(Timeout));
+    test.status.wait_ready();
+    test.status.wait_busy(2);
+}
+
+#[test]
+fn wait_async_resolves_once_set() {
+    let test: wait_test::WaitTest = unsafe { mem::zeroed() };
+    let waker = unsafe { Waker::from_raw(noop_raw_waker()) };
+    let mut cx = Context::from_waker(&waker);
+    let mut fut = test.status.wait_ready_async();
+    assert_eq!(Pin::new(&mut fut).poll(&mut cx), Poll::Pending);
+    unsafe { set_status(&test, 0b1) };
+    assert_eq!(Pin::new(&mut fut).poll(&mut cx), Poll::Ready(()));
+}
+
+#[test]
+fn wait_async_woken_by_slot() {
+    static SLOT: WakerSlot = WakerSlot::new();
+    let test: wait_test::WaitTest = unsafe { mem::zeroed() };
+    let waker = unsafe { Waker::from_raw(noop_raw_waker()) };
+    let mut cx = Context::from_waker(&waker);
+    let mut fut = test.status.wait_busy_async(1).woken_by(&SLOT);
+    assert_eq!(Pin::new(&mut fut).poll(&mut cx), Poll::Pending);
+    unsafe { set_status(&test, 0b100) };
+    SLOT.wake();
+    assert_eq!(Pin::new(&mut fut).poll(&mut cx), Poll::Ready(()));
+}
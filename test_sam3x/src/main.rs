@@ -20,6 +20,7 @@ static LED_CONF: GpioConf = GpioConf {
 #[zinc_main]
 fn main() {
     use zinc::hal::sam3x::watchdog;
+    use zinc::hal::sam3x::peripherals;
     use zinc::hal::sam3x::pin;
     use zinc::hal::sam3x::system_clock;
 
@@ -30,7 +31,8 @@ fn main() {
 
     // watchdog::disable();
 
-    let led = pin::Pin::from(LED_CONF);
+    let p = peripherals::Peripherals::take().unwrap();
+    let led = pin::Pin::from_conf(&p, LED_CONF);
     led.set_low();
 }
 
@@ -0,0 +1,95 @@
+//! Access to registers through something other than memory mapped cells,
+//! e.g. devices on an I2C or SPI bus, or a simulator on the host.
+//!
+//! Peripherals declared with the `backend` option are generic over a
+//! `RegisterBackend` and created with `Peripheral::new(backend)`. Registers
+//! are then read and written through the backend with the same typed API as
+//! memory mapped peripherals.
+
+use core::ptr::{read_volatile, write_volatile};
+
+/// Reads and writes registers of a single device.
+///
+/// Bus errors can't be reported through the typed register API, so
+/// implementations have to deal with them, e.g. by recording them to be
+/// checked once a transaction is done.
+pub trait RegisterBackend {
+  /// Read the `width` bit register at `offset` bytes from the device base.
+  fn read(&self, offset: usize, width: u32) -> u64;
+
+  /// Write the `width` bit register at `offset` bytes from the device base.
+  fn write(&self, offset: usize, width: u32, value: u64);
+}
+
+impl<'a, T: RegisterBackend + ?Sized> RegisterBackend for &'a T {
+  #[inline(always)]
+  fn read(&self, offset: usize, width: u32) -> u64 {
+    (**self).read(offset, width)
+  }
+
+  #[inline(always)]
+  fn write(&self, offset: usize, width: u32, value: u64) {
+    (**self).write(offset, width, value)
+  }
+}
+
+/// Memory mapped registers starting at a base address, the same way the
+/// peripherals without a backend are accessed.
+///
+/// Registers of widths other than 8, 16, 32 and 64 bits are accessed one
+/// byte at a time, least significant first.
+#[derive(Clone, Copy)]
+pub struct Mmio {
+  base: usize,
+}
+
+impl Mmio {
+  /// Access the registers at `base`.
+  ///
+  /// Unsafe because `base` must be the address of the device registers.
+  pub const unsafe fn new(base: usize) -> Mmio {
+    Mmio {
+      base: base,
+    }
+  }
+}
+
+impl RegisterBackend for Mmio {
+  #[inline(always)]
+  fn read(&self, offset: usize, width: u32) -> u64 {
+    let address = self.base + offset;
+    unsafe {
+      match width {
+        8 => read_volatile(address as *const u8) as u64,
+        16 => read_volatile(address as *const u16) as u64,
+        32 => read_volatile(address as *const u32) as u64,
+        64 => read_volatile(address as *const u64),
+        _ => {
+          let mut value = 0;
+          for i in 0..(width as usize / 8) {
+            value |= (read_volatile((address + i) as *const u8) as u64) << (i * 8);
+          }
+          value
+        },
+      }
+    }
+  }
+
+  #[inline(always)]
+  fn write(&self, offset: usize, width: u32, value: u64) {
+    let address = self.base + offset;
+    unsafe {
+      match width {
+        8 => write_volatile(address as *mut u8, value as u8),
+        16 => write_volatile(address as *mut u16, value as u16),
+        32 => write_volatile(address as *mut u32, value as u32),
+        64 => write_volatile(address as *mut u64, value),
+        _ => {
+          for i in 0..(width as usize / 8) {
+            write_volatile((address + i) as *mut u8, (value >> (i * 8)) as u8);
+          }
+        },
+      }
+    }
+  }
+}
@@ -0,0 +1,94 @@
+//! Support types for code generated by `ioregs!` that describe register fields.
+
+use core::fmt;
+
+/// A field value decoded into its variant enum, or the raw value if it
+/// doesn't match any variant. Used by the generated `Debug` implementations.
+pub struct Decoded<E, R>(pub Result<E, R>);
+
+impl<E: fmt::Debug, R: fmt::LowerHex> fmt::Debug for Decoded<E, R> {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self.0 {
+      Ok(ref variant) => variant.fmt(f),
+      Err(ref raw) => write!(f, "{:#x}", raw),
+    }
+  }
+}
+
+/// Location of a (possibly repeated) field within a register.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FieldDescriptor {
+  pub name: &'static str,
+  /// Bit offset of the first element.
+  pub offset: u32,
+  /// Width in bits of a single element.
+  pub width: u32,
+  /// Number of consecutive elements, 1 for plain fields.
+  pub count: u32,
+}
+
+impl FieldDescriptor {
+  #[inline]
+  fn extract(&self, value: u64, index: u32) -> u64 {
+    let mask = if self.width >= 64 { !0 } else { (1u64 << self.width) - 1 };
+    (value >> (self.offset + self.width * index)) & mask
+  }
+}
+
+/// A field whose value differs between two readings of a register.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FieldChange {
+  pub name: &'static str,
+  /// Element index for repeated fields.
+  pub index: Option<usize>,
+  pub old: u64,
+  pub new: u64,
+}
+
+/// Iterator over the fields that changed between two register values.
+pub struct FieldChanges {
+  fields: &'static [FieldDescriptor],
+  old: u64,
+  new: u64,
+  field: usize,
+  index: u32,
+}
+
+impl FieldChanges {
+  pub fn new(fields: &'static [FieldDescriptor], old: u64, new: u64) -> FieldChanges {
+    FieldChanges {
+      fields: fields,
+      old: old,
+      new: new,
+      field: 0,
+      index: 0,
+    }
+  }
+}
+
+impl Iterator for FieldChanges {
+  type Item = FieldChange;
+
+  fn next(&mut self) -> Option<FieldChange> {
+    while self.field < self.fields.len() {
+      let desc = &self.fields[self.field];
+      let index = self.index;
+      self.index += 1;
+      if self.index >= desc.count {
+        self.field += 1;
+        self.index = 0;
+      }
+      let old = desc.extract(self.old, index);
+      let new = desc.extract(self.new, index);
+      if old != new {
+        return Some(FieldChange {
+          name: desc.name,
+          index: if desc.count > 1 { Some(index as usize) } else { None },
+          old: old,
+          new: new,
+        });
+      }
+    }
+    None
+  }
+}
@@ -18,20 +18,21 @@
 #![no_std]
 
 
-#[cfg(feature="replayer")] #[macro_use(expect)] extern crate expectest;
+#[cfg(feature="replayer")] extern crate expectest;
 #[cfg(feature="replayer")] #[macro_use] extern crate std;
 
 #[cfg(feature="replayer")] use std::vec::Vec;
-#[cfg(feature="replayer")] use expectest::prelude::*;
-#[cfg(feature="replayer")] use expectest::core::Matcher;
 #[cfg(feature="replayer")] use std::string::String;
-#[cfg(feature="replayer")] use std::fmt;
-#[cfg(feature="replayer")] use core::cmp::PartialEq;
-#[cfg(feature="replayer")] use core::clone::Clone;
 #[cfg(feature="replayer")] use core::cell::RefCell;
 
 #[cfg(not(feature="replayer"))] use core::ptr::{read_volatile as volatile_load, write_volatile as volatile_store};
-#[cfg(feature="replayer")] use core::mem::transmute;
+#[cfg(feature="replayer")] use core::mem;
+
+pub mod backend;
+pub mod field;
+pub mod sync;
+pub mod trace;
+pub mod wait;
 
 // TODO(farcaller): why this needs copy/clone?
 /// This structure is used to represent a hardware register.
@@ -50,6 +51,12 @@ impl<T: Copy> VolatileCell<T> {
     }
   }
 
+  /// Get a raw pointer to the register, e.g. to derive its address.
+  #[inline]
+  pub fn as_ptr(&self) -> *mut T {
+    &self.value as *const T as *mut T
+  }
+
   /// Get register value.
   #[cfg(not(feature="replayer"))]
   #[inline]
@@ -70,47 +77,79 @@ impl<T: Copy> VolatileCell<T> {
 }
 
 #[cfg(feature="replayer")]
-impl VolatileCell<u32> {
-  pub fn get(&self) -> u32 {
-    unsafe {
-      GLOBAL_REPLAYER.with(|gr| { gr.borrow_mut().get_cell(transmute(&self.value)) })
-    }
+impl<T: ReplayValue> VolatileCell<T> {
+  /// Get register value from the replayer.
+  pub fn get(&self) -> T {
+    let address = self.as_ptr() as usize;
+    let bits = GLOBAL_REPLAYER.with(|gr| { gr.borrow_mut().get_cell(address, T::WIDTH) });
+    T::from_bits(bits)
   }
 
-  pub fn set(&self, value: u32) {
-    unsafe {
-      GLOBAL_REPLAYER.with(|gr| { gr.borrow_mut().set_cell(transmute(&self.value), value) })
-    }
+  /// Set register value through the replayer.
+  pub fn set(&self, value: T) {
+    let address = self.as_ptr() as usize;
+    GLOBAL_REPLAYER.with(|gr| { gr.borrow_mut().set_cell(address, T::WIDTH, value.to_bits()) })
   }
 }
 
 #[cfg(feature="replayer")]
-impl VolatileCell<u16> {
-  pub fn get(&self) -> u16 {
-    unsafe {
-      GLOBAL_REPLAYER.with(|gr| { gr.borrow_mut().get_cell(transmute(&self.value)) }) as u16
-    }
-  }
+mod private {
+  pub trait Sealed {}
+}
 
-  pub fn set(&self, value: u16) {
-    unsafe {
-      GLOBAL_REPLAYER.with(|gr| { gr.borrow_mut().set_cell(transmute(&self.value), value as u32) })
-    }
-  }
+/// Values of the cells the replayer can stand in for: integers of up to 64
+/// bits, which any bits replayed are valid for, and `bool`.
+#[cfg(feature="replayer")]
+pub trait ReplayValue: Copy + private::Sealed {
+  /// Width in bits of the accesses to a cell.
+  const WIDTH: u32;
+
+  /// Zero-extends the value to 64 bits.
+  fn to_bits(self) -> u64;
+
+  /// Truncates `bits` to a value.
+  fn from_bits(bits: u64) -> Self;
 }
 
 #[cfg(feature="replayer")]
-impl VolatileCell<u8> {
-  pub fn get(&self) -> u8 {
-    unsafe {
-      GLOBAL_REPLAYER.with(|gr| { gr.borrow_mut().get_cell(transmute(&self.value)) }) as u8
-    }
+macro_rules! replay_value {
+  ($($ty:ty as $unsigned:ty),*) => {
+    $(
+      impl private::Sealed for $ty {}
+
+      impl ReplayValue for $ty {
+        const WIDTH: u32 = (mem::size_of::<$ty>() * 8) as u32;
+
+        fn to_bits(self) -> u64 {
+          self as $unsigned as u64
+        }
+
+        fn from_bits(bits: u64) -> $ty {
+          bits as $unsigned as $ty
+        }
+      }
+    )*
+  };
+}
+
+#[cfg(feature="replayer")]
+replay_value!(u8 as u8, u16 as u16, u32 as u32, u64 as u64, usize as usize,
+              i8 as u8, i16 as u16, i32 as u32, i64 as u64, isize as usize);
+
+#[cfg(feature="replayer")]
+impl private::Sealed for bool {}
+
+/// A `bool` is replayed as a byte, any non-zero byte reading as `true`.
+#[cfg(feature="replayer")]
+impl ReplayValue for bool {
+  const WIDTH: u32 = 8;
+
+  fn to_bits(self) -> u64 {
+    self as u64
   }
 
-  pub fn set(&self, value: u8) {
-    unsafe {
-      GLOBAL_REPLAYER.with(|gr| { gr.borrow_mut().set_cell(transmute(&self.value), value as u32) })
-    }
+  fn from_bits(bits: u64) -> bool {
+    bits as u8 != 0
   }
 }
 
@@ -118,12 +157,14 @@ impl VolatileCell<u8> {
 struct ReplayRecord {
   is_read: bool,
   address: usize,
-  value: u32,
+  width: u32,
+  value: u64,
 
   replayed: bool,
   did_read: bool,
   actual_address: usize,
-  actual_value: u32,
+  actual_width: u32,
+  actual_value: u64,
 
   loc: expectest::core::SourceLocation,
 }
@@ -132,8 +173,8 @@ struct ReplayRecord {
 impl core::fmt::Display for ReplayRecord {
   fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
     match self.is_read {
-      true  => write!(f, "read 0x{:x} from 0x{:x}", self.value, self.address),
-      false => write!(f, "write 0x{:x} to 0x{:x}", self.value, self.address),
+      true  => write!(f, "{}-bit read 0x{:x} from 0x{:x}", self.width, self.value, self.address),
+      false => write!(f, "{}-bit write 0x{:x} to 0x{:x}", self.width, self.value, self.address),
     }
   }
 }
@@ -153,81 +194,95 @@ impl VolatileCellReplayer {
     }
   }
 
+  /// Expect a 32-bit read of `address`, returning `value`.
   pub fn expect_read(&mut self, address: usize, value: u32,
       loc: expectest::core::SourceLocation) {
-    self.replays.push(ReplayRecord {
-      is_read: true,
-      address: address,
-      value: value,
-      replayed: false,
-      did_read: false,
-      actual_address: 0,
-      actual_value: 0,
-      loc: loc,
-    });
+    self.expect_read_sized(address, 32, value as u64, loc);
   }
 
+  /// Expect a `width` bit read of `address`, returning `value`.
+  pub fn expect_read_sized(&mut self, address: usize, width: u32, value: u64,
+      loc: expectest::core::SourceLocation) {
+    self.push_replay(true, address, width, value, loc);
+  }
+
+  /// Expect a 32-bit write of `value` to `address`.
   pub fn expect_write(&mut self, address: usize, value: u32,
       loc: expectest::core::SourceLocation) {
+    self.expect_write_sized(address, 32, value as u64, loc);
+  }
+
+  /// Expect a `width` bit write of `value` to `address`.
+  pub fn expect_write_sized(&mut self, address: usize, width: u32, value: u64,
+      loc: expectest::core::SourceLocation) {
+    self.push_replay(false, address, width, value, loc);
+  }
+
+  fn push_replay(&mut self, is_read: bool, address: usize, width: u32, value: u64,
+      loc: expectest::core::SourceLocation) {
     self.replays.push(ReplayRecord {
-      is_read: false,
+      is_read: is_read,
       address: address,
+      width: width,
       value: value,
       replayed: false,
       did_read: false,
       actual_address: 0,
+      actual_width: 0,
       actual_value: 0,
       loc: loc,
     });
   }
 
   pub fn verify(&self, loc: expectest::core::SourceLocation) {
-    expect(self.current_replay).location(loc).to(
-      be_equal_to_with_context(
-          self.replays.len(),
-          format!("expected {} replays, performed {}",
-              self.replays.len(), self.current_replay)));
+    check(self.current_replay == self.replays.len(), loc,
+      format!("expected {} replays, performed {}",
+          self.replays.len(), self.current_replay));
 
     for ref replay in &*self.replays {
-      expect(replay.replayed).location(replay.loc).to(be_equal_to_with_context(true,
-        format!("expected replay {} to be performed, was not", replay)));
-      expect(replay.is_read).location(replay.loc).to(be_equal_to_with_context(replay.did_read,
+      check(replay.replayed, replay.loc,
+        format!("expected replay {} to be performed, was not", replay));
+      check(replay.is_read == replay.did_read, replay.loc,
         format!("expected replay to be {} replay, was {} replay",
           if replay.is_read {"read"} else {"write"},
-          if replay.is_read {"write"} else {"read"})));
-      expect(replay.address).location(replay.loc).to(be_equal_to_with_context(replay.actual_address,
-        format!("expected replay address 0x{:x}, was 0x{:x}", replay.address, replay.actual_address)));
+          if replay.is_read {"write"} else {"read"}));
+      check(replay.address == replay.actual_address, replay.loc,
+        format!("expected replay address 0x{:x}, was 0x{:x}", replay.address, replay.actual_address));
+      check(replay.width == replay.actual_width, replay.loc,
+        format!("expected replay to be {}-bit access, was {}-bit access", replay.width, replay.actual_width));
       if !replay.is_read {
-        expect(replay.value).location(replay.loc).to(be_equal_to_with_context(replay.actual_value,
-          format!("expected replay to write 0x{:x}, written 0x{:x}", replay.value, replay.actual_value)));
+        check(replay.value == replay.actual_value, replay.loc,
+          format!("expected replay to write 0x{:x}, written 0x{:x}", replay.value, replay.actual_value));
       }
     }
   }
 
-  pub fn get_cell(&mut self, address: usize) -> u32 {
+  pub fn get_cell(&mut self, address: usize, width: u32) -> u64 {
     if self.current_replay >= self.replays.len() {
-      panic!("get_cell(0x{:x}) faled, current replay: {}, total replays: {}",
-        address, self.current_replay+1, self.replays.len());
+      panic!("get_cell(0x{:x}, {}-bit) faled, current replay: {}, total replays: {}",
+        address, width, self.current_replay+1, self.replays.len());
     }
     let replay: &mut ReplayRecord = &mut self.replays[self.current_replay];
     replay.replayed = true;
     replay.did_read = true;
     replay.actual_address = address;
+    replay.actual_width = width;
 
     self.current_replay += 1;
 
     replay.value
   }
 
-  pub fn set_cell(&mut self, address: usize, value: u32) {
+  pub fn set_cell(&mut self, address: usize, width: u32, value: u64) {
     if self.current_replay >= self.replays.len() {
-      panic!("set_cell(0x{:x}, 0x{:x}) faled, current replay: {}, total replays: {}",
-        address, value, self.current_replay+1, self.replays.len());
+      panic!("set_cell(0x{:x}, {}-bit, 0x{:x}) faled, current replay: {}, total replays: {}",
+        address, width, value, self.current_replay+1, self.replays.len());
     }
     let replay: &mut ReplayRecord = &mut self.replays[self.current_replay];
     replay.replayed = true;
     replay.did_read = false;
     replay.actual_address = address;
+    replay.actual_width = width;
     replay.actual_value = value;
 
     self.current_replay += 1;
@@ -253,33 +308,13 @@ pub fn with_mut_replayer<F>(f: F) where F: core::ops::FnOnce(&mut VolatileCellRe
   });
 }
 
+/// Fails the test at `loc` with `context` as the panic message, so that
+/// `#[should_panic(expected = ...)]` can match on what went wrong.
 #[cfg(feature="replayer")]
-struct BeEqualToWithContext<E> {
-    expected: E,
-    context: String,
-}
-
-#[cfg(feature="replayer")]
-fn be_equal_to_with_context<E>(expected: E, context: String) -> BeEqualToWithContext<E> {
-    BeEqualToWithContext {
-        expected: expected,
-        context: context,
-    }
-}
-
-#[cfg(feature="replayer")]
-impl<A, E> Matcher<A, E> for BeEqualToWithContext<E>
-    where
-        A: PartialEq<E> + fmt::Debug,
-        E: fmt::Debug {
-
-    fn failure_message(&self, _: expectest::core::Join, _: &A) -> String {
-        self.context.clone()
-    }
-
-    fn matches(&self, actual: &A) -> bool {
-        *actual == self.expected
-    }
+fn check(ok: bool, loc: expectest::core::SourceLocation, context: String) {
+  if !ok {
+    panic!("{:?}: {}", loc, context);
+  }
 }
 
 #[macro_export]
@@ -289,6 +324,11 @@ macro_rules! expect_volatile_read {
       r.expect_read($addr, $val, expectest::core::SourceLocation::new(file!(), line!()));
     })
   );
+  ($addr: expr, $val: expr, width = $width: expr) => (
+    $crate::with_mut_replayer(|r| {
+      r.expect_read_sized($addr, $width, $val, expectest::core::SourceLocation::new(file!(), line!()));
+    })
+  );
 }
 
 #[macro_export]
@@ -298,6 +338,11 @@ macro_rules! expect_volatile_write {
       r.expect_write($addr, $val, expectest::core::SourceLocation::new(file!(), line!()));
     })
   );
+  ($addr: expr, $val: expr, width = $width: expr) => (
+    $crate::with_mut_replayer(|r| {
+      r.expect_write_sized($addr, $width, $val, expectest::core::SourceLocation::new(file!(), line!()));
+    })
+  );
 }
 
 #[macro_export]
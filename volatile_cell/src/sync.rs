@@ -0,0 +1,14 @@
+//! Support for updating registers that are shared with interrupt handlers.
+
+/// A token proving that the holder can't be preempted by anything else
+/// touching the registers, e.g. because interrupts are disabled.
+///
+/// Generated registers provide `update_in(&cs)`, which ties the lifetime of
+/// the updater to the critical section so that its read-modify-write can't
+/// outlive it.
+///
+/// # Safety
+///
+/// Implementors must guarantee that no interrupt handler (or other core) can
+/// run while a value of the implementing type is alive.
+pub unsafe trait CriticalSection {}
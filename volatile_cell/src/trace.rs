@@ -0,0 +1,56 @@
+//! Hook for tracing register accesses made by code generated by `ioregs!`
+//! with the `tracing` feature.
+//!
+//! Every `get()` and every store of an updater is reported to the hook set
+//! with `set_hook`, e.g. to push into a ring buffer drained over RTT/ITM, or
+//! to a `Vec` in host tests. Exclusive, bit-band and lane accesses
+//! aren't reported.
+
+use core::mem;
+use core::ptr;
+use core::sync::atomic::{AtomicPtr, Ordering};
+
+/// Direction of a register access.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Access {
+  Read,
+  Write,
+}
+
+/// A single register access.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Event {
+  /// Module path of the peripheral (or register group), e.g. `pmc`.
+  pub peripheral: &'static str,
+  pub register: &'static str,
+  pub address: usize,
+  /// Value read or written, zero-extended.
+  pub value: u64,
+  pub access: Access,
+}
+
+/// Function called for every traced access.
+pub type Hook = fn(&Event);
+
+static HOOK: AtomicPtr<()> = AtomicPtr::new(ptr::null_mut());
+
+/// Set the function called for every traced access, replacing any previous
+/// one.
+pub fn set_hook(hook: Hook) {
+  HOOK.store(hook as *mut (), Ordering::SeqCst);
+}
+
+/// Stop reporting accesses.
+pub fn clear_hook() {
+  HOOK.store(ptr::null_mut(), Ordering::SeqCst);
+}
+
+/// Report `event` to the hook, if any.
+#[inline]
+pub fn record(event: &Event) {
+  let hook = HOOK.load(Ordering::SeqCst);
+  if !hook.is_null() {
+    let hook: Hook = unsafe { mem::transmute(hook) };
+    hook(event);
+  }
+}
@@ -0,0 +1,130 @@
+//! Support for the `wait_*` helpers generated for single-bit fields, which
+//! wait for a bit to be set with a spin loop, a bounded number of polls, or
+//! a `Future`.
+
+use core::cell::UnsafeCell;
+use core::future::Future;
+use core::pin::Pin;
+use core::sync::atomic::{AtomicBool, Ordering};
+use core::task::{Context, Poll, Waker};
+
+/// The condition didn't become true within the allowed number of polls.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Timeout;
+
+/// Poll `ready` until it returns true, at most `max_polls` times.
+#[inline]
+pub fn poll_until<F: FnMut() -> bool>(mut ready: F, max_polls: u32) -> Result<(), Timeout> {
+  for _ in 0..max_polls {
+    if ready() {
+      return Ok(());
+    }
+  }
+  Err(Timeout)
+}
+
+/// Holds the waker of a task waiting for an interrupt.
+///
+/// Futures created with `WaitUntil::woken_by` register here while pending,
+/// and the interrupt handler calls `wake` once the condition may have
+/// changed.
+pub struct WakerSlot {
+  locked: AtomicBool,
+  waker: UnsafeCell<Option<Waker>>,
+}
+
+// The waker is only accessed while holding the lock
+unsafe impl Sync for WakerSlot {}
+
+impl WakerSlot {
+  pub const fn new() -> WakerSlot {
+    WakerSlot {
+      locked: AtomicBool::new(false),
+      waker: UnsafeCell::new(None),
+    }
+  }
+
+  fn with_lock<R, F: FnOnce(&mut Option<Waker>) -> R>(&self, f: F) -> Option<R> {
+    if self.locked.swap(true, Ordering::Acquire) {
+      return None;
+    }
+    let ret = f(unsafe { &mut *self.waker.get() });
+    self.locked.store(false, Ordering::Release);
+    Some(ret)
+  }
+
+  /// Store `waker` to be woken by the next call to `wake`.
+  pub fn register(&self, waker: &Waker) {
+    let registered = self.with_lock(|slot| match *slot {
+      Some(ref current) if current.will_wake(waker) => {},
+      _ => *slot = Some(waker.clone()),
+    });
+    if registered.is_none() {
+      // Contended, so make sure the task polls again
+      waker.wake_by_ref();
+    }
+  }
+
+  /// Wake the registered task, if any. Meant to be called from interrupt
+  /// handlers.
+  pub fn wake(&self) {
+    if let Some(Some(waker)) = self.with_lock(|slot| slot.take()) {
+      waker.wake();
+    }
+  }
+}
+
+impl Default for WakerSlot {
+  fn default() -> WakerSlot {
+    WakerSlot::new()
+  }
+}
+
+/// Future resolving once `ready` returns true.
+///
+/// By default it asks to be polled again right away, so the executor keeps
+/// polling it; with `woken_by` it instead waits for an interrupt to wake it
+/// through a `WakerSlot`.
+pub struct WaitUntil<'a, F> {
+  ready: F,
+  slot: Option<&'a WakerSlot>,
+}
+
+impl<'a, F: FnMut() -> bool> WaitUntil<'a, F> {
+  pub fn new(ready: F) -> WaitUntil<'a, F> {
+    WaitUntil {
+      ready: ready,
+      slot: None,
+    }
+  }
+
+  /// Wait to be woken through `slot` rather than polling continuously.
+  pub fn woken_by(self, slot: &'a WakerSlot) -> WaitUntil<'a, F> {
+    WaitUntil {
+      ready: self.ready,
+      slot: Some(slot),
+    }
+  }
+}
+
+impl<'a, F: FnMut() -> bool + Unpin> Future for WaitUntil<'a, F> {
+  type Output = ();
+
+  fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<()> {
+    let this = self.get_mut();
+    if (this.ready)() {
+      return Poll::Ready(());
+    }
+    match this.slot {
+      Some(slot) => {
+        slot.register(cx.waker());
+        // The interrupt may have fired before the waker was registered
+        if (this.ready)() {
+          return Poll::Ready(());
+        }
+      },
+      None => cx.waker().wake_by_ref(),
+    }
+    Poll::Pending
+  }
+}
@@ -0,0 +1,88 @@
+//! Replays of cells of every width. Needs the `replayer` feature, so run with
+//! `cargo test -p volatile_cell --features replayer`.
+
+#![cfg(feature="replayer")]
+
+#[macro_use] extern crate volatile_cell;
+extern crate expectest;
+
+use volatile_cell::VolatileCell;
+
+#[test]
+fn replays_u64_cell() {
+  let cell = VolatileCell::new(0u64);
+  let address = cell.as_ptr() as usize;
+  expect_volatile_read!(address, 0x1122_3344_5566_7788, width = 64);
+  expect_volatile_write!(address, 0xffff_0000_ffff_0000, width = 64);
+  assert_eq!(cell.get(), 0x1122_3344_5566_7788);
+  cell.set(0xffff_0000_ffff_0000);
+  expect_replayer_valid!();
+}
+
+#[test]
+fn replays_u8_cell() {
+  let cell = VolatileCell::new(0u8);
+  let address = cell.as_ptr() as usize;
+  expect_volatile_read!(address, 0xab, width = 8);
+  expect_volatile_write!(address, 0x5a, width = 8);
+  assert_eq!(cell.get(), 0xab);
+  cell.set(0x5a);
+  expect_replayer_valid!();
+}
+
+#[test]
+fn replays_signed_cell_zero_extended() {
+  let cell = VolatileCell::new(0i16);
+  let address = cell.as_ptr() as usize;
+  expect_volatile_read!(address, 0x8001, width = 16);
+  expect_volatile_write!(address, 0xffff, width = 16);
+  assert_eq!(cell.get(), -0x7fff);
+  cell.set(-1);
+  expect_replayer_valid!();
+}
+
+#[test]
+fn replays_usize_cell_at_pointer_width() {
+  let width = (std::mem::size_of::<usize>() * 8) as u32;
+  let cell = VolatileCell::new(0usize);
+  let address = cell.as_ptr() as usize;
+  expect_volatile_read!(address, 0x1234_5678, width = width);
+  expect_volatile_write!(address, 0x8765_4321, width = width);
+  assert_eq!(cell.get(), 0x1234_5678);
+  cell.set(0x8765_4321);
+  expect_replayer_valid!();
+}
+
+#[test]
+fn replays_isize_cell_zero_extended() {
+  let width = (std::mem::size_of::<isize>() * 8) as u32;
+  let cell = VolatileCell::new(0isize);
+  let address = cell.as_ptr() as usize;
+  expect_volatile_read!(address, 0x7fff, width = width);
+  expect_volatile_write!(address, usize::max_value() as u64, width = width);
+  assert_eq!(cell.get(), 0x7fff);
+  cell.set(-1);
+  expect_replayer_valid!();
+}
+
+#[test]
+fn replays_bool_cell() {
+  let cell = VolatileCell::new(false);
+  let address = cell.as_ptr() as usize;
+  expect_volatile_read!(address, 0x0, width = 8);
+  expect_volatile_read!(address, 0x2, width = 8);
+  expect_volatile_write!(address, 0x1, width = 8);
+  assert_eq!(cell.get(), false);
+  assert_eq!(cell.get(), true);
+  cell.set(true);
+  expect_replayer_valid!();
+}
+
+#[test]
+#[should_panic(expected = "expected replay to be 32-bit access, was 8-bit access")]
+fn reports_width_mismatch() {
+  let cell = VolatileCell::new(0u8);
+  expect_volatile_write!(cell.as_ptr() as usize, 0x1);
+  cell.set(0x1);
+  expect_replayer_valid!();
+}
@@ -22,14 +22,18 @@ fn main(_: isize, _: *const *const u8) -> isize {
     unsafe { mem_init::init_stack() };
     mem_init::init_data();
 
-    let mck_freq = system_clock::init_clock(system_clock::ClockSource::InternalSlow, None);
+    let mck_freq = system_clock::init_clock(system_clock::ClockSource::InternalSlow, None)
+        .unwrap();
 
     let pll = system_clock::Pll {
         mul: 0x1,
         div: 0x1,
         count: 0x3f,
     };
-    let mck_freq = system_clock::init_clock(system_clock::ClockSource::Main(Some(12_000_000)), Some(pll));
+    // Fall back to the internal RC oscillator if there's no crystal fitted
+    let mck_freq = system_clock::init_clock(system_clock::ClockSource::Main(Some(12_000_000)), Some(pll))
+        .or_else(|_| system_clock::init_clock(system_clock::ClockSource::InternalRc(system_clock::RCFreq::MHz_12), None))
+        .unwrap();
     systick::setup(mck_freq / 1000);
     systick::enable();
     // system_clock::temp_boot();
@@ -37,7 +41,8 @@ fn main(_: isize, _: *const *const u8) -> isize {
     use zinc::hal::sam3x::watchdog;
     watchdog::disable();
 
-    let led = ::zinc::hal::sam3x::pin::Pin::from(LED_CONF);
+    let p = ::zinc::hal::sam3x::peripherals::Peripherals::take().unwrap();
+    let led = ::zinc::hal::sam3x::pin::Pin::from_conf(&p, LED_CONF);
     real_main(&led)
 }
 
@@ -19,7 +19,8 @@ fn start(_: isize, _: *const *const u8) -> isize {
     use zinc::hal::sam3x::watchdog;
     watchdog::disable();
 
-    let led = zinc::hal::sam3x::pin::Pin::from(LED_CONF);
+    let p = zinc::hal::sam3x::peripherals::Peripherals::take().unwrap();
+    let led = zinc::hal::sam3x::pin::Pin::from_conf(&p, LED_CONF);
     led.set_low();
 
     0
@@ -35,6 +35,9 @@ impl NoInterrupts {
   }
 }
 
+// Interrupts stay disabled for as long as the token is alive.
+unsafe impl ::volatile_cell::sync::CriticalSection for NoInterrupts {}
+
 impl Drop for NoInterrupts {
   fn drop(&mut self) {
     unsafe {
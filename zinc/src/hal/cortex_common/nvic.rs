@@ -55,12 +55,13 @@ pub fn is_active(irqn: usize) -> bool {
 
 /// Set the priority for the given interrupt
 pub fn set_priority(irqn: usize, prio: u8) {
-  get_reg().ipr[irqn / 4].update().set_ipr(irqn % 4, prio);
+  // Byte store, so other priorities in the register can't be clobbered
+  get_reg().ipr[irqn / 4].write_ipr(irqn % 4, prio);
 }
 
 /// Return the priority for the given interrupt
 pub fn get_priority(irqn: usize) -> u8 {
-  get_reg().ipr[irqn / 4].get().ipr(irqn % 4) as u8
+  get_reg().ipr[irqn / 4].read_ipr(irqn % 4)
 }
 
 mod reg {
@@ -83,7 +84,7 @@ mod reg {
     },
     0x400     => reg32 ipr[8] {       // Interrupt priority register
       0..31   => ipr[4],
-    },
+    }: bytes,
     0xF00     => reg32 stir[8] {      // Software triggered interrupt register
       0..8    => stir,
     },
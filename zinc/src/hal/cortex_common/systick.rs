@@ -85,6 +85,39 @@ pub fn tick() -> bool {
   get_reg().csr.get().countflag()
 }
 
+/// A point in time a number of SysTick periods from now, for bounding busy
+/// waits.
+///
+/// Time is counted in SysTick wraps, observed through `countflag`. Nothing
+/// here configures the timer: the caller must already have it enabled with a
+/// 1ms period at the current core clock (e.g. `setup(mck_freq / 1000)`), or a
+/// deadline never expires, and it's only as accurate as that setup. As
+/// reading `countflag` clears it, only one deadline should be polled at a
+/// time, and `tick()` shouldn't be used meanwhile.
+pub struct Deadline {
+  remaining: u32,
+}
+
+impl Deadline {
+  /// Creates a deadline `ms` SysTick periods from now.
+  pub fn after_ms(ms: u32) -> Deadline {
+    debug_assert!(get_reg().csr.get().enable(), "SysTick isn't running");
+    // Discard a wrap that happened before now
+    tick();
+    Deadline {
+      remaining: ms,
+    }
+  }
+
+  /// Checks whether the deadline has passed.
+  pub fn expired(&mut self) -> bool {
+    if self.remaining > 0 && tick() {
+      self.remaining -= 1;
+    }
+    self.remaining == 0
+  }
+}
+
 #[allow(dead_code)]
 mod reg {
   use ioreg_proc::ioregs;
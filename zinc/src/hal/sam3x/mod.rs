@@ -1,5 +1,6 @@
 pub mod watchdog;
 pub mod peripheral_clock;
+pub mod peripherals;
 pub mod pin;
 pub mod system_clock;
 pub mod scb;
@@ -1,22 +1,18 @@
-#[derive(Clone, Copy)]
-pub struct PeripheralClock {
+/// Clock of a peripheral, switched through the PMC token it borrows.
+pub struct PeripheralClock<'a> {
+    pmc: &'a reg::pmc::PmcPeripheral,
     pub index: u8,
 }
 
-fn get_pmc() -> &'static reg::pmc::Pmc {
-    unsafe {
-        &reg::PMC_I
-    }
-}
-
 enum Group {
     Group0,
     Group1,
 }
 
-impl PeripheralClock {
-    pub fn new(index: u8) -> PeripheralClock {
+impl<'a> PeripheralClock<'a> {
+    pub fn new(pmc: &'a reg::pmc::PmcPeripheral, index: u8) -> PeripheralClock<'a> {
         PeripheralClock {
+            pmc: pmc,
             index: index,
         }
     }
@@ -24,10 +20,10 @@ impl PeripheralClock {
     pub fn enable(&self) {
         match self.group_info() {
             (Group::Group0, idx) => {
-                get_pmc().pcer0.update().set_pce(idx as usize, true);
+                self.pmc.pcer0.update().set_pce(idx as usize, true);
             },
             (Group::Group1, idx) => {
-                get_pmc().pcer1.update().set_pce(idx as usize, true);
+                self.pmc.pcer1.update().set_pce(idx as usize, true);
             },
         };
     }
@@ -35,10 +31,10 @@ impl PeripheralClock {
     pub fn disable(&self) {
         match self.group_info() {
             (Group::Group0, idx) => {
-                get_pmc().pcdr0.update().set_pcd(idx as usize, true);
+                self.pmc.pcdr0.update().set_pcd(idx as usize, true);
             },
             (Group::Group1, idx) => {
-                get_pmc().pcdr1.update().set_pcd(idx as usize, true);
+                self.pmc.pcdr1.update().set_pcd(idx as usize, true);
             },
         };
     }
@@ -52,7 +48,7 @@ impl PeripheralClock {
     }
 }
 
-mod reg {
+pub(super) mod reg {
     use ioreg_proc::ioregs;
 
     ioregs!(PMC = {
@@ -78,6 +74,6 @@ mod reg {
 
     extern {
         #[link_name="sam3x_iomem_PMC"]
-        pub static PMC_I: pmc::Pmc;
+        pub static mut PMC_I: pmc::Pmc;
     }
 }
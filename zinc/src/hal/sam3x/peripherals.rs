@@ -0,0 +1,15 @@
+//! Owned peripheral tokens, handed out once by `Peripherals::take()`.
+
+use core::ptr;
+use ioreg_proc::peripherals;
+use super::{peripheral_clock, pin};
+
+peripherals!(Peripherals {
+    PIOA: pin::reg::pio::PioPeripheral = &mut *ptr::addr_of_mut!(pin::reg::PIO_A),
+    PIOB: pin::reg::pio::PioPeripheral = &mut *ptr::addr_of_mut!(pin::reg::PIO_B),
+    PIOC: pin::reg::pio::PioPeripheral = &mut *ptr::addr_of_mut!(pin::reg::PIO_C),
+    PIOD: pin::reg::pio::PioPeripheral = &mut *ptr::addr_of_mut!(pin::reg::PIO_D),
+    PIOE: pin::reg::pio::PioPeripheral = &mut *ptr::addr_of_mut!(pin::reg::PIO_E),
+    PIOF: pin::reg::pio::PioPeripheral = &mut *ptr::addr_of_mut!(pin::reg::PIO_F),
+    PMC: peripheral_clock::reg::pmc::PmcPeripheral = &mut *ptr::addr_of_mut!(peripheral_clock::reg::PMC_I),
+});
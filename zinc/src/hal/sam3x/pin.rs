@@ -1,4 +1,5 @@
 use super::peripheral_clock;
+use super::peripherals::Peripherals;
 pub use self::Controller::*;
 use hal::pin;
 use hal::pin::{Gpio, GpioConf};
@@ -31,92 +32,85 @@ pub enum Function {
 }
 
 impl Controller {
-    fn to_reg(self) -> &'static reg::pio::Pio {
-        unsafe {
-            match self {
-                ControllerA => &reg::PIO_A,
-                ControllerB => &reg::PIO_B,
-                ControllerC => &reg::PIO_C,
-                ControllerD => &reg::PIO_D,
-                ControllerE => &reg::PIO_E,
-                ControllerF => &reg::PIO_F,
-            }
+    /// The token of the controller's registers, out of the peripheral tokens.
+    pub fn pio<'a>(self, peripherals: &'a Peripherals) -> &'a reg::pio::PioPeripheral {
+        match self {
+            ControllerA => &peripherals.PIOA,
+            ControllerB => &peripherals.PIOB,
+            ControllerC => &peripherals.PIOC,
+            ControllerD => &peripherals.PIOD,
+            ControllerE => &peripherals.PIOE,
+            ControllerF => &peripherals.PIOF,
         }
     }
 
-    fn clock(self) -> peripheral_clock::PeripheralClock {
+    /// The clock of the controller, switched through `pmc`.
+    pub fn clock<'a>(self, pmc: &'a peripheral_clock::reg::pmc::PmcPeripheral)
+                     -> peripheral_clock::PeripheralClock<'a> {
         let index = match self {
             ControllerA => 11,
             ControllerB => 12,
             _ => unimplemented!(),
         };
-        peripheral_clock::PeripheralClock {
-            index: index,
-        }
+        peripheral_clock::PeripheralClock::new(pmc, index)
     }
 }
 
-#[derive(Clone, Copy)]
-pub struct Pin {
-    pub controller: Controller,
+/// A pin, borrowing the token of its controller for as long as it's used.
+pub struct Pin<'a> {
+    pio: &'a reg::pio::PioPeripheral,
     pub pin: u8,
 }
 
-impl Pin {
-    pub fn new(controller: Controller, pin_index: u8,
-               function: Function) -> Pin {
+impl<'a> Pin<'a> {
+    pub fn new(peripherals: &'a Peripherals, controller: Controller, pin_index: u8,
+               function: Function) -> Pin<'a> {
         let p = Pin {
-            controller: controller,
+            pio: controller.pio(peripherals),
             pin: pin_index,
         };
         let offset = p.pin as usize;
-        let pio = p.controller.to_reg();
 
-        p.start_clock();
+        controller.clock(&peripherals.PMC).enable();
 
         match function {
             Function::Gpio(direction) => {
-                pio.per.update().set_pe(offset, true);
+                p.pio.per.update().set_pe(offset, true);
                 p.set_direction(direction);
             },
             Function::Peripheral(peripheral) => {
-                pio.pdr.update().set_pd(offset, true);
-                pio.absr.update().set_abs(offset, From::from(peripheral));
+                p.pio.pdr.update().set_pd(offset, true);
+                p.pio.absr.update().set_abs(offset, From::from(peripheral));
             },
         }
 
         p
     }
 
-    fn start_clock(&self) {
-        let p_clk = self.controller.clock();
-        p_clk.enable();
-    }
-}
-
-impl From<GpioConf> for Pin {
-  fn from(c: GpioConf) -> Pin {
-    let idx = (c.index % 32) as u8;
-    let controller = match c.index / 32 {
-      0 => ControllerA,
-      1 => ControllerB,
-      2 => ControllerC,
-      3 => ControllerD,
-      4 => ControllerE,
-      5 => ControllerF,
-      _ => panic!(),
-    };
+    /// Set up the GPIO described by `c`.
+    pub fn from_conf(peripherals: &'a Peripherals, c: GpioConf) -> Pin<'a> {
+        let idx = (c.index % 32) as u8;
+        let controller = match c.index / 32 {
+            0 => ControllerA,
+            1 => ControllerB,
+            2 => ControllerC,
+            3 => ControllerD,
+            4 => ControllerE,
+            5 => ControllerF,
+            _ => panic!(),
+        };
 
-    Pin::new(controller,
-             idx,
-             Function::Gpio(c.direction))
-  }
+        Pin::new(peripherals,
+                 controller,
+                 idx,
+                 Function::Gpio(c.direction))
+    }
 }
 
-impl ::hal::pin::Gpio for Pin {
+impl<'a> ::hal::pin::Gpio for Pin<'a> {
     fn set_level(&self, level: ::hal::pin::GpioLevel) {
         use self::pin::GpioLevel;
-        let pio = self.controller.to_reg();
+        let pio = self.pio;
         let level = match level {
             GpioLevel::Low => false,
             GpioLevel::High => true,
@@ -125,7 +119,7 @@ impl ::hal::pin::Gpio for Pin {
     }
 
     fn level(&self) -> pin::GpioLevel {
-        let pio = self.controller.to_reg();
+        let pio = self.pio;
 
         match pio.pdsr.get().pds(self.pin as usize) {
             false => pin::GpioLevel::Low,
@@ -134,7 +128,7 @@ impl ::hal::pin::Gpio for Pin {
     }
 
     fn set_direction(&self, new_dir: pin::GpioDirection) {
-        let pio = self.controller.to_reg();
+        let pio = self.pio;
         let offset = self.pin as usize;
 
         match new_dir {
@@ -150,7 +144,7 @@ impl ::hal::pin::Gpio for Pin {
 
 
 
-mod reg {
+pub(super) mod reg {
     use ioreg_proc::ioregs;
 
     ioregs!(PIO = {
@@ -193,18 +187,7 @@ mod reg {
         0x3c => reg32 pdsr {
             0..31 => pds[32]: ro
         },
-        0x40 => reg32 ier {
-            0..31 => ie[32]: wo
-        },
-        0x44 => reg32 idr {
-            0..31 => id[32]: wo
-        },
-        0x48 => reg32 imr {
-            0..31 => im[32]: ro
-        },
-        0x4c => reg32 isr {
-            0..31 => is[32]: ro
-        },
+        0x40 => interrupts reg32 irq {},
         0x50 => reg32 mder {
             0..31 => mde[32]: wo
         },
@@ -245,16 +228,16 @@ mod reg {
 
     extern {
         #[link_name="sam3x_iomem_PIOA"]
-        pub static PIO_A: pio::Pio;
+        pub static mut PIO_A: pio::Pio;
         #[link_name="sam3x_iomem_PIOB"]
-        pub static PIO_B: pio::Pio;
+        pub static mut PIO_B: pio::Pio;
         #[link_name="sam3x_iomem_PIOC"]
-        pub static PIO_C: pio::Pio;
+        pub static mut PIO_C: pio::Pio;
         #[link_name="sam3x_iomem_PIOD"]
-        pub static PIO_D: pio::Pio;
+        pub static mut PIO_D: pio::Pio;
         #[link_name="sam3x_iomem_PIOE"]
-        pub static PIO_E: pio::Pio;
+        pub static mut PIO_E: pio::Pio;
         #[link_name="sam3x_iomem_PIOF"]
-        pub static PIO_F: pio::Pio;
+        pub static mut PIO_F: pio::Pio;
     }
 }
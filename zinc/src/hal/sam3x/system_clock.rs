@@ -4,9 +4,41 @@ pub use self::reg::pmc::mor::Moscrcf as PmcMorMoscRCF;
 
 const DEFAULT_SYSTEM_CLOCK: u32 = 84_000_000;
 
+// Timeouts for the waits below, as numbers of status register reads. They
+// don't depend on any timer, since the clocks are being changed, and are
+// generous compared to the start-up times configured even at the 4 MHz boot
+// clock, so they only trip when something is missing or broken. From the slow
+// clock they take correspondingly longer to give up.
+const OSCILLATOR_TIMEOUT_POLLS: u32 = 1_000_000;
+const PLL_LOCK_TIMEOUT_POLLS: u32 = 1_000_000;
+const MASTER_CLOCK_TIMEOUT_POLLS: u32 = 100_000;
+const FLASH_TIMEOUT_POLLS: u32 = 100_000;
+
+/// Errors bringing up the system clock
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ClockError {
+    /// The main crystal oscillator never stabilized (e.g. no crystal fitted)
+    MainOscillator,
+    /// The internal RC oscillator never stabilized
+    RcOscillator,
+    /// The main clock never switched to the selected oscillator
+    OscillatorSelect,
+    /// The master clock never became ready after a change
+    MasterClock,
+    /// PLLA never locked
+    PllLock,
+    /// The flash controllers never became ready
+    Flash,
+}
+
+fn wait_mckrdy(pmc: &reg::pmc::Pmc) -> Result<(), ClockError> {
+    pmc.st.wait_mckrdy_timeout(MASTER_CLOCK_TIMEOUT_POLLS)
+        .map_err(|_| ClockError::MasterClock)
+}
+
 /// Initialize with our math, but should be like CMSIS
-pub fn init_default() -> u32 {
-    init_flash(DEFAULT_SYSTEM_CLOCK);
+pub fn init_default() -> Result<u32, ClockError> {
+    init_flash(DEFAULT_SYSTEM_CLOCK)?;
 
     let pmc = unsafe { &reg::PMC };
     let pll = Pll {
@@ -15,26 +47,26 @@ pub fn init_default() -> u32 {
         count: 0x3f,
     };
     // Initialize main oscillator and Xtal oscillator
-    init_main_oscillator(0x8);
+    init_main_oscillator(0x8)?;
 
     // Swap main clock source to newly-initialized xtal oscillator
     pmc.mckr.update()
         .set_css(self::reg::pmc::mckr::Css::MainClk);
-    wait_for!(pmc.st.get().mckrdy());
+    wait_mckrdy(pmc)?;
 
     // Initialize PLLA
     pmc.mckr.update()
         .set_pres(self::reg::pmc::mckr::Pres::CLK_2)
         .set_css(self::reg::pmc::mckr::Css::MainClk);
-    wait_for!(pmc.st.get().mckrdy());
+    wait_mckrdy(pmc)?;
 
     // Switch to PLLA clock
     pmc.mckr.update()
         .set_pres(self::reg::pmc::mckr::Pres::CLK_2)
         .set_css(self::reg::pmc::mckr::Css::PllaClk);
-    wait_for!(pmc.st.get().mckrdy());
+    wait_mckrdy(pmc)?;
 
-    DEFAULT_SYSTEM_CLOCK
+    Ok(DEFAULT_SYSTEM_CLOCK)
 }
 
 #[derive(Clone, Copy)]
@@ -78,23 +110,23 @@ pub enum ClockSource {
 const SLOW_CLOCK_FREQ: u32 = 32_768;
 
 impl ClockSource {
-    fn init(&self) {
+    fn init(&self) -> Result<(), ClockError> {
         use self::ClockSource::*;
         let css = match self {
             &InternalSlow => reg::pmc::mckr::Css::SlowClk,
             &InternalRc(freq) => {
-                init_rc_oscillator(freq);
+                init_rc_oscillator(freq)?;
                 reg::pmc::mckr::Css::MainClk
             },
             &Main(_) => {
                 // FIXME(mcoffin): wait time hard coded
-                init_main_oscillator(0x8);
+                init_main_oscillator(0x8)?;
                 reg::pmc::mckr::Css::MainClk
             },
         };
         let pmc = unsafe { &reg::PMC };
         pmc.mckr.update().set_css(css);
-        wait_for!(pmc.st.get().mckrdy());
+        wait_mckrdy(pmc)
     }
 
     fn freq(&self) -> u32 {
@@ -130,7 +162,7 @@ pub struct Pll {
 }
 
 impl Pll {
-    fn init(&self, src_freq: u32) {
+    fn init(&self, src_freq: u32) -> Result<(), ClockError> {
         use self::reg::pmc::mckr::Pres::*;
         let pmc = unsafe { &reg::PMC };
 
@@ -139,7 +171,8 @@ impl Pll {
             .set_mula(self.mul as u16)
             .set_diva(self.div as u8)
             .set_pllacount(self.count as u8);
-        wait_for!(pmc.st.get().locka());
+        pmc.st.wait_locka_timeout(PLL_LOCK_TIMEOUT_POLLS)
+            .map_err(|_| ClockError::PllLock)?;
 
         /*
         pmc.mckr.set_pres(match self.apply_freq(src_freq) {
@@ -153,11 +186,11 @@ impl Pll {
         */
         pmc.mckr.update()
             .set_pres(CLK);
-        wait_for!(pmc.st.get().mckrdy());
+        wait_mckrdy(pmc)?;
 
         pmc.mckr.update()
             .set_css(reg::pmc::mckr::Css::PllaClk);
-        wait_for!(pmc.st.get().mckrdy());
+        wait_mckrdy(pmc)
     }
 
     fn apply_freq(&self, freq: u32) -> u32 {
@@ -167,7 +200,7 @@ impl Pll {
 
 static FLASH_MAX_FREQ: u32 = 20_000_000;
 
-pub fn init_flash(clk_freq: u32) {
+pub fn init_flash(clk_freq: u32) -> Result<(), ClockError> {
     let (eefc0, eefc1) = unsafe {
         (&reg::EEFC0, &reg::EEFC1)
     };
@@ -176,14 +209,17 @@ pub fn init_flash(clk_freq: u32) {
     eefc0.fmr.update().set_fws(cycles as u16);
     eefc1.fmr.update().set_fws(cycles as u16);
 
-    wait_for!(eefc0.fsr.get().fready() &&
-              eefc1.fsr.get().fready());
+    eefc0.fsr.wait_fready_timeout(FLASH_TIMEOUT_POLLS)
+        .and_then(|_| eefc1.fsr.wait_fready_timeout(FLASH_TIMEOUT_POLLS))
+        .map_err(|_| ClockError::Flash)
 }
 
 /// Initializes the system master clock to be a given clock source optionally
 /// with PLL scaling
-pub fn init_clock(source: ClockSource, pll: Option<Pll>) -> u32 {
-    source.init();
+///
+/// Fails instead of hanging if the selected oscillator doesn't start.
+pub fn init_clock(source: ClockSource, pll: Option<Pll>) -> Result<u32, ClockError> {
+    source.init()?;
 
     let src_freq = source.freq();
     let freq = match pll {
@@ -192,18 +228,18 @@ pub fn init_clock(source: ClockSource, pll: Option<Pll>) -> u32 {
     };
 
     // Init flash
-    init_flash(freq);
+    init_flash(freq)?;
 
     match pll {
-        Some(p) => p.init(src_freq),
+        Some(p) => p.init(src_freq)?,
         _ => {},
     }
 
-    freq
+    Ok(freq)
 }
 
 /// Initializes the system exactly how CMSIS does (84MHz clock)
-pub fn temp_boot() {
+pub fn temp_boot() -> Result<(), ClockError> {
     use self::reg::pmc::mor::Moscsel::*;
     use self::reg::pmc::mckr::Css::*;
     use self::reg::pmc::mckr::Pres::*;
@@ -225,7 +261,8 @@ pub fn temp_boot() {
                 .set_moscxtst(0x8)
                 .set_moscrcen(true)
                 .set_moscxten(true);
-            wait_for!(pmc.st.get().moscxts());
+            pmc.st.wait_moscxts_timeout(OSCILLATOR_TIMEOUT_POLLS)
+                .map_err(|_| ClockError::MainOscillator)?;
         },
     }
 
@@ -236,10 +273,11 @@ pub fn temp_boot() {
         .set_moscrcen(true)
         .set_moscxten(true)
         .set_moscsel(MOSCXT);
-    wait_for!(pmc.st.get().moscsels());
+    pmc.st.wait_moscsels_timeout(OSCILLATOR_TIMEOUT_POLLS)
+        .map_err(|_| ClockError::OscillatorSelect)?;
     pmc.mckr.update()
         .set_css(MainClk);
-    wait_for!(pmc.st.get().mckrdy());
+    wait_mckrdy(pmc)?;
 
     // Initialize PLLA
     pmc.pllar.update()
@@ -247,25 +285,27 @@ pub fn temp_boot() {
         .set_mula(0x3)
         .set_pllacount(0x3f)
         .set_diva(0x1);
-    wait_for!(pmc.st.get().locka());
+    pmc.st.wait_locka_timeout(PLL_LOCK_TIMEOUT_POLLS)
+        .map_err(|_| ClockError::PllLock)?;
 
     // Switch to main clock
     pmc.mckr.update()
         .set_pres(CLK_2);
-    wait_for!(pmc.st.get().mckrdy());
+    wait_mckrdy(pmc)?;
 
     // Switch to PLLA
     pmc.mckr.update()
         .set_css(PllaClk);
-    wait_for!(pmc.st.get().mckrdy());
+    wait_mckrdy(pmc)?;
 
     ::hal::cortex_m3::systick::setup(24_000_000 / 1000);
     ::hal::cortex_m3::systick::enable();
+    Ok(())
 }
 
 const MOR_KEY: u8 = 0x37;
 
-fn init_rc_oscillator(freq: RCFreq) {
+fn init_rc_oscillator(freq: RCFreq) -> Result<(), ClockError> {
     let pmc = unsafe { &reg::PMC };
 
     // Enable MOSCRC
@@ -273,15 +313,17 @@ fn init_rc_oscillator(freq: RCFreq) {
         .set_moscrcen(true)
         .set_moscrcf(freq.into())
        .set_key(MOR_KEY);
-    wait_for!(pmc.st.get().moscrcs());
+    pmc.st.wait_moscrcs_timeout(OSCILLATOR_TIMEOUT_POLLS)
+        .map_err(|_| ClockError::RcOscillator)?;
 
     pmc.mor.update()
         .set_moscsel(reg::pmc::mor::Moscsel::MOSCRC)
         .set_key(MOR_KEY);
-    wait_for!(pmc.st.get().moscsels());
+    pmc.st.wait_moscsels_timeout(OSCILLATOR_TIMEOUT_POLLS)
+        .map_err(|_| ClockError::OscillatorSelect)
 }
 
-fn init_main_oscillator(start_time: u32) {
+fn init_main_oscillator(start_time: u32) -> Result<(), ClockError> {
     let pmc = unsafe { &reg::PMC };
 
     // Enable MOSCXT
@@ -290,7 +332,8 @@ fn init_main_oscillator(start_time: u32) {
         .set_moscrcen(true)
         .set_moscxtst(start_time as u8)
         .set_key(MOR_KEY);
-    wait_for!(pmc.st.get().moscxts());
+    pmc.st.wait_moscxts_timeout(OSCILLATOR_TIMEOUT_POLLS)
+        .map_err(|_| ClockError::MainOscillator)?;
 
     pmc.mor.update()
         .set_moscxten(true)
@@ -298,7 +341,8 @@ fn init_main_oscillator(start_time: u32) {
         .set_moscxtst(start_time as u8)
         .set_moscsel(reg::pmc::mor::Moscsel::MOSCXT)
         .set_key(MOR_KEY);
-    wait_for!(pmc.st.get().moscsels());
+    pmc.st.wait_moscsels_timeout(OSCILLATOR_TIMEOUT_POLLS)
+        .map_err(|_| ClockError::OscillatorSelect)
 }
 
 mod reg {
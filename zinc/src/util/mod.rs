@@ -1,3 +1,14 @@
+#[cfg(feature = "cpu_cortex-m3")]
+pub use hal::cortex_m3::systick::Deadline;
+pub use volatile_cell::wait::Timeout;
+
+/// Busy-waits until `$cond` is true.
+///
+/// With `timeout = N ms`, gives up after `N` SysTick periods (see
+/// `Deadline`), evaluating to `Err(Timeout)` rather than `Ok(())`. Zinc never
+/// sets SysTick up itself, so the application must already have it running
+/// with a 1ms period; don't use this form while changing clocks, bound those
+/// waits by a number of polls instead.
 #[macro_export]
 macro_rules! wait_for {
     ($cond:expr) => {
@@ -6,5 +17,16 @@ macro_rules! wait_for {
                 break;
             }
         }
-    }
+    };
+    ($cond:expr, timeout = $ms:tt ms) => {{
+        let mut deadline = $crate::util::Deadline::after_ms($ms);
+        loop {
+            if $cond {
+                break Ok(());
+            }
+            if deadline.expired() {
+                break Err($crate::util::Timeout);
+            }
+        }
+    }};
 }